@@ -0,0 +1,24 @@
+use twilight_cache_inmemory::CacheableAutoModerationRule;
+use twilight_model::{
+    guild::auto_moderation::AutoModerationRule,
+    id::{marker::AutoModerationRuleMarker, Id},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinimalCachedAutoModerationRule {
+    pub id: Id<AutoModerationRuleMarker>,
+}
+
+impl From<AutoModerationRule> for MinimalCachedAutoModerationRule {
+    fn from(rule: AutoModerationRule) -> Self {
+        Self { id: rule.id }
+    }
+}
+
+impl PartialEq<AutoModerationRule> for MinimalCachedAutoModerationRule {
+    fn eq(&self, other: &AutoModerationRule) -> bool {
+        self.id == other.id
+    }
+}
+
+impl CacheableAutoModerationRule for MinimalCachedAutoModerationRule {}
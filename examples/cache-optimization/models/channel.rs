@@ -14,6 +14,7 @@ pub struct MinimalCachedChannel {
     pub guild_id: Option<Id<GuildMarker>>,
     pub kind: ChannelType,
     pub parent_id: Option<Id<ChannelMarker>>,
+    pub position: Option<i32>,
 }
 
 impl From<Channel> for MinimalCachedChannel {
@@ -23,6 +24,7 @@ impl From<Channel> for MinimalCachedChannel {
             guild_id: channel.guild_id,
             kind: channel.kind,
             parent_id: channel.parent_id,
+            position: channel.position,
         }
     }
 }
@@ -33,6 +35,7 @@ impl PartialEq<Channel> for MinimalCachedChannel {
             && self.guild_id == other.guild_id
             && self.kind == other.kind
             && self.parent_id == other.parent_id
+            && self.position == other.position
     }
 }
 
@@ -53,6 +56,10 @@ impl CacheableChannel for MinimalCachedChannel {
         self.parent_id
     }
 
+    fn position(&self) -> Option<i32> {
+        self.position
+    }
+
     fn permission_overwrites(&self) -> Option<&[PermissionOverwrite]> {
         None
     }
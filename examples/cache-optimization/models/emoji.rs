@@ -7,18 +7,26 @@ use twilight_model::{
 #[derive(Clone, Debug, PartialEq)]
 pub struct MinimalCachedEmoji {
     pub id: Id<EmojiMarker>,
+    pub name: String,
 }
 
 impl From<Emoji> for MinimalCachedEmoji {
     fn from(value: Emoji) -> Self {
-        Self { id: value.id }
+        Self {
+            id: value.id,
+            name: value.name,
+        }
     }
 }
 
 impl PartialEq<Emoji> for MinimalCachedEmoji {
     fn eq(&self, other: &Emoji) -> bool {
-        self.id == other.id
+        self.id == other.id && self.name == other.name
     }
 }
 
-impl CacheableEmoji for MinimalCachedEmoji {}
+impl CacheableEmoji for MinimalCachedEmoji {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
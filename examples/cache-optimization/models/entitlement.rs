@@ -0,0 +1,65 @@
+use twilight_cache_inmemory::CacheableEntitlement;
+use twilight_model::{
+    application::monetization::Entitlement,
+    id::{
+        marker::{EntitlementMarker, GuildMarker, SkuMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinimalCachedEntitlement {
+    pub consumed: bool,
+    pub ends_at: Option<Timestamp>,
+    pub guild_id: Option<Id<GuildMarker>>,
+    pub id: Id<EntitlementMarker>,
+    pub sku_id: Id<SkuMarker>,
+    pub user_id: Option<Id<UserMarker>>,
+}
+
+impl From<Entitlement> for MinimalCachedEntitlement {
+    fn from(entitlement: Entitlement) -> Self {
+        Self {
+            consumed: entitlement.consumed == Some(true),
+            ends_at: entitlement.ends_at,
+            guild_id: entitlement.guild_id,
+            id: entitlement.id,
+            sku_id: entitlement.sku_id,
+            user_id: entitlement.user_id,
+        }
+    }
+}
+
+impl PartialEq<Entitlement> for MinimalCachedEntitlement {
+    fn eq(&self, other: &Entitlement) -> bool {
+        self.consumed == (other.consumed == Some(true))
+            && self.ends_at == other.ends_at
+            && self.guild_id == other.guild_id
+            && self.id == other.id
+            && self.sku_id == other.sku_id
+            && self.user_id == other.user_id
+    }
+}
+
+impl CacheableEntitlement for MinimalCachedEntitlement {
+    fn consumed(&self) -> bool {
+        self.consumed
+    }
+
+    fn ends_at(&self) -> Option<Timestamp> {
+        self.ends_at
+    }
+
+    fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        self.guild_id
+    }
+
+    fn sku_id(&self) -> Id<SkuMarker> {
+        self.sku_id
+    }
+
+    fn user_id(&self) -> Option<Id<UserMarker>> {
+        self.user_id
+    }
+}
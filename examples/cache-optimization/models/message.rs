@@ -3,6 +3,7 @@ use twilight_model::{
     channel::{message::Reaction, Message},
     gateway::payload::incoming::MessageUpdate,
     id::{marker::MessageMarker, Id},
+    poll::Poll,
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -39,6 +40,10 @@ impl CacheableMessage for MinimalCachedMessage {
         &[]
     }
 
+    fn poll_mut(&mut self) -> Option<&mut Poll> {
+        None
+    }
+
     fn reactions_mut(&mut self) -> &mut [Reaction] {
         &mut []
     }
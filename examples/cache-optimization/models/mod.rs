@@ -3,9 +3,11 @@
 use twilight_cache_inmemory::CacheableModels;
 use twilight_cache_inmemory::InMemoryCache;
 
+pub mod auto_moderation_rule;
 pub mod channel;
 pub mod current_user;
 pub mod emoji;
+pub mod entitlement;
 pub mod guild;
 pub mod guild_integration;
 pub mod guild_scheduled_events;
@@ -22,9 +24,11 @@ pub mod voice_state;
 pub struct CustomCacheModels;
 
 impl CacheableModels for CustomCacheModels {
+    type AutoModerationRule = auto_moderation_rule::MinimalCachedAutoModerationRule;
     type Channel = channel::MinimalCachedChannel;
     type CurrentUser = current_user::MinimalCachedCurrentUser;
     type Emoji = emoji::MinimalCachedEmoji;
+    type Entitlement = entitlement::MinimalCachedEntitlement;
     type Guild = guild::MinimalCachedGuild;
     type GuildIntegration = guild_integration::MinimalCachedGuildIntegration;
     type GuildScheduledEvent = guild_scheduled_events::MinimalGuildScheduledEvent;
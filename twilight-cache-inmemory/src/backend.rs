@@ -0,0 +1,883 @@
+//! Reusing the event-to-cache mapping with alternative storage.
+//!
+//! [`InMemoryCache`] stores resources in process memory. Bots that instead
+//! keep their cache in another store, such as Redis, sled, or a remote cache
+//! service, can implement [`CacheBackend`] for it to have events applied to
+//! it via [`CacheBackend::update`] without reimplementing how each event
+//! affects the cached resources.
+//!
+//! [`InMemoryCache`] processes events through the same mapping, so backends
+//! stay consistent with it.
+//!
+//! [`InMemoryCache`]: crate::InMemoryCache
+
+use crate::{
+    config::{ResourceField, ResourceType},
+    model::CachedBan,
+    CacheableModels,
+};
+use std::ops::Deref;
+use twilight_model::{
+    gateway::event::Event,
+    id::{
+        marker::{
+            AutoModerationRuleMarker, ChannelMarker, EmojiMarker, EntitlementMarker, GuildMarker,
+            IntegrationMarker, MessageMarker, RoleMarker, ScheduledEventMarker, StageMarker,
+            StickerMarker, UserMarker,
+        },
+        Id,
+    },
+};
+
+pub(crate) mod in_memory;
+
+/// Storage of cached resources that events can be applied to.
+///
+/// Implementors only provide getting, inserting, modifying, and removing
+/// single resources of the cached [`Models`], while [`update`] maps events to
+/// those operations the same way [`InMemoryCache`] processes them.
+///
+/// Every method other than [`update`] defaults to storing nothing, so only the
+/// methods of stored resources need to be implemented. Methods of resource
+/// types not returned by [`resource_types`] are never called by [`update`],
+/// and fields returned by [`dropped_fields`] are removed from resources before
+/// they're inserted.
+///
+/// Guilds are inserted after their channels, emojis, members, presences,
+/// roles, scheduled events, stage instances, stickers, and voice states, which
+/// are inserted separately.
+///
+/// # Errors
+///
+/// Every method returns [`Error`] if the storage fails, which [`update`]
+/// passes through.
+///
+/// [`Error`]: Self::Error
+/// [`InMemoryCache`]: crate::InMemoryCache
+/// [`Models`]: Self::Models
+/// [`dropped_fields`]: Self::dropped_fields
+/// [`resource_types`]: Self::resource_types
+/// [`update`]: Self::update
+#[allow(clippy::missing_errors_doc, unused_variables)]
+pub trait CacheBackend {
+    /// Cached representations of the resources.
+    type Models: CacheableModels;
+
+    /// Error returned by the storage.
+    type Error;
+
+    /// Resource types to cache.
+    ///
+    /// Defaults to all resource types.
+    fn resource_types(&self) -> ResourceType {
+        ResourceType::all()
+    }
+
+    /// Fields of resources to not cache.
+    ///
+    /// Defaults to no fields.
+    fn dropped_fields(&self) -> ResourceField {
+        ResourceField::empty()
+    }
+
+    /// Get the current user.
+    fn current_user(
+        &self,
+    ) -> Result<Option<<Self::Models as CacheableModels>::CurrentUser>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Insert or replace the current user.
+    fn insert_current_user(
+        &self,
+        current_user: <Self::Models as CacheableModels>::CurrentUser,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace an auto moderation rule of a guild.
+    fn insert_auto_moderation_rule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        rule_id: Id<AutoModerationRuleMarker>,
+        rule: <Self::Models as CacheableModels>::AutoModerationRule,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove an auto moderation rule.
+    fn remove_auto_moderation_rule(
+        &self,
+        rule_id: Id<AutoModerationRuleMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Get the ban of a user from a guild.
+    fn ban(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<CachedBan>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Insert or replace the ban of a user from a guild.
+    fn insert_ban(&self, ban: CachedBan) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove the ban of a user from a guild.
+    fn remove_ban(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Get a channel or thread by ID.
+    fn channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<Option<<Self::Models as CacheableModels>::Channel>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Insert or replace a channel or thread.
+    fn insert_channel(
+        &self,
+        channel: <Self::Models as CacheableModels>::Channel,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Modify a channel or thread, if it's stored.
+    fn modify_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        f: impl FnOnce(&mut <Self::Models as CacheableModels>::Channel),
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a channel or thread, along with the members of the thread.
+    fn remove_channel(&self, channel_id: Id<ChannelMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Get the IDs of the emojis of a guild.
+    fn guild_emoji_ids(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<Id<EmojiMarker>>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    /// Insert or replace an emoji of a guild.
+    fn insert_emoji(
+        &self,
+        guild_id: Id<GuildMarker>,
+        emoji_id: Id<EmojiMarker>,
+        emoji: <Self::Models as CacheableModels>::Emoji,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove an emoji.
+    fn remove_emoji(&self, emoji_id: Id<EmojiMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace an entitlement.
+    fn insert_entitlement(
+        &self,
+        entitlement_id: Id<EntitlementMarker>,
+        entitlement: <Self::Models as CacheableModels>::Entitlement,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove an entitlement.
+    fn remove_entitlement(&self, entitlement_id: Id<EntitlementMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace a guild, which is then available.
+    fn insert_guild(
+        &self,
+        guild: <Self::Models as CacheableModels>::Guild,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Modify a guild, if it's stored.
+    fn modify_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        f: impl FnOnce(&mut <Self::Models as CacheableModels>::Guild),
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a guild along with all of its resources.
+    ///
+    /// Called when the guild is deleted or the current user leaves it.
+    fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Mark a guild as unavailable, removing all of its resources other than
+    /// its bans, which aren't received again once it's available.
+    fn mark_guild_unavailable(&self, guild_id: Id<GuildMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace an integration of a guild.
+    fn insert_integration(
+        &self,
+        guild_id: Id<GuildMarker>,
+        integration_id: Id<IntegrationMarker>,
+        integration: <Self::Models as CacheableModels>::GuildIntegration,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove an integration of a guild.
+    fn remove_integration(
+        &self,
+        guild_id: Id<GuildMarker>,
+        integration_id: Id<IntegrationMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Get a member of a guild by their user ID.
+    fn member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<<Self::Models as CacheableModels>::Member>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Insert or replace a member of a guild.
+    fn insert_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        member: <Self::Models as CacheableModels>::Member,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Modify a member of a guild, if it's stored.
+    fn modify_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        f: impl FnOnce(&mut <Self::Models as CacheableModels>::Member),
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a member of a guild.
+    fn remove_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace a message of a channel, in a guild if it has an ID.
+    fn insert_message(
+        &self,
+        guild_id: Option<Id<GuildMarker>>,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        message: <Self::Models as CacheableModels>::Message,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Modify a message, if it's stored.
+    fn modify_message(
+        &self,
+        message_id: Id<MessageMarker>,
+        f: impl FnOnce(&mut <Self::Models as CacheableModels>::Message),
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a message of a channel.
+    fn remove_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace the presence of a user in a guild.
+    fn insert_presence(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        presence: <Self::Models as CacheableModels>::Presence,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace a role of a guild.
+    fn insert_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role: <Self::Models as CacheableModels>::Role,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a role.
+    fn remove_role(&self, role_id: Id<RoleMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace a scheduled event of a guild.
+    fn insert_scheduled_event(
+        &self,
+        guild_id: Id<GuildMarker>,
+        event_id: Id<ScheduledEventMarker>,
+        event: <Self::Models as CacheableModels>::GuildScheduledEvent,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Modify a scheduled event, if it's stored.
+    fn modify_scheduled_event(
+        &self,
+        event_id: Id<ScheduledEventMarker>,
+        f: impl FnOnce(&mut <Self::Models as CacheableModels>::GuildScheduledEvent),
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a scheduled event of a guild.
+    fn remove_scheduled_event(
+        &self,
+        guild_id: Id<GuildMarker>,
+        event_id: Id<ScheduledEventMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace a stage instance of a guild.
+    fn insert_stage_instance(
+        &self,
+        guild_id: Id<GuildMarker>,
+        stage_id: Id<StageMarker>,
+        stage_instance: <Self::Models as CacheableModels>::StageInstance,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a stage instance.
+    fn remove_stage_instance(&self, stage_id: Id<StageMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Get the IDs of the stickers of a guild.
+    fn guild_sticker_ids(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<Id<StickerMarker>>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    /// Insert or replace a sticker of a guild.
+    fn insert_sticker(
+        &self,
+        guild_id: Id<GuildMarker>,
+        sticker: <Self::Models as CacheableModels>::Sticker,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a sticker.
+    fn remove_sticker(&self, sticker_id: Id<StickerMarker>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert a member of a thread.
+    fn insert_thread_member(
+        &self,
+        thread_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove a member of a thread.
+    fn remove_thread_member(
+        &self,
+        thread_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace a user, seen in a guild if it has an ID.
+    fn insert_user(
+        &self,
+        guild_id: Option<Id<GuildMarker>>,
+        user_id: Id<UserMarker>,
+        user: <Self::Models as CacheableModels>::User,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Insert or replace the voice state of a user in a guild.
+    fn insert_voice_state(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        voice_state: <Self::Models as CacheableModels>::VoiceState,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Remove the voice state of a user in a guild.
+    fn remove_voice_state(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Update the storage with an event.
+    ///
+    /// Events that don't affect any cached resource are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of the storage, leaving the event partially
+    /// applied.
+    fn update(&self, event: &Event) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        event.update_backend(self)
+    }
+}
+
+/// Mapping of an event to the operations of a backend.
+pub(crate) trait UpdateBackend {
+    /// Apply the event to a backend.
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error>;
+}
+
+/// Whether a backend caches a resource type.
+pub(crate) fn wants(backend: &impl CacheBackend, resource_type: ResourceType) -> bool {
+    backend.resource_types().contains(resource_type)
+}
+
+impl UpdateBackend for Event {
+    // clippy: using `.deref()` is cleaner
+    #[allow(clippy::explicit_deref_methods)]
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        match self {
+            Event::AutoModerationRuleCreate(v) => v.update_backend(backend),
+            Event::AutoModerationRuleDelete(v) => v.update_backend(backend),
+            Event::AutoModerationRuleUpdate(v) => v.update_backend(backend),
+            Event::BanAdd(v) => v.update_backend(backend),
+            Event::BanRemove(v) => v.update_backend(backend),
+            Event::ChannelCreate(v) => v.deref().update_backend(backend),
+            Event::ChannelDelete(v) => v.deref().update_backend(backend),
+            Event::ChannelPinsUpdate(v) => v.update_backend(backend),
+            Event::ChannelUpdate(v) => v.deref().update_backend(backend),
+            Event::EntitlementCreate(v) => v.update_backend(backend),
+            Event::EntitlementDelete(v) => v.update_backend(backend),
+            Event::EntitlementUpdate(v) => v.update_backend(backend),
+            Event::GuildCreate(v) => v.deref().update_backend(backend),
+            Event::GuildDelete(v) => v.update_backend(backend),
+            Event::GuildAuditLogEntryCreate(v) => v.deref().update_backend(backend),
+            Event::GuildEmojisUpdate(v) => v.update_backend(backend),
+            Event::GuildScheduledEventCreate(v) => v.deref().update_backend(backend),
+            Event::GuildScheduledEventDelete(v) => v.deref().update_backend(backend),
+            Event::GuildScheduledEventUpdate(v) => v.deref().update_backend(backend),
+            Event::GuildScheduledEventUserAdd(v) => v.update_backend(backend),
+            Event::GuildScheduledEventUserRemove(v) => v.update_backend(backend),
+            Event::GuildStickersUpdate(v) => v.update_backend(backend),
+            Event::GuildUpdate(v) => v.deref().update_backend(backend),
+            Event::IntegrationCreate(v) => v.deref().update_backend(backend),
+            Event::IntegrationDelete(v) => v.update_backend(backend),
+            Event::IntegrationUpdate(v) => v.deref().update_backend(backend),
+            Event::InteractionCreate(v) => v.deref().update_backend(backend),
+            Event::MemberAdd(v) => v.deref().update_backend(backend),
+            Event::MemberChunk(v) => v.update_backend(backend),
+            Event::MemberRemove(v) => v.update_backend(backend),
+            Event::MemberUpdate(v) => v.deref().update_backend(backend),
+            Event::MessageCreate(v) => v.deref().update_backend(backend),
+            Event::MessageDelete(v) => v.update_backend(backend),
+            Event::MessageDeleteBulk(v) => v.update_backend(backend),
+            Event::MessagePollVoteAdd(v) => v.update_backend(backend),
+            Event::MessagePollVoteRemove(v) => v.update_backend(backend),
+            Event::MessageUpdate(v) => v.deref().update_backend(backend),
+            Event::PresenceUpdate(v) => v.deref().update_backend(backend),
+            Event::ReactionAdd(v) => v.deref().update_backend(backend),
+            Event::ReactionRemove(v) => v.deref().update_backend(backend),
+            Event::ReactionRemoveAll(v) => v.update_backend(backend),
+            Event::ReactionRemoveEmoji(v) => v.update_backend(backend),
+            Event::Ready(v) => v.deref().update_backend(backend),
+            Event::RoleCreate(v) => v.update_backend(backend),
+            Event::RoleDelete(v) => v.update_backend(backend),
+            Event::RoleUpdate(v) => v.update_backend(backend),
+            Event::StageInstanceCreate(v) => v.update_backend(backend),
+            Event::StageInstanceDelete(v) => v.update_backend(backend),
+            Event::StageInstanceUpdate(v) => v.update_backend(backend),
+            Event::ThreadCreate(v) => v.deref().update_backend(backend),
+            Event::ThreadDelete(v) => v.update_backend(backend),
+            Event::ThreadListSync(v) => v.update_backend(backend),
+            Event::ThreadMemberUpdate(v) => v.deref().update_backend(backend),
+            Event::ThreadMembersUpdate(v) => v.update_backend(backend),
+            Event::ThreadUpdate(v) => v.deref().update_backend(backend),
+            Event::UnavailableGuild(v) => v.update_backend(backend),
+            Event::UserUpdate(v) => v.update_backend(backend),
+            Event::VoiceStateUpdate(v) => v.deref().update_backend(backend),
+
+            // Ignored events.
+            Event::AutoModerationActionExecution(_)
+            | Event::CommandPermissionsUpdate(_)
+            | Event::GatewayClose(_)
+            | Event::GatewayHeartbeat(_)
+            | Event::GatewayHeartbeatAck
+            | Event::GatewayHello(_)
+            | Event::GatewayInvalidateSession(_)
+            | Event::GatewayReconnect
+            | Event::GuildIntegrationsUpdate(_)
+            | Event::InviteCreate(_)
+            | Event::InviteDelete(_)
+            | Event::Resumed
+            | Event::TypingStart(_)
+            | Event::Unknown { .. }
+            | Event::VoiceServerUpdate(_)
+            | Event::WebhooksUpdate(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CacheBackend;
+    use crate::{
+        config::ResourceType,
+        model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage},
+        test, DefaultCacheModels,
+    };
+    use std::{collections::HashMap, convert::Infallible, hash::Hash, sync::Mutex};
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::incoming::{
+                GuildCreate, GuildDelete, GuildEmojisUpdate, MemberUpdate, MessageCreate,
+                MessageUpdate,
+            },
+        },
+        guild::Role,
+        id::{
+            marker::{
+                ChannelMarker, EmojiMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker,
+            },
+            Id,
+        },
+        user::User,
+    };
+
+    type Store<K, V> = Mutex<HashMap<K, V>>;
+
+    fn get<K: Eq + Hash, V: Clone>(store: &Store<K, V>, key: &K) -> Option<V> {
+        store.lock().unwrap().get(key).cloned()
+    }
+
+    fn insert<K: Eq + Hash, V>(store: &Store<K, V>, key: K, value: V) {
+        store.lock().unwrap().insert(key, value);
+    }
+
+    fn remove<K: Eq + Hash, V>(store: &Store<K, V>, key: &K) {
+        store.lock().unwrap().remove(key);
+    }
+
+    /// Backend storing emojis, guilds, members, messages, roles, and users in
+    /// hash maps.
+    #[derive(Default)]
+    struct MapBackend {
+        emojis: Store<Id<EmojiMarker>, (Id<GuildMarker>, CachedEmoji)>,
+        guilds: Store<Id<GuildMarker>, CachedGuild>,
+        members: Store<(Id<GuildMarker>, Id<UserMarker>), CachedMember>,
+        messages: Store<Id<MessageMarker>, CachedMessage>,
+        resource_types: Option<ResourceType>,
+        roles: Store<Id<RoleMarker>, (Id<GuildMarker>, Role)>,
+        users: Store<Id<UserMarker>, User>,
+    }
+
+    impl CacheBackend for MapBackend {
+        type Models = DefaultCacheModels;
+        type Error = Infallible;
+
+        fn resource_types(&self) -> ResourceType {
+            self.resource_types.unwrap_or(ResourceType::all())
+        }
+
+        fn guild_emoji_ids(
+            &self,
+            guild_id: Id<GuildMarker>,
+        ) -> Result<Vec<Id<EmojiMarker>>, Infallible> {
+            Ok(self
+                .emojis
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(_, (emoji_guild_id, _))| *emoji_guild_id == guild_id)
+                .map(|(emoji_id, _)| *emoji_id)
+                .collect())
+        }
+
+        fn insert_emoji(
+            &self,
+            guild_id: Id<GuildMarker>,
+            emoji_id: Id<EmojiMarker>,
+            emoji: CachedEmoji,
+        ) -> Result<(), Infallible> {
+            insert(&self.emojis, emoji_id, (guild_id, emoji));
+
+            Ok(())
+        }
+
+        fn remove_emoji(&self, emoji_id: Id<EmojiMarker>) -> Result<(), Infallible> {
+            remove(&self.emojis, &emoji_id);
+
+            Ok(())
+        }
+
+        fn insert_guild(&self, guild: CachedGuild) -> Result<(), Infallible> {
+            insert(&self.guilds, guild.id(), guild);
+
+            Ok(())
+        }
+
+        fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<(), Infallible> {
+            remove(&self.guilds, &guild_id);
+            self.emojis
+                .lock()
+                .unwrap()
+                .retain(|_, (emoji_guild_id, _)| *emoji_guild_id != guild_id);
+            self.members
+                .lock()
+                .unwrap()
+                .retain(|(member_guild_id, _), _| *member_guild_id != guild_id);
+            self.roles
+                .lock()
+                .unwrap()
+                .retain(|_, (role_guild_id, _)| *role_guild_id != guild_id);
+
+            Ok(())
+        }
+
+        fn member(
+            &self,
+            guild_id: Id<GuildMarker>,
+            user_id: Id<UserMarker>,
+        ) -> Result<Option<CachedMember>, Infallible> {
+            Ok(get(&self.members, &(guild_id, user_id)))
+        }
+
+        fn insert_member(
+            &self,
+            guild_id: Id<GuildMarker>,
+            user_id: Id<UserMarker>,
+            member: CachedMember,
+        ) -> Result<(), Infallible> {
+            insert(&self.members, (guild_id, user_id), member);
+
+            Ok(())
+        }
+
+        fn modify_member(
+            &self,
+            guild_id: Id<GuildMarker>,
+            user_id: Id<UserMarker>,
+            f: impl FnOnce(&mut CachedMember),
+        ) -> Result<(), Infallible> {
+            if let Some(member) = self.members.lock().unwrap().get_mut(&(guild_id, user_id)) {
+                f(member);
+            }
+
+            Ok(())
+        }
+
+        fn insert_message(
+            &self,
+            _: Option<Id<GuildMarker>>,
+            _: Id<ChannelMarker>,
+            message_id: Id<MessageMarker>,
+            message: CachedMessage,
+        ) -> Result<(), Infallible> {
+            insert(&self.messages, message_id, message);
+
+            Ok(())
+        }
+
+        fn modify_message(
+            &self,
+            message_id: Id<MessageMarker>,
+            f: impl FnOnce(&mut CachedMessage),
+        ) -> Result<(), Infallible> {
+            if let Some(message) = self.messages.lock().unwrap().get_mut(&message_id) {
+                f(message);
+            }
+
+            Ok(())
+        }
+
+        fn insert_role(&self, guild_id: Id<GuildMarker>, role: Role) -> Result<(), Infallible> {
+            insert(&self.roles, role.id, (guild_id, role));
+
+            Ok(())
+        }
+
+        fn insert_user(
+            &self,
+            _: Option<Id<GuildMarker>>,
+            user_id: Id<UserMarker>,
+            user: User,
+        ) -> Result<(), Infallible> {
+            insert(&self.users, user_id, user);
+
+            Ok(())
+        }
+    }
+
+    /// Backend failing to store users.
+    struct FailingBackend;
+
+    impl CacheBackend for FailingBackend {
+        type Models = DefaultCacheModels;
+        type Error = &'static str;
+
+        fn insert_user(
+            &self,
+            _: Option<Id<GuildMarker>>,
+            _: Id<UserMarker>,
+            _: User,
+        ) -> Result<(), Self::Error> {
+            Err("unavailable")
+        }
+    }
+
+    #[test]
+    fn update() -> Result<(), Infallible> {
+        let backend = MapBackend::default();
+        let guild_id = Id::new(1);
+        let user_id = Id::new(3);
+
+        let mut guild = test::guild(guild_id, Some(1));
+        guild.emojis = vec![test::emoji(Id::new(5), None), test::emoji(Id::new(6), None)];
+        guild.members = vec![test::member(user_id)];
+        guild.roles = vec![test::role(Id::new(7))];
+        backend.update(&Event::GuildCreate(Box::new(GuildCreate::Available(guild))))?;
+
+        assert!(get(&backend.guilds, &guild_id).is_some());
+        assert!(get(&backend.members, &(guild_id, user_id)).is_some());
+        assert!(get(&backend.users, &user_id).is_some());
+        assert!(get(&backend.roles, &Id::new(7)).is_some());
+
+        let user = get(&backend.users, &user_id).unwrap();
+        backend.update(&Event::MemberUpdate(Box::new(MemberUpdate {
+            avatar: None,
+            communication_disabled_until: None,
+            guild_id,
+            deaf: None,
+            joined_at: None,
+            mute: Some(true),
+            nick: Some("nick".to_owned()),
+            pending: false,
+            premium_since: None,
+            roles: vec![Id::new(7)],
+            user,
+        })))?;
+        let member = get(&backend.members, &(guild_id, user_id)).unwrap();
+        assert_eq!(Some("nick"), member.nick());
+        assert_eq!(Some(true), member.mute());
+        assert_eq!(&[Id::new(7)], member.roles());
+
+        backend.update(&Event::GuildEmojisUpdate(GuildEmojisUpdate {
+            emojis: vec![test::emoji(Id::new(6), None)],
+            guild_id,
+        }))?;
+        assert!(get(&backend.emojis, &Id::new(5)).is_none());
+        assert!(get(&backend.emojis, &Id::new(6)).is_some());
+
+        backend.update(&Event::MessageCreate(Box::new(MessageCreate(
+            test::message(),
+        ))))?;
+        backend.update(&Event::MessageUpdate(Box::new(MessageUpdate {
+            attachments: None,
+            author: None,
+            channel_id: Id::new(2),
+            content: Some("pong".to_owned()),
+            edited_timestamp: None,
+            embeds: None,
+            guild_id: None,
+            id: Id::new(4),
+            kind: None,
+            mention_everyone: None,
+            mention_roles: None,
+            mentions: None,
+            pinned: Some(true),
+            timestamp: None,
+            tts: None,
+        })))?;
+        let message = get(&backend.messages, &Id::new(4)).unwrap();
+        assert_eq!("pong", message.content());
+        assert!(message.pinned());
+
+        backend.update(&Event::GuildDelete(GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        }))?;
+        assert!(get(&backend.guilds, &guild_id).is_none());
+        assert!(get(&backend.members, &(guild_id, user_id)).is_none());
+        assert!(get(&backend.emojis, &Id::new(6)).is_none());
+
+        Ok(())
+    }
+
+    /// Resources of types the backend doesn't cache are never inserted.
+    #[test]
+    fn resource_types() -> Result<(), Infallible> {
+        let backend = MapBackend {
+            resource_types: Some(ResourceType::MESSAGE),
+            ..MapBackend::default()
+        };
+
+        backend.update(&Event::MessageCreate(Box::new(MessageCreate(
+            test::message(),
+        ))))?;
+        assert!(get(&backend.messages, &Id::new(4)).is_some());
+        assert!(backend.users.lock().unwrap().is_empty());
+        assert!(backend.members.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    /// Errors of the storage are returned by the update.
+    #[test]
+    fn error() {
+        let result = FailingBackend.update(&Event::MessageCreate(Box::new(MessageCreate(
+            test::message(),
+        ))));
+
+        assert_eq!(Err("unavailable"), result);
+    }
+}
@@ -0,0 +1,863 @@
+//! Storage of the in-memory cache as a backend.
+
+use super::{CacheBackend, UpdateBackend};
+use crate::{
+    change::CacheChange,
+    config::{ResourceField, ResourceType},
+    model::CachedBan,
+    traits::{CacheableChannel, CacheableRole, CacheableSticker, CacheableVoiceState},
+    CacheableModels, GuildResource, InMemoryCache,
+};
+use std::{collections::HashSet, convert::Infallible};
+use twilight_model::id::{
+    marker::{
+        AutoModerationRuleMarker, ChannelMarker, EmojiMarker, EntitlementMarker, GuildMarker,
+        IntegrationMarker, MessageMarker, RoleMarker, ScheduledEventMarker, StageMarker,
+        StickerMarker, UserMarker,
+    },
+    Id,
+};
+
+/// Backend storing resources in an [`InMemoryCache`].
+pub(crate) struct Storage<'a, CacheModels: CacheableModels>(pub &'a InMemoryCache<CacheModels>);
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Apply an event to the cache.
+    pub(crate) fn apply<T: UpdateBackend + ?Sized>(&self, value: &T) {
+        infallible(value.update_backend(&Storage(self)));
+    }
+}
+
+/// Unwrap the result of an operation of [`Storage`], which can't fail.
+pub(crate) fn infallible<T>(result: Result<T, Infallible>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(never) => match never {},
+    }
+}
+
+impl<CacheModels: CacheableModels> CacheBackend for Storage<'_, CacheModels> {
+    type Models = CacheModels;
+    type Error = Infallible;
+
+    fn resource_types(&self) -> ResourceType {
+        self.0.config.resource_types()
+    }
+
+    fn dropped_fields(&self) -> ResourceField {
+        self.0.config.dropped_fields()
+    }
+
+    fn current_user(&self) -> Result<Option<CacheModels::CurrentUser>, Infallible> {
+        Ok(self.0.current_user())
+    }
+
+    fn insert_current_user(
+        &self,
+        current_user: CacheModels::CurrentUser,
+    ) -> Result<(), Infallible> {
+        self.0
+            .current_user
+            .lock()
+            .expect("current user poisoned")
+            .replace(current_user);
+
+        Ok(())
+    }
+
+    fn insert_auto_moderation_rule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        rule_id: Id<AutoModerationRuleMarker>,
+        rule: CacheModels::AutoModerationRule,
+    ) -> Result<(), Infallible> {
+        self.0
+            .guild_auto_moderation_rules
+            .entry(guild_id)
+            .or_default()
+            .insert(rule_id);
+
+        crate::upsert_guild_item(&self.0.auto_moderation_rules, guild_id, rule_id, rule);
+
+        Ok(())
+    }
+
+    fn remove_auto_moderation_rule(
+        &self,
+        rule_id: Id<AutoModerationRuleMarker>,
+    ) -> Result<(), Infallible> {
+        if let Some((_, data)) = self.0.auto_moderation_rules.remove(&rule_id) {
+            let guild_id = data.guild_id;
+
+            if let Some(mut rules) = self.0.guild_auto_moderation_rules.get_mut(&guild_id) {
+                rules.remove(&rule_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn ban(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<CachedBan>, Infallible> {
+        Ok(self
+            .0
+            .bans
+            .get(&(guild_id, user_id))
+            .map(|ban| ban.value().clone()))
+    }
+
+    fn insert_ban(&self, ban: CachedBan) -> Result<(), Infallible> {
+        self.0
+            .guild_bans
+            .entry(ban.guild_id)
+            .or_default()
+            .insert(ban.user_id);
+        self.0.bans.insert((ban.guild_id, ban.user_id), ban);
+
+        Ok(())
+    }
+
+    fn remove_ban(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Infallible> {
+        self.0.bans.remove(&(guild_id, user_id));
+
+        let remove_guild = self
+            .0
+            .guild_bans
+            .get_mut(&guild_id)
+            .is_some_and(|mut bans| bans.remove(&user_id) && bans.is_empty());
+
+        if remove_guild {
+            self.0.guild_bans.remove(&guild_id);
+        }
+
+        Ok(())
+    }
+
+    fn channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<Option<CacheModels::Channel>, Infallible> {
+        Ok(self
+            .0
+            .channels
+            .get(&channel_id)
+            .map(|channel| channel.value().clone()))
+    }
+
+    fn insert_channel(&self, channel: CacheModels::Channel) -> Result<(), Infallible> {
+        if let Some(guild_id) = channel.guild_id() {
+            self.0
+                .guild_channels
+                .entry(guild_id)
+                .or_default()
+                .insert(channel.id());
+        }
+
+        self.0.insert_channel(channel);
+
+        Ok(())
+    }
+
+    fn modify_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        f: impl FnOnce(&mut CacheModels::Channel),
+    ) -> Result<(), Infallible> {
+        if let Some(mut channel) = self.0.channels.get_mut(&channel_id) {
+            let old = self.0.watch(channel.value());
+            f(channel.value_mut());
+            self.0
+                .notify_modified(CacheChange::Channel, channel_id, old, channel.value());
+        }
+
+        Ok(())
+    }
+
+    fn remove_channel(&self, channel_id: Id<ChannelMarker>) -> Result<(), Infallible> {
+        if let Some(channel) = self.0.remove_channel(channel_id) {
+            if let Some(guild_id) = channel.guild_id() {
+                if let Some(mut channels) = self.0.guild_channels.get_mut(&guild_id) {
+                    channels.remove(&channel_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn guild_emoji_ids(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<Id<EmojiMarker>>, Infallible> {
+        Ok(self
+            .0
+            .guild_emojis
+            .get(&guild_id)
+            .map(|emojis| emojis.iter().copied().collect())
+            .unwrap_or_default())
+    }
+
+    fn insert_emoji(
+        &self,
+        guild_id: Id<GuildMarker>,
+        emoji_id: Id<EmojiMarker>,
+        emoji: CacheModels::Emoji,
+    ) -> Result<(), Infallible> {
+        if self
+            .0
+            .emojis
+            .get(&emoji_id)
+            .is_some_and(|cached| cached.value == emoji)
+        {
+            return Ok(());
+        }
+
+        self.0.insert_emoji(guild_id, emoji_id, emoji);
+
+        self.0
+            .guild_emojis
+            .entry(guild_id)
+            .or_default()
+            .insert(emoji_id);
+        self.0.track_emoji(emoji_id);
+
+        Ok(())
+    }
+
+    fn remove_emoji(&self, emoji_id: Id<EmojiMarker>) -> Result<(), Infallible> {
+        if let Some(emoji) = self.0.remove_emoji(emoji_id) {
+            if let Some(mut guild_emojis) = self.0.guild_emojis.get_mut(&emoji.guild_id) {
+                guild_emojis.remove(&emoji_id);
+            }
+        }
+
+        self.0.emoji_limit.remove(&emoji_id);
+
+        Ok(())
+    }
+
+    fn insert_entitlement(
+        &self,
+        entitlement_id: Id<EntitlementMarker>,
+        entitlement: CacheModels::Entitlement,
+    ) -> Result<(), Infallible> {
+        self.0.insert_entitlement(entitlement_id, entitlement);
+
+        Ok(())
+    }
+
+    fn remove_entitlement(&self, entitlement_id: Id<EntitlementMarker>) -> Result<(), Infallible> {
+        self.0.remove_entitlement(entitlement_id);
+
+        Ok(())
+    }
+
+    fn insert_guild(&self, guild: CacheModels::Guild) -> Result<(), Infallible> {
+        let guild_id = crate::CacheableGuild::id(&guild);
+        self.0.unavailable_guilds.remove(&guild_id);
+        let new = self.0.watch(&guild);
+        let old = self.0.guilds.insert(guild_id, guild);
+        self.0
+            .notify_inserted(CacheChange::Guild, guild_id, old, new);
+
+        Ok(())
+    }
+
+    fn modify_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        f: impl FnOnce(&mut CacheModels::Guild),
+    ) -> Result<(), Infallible> {
+        if let Some(mut guild) = self.0.guilds.get_mut(&guild_id) {
+            let old = self.0.watch(guild.value());
+            f(guild.value_mut());
+            self.0
+                .notify_modified(CacheChange::Guild, guild_id, old, guild.value());
+        }
+
+        Ok(())
+    }
+
+    fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<(), Infallible> {
+        self.0.delete_guild(guild_id, false);
+
+        Ok(())
+    }
+
+    fn mark_guild_unavailable(&self, guild_id: Id<GuildMarker>) -> Result<(), Infallible> {
+        self.0.unavailable_guilds.insert(guild_id);
+        self.0.delete_guild(guild_id, true);
+
+        Ok(())
+    }
+
+    fn insert_integration(
+        &self,
+        guild_id: Id<GuildMarker>,
+        integration_id: Id<IntegrationMarker>,
+        integration: CacheModels::GuildIntegration,
+    ) -> Result<(), Infallible> {
+        self.0
+            .guild_integrations
+            .entry(guild_id)
+            .or_default()
+            .insert(integration_id);
+
+        self.0.upsert_guild_resource(
+            CacheChange::Integration,
+            &self.0.integrations,
+            guild_id,
+            (guild_id, integration_id),
+            integration,
+        );
+
+        Ok(())
+    }
+
+    fn remove_integration(
+        &self,
+        guild_id: Id<GuildMarker>,
+        integration_id: Id<IntegrationMarker>,
+    ) -> Result<(), Infallible> {
+        if let Some((key, integration)) = self.0.integrations.remove(&(guild_id, integration_id)) {
+            self.0
+                .notify_removed(CacheChange::Integration, key, &integration);
+
+            if let Some(mut integrations) = self.0.guild_integrations.get_mut(&guild_id) {
+                integrations.remove(&integration_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<CacheModels::Member>, Infallible> {
+        Ok(self
+            .0
+            .members
+            .get(&(guild_id, user_id))
+            .map(|member| member.value().clone()))
+    }
+
+    fn insert_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        member: CacheModels::Member,
+    ) -> Result<(), Infallible> {
+        if self
+            .0
+            .members
+            .get(&(guild_id, user_id))
+            .is_some_and(|cached| *cached == member)
+        {
+            return Ok(());
+        }
+
+        self.0
+            .guild_members
+            .entry(guild_id)
+            .or_default()
+            .insert(user_id);
+
+        self.0.insert_member(guild_id, user_id, member);
+        self.0.track_member(guild_id, user_id);
+
+        Ok(())
+    }
+
+    fn modify_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        f: impl FnOnce(&mut CacheModels::Member),
+    ) -> Result<(), Infallible> {
+        let key = (guild_id, user_id);
+
+        if let Some(mut member) = self.0.members.get_mut(&key) {
+            let old = self.0.watch(member.value());
+            self.0.unindex_member(user_id, &member);
+            f(member.value_mut());
+            self.0.index_member(user_id, &member);
+            self.0
+                .notify_modified(CacheChange::Member, key, old, member.value());
+        }
+
+        Ok(())
+    }
+
+    fn remove_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Infallible> {
+        self.0.remove_member(guild_id, user_id);
+        self.0.untrack_member(guild_id, user_id);
+
+        if let Some(mut members) = self.0.guild_members.get_mut(&guild_id) {
+            members.remove(&user_id);
+        }
+
+        // Avoid a deadlock by mutating the user, dropping the lock to the map,
+        // and then removing the user later if they are in no guilds.
+        let mut remove_user = false;
+
+        if let Some(mut user_guilds) = self.0.user_guilds.get_mut(&user_id) {
+            user_guilds.remove(&guild_id);
+
+            remove_user = user_guilds.is_empty();
+        }
+
+        if remove_user {
+            self.0.remove_user(user_id);
+            self.0.user_limit.remove(&user_id);
+        }
+
+        Ok(())
+    }
+
+    fn insert_message(
+        &self,
+        guild_id: Option<Id<GuildMarker>>,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        message: CacheModels::Message,
+    ) -> Result<(), Infallible> {
+        let mut channel_messages = self.0.channel_messages.entry(channel_id).or_default();
+
+        // If the channel has more messages than the cache size the user has
+        // requested then we pop a message ID out. Once we have the popped ID we
+        // can remove it from the message cache. This prevents the cache from
+        // filling up with old messages that aren't in any channel cache.
+        let message_cache_size = guild_id
+            .and_then(|guild_id| self.0.config.guild_message_cache_size(guild_id))
+            .unwrap_or(self.0.config.message_cache_size());
+
+        if !channel_messages.contains(&message_id) {
+            // Channel messages are ordered from newest to oldest, and message
+            // IDs are snowflakes increasing over time.
+            let index = channel_messages
+                .iter()
+                .position(|id| *id < message_id)
+                .unwrap_or(channel_messages.len());
+
+            if channel_messages.len() >= message_cache_size {
+                // Messages older than all of the channel's cached messages
+                // would be evicted right away.
+                if index == channel_messages.len() {
+                    return Ok(());
+                }
+
+                if let Some(popped_id) = channel_messages.pop_back() {
+                    self.0.remove_message(popped_id);
+                }
+            }
+
+            channel_messages.insert(index, message_id);
+        }
+
+        let new = self.0.watch(&message);
+        let old = self.0.messages.insert(message_id, message);
+        self.0
+            .notify_inserted(CacheChange::Message, message_id, old, new);
+        self.0.track_message(channel_id, message_id);
+
+        Ok(())
+    }
+
+    fn modify_message(
+        &self,
+        message_id: Id<MessageMarker>,
+        f: impl FnOnce(&mut CacheModels::Message),
+    ) -> Result<(), Infallible> {
+        if let Some(mut message) = self.0.messages.get_mut(&message_id) {
+            let old = self.0.watch(message.value());
+            f(message.value_mut());
+            self.0
+                .notify_modified(CacheChange::Message, message_id, old, message.value());
+        }
+
+        Ok(())
+    }
+
+    fn remove_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Result<(), Infallible> {
+        self.0.remove_message(message_id);
+
+        if let Some(mut channel_messages) = self.0.channel_messages.get_mut(&channel_id) {
+            if let Some(idx) = channel_messages.iter().position(|id| *id == message_id) {
+                channel_messages.remove(idx);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn insert_presence(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        presence: CacheModels::Presence,
+    ) -> Result<(), Infallible> {
+        self.0
+            .guild_presences
+            .entry(guild_id)
+            .or_default()
+            .insert(user_id);
+
+        let new = self.0.watch(&presence);
+        let old = self.0.presences.insert((guild_id, user_id), presence);
+        self.0
+            .notify_inserted(CacheChange::Presence, (guild_id, user_id), old, new);
+        self.0.track_presence(guild_id, user_id);
+
+        Ok(())
+    }
+
+    fn insert_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role: CacheModels::Role,
+    ) -> Result<(), Infallible> {
+        let role_id = role.id();
+
+        self.0
+            .guild_roles
+            .entry(guild_id)
+            .or_default()
+            .insert(role_id);
+
+        self.0
+            .upsert_guild_resource(CacheChange::Role, &self.0.roles, guild_id, role_id, role);
+
+        Ok(())
+    }
+
+    fn remove_role(&self, role_id: Id<RoleMarker>) -> Result<(), Infallible> {
+        if let Some((_, role)) = self.0.roles.remove(&role_id) {
+            self.0.notify_removed(CacheChange::Role, role_id, &role);
+
+            if let Some(mut roles) = self.0.guild_roles.get_mut(&role.guild_id) {
+                roles.remove(&role_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn insert_scheduled_event(
+        &self,
+        guild_id: Id<GuildMarker>,
+        event_id: Id<ScheduledEventMarker>,
+        event: CacheModels::GuildScheduledEvent,
+    ) -> Result<(), Infallible> {
+        self.0
+            .guild_scheduled_events
+            .entry(guild_id)
+            .or_default()
+            .insert(event_id);
+
+        self.0.upsert_guild_resource(
+            CacheChange::ScheduledEvent,
+            &self.0.scheduled_events,
+            guild_id,
+            event_id,
+            event,
+        );
+
+        Ok(())
+    }
+
+    fn modify_scheduled_event(
+        &self,
+        event_id: Id<ScheduledEventMarker>,
+        f: impl FnOnce(&mut CacheModels::GuildScheduledEvent),
+    ) -> Result<(), Infallible> {
+        if let Some(mut event) = self.0.scheduled_events.get_mut(&event_id) {
+            let old = self.0.watch(event.value());
+            f(&mut event.value);
+            self.0
+                .notify_modified(CacheChange::ScheduledEvent, event_id, old, event.value());
+        }
+
+        Ok(())
+    }
+
+    fn remove_scheduled_event(
+        &self,
+        guild_id: Id<GuildMarker>,
+        event_id: Id<ScheduledEventMarker>,
+    ) -> Result<(), Infallible> {
+        if let Some((_, event)) = self.0.scheduled_events.remove(&event_id) {
+            self.0
+                .notify_removed(CacheChange::ScheduledEvent, event_id, &event);
+
+            if let Some(mut events) = self.0.guild_scheduled_events.get_mut(&guild_id) {
+                events.remove(&event_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn insert_stage_instance(
+        &self,
+        guild_id: Id<GuildMarker>,
+        stage_id: Id<StageMarker>,
+        stage_instance: CacheModels::StageInstance,
+    ) -> Result<(), Infallible> {
+        self.0
+            .guild_stage_instances
+            .entry(guild_id)
+            .or_default()
+            .insert(stage_id);
+
+        self.0.upsert_guild_resource(
+            CacheChange::StageInstance,
+            &self.0.stage_instances,
+            guild_id,
+            stage_id,
+            stage_instance,
+        );
+
+        Ok(())
+    }
+
+    fn remove_stage_instance(&self, stage_id: Id<StageMarker>) -> Result<(), Infallible> {
+        if let Some((_, data)) = self.0.stage_instances.remove(&stage_id) {
+            self.0
+                .notify_removed(CacheChange::StageInstance, stage_id, &data);
+            let guild_id = data.guild_id;
+
+            if let Some(mut stage_instances) = self.0.guild_stage_instances.get_mut(&guild_id) {
+                stage_instances.remove(&stage_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn guild_sticker_ids(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<Id<StickerMarker>>, Infallible> {
+        Ok(self
+            .0
+            .guild_stickers
+            .get(&guild_id)
+            .map(|stickers| stickers.iter().copied().collect())
+            .unwrap_or_default())
+    }
+
+    fn insert_sticker(
+        &self,
+        guild_id: Id<GuildMarker>,
+        sticker: CacheModels::Sticker,
+    ) -> Result<(), Infallible> {
+        let sticker_id = sticker.id();
+
+        if self
+            .0
+            .stickers
+            .get(&sticker_id)
+            .is_some_and(|cached| cached.value == sticker)
+        {
+            return Ok(());
+        }
+
+        let sticker = GuildResource {
+            guild_id,
+            value: sticker,
+        };
+        let new = self.0.watch(&sticker);
+        let old = self.0.stickers.insert(sticker_id, sticker);
+        self.0
+            .notify_inserted(CacheChange::Sticker, sticker_id, old, new);
+
+        self.0
+            .guild_stickers
+            .entry(guild_id)
+            .or_default()
+            .insert(sticker_id);
+
+        Ok(())
+    }
+
+    fn remove_sticker(&self, sticker_id: Id<StickerMarker>) -> Result<(), Infallible> {
+        if let Some((_, sticker)) = self.0.stickers.remove(&sticker_id) {
+            self.0
+                .notify_removed(CacheChange::Sticker, sticker_id, &sticker);
+
+            if let Some(mut guild_stickers) = self.0.guild_stickers.get_mut(&sticker.guild_id) {
+                guild_stickers.remove(&sticker_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn insert_thread_member(
+        &self,
+        thread_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Infallible> {
+        self.0
+            .thread_members
+            .entry(thread_id)
+            .or_default()
+            .insert(user_id);
+
+        Ok(())
+    }
+
+    fn remove_thread_member(
+        &self,
+        thread_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Infallible> {
+        if let Some(mut members) = self.0.thread_members.get_mut(&thread_id) {
+            members.remove(&user_id);
+        }
+
+        self.0
+            .thread_members
+            .remove_if(&thread_id, |_, members| members.is_empty());
+
+        Ok(())
+    }
+
+    fn insert_user(
+        &self,
+        guild_id: Option<Id<GuildMarker>>,
+        user_id: Id<UserMarker>,
+        user: CacheModels::User,
+    ) -> Result<(), Infallible> {
+        if let Some(cached_user) = self.0.users.get_mut(&user_id) {
+            if *cached_user.value() == user {
+                if let Some(guild_id) = guild_id {
+                    self.0
+                        .user_guilds
+                        .entry(user_id)
+                        .or_default()
+                        .insert(guild_id);
+                }
+
+                drop(cached_user);
+                self.0.user_limit.access(&user_id);
+
+                return Ok(());
+            }
+        }
+
+        let new = self.0.watch(&user);
+        let old = self.0.users.insert(user_id, user);
+        self.0.notify_inserted(CacheChange::User, user_id, old, new);
+
+        if let Some(guild_id) = guild_id {
+            let mut guild_id_set = HashSet::new();
+            guild_id_set.insert(guild_id);
+            self.0.user_guilds.insert(user_id, guild_id_set);
+        }
+
+        self.0.track_user(user_id);
+
+        Ok(())
+    }
+
+    fn insert_voice_state(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        voice_state: CacheModels::VoiceState,
+    ) -> Result<(), Infallible> {
+        self.0.unindex_voice_state(guild_id, user_id);
+        let channel_id = voice_state.channel_id();
+
+        let new = self.0.watch(&voice_state);
+        let old = self.0.voice_states.insert((guild_id, user_id), voice_state);
+        self.0
+            .notify_inserted(CacheChange::VoiceState, (guild_id, user_id), old, new);
+
+        self.0
+            .voice_state_guilds
+            .entry(guild_id)
+            .or_default()
+            .insert(user_id);
+
+        self.0
+            .voice_state_channels
+            .entry(channel_id)
+            .or_default()
+            .insert((guild_id, user_id));
+
+        Ok(())
+    }
+
+    fn remove_voice_state(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), Infallible> {
+        self.0.unindex_voice_state(guild_id, user_id);
+
+        let remove_guild =
+            self.0
+                .voice_state_guilds
+                .get_mut(&guild_id)
+                .is_some_and(|mut guild_users| {
+                    guild_users.remove(&user_id);
+
+                    guild_users.is_empty()
+                });
+
+        if remove_guild {
+            self.0.voice_state_guilds.remove(&guild_id);
+        }
+
+        if let Some((key, voice_state)) = self.0.voice_states.remove(&(guild_id, user_id)) {
+            self.0
+                .notify_removed(CacheChange::VoiceState, key, &voice_state);
+        }
+
+        Ok(())
+    }
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Remove a user's voice state from the voice states of its channel.
+    fn unindex_voice_state(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        let Some(channel_id) = self
+            .voice_states
+            .get(&(guild_id, user_id))
+            .map(|voice_state| voice_state.channel_id())
+        else {
+            return;
+        };
+
+        let remove_channel_mapping = self.voice_state_channels.get_mut(&channel_id).is_some_and(
+            |mut channel_voice_states| {
+                channel_voice_states.remove(&(guild_id, user_id));
+
+                channel_voice_states.is_empty()
+            },
+        );
+
+        if remove_channel_mapping {
+            self.voice_state_channels.remove(&channel_id);
+        }
+    }
+}
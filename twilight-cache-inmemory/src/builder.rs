@@ -1,10 +1,12 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::time::Duration;
+use twilight_model::id::{marker::GuildMarker, Id};
 
 use crate::{CacheableModels, DefaultCacheModels};
 
 use super::{
-    config::{Config, ResourceType},
+    config::{Config, EvictionPolicy, ResourceField, ResourceType},
     InMemoryCache,
 };
 
@@ -38,6 +40,15 @@ impl<CacheModels: CacheableModels> InMemoryCacheBuilder<CacheModels> {
         self
     }
 
+    /// Sets the fields of resources to drop before caching them.
+    ///
+    /// Defaults to no fields.
+    pub const fn dropped_fields(mut self, dropped_fields: ResourceField) -> Self {
+        self.0.dropped_fields = dropped_fields;
+
+        self
+    }
+
     /// Sets the number of messages to cache per channel.
     ///
     /// Defaults to 100.
@@ -46,6 +57,146 @@ impl<CacheModels: CacheableModels> InMemoryCacheBuilder<CacheModels> {
 
         self
     }
+
+    /// Sets the number of messages to cache per channel in a guild,
+    /// overriding the [number of messages] to cache per channel.
+    ///
+    /// [number of messages]: Self::message_cache_size
+    pub fn guild_message_cache_size(
+        mut self,
+        guild_id: Id<GuildMarker>,
+        message_cache_size: usize,
+    ) -> Self {
+        self.0
+            .guild_message_cache_sizes
+            .insert(guild_id, message_cache_size);
+
+        self
+    }
+
+    /// Sets the maximum number of emojis to cache, evicting emojis according
+    /// to the [eviction policy] once reached.
+    ///
+    /// Defaults to unbounded.
+    ///
+    /// [eviction policy]: Self::eviction_policy
+    pub const fn emoji_cache_size(mut self, emoji_cache_size: usize) -> Self {
+        self.0.emoji_cache_size = Some(emoji_cache_size);
+
+        self
+    }
+
+    /// Sets the maximum number of members to cache across all guilds,
+    /// evicting members according to the [eviction policy] once reached.
+    ///
+    /// Defaults to unbounded.
+    ///
+    /// [eviction policy]: Self::eviction_policy
+    pub const fn member_cache_size(mut self, member_cache_size: usize) -> Self {
+        self.0.member_cache_size = Some(member_cache_size);
+
+        self
+    }
+
+    /// Sets the maximum number of members to cache in a guild, evicting
+    /// members of the guild according to the [eviction policy] once reached.
+    ///
+    /// This is in addition to the [maximum number of members] across all
+    /// guilds, so a few large guilds can't evict the members of all others.
+    ///
+    /// Defaults to only being bounded by the maximum number of members across
+    /// all guilds.
+    ///
+    /// [eviction policy]: Self::eviction_policy
+    /// [maximum number of members]: Self::member_cache_size
+    pub fn guild_member_cache_size(
+        mut self,
+        guild_id: Id<GuildMarker>,
+        member_cache_size: usize,
+    ) -> Self {
+        self.0
+            .guild_member_cache_sizes
+            .insert(guild_id, member_cache_size);
+
+        self
+    }
+
+    /// Sets the maximum number of presences to cache across all guilds,
+    /// evicting presences according to the [eviction policy] once reached.
+    ///
+    /// Defaults to unbounded.
+    ///
+    /// [eviction policy]: Self::eviction_policy
+    pub const fn presence_cache_size(mut self, presence_cache_size: usize) -> Self {
+        self.0.presence_cache_size = Some(presence_cache_size);
+
+        self
+    }
+
+    /// Sets the maximum number of users to cache, evicting users according to
+    /// the [eviction policy] once reached.
+    ///
+    /// Defaults to unbounded.
+    ///
+    /// [eviction policy]: Self::eviction_policy
+    pub const fn user_cache_size(mut self, user_cache_size: usize) -> Self {
+        self.0.user_cache_size = Some(user_cache_size);
+
+        self
+    }
+
+    /// Sets the duration after which members expire from the cache, counted
+    /// from their last update.
+    ///
+    /// Defaults to never expiring.
+    pub const fn member_ttl(mut self, member_ttl: Duration) -> Self {
+        self.0.member_ttl = Some(member_ttl);
+
+        self
+    }
+
+    /// Sets the duration after which messages expire from the cache, counted
+    /// from their last update.
+    ///
+    /// Defaults to never expiring.
+    pub const fn message_ttl(mut self, message_ttl: Duration) -> Self {
+        self.0.message_ttl = Some(message_ttl);
+
+        self
+    }
+
+    /// Sets the duration after which presences expire from the cache, counted
+    /// from their last update.
+    ///
+    /// Defaults to never expiring.
+    pub const fn presence_ttl(mut self, presence_ttl: Duration) -> Self {
+        self.0.presence_ttl = Some(presence_ttl);
+
+        self
+    }
+
+    /// Sets the duration after which users expire from the cache, counted
+    /// from their last update.
+    ///
+    /// Defaults to never expiring.
+    pub const fn user_ttl(mut self, user_ttl: Duration) -> Self {
+        self.0.user_ttl = Some(user_ttl);
+
+        self
+    }
+
+    /// Sets the policy for choosing which entry to evict once a resource type
+    /// reaches its maximum number of entries.
+    ///
+    /// Both reading an entry via the cache's getters and updating it count as
+    /// a use.
+    ///
+    /// Defaults to [`EvictionPolicy::Lru`].
+    pub const fn eviction_policy(mut self, eviction_policy: EvictionPolicy) -> Self {
+        self.0.eviction_policy = eviction_policy;
+
+        self
+    }
 }
 
 impl<CacheModels: CacheableModels> Default for InMemoryCacheBuilder<CacheModels> {
@@ -0,0 +1,554 @@
+//! Subscribing to changes of cached resources.
+//!
+//! Subscribers receive a [`CacheChange`] for every cached channel, emoji,
+//! entitlement, guild, integration, member, message, presence, role,
+//! scheduled event, stage instance, sticker, user, or voice state that is
+//! inserted, updated, or removed while processing events. This allows
+//! mirroring the cache in other systems, such as search indexes, without
+//! processing gateway events again.
+//!
+//! Changes are only cloned into notifications while there are subscribers, so
+//! caches without subscribers are unaffected.
+
+use crate::{CacheableModels, GuildResource, InMemoryCache};
+use dashmap::DashMap;
+use std::{
+    hash::Hash,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use twilight_model::id::{
+    marker::{
+        ChannelMarker, EmojiMarker, EntitlementMarker, GuildMarker, IntegrationMarker,
+        MessageMarker, RoleMarker, ScheduledEventMarker, StageMarker, StickerMarker, UserMarker,
+    },
+    Id,
+};
+
+/// Change of a cached resource.
+///
+/// An insertion has no [`previous`] value and a removal has no [`current`]
+/// value.
+///
+/// [`current`]: Self::current
+/// [`previous`]: Self::previous
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change<K, V> {
+    current: Option<V>,
+    id: K,
+    previous: Option<V>,
+}
+
+impl<K, V> Change<K, V> {
+    /// ID of the changed resource.
+    pub const fn id(&self) -> &K {
+        &self.id
+    }
+
+    /// Value of the resource after the change, if it is still cached.
+    pub const fn current(&self) -> Option<&V> {
+        self.current.as_ref()
+    }
+
+    /// Value of the resource before the change, if it was cached.
+    pub const fn previous(&self) -> Option<&V> {
+        self.previous.as_ref()
+    }
+
+    /// Consume the change, returning its ID, previous value, and current
+    /// value.
+    pub fn into_parts(self) -> (K, Option<V>, Option<V>) {
+        (self.id, self.previous, self.current)
+    }
+}
+
+/// Change of a cached resource, by resource type.
+#[allow(clippy::type_complexity)]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CacheChange<CacheModels: CacheableModels> {
+    /// Change of a channel.
+    Channel(Change<Id<ChannelMarker>, CacheModels::Channel>),
+    /// Change of an emoji.
+    Emoji(Change<Id<EmojiMarker>, GuildResource<CacheModels::Emoji>>),
+    /// Change of an entitlement.
+    Entitlement(Change<Id<EntitlementMarker>, CacheModels::Entitlement>),
+    /// Change of a guild.
+    Guild(Change<Id<GuildMarker>, CacheModels::Guild>),
+    /// Change of an integration, by guild and integration ID.
+    Integration(
+        Change<
+            (Id<GuildMarker>, Id<IntegrationMarker>),
+            GuildResource<CacheModels::GuildIntegration>,
+        >,
+    ),
+    /// Change of a member, by guild and user ID.
+    Member(Change<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Member>),
+    /// Change of a message.
+    Message(Change<Id<MessageMarker>, CacheModels::Message>),
+    /// Change of a presence, by guild and user ID.
+    Presence(Change<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Presence>),
+    /// Change of a role.
+    Role(Change<Id<RoleMarker>, GuildResource<CacheModels::Role>>),
+    /// Change of a scheduled event.
+    ScheduledEvent(
+        Change<Id<ScheduledEventMarker>, GuildResource<CacheModels::GuildScheduledEvent>>,
+    ),
+    /// Change of a stage instance.
+    StageInstance(Change<Id<StageMarker>, GuildResource<CacheModels::StageInstance>>),
+    /// Change of a sticker.
+    Sticker(Change<Id<StickerMarker>, GuildResource<CacheModels::Sticker>>),
+    /// Change of a user.
+    User(Change<Id<UserMarker>, CacheModels::User>),
+    /// Change of a voice state, by guild and user ID.
+    VoiceState(Change<(Id<GuildMarker>, Id<UserMarker>), CacheModels::VoiceState>),
+}
+
+/// Receiver of the changes of an [`InMemoryCache`], with its own bounded
+/// buffer.
+///
+/// Changes are sent without waiting for the subscription, so a slow
+/// subscription never delays processing events. A subscription whose buffer
+/// is full misses the change instead, which is counted by [`lagged`].
+///
+/// Dropping the subscription unsubscribes it.
+///
+/// May be obtained via [`InMemoryCache::subscribe`].
+///
+/// [`lagged`]: Self::lagged
+#[derive(Debug)]
+pub struct Subscription<CacheModels: CacheableModels> {
+    /// Number of changes the subscription missed.
+    lagged: Arc<AtomicU64>,
+    /// Receiving half of the bounded channel.
+    rx: mpsc::Receiver<CacheChange<CacheModels>>,
+}
+
+impl<CacheModels: CacheableModels> Subscription<CacheModels> {
+    /// Number of changes missed because the subscription's buffer was full.
+    pub fn lagged(&self) -> u64 {
+        self.lagged.load(Ordering::Relaxed)
+    }
+
+    /// Receive the next change, waiting until one is sent.
+    ///
+    /// Returns `None` once the cache is dropped and all buffered changes
+    /// were received.
+    pub async fn recv(&mut self) -> Option<CacheChange<CacheModels>> {
+        self.rx.recv().await
+    }
+
+    /// Receive the next change, blocking the current thread until one is
+    /// sent.
+    ///
+    /// Returns `None` once the cache is dropped and all buffered changes
+    /// were received.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called within an asynchronous execution context.
+    pub fn blocking_recv(&mut self) -> Option<CacheChange<CacheModels>> {
+        self.rx.blocking_recv()
+    }
+
+    /// Receive the next buffered change, if any.
+    pub fn try_recv(&mut self) -> Option<CacheChange<CacheModels>> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// Sender of notifications to a subscriber.
+#[derive(Debug)]
+struct Subscriber<T> {
+    /// Number of notifications the subscriber missed.
+    lagged: Arc<AtomicU64>,
+    /// Sending half of the subscriber's bounded channel.
+    tx: mpsc::Sender<T>,
+}
+
+/// Senders of notifications to subscribers.
+#[derive(Debug)]
+pub(crate) struct Subscribers<T> {
+    /// Whether there may be subscribers, to avoid locking the senders.
+    active: AtomicBool,
+    /// Senders of the subscribers.
+    senders: Mutex<Vec<Subscriber<T>>>,
+}
+
+impl<T: Clone> Subscribers<T> {
+    /// Create a new set of subscribers without any subscriber.
+    pub const fn new() -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            senders: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Whether there may be subscribers.
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Add a subscriber buffering up to `capacity` notifications, returning
+    /// its counter of missed notifications and the receiver of its
+    /// notifications.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn subscribe(&self, capacity: usize) -> (Arc<AtomicU64>, mpsc::Receiver<T>) {
+        let (tx, rx) = mpsc::channel(capacity);
+        let lagged = Arc::new(AtomicU64::new(0));
+        let mut senders = self.senders.lock().expect("subscribers poisoned");
+        senders.push(Subscriber {
+            lagged: Arc::clone(&lagged),
+            tx,
+        });
+        self.active.store(true, Ordering::Relaxed);
+
+        (lagged, rx)
+    }
+
+    /// Send a notification to every subscriber, removing those whose receiver
+    /// was dropped.
+    ///
+    /// Subscribers whose buffer is full miss the notification.
+    pub fn send(&self, notification: &T) {
+        let mut senders = self.senders.lock().expect("subscribers poisoned");
+        senders.retain(
+            |subscriber| match subscriber.tx.try_send(notification.clone()) {
+                Ok(()) => true,
+                Err(TrySendError::Full(_)) => {
+                    subscriber.lagged.fetch_add(1, Ordering::Relaxed);
+
+                    true
+                }
+                Err(TrySendError::Closed(_)) => false,
+            },
+        );
+        self.active.store(!senders.is_empty(), Ordering::Relaxed);
+    }
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Subscribe to changes of cached resources, buffering up to `capacity`
+    /// changes.
+    ///
+    /// The returned subscription is sent a [`CacheChange`] whenever a cached
+    /// resource is inserted, updated, or removed. Updates that leave a
+    /// resource unchanged are not sent. Clearing the cache or restoring a
+    /// snapshot of it does not send any change.
+    ///
+    /// Changes are never waited on, so a subscription whose buffer is full
+    /// misses them instead, refer to [`Subscription::lagged`]. Size the buffer
+    /// to absorb bursts, such as caching guilds after identifying.
+    ///
+    /// # Examples
+    ///
+    /// Print the IDs of guilds as they are cached:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() {
+    /// use twilight_cache_inmemory::{change::CacheChange, DefaultInMemoryCache};
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    /// let mut changes = cache.subscribe(256);
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Some(change) = changes.recv().await {
+    ///         if let CacheChange::Guild(change) = change {
+    ///             println!("guild {} changed", change.id());
+    ///         }
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn subscribe(&self, capacity: usize) -> Subscription<CacheModels> {
+        let (lagged, rx) = self.subscribers.subscribe(capacity);
+
+        Subscription { lagged, rx }
+    }
+
+    /// Clone a value about to change if there are subscribers.
+    pub(crate) fn watch<V: Clone>(&self, value: &V) -> Option<V> {
+        self.subscribers.is_active().then(|| value.clone())
+    }
+
+    /// Notify subscribers of an inserted value, watched before insertion,
+    /// replacing the old value, if any.
+    pub(crate) fn notify_inserted<K, V: PartialEq>(
+        &self,
+        kind: fn(Change<K, V>) -> CacheChange<CacheModels>,
+        id: K,
+        old: Option<V>,
+        new: Option<V>,
+    ) {
+        if new.is_some() {
+            self.notify(kind, id, old, new);
+        }
+    }
+
+    /// Notify subscribers of a modified value, watched before modification.
+    pub(crate) fn notify_modified<K, V: Clone + PartialEq>(
+        &self,
+        kind: fn(Change<K, V>) -> CacheChange<CacheModels>,
+        id: K,
+        old: Option<V>,
+        new: &V,
+    ) {
+        if old.is_some() {
+            self.notify(kind, id, old, Some(new.clone()));
+        }
+    }
+
+    /// Notify subscribers of a removed value.
+    pub(crate) fn notify_removed<K, V: Clone + PartialEq>(
+        &self,
+        kind: fn(Change<K, V>) -> CacheChange<CacheModels>,
+        id: K,
+        old: &V,
+    ) {
+        if self.subscribers.is_active() {
+            self.notify(kind, id, Some(old.clone()), None);
+        }
+    }
+
+    /// Insert or replace a guild resource unless unchanged, notifying
+    /// subscribers.
+    pub(crate) fn upsert_guild_resource<K: Copy + Eq + Hash, V: Clone + PartialEq>(
+        &self,
+        kind: fn(Change<K, GuildResource<V>>) -> CacheChange<CacheModels>,
+        map: &DashMap<K, GuildResource<V>>,
+        guild_id: Id<GuildMarker>,
+        key: K,
+        value: V,
+    ) {
+        let new = self
+            .watch(&value)
+            .map(|value| GuildResource { guild_id, value });
+        let old = new
+            .as_ref()
+            .and_then(|_| map.get(&key).map(|resource| resource.value().clone()));
+
+        crate::upsert_guild_item(map, guild_id, key, value);
+        self.notify_inserted(kind, key, old, new);
+    }
+
+    /// Notify subscribers of a change, unless the value is unchanged.
+    fn notify<K, V: PartialEq>(
+        &self,
+        kind: fn(Change<K, V>) -> CacheChange<CacheModels>,
+        id: K,
+        old: Option<V>,
+        new: Option<V>,
+    ) {
+        if old == new {
+            return;
+        }
+
+        self.subscribers.send(&kind(Change {
+            current: new,
+            id,
+            previous: old,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheChange, Change, Subscribers, Subscription};
+    use crate::{test, DefaultCacheModels, DefaultInMemoryCache, ResourceType};
+    use static_assertions::assert_impl_all;
+    use std::{borrow::Cow, fmt::Debug, sync::atomic::Ordering};
+    use twilight_model::{
+        gateway::payload::incoming::{
+            ChannelDelete, GuildScheduledEventCreate, GuildScheduledEventDelete, MessageCreate,
+            MessageDelete, MessageUpdate, VoiceStateUpdate,
+        },
+        id::Id,
+    };
+
+    assert_impl_all!(Change<u64, u64>: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Subscribers<u64>: Debug, Send, Sync);
+    assert_impl_all!(Subscription<DefaultCacheModels>: Debug, Send, Sync);
+
+    #[test]
+    fn unsubscribe() {
+        let subscribers = Subscribers::new();
+        assert!(!subscribers.is_active());
+
+        let (_, mut receiver) = subscribers.subscribe(1);
+        subscribers.send(&1);
+        assert!(subscribers.is_active());
+        assert_eq!(Ok(1), receiver.try_recv());
+
+        drop(receiver);
+        subscribers.send(&2);
+        assert!(!subscribers.is_active());
+    }
+
+    #[test]
+    fn lagged() {
+        let subscribers = Subscribers::new();
+        let (slow_lagged, mut slow) = subscribers.subscribe(1);
+        let (fast_lagged, mut fast) = subscribers.subscribe(2);
+
+        subscribers.send(&1);
+        subscribers.send(&2);
+        assert_eq!(1, slow_lagged.load(Ordering::Relaxed));
+        assert_eq!(0, fast_lagged.load(Ordering::Relaxed));
+        assert!(subscribers.is_active());
+
+        assert_eq!(Ok(1), slow.try_recv());
+        assert!(slow.try_recv().is_err());
+        assert_eq!(Ok(1), fast.try_recv());
+        assert_eq!(Ok(2), fast.try_recv());
+    }
+
+    #[test]
+    fn channel_changes() {
+        let cache = DefaultInMemoryCache::new();
+        let (_, channel_id, mut channel) = test::guild_channel_text();
+        let mut changes = cache.subscribe(8);
+
+        cache.cache_channel(channel.clone());
+        cache.cache_channel(channel.clone());
+        channel.name = Some("renamed".to_owned());
+        cache.cache_channel(channel.clone());
+        cache.update(&ChannelDelete(channel.clone()));
+
+        let changes = std::iter::from_fn(|| changes.try_recv())
+            .map(|change| match change {
+                CacheChange::Channel(change) => change.into_parts(),
+                other => panic!("unexpected change {other:?}"),
+            })
+            .map(|(id, old, new)| {
+                assert_eq!(channel_id, id);
+
+                (
+                    old.and_then(|channel| channel.name),
+                    new.and_then(|channel| channel.name),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (None, Some("test".to_owned())),
+                (Some("test".to_owned()), Some("renamed".to_owned())),
+                (Some("renamed".to_owned()), None),
+            ],
+            changes
+        );
+    }
+
+    #[test]
+    fn message_and_user_changes() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::MESSAGE | ResourceType::USER)
+            .build();
+        let message = test::message();
+        cache.cache_user(Cow::Borrowed(&message.author), None);
+        let mut changes = cache.subscribe(8);
+
+        cache.update(&MessageCreate(message.clone()));
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::Message(change)) if change.previous().is_none() && *change.id() == message.id
+        ));
+
+        cache.update(&MessageUpdate {
+            attachments: None,
+            author: None,
+            channel_id: message.channel_id,
+            content: Some("edited".to_owned()),
+            edited_timestamp: None,
+            embeds: None,
+            guild_id: message.guild_id,
+            id: message.id,
+            kind: None,
+            mention_everyone: None,
+            mention_roles: None,
+            mentions: None,
+            pinned: None,
+            timestamp: None,
+            tts: None,
+        });
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::Message(change))
+                if change.current().is_some_and(|message| message.content() == "edited")
+        ));
+
+        cache.update(&MessageDelete {
+            channel_id: message.channel_id,
+            guild_id: message.guild_id,
+            id: message.id,
+        });
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::Message(change)) if change.current().is_none()
+        ));
+        assert!(changes.try_recv().is_none());
+
+        let mut user = message.author;
+        user.name = "renamed".to_owned();
+        cache.cache_user(Cow::Owned(user), None);
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::User(change))
+                if change.previous().is_some_and(|user| user.name != "renamed")
+        ));
+    }
+
+    #[test]
+    fn scheduled_event_and_voice_state_changes() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let event = test::guild_scheduled_event(Id::new(2), guild_id, None);
+        let mut changes = cache.subscribe(8);
+
+        cache.update(&GuildScheduledEventCreate(event.clone()));
+        cache.update(&GuildScheduledEventCreate(event.clone()));
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::ScheduledEvent(change))
+                if change.previous().is_none()
+                    && change.current().is_some_and(|current| current.guild_id() == guild_id)
+        ));
+        assert!(changes.try_recv().is_none());
+
+        cache.update(&GuildScheduledEventDelete(event.clone()));
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::ScheduledEvent(change))
+                if *change.id() == event.id && change.current().is_none()
+        ));
+
+        let user_id = Id::new(3);
+        let voice_state = test::voice_state(guild_id, Some(Id::new(4)), user_id);
+        cache.update(&VoiceStateUpdate(voice_state.clone()));
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::VoiceState(change))
+                if *change.id() == (guild_id, user_id) && change.previous().is_none()
+        ));
+
+        cache.update(&VoiceStateUpdate(test::voice_state(
+            guild_id, None, user_id,
+        )));
+        assert!(matches!(
+            changes.try_recv(),
+            Some(CacheChange::VoiceState(change))
+                if change.previous().is_some() && change.current().is_none()
+        ));
+        assert!(changes.try_recv().is_none());
+    }
+}
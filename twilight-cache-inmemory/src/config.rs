@@ -1,4 +1,6 @@
 use bitflags::bitflags;
+use std::{collections::BTreeMap, time::Duration};
+use twilight_model::id::{marker::GuildMarker, Id};
 
 bitflags! {
     /// A set of bitflags which can be used to specify what resource to process
@@ -39,16 +41,73 @@ bitflags! {
         const STICKER = 1 << 13;
         /// Information relating to guild scheduled events.
         const GUILD_SCHEDULED_EVENT = 1 << 14;
+        /// Information relating to guild auto moderation rules.
+        const AUTO_MODERATION_RULE = 1 << 15;
+        /// Information relating to entitlements.
+        const ENTITLEMENT = 1 << 16;
+        /// Information relating to guild bans.
+        const BAN = 1 << 17;
     }
 }
 
+bitflags! {
+    /// A set of bitflags which can be used to specify what fields of resources
+    /// to drop before caching them.
+    ///
+    /// Dropping heavy fields reduces memory usage without disabling the
+    /// resource types containing them. Dropped fields are empty in the cache.
+    ///
+    /// For example, specifying [`MESSAGE_EMBEDS`] will cache messages without
+    /// their embeds.
+    ///
+    /// [`MESSAGE_EMBEDS`]: Self::MESSAGE_EMBEDS
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct ResourceField: u64 {
+        /// Guild avatars of members.
+        const MEMBER_AVATAR = 1;
+        /// Components of messages.
+        const MESSAGE_COMPONENTS = 1 << 1;
+        /// Embeds of messages.
+        const MESSAGE_EMBEDS = 1 << 2;
+        /// Activities of presences.
+        const PRESENCE_ACTIVITIES = 1 << 3;
+        /// Assets of the activities of presences.
+        const PRESENCE_ACTIVITY_ASSETS = 1 << 4;
+    }
+}
+
+/// Strategy for choosing which entry to evict once a resource type reaches its
+/// maximum number of entries.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EvictionPolicy {
+    /// Evict the least frequently used entry, breaking ties by evicting the
+    /// least recently used one.
+    Lfu,
+    /// Evict the least recently used entry.
+    #[default]
+    Lru,
+}
+
 /// Configuration for an [`InMemoryCache`].
 ///
 /// [`InMemoryCache`]: crate::InMemoryCache
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
     pub(super) resource_types: ResourceType,
+    pub(super) dropped_fields: ResourceField,
     pub(super) message_cache_size: usize,
+    pub(super) emoji_cache_size: Option<usize>,
+    pub(super) member_cache_size: Option<usize>,
+    pub(super) guild_member_cache_sizes: BTreeMap<Id<GuildMarker>, usize>,
+    pub(super) guild_message_cache_sizes: BTreeMap<Id<GuildMarker>, usize>,
+    pub(super) presence_cache_size: Option<usize>,
+    pub(super) user_cache_size: Option<usize>,
+    pub(super) eviction_policy: EvictionPolicy,
+    pub(super) member_ttl: Option<Duration>,
+    pub(super) message_ttl: Option<Duration>,
+    pub(super) presence_ttl: Option<Duration>,
+    pub(super) user_ttl: Option<Duration>,
 }
 
 impl Config {
@@ -58,10 +117,104 @@ impl Config {
     pub const fn new() -> Self {
         Self {
             resource_types: ResourceType::all(),
+            dropped_fields: ResourceField::empty(),
             message_cache_size: 100,
+            emoji_cache_size: None,
+            member_cache_size: None,
+            guild_member_cache_sizes: BTreeMap::new(),
+            guild_message_cache_sizes: BTreeMap::new(),
+            presence_cache_size: None,
+            user_cache_size: None,
+            eviction_policy: EvictionPolicy::Lru,
+            member_ttl: None,
+            message_ttl: None,
+            presence_ttl: None,
+            user_ttl: None,
         }
     }
 
+    /// Returns the fields of resources dropped before caching them.
+    ///
+    /// Defaults to no fields.
+    pub const fn dropped_fields(&self) -> ResourceField {
+        self.dropped_fields
+    }
+
+    /// Returns a mutable reference to the fields of resources dropped before
+    /// caching them.
+    pub fn dropped_fields_mut(&mut self) -> &mut ResourceField {
+        &mut self.dropped_fields
+    }
+
+    /// Returns the maximum number of emojis to cache.
+    ///
+    /// Defaults to [`None`], meaning unbounded.
+    pub const fn emoji_cache_size(&self) -> Option<usize> {
+        self.emoji_cache_size
+    }
+
+    /// Returns a mutable reference to the maximum number of emojis to cache.
+    pub fn emoji_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.emoji_cache_size
+    }
+
+    /// Returns the maximum number of members to cache in a guild.
+    ///
+    /// Defaults to [`None`], meaning only bounded by the
+    /// [maximum number of members] across all guilds.
+    ///
+    /// [maximum number of members]: Self::member_cache_size
+    pub fn guild_member_cache_size(&self, guild_id: Id<GuildMarker>) -> Option<usize> {
+        self.guild_member_cache_sizes.get(&guild_id).copied()
+    }
+
+    /// Returns a mutable reference to the maximum numbers of members to cache
+    /// in guilds, by guild ID.
+    pub fn guild_member_cache_sizes_mut(&mut self) -> &mut BTreeMap<Id<GuildMarker>, usize> {
+        &mut self.guild_member_cache_sizes
+    }
+
+    /// Returns the number of messages to cache per channel in a guild.
+    ///
+    /// Defaults to [`None`], meaning the [number of messages] to cache per
+    /// channel applies.
+    ///
+    /// [number of messages]: Self::message_cache_size
+    pub fn guild_message_cache_size(&self, guild_id: Id<GuildMarker>) -> Option<usize> {
+        self.guild_message_cache_sizes.get(&guild_id).copied()
+    }
+
+    /// Returns a mutable reference to the numbers of messages to cache per
+    /// channel in guilds, by guild ID.
+    pub fn guild_message_cache_sizes_mut(&mut self) -> &mut BTreeMap<Id<GuildMarker>, usize> {
+        &mut self.guild_message_cache_sizes
+    }
+
+    /// Returns the policy for evicting entries of resource types at their
+    /// maximum number of entries.
+    ///
+    /// Defaults to [`EvictionPolicy::Lru`].
+    pub const fn eviction_policy(&self) -> EvictionPolicy {
+        self.eviction_policy
+    }
+
+    /// Returns a mutable reference to the eviction policy.
+    pub fn eviction_policy_mut(&mut self) -> &mut EvictionPolicy {
+        &mut self.eviction_policy
+    }
+
+    /// Returns the maximum number of members to cache across all guilds.
+    ///
+    /// Defaults to [`None`], meaning unbounded.
+    pub const fn member_cache_size(&self) -> Option<usize> {
+        self.member_cache_size
+    }
+
+    /// Returns a mutable reference to the maximum number of members to cache.
+    pub fn member_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.member_cache_size
+    }
+
     /// Returns an immutable reference to the message cache size.
     ///
     /// Defaults to 100.
@@ -73,6 +226,20 @@ impl Config {
     pub fn message_cache_size_mut(&mut self) -> &mut usize {
         &mut self.message_cache_size
     }
+
+    /// Returns the maximum number of presences to cache across all guilds.
+    ///
+    /// Defaults to [`None`], meaning unbounded.
+    pub const fn presence_cache_size(&self) -> Option<usize> {
+        self.presence_cache_size
+    }
+
+    /// Returns a mutable reference to the maximum number of presences to
+    /// cache.
+    pub fn presence_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.presence_cache_size
+    }
+
     /// Returns an immutable reference to the resource types enabled.
     ///
     /// Defaults to all resource types.
@@ -84,6 +251,70 @@ impl Config {
     pub fn resource_types_mut(&mut self) -> &mut ResourceType {
         &mut self.resource_types
     }
+
+    /// Returns the maximum number of users to cache.
+    ///
+    /// Defaults to [`None`], meaning unbounded.
+    pub const fn user_cache_size(&self) -> Option<usize> {
+        self.user_cache_size
+    }
+
+    /// Returns a mutable reference to the maximum number of users to cache.
+    pub fn user_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.user_cache_size
+    }
+
+    /// Returns the duration after which members expire from the cache,
+    /// counted from their last update.
+    ///
+    /// Defaults to [`None`], meaning members never expire.
+    pub const fn member_ttl(&self) -> Option<Duration> {
+        self.member_ttl
+    }
+
+    /// Returns a mutable reference to the time-to-live of members.
+    pub fn member_ttl_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.member_ttl
+    }
+
+    /// Returns the duration after which messages expire from the cache,
+    /// counted from their last update.
+    ///
+    /// Defaults to [`None`], meaning messages never expire.
+    pub const fn message_ttl(&self) -> Option<Duration> {
+        self.message_ttl
+    }
+
+    /// Returns a mutable reference to the time-to-live of messages.
+    pub fn message_ttl_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.message_ttl
+    }
+
+    /// Returns the duration after which presences expire from the cache,
+    /// counted from their last update.
+    ///
+    /// Defaults to [`None`], meaning presences never expire.
+    pub const fn presence_ttl(&self) -> Option<Duration> {
+        self.presence_ttl
+    }
+
+    /// Returns a mutable reference to the time-to-live of presences.
+    pub fn presence_ttl_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.presence_ttl
+    }
+
+    /// Returns the duration after which users expire from the cache,
+    /// counted from their last update.
+    ///
+    /// Defaults to [`None`], meaning users never expire.
+    pub const fn user_ttl(&self) -> Option<Duration> {
+        self.user_ttl
+    }
+
+    /// Returns a mutable reference to the time-to-live of users.
+    pub fn user_ttl_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.user_ttl
+    }
 }
 
 impl Default for Config {
@@ -94,19 +325,70 @@ impl Default for Config {
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, ResourceType};
-    use static_assertions::assert_fields;
+    use super::{Config, EvictionPolicy, ResourceField, ResourceType};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::BTreeMap, fmt::Debug, hash::Hash};
 
-    assert_fields!(Config: resource_types, message_cache_size);
+    assert_fields!(
+        Config: resource_types,
+        dropped_fields,
+        message_cache_size,
+        emoji_cache_size,
+        member_cache_size,
+        guild_member_cache_sizes,
+        guild_message_cache_sizes,
+        presence_cache_size,
+        user_cache_size,
+        eviction_policy,
+        member_ttl,
+        message_ttl,
+        presence_ttl,
+        user_ttl
+    );
+    assert_impl_all!(
+        EvictionPolicy: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
 
     #[test]
     fn defaults() {
         let conf = Config {
             resource_types: ResourceType::all(),
+            dropped_fields: ResourceField::empty(),
             message_cache_size: 100,
+            emoji_cache_size: None,
+            member_cache_size: None,
+            guild_member_cache_sizes: BTreeMap::new(),
+            guild_message_cache_sizes: BTreeMap::new(),
+            presence_cache_size: None,
+            user_cache_size: None,
+            eviction_policy: EvictionPolicy::Lru,
+            member_ttl: None,
+            message_ttl: None,
+            presence_ttl: None,
+            user_ttl: None,
         };
         let default = Config::default();
         assert_eq!(conf.resource_types, default.resource_types);
+        assert_eq!(conf.dropped_fields, default.dropped_fields);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.member_cache_size, default.member_cache_size);
+        assert_eq!(
+            conf.guild_member_cache_sizes,
+            default.guild_member_cache_sizes
+        );
+        assert_eq!(
+            conf.guild_message_cache_sizes,
+            default.guild_message_cache_sizes
+        );
+        assert_eq!(conf.eviction_policy, default.eviction_policy);
+        assert_eq!(conf.message_ttl, default.message_ttl);
     }
 }
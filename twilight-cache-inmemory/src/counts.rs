@@ -0,0 +1,317 @@
+//! Maintaining counts of the entities of guilds from events.
+
+use crate::{CacheableModels, InMemoryCache};
+use std::collections::HashSet;
+use twilight_model::{
+    channel::Channel,
+    gateway::presence::Status,
+    guild::Guild,
+    id::{
+        marker::{ChannelMarker, GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Counts of the entities of a guild.
+///
+/// Counts start out from the guild's [`GuildCreate`] event and are then
+/// maintained by applying events adding and removing entities, independently
+/// of which entities themselves are cached. As such they are approximate:
+/// events missed while a shard was disconnected aren't accounted for until the
+/// guild is received again.
+///
+/// Retrieved via [`InMemoryCache::guild_counts`].
+///
+/// [`GuildCreate`]: twilight_model::gateway::payload::incoming::GuildCreate
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GuildCounts {
+    channels: usize,
+    members: u64,
+    online_presences: usize,
+    threads: usize,
+}
+
+impl GuildCounts {
+    /// Number of channels, excluding threads.
+    pub const fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Number of members.
+    pub const fn members(&self) -> u64 {
+        self.members
+    }
+
+    /// Number of members whose presence isn't offline.
+    ///
+    /// Requires the [`GUILD_PRESENCES`] intent. Only online members of large
+    /// guilds are included in their [`GuildCreate`] event, so this also
+    /// counts members coming online afterwards.
+    ///
+    /// [`GUILD_PRESENCES`]: twilight_model::gateway::Intents::GUILD_PRESENCES
+    /// [`GuildCreate`]: twilight_model::gateway::payload::incoming::GuildCreate
+    pub const fn online_presences(&self) -> usize {
+        self.online_presences
+    }
+
+    /// Number of active threads.
+    pub const fn threads(&self) -> usize {
+        self.threads
+    }
+}
+
+/// Entities of a guild counted by [`GuildCounts`].
+#[derive(Debug)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize, serde::Serialize))]
+pub(crate) struct GuildCounter {
+    channels: HashSet<Id<ChannelMarker>>,
+    members: u64,
+    online: HashSet<Id<UserMarker>>,
+    threads: HashSet<Id<ChannelMarker>>,
+}
+
+impl GuildCounter {
+    /// Create a counter from the entities of a guild.
+    fn new(guild: &Guild) -> Self {
+        Self {
+            channels: guild.channels.iter().map(|channel| channel.id).collect(),
+            members: guild.member_count.unwrap_or(guild.members.len() as u64),
+            online: guild
+                .presences
+                .iter()
+                .filter(|presence| presence.status != Status::Offline)
+                .map(|presence| presence.user.id())
+                .collect(),
+            threads: guild
+                .threads
+                .iter()
+                .filter(|thread| !is_archived(thread))
+                .map(|thread| thread.id)
+                .collect(),
+        }
+    }
+
+    /// Count a created or updated channel or thread.
+    pub fn channel(&mut self, channel: &Channel) {
+        if !channel.kind.is_thread() {
+            self.channels.insert(channel.id);
+        } else if is_archived(channel) {
+            self.threads.remove(&channel.id);
+        } else {
+            self.threads.insert(channel.id);
+        }
+    }
+
+    /// Uncount a deleted channel or thread.
+    pub fn remove_channel(&mut self, channel_id: Id<ChannelMarker>) {
+        self.channels.remove(&channel_id);
+        self.threads.remove(&channel_id);
+    }
+
+    /// Count a member joining.
+    pub fn add_member(&mut self) {
+        self.members = self.members.saturating_add(1);
+    }
+
+    /// Uncount a member leaving.
+    pub fn remove_member(&mut self, user_id: Id<UserMarker>) {
+        self.members = self.members.saturating_sub(1);
+        self.online.remove(&user_id);
+    }
+
+    /// Count a member's updated presence.
+    pub fn presence(&mut self, user_id: Id<UserMarker>, status: Status) {
+        if status == Status::Offline {
+            self.online.remove(&user_id);
+        } else {
+            self.online.insert(user_id);
+        }
+    }
+
+    /// Replace the active threads with synced threads.
+    ///
+    /// If only the threads of some channels were synced, the synced threads
+    /// are added to the active threads, as the parents of counted threads
+    /// aren't known.
+    pub fn sync_threads(&mut self, threads: &[Channel], complete: bool) {
+        if complete {
+            self.threads.clear();
+        }
+
+        for thread in threads {
+            self.channel(thread);
+        }
+    }
+
+    /// Copy the current counts.
+    fn counts(&self) -> GuildCounts {
+        GuildCounts {
+            channels: self.channels.len(),
+            members: self.members,
+            online_presences: self.online.len(),
+            threads: self.threads.len(),
+        }
+    }
+}
+
+/// Whether a thread is archived.
+fn is_archived(thread: &Channel) -> bool {
+    thread
+        .thread_metadata
+        .as_ref()
+        .is_some_and(|metadata| metadata.archived)
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Counts of the entities of a guild.
+    ///
+    /// This requires the [`GUILD_CREATE`] event and the [`GUILDS`] intent, as
+    /// well as the [`GUILD_MEMBERS`] and [`GUILD_PRESENCES`] intents for
+    /// accurate member and presence counts.
+    ///
+    /// [`GUILD_CREATE`]: twilight_model::gateway::event::EventType::GuildCreate
+    /// [`GUILDS`]: twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
+    /// [`GUILD_PRESENCES`]: twilight_model::gateway::Intents::GUILD_PRESENCES
+    pub fn guild_counts(&self, guild_id: Id<GuildMarker>) -> Option<GuildCounts> {
+        self.guild_counters
+            .get(&guild_id)
+            .map(|counter| counter.counts())
+    }
+
+    /// Start counting the entities of a guild, replacing previous counts.
+    pub(crate) fn count_guild(&self, guild: &Guild) {
+        self.guild_counters
+            .insert(guild.id, GuildCounter::new(guild));
+    }
+
+    /// Count a created or updated channel or thread of a guild.
+    pub(crate) fn count_channel(&self, channel: &Channel) {
+        if let Some(guild_id) = channel.guild_id {
+            self.count(guild_id, |counter| counter.channel(channel));
+        }
+    }
+
+    /// Update the counts of a guild, if it's counted.
+    pub(crate) fn count(&self, guild_id: Id<GuildMarker>, f: impl FnOnce(&mut GuildCounter)) {
+        if let Some(mut counter) = self.guild_counters.get_mut(&guild_id) {
+            f(&mut counter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildCounts;
+    use crate::DefaultInMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::{
+        channel::{
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            ChannelType,
+        },
+        fixture,
+        gateway::{
+            payload::incoming::{
+                ChannelCreate, ChannelDelete, GuildCreate, GuildDelete, MemberAdd, MemberRemove,
+                PresenceUpdate, ThreadCreate, ThreadUpdate,
+            },
+            presence::{ClientStatus, Presence, Status, UserOrId},
+        },
+        id::Id,
+        util::Timestamp,
+    };
+
+    assert_impl_all!(GuildCounts: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    fn presence(user_id: u64, status: Status) -> PresenceUpdate {
+        PresenceUpdate(Presence {
+            activities: Vec::new(),
+            client_status: ClientStatus {
+                desktop: None,
+                mobile: None,
+                web: None,
+            },
+            guild_id: Id::new(1),
+            status,
+            user: UserOrId::UserId {
+                id: Id::new(user_id),
+            },
+        })
+    }
+
+    #[test]
+    fn guild_counts() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let owner = fixture::user(Id::new(2));
+
+        let mut text = fixture::channel(Id::new(3), ChannelType::GuildText);
+        text.guild_id = Some(guild_id);
+        let mut thread = fixture::channel(Id::new(4), ChannelType::PublicThread);
+        thread.guild_id = Some(guild_id);
+        thread.parent_id = Some(text.id);
+
+        let mut guild = fixture::guild(guild_id, owner.id);
+        guild.channels = vec![text.clone()];
+        guild.member_count = Some(10);
+        guild.presences = vec![
+            presence(2, Status::Online).0,
+            presence(5, Status::Offline).0,
+        ];
+        guild.threads = vec![thread.clone()];
+
+        assert!(cache.guild_counts(guild_id).is_none());
+        cache.update(&GuildCreate::Available(guild));
+
+        let counts = cache.guild_counts(guild_id).unwrap();
+        assert_eq!(1, counts.channels());
+        assert_eq!(10, counts.members());
+        assert_eq!(1, counts.online_presences());
+        assert_eq!(1, counts.threads());
+
+        let mut voice = fixture::channel(Id::new(6), ChannelType::GuildVoice);
+        voice.guild_id = Some(guild_id);
+        cache.update(&ChannelCreate(voice.clone()));
+        cache.update(&ChannelDelete(text));
+        assert_eq!(1, cache.guild_counts(guild_id).unwrap().channels());
+
+        let mut new_thread = fixture::channel(Id::new(7), ChannelType::PublicThread);
+        new_thread.guild_id = Some(guild_id);
+        cache.update(&ThreadCreate(new_thread));
+        thread.thread_metadata = Some(ThreadMetadata {
+            archived: true,
+            auto_archive_duration: AutoArchiveDuration::Hour,
+            archive_timestamp: Timestamp::from_secs(fixture::TIMESTAMP_SECS).unwrap(),
+            create_timestamp: None,
+            invitable: None,
+            locked: false,
+        });
+        cache.update(&ThreadUpdate(thread));
+        assert_eq!(1, cache.guild_counts(guild_id).unwrap().threads());
+
+        cache.update(&presence(5, Status::Idle));
+        cache.update(&presence(2, Status::Offline));
+        cache.update(&presence(8, Status::DoNotDisturb));
+        assert_eq!(2, cache.guild_counts(guild_id).unwrap().online_presences());
+
+        cache.update(&MemberAdd {
+            guild_id,
+            member: fixture::member(fixture::user(Id::new(9))),
+        });
+        cache.update(&MemberRemove {
+            guild_id,
+            user: fixture::user(Id::new(8)),
+        });
+        let counts = cache.guild_counts(guild_id).unwrap();
+        assert_eq!(10, counts.members());
+        assert_eq!(1, counts.online_presences());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+        assert!(cache.guild_counts(guild_id).is_none());
+    }
+}
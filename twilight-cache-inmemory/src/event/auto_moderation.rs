@@ -0,0 +1,151 @@
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use twilight_model::{
+    gateway::payload::incoming::{
+        AutoModerationRuleCreate, AutoModerationRuleDelete, AutoModerationRuleUpdate,
+    },
+    guild::auto_moderation::AutoModerationRule,
+};
+
+fn cache_auto_moderation_rule<B: CacheBackend>(
+    backend: &B,
+    rule: &AutoModerationRule,
+) -> Result<(), B::Error> {
+    backend.insert_auto_moderation_rule(
+        rule.guild_id,
+        rule.id,
+        <B::Models as CacheableModels>::AutoModerationRule::from(rule.clone()),
+    )
+}
+
+impl UpdateBackend for AutoModerationRuleCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::AUTO_MODERATION_RULE) {
+            return Ok(());
+        }
+
+        cache_auto_moderation_rule(backend, &self.0)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for AutoModerationRuleCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for AutoModerationRuleDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::AUTO_MODERATION_RULE) {
+            return Ok(());
+        }
+
+        backend.remove_auto_moderation_rule(self.id)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for AutoModerationRuleDelete {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for AutoModerationRuleUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::AUTO_MODERATION_RULE) {
+            return Ok(());
+        }
+
+        cache_auto_moderation_rule(backend, &self.0)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for AutoModerationRuleUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DefaultInMemoryCache;
+    use twilight_model::{
+        gateway::payload::incoming::{
+            AutoModerationRuleCreate, AutoModerationRuleDelete, AutoModerationRuleUpdate,
+            GuildDelete,
+        },
+        guild::auto_moderation::{
+            AutoModerationEventType, AutoModerationRule, AutoModerationTriggerMetadata,
+            AutoModerationTriggerType,
+        },
+        id::Id,
+    };
+
+    fn rule(id: u64) -> AutoModerationRule {
+        AutoModerationRule {
+            actions: Vec::new(),
+            creator_id: Id::new(1),
+            enabled: true,
+            event_type: AutoModerationEventType::MessageSend,
+            exempt_channels: Vec::new(),
+            exempt_roles: Vec::new(),
+            guild_id: Id::new(2),
+            id: Id::new(id),
+            name: "rule".to_owned(),
+            trigger_metadata: AutoModerationTriggerMetadata {
+                allow_list: None,
+                keyword_filter: Some(vec!["keyword".to_owned()]),
+                presets: None,
+                mention_raid_protection_enabled: None,
+                mention_total_limit: None,
+                regex_patterns: None,
+            },
+            trigger_type: AutoModerationTriggerType::Keyword,
+        }
+    }
+
+    #[test]
+    fn auto_moderation_rules() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(2);
+
+        cache.update(&AutoModerationRuleCreate(rule(3)));
+        cache.update(&AutoModerationRuleCreate(rule(4)));
+        assert_eq!(Some(2), cache.stats().guild_auto_moderation_rules(guild_id));
+        assert_eq!(
+            guild_id,
+            cache.auto_moderation_rule(Id::new(3)).unwrap().guild_id()
+        );
+
+        let mut updated = rule(3);
+        updated.enabled = false;
+        cache.update(&AutoModerationRuleUpdate(updated.clone()));
+        assert_eq!(
+            &updated,
+            cache.auto_moderation_rule(Id::new(3)).unwrap().resource()
+        );
+
+        cache.update(&AutoModerationRuleDelete(rule(3)));
+        assert!(cache.auto_moderation_rule(Id::new(3)).is_none());
+        assert_eq!(
+            Some(&Id::new(4)),
+            cache
+                .guild_auto_moderation_rules(guild_id)
+                .unwrap()
+                .iter()
+                .next()
+        );
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+        assert!(cache.auto_moderation_rule(Id::new(4)).is_none());
+        assert!(cache.guild_auto_moderation_rules(guild_id).is_none());
+        assert_eq!(0, cache.stats().auto_moderation_rules());
+        assert!(cache.iter().auto_moderation_rules().next().is_none());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    model::CachedBan,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use twilight_model::{
+    gateway::payload::incoming::{BanAdd, BanRemove, GuildAuditLogEntryCreate},
+    guild::{audit_log::AuditLogEventType, Ban},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Cache the bans of a guild, such as those returned by the
+    /// [`GetBans`] request, replacing its cached bans.
+    ///
+    /// Bans are otherwise only cached as users are banned, so seeding them
+    /// allows determining whether users banned beforehand are banned.
+    ///
+    /// Does nothing if [`ResourceType::BAN`] isn't wanted.
+    ///
+    /// [`GetBans`]: https://docs.rs/twilight-http/latest/twilight_http/request/guild/ban/struct.GetBans.html
+    pub fn cache_bans(&self, guild_id: Id<GuildMarker>, bans: impl IntoIterator<Item = Ban>) {
+        if !self.wants(ResourceType::BAN) {
+            return;
+        }
+
+        self.remove_bans(guild_id);
+
+        let mut guild_bans = self.guild_bans.entry(guild_id).or_default();
+
+        for ban in bans {
+            guild_bans.insert(ban.user.id);
+            self.bans.insert(
+                (guild_id, ban.user.id),
+                CachedBan::from_model(guild_id, ban),
+            );
+        }
+    }
+
+    pub(crate) fn remove_bans(&self, guild_id: Id<GuildMarker>) {
+        if let Some((_, user_ids)) = self.guild_bans.remove(&guild_id) {
+            for user_id in user_ids {
+                self.bans.remove(&(guild_id, user_id));
+            }
+        }
+    }
+}
+
+fn cache_ban<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    reason: Option<&str>,
+) -> Result<(), B::Error> {
+    let mut ban = backend.ban(guild_id, user_id)?.unwrap_or(CachedBan {
+        guild_id,
+        reason: None,
+        user_id,
+    });
+
+    if let Some(reason) = reason {
+        ban.reason = Some(reason.to_owned());
+    }
+
+    backend.insert_ban(ban)
+}
+
+impl UpdateBackend for BanAdd {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::BAN) {
+            return Ok(());
+        }
+
+        cache_ban(backend, self.guild_id, self.user.id, None)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for BanAdd {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for BanRemove {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::BAN) {
+            return Ok(());
+        }
+
+        backend.remove_ban(self.guild_id, self.user.id)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for BanRemove {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for GuildAuditLogEntryCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::BAN) || self.action_type != AuditLogEventType::MemberBanAdd
+        {
+            return Ok(());
+        }
+
+        if let (Some(guild_id), Some(target_id)) = (self.guild_id, self.target_id) {
+            cache_ban(backend, guild_id, target_id.cast(), self.reason.as_deref())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Audit log entries of bans are used to cache their reasons, which
+/// [`BanAdd`] events don't include.
+///
+/// This requires the [`GUILD_MODERATION`] intent and the bot to have the
+/// [`VIEW_AUDIT_LOG`] permission.
+///
+/// [`GUILD_MODERATION`]: twilight_model::gateway::Intents::GUILD_MODERATION
+/// [`VIEW_AUDIT_LOG`]: twilight_model::guild::Permissions::VIEW_AUDIT_LOG
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildAuditLogEntryCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultInMemoryCache, ResourceType};
+    use twilight_model::{
+        fixture,
+        gateway::payload::incoming::{
+            BanAdd, BanRemove, GuildAuditLogEntryCreate, GuildDelete, UnavailableGuild,
+        },
+        guild::{
+            audit_log::{AuditLogEntry, AuditLogEventType},
+            Ban,
+        },
+        id::Id,
+    };
+
+    fn audit_log_entry(kind: AuditLogEventType, reason: &str) -> GuildAuditLogEntryCreate {
+        GuildAuditLogEntryCreate(AuditLogEntry {
+            action_type: kind,
+            changes: Vec::new(),
+            guild_id: Some(Id::new(1)),
+            id: Id::new(3),
+            options: None,
+            reason: Some(reason.to_owned()),
+            target_id: Some(Id::new(2)),
+            user_id: Some(Id::new(4)),
+        })
+    }
+
+    #[test]
+    fn ban_add_and_remove() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let user = fixture::user(Id::new(2));
+
+        cache.update(&BanAdd {
+            guild_id,
+            user: user.clone(),
+        });
+        let ban = cache.ban(guild_id, user.id).unwrap();
+        assert_eq!(user.id, ban.user_id());
+        assert_eq!(None, ban.reason());
+        drop(ban);
+
+        cache.update(&audit_log_entry(AuditLogEventType::MemberKick, "kick"));
+        cache.update(&audit_log_entry(AuditLogEventType::MemberBanAdd, "spam"));
+        assert_eq!(Some("spam"), cache.ban(guild_id, user.id).unwrap().reason());
+
+        // Repeated events keep the known reason.
+        cache.update(&BanAdd {
+            guild_id,
+            user: user.clone(),
+        });
+        assert_eq!(Some("spam"), cache.ban(guild_id, user.id).unwrap().reason());
+
+        cache.update(&BanRemove { guild_id, user });
+        assert!(cache.ban(guild_id, Id::new(2)).is_none());
+        assert!(cache.guild_bans(guild_id).is_none());
+    }
+
+    #[test]
+    fn cache_bans() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+
+        cache.update(&BanAdd {
+            guild_id,
+            user: fixture::user(Id::new(2)),
+        });
+        cache.cache_bans(
+            guild_id,
+            [Ban {
+                reason: Some("spam".to_owned()),
+                user: fixture::user(Id::new(3)),
+            }],
+        );
+
+        assert!(cache.ban(guild_id, Id::new(2)).is_none());
+        assert_eq!(
+            Some("spam"),
+            cache.ban(guild_id, Id::new(3)).unwrap().reason()
+        );
+        assert_eq!(1, cache.guild_bans(guild_id).unwrap().len());
+
+        // Bans are kept while guilds are unavailable.
+        cache.update(&UnavailableGuild { id: guild_id });
+        assert!(cache.ban(guild_id, Id::new(3)).is_some());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+        assert!(cache.ban(guild_id, Id::new(3)).is_none());
+        assert!(cache.guild_bans(guild_id).is_none());
+    }
+
+    #[test]
+    fn unwanted() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::BAN)
+            .build();
+        let guild_id = Id::new(1);
+
+        cache.update(&BanAdd {
+            guild_id,
+            user: fixture::user(Id::new(2)),
+        });
+        cache.cache_bans(
+            guild_id,
+            [Ban {
+                reason: None,
+                user: fixture::user(Id::new(3)),
+            }],
+        );
+
+        assert!(cache.guild_bans(guild_id).is_none());
+    }
+}
@@ -1,85 +1,91 @@
-use crate::{traits::CacheableChannel, CacheableModels, InMemoryCache, ResourceType, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    traits::CacheableChannel,
+    CacheableModels, InMemoryCache, ResourceType, UpdateCache,
+};
 use twilight_model::{
     channel::Channel,
     gateway::payload::incoming::{ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate},
-    id::{marker::ChannelMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_channels(&self, channels: impl IntoIterator<Item = Channel>) {
-        for channel in channels {
-            self.cache_channel(channel);
-        }
-    }
+pub(crate) fn cache_channel<B: CacheBackend>(
+    backend: &B,
+    channel: &Channel,
+) -> Result<(), B::Error> {
+    backend.insert_channel(<B::Models as CacheableModels>::Channel::from(
+        channel.clone(),
+    ))
+}
 
-    pub(crate) fn cache_channel(&self, channel: Channel) {
-        if let Some(guild_id) = channel.guild_id {
-            self.guild_channels
-                .entry(guild_id)
-                .or_default()
-                .insert(channel.id);
+impl UpdateBackend for ChannelCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
         }
 
-        self.channels
-            .insert(channel.id, CacheModels::Channel::from(channel));
-    }
-
-    /// Delete a guild channel from the cache.
-    ///
-    /// The guild channel data itself and the channel entry in its guild's list
-    /// of channels will be deleted.
-    pub(crate) fn delete_channel(&self, channel_id: Id<ChannelMarker>) {
-        if let Some((_, channel)) = self.channels.remove(&channel_id) {
-            if let Some(guild_id) = channel.guild_id() {
-                let maybe_channels = self.guild_channels.get_mut(&guild_id);
-
-                if let Some(mut channels) = maybe_channels {
-                    channels.remove(&channel_id);
-                }
-            }
-        }
+        cache_channel(backend, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+        cache.count_channel(&self.0);
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ChannelDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
         }
 
-        cache.cache_channel(self.0.clone());
+        backend.remove_channel(self.0.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+        if let Some(guild_id) = self.0.guild_id {
+            cache.count(guild_id, |counter| counter.remove_channel(self.0.id));
         }
 
-        cache.delete_channel(self.0.id);
+        cache.apply(self);
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelPinsUpdate {
-    fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+impl UpdateBackend for ChannelPinsUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
         }
 
-        if let Some(mut channel) = cache.channels.get_mut(&self.channel_id) {
+        backend.modify_channel(self.channel_id, |channel| {
             channel.set_last_pin_timestamp(self.last_pin_timestamp);
-        }
+        })
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelUpdate {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelPinsUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ChannelUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
         }
 
-        cache.cache_channel(self.0.clone());
+        cache_channel(backend, &self.0)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.count_channel(&self.0);
+        cache.apply(self);
     }
 }
 
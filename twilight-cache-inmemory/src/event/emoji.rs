@@ -1,72 +1,61 @@
-use crate::{config::ResourceType, CacheableModels, GuildResource, InMemoryCache, UpdateCache};
-use std::borrow::Cow;
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::incoming::GuildEmojisUpdate,
     guild::Emoji,
     id::{marker::GuildMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_emojis(&self, guild_id: Id<GuildMarker>, emojis: Vec<Emoji>) {
-        if let Some(mut guild_emojis) = self.guild_emojis.get_mut(&guild_id) {
-            let incoming: Vec<_> = emojis.iter().map(|e| e.id).collect();
-
-            let removal_filter: Vec<_> = guild_emojis
-                .iter()
-                .copied()
-                .filter(|e| !incoming.contains(e))
-                .collect();
+pub(crate) fn cache_emojis<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    emojis: &[Emoji],
+) -> Result<(), B::Error> {
+    for emoji_id in backend.guild_emoji_ids(guild_id)? {
+        if !emojis.iter().any(|emoji| emoji.id == emoji_id) {
+            backend.remove_emoji(emoji_id)?;
+        }
+    }
 
-            for to_remove in &removal_filter {
-                guild_emojis.remove(to_remove);
-            }
+    for emoji in emojis {
+        cache_emoji(backend, guild_id, emoji)?;
+    }
 
-            for to_remove in &removal_filter {
-                self.emojis.remove(to_remove);
-            }
-        }
+    Ok(())
+}
 
-        for emoji in emojis {
-            self.cache_emoji(guild_id, emoji);
-        }
+pub(crate) fn cache_emoji<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    emoji: &Emoji,
+) -> Result<(), B::Error> {
+    if let Some(user) = emoji.user.as_ref() {
+        super::cache_user(backend, user, Some(guild_id))?;
     }
 
-    pub(crate) fn cache_emoji(&self, guild_id: Id<GuildMarker>, emoji: Emoji) {
-        if let Some(cached_emoji) = self.emojis.get(&emoji.id) {
-            if cached_emoji.value == emoji {
-                return;
-            }
-        }
+    backend.insert_emoji(
+        guild_id,
+        emoji.id,
+        <B::Models as CacheableModels>::Emoji::from(emoji.clone()),
+    )
+}
 
-        if let Some(user) = emoji.user.as_ref() {
-            self.cache_user(Cow::Borrowed(user), Some(guild_id));
+impl UpdateBackend for GuildEmojisUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::EMOJI) {
+            return Ok(());
         }
 
-        let emoji_id = emoji.id;
-        let cached = CacheModels::Emoji::from(emoji);
-
-        self.emojis.insert(
-            emoji_id,
-            GuildResource {
-                guild_id,
-                value: cached,
-            },
-        );
-
-        self.guild_emojis
-            .entry(guild_id)
-            .or_default()
-            .insert(emoji_id);
+        cache_emojis(backend, self.guild_id, &self.emojis)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildEmojisUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::EMOJI) {
-            return;
-        }
-
-        cache.cache_emojis(self.guild_id, self.emojis.clone());
+        cache.apply(self);
     }
 }
 
@@ -0,0 +1,178 @@
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use twilight_model::{
+    application::monetization::Entitlement,
+    gateway::payload::incoming::{EntitlementCreate, EntitlementDelete, EntitlementUpdate},
+};
+
+fn cache_entitlement<B: CacheBackend>(
+    backend: &B,
+    entitlement: &Entitlement,
+) -> Result<(), B::Error> {
+    if entitlement.deleted {
+        return backend.remove_entitlement(entitlement.id);
+    }
+
+    backend.insert_entitlement(
+        entitlement.id,
+        <B::Models as CacheableModels>::Entitlement::from(entitlement.clone()),
+    )
+}
+
+impl UpdateBackend for [Entitlement] {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::ENTITLEMENT) {
+            return Ok(());
+        }
+
+        for entitlement in self {
+            cache_entitlement(backend, entitlement)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Seeds the cache with entitlements, such as those returned by the List
+/// Entitlements endpoint.
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for [Entitlement] {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for EntitlementCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::ENTITLEMENT) {
+            return Ok(());
+        }
+
+        cache_entitlement(backend, &self.0)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for EntitlementCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for EntitlementDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::ENTITLEMENT) {
+            return Ok(());
+        }
+
+        backend.remove_entitlement(self.id)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for EntitlementDelete {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for EntitlementUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::ENTITLEMENT) {
+            return Ok(());
+        }
+
+        cache_entitlement(backend, &self.0)
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for EntitlementUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DefaultInMemoryCache;
+    use twilight_model::{
+        application::monetization::{Entitlement, EntitlementType},
+        gateway::payload::incoming::{EntitlementCreate, EntitlementDelete, EntitlementUpdate},
+        id::Id,
+    };
+
+    fn entitlement() -> Entitlement {
+        Entitlement {
+            application_id: Id::new(1),
+            consumed: None,
+            deleted: false,
+            ends_at: None,
+            guild_id: None,
+            id: Id::new(2),
+            kind: EntitlementType::ApplicationSubscription,
+            sku_id: Id::new(3),
+            starts_at: None,
+            user_id: Some(Id::new(4)),
+        }
+    }
+
+    #[test]
+    fn entitlement_create_and_delete() {
+        let cache = DefaultInMemoryCache::new();
+        let entitlement = entitlement();
+        cache.update(&EntitlementCreate(entitlement.clone()));
+
+        assert_eq!(
+            Some(&entitlement),
+            cache.entitlement(entitlement.id).as_deref()
+        );
+        assert!(cache
+            .user_entitlements(Id::new(4))
+            .unwrap()
+            .contains(&entitlement.id));
+        assert!(cache
+            .sku_entitlements(Id::new(3))
+            .unwrap()
+            .contains(&entitlement.id));
+        assert!(cache.user_has_sku(Id::new(4), Id::new(3)));
+        assert!(!cache.user_has_sku(Id::new(4), Id::new(5)));
+
+        cache.update(&EntitlementDelete(entitlement.clone()));
+        assert!(cache.entitlement(entitlement.id).is_none());
+        assert!(cache.user_entitlements(Id::new(4)).is_none());
+        assert!(cache.sku_entitlements(Id::new(3)).is_none());
+    }
+
+    #[test]
+    fn entitlement_update() {
+        let cache = DefaultInMemoryCache::new();
+        let mut entitlement = entitlement();
+        cache.update(&EntitlementCreate(entitlement.clone()));
+
+        entitlement.guild_id = Some(Id::new(6));
+        entitlement.user_id = None;
+        cache.update(&EntitlementUpdate(entitlement.clone()));
+        assert!(cache.user_entitlements(Id::new(4)).is_none());
+        assert!(cache.guild_has_sku(Id::new(6), Id::new(3)));
+
+        entitlement.deleted = true;
+        cache.update(&EntitlementUpdate(entitlement.clone()));
+        assert!(cache.entitlement(entitlement.id).is_none());
+        assert!(cache.guild_entitlements(Id::new(6)).is_none());
+    }
+
+    #[test]
+    fn entitlements_seed() {
+        let cache = DefaultInMemoryCache::new();
+        let mut consumed = entitlement();
+        consumed.consumed = Some(true);
+        consumed.id = Id::new(7);
+        consumed.kind = EntitlementType::Unknown(2);
+        consumed.sku_id = Id::new(8);
+        cache.update([entitlement(), consumed].as_slice());
+
+        assert_eq!(2, cache.user_entitlements(Id::new(4)).unwrap().len());
+        assert!(cache.user_has_sku(Id::new(4), Id::new(3)));
+        assert!(!cache.user_has_sku(Id::new(4), Id::new(8)));
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{config::ResourceType, CacheableGuild, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    change::CacheChange,
+    config::ResourceType,
+    CacheableGuild, CacheableModels, InMemoryCache, UpdateCache,
+};
 use dashmap::DashMap;
 use std::{collections::HashSet, hash::Hash, mem};
 use twilight_model::{
@@ -7,76 +12,111 @@ use twilight_model::{
     id::{marker::GuildMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    #[allow(clippy::too_many_lines)]
-    fn cache_guild(&self, mut guild: Guild) {
-        // The map and set creation needs to occur first, so caching states and
-        // objects always has a place to put them.
-        if self.wants(ResourceType::CHANNEL) {
-            self.guild_channels.insert(guild.id, HashSet::new());
+pub(crate) fn cache_guild<B: CacheBackend>(backend: &B, guild: &Guild) -> Result<(), B::Error> {
+    let mut guild = guild.clone();
+    let channels = mem::take(&mut guild.channels);
+    let threads = mem::take(&mut guild.threads);
+    let emojis = mem::take(&mut guild.emojis);
+    let members = mem::take(&mut guild.members);
+    let presences = mem::take(&mut guild.presences);
+    let roles = mem::take(&mut guild.roles);
+    let stickers = mem::take(&mut guild.stickers);
+    let voice_states = mem::take(&mut guild.voice_states);
+    let stage_instances = mem::take(&mut guild.stage_instances);
+    let scheduled_events = mem::take(&mut guild.guild_scheduled_events);
+
+    if wants(backend, ResourceType::CHANNEL) {
+        for mut channel in channels.into_iter().chain(threads) {
+            channel.guild_id = Some(guild.id);
+            super::channel::cache_channel(backend, &channel)?;
+        }
+    }
 
-            let mut channels = mem::take(&mut guild.channels);
-            let mut threads = mem::take(&mut guild.threads);
+    if wants(backend, ResourceType::EMOJI) {
+        super::emoji::cache_emojis(backend, guild.id, &emojis)?;
+    }
 
-            for channel in &mut channels {
-                channel.guild_id = Some(guild.id);
-            }
+    if wants(backend, ResourceType::MEMBER) {
+        super::member::cache_members(backend, guild.id, &members)?;
+    }
 
-            for channel in &mut threads {
-                channel.guild_id = Some(guild.id);
-            }
+    if wants(backend, ResourceType::PRESENCE) {
+        super::presence::cache_presences(backend, guild.id, &presences)?;
+    }
+
+    if wants(backend, ResourceType::ROLE) {
+        super::role::cache_roles(backend, guild.id, &roles)?;
+    }
+
+    if wants(backend, ResourceType::STICKER) {
+        super::sticker::cache_stickers(backend, guild.id, &stickers)?;
+    }
+
+    if wants(backend, ResourceType::VOICE_STATE) {
+        super::voice_state::cache_voice_states(backend, &voice_states)?;
+    }
+
+    if wants(backend, ResourceType::STAGE_INSTANCE) {
+        super::stage_instance::cache_stage_instances(backend, guild.id, &stage_instances)?;
+    }
+
+    if wants(backend, ResourceType::GUILD_SCHEDULED_EVENT) {
+        super::guild_scheduled_events::cache_guild_scheduled_events(
+            backend,
+            guild.id,
+            &scheduled_events,
+        )?;
+    }
+
+    if wants(backend, ResourceType::GUILD) {
+        backend.insert_guild(<B::Models as CacheableModels>::Guild::from(guild))?;
+    }
+
+    Ok(())
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Create the sets of the resources of a guild, so a guild without any
+    /// resource of a type has an empty set of them.
+    pub(crate) fn create_guild_sets(&self, guild_id: Id<GuildMarker>) {
+        fn create<T>(guild_map: &DashMap<Id<GuildMarker>, HashSet<T>>, guild_id: Id<GuildMarker>) {
+            guild_map.entry(guild_id).or_default();
+        }
 
-            self.cache_channels(channels);
-            self.cache_channels(threads);
+        if self.wants(ResourceType::CHANNEL) {
+            create(&self.guild_channels, guild_id);
         }
 
         if self.wants(ResourceType::EMOJI) {
-            self.guild_emojis.insert(guild.id, HashSet::new());
-            self.cache_emojis(guild.id, mem::take(&mut guild.emojis));
+            create(&self.guild_emojis, guild_id);
         }
 
         if self.wants(ResourceType::MEMBER) {
-            self.guild_members.insert(guild.id, HashSet::new());
-            self.cache_members(guild.id, mem::take(&mut guild.members));
+            create(&self.guild_members, guild_id);
         }
 
         if self.wants(ResourceType::PRESENCE) {
-            self.guild_presences.insert(guild.id, HashSet::new());
-            self.cache_presences(guild.id, mem::take(&mut guild.presences));
+            create(&self.guild_presences, guild_id);
         }
 
         if self.wants(ResourceType::ROLE) {
-            self.guild_roles.insert(guild.id, HashSet::new());
-            self.cache_roles(guild.id, mem::take(&mut guild.roles));
+            create(&self.guild_roles, guild_id);
         }
 
         if self.wants(ResourceType::STICKER) {
-            self.guild_stickers.insert(guild.id, HashSet::new());
-            self.cache_stickers(guild.id, mem::take(&mut guild.stickers));
+            create(&self.guild_stickers, guild_id);
         }
 
         if self.wants(ResourceType::VOICE_STATE) {
-            self.voice_state_guilds.insert(guild.id, HashSet::new());
-            self.cache_voice_states(mem::take(&mut guild.voice_states));
+            create(&self.voice_state_guilds, guild_id);
         }
 
         if self.wants(ResourceType::STAGE_INSTANCE) {
-            self.guild_stage_instances.insert(guild.id, HashSet::new());
-            self.cache_stage_instances(guild.id, mem::take(&mut guild.stage_instances));
+            create(&self.guild_stage_instances, guild_id);
         }
 
         if self.wants(ResourceType::GUILD_SCHEDULED_EVENT) {
-            self.guild_scheduled_events.insert(guild.id, HashSet::new());
-            self.cache_guild_scheduled_events(
-                guild.id,
-                mem::take(&mut guild.guild_scheduled_events),
-            );
-        }
-
-        if self.wants(ResourceType::GUILD) {
-            let guild = CacheModels::Guild::from(guild);
-            self.unavailable_guilds.remove(&guild.id());
-            self.guilds.insert(guild.id(), guild);
+            create(&self.guild_scheduled_events, guild_id);
         }
     }
 
@@ -96,27 +136,78 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         if self.wants(ResourceType::GUILD) {
             if unavailable {
                 if let Some(mut guild) = self.guilds.get_mut(&id) {
+                    let old = self.watch(guild.value());
                     guild.set_unavailable(true);
+                    self.notify_modified(CacheChange::Guild, id, old, guild.value());
                 }
-            } else {
-                self.guilds.remove(&id);
+            } else if let Some((_, guild)) = self.guilds.remove(&id) {
+                self.notify_removed(CacheChange::Guild, id, &guild);
+            }
+
+            if !unavailable {
+                self.guild_counters.remove(&id);
             }
         }
 
+        if self.wants(ResourceType::AUTO_MODERATION_RULE) {
+            remove_ids(
+                &self.guild_auto_moderation_rules,
+                &self.auto_moderation_rules,
+                id,
+            );
+        }
+
+        // Bans aren't part of guilds received again once they're available,
+        // so they're kept while a guild is unavailable.
+        if self.wants(ResourceType::BAN) && !unavailable {
+            self.remove_bans(id);
+        }
+
         if self.wants(ResourceType::CHANNEL) {
-            remove_ids(&self.guild_channels, &self.channels, id);
+            if let Some((_, ids)) = self.guild_channels.remove(&id) {
+                for channel_id in ids {
+                    self.remove_channel(channel_id);
+                }
+            }
         }
 
         if self.wants(ResourceType::EMOJI) {
-            remove_ids(&self.guild_emojis, &self.emojis, id);
+            if let Some((_, ids)) = self.guild_emojis.remove(&id) {
+                for emoji_id in ids {
+                    self.remove_emoji(emoji_id);
+                    self.emoji_limit.remove(&emoji_id);
+                }
+            }
         }
 
         if self.wants(ResourceType::ROLE) {
-            remove_ids(&self.guild_roles, &self.roles, id);
+            if let Some((_, ids)) = self.guild_roles.remove(&id) {
+                for role_id in ids {
+                    if let Some((_, role)) = self.roles.remove(&role_id) {
+                        self.notify_removed(CacheChange::Role, role_id, &role);
+                    }
+                }
+            }
         }
 
         if self.wants(ResourceType::STICKER) {
-            remove_ids(&self.guild_stickers, &self.stickers, id);
+            if let Some((_, ids)) = self.guild_stickers.remove(&id) {
+                for sticker_id in ids {
+                    if let Some((_, sticker)) = self.stickers.remove(&sticker_id) {
+                        self.notify_removed(CacheChange::Sticker, sticker_id, &sticker);
+                    }
+                }
+            }
+        }
+
+        if self.wants(ResourceType::GUILD_SCHEDULED_EVENT) {
+            if let Some((_, ids)) = self.guild_scheduled_events.remove(&id) {
+                for event_id in ids {
+                    if let Some((_, event)) = self.scheduled_events.remove(&event_id) {
+                        self.notify_removed(CacheChange::ScheduledEvent, event_id, &event);
+                    }
+                }
+            }
         }
 
         if self.wants(ResourceType::VOICE_STATE) {
@@ -127,7 +218,8 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         if self.wants(ResourceType::MEMBER) {
             if let Some((_, ids)) = self.guild_members.remove(&id) {
                 for user_id in ids {
-                    self.members.remove(&(id, user_id));
+                    self.remove_member(id, user_id);
+                    self.untrack_member(id, user_id);
                 }
             }
         }
@@ -135,39 +227,62 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         if self.wants(ResourceType::PRESENCE) {
             if let Some((_, ids)) = self.guild_presences.remove(&id) {
                 for user_id in ids {
-                    self.presences.remove(&(id, user_id));
+                    self.remove_presence(id, user_id);
+                    self.presence_limit.remove(&(id, user_id));
                 }
             }
         }
     }
 }
 
+impl UpdateBackend for GuildCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        match self {
+            GuildCreate::Available(guild) => cache_guild(backend, guild),
+            GuildCreate::Unavailable(guild) => backend.mark_guild_unavailable(guild.id),
+        }
+    }
+}
+
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        match self {
-            GuildCreate::Available(g) => cache.cache_guild(g.clone()),
-            GuildCreate::Unavailable(g) => {
-                cache.unavailable_guild(g.id);
+        if let GuildCreate::Available(guild) = self {
+            if cache.wants(ResourceType::GUILD) {
+                cache.count_guild(guild);
             }
+
+            cache.create_guild_sets(guild.id);
         }
+
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for GuildDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        backend.remove_guild(self.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        cache.delete_guild(self.id, false);
+        cache.apply(self);
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildUpdate {
-    fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::GUILD) {
-            return;
+impl UpdateBackend for GuildUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::GUILD) {
+            return Ok(());
         }
 
-        if let Some(mut guild) = cache.guilds.get_mut(&self.0.id) {
-            guild.update_with_guild_update(self);
-        };
+        backend.modify_guild(self.0.id, |guild| guild.update_with_guild_update(self))
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
     }
 }
 
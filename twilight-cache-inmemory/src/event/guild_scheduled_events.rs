@@ -8,96 +8,116 @@ use twilight_model::{
 };
 
 use crate::{
-    traits::CacheableGuildScheduledEvent, CacheableModels, InMemoryCache, ResourceType, UpdateCache,
+    backend::{wants, CacheBackend, UpdateBackend},
+    traits::CacheableGuildScheduledEvent,
+    CacheableModels, InMemoryCache, ResourceType, UpdateCache,
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_guild_scheduled_events(
-        &self,
-        guild_id: Id<GuildMarker>,
-        guild_scheduled_events: impl IntoIterator<Item = GuildScheduledEvent>,
-    ) {
-        for event in guild_scheduled_events {
-            self.cache_guild_scheduled_event(guild_id, event);
-        }
+pub(crate) fn cache_guild_scheduled_events<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    guild_scheduled_events: &[GuildScheduledEvent],
+) -> Result<(), B::Error> {
+    for event in guild_scheduled_events {
+        cache_guild_scheduled_event(backend, guild_id, event)?;
     }
 
-    fn cache_guild_scheduled_event(
-        &self,
-        guild_id: Id<GuildMarker>,
-        guild_scheduled_event: GuildScheduledEvent,
-    ) {
-        self.guild_scheduled_events
-            .entry(guild_id)
-            .or_default()
-            .insert(guild_scheduled_event.id);
-
-        crate::upsert_guild_item(
-            &self.scheduled_events,
-            guild_id,
-            guild_scheduled_event.id,
-            CacheModels::GuildScheduledEvent::from(guild_scheduled_event),
-        );
+    Ok(())
+}
+
+fn cache_guild_scheduled_event<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    guild_scheduled_event: &GuildScheduledEvent,
+) -> Result<(), B::Error> {
+    backend.insert_scheduled_event(
+        guild_id,
+        guild_scheduled_event.id,
+        <B::Models as CacheableModels>::GuildScheduledEvent::from(guild_scheduled_event.clone()),
+    )
+}
+
+impl UpdateBackend for GuildScheduledEventCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::GUILD_SCHEDULED_EVENT) {
+            return Ok(());
+        }
+
+        cache_guild_scheduled_event(backend, self.guild_id, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildScheduledEventCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::GUILD_SCHEDULED_EVENT) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for GuildScheduledEventDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::GUILD_SCHEDULED_EVENT) {
+            return Ok(());
         }
 
-        cache.cache_guild_scheduled_event(self.guild_id, self.0.clone());
+        backend.remove_scheduled_event(self.guild_id, self.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildScheduledEventDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::GUILD_SCHEDULED_EVENT) {
-            return;
-        }
+        cache.apply(self);
+    }
+}
 
-        if cache.scheduled_events.remove(&self.id).is_some() {
-            if let Some(mut events) = cache.guild_scheduled_events.get_mut(&self.guild_id) {
-                events.remove(&self.id);
-            }
+impl UpdateBackend for GuildScheduledEventUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::GUILD_SCHEDULED_EVENT) {
+            return Ok(());
         }
+
+        cache_guild_scheduled_event(backend, self.guild_id, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildScheduledEventUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::GUILD_SCHEDULED_EVENT) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for GuildScheduledEventUserAdd {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::GUILD_SCHEDULED_EVENT) {
+            return Ok(());
         }
 
-        cache.cache_guild_scheduled_event(self.guild_id, self.0.clone());
+        backend.modify_scheduled_event(self.guild_scheduled_event_id, |event| {
+            event.add_user(self.guild_id, self.guild_scheduled_event_id, self.user_id);
+        })
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildScheduledEventUserAdd {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        cache
-            .scheduled_events
-            .entry(self.guild_scheduled_event_id)
-            .and_modify(|event| {
-                event
-                    .value
-                    .add_user(self.guild_id, self.guild_scheduled_event_id, self.user_id);
-            });
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for GuildScheduledEventUserRemove {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::GUILD_SCHEDULED_EVENT) {
+            return Ok(());
+        }
+
+        backend.modify_scheduled_event(self.guild_scheduled_event_id, |event| {
+            event.remove_user(self.guild_id, self.guild_scheduled_event_id, self.user_id);
+        })
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildScheduledEventUserRemove {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        cache
-            .scheduled_events
-            .entry(self.guild_scheduled_event_id)
-            .and_modify(|event| {
-                event
-                    .value
-                    .remove_user(self.guild_id, self.guild_scheduled_event_id, self.user_id);
-            });
+        cache.apply(self);
     }
 }
 
@@ -106,7 +126,8 @@ mod tests {
     use crate::{test, DefaultInMemoryCache};
     use twilight_model::{
         gateway::payload::incoming::{
-            GuildScheduledEventCreate, GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
+            GuildDelete, GuildScheduledEventCreate, GuildScheduledEventUserAdd,
+            GuildScheduledEventUserRemove,
         },
         id::Id,
     };
@@ -157,4 +178,25 @@ mod tests {
             cache.scheduled_events.get(&id).unwrap().user_count.unwrap()
         );
     }
+
+    #[test]
+    fn guild_delete() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(2);
+
+        cache.update(&GuildScheduledEventCreate(test::guild_scheduled_event(
+            Id::new(1),
+            guild_id,
+            None,
+        )));
+        assert_eq!(Some(1), cache.stats().guild_scheduled_events(guild_id));
+        assert_eq!(1, cache.iter().scheduled_events().count());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+        assert!(cache.scheduled_events(guild_id).is_none());
+        assert_eq!(0, cache.stats().scheduled_events());
+    }
 }
@@ -1,76 +1,72 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::incoming::{IntegrationCreate, IntegrationDelete, IntegrationUpdate},
     guild::GuildIntegration,
-    id::{
-        marker::{GuildMarker, IntegrationMarker},
-        Id,
-    },
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    fn cache_integration(&self, guild_id: Id<GuildMarker>, integration: GuildIntegration) {
-        self.guild_integrations
-            .entry(guild_id)
-            .or_default()
-            .insert(integration.id);
+fn cache_integration<B: CacheBackend>(
+    backend: &B,
+    integration: &GuildIntegration,
+) -> Result<(), B::Error> {
+    let Some(guild_id) = integration.guild_id else {
+        return Ok(());
+    };
 
-        crate::upsert_guild_item(
-            &self.integrations,
-            guild_id,
-            (guild_id, integration.id),
-            CacheModels::GuildIntegration::from(integration),
-        );
-    }
+    backend.insert_integration(
+        guild_id,
+        integration.id,
+        <B::Models as CacheableModels>::GuildIntegration::from(integration.clone()),
+    )
+}
 
-    fn delete_integration(&self, guild_id: Id<GuildMarker>, integration_id: Id<IntegrationMarker>) {
-        if self
-            .integrations
-            .remove(&(guild_id, integration_id))
-            .is_some()
-        {
-            if let Some(mut integrations) = self.guild_integrations.get_mut(&guild_id) {
-                integrations.remove(&integration_id);
-            }
+impl UpdateBackend for IntegrationCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::INTEGRATION) {
+            return Ok(());
         }
+
+        cache_integration(backend, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for IntegrationCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::INTEGRATION) {
-            return;
-        }
+        cache.apply(self);
+    }
+}
 
-        if let Some(guild_id) = self.guild_id {
-            crate::upsert_guild_item(
-                &cache.integrations,
-                guild_id,
-                (guild_id, self.id),
-                CacheModels::GuildIntegration::from(self.0.clone()),
-            );
+impl UpdateBackend for IntegrationDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::INTEGRATION) {
+            return Ok(());
         }
+
+        backend.remove_integration(self.guild_id, self.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for IntegrationDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::INTEGRATION) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for IntegrationUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::INTEGRATION) {
+            return Ok(());
         }
 
-        cache.delete_integration(self.guild_id, self.id);
+        cache_integration(backend, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for IntegrationUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::INTEGRATION) {
-            return;
-        }
-
-        if let Some(guild_id) = self.guild_id {
-            cache.cache_integration(guild_id, self.0.clone());
-        }
+        cache.apply(self);
     }
 }
@@ -1,59 +1,97 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
-use std::borrow::Cow;
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::{
-    application::interaction::InteractionData, gateway::payload::incoming::InteractionCreate,
+    application::interaction::{InteractionData, InteractionDataResolved},
+    gateway::payload::incoming::InteractionCreate,
+    id::{marker::GuildMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for InteractionCreate {
-    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+/// Cache the resolved data of an interaction.
+fn cache_interaction_resolved<B: CacheBackend>(
+    backend: &B,
+    guild_id: Option<Id<GuildMarker>>,
+    resolved: &InteractionDataResolved,
+) -> Result<(), B::Error> {
+    // Cache resolved users and members
+    for u in resolved.users.values() {
+        if wants(backend, ResourceType::USER) {
+            super::cache_user(backend, u, guild_id)?;
+        }
+
+        if !wants(backend, ResourceType::MEMBER) {
+            continue;
+        }
+
+        // This should always match, because resolved members
+        // are guaranteed to have a matching resolved user
+        if let (Some(member), Some(guild_id)) = (resolved.members.get(&u.id), guild_id) {
+            super::member::cache_borrowed_interaction_member(backend, guild_id, member, u.id)?;
+        }
+    }
+
+    // Cache resolved roles
+    if wants(backend, ResourceType::ROLE) {
+        if let Some(guild_id) = guild_id {
+            for role in resolved.roles.values() {
+                super::role::cache_role(backend, guild_id, role)?;
+            }
+        }
+    }
+
+    // Resolved channels are partial and hence not cached, so as to not store
+    // channels lacking most of their fields
+
+    // Cache resolved messages along with their authors
+    for message in resolved.messages.values() {
+        super::message::cache_message(backend, message)?;
+    }
+
+    Ok(())
+}
+
+impl UpdateBackend for InteractionCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
         // Cache interaction member
-        if cache.wants(ResourceType::MEMBER) {
+        if wants(backend, ResourceType::MEMBER) {
             if let (Some(member), Some(guild_id)) = (&self.member, self.guild_id) {
                 if let Some(user) = &member.user {
-                    cache.cache_user(Cow::Borrowed(user), self.guild_id);
+                    super::cache_user(backend, user, self.guild_id)?;
 
-                    cache.cache_borrowed_partial_member(guild_id, member, user.id);
+                    super::member::cache_borrowed_partial_member(
+                        backend, guild_id, member, user.id,
+                    )?;
                 }
             }
         }
 
         // Cache interaction user
-        if cache.wants(ResourceType::USER) {
+        if wants(backend, ResourceType::USER) {
             if let Some(user) = &self.user {
-                cache.cache_user(Cow::Borrowed(user), None);
+                super::cache_user(backend, user, None)?;
             }
         }
 
         // Cache resolved interaction data
-        if let Some(InteractionData::ApplicationCommand(data)) = &self.data {
-            if let Some(resolved) = &data.resolved {
-                // Cache resolved users and members
-                for u in resolved.users.values() {
-                    if cache.wants(ResourceType::USER) {
-                        cache.cache_user(Cow::Borrowed(u), self.guild_id);
-                    }
+        let resolved = match &self.data {
+            Some(InteractionData::ApplicationCommand(data)) => data.resolved.as_ref(),
+            Some(InteractionData::MessageComponent(data)) => data.resolved.as_ref(),
+            _ => None,
+        };
 
-                    if !cache.wants(ResourceType::MEMBER) || self.guild_id.is_none() {
-                        continue;
-                    }
+        if let Some(resolved) = resolved {
+            cache_interaction_resolved(backend, self.guild_id, resolved)?;
+        }
 
-                    // This should always match, because resolved members
-                    // are guaranteed to have a matching resolved user
-                    if let Some(member) = &resolved.members.get(&u.id) {
-                        if let Some(guild_id) = self.guild_id {
-                            cache.cache_borrowed_interaction_member(guild_id, member, u.id);
-                        }
-                    }
-                }
+        Ok(())
+    }
+}
 
-                // Cache resolved roles
-                if cache.wants(ResourceType::ROLE) {
-                    if let Some(guild_id) = self.guild_id {
-                        cache.cache_roles(guild_id, resolved.roles.values().cloned());
-                    }
-                }
-            }
-        }
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for InteractionCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
     }
 }
 
@@ -65,7 +103,7 @@ mod tests {
         application::{
             command::CommandType,
             interaction::{
-                application_command::CommandData, Interaction, InteractionData,
+                application_command::CommandData, Interaction, InteractionChannel, InteractionData,
                 InteractionDataResolved, InteractionMember, InteractionType,
             },
         },
@@ -143,7 +181,17 @@ mod tests {
                 options: Vec::new(),
                 resolved: Some(InteractionDataResolved {
                     attachments: HashMap::new(),
-                    channels: HashMap::new(),
+                    channels: HashMap::from([(
+                        Id::new(9),
+                        InteractionChannel {
+                            id: Id::new(9),
+                            kind: ChannelType::GuildText,
+                            name: "channel name".to_owned(),
+                            parent_id: Some(Id::new(10)),
+                            permissions: Permissions::empty(),
+                            thread_metadata: None,
+                        },
+                    )]),
                     members: HashMap::from([(
                         Id::new(7),
                         InteractionMember {
@@ -339,6 +387,22 @@ mod tests {
             assert_eq!(guild_roles.len(), 1);
         }
 
+        assert!(cache.channel(Id::new(9)).is_none());
+
+        {
+            let message = cache.message(Id::new(4)).unwrap();
+            assert_eq!(message.content, "ping");
+            assert!(cache.user(Id::new(3)).is_some());
+            assert_eq!(
+                cache
+                    .channel_messages(Id::new(2))
+                    .unwrap()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                [&Id::new(4)]
+            );
+        }
+
         Ok(())
     }
 }
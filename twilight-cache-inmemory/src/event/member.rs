@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
 use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
     config::ResourceType,
+    counts::GuildCounter,
     model::member::ComputedInteractionMember,
+    retention,
     traits::{CacheableGuild, CacheableMember},
     CacheableModels, InMemoryCache, UpdateCache,
 };
@@ -16,165 +19,159 @@ use twilight_model::{
     },
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_members(
-        &self,
-        guild_id: Id<GuildMarker>,
-        members: impl IntoIterator<Item = Member>,
-    ) {
-        for member in members {
-            self.cache_member(guild_id, member);
-        }
-    }
-
-    pub(crate) fn cache_member(&self, guild_id: Id<GuildMarker>, member: Member) {
-        let member_id = member.user.id;
-        let id = (guild_id, member_id);
-
-        if let Some(m) = self.members.get(&id) {
-            if *m == member {
-                return;
-            }
-        }
-
-        self.cache_user(Cow::Borrowed(&member.user), Some(guild_id));
-        let cached = CacheModels::Member::from(member);
-        self.members.insert(id, cached);
-        self.guild_members
-            .entry(guild_id)
-            .or_default()
-            .insert(member_id);
+pub(crate) fn cache_members<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    members: &[Member],
+) -> Result<(), B::Error> {
+    for member in members {
+        cache_member(backend, guild_id, member)?;
     }
 
-    pub(crate) fn cache_borrowed_partial_member(
-        &self,
-        guild_id: Id<GuildMarker>,
-        member: &PartialMember,
-        user_id: Id<UserMarker>,
-    ) {
-        let id = (guild_id, user_id);
-
-        if let Some(m) = self.members.get(&id) {
-            if &*m == member {
-                return;
-            }
-        }
+    Ok(())
+}
 
-        self.guild_members
-            .entry(guild_id)
-            .or_default()
-            .insert(user_id);
+pub(crate) fn cache_member<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    member: &Member,
+) -> Result<(), B::Error> {
+    super::cache_user(backend, &member.user, Some(guild_id))?;
+
+    let mut member = member.clone();
+    retention::retain_member_avatar(backend.dropped_fields(), &mut member.avatar);
+    let member_id = member.user.id;
+
+    backend.insert_member(
+        guild_id,
+        member_id,
+        <B::Models as CacheableModels>::Member::from(member),
+    )
+}
 
-        let cached = CacheModels::Member::from((user_id, member.clone()));
-        self.members.insert(id, cached);
-    }
+pub(crate) fn cache_borrowed_partial_member<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    member: &PartialMember,
+    user_id: Id<UserMarker>,
+) -> Result<(), B::Error> {
+    let mut member = member.clone();
+    retention::retain_member_avatar(backend.dropped_fields(), &mut member.avatar);
+
+    backend.insert_member(
+        guild_id,
+        user_id,
+        <B::Models as CacheableModels>::Member::from((user_id, member)),
+    )
+}
 
-    pub(crate) fn cache_borrowed_interaction_member(
-        &self,
-        guild_id: Id<GuildMarker>,
-        member: &InteractionMember,
-        user_id: Id<UserMarker>,
-    ) {
-        let id = (guild_id, user_id);
-
-        let (avatar, deaf, mute) = match self.members.get(&id) {
-            Some(m) if &*m == member => return,
-            Some(m) => (m.avatar(), m.deaf(), m.mute()),
-            None => (None, None, None),
-        };
-
-        self.guild_members
-            .entry(guild_id)
-            .or_default()
-            .insert(user_id);
-
-        let cached = CacheModels::Member::from(ComputedInteractionMember {
+pub(crate) fn cache_borrowed_interaction_member<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    member: &InteractionMember,
+    user_id: Id<UserMarker>,
+) -> Result<(), B::Error> {
+    let (avatar, deaf, mute) = match backend.member(guild_id, user_id)? {
+        Some(m) if m == *member => return Ok(()),
+        Some(m) => (m.avatar(), m.deaf(), m.mute()),
+        None => (None, None, None),
+    };
+
+    backend.insert_member(
+        guild_id,
+        user_id,
+        <B::Models as CacheableModels>::Member::from(ComputedInteractionMember {
             avatar,
             deaf,
             interaction_member: member.clone(),
             mute,
             user_id,
-        });
+        }),
+    )
+}
 
-        self.members.insert(id, cached);
+impl UpdateBackend for MemberAdd {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if wants(backend, ResourceType::GUILD) {
+            backend.modify_guild(self.guild_id, |guild| guild.increase_member_count(1))?;
+        }
+
+        if !wants(backend, ResourceType::MEMBER) {
+            return Ok(());
+        }
+
+        cache_member(backend, self.guild_id, &self.member)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberAdd {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if cache.wants(ResourceType::GUILD) {
-            if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
-                guild.increase_member_count(1);
-            }
-        }
+        cache.count(self.guild_id, GuildCounter::add_member);
+        cache.apply(self);
+    }
+}
 
-        if !cache.wants(ResourceType::MEMBER) {
-            return;
+impl UpdateBackend for MemberChunk {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::MEMBER) {
+            return Ok(());
         }
 
-        cache.cache_member(self.guild_id, self.member.clone());
+        cache_members(backend, self.guild_id, &self.members)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberChunk {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::MEMBER) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for MemberRemove {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if wants(backend, ResourceType::GUILD) {
+            backend.modify_guild(self.guild_id, |guild| guild.decrease_member_count(1))?;
         }
 
-        if self.members.is_empty() {
-            return;
+        if !wants(backend, ResourceType::MEMBER) {
+            return Ok(());
         }
 
-        cache.cache_members(self.guild_id, self.members.clone());
+        backend.remove_member(self.guild_id, self.user.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberRemove {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if cache.wants(ResourceType::GUILD) {
-            if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
-                guild.decrease_member_count(1);
-            }
-        }
-
-        if !cache.wants(ResourceType::MEMBER) {
-            return;
-        }
-
-        cache.members.remove(&(self.guild_id, self.user.id));
+        cache.count(self.guild_id, |counter| counter.remove_member(self.user.id));
+        cache.apply(self);
+    }
+}
 
-        if let Some(mut members) = cache.guild_members.get_mut(&self.guild_id) {
-            members.remove(&self.user.id);
+impl UpdateBackend for MemberUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::MEMBER) {
+            return Ok(());
         }
 
-        // Avoid a deadlock by mutating the user, dropping the lock to the map,
-        // and then removing the user later if they are in no guilds.
-        let mut remove_user = false;
+        let mut member_update = Cow::Borrowed(self);
 
-        if let Some(mut user_guilds) = cache.user_guilds.get_mut(&self.user.id) {
-            user_guilds.remove(&self.guild_id);
-
-            remove_user = user_guilds.is_empty();
+        if self.avatar.is_some() {
+            retention::retain_member_avatar(
+                backend.dropped_fields(),
+                &mut member_update.to_mut().avatar,
+            );
         }
 
-        if remove_user {
-            cache.users.remove(&self.user.id);
-        }
+        backend.modify_member(self.guild_id, self.user.id, |member| {
+            member.update_with_member_update(&member_update);
+        })
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::MEMBER) {
-            return;
-        }
-
-        let key = (self.guild_id, self.user.id);
-
-        if let Some(mut member) = cache.members.get_mut(&key) {
-            member.update_with_member_update(self);
-        }
+        cache.apply(self);
     }
 }
 
@@ -1,98 +1,135 @@
-use crate::{config::ResourceType, CacheableMessage, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    change::CacheChange,
+    config::ResourceType,
+    retention, CacheableMessage, CacheableModels, InMemoryCache, UpdateCache,
+};
 use std::borrow::Cow;
-use twilight_model::gateway::payload::incoming::{
-    MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
+use twilight_model::{
+    channel::Message,
+    gateway::payload::incoming::{MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate},
+    id::{marker::MessageMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
-    fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if cache.wants(ResourceType::USER) {
-            cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
-        }
+/// Cache a message along with its author and their member.
+///
+/// Messages not yet in the messages of their channel are inserted in order of
+/// their ID, whereas the others are replaced in place.
+pub(crate) fn cache_message<B: CacheBackend>(
+    backend: &B,
+    message: &Message,
+) -> Result<(), B::Error> {
+    if wants(backend, ResourceType::USER) {
+        super::cache_user(backend, &message.author, message.guild_id)?;
+    }
 
-        if let (Some(member), Some(guild_id), true) = (
-            &self.member,
-            self.guild_id,
-            cache.wants(ResourceType::MEMBER),
-        ) {
-            cache.cache_borrowed_partial_member(guild_id, member, self.author.id);
-        }
+    if let (Some(member), Some(guild_id), true) = (
+        &message.member,
+        message.guild_id,
+        wants(backend, ResourceType::MEMBER),
+    ) {
+        super::member::cache_borrowed_partial_member(backend, guild_id, member, message.author.id)?;
+    }
 
-        if !cache.wants(ResourceType::MESSAGE) {
-            return;
-        }
+    if !wants(backend, ResourceType::MESSAGE) {
+        return Ok(());
+    }
+
+    let mut cached = message.clone();
+    retention::retain_message(backend.dropped_fields(), &mut cached);
 
-        let mut channel_messages = cache.channel_messages.entry(self.0.channel_id).or_default();
+    backend.insert_message(
+        message.guild_id,
+        message.channel_id,
+        message.id,
+        <B::Models as CacheableModels>::Message::from(cached),
+    )
+}
 
-        // If the channel has more messages than the cache size the user has
-        // requested then we pop a message ID out. Once we have the popped ID we
-        // can remove it from the message cache. This prevents the cache from
-        // filling up with old messages that aren't in any channel cache.
-        if channel_messages.len() >= cache.config.message_cache_size() {
-            if let Some(popped_id) = channel_messages.pop_back() {
-                cache.messages.remove(&popped_id);
-            }
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Remove a message, without removing it from the messages of its channel.
+    pub(crate) fn remove_message(&self, message_id: Id<MessageMarker>) {
+        if let Some((_, message)) = self.messages.remove(&message_id) {
+            self.notify_removed(CacheChange::Message, message_id, &message);
         }
+    }
+}
 
-        channel_messages.push_front(self.0.id);
-        cache
-            .messages
-            .insert(self.0.id, CacheModels::Message::from(self.0.clone()));
+impl UpdateBackend for MessageCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        cache_message(backend, &self.0)
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageDelete {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::MESSAGE) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for MessageDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::MESSAGE) {
+            return Ok(());
         }
 
-        cache.messages.remove(&self.id);
+        backend.remove_message(self.channel_id, self.id)
+    }
+}
 
-        let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageDelete {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for MessageDeleteBulk {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::MESSAGE) {
+            return Ok(());
+        }
 
-        if let Some(idx) = channel_messages.iter().position(|id| *id == self.id) {
-            channel_messages.remove(idx);
+        for id in &self.ids {
+            backend.remove_message(self.channel_id, *id)?;
         }
+
+        Ok(())
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageDeleteBulk {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::MESSAGE) {
-            return;
-        }
+        cache.apply(self);
+    }
+}
 
-        let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
+impl UpdateBackend for MessageUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::MESSAGE) {
+            return Ok(());
+        }
 
-        for id in &self.ids {
-            cache.messages.remove(id);
+        let mut message_update = Cow::Borrowed(self);
 
-            if let Some(idx) = channel_messages
-                .iter()
-                .position(|message_id| message_id == id)
-            {
-                channel_messages.remove(idx);
-            }
+        if self.embeds.is_some() {
+            retention::retain_message_update(backend.dropped_fields(), message_update.to_mut());
         }
+
+        backend.modify_message(self.id, |message| {
+            message.update_with_message_update(&message_update);
+        })
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::MESSAGE) {
-            return;
-        }
-
-        if let Some(mut message) = cache.messages.get_mut(&self.id) {
-            message.update_with_message_update(self);
-        }
+        cache.apply(self);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{DefaultInMemoryCache, ResourceType};
+    use crate::{test, DefaultInMemoryCache, ResourceType};
     use twilight_model::{
         channel::message::{Message, MessageFlags, MessageType},
         gateway::payload::incoming::MessageCreate,
@@ -208,4 +245,54 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn guild_message_cache_size() {
+        let cache = DefaultInMemoryCache::builder()
+            .message_cache_size(3)
+            .guild_message_cache_size(Id::new(1), 1)
+            .build();
+        let mut message = test::message();
+        cache.update(&MessageCreate(message.clone()));
+        message.id = Id::new(5);
+        cache.update(&MessageCreate(message.clone()));
+
+        assert!(cache.message(Id::new(4)).is_none());
+        assert_eq!(1, cache.channel_messages(Id::new(2)).unwrap().len());
+
+        message.channel_id = Id::new(6);
+        message.guild_id = None;
+        for id in 7..10 {
+            message.id = Id::new(id);
+            cache.update(&MessageCreate(message.clone()));
+        }
+
+        assert_eq!(3, cache.channel_messages(Id::new(6)).unwrap().len());
+    }
+
+    #[test]
+    fn message_order() {
+        let cache = DefaultInMemoryCache::builder()
+            .message_cache_size(3)
+            .build();
+        let mut message = test::message();
+
+        for id in [6, 8, 4, 7, 5] {
+            message.id = Id::new(id);
+            cache.update(&MessageCreate(message.clone()));
+        }
+
+        assert_eq!(
+            [Id::new(8), Id::new(7), Id::new(6)],
+            *cache
+                .channel_messages(Id::new(2))
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert!(cache.message(Id::new(4)).is_none());
+        assert!(cache.message(Id::new(5)).is_none());
+        assert!(cache.message(Id::new(6)).is_some());
+    }
 }
@@ -1,11 +1,15 @@
+pub mod auto_moderation;
+pub mod ban;
 pub mod channel;
 pub mod emoji;
+pub mod entitlement;
 pub mod guild;
 pub mod guild_scheduled_events;
 pub mod integration;
 pub mod interaction;
 pub mod member;
 pub mod message;
+pub mod poll;
 pub mod presence;
 pub mod reaction;
 pub mod role;
@@ -14,84 +18,97 @@ pub mod sticker;
 pub mod thread;
 pub mod voice_state;
 
-use std::{borrow::Cow, collections::HashSet};
-
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    change::CacheChange,
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::incoming::{Ready, UnavailableGuild, UserUpdate},
-    id::{marker::GuildMarker, Id},
-    user::{CurrentUser, User},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+    user::User,
 };
 
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    fn cache_current_user(&self, current_user: CurrentUser) {
-        self.current_user
-            .lock()
-            .expect("current user poisoned")
-            .replace(CacheModels::CurrentUser::from(current_user));
+    /// Remove a user, without removing it from the users of its guilds.
+    pub(crate) fn remove_user(&self, user_id: Id<UserMarker>) {
+        if let Some((_, user)) = self.users.remove(&user_id) {
+            self.notify_removed(CacheChange::User, user_id, &user);
+        }
     }
+}
 
-    pub(crate) fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<Id<GuildMarker>>) {
-        if let Some(cached_user) = self.users.get_mut(&user.id) {
-            if cached_user.value() == user.as_ref() {
-                if let Some(guild_id) = guild_id {
-                    self.user_guilds
-                        .entry(user.id)
-                        .or_default()
-                        .insert(guild_id);
-                }
+pub(crate) fn cache_user<B: CacheBackend>(
+    backend: &B,
+    user: &User,
+    guild_id: Option<Id<GuildMarker>>,
+) -> Result<(), B::Error> {
+    backend.insert_user(
+        guild_id,
+        user.id,
+        <B::Models as CacheableModels>::User::from(user.clone()),
+    )
+}
 
-                return;
-            }
+impl UpdateBackend for Ready {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if wants(backend, ResourceType::USER_CURRENT) {
+            backend.insert_current_user(<B::Models as CacheableModels>::CurrentUser::from(
+                self.user.clone(),
+            ))?;
         }
 
-        let user = user.into_owned();
-        let user_id = user.id;
-
-        self.users.insert(user_id, CacheModels::User::from(user));
-
-        if let Some(guild_id) = guild_id {
-            let mut guild_id_set = HashSet::new();
-            guild_id_set.insert(guild_id);
-            self.user_guilds.insert(user_id, guild_id_set);
+        if wants(backend, ResourceType::GUILD) {
+            for guild in &self.guilds {
+                backend.mark_guild_unavailable(guild.id)?;
+            }
         }
-    }
 
-    fn unavailable_guild(&self, guild_id: Id<GuildMarker>) {
-        self.unavailable_guilds.insert(guild_id);
-        self.delete_guild(guild_id, true);
+        Ok(())
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Ready {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if cache.wants(ResourceType::USER_CURRENT) {
-            cache.cache_current_user(self.user.clone());
-        }
+        cache.apply(self);
+    }
+}
 
-        if cache.wants(ResourceType::GUILD) {
-            for guild in &self.guilds {
-                cache.unavailable_guild(guild.id);
-            }
+impl UpdateBackend for UnavailableGuild {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::GUILD) {
+            return Ok(());
         }
+
+        backend.mark_guild_unavailable(self.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for UnavailableGuild {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if cache.wants(ResourceType::GUILD) {
-            cache.unavailable_guild(self.id);
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for UserUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::USER_CURRENT) {
+            return Ok(());
         }
+
+        backend.insert_current_user(<B::Models as CacheableModels>::CurrentUser::from(
+            self.0.clone(),
+        ))
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for UserUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::USER_CURRENT) {
-            return;
-        }
-
-        cache.cache_current_user(self.0.clone());
+        cache.apply(self);
     }
 }
 
@@ -0,0 +1,193 @@
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    traits::{CacheableCurrentUser, CacheableMessage},
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use twilight_model::{
+    gateway::payload::incoming::{MessagePollVoteAdd, MessagePollVoteRemove},
+    poll::{AnswerCount, PollResults},
+};
+
+impl UpdateBackend for MessagePollVoteAdd {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::MESSAGE) {
+            return Ok(());
+        }
+
+        let me = backend
+            .current_user()?
+            .is_some_and(|user| user.id() == self.user_id);
+
+        backend.modify_message(self.message_id, |message| {
+            let Some(poll) = message.poll_mut() else {
+                return;
+            };
+
+            let results = poll.results.get_or_insert_with(|| PollResults {
+                answer_counts: Vec::new(),
+                is_finalized: false,
+            });
+
+            if let Some(answer_count) = results
+                .answer_counts
+                .iter_mut()
+                .find(|answer_count| answer_count.id == self.answer_id)
+            {
+                answer_count.count += 1;
+                answer_count.me_voted |= me;
+            } else {
+                results.answer_counts.push(AnswerCount {
+                    id: self.answer_id,
+                    count: 1,
+                    me_voted: me,
+                });
+            }
+        })
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessagePollVoteAdd {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for MessagePollVoteRemove {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::MESSAGE) {
+            return Ok(());
+        }
+
+        let me = backend
+            .current_user()?
+            .is_some_and(|user| user.id() == self.user_id);
+
+        backend.modify_message(self.message_id, |message| {
+            let Some(results) = message.poll_mut().and_then(|poll| poll.results.as_mut()) else {
+                return;
+            };
+
+            let Some(position) = results
+                .answer_counts
+                .iter()
+                .position(|answer_count| answer_count.id == self.answer_id)
+            else {
+                return;
+            };
+
+            let answer_count = &mut results.answer_counts[position];
+            answer_count.count = answer_count.count.saturating_sub(1);
+
+            if me {
+                answer_count.me_voted = false;
+            }
+
+            if answer_count.count == 0 {
+                results.answer_counts.remove(position);
+            }
+        })
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessagePollVoteRemove {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test;
+    use twilight_model::{
+        gateway::payload::incoming::{MessagePollVoteAdd, MessagePollVoteRemove},
+        id::Id,
+        poll::{AnswerCount, Poll, PollAnswer, PollLayoutType, PollMedia},
+    };
+
+    fn media(text: &str) -> PollMedia {
+        PollMedia {
+            emoji: None,
+            text: Some(text.to_owned()),
+        }
+    }
+
+    #[test]
+    fn poll_votes() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.cache_current_user(test::current_user(5));
+        cache.messages.get_mut(&Id::new(4)).unwrap().poll = Some(Poll {
+            answers: vec![
+                PollAnswer {
+                    answer_id: 1,
+                    poll_media: media("a"),
+                },
+                PollAnswer {
+                    answer_id: 2,
+                    poll_media: media("b"),
+                },
+            ],
+            allow_multiselect: false,
+            expiry: None,
+            layout_type: PollLayoutType::Default,
+            question: media("question"),
+            results: None,
+        });
+
+        let vote = |answer_id, user_id| MessagePollVoteAdd {
+            answer_id,
+            channel_id: Id::new(2),
+            guild_id: Some(Id::new(1)),
+            message_id: Id::new(4),
+            user_id: Id::new(user_id),
+        };
+        cache.update(&vote(1, 5));
+        cache.update(&vote(1, 6));
+        cache.update(&vote(2, 6));
+
+        let answer_counts = || {
+            let message = cache.message(Id::new(4)).unwrap();
+
+            message
+                .poll()
+                .unwrap()
+                .results
+                .clone()
+                .unwrap()
+                .answer_counts
+        };
+        assert_eq!(
+            vec![
+                AnswerCount {
+                    id: 1,
+                    count: 2,
+                    me_voted: true,
+                },
+                AnswerCount {
+                    id: 2,
+                    count: 1,
+                    me_voted: false,
+                },
+            ],
+            answer_counts()
+        );
+
+        let unvote = |answer_id, user_id| MessagePollVoteRemove {
+            answer_id,
+            channel_id: Id::new(2),
+            guild_id: Some(Id::new(1)),
+            message_id: Id::new(4),
+            user_id: Id::new(user_id),
+        };
+        cache.update(&unvote(1, 5));
+        cache.update(&unvote(2, 6));
+        assert_eq!(
+            vec![AnswerCount {
+                id: 1,
+                count: 1,
+                me_voted: false,
+            }],
+            answer_counts()
+        );
+    }
+}
@@ -1,40 +1,71 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    change::CacheChange,
+    config::ResourceType,
+    retention, CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::{payload::incoming::PresenceUpdate, presence::Presence},
-    id::{marker::GuildMarker, Id},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_presences(
-        &self,
-        guild_id: Id<GuildMarker>,
-        presences: impl IntoIterator<Item = Presence>,
-    ) {
-        for presence in presences {
-            self.cache_presence(guild_id, presence);
+    /// Remove a presence, without removing it from the presences of its
+    /// guild.
+    pub(crate) fn remove_presence(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if let Some((key, presence)) = self.presences.remove(&(guild_id, user_id)) {
+            self.notify_removed(CacheChange::Presence, key, &presence);
         }
     }
+}
 
-    fn cache_presence(&self, guild_id: Id<GuildMarker>, presence: Presence) {
-        self.guild_presences
-            .entry(guild_id)
-            .or_default()
-            .insert(presence.user.id());
+pub(crate) fn cache_presences<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    presences: &[Presence],
+) -> Result<(), B::Error> {
+    for presence in presences {
+        cache_presence(backend, guild_id, presence)?;
+    }
 
-        self.presences.insert(
-            (guild_id, presence.user.id()),
-            CacheModels::Presence::from(presence),
-        );
+    Ok(())
+}
+
+fn cache_presence<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    presence: &Presence,
+) -> Result<(), B::Error> {
+    let mut presence = presence.clone();
+    retention::retain_presence(backend.dropped_fields(), &mut presence);
+    let user_id = presence.user.id();
+
+    backend.insert_presence(
+        guild_id,
+        user_id,
+        <B::Models as CacheableModels>::Presence::from(presence),
+    )
+}
+
+impl UpdateBackend for PresenceUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::PRESENCE) {
+            return Ok(());
+        }
+
+        cache_presence(backend, self.guild_id, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for PresenceUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::PRESENCE) {
-            return;
-        }
-
-        cache.cache_presence(self.guild_id, self.0.clone());
+        cache.count(self.guild_id, |counter| {
+            counter.presence(self.user.id(), self.status);
+        });
+        cache.apply(self);
     }
 }
 
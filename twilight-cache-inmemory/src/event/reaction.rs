@@ -1,4 +1,5 @@
 use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
     config::ResourceType,
     traits::{CacheableCurrentUser, CacheableMessage},
     CacheableModels, InMemoryCache, UpdateCache,
@@ -10,116 +11,148 @@ use twilight_model::{
     },
 };
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionAdd {
-    fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::REACTION) {
-            return;
+impl UpdateBackend for ReactionAdd {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::REACTION) {
+            return Ok(());
         }
 
-        let key = self.0.message_id;
-
-        let Some(mut message) = cache.messages.get_mut(&key) else {
-            return;
-        };
-
-        if let Some(reaction) = message
-            .reactions_mut()
-            .iter_mut()
-            .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
-        {
-            if !reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id() == self.0.user_id {
-                        reaction.me = true;
+        let me = backend
+            .current_user()?
+            .is_some_and(|user| user.id() == self.0.user_id);
+
+        backend.modify_message(self.0.message_id, |message| {
+            if let Some(reaction) = message
+                .reactions_mut()
+                .iter_mut()
+                .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
+            {
+                if self.0.burst {
+                    reaction.count_details.burst += 1;
+                    reaction.me_burst |= me;
+
+                    for color in &self.0.burst_colors {
+                        if !reaction.burst_colors.contains(color) {
+                            reaction.burst_colors.push(*color);
+                        }
                     }
+                } else {
+                    reaction.count_details.normal += 1;
+                    reaction.me |= me;
                 }
-            }
 
-            reaction.count += 1;
-        } else {
-            let me = cache
-                .current_user()
-                .is_some_and(|user| user.id() == self.0.user_id);
-
-            message.add_reaction(Reaction {
-                burst_colors: Vec::new(),
-                count: 1,
-                count_details: ReactionCountDetails {
-                    burst: 0,
-                    normal: 1,
-                },
-                emoji: self.0.emoji.clone(),
-                me,
-                me_burst: false,
-            });
-        }
+                reaction.count += 1;
+            } else {
+                let burst = self.0.burst;
+
+                message.add_reaction(Reaction {
+                    burst_colors: if burst {
+                        self.0.burst_colors.clone()
+                    } else {
+                        Vec::new()
+                    },
+                    count: 1,
+                    count_details: ReactionCountDetails {
+                        burst: u64::from(burst),
+                        normal: u64::from(!burst),
+                    },
+                    emoji: self.0.emoji.clone(),
+                    me: me && !burst,
+                    me_burst: me && burst,
+                });
+            }
+        })
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemove {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionAdd {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::REACTION) {
-            return;
-        }
+        cache.apply(self);
+    }
+}
 
-        let Some(mut message) = cache.messages.get_mut(&self.0.message_id) else {
-            return;
-        };
+impl UpdateBackend for ReactionRemove {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::REACTION) {
+            return Ok(());
+        }
 
-        if let Some(reaction) = message
-            .reactions_mut()
-            .iter_mut()
-            .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
-        {
-            if reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id() == self.0.user_id {
-                        reaction.me = false;
+        let me = backend
+            .current_user()?
+            .is_some_and(|user| user.id() == self.0.user_id);
+
+        backend.modify_message(self.0.message_id, |message| {
+            if let Some(reaction) = message
+                .reactions_mut()
+                .iter_mut()
+                .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
+            {
+                if self.0.burst {
+                    reaction.count_details.burst = reaction.count_details.burst.saturating_sub(1);
+                    reaction.me_burst &= !me;
+
+                    if reaction.count_details.burst == 0 {
+                        reaction.burst_colors.clear();
                     }
+                } else {
+                    reaction.count_details.normal = reaction.count_details.normal.saturating_sub(1);
+                    reaction.me &= !me;
                 }
-            }
 
-            if reaction.count > 1 {
-                reaction.count -= 1;
-            } else {
-                message.retain_reactions(|e| !(reactions_eq(&e.emoji, &self.0.emoji)));
+                if reaction.count > 1 {
+                    reaction.count -= 1;
+                } else {
+                    message.retain_reactions(|e| !(reactions_eq(&e.emoji, &self.0.emoji)));
+                }
             }
-        }
+        })
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveAll {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemove {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::REACTION) {
-            return;
-        }
+        cache.apply(self);
+    }
+}
 
-        let Some(mut message) = cache.messages.get_mut(&self.message_id) else {
-            return;
-        };
+impl UpdateBackend for ReactionRemoveAll {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::REACTION) {
+            return Ok(());
+        }
 
-        message.clear_reactions();
+        backend.modify_message(self.message_id, CacheableMessage::clear_reactions)
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveEmoji {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveAll {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::REACTION) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ReactionRemoveEmoji {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::REACTION) {
+            return Ok(());
         }
 
-        let Some(mut message) = cache.messages.get_mut(&self.message_id) else {
-            return;
-        };
+        backend.modify_message(self.message_id, |message| {
+            let maybe_index = message
+                .reactions()
+                .iter()
+                .position(|r| reactions_eq(&r.emoji, &self.emoji));
 
-        let maybe_index = message
-            .reactions()
-            .iter()
-            .position(|r| reactions_eq(&r.emoji, &self.emoji));
+            if let Some(index) = maybe_index {
+                message.remove_reaction(index);
+            }
+        })
+    }
+}
 
-        if let Some(index) = maybe_index {
-            message.remove_reaction(index);
-        }
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveEmoji {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
     }
 }
 
@@ -144,10 +177,13 @@ mod tests {
     use twilight_model::{
         channel::message::{EmojiReactionType, Reaction},
         gateway::{
-            payload::incoming::{ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji},
+            payload::incoming::{
+                ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
+            },
             GatewayReaction,
         },
         id::Id,
+        util::HexColor,
     };
 
     fn find_custom_react(msg: &CachedMessage) -> Option<&Reaction> {
@@ -241,6 +277,52 @@ mod tests {
         assert!(custom_react.is_none());
     }
 
+    #[test]
+    fn reaction_burst() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.cache_current_user(test::current_user(5));
+
+        let reaction = GatewayReaction {
+            burst: true,
+            burst_colors: vec![HexColor(242, 0, 255)],
+            channel_id: Id::new(2),
+            emoji: EmojiReactionType::Unicode {
+                name: "😀".to_owned(),
+            },
+            guild_id: Some(Id::new(1)),
+            member: None,
+            message_author_id: None,
+            message_id: Id::new(4),
+            user_id: Id::new(5),
+        };
+        let smiley_react = |cache: &crate::DefaultInMemoryCache| {
+            cache
+                .message(Id::new(4))
+                .unwrap()
+                .reactions
+                .iter()
+                .find(|&r| matches!(&r.emoji, EmojiReactionType::Unicode {name} if name == "😀"))
+                .cloned()
+                .unwrap()
+        };
+
+        cache.update(&ReactionAdd(reaction.clone()));
+        let react = smiley_react(&cache);
+        assert_eq!(3, react.count);
+        assert_eq!(1, react.count_details.burst);
+        assert_eq!(2, react.count_details.normal);
+        assert_eq!(vec![HexColor(242, 0, 255)], react.burst_colors);
+        assert!(react.me_burst);
+
+        cache.update(&ReactionRemove(reaction));
+        let react = smiley_react(&cache);
+        assert_eq!(2, react.count);
+        assert_eq!(0, react.count_details.burst);
+        assert_eq!(2, react.count_details.normal);
+        assert!(react.burst_colors.is_empty());
+        assert!(!react.me_burst);
+    }
+
     #[test]
     fn reaction_remove_all() {
         let cache = test::cache_with_message_and_reactions();
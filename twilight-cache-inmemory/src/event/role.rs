@@ -1,76 +1,82 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::incoming::{RoleCreate, RoleDelete, RoleUpdate},
     guild::Role,
-    id::{
-        marker::{GuildMarker, RoleMarker},
-        Id,
-    },
+    id::{marker::GuildMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_roles(
-        &self,
-        guild_id: Id<GuildMarker>,
-        roles: impl IntoIterator<Item = Role>,
-    ) {
-        for role in roles {
-            self.cache_role(guild_id, role);
-        }
+pub(crate) fn cache_roles<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    roles: &[Role],
+) -> Result<(), B::Error> {
+    for role in roles {
+        cache_role(backend, guild_id, role)?;
     }
 
-    fn cache_role(&self, guild_id: Id<GuildMarker>, role: Role) {
-        // Insert the role into the guild_roles map
-        self.guild_roles
-            .entry(guild_id)
-            .or_default()
-            .insert(role.id);
-
-        // Insert the role into the all roles map
-        crate::upsert_guild_item(
-            &self.roles,
-            guild_id,
-            role.id,
-            CacheModels::Role::from(role),
-        );
-    }
+    Ok(())
+}
 
-    fn delete_role(&self, role_id: Id<RoleMarker>) {
-        if let Some((_, role)) = self.roles.remove(&role_id) {
-            if let Some(mut roles) = self.guild_roles.get_mut(&role.guild_id) {
-                roles.remove(&role_id);
-            }
+pub(crate) fn cache_role<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    role: &Role,
+) -> Result<(), B::Error> {
+    backend.insert_role(
+        guild_id,
+        <B::Models as CacheableModels>::Role::from(role.clone()),
+    )
+}
+
+impl UpdateBackend for RoleCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::ROLE) {
+            return Ok(());
         }
+
+        cache_role(backend, self.guild_id, &self.role)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for RoleCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::ROLE) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for RoleDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::ROLE) {
+            return Ok(());
         }
 
-        cache.cache_role(self.guild_id, self.role.clone());
+        backend.remove_role(self.role_id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for RoleDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::ROLE) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for RoleUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::ROLE) {
+            return Ok(());
         }
 
-        cache.delete_role(self.role_id);
+        cache_role(backend, self.guild_id, &self.role)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for RoleUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::ROLE) {
-            return;
-        }
-
-        cache.cache_role(self.guild_id, self.role.clone());
+        cache.apply(self);
     }
 }
 
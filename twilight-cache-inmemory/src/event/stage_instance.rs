@@ -1,76 +1,83 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     channel::StageInstance,
     gateway::payload::incoming::{StageInstanceCreate, StageInstanceDelete, StageInstanceUpdate},
-    id::{
-        marker::{GuildMarker, StageMarker},
-        Id,
-    },
+    id::{marker::GuildMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_stage_instances(
-        &self,
-        guild_id: Id<GuildMarker>,
-        stage_instances: impl IntoIterator<Item = StageInstance>,
-    ) {
-        for stage_instance in stage_instances {
-            self.cache_stage_instance(guild_id, stage_instance);
-        }
+pub(crate) fn cache_stage_instances<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    stage_instances: &[StageInstance],
+) -> Result<(), B::Error> {
+    for stage_instance in stage_instances {
+        cache_stage_instance(backend, guild_id, stage_instance)?;
     }
 
-    fn cache_stage_instance(&self, guild_id: Id<GuildMarker>, stage_instance: StageInstance) {
-        self.guild_stage_instances
-            .entry(guild_id)
-            .or_default()
-            .insert(stage_instance.id);
-
-        crate::upsert_guild_item(
-            &self.stage_instances,
-            guild_id,
-            stage_instance.id,
-            CacheModels::StageInstance::from(stage_instance),
-        );
-    }
+    Ok(())
+}
 
-    fn delete_stage_instance(&self, stage_id: Id<StageMarker>) {
-        if let Some((_, data)) = self.stage_instances.remove(&stage_id) {
-            let guild_id = data.guild_id;
+fn cache_stage_instance<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    stage_instance: &StageInstance,
+) -> Result<(), B::Error> {
+    backend.insert_stage_instance(
+        guild_id,
+        stage_instance.id,
+        <B::Models as CacheableModels>::StageInstance::from(stage_instance.clone()),
+    )
+}
 
-            if let Some(mut stage_instances) = self.guild_stage_instances.get_mut(&guild_id) {
-                stage_instances.remove(&stage_id);
-            }
+impl UpdateBackend for StageInstanceCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::STAGE_INSTANCE) {
+            return Ok(());
         }
+
+        cache_stage_instance(backend, self.guild_id, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for StageInstanceCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::STAGE_INSTANCE) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for StageInstanceDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::STAGE_INSTANCE) {
+            return Ok(());
         }
 
-        cache.cache_stage_instance(self.guild_id, self.0.clone());
+        backend.remove_stage_instance(self.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for StageInstanceDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::STAGE_INSTANCE) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for StageInstanceUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::STAGE_INSTANCE) {
+            return Ok(());
         }
 
-        cache.delete_stage_instance(self.id);
+        cache_stage_instance(backend, self.guild_id, &self.0)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for StageInstanceUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::STAGE_INSTANCE) {
-            return;
-        }
-
-        cache.cache_stage_instance(self.guild_id, self.0.clone());
+        cache.apply(self);
     }
 }
 
@@ -1,8 +1,7 @@
-use std::{borrow::Cow, collections::HashSet};
-
 use crate::{
-    config::ResourceType, CacheableModels, CacheableSticker, GuildResource, InMemoryCache,
-    UpdateCache,
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
 };
 use twilight_model::{
     channel::message::Sticker,
@@ -10,72 +9,54 @@ use twilight_model::{
     id::{marker::GuildMarker, Id},
 };
 
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_stickers(&self, guild_id: Id<GuildMarker>, stickers: Vec<Sticker>) {
-        if let Some(mut guild_stickers) = self.guild_stickers.get_mut(&guild_id) {
-            let incoming_sticker_ids = stickers
-                .iter()
-                .map(|sticker| sticker.id)
-                .collect::<HashSet<_>>();
-
-            // Iterate over the set of a guild's stickers, retaining only the
-            // existing stickers that are still present in the updated list of
-            // stickers.
-            //
-            // If one is not, then we remove it both from the guild's set of
-            // stickers and the sticker cache.
-            guild_stickers.retain(|sticker_id| {
-                let retain = incoming_sticker_ids.contains(sticker_id);
-
-                if !retain {
-                    self.stickers.remove(sticker_id);
-                }
-
-                retain
-            });
+pub(crate) fn cache_stickers<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    stickers: &[Sticker],
+) -> Result<(), B::Error> {
+    // Remove the cached stickers of the guild that aren't present in the
+    // updated list of stickers.
+    for sticker_id in backend.guild_sticker_ids(guild_id)? {
+        if !stickers.iter().any(|sticker| sticker.id == sticker_id) {
+            backend.remove_sticker(sticker_id)?;
         }
+    }
 
-        for sticker in stickers {
-            self.cache_sticker(guild_id, sticker);
-        }
+    for sticker in stickers {
+        cache_sticker(backend, guild_id, sticker)?;
     }
 
-    pub(crate) fn cache_sticker(&self, guild_id: Id<GuildMarker>, sticker: Sticker) {
-        if let Some(cached_sticker) = self.stickers.get(&sticker.id) {
-            if cached_sticker.value == sticker {
-                return;
-            }
-        }
+    Ok(())
+}
 
-        if let Some(user) = sticker.user.clone() {
-            self.cache_user(Cow::Owned(user), Some(guild_id));
-        }
+pub(crate) fn cache_sticker<B: CacheBackend>(
+    backend: &B,
+    guild_id: Id<GuildMarker>,
+    sticker: &Sticker,
+) -> Result<(), B::Error> {
+    if let Some(user) = sticker.user.as_ref() {
+        super::cache_user(backend, user, Some(guild_id))?;
+    }
 
-        let sticker_id = sticker.id;
-        let cached = CacheModels::Sticker::from(sticker);
+    backend.insert_sticker(
+        guild_id,
+        <B::Models as CacheableModels>::Sticker::from(sticker.clone()),
+    )
+}
 
-        self.stickers.insert(
-            cached.id(),
-            GuildResource {
-                guild_id,
-                value: cached,
-            },
-        );
+impl UpdateBackend for GuildStickersUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::STICKER) {
+            return Ok(());
+        }
 
-        self.guild_stickers
-            .entry(guild_id)
-            .or_default()
-            .insert(sticker_id);
+        cache_stickers(backend, self.guild_id, &self.stickers)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildStickersUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::STICKER) {
-            return;
-        }
-
-        cache.cache_stickers(self.guild_id, self.stickers.clone());
+        cache.apply(self);
     }
 }
 
@@ -1,44 +1,169 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
-use twilight_model::gateway::payload::incoming::{
-    ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate,
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    traits::CacheableCurrentUser,
+    CacheableModels, InMemoryCache, UpdateCache,
 };
+use twilight_model::{
+    channel::thread::ThreadMember,
+    gateway::payload::incoming::{
+        ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+        ThreadUpdate,
+    },
+    id::{marker::ChannelMarker, Id},
+};
+
+/// Cache a member of a thread.
+///
+/// Thread members without a user ID are the current user.
+fn cache_thread_member<B: CacheBackend>(
+    backend: &B,
+    thread_id: Id<ChannelMarker>,
+    member: &ThreadMember,
+) -> Result<(), B::Error> {
+    let user_id = match member.user_id {
+        Some(user_id) => user_id,
+        None => match backend.current_user()? {
+            Some(user) => user.id(),
+            None => return Ok(()),
+        },
+    };
+
+    backend.insert_thread_member(thread_id, user_id)
+}
+
+impl UpdateBackend for ThreadCreate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
+        }
+
+        super::channel::cache_channel(backend, &self.0)?;
+
+        if let Some(member) = &self.member {
+            cache_thread_member(backend, self.id, member)?;
+        }
+
+        Ok(())
+    }
+}
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+        cache.count_channel(&self.0);
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ThreadDelete {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
         }
 
-        cache.cache_channel(self.0.clone());
+        backend.remove_channel(self.id)
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+        cache.count(self.guild_id, |counter| counter.remove_channel(self.id));
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ThreadListSync {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
+        }
+
+        for thread in &self.threads {
+            super::channel::cache_channel(backend, thread)?;
         }
 
-        cache.delete_channel(self.id);
+        for member in &self.members {
+            if let Some(thread_id) = member.id {
+                cache_thread_member(backend, thread_id, member)?;
+            }
+        }
+
+        Ok(())
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadListSync {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+        cache.count(self.guild_id, |counter| {
+            counter.sync_threads(&self.threads, self.channel_ids.is_empty());
+        });
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ThreadMemberUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
+        }
+
+        if let Some(thread_id) = self.member.id {
+            cache_thread_member(backend, thread_id, &self.member)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadMemberUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ThreadMembersUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
+        }
+
+        for member in &self.added_members {
+            cache_thread_member(backend, self.id, member)?;
+        }
+
+        for user_id in &self.removed_member_ids {
+            backend.remove_thread_member(self.id, *user_id)?;
         }
 
-        cache.cache_channels(self.threads.clone());
+        Ok(())
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadUpdate {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadMembersUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
-            return;
+        cache.apply(self);
+    }
+}
+
+impl UpdateBackend for ThreadUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::CHANNEL) {
+            return Ok(());
         }
 
-        cache.cache_channel(self.0.clone());
+        super::channel::cache_channel(backend, &self.0)?;
+
+        if let Some(member) = &self.member {
+            cache_thread_member(backend, self.id, member)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.count_channel(&self.0);
+        cache.apply(self);
     }
 }
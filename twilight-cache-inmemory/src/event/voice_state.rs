@@ -1,88 +1,67 @@
-use crate::CacheableVoiceState;
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
-use twilight_model::gateway::payload::incoming::VoiceStateUpdate;
-use twilight_model::voice::VoiceState;
-
-impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
-    pub(crate) fn cache_voice_states(&self, voice_states: impl IntoIterator<Item = VoiceState>) {
-        for voice_state in voice_states {
-            self.cache_voice_state(voice_state);
-        }
+use crate::{
+    backend::{wants, CacheBackend, UpdateBackend},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use twilight_model::{gateway::payload::incoming::VoiceStateUpdate, voice::VoiceState};
+
+pub(crate) fn cache_voice_states<B: CacheBackend>(
+    backend: &B,
+    voice_states: &[VoiceState],
+) -> Result<(), B::Error> {
+    for voice_state in voice_states {
+        cache_voice_state(backend, voice_state)?;
     }
 
-    fn cache_voice_state(&self, voice_state: VoiceState) {
-        // This should always exist, but let's check just in case.
-        let Some(guild_id) = voice_state.guild_id else {
-            return;
-        };
-
-        let user_id = voice_state.user_id;
+    Ok(())
+}
 
-        // Check if the user is switching channels in the same guild (ie. they already have a voice state entry)
-        if let Some(voice_state) = self.voice_states.get(&(guild_id, user_id)) {
-            let remove_channel_mapping = self
-                .voice_state_channels
-                .get_mut(&voice_state.channel_id())
-                .is_some_and(|mut channel_voice_states| {
-                    channel_voice_states.remove(&(guild_id, user_id));
+pub(crate) fn cache_voice_state<B: CacheBackend>(
+    backend: &B,
+    voice_state: &VoiceState,
+) -> Result<(), B::Error> {
+    // This should always exist, but let's check just in case.
+    let Some(guild_id) = voice_state.guild_id else {
+        return Ok(());
+    };
 
-                    channel_voice_states.is_empty()
-                });
+    let user_id = voice_state.user_id;
+
+    if let Some(channel_id) = voice_state.channel_id {
+        backend.insert_voice_state(
+            guild_id,
+            user_id,
+            <B::Models as CacheableModels>::VoiceState::from((
+                channel_id,
+                guild_id,
+                voice_state.clone(),
+            )),
+        )
+    } else {
+        // voice channel_id does not exist, signifying that the user has left
+        backend.remove_voice_state(guild_id, user_id)
+    }
+}
 
-            if remove_channel_mapping {
-                self.voice_state_channels.remove(&voice_state.channel_id());
-            }
+impl UpdateBackend for VoiceStateUpdate {
+    fn update_backend<B: CacheBackend>(&self, backend: &B) -> Result<(), B::Error> {
+        if !wants(backend, ResourceType::VOICE_STATE) {
+            return Ok(());
         }
 
-        if let Some(channel_id) = voice_state.channel_id {
-            let cached_voice_state =
-                CacheModels::VoiceState::from((channel_id, guild_id, voice_state));
-
-            self.voice_states
-                .insert((guild_id, user_id), cached_voice_state);
-
-            self.voice_state_guilds
-                .entry(guild_id)
-                .or_default()
-                .insert(user_id);
-
-            self.voice_state_channels
-                .entry(channel_id)
-                .or_default()
-                .insert((guild_id, user_id));
-        } else {
-            // voice channel_id does not exist, signifying that the user has left
-            {
-                let remove_guild =
-                    self.voice_state_guilds
-                        .get_mut(&guild_id)
-                        .is_some_and(|mut guild_users| {
-                            guild_users.remove(&user_id);
-
-                            guild_users.is_empty()
-                        });
-
-                if remove_guild {
-                    self.voice_state_guilds.remove(&guild_id);
-                }
-            }
-
-            self.voice_states.remove(&(guild_id, user_id));
+        cache_voice_state(backend, &self.0)?;
+
+        if let (Some(guild_id), Some(member)) = (self.0.guild_id, &self.0.member) {
+            super::member::cache_member(backend, guild_id, member)?;
         }
+
+        Ok(())
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for VoiceStateUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::VOICE_STATE) {
-            return;
-        }
-
-        cache.cache_voice_state(self.0.clone());
-
-        if let (Some(guild_id), Some(member)) = (self.0.guild_id, &self.0.member) {
-            cache.cache_member(guild_id, member.clone());
-        }
+        cache.apply(self);
     }
 }
 
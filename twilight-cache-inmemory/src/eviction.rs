@@ -0,0 +1,329 @@
+//! Bounding the number of cached entries of resource types.
+
+use crate::{config::EvictionPolicy, CacheableModels, InMemoryCache};
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+use twilight_model::id::{
+    marker::{EmojiMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+/// Position of an entry in the eviction order, the lowest being evicted
+/// first.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Rank {
+    /// Number of uses, always 0 for [`EvictionPolicy::Lru`].
+    uses: u64,
+    /// Tick of the last use, unique across entries.
+    tick: u64,
+}
+
+/// [`Limit`]'s state.
+#[derive(Debug)]
+struct State<K> {
+    /// Entries ordered by their rank.
+    order: BTreeMap<Rank, K>,
+    /// Rank of each entry.
+    ranks: HashMap<K, Rank>,
+    /// Incremented on every use.
+    tick: u64,
+}
+
+/// Maximum number of entries of a resource type, tracking their use to pick
+/// which one to evict.
+///
+/// All operations are no-ops if the resource type is unbounded.
+#[derive(Debug)]
+pub(crate) struct Limit<K> {
+    /// Maximum number of entries, if bounded.
+    capacity: Option<usize>,
+    /// Number of evicted entries.
+    evictions: AtomicU64,
+    /// Policy for choosing which entry to evict.
+    policy: EvictionPolicy,
+    /// Use of the entries.
+    state: Mutex<State<K>>,
+}
+
+impl<K: Clone + Eq + Hash> Limit<K> {
+    /// Create a new limit of `capacity` entries, if any.
+    pub fn new(capacity: Option<usize>, policy: EvictionPolicy) -> Self {
+        Self {
+            capacity,
+            evictions: AtomicU64::new(0),
+            policy,
+            state: Mutex::new(State {
+                order: BTreeMap::new(),
+                ranks: HashMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// Number of evicted entries.
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Record a use of the entry, if tracked.
+    pub fn access(&self, key: &K) {
+        if self.capacity.is_none() {
+            return;
+        }
+
+        let mut state = self.state.lock().expect("eviction state poisoned");
+        if state.ranks.contains_key(key) {
+            self.touch(&mut state, key.clone());
+        }
+    }
+
+    /// Record an insertion or update of the entry, returning the entry to
+    /// evict if over capacity.
+    pub fn insert(&self, key: K) -> Option<K> {
+        let capacity = self.capacity?;
+
+        let mut state = self.state.lock().expect("eviction state poisoned");
+        self.touch(&mut state, key);
+
+        if state.ranks.len() <= capacity {
+            return None;
+        }
+
+        let (_, evicted) = state.order.pop_first()?;
+        state.ranks.remove(&evicted);
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+
+        Some(evicted)
+    }
+
+    /// Stop tracking the entry.
+    pub fn remove(&self, key: &K) {
+        if self.capacity.is_none() {
+            return;
+        }
+
+        let mut state = self.state.lock().expect("eviction state poisoned");
+        if let Some(rank) = state.ranks.remove(key) {
+            state.order.remove(&rank);
+        }
+    }
+
+    /// Stop tracking all entries.
+    pub fn clear(&self) {
+        if self.capacity.is_none() {
+            return;
+        }
+
+        let mut state = self.state.lock().expect("eviction state poisoned");
+        state.order.clear();
+        state.ranks.clear();
+    }
+
+    /// Move the entry to its new rank after a use.
+    fn touch(&self, state: &mut State<K>, key: K) {
+        state.tick += 1;
+
+        let previous = state.ranks.get(&key).copied();
+        if let Some(previous) = previous {
+            state.order.remove(&previous);
+        }
+
+        let uses = match self.policy {
+            EvictionPolicy::Lfu => previous.map_or(1, |rank| rank.uses + 1),
+            EvictionPolicy::Lru => 0,
+        };
+        let rank = Rank {
+            uses,
+            tick: state.tick,
+        };
+
+        state.order.insert(rank, key.clone());
+        state.ranks.insert(key, rank);
+    }
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Track the cached emoji, evicting another one if over capacity.
+    pub(crate) fn track_emoji(&self, emoji_id: Id<EmojiMarker>) {
+        let Some(evicted) = self.emoji_limit.insert(emoji_id) else {
+            return;
+        };
+
+        if let Some(emoji) = self.remove_emoji(evicted) {
+            if let Some(mut guild_emojis) = self.guild_emojis.get_mut(&emoji.guild_id) {
+                guild_emojis.remove(&evicted);
+            }
+        }
+    }
+
+    /// Record a use of the cached member.
+    pub(crate) fn access_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        self.member_limit.access(&(guild_id, user_id));
+
+        if let Some(limit) = self.member_guild_limits.get(&guild_id) {
+            limit.access(&user_id);
+        }
+    }
+
+    /// Track the cached member for expiry, evicting another one of all guilds
+    /// or of its guild if over capacity.
+    pub(crate) fn track_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        self.member_expiry.insert((guild_id, user_id));
+
+        if let Some((guild_id, user_id)) = self.member_limit.insert((guild_id, user_id)) {
+            self.evict_member(guild_id, user_id);
+
+            if let Some(limit) = self.member_guild_limits.get(&guild_id) {
+                limit.remove(&user_id);
+            }
+        }
+
+        if let Some(limit) = self.member_guild_limits.get(&guild_id) {
+            if let Some(user_id) = limit.insert(user_id) {
+                self.evict_member(guild_id, user_id);
+                self.member_limit.remove(&(guild_id, user_id));
+            }
+        }
+    }
+
+    /// Stop tracking the removed member for eviction.
+    pub(crate) fn untrack_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        self.member_limit.remove(&(guild_id, user_id));
+
+        if let Some(limit) = self.member_guild_limits.get(&guild_id) {
+            limit.remove(&user_id);
+        }
+    }
+
+    /// Remove an evicted member.
+    fn evict_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        self.remove_member(guild_id, user_id);
+
+        if let Some(mut guild_members) = self.guild_members.get_mut(&guild_id) {
+            guild_members.remove(&user_id);
+        }
+    }
+
+    /// Track the cached presence for expiry, evicting another one if over
+    /// capacity.
+    pub(crate) fn track_presence(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        self.presence_expiry.insert((guild_id, user_id));
+
+        let Some((guild_id, user_id)) = self.presence_limit.insert((guild_id, user_id)) else {
+            return;
+        };
+
+        self.remove_presence(guild_id, user_id);
+
+        if let Some(mut guild_presences) = self.guild_presences.get_mut(&guild_id) {
+            guild_presences.remove(&user_id);
+        }
+    }
+
+    /// Track the cached user for expiry, evicting another one if over
+    /// capacity.
+    pub(crate) fn track_user(&self, user_id: Id<UserMarker>) {
+        self.user_expiry.insert(user_id);
+
+        let Some(user_id) = self.user_limit.insert(user_id) else {
+            return;
+        };
+
+        self.remove_user(user_id);
+        self.user_guilds.remove(&user_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Limit;
+    use crate::{config::EvictionPolicy, test, DefaultInMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::{borrow::Cow, fmt::Debug};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(Limit<u64>: Debug, Send, Sync);
+
+    #[test]
+    fn lru() {
+        let limit = Limit::new(Some(2), EvictionPolicy::Lru);
+        assert_eq!(None, limit.insert(1));
+        assert_eq!(None, limit.insert(2));
+        limit.access(&1);
+        assert_eq!(Some(2), limit.insert(3));
+        limit.remove(&1);
+        assert_eq!(None, limit.insert(4));
+        assert_eq!(Some(3), limit.insert(5));
+        assert_eq!(2, limit.evictions());
+    }
+
+    #[test]
+    fn lfu() {
+        let limit = Limit::new(Some(2), EvictionPolicy::Lfu);
+        limit.insert(1);
+        limit.access(&1);
+        limit.insert(2);
+        assert_eq!(Some(2), limit.insert(3));
+        assert_eq!(Some(3), limit.insert(4));
+    }
+
+    #[test]
+    fn unbounded() {
+        let limit = Limit::new(None, EvictionPolicy::Lru);
+        for key in 0..10 {
+            assert_eq!(None, limit.insert(key));
+        }
+        assert_eq!(0, limit.evictions());
+    }
+
+    #[test]
+    fn evict_users() {
+        let cache = DefaultInMemoryCache::builder().user_cache_size(2).build();
+        cache.cache_user(Cow::Owned(test::user(Id::new(1))), Some(Id::new(10)));
+        cache.cache_user(Cow::Owned(test::user(Id::new(2))), Some(Id::new(10)));
+        assert!(cache.user(Id::new(1)).is_some());
+        cache.cache_user(Cow::Owned(test::user(Id::new(3))), Some(Id::new(10)));
+
+        assert!(cache.user(Id::new(1)).is_some());
+        assert!(cache.user(Id::new(2)).is_none());
+        assert!(cache.user_guilds(Id::new(2)).is_none());
+        assert!(cache.user(Id::new(3)).is_some());
+        assert_eq!(1, cache.stats().user_evictions());
+    }
+
+    #[test]
+    fn evict_members() {
+        let cache = DefaultInMemoryCache::builder().member_cache_size(1).build();
+        let guild_id = Id::new(1);
+        cache.cache_member(guild_id, test::member(Id::new(2)));
+        cache.cache_member(guild_id, test::member(Id::new(3)));
+
+        assert!(cache.member(guild_id, Id::new(2)).is_none());
+        assert!(cache.member(guild_id, Id::new(3)).is_some());
+        assert_eq!(Some(1), cache.stats().guild_members(guild_id),);
+        assert_eq!(1, cache.stats().member_evictions());
+    }
+
+    #[test]
+    fn evict_guild_members() {
+        let guild_id = Id::new(1);
+        let cache = DefaultInMemoryCache::builder()
+            .guild_member_cache_size(guild_id, 1)
+            .build();
+        cache.cache_member(guild_id, test::member(Id::new(2)));
+        cache.cache_member(guild_id, test::member(Id::new(3)));
+        cache.cache_member(Id::new(4), test::member(Id::new(2)));
+        cache.cache_member(Id::new(4), test::member(Id::new(3)));
+
+        assert!(cache.member(guild_id, Id::new(2)).is_none());
+        assert!(cache.member(guild_id, Id::new(3)).is_some());
+        assert_eq!(Some(2), cache.stats().guild_members(Id::new(4)));
+        assert_eq!(1, cache.stats().member_evictions());
+    }
+}
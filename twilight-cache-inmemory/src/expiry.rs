@@ -0,0 +1,207 @@
+//! Expiring cached entries of resource types after their time-to-live.
+
+use crate::{CacheableModels, InMemoryCache};
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker},
+    Id,
+};
+
+/// [`Expiry`]'s state.
+#[derive(Debug)]
+struct State<K> {
+    /// Time of the last write of each entry.
+    latest: HashMap<K, Instant>,
+    /// Writes ordered by time, including ones superseded by later writes.
+    queue: VecDeque<(Instant, K)>,
+}
+
+/// Time-to-live of the entries of a resource type, counted from their last
+/// write.
+///
+/// Entries removed from the cache are not untracked, so expiring them later
+/// has no effect.
+///
+/// All operations are no-ops if entries of the resource type never expire.
+#[derive(Debug)]
+pub(crate) struct Expiry<K> {
+    /// Duration after which entries expire, if any.
+    ttl: Option<Duration>,
+    /// Write times of the entries.
+    state: Mutex<State<K>>,
+}
+
+impl<K: Clone + Eq + Hash> Expiry<K> {
+    /// Create a new expiry of entries `ttl` after their last write, if any.
+    pub fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl,
+            state: Mutex::new(State {
+                latest: HashMap::new(),
+                queue: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Record a write of the entry.
+    pub fn insert(&self, key: K) {
+        if self.ttl.is_none() {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut state = self.state.lock().expect("expiry state poisoned");
+        state.latest.insert(key.clone(), now);
+        state.queue.push_back((now, key));
+    }
+
+    /// Stop tracking and return the entries expired at `now`.
+    pub fn expired(&self, now: Instant) -> Vec<K> {
+        let Some(ttl) = self.ttl else {
+            return Vec::new();
+        };
+
+        let mut state = self.state.lock().expect("expiry state poisoned");
+        let mut expired = Vec::new();
+
+        while let Some((written, _)) = state.queue.front() {
+            if now.saturating_duration_since(*written) < ttl {
+                break;
+            }
+
+            let (written, key) = state.queue.pop_front().expect("queue is non-empty");
+            if state.latest.get(&key) == Some(&written) {
+                state.latest.remove(&key);
+                expired.push(key);
+            }
+        }
+
+        expired
+    }
+
+    /// Stop tracking all entries.
+    pub fn clear(&self) {
+        if self.ttl.is_none() {
+            return;
+        }
+
+        let mut state = self.state.lock().expect("expiry state poisoned");
+        state.latest.clear();
+        state.queue.clear();
+    }
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Track the cached message for expiry.
+    pub(crate) fn track_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) {
+        self.message_expiry.insert((channel_id, message_id));
+    }
+
+    /// Remove entries that outlived their configured time-to-live.
+    ///
+    /// Expired entries are also removed while processing events via
+    /// [`update`], so calling this is only necessary to free memory while no
+    /// events are received, such as from a background task.
+    ///
+    /// [`update`]: Self::update
+    pub fn remove_expired(&self) {
+        let now = Instant::now();
+
+        for (channel_id, message_id) in self.message_expiry.expired(now) {
+            self.remove_message(message_id);
+
+            if let Some(mut channel_messages) = self.channel_messages.get_mut(&channel_id) {
+                if let Some(idx) = channel_messages.iter().position(|id| *id == message_id) {
+                    channel_messages.remove(idx);
+                }
+            }
+        }
+
+        for (guild_id, user_id) in self.member_expiry.expired(now) {
+            self.remove_member(guild_id, user_id);
+            self.untrack_member(guild_id, user_id);
+
+            if let Some(mut guild_members) = self.guild_members.get_mut(&guild_id) {
+                guild_members.remove(&user_id);
+            }
+        }
+
+        for (guild_id, user_id) in self.presence_expiry.expired(now) {
+            self.remove_presence(guild_id, user_id);
+            self.presence_limit.remove(&(guild_id, user_id));
+
+            if let Some(mut guild_presences) = self.guild_presences.get_mut(&guild_id) {
+                guild_presences.remove(&user_id);
+            }
+        }
+
+        for user_id in self.user_expiry.expired(now) {
+            self.remove_user(user_id);
+            self.user_limit.remove(&user_id);
+            self.user_guilds.remove(&user_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expiry;
+    use crate::{test, DefaultInMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::{
+        borrow::Cow,
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+    use twilight_model::id::Id;
+
+    assert_impl_all!(Expiry<u64>: Debug, Send, Sync);
+
+    #[test]
+    fn expired() {
+        let expiry = Expiry::new(Some(Duration::from_secs(60)));
+        expiry.insert(1);
+        expiry.insert(2);
+        expiry.insert(1);
+
+        let now = Instant::now();
+        assert!(expiry.expired(now).is_empty());
+        assert_eq!(vec![2, 1], expiry.expired(now + Duration::from_secs(60)));
+        assert!(expiry.expired(now + Duration::from_secs(120)).is_empty());
+    }
+
+    #[test]
+    fn never() {
+        let expiry = Expiry::new(None);
+        expiry.insert(1);
+        assert!(expiry
+            .expired(Instant::now() + Duration::from_secs(u32::MAX.into()))
+            .is_empty());
+    }
+
+    #[test]
+    fn remove_expired() {
+        let cache = DefaultInMemoryCache::builder()
+            .member_ttl(Duration::ZERO)
+            .user_ttl(Duration::from_secs(3600))
+            .build();
+        let guild_id = Id::new(1);
+        cache.cache_member(guild_id, test::member(Id::new(2)));
+        cache.cache_user(Cow::Owned(test::user(Id::new(3))), None);
+
+        cache.remove_expired();
+        assert!(cache.member(guild_id, Id::new(2)).is_none());
+        assert_eq!(Some(0), cache.stats().guild_members(guild_id));
+        assert!(cache.user(Id::new(2)).is_some());
+        assert!(cache.user(Id::new(3)).is_some());
+    }
+}
@@ -0,0 +1,366 @@
+//! Maintaining secondary indexes of cached resources for common lookups.
+
+use crate::{
+    change::CacheChange,
+    traits::{CacheableChannel, CacheableEmoji, CacheableEntitlement, CacheableMember},
+    CacheableModels, GuildResource, InMemoryCache,
+};
+use dashmap::DashMap;
+use std::{collections::HashSet, hash::Hash};
+use twilight_model::id::{
+    marker::{ChannelMarker, EmojiMarker, EntitlementMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+/// Add a value to the set of a key.
+fn index<K: Eq + Hash, V: Eq + Hash>(map: &DashMap<K, HashSet<V>>, key: K, value: V) {
+    map.entry(key).or_default().insert(value);
+}
+
+/// Remove a value from the set of a key, removing the set once empty.
+fn unindex<K: Eq + Hash, V: Eq + Hash>(map: &DashMap<K, HashSet<V>>, key: &K, value: &V) {
+    if let Some(mut values) = map.get_mut(key) {
+        values.remove(value);
+    }
+
+    map.remove_if(key, |_, values| values.is_empty());
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Insert or replace a channel, updating the indexes by parent.
+    pub(crate) fn insert_channel(&self, channel: CacheModels::Channel) {
+        let channel_id = channel.id();
+
+        if let Some(previous) = self.channels.get(&channel_id) {
+            self.unindex_channel(&previous);
+        }
+
+        self.index_channel(&channel);
+        let new = self.watch(&channel);
+        let old = self.channels.insert(channel_id, channel);
+        self.notify_inserted(CacheChange::Channel, channel_id, old, new);
+    }
+
+    /// Remove a channel, updating the indexes by parent and removing its
+    /// members if it is a thread.
+    pub(crate) fn remove_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Option<CacheModels::Channel> {
+        let (_, channel) = self.channels.remove(&channel_id)?;
+        self.thread_members.remove(&channel_id);
+        self.unindex_channel(&channel);
+        self.notify_removed(CacheChange::Channel, channel_id, &channel);
+
+        Some(channel)
+    }
+
+    /// Insert or replace an emoji, updating the index by name.
+    pub(crate) fn insert_emoji(
+        &self,
+        guild_id: Id<GuildMarker>,
+        emoji_id: Id<EmojiMarker>,
+        emoji: CacheModels::Emoji,
+    ) {
+        if let Some(previous) = self.emojis.get(&emoji_id) {
+            unindex(
+                &self.emoji_names,
+                &(previous.guild_id, previous.value.name().to_owned()),
+                &emoji_id,
+            );
+        }
+
+        index(
+            &self.emoji_names,
+            (guild_id, emoji.name().to_owned()),
+            emoji_id,
+        );
+        let emoji = GuildResource {
+            guild_id,
+            value: emoji,
+        };
+        let new = self.watch(&emoji);
+        let old = self.emojis.insert(emoji_id, emoji);
+        self.notify_inserted(CacheChange::Emoji, emoji_id, old, new);
+    }
+
+    /// Remove an emoji, updating the index by name.
+    pub(crate) fn remove_emoji(
+        &self,
+        emoji_id: Id<EmojiMarker>,
+    ) -> Option<GuildResource<CacheModels::Emoji>> {
+        let (_, emoji) = self.emojis.remove(&emoji_id)?;
+        unindex(
+            &self.emoji_names,
+            &(emoji.guild_id, emoji.value.name().to_owned()),
+            &emoji_id,
+        );
+        self.notify_removed(CacheChange::Emoji, emoji_id, &emoji);
+
+        Some(emoji)
+    }
+
+    /// Insert or replace an entitlement, updating the indexes by guild, SKU,
+    /// and user.
+    pub(crate) fn insert_entitlement(
+        &self,
+        entitlement_id: Id<EntitlementMarker>,
+        entitlement: CacheModels::Entitlement,
+    ) {
+        if let Some(previous) = self.entitlements.get(&entitlement_id) {
+            self.unindex_entitlement(entitlement_id, &previous);
+        }
+
+        self.index_entitlement(entitlement_id, &entitlement);
+        let new = self.watch(&entitlement);
+        let old = self.entitlements.insert(entitlement_id, entitlement);
+        self.notify_inserted(CacheChange::Entitlement, entitlement_id, old, new);
+    }
+
+    /// Remove an entitlement, updating the indexes by guild, SKU, and user.
+    pub(crate) fn remove_entitlement(&self, entitlement_id: Id<EntitlementMarker>) {
+        if let Some((_, entitlement)) = self.entitlements.remove(&entitlement_id) {
+            self.unindex_entitlement(entitlement_id, &entitlement);
+            self.notify_removed(CacheChange::Entitlement, entitlement_id, &entitlement);
+        }
+    }
+
+    /// Insert or replace a member, updating the indexes by role.
+    pub(crate) fn insert_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        member: CacheModels::Member,
+    ) {
+        if let Some(previous) = self.members.get(&(guild_id, user_id)) {
+            self.unindex_member(user_id, &previous);
+        }
+
+        self.index_member(user_id, &member);
+        let new = self.watch(&member);
+        let old = self.members.insert((guild_id, user_id), member);
+        self.notify_inserted(CacheChange::Member, (guild_id, user_id), old, new);
+    }
+
+    /// Remove a member, updating the indexes by role.
+    pub(crate) fn remove_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if let Some((_, member)) = self.members.remove(&(guild_id, user_id)) {
+            self.unindex_member(user_id, &member);
+            self.notify_removed(CacheChange::Member, (guild_id, user_id), &member);
+        }
+    }
+
+    /// Add a member to the indexes of its roles.
+    pub(crate) fn index_member(&self, user_id: Id<UserMarker>, member: &CacheModels::Member) {
+        for role_id in member.roles() {
+            index(&self.role_members, *role_id, user_id);
+        }
+    }
+
+    /// Remove a member from the indexes of its roles.
+    pub(crate) fn unindex_member(&self, user_id: Id<UserMarker>, member: &CacheModels::Member) {
+        for role_id in member.roles() {
+            unindex(&self.role_members, role_id, &user_id);
+        }
+    }
+
+    /// Rebuild the indexes from the cached resources.
+    #[cfg(feature = "snapshot")]
+    pub(crate) fn reindex(&self) {
+        for channel in &self.channels {
+            self.index_channel(channel.value());
+        }
+
+        for emoji in &self.emojis {
+            index(
+                &self.emoji_names,
+                (emoji.guild_id, emoji.value.name().to_owned()),
+                *emoji.key(),
+            );
+        }
+
+        for member in &self.members {
+            self.index_member(member.key().1, member.value());
+        }
+    }
+
+    /// Add an entitlement to the indexes of its guild, SKU, and user.
+    fn index_entitlement(
+        &self,
+        entitlement_id: Id<EntitlementMarker>,
+        entitlement: &CacheModels::Entitlement,
+    ) {
+        if let Some(guild_id) = entitlement.guild_id() {
+            index(&self.guild_entitlements, guild_id, entitlement_id);
+        }
+
+        index(&self.sku_entitlements, entitlement.sku_id(), entitlement_id);
+
+        if let Some(user_id) = entitlement.user_id() {
+            index(&self.user_entitlements, user_id, entitlement_id);
+        }
+    }
+
+    /// Remove an entitlement from the indexes of its guild, SKU, and user.
+    fn unindex_entitlement(
+        &self,
+        entitlement_id: Id<EntitlementMarker>,
+        entitlement: &CacheModels::Entitlement,
+    ) {
+        if let Some(guild_id) = entitlement.guild_id() {
+            unindex(&self.guild_entitlements, &guild_id, &entitlement_id);
+        }
+
+        unindex(
+            &self.sku_entitlements,
+            &entitlement.sku_id(),
+            &entitlement_id,
+        );
+
+        if let Some(user_id) = entitlement.user_id() {
+            unindex(&self.user_entitlements, &user_id, &entitlement_id);
+        }
+    }
+
+    /// Add a channel to the index of its parent.
+    fn index_channel(&self, channel: &CacheModels::Channel) {
+        if let Some(parent_id) = channel.parent_id() {
+            let map = if channel.kind().is_thread() {
+                &self.channel_threads
+            } else {
+                &self.channel_children
+            };
+
+            index(map, parent_id, channel.id());
+        }
+    }
+
+    /// Remove a channel from the index of its parent.
+    fn unindex_channel(&self, channel: &CacheModels::Channel) {
+        if let Some(parent_id) = channel.parent_id() {
+            let map = if channel.kind().is_thread() {
+                &self.channel_threads
+            } else {
+                &self.channel_children
+            };
+
+            unindex(map, &parent_id, &channel.id());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, DefaultInMemoryCache};
+    use twilight_model::{
+        channel::ChannelType,
+        gateway::payload::incoming::{
+            ChannelUpdate, GuildDelete, GuildEmojisUpdate, MemberRemove, MemberUpdate,
+        },
+        id::Id,
+    };
+
+    #[test]
+    fn role_members() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let mut member = test::member(Id::new(2));
+        member.roles = vec![Id::new(3), Id::new(4)];
+        cache.cache_member(guild_id, member.clone());
+        cache.cache_member(guild_id, test::member(Id::new(5)));
+
+        assert!(cache
+            .role_members(Id::new(3))
+            .unwrap()
+            .contains(&Id::new(2)));
+        assert_eq!(1, cache.role_members(Id::new(4)).unwrap().len());
+
+        cache.update(&MemberUpdate {
+            avatar: None,
+            communication_disabled_until: None,
+            guild_id,
+            deaf: None,
+            joined_at: member.joined_at,
+            mute: None,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: vec![Id::new(4)],
+            user: member.user.clone(),
+        });
+        assert!(cache.role_members(Id::new(3)).is_none());
+        assert!(cache
+            .role_members(Id::new(4))
+            .unwrap()
+            .contains(&Id::new(2)));
+
+        cache.update(&MemberRemove {
+            guild_id,
+            user: member.user,
+        });
+        assert!(cache.role_members(Id::new(4)).is_none());
+    }
+
+    #[test]
+    fn channel_children_and_threads() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, channel_id, mut channel) = test::guild_channel_text();
+        channel.parent_id = Some(Id::new(10));
+        cache.cache_channel(channel.clone());
+
+        let mut thread = channel.clone();
+        thread.id = Id::new(20);
+        thread.kind = ChannelType::PublicThread;
+        thread.parent_id = Some(channel_id);
+        cache.cache_channel(thread);
+
+        assert!(cache
+            .channel_children(Id::new(10))
+            .unwrap()
+            .contains(&channel_id));
+        assert!(cache
+            .channel_threads(channel_id)
+            .unwrap()
+            .contains(&Id::new(20)));
+
+        channel.parent_id = Some(Id::new(11));
+        cache.update(&ChannelUpdate(channel));
+        assert!(cache.channel_children(Id::new(10)).is_none());
+        assert!(cache.channel_children(Id::new(11)).is_some());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+        assert!(cache.channel_children(Id::new(11)).is_none());
+        assert!(cache.channel_threads(channel_id).is_none());
+    }
+
+    #[test]
+    fn emojis_by_name() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let emoji = test::emoji(Id::new(2), None);
+        let name = emoji.name.clone();
+        let mut renamed = test::emoji(Id::new(3), None);
+        renamed.name = "renamed".to_owned();
+        cache.cache_emojis(guild_id, vec![emoji.clone(), renamed.clone()]);
+
+        assert!(cache
+            .guild_emojis_by_name(guild_id, &name)
+            .unwrap()
+            .contains(&Id::new(2)));
+        assert!(cache.guild_emojis_by_name(Id::new(4), &name).is_none());
+
+        renamed.name.clone_from(&name);
+        cache.update(&GuildEmojisUpdate {
+            emojis: vec![renamed],
+            guild_id,
+        });
+        let by_name = cache.guild_emojis_by_name(guild_id, &name).unwrap();
+        assert_eq!(1, by_name.len());
+        assert!(by_name.contains(&Id::new(3)));
+        drop(by_name);
+        assert!(cache.guild_emojis_by_name(guild_id, "renamed").is_none());
+    }
+}
@@ -12,13 +12,14 @@
 //! underlying key and value. It also implements [`std::ops::Deref`] and
 //! dereferences to the value.
 
-use crate::{CacheableModels, GuildResource, InMemoryCache};
+use crate::{model::CachedBan, CacheableModels, GuildResource, InMemoryCache};
 use dashmap::{iter::Iter, mapref::multiple::RefMulti};
 use std::{hash::Hash, ops::Deref};
 use twilight_model::id::{
     marker::{
-        ChannelMarker, EmojiMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
-        StageMarker, StickerMarker, UserMarker,
+        AutoModerationRuleMarker, ChannelMarker, EmojiMarker, EntitlementMarker, GuildMarker,
+        IntegrationMarker, MessageMarker, RoleMarker, ScheduledEventMarker, StageMarker,
+        StickerMarker, UserMarker,
     },
     Id,
 };
@@ -136,6 +137,22 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
         self.0
     }
 
+    /// Create an iterator over the auto moderation rules in the cache.
+    pub fn auto_moderation_rules(
+        &self,
+    ) -> ResourceIter<
+        'a,
+        Id<AutoModerationRuleMarker>,
+        GuildResource<CacheModels::AutoModerationRule>,
+    > {
+        ResourceIter::new(self.0.auto_moderation_rules.iter())
+    }
+
+    /// Create an iterator over the bans in the cache.
+    pub fn bans(&self) -> ResourceIter<'a, (Id<GuildMarker>, Id<UserMarker>), CachedBan> {
+        ResourceIter::new(self.0.bans.iter())
+    }
+
     /// Create an iterator over the channels in the cache.
     pub fn channels(&self) -> ResourceIter<'a, Id<ChannelMarker>, CacheModels::Channel> {
         ResourceIter::new(self.0.channels.iter())
@@ -146,6 +163,13 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
         ResourceIter::new(self.0.emojis.iter())
     }
 
+    /// Create an iterator over the entitlements in the cache.
+    pub fn entitlements(
+        &self,
+    ) -> ResourceIter<'a, Id<EntitlementMarker>, CacheModels::Entitlement> {
+        ResourceIter::new(self.0.entitlements.iter())
+    }
+
     /// Create an iterator over the guilds in the cache.
     pub fn guilds(&self) -> ResourceIter<'a, Id<GuildMarker>, CacheModels::Guild> {
         ResourceIter::new(self.0.guilds.iter())
@@ -187,6 +211,14 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
         ResourceIter::new(self.0.roles.iter())
     }
 
+    /// Create an iterator over the scheduled events in the cache.
+    pub fn scheduled_events(
+        &self,
+    ) -> ResourceIter<'a, Id<ScheduledEventMarker>, GuildResource<CacheModels::GuildScheduledEvent>>
+    {
+        ResourceIter::new(self.0.scheduled_events.iter())
+    }
+
     /// Create an iterator over the stage instances in the cache.
     pub fn stage_instances(
         &self,
@@ -12,6 +12,8 @@
     clippy::unnecessary_wraps
 )]
 
+pub mod backend;
+pub mod change;
 pub mod iter;
 pub mod model;
 pub mod traits;
@@ -19,9 +21,17 @@ pub mod traits;
 #[cfg(feature = "permission-calculator")]
 pub mod permission;
 
+#[cfg(feature = "snapshot")]
+mod snapshot;
+
 mod builder;
 mod config;
+mod counts;
 mod event;
+mod eviction;
+mod expiry;
+mod index;
+mod retention;
 mod stats;
 
 #[cfg(test)]
@@ -29,39 +39,53 @@ mod test;
 
 pub use self::{
     builder::InMemoryCacheBuilder,
-    config::{Config, ResourceType},
-    stats::InMemoryCacheStats,
+    config::{Config, EvictionPolicy, ResourceField, ResourceType},
+    counts::GuildCounts,
+    stats::{InMemoryCacheStats, ResourceUsage},
     traits::{
-        CacheableChannel, CacheableCurrentUser, CacheableEmoji, CacheableGuild,
-        CacheableGuildIntegration, CacheableMember, CacheableMessage, CacheableModels,
-        CacheablePresence, CacheableRole, CacheableStageInstance, CacheableSticker, CacheableUser,
-        CacheableVoiceState,
+        CacheableAutoModerationRule, CacheableChannel, CacheableCurrentUser, CacheableEmoji,
+        CacheableEntitlement, CacheableGuild, CacheableGuildIntegration, CacheableMember,
+        CacheableMessage, CacheableModels, CacheablePresence, CacheableRole,
+        CacheableStageInstance, CacheableSticker, CacheableUser, CacheableVoiceState,
     },
 };
 
 #[cfg(feature = "permission-calculator")]
 pub use self::permission::InMemoryCachePermissions;
 
-use self::iter::InMemoryCacheIter;
+#[cfg(feature = "snapshot")]
+pub use self::snapshot::SnapshotModels;
+
+use self::{
+    change::{CacheChange, Subscribers},
+    counts::GuildCounter,
+    eviction::Limit,
+    expiry::Expiry,
+    iter::InMemoryCacheIter,
+};
 use dashmap::{
     mapref::{entry::Entry, one::Ref},
     DashMap, DashSet,
 };
 use std::{
-    collections::{HashSet, VecDeque},
+    cmp::Reverse,
+    collections::{HashMap, HashSet, VecDeque},
     fmt::{Debug, Formatter, Result as FmtResult},
     hash::Hash,
     ops::Deref,
     sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
 };
 use twilight_model::{
-    channel::{Channel, StageInstance},
+    application::monetization::Entitlement,
+    channel::{Channel, ChannelType, StageInstance},
     gateway::event::Event,
-    guild::{scheduled_event::GuildScheduledEvent, GuildIntegration, Role},
+    guild::{auto_moderation::AutoModerationRule, GuildIntegration, Role},
     id::{
         marker::{
-            ChannelMarker, EmojiMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
-            ScheduledEventMarker, StageMarker, StickerMarker, UserMarker,
+            AutoModerationRuleMarker, ChannelMarker, EmojiMarker, EntitlementMarker, GuildMarker,
+            IntegrationMarker, MessageMarker, RoleMarker, ScheduledEventMarker, SkuMarker,
+            StageMarker, StickerMarker, UserMarker,
         },
         Id,
     },
@@ -74,6 +98,7 @@ use twilight_model::{
 /// ID. In lieu of the resource itself storing its guild's ID this relation
 /// includes it.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize, serde::Serialize))]
 pub struct GuildResource<T> {
     guild_id: Id<GuildMarker>,
     value: T,
@@ -192,14 +217,26 @@ fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
 #[derive(Debug)]
 pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
     config: Config,
+    auto_moderation_rules:
+        DashMap<Id<AutoModerationRuleMarker>, GuildResource<CacheModels::AutoModerationRule>>,
+    bans: DashMap<(Id<GuildMarker>, Id<UserMarker>), model::CachedBan>,
     channels: DashMap<Id<ChannelMarker>, CacheModels::Channel>,
+    channel_children: DashMap<Id<ChannelMarker>, HashSet<Id<ChannelMarker>>>,
     channel_messages: DashMap<Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
+    channel_threads: DashMap<Id<ChannelMarker>, HashSet<Id<ChannelMarker>>>,
     // So long as the lock isn't held across await or panic points this is fine.
     current_user: Mutex<Option<CacheModels::CurrentUser>>,
     emojis: DashMap<Id<EmojiMarker>, GuildResource<CacheModels::Emoji>>,
+    emoji_limit: Limit<Id<EmojiMarker>>,
+    emoji_names: DashMap<(Id<GuildMarker>, String), HashSet<Id<EmojiMarker>>>,
+    entitlements: DashMap<Id<EntitlementMarker>, CacheModels::Entitlement>,
     guilds: DashMap<Id<GuildMarker>, CacheModels::Guild>,
+    guild_auto_moderation_rules: DashMap<Id<GuildMarker>, HashSet<Id<AutoModerationRuleMarker>>>,
+    guild_bans: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
     guild_channels: DashMap<Id<GuildMarker>, HashSet<Id<ChannelMarker>>>,
+    guild_counters: DashMap<Id<GuildMarker>, GuildCounter>,
     guild_emojis: DashMap<Id<GuildMarker>, HashSet<Id<EmojiMarker>>>,
+    guild_entitlements: DashMap<Id<GuildMarker>, HashSet<Id<EntitlementMarker>>>,
     guild_integrations: DashMap<Id<GuildMarker>, HashSet<Id<IntegrationMarker>>>,
     guild_members: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
     guild_presences: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
@@ -212,15 +249,28 @@ pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
         GuildResource<CacheModels::GuildIntegration>,
     >,
     members: DashMap<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Member>,
+    member_expiry: Expiry<(Id<GuildMarker>, Id<UserMarker>)>,
+    member_limit: Limit<(Id<GuildMarker>, Id<UserMarker>)>,
+    member_guild_limits: HashMap<Id<GuildMarker>, Limit<Id<UserMarker>>>,
     messages: DashMap<Id<MessageMarker>, CacheModels::Message>,
+    message_expiry: Expiry<(Id<ChannelMarker>, Id<MessageMarker>)>,
     presences: DashMap<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Presence>,
+    presence_expiry: Expiry<(Id<GuildMarker>, Id<UserMarker>)>,
+    presence_limit: Limit<(Id<GuildMarker>, Id<UserMarker>)>,
     roles: DashMap<Id<RoleMarker>, GuildResource<CacheModels::Role>>,
+    role_members: DashMap<Id<RoleMarker>, HashSet<Id<UserMarker>>>,
     scheduled_events:
         DashMap<Id<ScheduledEventMarker>, GuildResource<CacheModels::GuildScheduledEvent>>,
+    sku_entitlements: DashMap<Id<SkuMarker>, HashSet<Id<EntitlementMarker>>>,
     stage_instances: DashMap<Id<StageMarker>, GuildResource<CacheModels::StageInstance>>,
     stickers: DashMap<Id<StickerMarker>, GuildResource<CacheModels::Sticker>>,
+    subscribers: Subscribers<CacheChange<CacheModels>>,
+    thread_members: DashMap<Id<ChannelMarker>, HashSet<Id<UserMarker>>>,
     unavailable_guilds: DashSet<Id<GuildMarker>>,
     users: DashMap<Id<UserMarker>, CacheModels::User>,
+    user_entitlements: DashMap<Id<UserMarker>, HashSet<Id<EntitlementMarker>>>,
+    user_expiry: Expiry<Id<UserMarker>>,
+    user_limit: Limit<Id<UserMarker>>,
     user_guilds: DashMap<Id<UserMarker>, HashSet<Id<GuildMarker>>>,
     /// Mapping of channels and the users currently connected.
     #[allow(clippy::type_complexity)]
@@ -236,9 +286,11 @@ pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
 pub struct DefaultCacheModels;
 
 impl CacheableModels for DefaultCacheModels {
+    type AutoModerationRule = AutoModerationRule;
     type Channel = Channel;
     type CurrentUser = CurrentUser;
     type Emoji = model::CachedEmoji;
+    type Entitlement = Entitlement;
     type Guild = model::CachedGuild;
     type GuildIntegration = GuildIntegration;
     type Member = model::CachedMember;
@@ -249,7 +301,7 @@ impl CacheableModels for DefaultCacheModels {
     type Sticker = model::CachedSticker;
     type User = User;
     type VoiceState = model::CachedVoiceState;
-    type GuildScheduledEvent = GuildScheduledEvent;
+    type GuildScheduledEvent = model::CachedGuildScheduledEvent;
 }
 
 /// The default implementation of [`InMemoryCache`].
@@ -287,16 +339,27 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     /// This is equal to creating a new empty cache.
     #[allow(clippy::missing_panics_doc)]
     pub fn clear(&self) {
+        self.auto_moderation_rules.clear();
+        self.bans.clear();
         self.channels.clear();
+        self.channel_children.clear();
         self.channel_messages.clear();
+        self.channel_threads.clear();
         self.current_user
             .lock()
             .expect("current user poisoned")
             .take();
         self.emojis.clear();
+        self.emoji_limit.clear();
+        self.emoji_names.clear();
+        self.entitlements.clear();
         self.guilds.clear();
+        self.guild_auto_moderation_rules.clear();
+        self.guild_bans.clear();
         self.guild_channels.clear();
+        self.guild_counters.clear();
         self.guild_emojis.clear();
+        self.guild_entitlements.clear();
         self.guild_integrations.clear();
         self.guild_members.clear();
         self.guild_presences.clear();
@@ -305,12 +368,28 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.guild_stickers.clear();
         self.integrations.clear();
         self.members.clear();
+        self.member_expiry.clear();
+        self.member_limit.clear();
+
+        for limit in self.member_guild_limits.values() {
+            limit.clear();
+        }
+
         self.messages.clear();
+        self.message_expiry.clear();
         self.presences.clear();
+        self.presence_expiry.clear();
+        self.presence_limit.clear();
         self.roles.clear();
+        self.role_members.clear();
+        self.sku_entitlements.clear();
         self.stickers.clear();
+        self.thread_members.clear();
         self.unavailable_guilds.clear();
         self.users.clear();
+        self.user_entitlements.clear();
+        self.user_expiry.clear();
+        self.user_limit.clear();
         self.voice_state_channels.clear();
         self.voice_state_guilds.clear();
         self.voice_states.clear();
@@ -402,8 +481,11 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     }
 
     /// Update the cache with an event from the gateway.
-    pub fn update(&self, value: &impl UpdateCache<CacheModels>) {
+    ///
+    /// Also removes entries that outlived their configured time-to-live.
+    pub fn update<T: UpdateCache<CacheModels> + ?Sized>(&self, value: &T) {
         value.update(self);
+        self.remove_expired();
     }
 
     /// Gets the current user.
@@ -415,6 +497,38 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             .clone()
     }
 
+    /// Gets an auto moderation rule by ID.
+    ///
+    /// This requires the [`AUTO_MODERATION_CONFIGURATION`] intent.
+    ///
+    /// [`AUTO_MODERATION_CONFIGURATION`]: ::twilight_model::gateway::Intents::AUTO_MODERATION_CONFIGURATION
+    pub fn auto_moderation_rule(
+        &self,
+        rule_id: Id<AutoModerationRuleMarker>,
+    ) -> Option<
+        Reference<'_, Id<AutoModerationRuleMarker>, GuildResource<CacheModels::AutoModerationRule>>,
+    > {
+        self.auto_moderation_rules.get(&rule_id).map(Reference::new)
+    }
+
+    /// Gets the ban of a user from a guild.
+    ///
+    /// Bans are only known if they were banned while the guild was
+    /// available or were seeded via [`cache_bans`].
+    ///
+    /// This requires the [`GUILD_MODERATION`] intent.
+    ///
+    /// [`GUILD_MODERATION`]: ::twilight_model::gateway::Intents::GUILD_MODERATION
+    /// [`cache_bans`]: Self::cache_bans
+    #[allow(clippy::type_complexity)]
+    pub fn ban(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), model::CachedBan>> {
+        self.bans.get(&(guild_id, user_id)).map(Reference::new)
+    }
+
     /// Gets a channel by ID.
     pub fn channel(
         &self,
@@ -423,6 +537,21 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.channels.get(&channel_id).map(Reference::new)
     }
 
+    /// Gets the set of channels in a category.
+    ///
+    /// Threads are not included; refer to [`channel_threads`] for them.
+    ///
+    /// This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`channel_threads`]: Self::channel_threads
+    pub fn channel_children(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Option<Reference<'_, Id<ChannelMarker>, HashSet<Id<ChannelMarker>>>> {
+        self.channel_children.get(&channel_id).map(Reference::new)
+    }
+
     /// Gets the set of messages in a channel.
     ///
     /// This requires the [`DIRECT_MESSAGES`] or [`GUILD_MESSAGES`] intents.
@@ -436,6 +565,18 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.channel_messages.get(&channel_id).map(Reference::new)
     }
 
+    /// Gets the set of threads in a channel.
+    ///
+    /// This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn channel_threads(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Option<Reference<'_, Id<ChannelMarker>, HashSet<Id<ChannelMarker>>>> {
+        self.channel_threads.get(&channel_id).map(Reference::new)
+    }
+
     /// Gets an emoji by ID.
     ///
     /// This requires the [`GUILD_EMOJIS_AND_STICKERS`] intent.
@@ -445,9 +586,34 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         &self,
         emoji_id: Id<EmojiMarker>,
     ) -> Option<Reference<'_, Id<EmojiMarker>, GuildResource<CacheModels::Emoji>>> {
+        self.emoji_limit.access(&emoji_id);
+
         self.emojis.get(&emoji_id).map(Reference::new)
     }
 
+    /// Gets an entitlement by ID.
+    ///
+    /// Entitlements are sent to every bot, so no intent is required. The
+    /// cache starts out empty and only learns of entitlements created or
+    /// updated while running, so seed it with the entitlements returned by
+    /// the List Entitlements endpoint on startup:
+    ///
+    /// ```
+    /// # use twilight_model::application::monetization::Entitlement;
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    ///
+    /// # let entitlements: Vec<Entitlement> = Vec::new();
+    /// // Entitlements returned by the List Entitlements endpoint.
+    /// let cache = DefaultInMemoryCache::new();
+    /// cache.update(entitlements.as_slice());
+    /// ```
+    pub fn entitlement(
+        &self,
+        entitlement_id: Id<EntitlementMarker>,
+    ) -> Option<Reference<'_, Id<EntitlementMarker>, CacheModels::Entitlement>> {
+        self.entitlements.get(&entitlement_id).map(Reference::new)
+    }
+
     /// Gets a guild by ID.
     ///
     /// This requires the [`GUILDS`] intent.
@@ -460,6 +626,34 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.guilds.get(&guild_id).map(Reference::new)
     }
 
+    /// Gets the set of auto moderation rules in a guild.
+    ///
+    /// This requires both the [`GUILDS`] and [`AUTO_MODERATION_CONFIGURATION`]
+    /// intents.
+    ///
+    /// [`AUTO_MODERATION_CONFIGURATION`]: ::twilight_model::gateway::Intents::AUTO_MODERATION_CONFIGURATION
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_auto_moderation_rules(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<AutoModerationRuleMarker>>>> {
+        self.guild_auto_moderation_rules
+            .get(&guild_id)
+            .map(Reference::new)
+    }
+
+    /// Gets the set of banned users of a guild.
+    ///
+    /// This requires the [`GUILD_MODERATION`] intent.
+    ///
+    /// [`GUILD_MODERATION`]: ::twilight_model::gateway::Intents::GUILD_MODERATION
+    pub fn guild_bans(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<UserMarker>>>> {
+        self.guild_bans.get(&guild_id).map(Reference::new)
+    }
+
     /// Gets the set of channels in a guild.
     ///
     /// This requires the [`GUILDS`] intent.
@@ -486,6 +680,34 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.guild_emojis.get(&guild_id).map(Reference::new)
     }
 
+    /// Gets the set of emojis in a guild with a name.
+    ///
+    /// This requires both the [`GUILDS`] and [`GUILD_EMOJIS_AND_STICKERS`]
+    /// intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_EMOJIS_AND_STICKERS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS_AND_STICKERS
+    #[allow(clippy::type_complexity)]
+    pub fn guild_emojis_by_name(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Option<Reference<'_, (Id<GuildMarker>, String), HashSet<Id<EmojiMarker>>>> {
+        self.emoji_names
+            .get(&(guild_id, name.to_owned()))
+            .map(Reference::new)
+    }
+
+    /// Gets the set of entitlements granted to a guild.
+    ///
+    /// Entitlements are sent to every bot, so no intent is required.
+    pub fn guild_entitlements(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<EntitlementMarker>>>> {
+        self.guild_entitlements.get(&guild_id).map(Reference::new)
+    }
+
     /// Gets the set of integrations in a guild.
     ///
     /// This requires the [`GUILD_INTEGRATIONS`] intent. The
@@ -630,6 +852,8 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         guild_id: Id<GuildMarker>,
         user_id: Id<UserMarker>,
     ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CacheModels::Member>> {
+        self.access_member(guild_id, user_id);
+
         self.members.get(&(guild_id, user_id)).map(Reference::new)
     }
 
@@ -658,6 +882,8 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         guild_id: Id<GuildMarker>,
         user_id: Id<UserMarker>,
     ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CacheModels::Presence>> {
+        self.presence_limit.access(&(guild_id, user_id));
+
         self.presences.get(&(guild_id, user_id)).map(Reference::new)
     }
 
@@ -673,6 +899,20 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.roles.get(&role_id).map(Reference::new)
     }
 
+    /// Gets the set of members with a role.
+    ///
+    /// This list may be incomplete if not all members have been cached.
+    ///
+    /// This requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn role_members(
+        &self,
+        role_id: Id<RoleMarker>,
+    ) -> Option<Reference<'_, Id<RoleMarker>, HashSet<Id<UserMarker>>>> {
+        self.role_members.get(&role_id).map(Reference::new)
+    }
+
     /// Gets a scheduled event by ID.
     ///
     /// This requires the [`GUILDS`] intent.
@@ -687,6 +927,16 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.scheduled_events.get(&event_id).map(Reference::new)
     }
 
+    /// Gets the set of entitlements to a SKU.
+    ///
+    /// Entitlements are sent to every bot, so no intent is required.
+    pub fn sku_entitlements(
+        &self,
+        sku_id: Id<SkuMarker>,
+    ) -> Option<Reference<'_, Id<SkuMarker>, HashSet<Id<EntitlementMarker>>>> {
+        self.sku_entitlements.get(&sku_id).map(Reference::new)
+    }
+
     /// Gets a stage instance by ID.
     ///
     /// This requires the [`GUILDS`] intent.
@@ -714,6 +964,20 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.stickers.get(&sticker_id).map(Reference::new)
     }
 
+    /// Gets the set of members of a thread.
+    ///
+    /// This requires the [`GUILDS`] intent, and the [`GUILD_MEMBERS`] intent
+    /// for members other than the current user.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn thread_members(
+        &self,
+        thread_id: Id<ChannelMarker>,
+    ) -> Option<Reference<'_, Id<ChannelMarker>, HashSet<Id<UserMarker>>>> {
+        self.thread_members.get(&thread_id).map(Reference::new)
+    }
+
     /// Gets a user by ID.
     ///
     /// This requires the [`GUILD_MEMBERS`] intent.
@@ -723,9 +987,21 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         &self,
         user_id: Id<UserMarker>,
     ) -> Option<Reference<'_, Id<UserMarker>, CacheModels::User>> {
+        self.user_limit.access(&user_id);
+
         self.users.get(&user_id).map(Reference::new)
     }
 
+    /// Gets the set of entitlements granted to a user.
+    ///
+    /// Entitlements are sent to every bot, so no intent is required.
+    pub fn user_entitlements(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Option<Reference<'_, Id<UserMarker>, HashSet<Id<EntitlementMarker>>>> {
+        self.user_entitlements.get(&user_id).map(Reference::new)
+    }
+
     /// Get the guilds a user is in by ID.
     ///
     /// Users are cached from a range of events such as [`InteractionCreate`]
@@ -811,8 +1087,158 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         highest_role.map(|(_, id)| id)
     }
 
+    /// Gets the channels of a guild in the order they're displayed in.
+    ///
+    /// Channels without a category are listed first, followed by each
+    /// category and the channels in it. Text channels are listed before voice
+    /// channels, and channels are otherwise ordered by their position, with
+    /// channels sharing a position ordered by their ID. Threads aren't
+    /// included.
+    ///
+    /// This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: twilight_model::gateway::Intents::GUILDS
+    pub fn guild_channels_sorted(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Option<Vec<Id<ChannelMarker>>> {
+        let channel_ids = self.guild_channels.get(&guild_id)?;
+
+        let mut channels = channel_ids
+            .iter()
+            .filter_map(|channel_id| self.channels.get(channel_id))
+            .filter(|channel| !channel.kind().is_thread())
+            .map(|channel| {
+                let kind = channel.kind();
+                let voice = matches!(kind, ChannelType::GuildStageVoice | ChannelType::GuildVoice);
+
+                (
+                    (voice, channel.position(), channel.id()),
+                    kind == ChannelType::GuildCategory,
+                    channel.parent_id(),
+                )
+            })
+            .collect::<Vec<_>>();
+        drop(channel_ids);
+        channels.sort_unstable_by_key(|(key, ..)| *key);
+
+        let mut children = channels
+            .iter()
+            .filter(|(_, category, _)| *category)
+            .map(|((.., id), ..)| (*id, Vec::new()))
+            .collect::<HashMap<_, _>>();
+        let mut sorted = Vec::with_capacity(channels.len());
+
+        for ((.., id), category, parent_id) in &channels {
+            if *category {
+                continue;
+            }
+
+            match parent_id.and_then(|parent_id| children.get_mut(&parent_id)) {
+                Some(siblings) => siblings.push(*id),
+                None => sorted.push(*id),
+            }
+        }
+
+        for ((.., id), category, _) in &channels {
+            if *category {
+                sorted.push(*id);
+                sorted.extend(children.remove(id).unwrap_or_default());
+            }
+        }
+
+        Some(sorted)
+    }
+
+    /// Gets the roles of a guild in the order they're displayed in, from the
+    /// highest to the lowest role.
+    ///
+    /// Roles are ordered by their position, with roles sharing a position
+    /// ordered by their ID.
+    ///
+    /// This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: twilight_model::gateway::Intents::GUILDS
+    pub fn guild_roles_sorted(&self, guild_id: Id<GuildMarker>) -> Option<Vec<Id<RoleMarker>>> {
+        let role_ids = self.guild_roles.get(&guild_id)?;
+
+        let mut roles = role_ids
+            .iter()
+            .filter_map(|role_id| self.roles.get(role_id))
+            .map(|role| (Reverse(role.position()), role.id()))
+            .collect::<Vec<_>>();
+        roles.sort_unstable();
+
+        Some(roles.into_iter().map(|(_, id)| id).collect())
+    }
+
+    /// Whether a guild has a currently valid entitlement to a SKU.
+    ///
+    /// Entitlements without an end time, such as test entitlements, are valid
+    /// until consumed. Only cached entitlements are considered, refer to
+    /// [`entitlement`] for seeding the cache.
+    ///
+    /// [`entitlement`]: Self::entitlement
+    pub fn guild_has_sku(&self, guild_id: Id<GuildMarker>, sku_id: Id<SkuMarker>) -> bool {
+        self.guild_entitlements
+            .get(&guild_id)
+            .is_some_and(|entitlement_ids| self.has_valid_entitlement(&entitlement_ids, sku_id))
+    }
+
+    /// Whether a user has a currently valid entitlement to a SKU.
+    ///
+    /// Entitlements without an end time, such as test entitlements, are valid
+    /// until consumed. Only cached entitlements are considered, refer to
+    /// [`entitlement`] for seeding the cache.
+    ///
+    /// [`entitlement`]: Self::entitlement
+    pub fn user_has_sku(&self, user_id: Id<UserMarker>, sku_id: Id<SkuMarker>) -> bool {
+        self.user_entitlements
+            .get(&user_id)
+            .is_some_and(|entitlement_ids| self.has_valid_entitlement(&entitlement_ids, sku_id))
+    }
+
+    /// Whether any of the entitlements is to a SKU and hasn't been consumed or
+    /// ended yet.
+    fn has_valid_entitlement(
+        &self,
+        entitlement_ids: &HashSet<Id<EntitlementMarker>>,
+        sku_id: Id<SkuMarker>,
+    ) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_micros());
+
+        entitlement_ids.iter().any(|entitlement_id| {
+            self.entitlements
+                .get(entitlement_id)
+                .is_some_and(|entitlement| {
+                    entitlement.sku_id() == sku_id
+                        && !entitlement.consumed()
+                        && entitlement.ends_at().map_or(true, |ends_at| {
+                            u128::try_from(ends_at.as_micros()).is_ok_and(|ends_at| ends_at > now)
+                        })
+                })
+        })
+    }
+
     fn new_with_config(config: Config) -> Self {
+        let policy = config.eviction_policy();
+
         Self {
+            emoji_limit: Limit::new(config.emoji_cache_size(), policy),
+            member_limit: Limit::new(config.member_cache_size(), policy),
+            member_guild_limits: config
+                .guild_member_cache_sizes
+                .iter()
+                .map(|(guild_id, capacity)| (*guild_id, Limit::new(Some(*capacity), policy)))
+                .collect(),
+            presence_limit: Limit::new(config.presence_cache_size(), policy),
+            user_limit: Limit::new(config.user_cache_size(), policy),
+            member_expiry: Expiry::new(config.member_ttl()),
+            message_expiry: Expiry::new(config.message_ttl()),
+            presence_expiry: Expiry::new(config.presence_ttl()),
+            user_expiry: Expiry::new(config.user_ttl()),
             config,
             ..Self::default()
         }
@@ -830,13 +1256,24 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
 impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
     fn default() -> Self {
         Self {
+            auto_moderation_rules: DashMap::new(),
+            bans: DashMap::new(),
+            channel_children: DashMap::new(),
             channel_messages: DashMap::new(),
+            channel_threads: DashMap::new(),
             channels: DashMap::new(),
             config: Config::default(),
             current_user: Mutex::new(None),
             emojis: DashMap::new(),
+            emoji_limit: Limit::new(None, EvictionPolicy::Lru),
+            emoji_names: DashMap::new(),
+            entitlements: DashMap::new(),
+            guild_auto_moderation_rules: DashMap::new(),
+            guild_bans: DashMap::new(),
             guild_channels: DashMap::new(),
+            guild_counters: DashMap::new(),
             guild_emojis: DashMap::new(),
+            guild_entitlements: DashMap::new(),
             guild_integrations: DashMap::new(),
             guild_members: DashMap::new(),
             guild_presences: DashMap::new(),
@@ -847,15 +1284,28 @@ impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
             guilds: DashMap::new(),
             integrations: DashMap::new(),
             members: DashMap::new(),
+            member_expiry: Expiry::new(None),
+            member_limit: Limit::new(None, EvictionPolicy::Lru),
+            member_guild_limits: HashMap::new(),
             messages: DashMap::new(),
+            message_expiry: Expiry::new(None),
             presences: DashMap::new(),
+            presence_expiry: Expiry::new(None),
+            presence_limit: Limit::new(None, EvictionPolicy::Lru),
             roles: DashMap::new(),
+            role_members: DashMap::new(),
             scheduled_events: DashMap::new(),
+            sku_entitlements: DashMap::new(),
             stage_instances: DashMap::new(),
             stickers: DashMap::new(),
+            subscribers: Subscribers::new(),
+            thread_members: DashMap::new(),
             unavailable_guilds: DashSet::new(),
             user_guilds: DashMap::new(),
             users: DashMap::new(),
+            user_entitlements: DashMap::new(),
+            user_expiry: Expiry::new(None),
+            user_limit: Limit::new(None, EvictionPolicy::Lru),
             voice_state_channels: DashMap::new(),
             voice_state_guilds: DashMap::new(),
             voice_states: DashMap::new(),
@@ -864,29 +1314,47 @@ impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
 }
 
 mod private {
-    use twilight_model::gateway::{
-        event::Event,
-        payload::incoming::{
-            ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate, GuildCreate,
-            GuildDelete, GuildEmojisUpdate, GuildScheduledEventCreate, GuildScheduledEventDelete,
-            GuildScheduledEventUpdate, GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
-            GuildStickersUpdate, GuildUpdate, IntegrationCreate, IntegrationDelete,
-            IntegrationUpdate, InteractionCreate, MemberAdd, MemberChunk, MemberRemove,
-            MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
-            PresenceUpdate, ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
-            Ready, RoleCreate, RoleDelete, RoleUpdate, StageInstanceCreate, StageInstanceDelete,
-            StageInstanceUpdate, ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate,
-            UnavailableGuild, UserUpdate, VoiceStateUpdate,
+    use twilight_model::{
+        application::monetization::Entitlement,
+        gateway::{
+            event::Event,
+            payload::incoming::{
+                AutoModerationRuleCreate, AutoModerationRuleDelete, AutoModerationRuleUpdate,
+                BanAdd, BanRemove, ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate,
+                EntitlementCreate, EntitlementDelete, EntitlementUpdate, GuildAuditLogEntryCreate,
+                GuildCreate, GuildDelete, GuildEmojisUpdate, GuildScheduledEventCreate,
+                GuildScheduledEventDelete, GuildScheduledEventUpdate, GuildScheduledEventUserAdd,
+                GuildScheduledEventUserRemove, GuildStickersUpdate, GuildUpdate, IntegrationCreate,
+                IntegrationDelete, IntegrationUpdate, InteractionCreate, MemberAdd, MemberChunk,
+                MemberRemove, MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk,
+                MessagePollVoteAdd, MessagePollVoteRemove, MessageUpdate, PresenceUpdate,
+                ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji, Ready,
+                RoleCreate, RoleDelete, RoleUpdate, StageInstanceCreate, StageInstanceDelete,
+                StageInstanceUpdate, ThreadCreate, ThreadDelete, ThreadListSync,
+                ThreadMemberUpdate, ThreadMembersUpdate, ThreadUpdate, UnavailableGuild,
+                UserUpdate, VoiceStateUpdate,
+            },
         },
     };
 
     pub trait Sealed {}
 
+    impl Sealed for [Entitlement] {}
+
     impl Sealed for Event {}
+    impl Sealed for AutoModerationRuleCreate {}
+    impl Sealed for AutoModerationRuleDelete {}
+    impl Sealed for AutoModerationRuleUpdate {}
+    impl Sealed for BanAdd {}
+    impl Sealed for BanRemove {}
     impl Sealed for ChannelCreate {}
     impl Sealed for ChannelDelete {}
     impl Sealed for ChannelPinsUpdate {}
     impl Sealed for ChannelUpdate {}
+    impl Sealed for EntitlementCreate {}
+    impl Sealed for EntitlementDelete {}
+    impl Sealed for EntitlementUpdate {}
+    impl Sealed for GuildAuditLogEntryCreate {}
     impl Sealed for GuildCreate {}
     impl Sealed for GuildEmojisUpdate {}
     impl Sealed for GuildDelete {}
@@ -903,6 +1371,8 @@ mod private {
     impl Sealed for MessageCreate {}
     impl Sealed for MessageDelete {}
     impl Sealed for MessageDeleteBulk {}
+    impl Sealed for MessagePollVoteAdd {}
+    impl Sealed for MessagePollVoteRemove {}
     impl Sealed for MessageUpdate {}
     impl Sealed for PresenceUpdate {}
     impl Sealed for ReactionAdd {}
@@ -919,6 +1389,8 @@ mod private {
     impl Sealed for ThreadCreate {}
     impl Sealed for ThreadDelete {}
     impl Sealed for ThreadListSync {}
+    impl Sealed for ThreadMemberUpdate {}
+    impl Sealed for ThreadMembersUpdate {}
     impl Sealed for ThreadUpdate {}
     impl Sealed for UnavailableGuild {}
     impl Sealed for UserUpdate {}
@@ -969,12 +1441,21 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
     #[allow(clippy::explicit_deref_methods)]
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
         match self {
+            Event::AutoModerationRuleCreate(v) => cache.update(v),
+            Event::AutoModerationRuleDelete(v) => cache.update(v),
+            Event::AutoModerationRuleUpdate(v) => cache.update(v),
+            Event::BanAdd(v) => cache.update(v),
+            Event::BanRemove(v) => cache.update(v),
             Event::ChannelCreate(v) => cache.update(v.deref()),
             Event::ChannelDelete(v) => cache.update(v.deref()),
             Event::ChannelPinsUpdate(v) => cache.update(v),
             Event::ChannelUpdate(v) => cache.update(v.deref()),
+            Event::EntitlementCreate(v) => cache.update(v),
+            Event::EntitlementDelete(v) => cache.update(v),
+            Event::EntitlementUpdate(v) => cache.update(v),
             Event::GuildCreate(v) => cache.update(v.deref()),
             Event::GuildDelete(v) => cache.update(v),
+            Event::GuildAuditLogEntryCreate(v) => cache.update(v.deref()),
             Event::GuildEmojisUpdate(v) => cache.update(v),
             Event::GuildScheduledEventCreate(v) => cache.update(v.deref()),
             Event::GuildScheduledEventDelete(v) => cache.update(v.deref()),
@@ -994,6 +1475,8 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             Event::MessageCreate(v) => cache.update(v.deref()),
             Event::MessageDelete(v) => cache.update(v),
             Event::MessageDeleteBulk(v) => cache.update(v),
+            Event::MessagePollVoteAdd(v) => cache.update(v),
+            Event::MessagePollVoteRemove(v) => cache.update(v),
             Event::MessageUpdate(v) => cache.update(v.deref()),
             Event::PresenceUpdate(v) => cache.update(v.deref()),
             Event::ReactionAdd(v) => cache.update(v.deref()),
@@ -1010,6 +1493,8 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             Event::ThreadCreate(v) => cache.update(v.deref()),
             Event::ThreadDelete(v) => cache.update(v),
             Event::ThreadListSync(v) => cache.update(v),
+            Event::ThreadMemberUpdate(v) => cache.update(v.deref()),
+            Event::ThreadMembersUpdate(v) => cache.update(v),
             Event::ThreadUpdate(v) => cache.update(v.deref()),
             Event::UnavailableGuild(v) => cache.update(v),
             Event::UserUpdate(v) => cache.update(v),
@@ -1017,31 +1502,19 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
 
             // Ignored events.
             Event::AutoModerationActionExecution(_)
-            | Event::AutoModerationRuleCreate(_)
-            | Event::AutoModerationRuleDelete(_)
-            | Event::AutoModerationRuleUpdate(_)
-            | Event::BanAdd(_)
-            | Event::BanRemove(_)
             | Event::CommandPermissionsUpdate(_)
-            | Event::EntitlementCreate(_)
-            | Event::EntitlementDelete(_)
-            | Event::EntitlementUpdate(_)
             | Event::GatewayClose(_)
             | Event::GatewayHeartbeat(_)
             | Event::GatewayHeartbeatAck
             | Event::GatewayHello(_)
             | Event::GatewayInvalidateSession(_)
             | Event::GatewayReconnect
-            | Event::GuildAuditLogEntryCreate(_)
             | Event::GuildIntegrationsUpdate(_)
             | Event::InviteCreate(_)
             | Event::InviteDelete(_)
-            | Event::MessagePollVoteAdd(_)
-            | Event::MessagePollVoteRemove(_)
             | Event::Resumed
-            | Event::ThreadMembersUpdate(_)
-            | Event::ThreadMemberUpdate(_)
             | Event::TypingStart(_)
+            | Event::Unknown { .. }
             | Event::VoiceServerUpdate(_)
             | Event::WebhooksUpdate(_) => {}
         }
@@ -1052,6 +1525,8 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
 mod tests {
     use crate::{test, DefaultInMemoryCache};
     use twilight_model::{
+        channel::ChannelType,
+        fixture,
         gateway::payload::incoming::RoleDelete,
         guild::{Member, MemberFlags, Permissions, Role, RoleFlags},
         id::Id,
@@ -1140,4 +1615,56 @@ mod tests {
             Some(Id::new(2))
         );
     }
+
+    #[test]
+    fn guild_channels_sorted() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+
+        let channel = |id, kind, position, parent_id: Option<u64>| {
+            let mut channel = fixture::channel(Id::new(id), kind);
+            channel.guild_id = Some(guild_id);
+            channel.parent_id = parent_id.map(Id::new);
+            channel.position = Some(position);
+
+            channel
+        };
+
+        cache.cache_channels([
+            channel(2, ChannelType::GuildCategory, 1, None),
+            channel(3, ChannelType::GuildCategory, 0, None),
+            channel(4, ChannelType::GuildVoice, 0, Some(2)),
+            channel(5, ChannelType::GuildText, 1, Some(2)),
+            channel(6, ChannelType::GuildText, 1, Some(3)),
+            channel(7, ChannelType::GuildAnnouncement, 0, Some(3)),
+            channel(8, ChannelType::GuildText, 5, None),
+            channel(9, ChannelType::GuildForum, 5, None),
+            channel(10, ChannelType::PublicThread, 0, Some(8)),
+        ]);
+
+        assert_eq!(
+            Some([8, 9, 3, 7, 6, 2, 5, 4].map(Id::new).to_vec()),
+            cache.guild_channels_sorted(guild_id)
+        );
+    }
+
+    #[test]
+    fn guild_roles_sorted() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+
+        let role = |id, position| {
+            let mut role = test::role(Id::new(id));
+            role.position = position;
+
+            role
+        };
+
+        cache.cache_roles(guild_id, [role(1, 0), role(2, 1), role(3, 2), role(4, 1)]);
+
+        assert_eq!(
+            Some([3, 2, 4, 1].map(Id::new).to_vec()),
+            cache.guild_roles_sorted(guild_id)
+        );
+    }
 }
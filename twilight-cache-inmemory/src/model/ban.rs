@@ -0,0 +1,75 @@
+use serde::Serialize;
+use twilight_model::{
+    guild::Ban,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Representation of a cached [`Ban`].
+///
+/// [`Ban`]: twilight_model::guild::Ban
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
+pub struct CachedBan {
+    /// ID of the guild the user is banned from.
+    pub(crate) guild_id: Id<GuildMarker>,
+    /// Reason for the ban, if known.
+    pub(crate) reason: Option<String>,
+    /// ID of the banned user.
+    pub(crate) user_id: Id<UserMarker>,
+}
+
+impl CachedBan {
+    /// ID of the guild the user is banned from.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+
+    /// Reason for the ban, if known.
+    ///
+    /// [`BanAdd`] events don't include the reason, so it's only known for
+    /// bans seeded via [`InMemoryCache::cache_bans`] or whose
+    /// [`GuildAuditLogEntryCreate`] event was received.
+    ///
+    /// [`BanAdd`]: twilight_model::gateway::payload::incoming::BanAdd
+    /// [`GuildAuditLogEntryCreate`]: twilight_model::gateway::payload::incoming::GuildAuditLogEntryCreate
+    /// [`InMemoryCache::cache_bans`]: crate::InMemoryCache::cache_bans
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// ID of the banned user.
+    pub const fn user_id(&self) -> Id<UserMarker> {
+        self.user_id
+    }
+
+    /// Construct a cached ban from its [`twilight_model`] form.
+    pub(crate) fn from_model(guild_id: Id<GuildMarker>, ban: Ban) -> Self {
+        Self {
+            guild_id,
+            reason: ban.reason,
+            user_id: ban.user.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedBan;
+    use serde::Serialize;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+
+    assert_fields!(CachedBan: guild_id, reason, user_id);
+    assert_impl_all!(
+        CachedBan: Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+}
@@ -1,4 +1,4 @@
-use crate::CacheableEmoji;
+use crate::{stats::vec_heap_size, CacheableEmoji};
 use serde::Serialize;
 use twilight_model::{
     guild::Emoji,
@@ -13,6 +13,7 @@ use twilight_model::{
 /// [`Emoji`]: twilight_model::guild::Emoji
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedEmoji {
     pub(crate) animated: bool,
     pub(crate) available: bool,
@@ -107,7 +108,15 @@ impl PartialEq<Emoji> for CachedEmoji {
     }
 }
 
-impl CacheableEmoji for CachedEmoji {}
+impl CacheableEmoji for CachedEmoji {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn heap_size(&self) -> usize {
+        self.name.capacity() + vec_heap_size(&self.roles)
+    }
+}
 
 #[cfg(test)]
 mod tests {
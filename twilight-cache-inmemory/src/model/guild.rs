@@ -15,12 +15,13 @@ use twilight_model::{
     util::{ImageHash, Timestamp},
 };
 
-use crate::CacheableGuild;
+use crate::{stats::vec_heap_size, CacheableGuild};
 
 /// Represents a cached [`Guild`].
 ///
 /// [`Guild`]: twilight_model::guild::Guild
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedGuild {
     pub(crate) afk_channel_id: Option<Id<ChannelMarker>>,
     pub(crate) afk_timeout: AfkTimeout,
@@ -477,6 +478,15 @@ impl CacheableGuild for CachedGuild {
     fn decrease_member_count(&mut self, amount: u64) {
         self.member_count = self.member_count.map(|count| count - amount);
     }
+
+    fn heap_size(&self) -> usize {
+        self.description.as_ref().map_or(0, String::capacity)
+            + vec_heap_size(&self.features)
+            + vec_heap_size(&self.guild_scheduled_events)
+            + self.name.capacity()
+            + self.preferred_locale.capacity()
+            + self.vanity_url_code.as_ref().map_or(0, String::capacity)
+    }
 }
 
 pub struct Features<'a> {
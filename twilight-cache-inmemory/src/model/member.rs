@@ -12,7 +12,7 @@ use twilight_model::{
     util::{ImageHash, Timestamp},
 };
 
-use crate::CacheableMember;
+use crate::{stats::vec_heap_size, CacheableMember};
 
 /// Computed components required to complete a full cached interaction member
 /// by implementing [`CacheableMember`].
@@ -42,6 +42,7 @@ impl Deref for ComputedInteractionMember {
 ///
 /// [`Member`]: twilight_model::guild::Member
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedMember {
     pub(crate) avatar: Option<ImageHash>,
     pub(crate) communication_disabled_until: Option<Timestamp>,
@@ -292,6 +293,10 @@ impl CacheableMember for CachedMember {
         self.pending = member_update.pending;
         self.communication_disabled_until = member_update.communication_disabled_until;
     }
+
+    fn heap_size(&self) -> usize {
+        self.nick.as_ref().map_or(0, String::capacity) + vec_heap_size(&self.roles)
+    }
 }
 
 #[cfg(test)]
@@ -24,10 +24,11 @@ use twilight_model::{
     util::Timestamp,
 };
 
-use crate::CacheableMessage;
+use crate::{stats::vec_heap_size, CacheableMessage};
 
 /// Information about the message interaction.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedMessageInteraction {
     id: Id<InteractionMarker>,
     #[serde(rename = "type")]
@@ -93,6 +94,7 @@ impl PartialEq<MessageInteraction> for CachedMessageInteraction {
 ///
 /// [`Message`]: twilight_model::channel::Message
 #[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedMessage {
     activity: Option<MessageActivity>,
     application: Option<MessageApplication>,
@@ -258,6 +260,14 @@ impl CachedMessage {
         self.pinned
     }
 
+    /// Poll attached to the message.
+    ///
+    /// Answer counts are kept up to date by the Message Poll Vote Add and
+    /// Remove events.
+    pub const fn poll(&self) -> Option<&Poll> {
+        self.poll.as_ref()
+    }
+
     /// Reactions to the message.
     pub fn reactions(&self) -> &[Reaction] {
         &self.reactions
@@ -412,6 +422,7 @@ impl PartialEq<Message> for CachedMessage {
                 .zip(other.mentions.iter())
                 .all(|(user_id, mention)| user_id == &mention.id)
             && self.pinned == other.pinned
+            && self.poll == other.poll
             && self.reactions == other.reactions
             && self.reference == other.reference
             && self.role_subscription_data == other.role_subscription_data
@@ -466,6 +477,10 @@ impl CacheableMessage for CachedMessage {
         }
     }
 
+    fn poll_mut(&mut self) -> Option<&mut Poll> {
+        self.poll.as_mut()
+    }
+
     fn reactions(&self) -> &[Reaction] {
         &self.reactions
     }
@@ -489,6 +504,19 @@ impl CacheableMessage for CachedMessage {
     fn remove_reaction(&mut self, idx: usize) {
         self.reactions.remove(idx);
     }
+
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.attachments)
+            + vec_heap_size(&self.components)
+            + self.content.capacity()
+            + vec_heap_size(&self.embeds)
+            + vec_heap_size(&self.mention_channels)
+            + vec_heap_size(&self.mention_roles)
+            + vec_heap_size(&self.mentions)
+            + vec_heap_size(&self.message_snapshots)
+            + vec_heap_size(&self.reactions)
+            + vec_heap_size(&self.sticker_items)
+    }
 }
 
 #[cfg(test)]
@@ -2,19 +2,23 @@
 
 pub mod message;
 
+mod ban;
 mod emoji;
 mod guild;
 pub(crate) mod member;
 mod presence;
+mod scheduled_event;
 mod sticker;
 mod voice_state;
 
 pub use self::{
+    ban::CachedBan,
     emoji::CachedEmoji,
     guild::CachedGuild,
     member::{CachedMember, ComputedInteractionMember},
     message::CachedMessage,
     presence::CachedPresence,
+    scheduled_event::CachedGuildScheduledEvent,
     sticker::CachedSticker,
     voice_state::CachedVoiceState,
 };
@@ -7,12 +7,13 @@ use twilight_model::{
     },
 };
 
-use crate::CacheablePresence;
+use crate::{stats::vec_heap_size, CacheablePresence};
 
 /// Represents a cached [`Presence`].
 ///
 /// [`Presence`]: twilight_model::gateway::presence::Presence
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedPresence {
     pub(crate) activities: Vec<Activity>,
     pub(crate) client_status: ClientStatus,
@@ -78,7 +79,11 @@ impl PartialEq<Presence> for CachedPresence {
     }
 }
 
-impl CacheablePresence for CachedPresence {}
+impl CacheablePresence for CachedPresence {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.activities)
+    }
+}
 
 #[cfg(test)]
 mod tests {
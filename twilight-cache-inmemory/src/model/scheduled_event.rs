@@ -0,0 +1,274 @@
+use serde::Serialize;
+use twilight_model::{
+    guild::scheduled_event::{
+        EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, Status,
+    },
+    id::{
+        marker::{
+            ChannelMarker, GuildMarker, ScheduledEventEntityMarker, ScheduledEventMarker,
+            UserMarker,
+        },
+        Id,
+    },
+    util::{ImageHash, Timestamp},
+};
+
+use crate::traits::CacheableGuildScheduledEvent;
+
+/// Representation of a cached [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: twilight_model::guild::scheduled_event::GuildScheduledEvent
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
+pub struct CachedGuildScheduledEvent {
+    /// ID of the stage or voice channel if there is one.
+    pub(crate) channel_id: Option<Id<ChannelMarker>>,
+    /// ID of the user who created the event.
+    pub(crate) creator_id: Option<Id<UserMarker>>,
+    /// Description of the event.
+    pub(crate) description: Option<String>,
+    /// ID of the event's entity.
+    pub(crate) entity_id: Option<Id<ScheduledEventEntityMarker>>,
+    /// Metadata of an entity, if it is not a channel.
+    pub(crate) entity_metadata: Option<EntityMetadata>,
+    /// Type of entity associated with the event.
+    pub(crate) entity_type: EntityType,
+    /// ID of the guild the event takes place in.
+    pub(crate) guild_id: Id<GuildMarker>,
+    /// ID of the event.
+    pub(crate) id: Id<ScheduledEventMarker>,
+    /// Hash of the event's cover image.
+    pub(crate) image: Option<ImageHash>,
+    /// Name of the event.
+    pub(crate) name: String,
+    /// Privacy level of the event.
+    pub(crate) privacy_level: PrivacyLevel,
+    /// Scheduled end time of the event.
+    pub(crate) scheduled_end_time: Option<Timestamp>,
+    /// Scheduled start time of the event.
+    pub(crate) scheduled_start_time: Timestamp,
+    /// Status of the event.
+    pub(crate) status: Status,
+    /// Number of users subscribed to the event.
+    pub(crate) user_count: Option<u64>,
+}
+
+impl CachedGuildScheduledEvent {
+    /// ID of the stage or voice channel if there is one.
+    pub const fn channel_id(&self) -> Option<Id<ChannelMarker>> {
+        self.channel_id
+    }
+
+    /// ID of the user who created the event.
+    ///
+    /// [`None`] for events created before October 25th, 2021.
+    pub const fn creator_id(&self) -> Option<Id<UserMarker>> {
+        self.creator_id
+    }
+
+    /// Description of the event.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// ID of the event's entity.
+    pub const fn entity_id(&self) -> Option<Id<ScheduledEventEntityMarker>> {
+        self.entity_id
+    }
+
+    /// Metadata of an entity, if it is not a channel.
+    pub const fn entity_metadata(&self) -> Option<&EntityMetadata> {
+        self.entity_metadata.as_ref()
+    }
+
+    /// Type of entity associated with the event.
+    pub const fn entity_type(&self) -> EntityType {
+        self.entity_type
+    }
+
+    /// ID of the guild the event takes place in.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+
+    /// ID of the event.
+    pub const fn id(&self) -> Id<ScheduledEventMarker> {
+        self.id
+    }
+
+    /// Hash of the event's cover image.
+    pub const fn image(&self) -> Option<&ImageHash> {
+        self.image.as_ref()
+    }
+
+    /// Name of the event.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Privacy level of the event.
+    pub const fn privacy_level(&self) -> PrivacyLevel {
+        self.privacy_level
+    }
+
+    /// Scheduled end time of the event.
+    pub const fn scheduled_end_time(&self) -> Option<Timestamp> {
+        self.scheduled_end_time
+    }
+
+    /// Scheduled start time of the event.
+    pub const fn scheduled_start_time(&self) -> Timestamp {
+        self.scheduled_start_time
+    }
+
+    /// Status of the event.
+    pub const fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Number of users subscribed to the event.
+    ///
+    /// Kept up to date by the Guild Scheduled Event User Add and Remove
+    /// events.
+    pub const fn user_count(&self) -> Option<u64> {
+        self.user_count
+    }
+}
+
+impl From<GuildScheduledEvent> for CachedGuildScheduledEvent {
+    fn from(event: GuildScheduledEvent) -> Self {
+        let GuildScheduledEvent {
+            channel_id,
+            creator,
+            creator_id,
+            description,
+            entity_id,
+            entity_metadata,
+            entity_type,
+            guild_id,
+            id,
+            image,
+            name,
+            privacy_level,
+            scheduled_end_time,
+            scheduled_start_time,
+            status,
+            user_count,
+        } = event;
+
+        Self {
+            channel_id,
+            creator_id: creator_id.or_else(|| creator.map(|user| user.id)),
+            description,
+            entity_id,
+            entity_metadata,
+            entity_type,
+            guild_id,
+            id,
+            image,
+            name,
+            privacy_level,
+            scheduled_end_time,
+            scheduled_start_time,
+            status,
+            user_count,
+        }
+    }
+}
+
+impl PartialEq<GuildScheduledEvent> for CachedGuildScheduledEvent {
+    fn eq(&self, other: &GuildScheduledEvent) -> bool {
+        self.channel_id == other.channel_id
+            && self.creator_id
+                == other
+                    .creator_id
+                    .or_else(|| other.creator.as_ref().map(|user| user.id))
+            && self.description == other.description
+            && self.entity_id == other.entity_id
+            && self.entity_metadata == other.entity_metadata
+            && self.entity_type == other.entity_type
+            && self.guild_id == other.guild_id
+            && self.id == other.id
+            && self.image == other.image
+            && self.name == other.name
+            && self.privacy_level == other.privacy_level
+            && self.scheduled_end_time == other.scheduled_end_time
+            && self.scheduled_start_time == other.scheduled_start_time
+            && self.status == other.status
+            && self.user_count == other.user_count
+    }
+}
+
+impl CacheableGuildScheduledEvent for CachedGuildScheduledEvent {
+    fn add_user(
+        &mut self,
+        _guild_id: Id<GuildMarker>,
+        _event_id: Id<ScheduledEventMarker>,
+        _user_id: Id<UserMarker>,
+    ) {
+        self.user_count = self.user_count.map(|count| count.saturating_add(1));
+    }
+
+    fn remove_user(
+        &mut self,
+        _guild_id: Id<GuildMarker>,
+        _event_id: Id<ScheduledEventMarker>,
+        _user_id: Id<UserMarker>,
+    ) {
+        self.user_count = self.user_count.map(|count| count.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedGuildScheduledEvent;
+    use crate::{test, traits::CacheableGuildScheduledEvent};
+    use serde::Serialize;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+    use twilight_model::{guild::scheduled_event::GuildScheduledEvent, id::Id};
+
+    assert_fields!(
+        CachedGuildScheduledEvent: channel_id,
+        creator_id,
+        description,
+        entity_id,
+        entity_metadata,
+        entity_type,
+        guild_id,
+        id,
+        image,
+        name,
+        privacy_level,
+        scheduled_end_time,
+        scheduled_start_time,
+        status,
+        user_count
+    );
+    assert_impl_all!(
+        CachedGuildScheduledEvent: Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        PartialEq<GuildScheduledEvent>,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn eq_guild_scheduled_event() {
+        let mut event = test::guild_scheduled_event(Id::new(1), Id::new(2), Some(3));
+        event.creator = Some(test::user(Id::new(4)));
+
+        let mut cached = CachedGuildScheduledEvent::from(event.clone());
+        assert_eq!(Some(Id::new(4)), cached.creator_id());
+        assert_eq!(cached, event);
+
+        cached.add_user(Id::new(2), Id::new(1), Id::new(5));
+        assert_eq!(Some(4), cached.user_count());
+        cached.remove_user(Id::new(2), Id::new(1), Id::new(5));
+        cached.remove_user(Id::new(2), Id::new(1), Id::new(5));
+        assert_eq!(Some(2), cached.user_count());
+    }
+}
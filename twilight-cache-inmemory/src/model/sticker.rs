@@ -16,6 +16,7 @@ use crate::CacheableSticker;
 ///
 /// [`Sticker`]: twilight_model::channel::message::sticker::Sticker
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedSticker {
     /// Whether the sticker is available.
     pub(crate) available: bool,
@@ -150,6 +151,10 @@ impl CacheableSticker for CachedSticker {
     fn id(&self) -> Id<StickerMarker> {
         self.id
     }
+
+    fn heap_size(&self) -> usize {
+        self.description.capacity() + self.name.capacity() + self.tags.capacity()
+    }
 }
 
 #[cfg(test)]
@@ -15,6 +15,7 @@ use crate::CacheableVoiceState;
 /// [`VoiceState`]: twilight_model::voice::VoiceState
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Deserialize))]
 pub struct CachedVoiceState {
     channel_id: Id<ChannelMarker>,
     deaf: bool,
@@ -155,6 +156,10 @@ impl CacheableVoiceState for CachedVoiceState {
     fn channel_id(&self) -> Id<ChannelMarker> {
         self.channel_id
     }
+
+    fn heap_size(&self) -> usize {
+        self.session_id.capacity()
+    }
 }
 
 #[cfg(test)]
@@ -49,7 +49,7 @@ use twilight_model::{
     channel::{permission_overwrite::PermissionOverwrite, ChannelType},
     guild::Permissions,
     id::{
-        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        marker::{ChannelMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker},
         Id,
     },
 };
@@ -143,6 +143,12 @@ impl Display for ChannelError {
 
                 f.write_str(" but it is not present in the cache")
             }
+            ChannelErrorType::ThreadMembersUnavailable { thread_id } => {
+                f.write_str("members of thread ")?;
+                Display::fmt(&thread_id, f)?;
+
+                f.write_str(" are not present in the cache")
+            }
         }
     }
 }
@@ -190,6 +196,14 @@ pub enum ChannelErrorType {
         /// ID of the role that the user has but details about is missing.
         role_id: Id<RoleMarker>,
     },
+    /// Members of a private thread are not available.
+    ///
+    /// This could be because the cache has not yet received the thread's
+    /// members or because all of its cached members left.
+    ThreadMembersUnavailable {
+        /// ID of the thread.
+        thread_id: Id<ChannelMarker>,
+    },
 }
 
 /// Error calculating permissions with information in a cache.
@@ -345,6 +359,48 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
         self
     }
 
+    /// Retrieve the permission overwrite of a role or member in a channel.
+    ///
+    /// Overwrites of a thread's parent channel are not included.
+    ///
+    /// The [`ResourceType::CHANNEL`] must be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    /// use twilight_model::id::{marker::UserMarker, Id};
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    ///
+    /// // later on...
+    ///
+    /// let channel_id = Id::new(4);
+    /// let user_id: Id<UserMarker> = Id::new(5);
+    ///
+    /// if let Some(overwrite) = cache
+    ///     .permissions()
+    ///     .channel_permission_overwrite(channel_id, user_id.cast())
+    /// {
+    ///     println!("User {user_id} in channel {channel_id} is denied {:?}", overwrite.deny);
+    /// }
+    /// ```
+    ///
+    /// [`ResourceType::CHANNEL`]: crate::ResourceType::CHANNEL
+    pub fn channel_permission_overwrite(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        id: Id<GenericMarker>,
+    ) -> Option<PermissionOverwrite> {
+        let channel = self.cache.channels.get(&channel_id)?;
+
+        channel
+            .permission_overwrites()?
+            .iter()
+            .find(|overwrite| overwrite.id == id)
+            .copied()
+    }
+
     /// Calculate the permissions of a member in a guild channel.
     ///
     /// Returns [`Permissions::all`] if the user is the owner of the guild.
@@ -353,6 +409,13 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
     /// restricted to [read-only permissions]. Refer to the [module level]
     /// documentation for more information.
     ///
+    /// Permissions in threads are calculated from the overwrites of their
+    /// parent channel. Members may only send messages in threads if they have
+    /// the [`SEND_MESSAGES_IN_THREADS`] permission, and have no permissions in
+    /// private threads they are not a member of unless they have the
+    /// [`MANAGE_THREADS`] permission. Membership of other users in threads is
+    /// only known with the [`GUILD_MEMBERS`] intent.
+    ///
     /// The following [`ResourceType`]s must be enabled:
     ///
     /// - [`ResourceType::CHANNEL`]
@@ -381,7 +444,10 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
     /// # Errors
     ///
     /// Returns a [`ChannelErrorType::ChannelUnavailable`] error type if the
-    /// guild channel is not in the cache.
+    /// guild channel or the parent of the thread is not in the cache.
+    ///
+    /// Returns a [`ChannelErrorType::ParentChannelNotPresent`] error type if
+    /// the thread has no parent.
     ///
     /// Returns a [`ChannelErrorType::MemberUnavailable`] error type if the
     /// member for the user in the guild is not present.
@@ -389,6 +455,12 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
     /// Returns a [`ChannelErrorType::RoleUnavailable`] error type if one of the
     /// member's roles is not in the cache.
     ///
+    /// Returns a [`ChannelErrorType::ThreadMembersUnavailable`] error type if
+    /// the channel is a private thread without cached members and the member
+    /// lacks the [`MANAGE_THREADS`] permission.
+    ///
+    /// [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
+    /// [`MANAGE_THREADS`]: twilight_model::guild::Permissions::MANAGE_THREADS
     /// [`Permissions::all`]: twilight_model::guild::Permissions::all
     /// [`ResourceType::CHANNEL`]: crate::ResourceType::CHANNEL
     /// [`ResourceType::MEMBER`]: crate::ResourceType::MEMBER
@@ -397,6 +469,7 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
     /// [communication has been disabled]: crate::model::CachedMember::communication_disabled_until
     /// [module level]: crate::permission
     /// [read-only permissions]: MEMBER_COMMUNICATION_DISABLED_ALLOWLIST
+    /// [`SEND_MESSAGES_IN_THREADS`]: twilight_model::guild::Permissions::SEND_MESSAGES_IN_THREADS
     pub fn in_channel(
         &self,
         user_id: Id<UserMarker>,
@@ -421,23 +494,98 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
             source: None,
         })?;
 
-        let MemberRoles { assigned, everyone } = self
+        let roles = self
             .member_roles(guild_id, &member)
             .map_err(ChannelError::from_member_roles)?;
 
-        let overwrites = match channel.kind() {
-            ChannelType::AnnouncementThread
-            | ChannelType::PrivateThread
-            | ChannelType::PublicThread => self.parent_overwrites(&channel)?,
-            _ => channel.permission_overwrites().unwrap_or_default().to_vec(),
+        let permissions = self.channel_permissions(user_id, guild_id, &roles, &channel)?;
+
+        Ok(self.disable_member_communication(&member, permissions))
+    }
+
+    /// Retrieve the channels of a guild a member can view.
+    ///
+    /// Channels are sorted by ID and include threads. Threads whose parent is
+    /// not in the cache are not included.
+    ///
+    /// Returns all channels of the guild if the user is the owner of the
+    /// guild, and no channels if the guild's channels are not in the cache.
+    ///
+    /// The following [`ResourceType`]s must be enabled:
+    ///
+    /// - [`ResourceType::CHANNEL`]
+    /// - [`ResourceType::MEMBER`]
+    /// - [`ResourceType::ROLE`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    /// use twilight_model::id::Id;
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    ///
+    /// // later on...
+    ///
+    /// let guild_id = Id::new(4);
+    /// let user_id = Id::new(5);
+    ///
+    /// let channel_ids = cache.permissions().channels_visible_to(user_id, guild_id)?;
+    /// println!("User {user_id} can view channels {channel_ids:?}");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RootErrorType::MemberUnavailable`] error type if the
+    /// member for the user in the guild is not present.
+    ///
+    /// Returns a [`RootErrorType::RoleUnavailable`] error type if one of the
+    /// member's roles is not in the cache.
+    ///
+    /// [`ResourceType::CHANNEL`]: crate::ResourceType::CHANNEL
+    /// [`ResourceType::MEMBER`]: crate::ResourceType::MEMBER
+    /// [`ResourceType::ROLE`]: crate::ResourceType::ROLE
+    /// [`ResourceType`]: crate::ResourceType
+    pub fn channels_visible_to(
+        &self,
+        user_id: Id<UserMarker>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<Id<ChannelMarker>>, RootError> {
+        let Some(channel_ids) = self.cache.guild_channels.get(&guild_id) else {
+            return Ok(Vec::new());
         };
 
-        let calculator =
-            PermissionCalculator::new(guild_id, user_id, everyone, assigned.as_slice());
+        let mut visible = if self.is_owner(user_id, guild_id) {
+            channel_ids.iter().copied().collect::<Vec<_>>()
+        } else {
+            let member = self.cache.member(guild_id, user_id).ok_or(RootError {
+                kind: RootErrorType::MemberUnavailable { guild_id, user_id },
+                source: None,
+            })?;
+
+            let roles = self
+                .member_roles(guild_id, &member)
+                .map_err(RootError::from_member_roles)?;
+
+            channel_ids
+                .iter()
+                .copied()
+                .filter(|channel_id| {
+                    self.cache.channels.get(channel_id).is_some_and(|channel| {
+                        self.channel_permissions(user_id, guild_id, &roles, &channel)
+                            .is_ok_and(|permissions| {
+                                permissions.contains(Permissions::VIEW_CHANNEL)
+                            })
+                    })
+                })
+                .collect()
+        };
 
-        let permissions = calculator.in_channel(channel.kind(), overwrites.as_slice());
+        visible.sort_unstable();
 
-        Ok(self.disable_member_communication(&member, permissions))
+        Ok(visible)
     }
 
     /// Calculate the guild-level permissions of a member.
@@ -512,6 +660,36 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
         Ok(self.disable_member_communication(&member, permissions))
     }
 
+    /// Calculate the permissions of a member with the given roles in a guild
+    /// channel, without considering whether their communication is disabled.
+    fn channel_permissions(
+        &self,
+        user_id: Id<UserMarker>,
+        guild_id: Id<GuildMarker>,
+        roles: &MemberRoles,
+        channel: &CacheModels::Channel,
+    ) -> Result<Permissions, ChannelError> {
+        let (kind, overwrites) = if channel.kind().is_thread() {
+            self.parent_overwrites(channel)?
+        } else {
+            (
+                channel.kind(),
+                channel.permission_overwrites().unwrap_or_default().to_vec(),
+            )
+        };
+
+        let calculator =
+            PermissionCalculator::new(guild_id, user_id, roles.everyone, roles.assigned.as_slice());
+
+        let mut permissions = calculator.in_channel(kind, overwrites.as_slice());
+
+        if channel.kind().is_thread() {
+            permissions = self.in_thread(user_id, channel, permissions)?;
+        }
+
+        Ok(permissions)
+    }
+
     /// Determine whether the provided member is disabled and restrict them to
     /// [read-only permissions] if they are.
     ///
@@ -601,12 +779,65 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
         }
     }
 
+    /// Restrict the permissions of a member in a thread's parent channel to
+    /// those applicable in the thread.
+    ///
+    /// Members may only send messages in threads with the
+    /// [`SEND_MESSAGES_IN_THREADS`] permission, and only see private threads
+    /// they are a member of unless they have the [`MANAGE_THREADS`]
+    /// permission.
+    ///
+    /// Returns a [`ChannelErrorType::ThreadMembersUnavailable`] error type if
+    /// membership of a private thread is needed but none of its members are
+    /// cached.
+    ///
+    /// [`MANAGE_THREADS`]: Permissions::MANAGE_THREADS
+    /// [`SEND_MESSAGES_IN_THREADS`]: Permissions::SEND_MESSAGES_IN_THREADS
+    fn in_thread(
+        &self,
+        user_id: Id<UserMarker>,
+        thread: &CacheModels::Channel,
+        mut permissions: Permissions,
+    ) -> Result<Permissions, ChannelError> {
+        if permissions.contains(Permissions::ADMINISTRATOR) {
+            return Ok(permissions);
+        }
+
+        if thread.kind() == ChannelType::PrivateThread
+            && !permissions.contains(Permissions::MANAGE_THREADS)
+        {
+            let members = self
+                .cache
+                .thread_members
+                .get(&thread.id())
+                .ok_or(ChannelError {
+                    kind: ChannelErrorType::ThreadMembersUnavailable {
+                        thread_id: thread.id(),
+                    },
+                    source: None,
+                })?;
+
+            if !members.contains(&user_id) {
+                return Ok(Permissions::empty());
+            }
+        }
+
+        permissions.set(
+            Permissions::SEND_MESSAGES,
+            permissions.contains(Permissions::SEND_MESSAGES_IN_THREADS),
+        );
+
+        Ok(permissions)
+    }
+
     /// Given a thread channel, retrieve its parent from the cache, and combine
     /// parent and child permissions.
+    ///
+    /// Returns the type of the parent along with the combined overwrites.
     fn parent_overwrites(
         &self,
         thread: &CacheModels::Channel,
-    ) -> Result<Vec<PermissionOverwrite>, ChannelError> {
+    ) -> Result<(ChannelType, Vec<PermissionOverwrite>), ChannelError> {
         let parent_id = thread.parent_id().ok_or(ChannelError {
             kind: ChannelErrorType::ParentChannelNotPresent {
                 thread_id: thread.id(),
@@ -631,7 +862,7 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
             overwrites.extend_from_slice(channel_overwrites);
             overwrites.extend_from_slice(thread_overwrites);
 
-            Ok(overwrites)
+            Ok((channel.kind(), overwrites))
         } else {
             Err(ChannelError {
                 kind: ChannelErrorType::ChannelNotInGuild {
@@ -659,10 +890,12 @@ mod tests {
     use twilight_model::{
         channel::{
             permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            thread::ThreadMember,
             Channel, ChannelType,
         },
         gateway::payload::incoming::{
             ChannelCreate, GuildCreate, MemberAdd, MemberUpdate, RoleCreate, ThreadCreate,
+            ThreadMembersUpdate,
         },
         guild::{
             AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel,
@@ -678,6 +911,7 @@ mod tests {
     assert_fields!(ChannelErrorType::ChannelUnavailable: channel_id);
     assert_fields!(ChannelErrorType::MemberUnavailable: guild_id, user_id);
     assert_fields!(ChannelErrorType::RoleUnavailable: role_id);
+    assert_fields!(ChannelErrorType::ThreadMembersUnavailable: thread_id);
     assert_impl_all!(ChannelErrorType: Debug, Send, Sync);
     assert_impl_all!(ChannelError: Debug, Send, Sync);
     assert_impl_all!(InMemoryCachePermissions<'_, DefaultCacheModels>: Clone, Debug, Send, Sync);
@@ -1012,13 +1246,97 @@ mod tests {
         cache.update(&ThreadCreate(thread()));
 
         assert_eq!(
-            Permissions::EMBED_LINKS | Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES,
+            Permissions::EMBED_LINKS | Permissions::ATTACH_FILES,
             permissions.in_channel(USER_ID, THREAD_ID)?
         );
 
         Ok(())
     }
 
+    /// Test that [`in_channel`] restricts sending messages in threads to
+    /// members with [`Permissions::SEND_MESSAGES_IN_THREADS`] and hides
+    /// private threads from non-members without
+    /// [`Permissions::MANAGE_THREADS`], and that membership is unknown while
+    /// no members of a private thread are cached.
+    ///
+    /// [`in_channel`]: super::InMemoryCachePermissions::in_channel
+    #[test]
+    fn in_thread() -> Result<(), Box<dyn Error>> {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate::Available(base_guild()));
+        cache.update(&ChannelCreate(channel()));
+        let mut member = test::member(USER_ID);
+        member.roles.push(OTHER_ROLE_ID);
+        cache.update(&MemberAdd {
+            guild_id: GUILD_ID,
+            member,
+        });
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(
+                OTHER_ROLE_ID,
+                Permissions::SEND_MESSAGES | Permissions::SEND_MESSAGES_IN_THREADS,
+            ),
+        ));
+
+        let mut private = thread();
+        private.kind = ChannelType::PrivateThread;
+        cache.update(&ThreadCreate(private));
+        assert!(matches!(
+            permissions.in_channel(USER_ID, THREAD_ID).unwrap_err().kind(),
+            ChannelErrorType::ThreadMembersUnavailable { thread_id }
+            if *thread_id == THREAD_ID
+        ));
+
+        let thread_member = |user_id| -> Result<ThreadMember, Box<dyn Error>> {
+            Ok(ThreadMember {
+                flags: 0,
+                id: Some(THREAD_ID),
+                join_timestamp: Timestamp::from_secs(1)?,
+                member: None,
+                presence: None,
+                user_id: Some(user_id),
+            })
+        };
+        cache.update(&ThreadMembersUpdate {
+            added_members: Vec::from([thread_member(USER_ID)?, thread_member(OWNER_ID)?]),
+            guild_id: GUILD_ID,
+            id: THREAD_ID,
+            member_count: 2,
+            removed_member_ids: Vec::new(),
+        });
+        assert_eq!(
+            Permissions::EMBED_LINKS
+                | Permissions::SEND_MESSAGES
+                | Permissions::SEND_MESSAGES_IN_THREADS
+                | Permissions::ATTACH_FILES,
+            permissions.in_channel(USER_ID, THREAD_ID)?
+        );
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: Vec::new(),
+            guild_id: GUILD_ID,
+            id: THREAD_ID,
+            member_count: 1,
+            removed_member_ids: Vec::from([USER_ID]),
+        });
+        assert!(permissions.in_channel(USER_ID, THREAD_ID)?.is_empty());
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: Vec::new(),
+            guild_id: GUILD_ID,
+            id: THREAD_ID,
+            member_count: 0,
+            removed_member_ids: Vec::from([OWNER_ID]),
+        });
+        assert!(!cache.thread_members.contains_key(&THREAD_ID));
+        assert!(permissions.in_channel(USER_ID, THREAD_ID).is_err());
+
+        Ok(())
+    }
+
     /// Test that [`in_channel`] and [`root`] both return [`Permissions::all`]
     /// if the user is also the owner of the guild.
     ///
@@ -1134,4 +1452,89 @@ mod tests {
 
         Ok(())
     }
+
+    /// Test that [`channel_permission_overwrite`] returns the overwrite of a
+    /// role or member in a channel.
+    ///
+    /// [`channel_permission_overwrite`]: super::InMemoryCachePermissions::channel_permission_overwrite
+    #[test]
+    fn channel_permission_overwrite() {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+        assert!(permissions
+            .channel_permission_overwrite(CHANNEL_ID, USER_ID.cast())
+            .is_none());
+
+        cache.update(&ChannelCreate(channel()));
+        assert_eq!(
+            Some(Permissions::EMBED_LINKS),
+            permissions
+                .channel_permission_overwrite(CHANNEL_ID, USER_ID.cast())
+                .map(|overwrite| overwrite.allow)
+        );
+        assert_eq!(
+            Some(Permissions::CREATE_INVITE),
+            permissions
+                .channel_permission_overwrite(CHANNEL_ID, EVERYONE_ROLE_ID.cast())
+                .map(|overwrite| overwrite.deny)
+        );
+        assert!(permissions
+            .channel_permission_overwrite(CHANNEL_ID, OTHER_ROLE_ID.cast())
+            .is_none());
+    }
+
+    /// Test that [`channels_visible_to`] only includes the channels and
+    /// threads a member has the [`Permissions::VIEW_CHANNEL`] permission in,
+    /// and all channels for the owner of the guild.
+    ///
+    /// [`channels_visible_to`]: super::InMemoryCachePermissions::channels_visible_to
+    #[test]
+    fn channels_visible_to() -> Result<(), Box<dyn Error>> {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate::Available(base_guild()));
+        cache.update(&ChannelCreate(channel()));
+        cache.update(&ThreadCreate(thread()));
+        let mut hidden = channel();
+        hidden.id = SAFETY_ALERTS_CHANNEL_ID;
+        hidden.permission_overwrites = Some(Vec::from([PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::VIEW_CHANNEL,
+            id: USER_ID.cast(),
+            kind: PermissionOverwriteType::Member,
+        }]));
+        cache.update(&ChannelCreate(hidden));
+
+        assert!(matches!(
+            permissions.channels_visible_to(USER_ID, GUILD_ID).unwrap_err().kind(),
+            RootErrorType::MemberUnavailable { guild_id: g_id, user_id: u_id }
+            if *g_id == GUILD_ID && *u_id == USER_ID
+        ));
+
+        let mut member = test::member(USER_ID);
+        member.roles.push(OTHER_ROLE_ID);
+        cache.update(&MemberAdd {
+            guild_id: GUILD_ID,
+            member,
+        });
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(OTHER_ROLE_ID, Permissions::VIEW_CHANNEL),
+        ));
+
+        assert_eq!(
+            Vec::from([CHANNEL_ID, THREAD_ID]),
+            permissions.channels_visible_to(USER_ID, GUILD_ID)?
+        );
+        assert_eq!(
+            Vec::from([CHANNEL_ID, THREAD_ID, SAFETY_ALERTS_CHANNEL_ID]),
+            permissions.channels_visible_to(OWNER_ID, GUILD_ID)?
+        );
+        assert!(permissions
+            .channels_visible_to(USER_ID, Id::new(7))?
+            .is_empty());
+
+        Ok(())
+    }
 }
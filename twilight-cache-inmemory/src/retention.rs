@@ -0,0 +1,118 @@
+//! Dropping configured fields of resources before caching them.
+
+use crate::config::ResourceField;
+use twilight_model::{
+    channel::Message,
+    gateway::{payload::incoming::MessageUpdate, presence::Presence},
+    util::ImageHash,
+};
+
+/// Drop the avatar of a member if configured.
+pub(crate) fn retain_member_avatar(dropped: ResourceField, avatar: &mut Option<ImageHash>) {
+    if dropped.contains(ResourceField::MEMBER_AVATAR) {
+        *avatar = None;
+    }
+}
+
+/// Drop the configured fields of a message.
+pub(crate) fn retain_message(dropped: ResourceField, message: &mut Message) {
+    if dropped.contains(ResourceField::MESSAGE_COMPONENTS) {
+        message.components = Vec::new();
+    }
+
+    if dropped.contains(ResourceField::MESSAGE_EMBEDS) {
+        message.embeds = Vec::new();
+    }
+}
+
+/// Drop the configured fields of a message update.
+pub(crate) fn retain_message_update(dropped: ResourceField, message_update: &mut MessageUpdate) {
+    if dropped.contains(ResourceField::MESSAGE_EMBEDS) {
+        message_update.embeds = None;
+    }
+}
+
+/// Drop the configured fields of a presence.
+pub(crate) fn retain_presence(dropped: ResourceField, presence: &mut Presence) {
+    if dropped.contains(ResourceField::PRESENCE_ACTIVITIES) {
+        presence.activities = Vec::new();
+    } else if dropped.contains(ResourceField::PRESENCE_ACTIVITY_ASSETS) {
+        for activity in &mut presence.activities {
+            activity.assets = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, DefaultInMemoryCache, ResourceField};
+    use twilight_model::{
+        channel::message::Embed,
+        gateway::payload::incoming::{MemberUpdate, MessageCreate},
+        id::Id,
+        util::ImageHash,
+    };
+
+    #[test]
+    fn member_avatar() {
+        let cache = DefaultInMemoryCache::builder()
+            .dropped_fields(ResourceField::MEMBER_AVATAR)
+            .build();
+        let guild_id = Id::new(1);
+        let avatar = ImageHash::new([1; 16], false);
+        let mut member = test::member(Id::new(2));
+        member.avatar = Some(avatar);
+        cache.cache_member(guild_id, member.clone());
+        assert!(cache
+            .member(guild_id, Id::new(2))
+            .unwrap()
+            .avatar()
+            .is_none());
+
+        cache.update(&MemberUpdate {
+            avatar: Some(avatar),
+            communication_disabled_until: None,
+            guild_id,
+            deaf: None,
+            joined_at: member.joined_at,
+            mute: None,
+            nick: Some("nick".to_owned()),
+            pending: false,
+            premium_since: None,
+            roles: Vec::new(),
+            user: member.user,
+        });
+        let cached = cache.member(guild_id, Id::new(2)).unwrap();
+        assert!(cached.avatar().is_none());
+        assert_eq!(Some("nick"), cached.nick());
+    }
+
+    #[test]
+    fn message_embeds() {
+        let cache = DefaultInMemoryCache::builder()
+            .dropped_fields(ResourceField::MESSAGE_EMBEDS)
+            .build();
+        let embed = Embed {
+            author: None,
+            color: None,
+            description: Some("description".to_owned()),
+            fields: Vec::new(),
+            footer: None,
+            image: None,
+            kind: "rich".to_owned(),
+            provider: None,
+            thumbnail: None,
+            timestamp: None,
+            title: None,
+            url: None,
+            video: None,
+        };
+        let mut message = test::message();
+        message.embeds = vec![embed];
+        cache.update(&MessageCreate(message.clone()));
+
+        let cached = cache.message(message.id).unwrap();
+        assert!(cached.embeds().is_empty());
+        assert_eq!("ping", cached.content());
+    }
+}
@@ -0,0 +1,387 @@
+//! Persisting the contents of the cache, such as across restarts.
+
+use crate::{
+    counts::GuildCounter, model::CachedBan, CacheableModels, GuildResource, InMemoryCache,
+};
+use dashmap::{mapref::multiple::RefMulti, DashMap};
+use serde::{
+    de::DeserializeOwned, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+use twilight_model::id::{
+    marker::{
+        AutoModerationRuleMarker, ChannelMarker, EmojiMarker, EntitlementMarker, GuildMarker,
+        IntegrationMarker, MessageMarker, RoleMarker, ScheduledEventMarker, SkuMarker, StageMarker,
+        StickerMarker, UserMarker,
+    },
+    Id,
+};
+
+/// [`CacheableModels`] whose models can be serialized into and deserialized
+/// from a [snapshot].
+///
+/// Implemented for all [`CacheableModels`] whose models implement
+/// [`Serialize`] and [`Deserialize`], including [`DefaultCacheModels`].
+///
+/// [`DefaultCacheModels`]: crate::DefaultCacheModels
+/// [snapshot]: InMemoryCache::serialize
+pub trait SnapshotModels:
+    CacheableModels<
+    AutoModerationRule: Serialize + DeserializeOwned,
+    Channel: Serialize + DeserializeOwned,
+    CurrentUser: Serialize + DeserializeOwned,
+    Emoji: Serialize + DeserializeOwned,
+    Entitlement: Serialize + DeserializeOwned,
+    Guild: Serialize + DeserializeOwned,
+    GuildIntegration: Serialize + DeserializeOwned,
+    GuildScheduledEvent: Serialize + DeserializeOwned,
+    Member: Serialize + DeserializeOwned,
+    Message: Serialize + DeserializeOwned,
+    Presence: Serialize + DeserializeOwned,
+    Role: Serialize + DeserializeOwned,
+    StageInstance: Serialize + DeserializeOwned,
+    Sticker: Serialize + DeserializeOwned,
+    User: Serialize + DeserializeOwned,
+    VoiceState: Serialize + DeserializeOwned,
+>
+{
+}
+
+impl<T> SnapshotModels for T where
+    T: CacheableModels<
+        AutoModerationRule: Serialize + DeserializeOwned,
+        Channel: Serialize + DeserializeOwned,
+        CurrentUser: Serialize + DeserializeOwned,
+        Emoji: Serialize + DeserializeOwned,
+        Entitlement: Serialize + DeserializeOwned,
+        Guild: Serialize + DeserializeOwned,
+        GuildIntegration: Serialize + DeserializeOwned,
+        GuildScheduledEvent: Serialize + DeserializeOwned,
+        Member: Serialize + DeserializeOwned,
+        Message: Serialize + DeserializeOwned,
+        Presence: Serialize + DeserializeOwned,
+        Role: Serialize + DeserializeOwned,
+        StageInstance: Serialize + DeserializeOwned,
+        Sticker: Serialize + DeserializeOwned,
+        User: Serialize + DeserializeOwned,
+        VoiceState: Serialize + DeserializeOwned,
+    >
+{
+}
+
+/// Map entries, serialized as a sequence of key-value pairs to support formats
+/// only allowing string map keys.
+type Entries<K, V> = Vec<(K, V)>;
+
+/// Deserialized snapshot.
+///
+/// Fields must match the ones serialized by [`InMemoryCache::serialize`].
+#[allow(clippy::type_complexity)]
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct Snapshot<CacheModels: SnapshotModels> {
+    auto_moderation_rules:
+        Entries<Id<AutoModerationRuleMarker>, GuildResource<CacheModels::AutoModerationRule>>,
+    bans: Entries<(Id<GuildMarker>, Id<UserMarker>), CachedBan>,
+    channels: Entries<Id<ChannelMarker>, CacheModels::Channel>,
+    channel_messages: Entries<Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
+    current_user: Option<CacheModels::CurrentUser>,
+    emojis: Entries<Id<EmojiMarker>, GuildResource<CacheModels::Emoji>>,
+    entitlements: Entries<Id<EntitlementMarker>, CacheModels::Entitlement>,
+    guilds: Entries<Id<GuildMarker>, CacheModels::Guild>,
+    guild_auto_moderation_rules: Entries<Id<GuildMarker>, HashSet<Id<AutoModerationRuleMarker>>>,
+    guild_bans: Entries<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+    guild_channels: Entries<Id<GuildMarker>, HashSet<Id<ChannelMarker>>>,
+    guild_counters: Entries<Id<GuildMarker>, GuildCounter>,
+    guild_emojis: Entries<Id<GuildMarker>, HashSet<Id<EmojiMarker>>>,
+    guild_entitlements: Entries<Id<GuildMarker>, HashSet<Id<EntitlementMarker>>>,
+    guild_integrations: Entries<Id<GuildMarker>, HashSet<Id<IntegrationMarker>>>,
+    guild_members: Entries<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+    guild_presences: Entries<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+    guild_roles: Entries<Id<GuildMarker>, HashSet<Id<RoleMarker>>>,
+    guild_scheduled_events: Entries<Id<GuildMarker>, HashSet<Id<ScheduledEventMarker>>>,
+    guild_stage_instances: Entries<Id<GuildMarker>, HashSet<Id<StageMarker>>>,
+    guild_stickers: Entries<Id<GuildMarker>, HashSet<Id<StickerMarker>>>,
+    integrations: Entries<
+        (Id<GuildMarker>, Id<IntegrationMarker>),
+        GuildResource<CacheModels::GuildIntegration>,
+    >,
+    members: Entries<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Member>,
+    messages: Entries<Id<MessageMarker>, CacheModels::Message>,
+    presences: Entries<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Presence>,
+    roles: Entries<Id<RoleMarker>, GuildResource<CacheModels::Role>>,
+    scheduled_events:
+        Entries<Id<ScheduledEventMarker>, GuildResource<CacheModels::GuildScheduledEvent>>,
+    sku_entitlements: Entries<Id<SkuMarker>, HashSet<Id<EntitlementMarker>>>,
+    stage_instances: Entries<Id<StageMarker>, GuildResource<CacheModels::StageInstance>>,
+    stickers: Entries<Id<StickerMarker>, GuildResource<CacheModels::Sticker>>,
+    thread_members: Entries<Id<ChannelMarker>, HashSet<Id<UserMarker>>>,
+    unavailable_guilds: Vec<Id<GuildMarker>>,
+    users: Entries<Id<UserMarker>, CacheModels::User>,
+    user_entitlements: Entries<Id<UserMarker>, HashSet<Id<EntitlementMarker>>>,
+    user_guilds: Entries<Id<UserMarker>, HashSet<Id<GuildMarker>>>,
+    voice_state_channels: Entries<Id<ChannelMarker>, HashSet<(Id<GuildMarker>, Id<UserMarker>)>>,
+    voice_state_guilds: Entries<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+    voice_states: Entries<(Id<GuildMarker>, Id<UserMarker>), CacheModels::VoiceState>,
+}
+
+/// Serialize a map's entries as [`Entries`].
+struct SerializeEntries<'a, K, V>(&'a DashMap<K, V>);
+
+impl<K: Eq + Hash + Serialize, V: Serialize> Serialize for SerializeEntries<'_, K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(SerializeEntry))
+    }
+}
+
+/// Serialize a map entry as a key-value pair.
+struct SerializeEntry<'a, K, V>(RefMulti<'a, K, V>);
+
+impl<K: Eq + Hash + Serialize, V: Serialize> Serialize for SerializeEntry<'_, K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.0.key(), self.0.value()).serialize(serializer)
+    }
+}
+
+/// Generate [`InMemoryCache::serialize`] and [`InMemoryCache::restore`] for
+/// all map fields.
+macro_rules! snapshot {
+    ($($field:ident),* $(,)?) => {
+        impl<CacheModels: SnapshotModels> InMemoryCache<CacheModels> {
+            /// Serialize a snapshot of the contents of the cache.
+            ///
+            /// The configuration is not included. Restore the snapshot via
+            /// [`restore`].
+            ///
+            /// # Examples
+            ///
+            /// Persist the cache across restarts:
+            ///
+            /// ```no_run
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use std::fs::File;
+            /// use twilight_cache_inmemory::DefaultInMemoryCache;
+            ///
+            /// let cache = DefaultInMemoryCache::new();
+            ///
+            /// // Before shutting down...
+            /// cache.serialize(&mut serde_json::Serializer::new(File::create("cache.json")?))?;
+            ///
+            /// // After starting up again...
+            /// let cache = DefaultInMemoryCache::new();
+            /// cache.restore(&mut serde_json::Deserializer::from_reader(File::open("cache.json")?))?;
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns the serializer's error if serializing fails.
+            ///
+            /// [`restore`]: Self::restore
+            #[allow(clippy::missing_panics_doc)]
+            pub fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let current_user = self.current_user();
+                let unavailable_guilds = self
+                    .unavailable_guilds
+                    .iter()
+                    .map(|guild_id| *guild_id)
+                    .collect::<Vec<_>>();
+
+                let mut state = serializer.serialize_struct("Snapshot", 38)?;
+                state.serialize_field("current_user", &current_user)?;
+                state.serialize_field("unavailable_guilds", &unavailable_guilds)?;
+                $(state.serialize_field(stringify!($field), &SerializeEntries(&self.$field))?;)*
+
+                state.end()
+            }
+
+            /// Replace the contents of the cache with a snapshot serialized by
+            /// [`serialize`].
+            ///
+            /// The cache's configuration is kept, evicting entries over the
+            /// configured maximums and counting the time-to-live of restored
+            /// entries from now.
+            ///
+            /// # Errors
+            ///
+            /// Returns the deserializer's error if deserializing fails, leaving
+            /// the cache unchanged.
+            ///
+            /// [`serialize`]: Self::serialize
+            #[allow(clippy::missing_panics_doc)]
+            pub fn restore<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<(), D::Error> {
+                let snapshot = Snapshot::<CacheModels>::deserialize(deserializer)?;
+
+                self.clear();
+                *self.current_user.lock().expect("current user poisoned") = snapshot.current_user;
+
+                for guild_id in snapshot.unavailable_guilds {
+                    self.unavailable_guilds.insert(guild_id);
+                }
+
+                $(
+                    for (key, value) in snapshot.$field {
+                        self.$field.insert(key, value);
+                    }
+                )*
+
+                self.track_restored();
+
+                Ok(())
+            }
+        }
+    };
+}
+
+snapshot!(
+    auto_moderation_rules,
+    bans,
+    channels,
+    channel_messages,
+    emojis,
+    entitlements,
+    guilds,
+    guild_auto_moderation_rules,
+    guild_bans,
+    guild_channels,
+    guild_counters,
+    guild_emojis,
+    guild_entitlements,
+    guild_integrations,
+    guild_members,
+    guild_presences,
+    guild_roles,
+    guild_scheduled_events,
+    guild_stage_instances,
+    guild_stickers,
+    integrations,
+    members,
+    messages,
+    presences,
+    roles,
+    scheduled_events,
+    sku_entitlements,
+    stage_instances,
+    stickers,
+    thread_members,
+    users,
+    user_entitlements,
+    user_guilds,
+    voice_state_channels,
+    voice_state_guilds,
+    voice_states,
+);
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Index the restored entries and track them for eviction and expiry.
+    fn track_restored(&self) {
+        self.reindex();
+
+        let channel_messages = self
+            .channel_messages
+            .iter()
+            .flat_map(|entry| {
+                let channel_id = *entry.key();
+
+                entry
+                    .value()
+                    .iter()
+                    .map(|message_id| (channel_id, *message_id))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for (channel_id, message_id) in channel_messages {
+            self.track_message(channel_id, message_id);
+        }
+
+        let emojis = self
+            .emojis
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        for emoji_id in emojis {
+            self.track_emoji(emoji_id);
+        }
+
+        let members = self
+            .members
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        for (guild_id, user_id) in members {
+            self.track_member(guild_id, user_id);
+        }
+
+        let presences = self
+            .presences
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        for (guild_id, user_id) in presences {
+            self.track_presence(guild_id, user_id);
+        }
+
+        let users = self
+            .users
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        for user_id in users {
+            self.track_user(user_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnapshotModels;
+    use crate::{test, DefaultCacheModels, DefaultInMemoryCache};
+    use static_assertions::assert_impl_all;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(DefaultCacheModels: SnapshotModels);
+
+    #[test]
+    fn round_trip() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.cache_member(Id::new(1), test::member(Id::new(5)));
+        cache
+            .current_user
+            .lock()
+            .unwrap()
+            .replace(test::current_user(6));
+        cache.unavailable_guilds.insert(Id::new(7));
+
+        let mut json = Vec::new();
+        cache
+            .serialize(&mut serde_json::Serializer::new(&mut json))
+            .unwrap();
+
+        let restored = DefaultInMemoryCache::builder().member_cache_size(1).build();
+        restored
+            .restore(&mut serde_json::Deserializer::from_slice(&json))
+            .unwrap();
+
+        assert_eq!(
+            cache.message(Id::new(4)).unwrap().value(),
+            restored.message(Id::new(4)).unwrap().value()
+        );
+        assert_eq!(
+            cache.channel_messages(Id::new(2)).unwrap().value(),
+            restored.channel_messages(Id::new(2)).unwrap().value()
+        );
+        assert_eq!(cache.current_user(), restored.current_user());
+        assert_eq!(cache.stats().users(), restored.stats().users());
+        assert_eq!(1, restored.stats().members());
+        assert_eq!(1, restored.stats().member_evictions());
+        assert!(restored.unavailable_guilds.contains(&Id::new(7)));
+
+        assert!(restored
+            .restore(&mut serde_json::Deserializer::from_slice(b"{}"))
+            .is_err());
+        assert_eq!(1, restored.stats().members());
+    }
+}
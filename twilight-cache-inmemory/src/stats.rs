@@ -1,18 +1,132 @@
+use dashmap::DashMap;
+use std::{
+    collections::HashSet,
+    hash::Hash,
+    mem,
+    ops::{Add, AddAssign},
+};
 use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker},
+    marker::{ChannelMarker, GuildMarker, MessageMarker},
     Id,
 };
 
-use crate::{CacheableModels, DefaultCacheModels};
+use crate::{
+    eviction::Limit,
+    traits::{
+        CacheableAutoModerationRule, CacheableChannel, CacheableCurrentUser, CacheableEmoji,
+        CacheableEntitlement, CacheableGuild, CacheableGuildIntegration,
+        CacheableGuildScheduledEvent, CacheableMember, CacheableMessage, CacheablePresence,
+        CacheableRole, CacheableStageInstance, CacheableSticker, CacheableUser,
+        CacheableVoiceState,
+    },
+    CacheableModels, DefaultCacheModels, GuildResource, ResourceType,
+};
 
 use super::InMemoryCache;
 
+/// Number of entries and estimated memory usage of resources in the cache.
+///
+/// Memory usage is estimated from the size of the keys and values of the
+/// entries and the memory the values allocate on the heap, as estimated by
+/// their `heap_size` methods, such as [`CacheableMember::heap_size`]. Memory
+/// allocated by the maps storing the entries isn't included, so actual memory
+/// usage is higher.
+///
+/// Usages can be added together to calculate the usage of multiple resources.
+///
+/// [`CacheableMember::heap_size`]: crate::traits::CacheableMember::heap_size
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ResourceUsage {
+    entries: usize,
+    estimated_bytes: usize,
+}
+
+impl ResourceUsage {
+    /// Create the usage of an entry of a key and value type.
+    const fn new<K, V>(heap_size: usize) -> Self {
+        Self {
+            entries: 1,
+            estimated_bytes: mem::size_of::<K>() + mem::size_of::<V>() + heap_size,
+        }
+    }
+
+    /// Create the usage of all entries of a map.
+    fn of_map<K: Eq + Hash, V>(map: &DashMap<K, V>, heap_size: impl Fn(&V) -> usize) -> Self {
+        map.iter()
+            .map(|entry| Self::new::<K, V>(heap_size(entry.value())))
+            .fold(Self::default(), Add::add)
+    }
+
+    /// Create the usage of the entries of a map associated with a guild.
+    ///
+    /// The guild's IDs of the entries are mapped to their keys in the map via
+    /// `key`.
+    fn of_guild<K: Eq + Hash, V, T: Copy + Eq + Hash>(
+        guild_map: &DashMap<Id<GuildMarker>, HashSet<T>>,
+        map: &DashMap<K, V>,
+        guild_id: Id<GuildMarker>,
+        key: impl Fn(T) -> K,
+        heap_size: impl Fn(&V) -> usize,
+    ) -> Self {
+        let Some(ids) = guild_map.get(&guild_id) else {
+            return Self::default();
+        };
+
+        ids.iter()
+            .filter_map(|id| map.get(&key(*id)))
+            .map(|entry| Self::new::<K, V>(heap_size(entry.value())))
+            .fold(Self::default(), Add::add)
+    }
+
+    /// Number of entries.
+    pub const fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Estimated memory usage of the entries in bytes.
+    pub const fn estimated_bytes(&self) -> usize {
+        self.estimated_bytes
+    }
+}
+
+impl Add for ResourceUsage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            entries: self.entries + rhs.entries,
+            estimated_bytes: self.estimated_bytes + rhs.estimated_bytes,
+        }
+    }
+}
+
+impl AddAssign for ResourceUsage {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Estimated number of bytes allocated by a list for its elements.
+///
+/// Memory allocated by the elements themselves isn't included.
+#[allow(clippy::ptr_arg)]
+pub(crate) fn vec_heap_size<T>(vec: &Vec<T>) -> usize {
+    vec.capacity() * mem::size_of::<T>()
+}
+
+/// Estimated heap size of a guild resource.
+fn resource_heap_size<T>(heap_size: impl Fn(&T) -> usize) -> impl Fn(&GuildResource<T>) -> usize {
+    move |resource| heap_size(resource.resource())
+}
+
 /// Retrieve statistics about the number of entities of each resource in the
 /// cache.
 ///
 /// Statistics can be retrieved about the amount of resources on a cache-level
 /// via a method such as [`users`] or in a particular channel via a method
-/// such as [`channel_messages`].
+/// such as [`channel_messages`]. The estimated memory usage of resource types
+/// can be retrieved via [`usage`], or in a particular guild via
+/// [`guild_usage`].
 ///
 /// # Examples
 ///
@@ -28,6 +142,8 @@ use super::InMemoryCache;
 /// ```
 ///
 /// [`channel_messages`]: Self::channel_messages
+/// [`guild_usage`]: Self::guild_usage
+/// [`usage`]: Self::usage
 /// [`users`]: Self::users
 #[allow(clippy::type_complexity)]
 #[derive(Clone, Debug)]
@@ -54,6 +170,16 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         self.0
     }
 
+    /// Number of auto moderation rules in the cache.
+    pub fn auto_moderation_rules(&self) -> usize {
+        self.0.auto_moderation_rules.len()
+    }
+
+    /// Number of bans in the cache.
+    pub fn bans(&self) -> usize {
+        self.0.bans.len()
+    }
+
     /// Number of channels in the cache.
     pub fn channels(&self) -> usize {
         self.0.channels.len()
@@ -86,11 +212,40 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         self.0.emojis.len()
     }
 
+    /// Number of emojis evicted from the cache due to reaching the
+    /// configured maximum number of emojis.
+    pub fn emoji_evictions(&self) -> u64 {
+        self.0.emoji_limit.evictions()
+    }
+
+    /// Number of entitlements in the cache.
+    pub fn entitlements(&self) -> usize {
+        self.0.entitlements.len()
+    }
+
     /// Number of guilds in the cache.
     pub fn guilds(&self) -> usize {
         self.0.guilds.len()
     }
 
+    /// Number of auto moderation rules in a given guild in the cache.
+    ///
+    /// Returns `None` if the guild hasn't yet been cached.
+    pub fn guild_auto_moderation_rules(&self, guild_id: Id<GuildMarker>) -> Option<usize> {
+        let guild = self.0.guild_auto_moderation_rules.get(&guild_id)?;
+
+        Some(guild.len())
+    }
+
+    /// Number of bans in a given guild in the cache.
+    ///
+    /// Returns `None` if no bans of the guild have been cached.
+    pub fn guild_bans(&self, guild_id: Id<GuildMarker>) -> Option<usize> {
+        let guild = self.0.guild_bans.get(&guild_id)?;
+
+        Some(guild.len())
+    }
+
     /// Number of channels in a given guild in the cache.
     ///
     /// Returns `None` if the guild hasn't yet been cached.
@@ -136,6 +291,15 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         Some(guild.len())
     }
 
+    /// Number of scheduled events in a given guild in the cache.
+    ///
+    /// Returns `None` if the guild hasn't yet been cached.
+    pub fn guild_scheduled_events(&self, guild_id: Id<GuildMarker>) -> Option<usize> {
+        let guild = self.0.guild_scheduled_events.get(&guild_id)?;
+
+        Some(guild.len())
+    }
+
     /// Number of voice states in a given guild in the cache.
     ///
     /// Returns `None` if the guild hasn't yet been cached.
@@ -150,16 +314,40 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         self.0.members.len()
     }
 
+    /// Number of members evicted from the cache due to reaching the
+    /// configured maximum number of members, either across all guilds or in
+    /// their guild.
+    pub fn member_evictions(&self) -> u64 {
+        self.0.member_limit.evictions()
+            + self
+                .0
+                .member_guild_limits
+                .values()
+                .map(Limit::evictions)
+                .sum::<u64>()
+    }
+
     /// Number of presences in the cache.
     pub fn presences(&self) -> usize {
         self.0.presences.len()
     }
 
+    /// Number of presences evicted from the cache due to reaching the
+    /// configured maximum number of presences.
+    pub fn presence_evictions(&self) -> u64 {
+        self.0.presence_limit.evictions()
+    }
+
     /// Number of roles in the cache.
     pub fn roles(&self) -> usize {
         self.0.roles.len()
     }
 
+    /// Number of scheduled events in the cache.
+    pub fn scheduled_events(&self) -> usize {
+        self.0.scheduled_events.len()
+    }
+
     /// Number of unavailable guilds in the cache.
     pub fn unavailable_guilds(&self) -> usize {
         self.0.unavailable_guilds.len()
@@ -170,17 +358,379 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         self.0.users.len()
     }
 
+    /// Number of users evicted from the cache due to reaching the
+    /// configured maximum number of users.
+    pub fn user_evictions(&self) -> u64 {
+        self.0.user_limit.evictions()
+    }
+
     /// Number of voice states in the cache.
     pub fn voice_states(&self) -> usize {
         self.0.voice_states.len()
     }
+
+    /// Number of entries and estimated memory usage of resource types in the
+    /// cache.
+    ///
+    /// Reactions are part of their messages, so [`ResourceType::REACTION`]
+    /// has no usage of its own.
+    ///
+    /// # Examples
+    ///
+    /// Print the estimated memory usage of members and presences:
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::{DefaultInMemoryCache, ResourceType};
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    ///
+    /// // later on...
+    /// let usage = cache
+    ///     .stats()
+    ///     .usage(ResourceType::MEMBER | ResourceType::PRESENCE);
+    /// println!("{} entries use {} bytes", usage.entries(), usage.estimated_bytes());
+    /// ```
+    #[allow(clippy::missing_panics_doc, clippy::too_many_lines)]
+    pub fn usage(&self, resource_types: ResourceType) -> ResourceUsage {
+        let cache = self.0;
+        let mut usage = ResourceUsage::default();
+
+        if resource_types.contains(ResourceType::AUTO_MODERATION_RULE) {
+            usage += ResourceUsage::of_map(
+                &cache.auto_moderation_rules,
+                resource_heap_size(CacheableAutoModerationRule::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::BAN) {
+            usage += ResourceUsage::of_map(&cache.bans, |_| 0);
+        }
+
+        if resource_types.contains(ResourceType::CHANNEL) {
+            usage += ResourceUsage::of_map(&cache.channels, CacheableChannel::heap_size);
+        }
+
+        if resource_types.contains(ResourceType::EMOJI) {
+            usage +=
+                ResourceUsage::of_map(&cache.emojis, resource_heap_size(CacheableEmoji::heap_size));
+        }
+
+        if resource_types.contains(ResourceType::ENTITLEMENT) {
+            usage += ResourceUsage::of_map(&cache.entitlements, CacheableEntitlement::heap_size);
+        }
+
+        if resource_types.contains(ResourceType::GUILD) {
+            usage += ResourceUsage::of_map(&cache.guilds, CacheableGuild::heap_size);
+        }
+
+        if resource_types.contains(ResourceType::GUILD_SCHEDULED_EVENT) {
+            usage += ResourceUsage::of_map(
+                &cache.scheduled_events,
+                resource_heap_size(CacheableGuildScheduledEvent::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::INTEGRATION) {
+            usage += ResourceUsage::of_map(
+                &cache.integrations,
+                resource_heap_size(CacheableGuildIntegration::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::MEMBER) {
+            usage += ResourceUsage::of_map(&cache.members, CacheableMember::heap_size);
+        }
+
+        if resource_types.contains(ResourceType::MESSAGE) {
+            usage += ResourceUsage::of_map(&cache.messages, CacheableMessage::heap_size);
+        }
+
+        if resource_types.contains(ResourceType::PRESENCE) {
+            usage += ResourceUsage::of_map(&cache.presences, CacheablePresence::heap_size);
+        }
+
+        if resource_types.contains(ResourceType::ROLE) {
+            usage +=
+                ResourceUsage::of_map(&cache.roles, resource_heap_size(CacheableRole::heap_size));
+        }
+
+        if resource_types.contains(ResourceType::STAGE_INSTANCE) {
+            usage += ResourceUsage::of_map(
+                &cache.stage_instances,
+                resource_heap_size(CacheableStageInstance::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::STICKER) {
+            usage += ResourceUsage::of_map(
+                &cache.stickers,
+                resource_heap_size(CacheableSticker::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::USER) {
+            usage += ResourceUsage::of_map(&cache.users, CacheableUser::heap_size);
+        }
+
+        if resource_types.contains(ResourceType::USER_CURRENT) {
+            let current_user = cache.current_user.lock().expect("current user poisoned");
+
+            if let Some(current_user) = current_user.as_ref() {
+                usage +=
+                    ResourceUsage::new::<(), CacheModels::CurrentUser>(current_user.heap_size());
+            }
+        }
+
+        if resource_types.contains(ResourceType::VOICE_STATE) {
+            usage += ResourceUsage::of_map(&cache.voice_states, CacheableVoiceState::heap_size);
+        }
+
+        usage
+    }
+
+    /// Number of entries and estimated memory usage of resource types of a
+    /// given guild in the cache.
+    ///
+    /// Users, including the current user, aren't associated with guilds, so
+    /// [`ResourceType::USER`] and [`ResourceType::USER_CURRENT`] have no
+    /// usage in guilds. Refer to [`usage`] for more information.
+    ///
+    /// [`usage`]: Self::usage
+    #[allow(clippy::too_many_lines)]
+    pub fn guild_usage(
+        &self,
+        guild_id: Id<GuildMarker>,
+        resource_types: ResourceType,
+    ) -> ResourceUsage {
+        let cache = self.0;
+        let mut usage = ResourceUsage::default();
+
+        if resource_types.contains(ResourceType::AUTO_MODERATION_RULE) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_auto_moderation_rules,
+                &cache.auto_moderation_rules,
+                guild_id,
+                |id| id,
+                resource_heap_size(CacheableAutoModerationRule::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::BAN) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_bans,
+                &cache.bans,
+                guild_id,
+                |user_id| (guild_id, user_id),
+                |_| 0,
+            );
+        }
+
+        if resource_types.contains(ResourceType::CHANNEL) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_channels,
+                &cache.channels,
+                guild_id,
+                |id| id,
+                CacheableChannel::heap_size,
+            );
+        }
+
+        if resource_types.contains(ResourceType::EMOJI) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_emojis,
+                &cache.emojis,
+                guild_id,
+                |id| id,
+                resource_heap_size(CacheableEmoji::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::ENTITLEMENT) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_entitlements,
+                &cache.entitlements,
+                guild_id,
+                |id| id,
+                CacheableEntitlement::heap_size,
+            );
+        }
+
+        if resource_types.contains(ResourceType::GUILD) {
+            if let Some(guild) = cache.guilds.get(&guild_id) {
+                usage +=
+                    ResourceUsage::new::<Id<GuildMarker>, CacheModels::Guild>(guild.heap_size());
+            }
+        }
+
+        if resource_types.contains(ResourceType::GUILD_SCHEDULED_EVENT) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_scheduled_events,
+                &cache.scheduled_events,
+                guild_id,
+                |id| id,
+                resource_heap_size(CacheableGuildScheduledEvent::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::INTEGRATION) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_integrations,
+                &cache.integrations,
+                guild_id,
+                |id| (guild_id, id),
+                resource_heap_size(CacheableGuildIntegration::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::MEMBER) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_members,
+                &cache.members,
+                guild_id,
+                |user_id| (guild_id, user_id),
+                CacheableMember::heap_size,
+            );
+        }
+
+        if resource_types.contains(ResourceType::MESSAGE) {
+            if let Some(channel_ids) = cache.guild_channels.get(&guild_id) {
+                let message_ids = channel_ids
+                    .iter()
+                    .filter_map(|channel_id| cache.channel_messages.get(channel_id));
+
+                for message_ids in message_ids {
+                    for message in message_ids.iter().filter_map(|id| cache.messages.get(id)) {
+                        usage += ResourceUsage::new::<Id<MessageMarker>, CacheModels::Message>(
+                            message.heap_size(),
+                        );
+                    }
+                }
+            }
+        }
+
+        if resource_types.contains(ResourceType::PRESENCE) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_presences,
+                &cache.presences,
+                guild_id,
+                |user_id| (guild_id, user_id),
+                CacheablePresence::heap_size,
+            );
+        }
+
+        if resource_types.contains(ResourceType::ROLE) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_roles,
+                &cache.roles,
+                guild_id,
+                |id| id,
+                resource_heap_size(CacheableRole::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::STAGE_INSTANCE) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_stage_instances,
+                &cache.stage_instances,
+                guild_id,
+                |id| id,
+                resource_heap_size(CacheableStageInstance::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::STICKER) {
+            usage += ResourceUsage::of_guild(
+                &cache.guild_stickers,
+                &cache.stickers,
+                guild_id,
+                |id| id,
+                resource_heap_size(CacheableSticker::heap_size),
+            );
+        }
+
+        if resource_types.contains(ResourceType::VOICE_STATE) {
+            usage += ResourceUsage::of_guild(
+                &cache.voice_state_guilds,
+                &cache.voice_states,
+                guild_id,
+                |user_id| (guild_id, user_id),
+                CacheableVoiceState::heap_size,
+            );
+        }
+
+        usage
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::InMemoryCacheStats;
+    use super::ResourceUsage;
+    use crate::{test, DefaultInMemoryCache, InMemoryCacheStats, ResourceType};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::id::Id;
 
     assert_impl_all!(InMemoryCacheStats<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(
+        ResourceUsage: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn usage() {
+        let cache = DefaultInMemoryCache::new();
+        cache.cache_member(Id::new(1), test::member(Id::new(2)));
+        cache.cache_member(Id::new(1), test::member(Id::new(3)));
+        cache.cache_member(Id::new(4), test::member(Id::new(2)));
+
+        let stats = cache.stats();
+        let members = stats.usage(ResourceType::MEMBER);
+        assert_eq!(3, members.entries());
+        assert!(members.estimated_bytes() > 0);
+        assert_eq!(2, stats.usage(ResourceType::USER).entries());
+        assert_eq!(
+            5,
+            stats
+                .usage(ResourceType::MEMBER | ResourceType::USER)
+                .entries()
+        );
+
+        let guild_members = stats.guild_usage(Id::new(1), ResourceType::all());
+        assert_eq!(2, guild_members.entries());
+        assert_eq!(
+            members.estimated_bytes() / 3 * 2,
+            guild_members.estimated_bytes()
+        );
+        assert_eq!(
+            ResourceUsage::default(),
+            stats.guild_usage(Id::new(5), ResourceType::all())
+        );
+    }
+
+    #[test]
+    fn usage_heap_size() {
+        let cache = DefaultInMemoryCache::new();
+        cache.cache_member(Id::new(1), test::member(Id::new(2)));
+        let without_nick = cache.stats().usage(ResourceType::MEMBER);
+
+        let mut member = test::member(Id::new(2));
+        member.nick = Some("nickname".to_owned());
+        member.roles = vec![Id::new(3), Id::new(4)];
+        cache.cache_member(Id::new(1), member);
+        let with_nick = cache.stats().usage(ResourceType::MEMBER);
+
+        assert_eq!(1, with_nick.entries());
+        assert!(with_nick.estimated_bytes() >= without_nick.estimated_bytes() + 8 + 2 * 8);
+        assert_eq!(
+            with_nick,
+            cache.stats().guild_usage(Id::new(1), ResourceType::MEMBER)
+        );
+    }
 }
@@ -1,4 +1,11 @@
-use crate::DefaultInMemoryCache;
+use crate::{
+    backend::{
+        in_memory::{infallible, Storage},
+        CacheBackend,
+    },
+    event, CacheableModels, DefaultInMemoryCache, InMemoryCache, UpdateCache,
+};
+use std::borrow::Cow;
 use twilight_model::{
     channel::{
         message::{
@@ -8,7 +15,7 @@ use twilight_model::{
         Channel, ChannelType,
     },
     gateway::{
-        payload::incoming::{MessageCreate, ReactionAdd},
+        payload::incoming::{GuildCreate, MessageCreate, ReactionAdd},
         GatewayReaction,
     },
     guild::{
@@ -29,18 +36,103 @@ use twilight_model::{
     voice::VoiceState,
 };
 
+/// Caching of single resources as events would, for setting up tests.
+// Tests pass resources by value, as events own them.
+#[allow(clippy::needless_pass_by_value)]
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    pub(crate) fn cache_channel(&self, channel: Channel) {
+        infallible(event::channel::cache_channel(&Storage(self), &channel));
+    }
+
+    pub(crate) fn cache_channels(&self, channels: impl IntoIterator<Item = Channel>) {
+        for channel in channels {
+            self.cache_channel(channel);
+        }
+    }
+
+    pub(crate) fn cache_current_user(&self, current_user: CurrentUser) {
+        infallible(Storage(self).insert_current_user(CacheModels::CurrentUser::from(current_user)));
+    }
+
+    pub(crate) fn cache_emoji(&self, guild_id: Id<GuildMarker>, emoji: Emoji) {
+        infallible(event::emoji::cache_emoji(&Storage(self), guild_id, &emoji));
+    }
+
+    pub(crate) fn cache_emojis(&self, guild_id: Id<GuildMarker>, emojis: Vec<Emoji>) {
+        infallible(event::emoji::cache_emojis(
+            &Storage(self),
+            guild_id,
+            &emojis,
+        ));
+    }
+
+    pub(crate) fn cache_guild(&self, guild: Guild) {
+        GuildCreate::Available(guild).update(self);
+    }
+
+    pub(crate) fn cache_member(&self, guild_id: Id<GuildMarker>, member: Member) {
+        infallible(event::member::cache_member(
+            &Storage(self),
+            guild_id,
+            &member,
+        ));
+    }
+
+    pub(crate) fn cache_members(
+        &self,
+        guild_id: Id<GuildMarker>,
+        members: impl IntoIterator<Item = Member>,
+    ) {
+        for member in members {
+            self.cache_member(guild_id, member);
+        }
+    }
+
+    pub(crate) fn cache_role(&self, guild_id: Id<GuildMarker>, role: Role) {
+        infallible(event::role::cache_role(&Storage(self), guild_id, &role));
+    }
+
+    pub(crate) fn cache_roles(
+        &self,
+        guild_id: Id<GuildMarker>,
+        roles: impl IntoIterator<Item = Role>,
+    ) {
+        for role in roles {
+            self.cache_role(guild_id, role);
+        }
+    }
+
+    pub(crate) fn cache_stickers(&self, guild_id: Id<GuildMarker>, stickers: Vec<Sticker>) {
+        infallible(event::sticker::cache_stickers(
+            &Storage(self),
+            guild_id,
+            &stickers,
+        ));
+    }
+
+    pub(crate) fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<Id<GuildMarker>>) {
+        infallible(event::cache_user(&Storage(self), &user, guild_id));
+    }
+
+    pub(crate) fn cache_voice_state(&self, voice_state: VoiceState) {
+        infallible(event::voice_state::cache_voice_state(
+            &Storage(self),
+            &voice_state,
+        ));
+    }
+}
+
 pub fn cache() -> DefaultInMemoryCache {
     DefaultInMemoryCache::new()
 }
 
 #[allow(clippy::too_many_lines)]
-pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
+pub fn message() -> Message {
     let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
-    let cache = DefaultInMemoryCache::new();
     let avatar = ImageHash::parse(b"6961d9f1fdb5880bf4a3ec6348d3bbcf").unwrap();
     let flags = MemberFlags::BYPASSES_VERIFICATION | MemberFlags::DID_REJOIN;
 
-    let msg = Message {
+    Message {
         activity: None,
         application: None,
         application_id: None,
@@ -105,9 +197,17 @@ pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
         timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
         tts: false,
         webhook_id: None,
-    };
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
+    let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
+    let cache = DefaultInMemoryCache::new();
+    let avatar = ImageHash::parse(b"6961d9f1fdb5880bf4a3ec6348d3bbcf").unwrap();
+    let flags = MemberFlags::BYPASSES_VERIFICATION | MemberFlags::DID_REJOIN;
 
-    cache.update(&MessageCreate(msg));
+    cache.update(&MessageCreate(message()));
 
     let mut reaction = ReactionAdd(GatewayReaction {
         burst: false,
@@ -22,10 +22,10 @@
 //!
 //! [`InMemoryCache`]: crate::InMemoryCache
 
-use crate::model::member::ComputedInteractionMember;
+use crate::{model::member::ComputedInteractionMember, stats::vec_heap_size};
 use std::fmt::Debug;
 use twilight_model::{
-    application::interaction::InteractionMember,
+    application::{interaction::InteractionMember, monetization::Entitlement},
     channel::{
         message::{Reaction, Sticker},
         Channel, ChannelType, Message, StageInstance,
@@ -35,15 +35,17 @@ use twilight_model::{
         presence::Presence,
     },
     guild::{
-        scheduled_event::GuildScheduledEvent, Emoji, Guild, GuildIntegration, Member,
-        PartialMember, Role,
+        auto_moderation::AutoModerationRule, scheduled_event::GuildScheduledEvent, Emoji, Guild,
+        GuildIntegration, Member, PartialMember, Role,
     },
     id::{
         marker::{
-            ChannelMarker, GuildMarker, RoleMarker, ScheduledEventMarker, StickerMarker, UserMarker,
+            ChannelMarker, GuildMarker, RoleMarker, ScheduledEventMarker, SkuMarker, StickerMarker,
+            UserMarker,
         },
         Id,
     },
+    poll::Poll,
     user::{CurrentUser, User},
     util::{ImageHash, Timestamp},
     voice::VoiceState,
@@ -53,12 +55,16 @@ use twilight_model::{channel::permission_overwrite::PermissionOverwrite, guild::
 
 /// Super-trait for the generic cached representations of Discord API models.
 pub trait CacheableModels: Clone + Debug {
+    /// The cached [`AutoModerationRule`] model representation.
+    type AutoModerationRule: CacheableAutoModerationRule;
     /// The cached [`Channel`] model representation.
     type Channel: CacheableChannel;
     /// The cached [`CurrentUser`] model representation.
     type CurrentUser: CacheableCurrentUser;
     /// The cached [`Emoji`] model representation.
     type Emoji: CacheableEmoji;
+    /// The cached [`Entitlement`] model representation.
+    type Entitlement: CacheableEntitlement;
     /// The cached [`Guild`] model representation.
     type Guild: CacheableGuild;
     /// The cached [`GuildIntegration`] model representation.
@@ -113,6 +119,15 @@ pub trait CacheableMember:
 
     /// Update the cached data with a [`MemberUpdate`] event.
     fn update_with_member_update(&mut self, member_update: &MemberUpdate);
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 /// Trait for a generic cached representation of a [`Role`].
@@ -126,6 +141,15 @@ pub trait CacheableRole: From<Role> + PartialEq<Role> + PartialEq<Self> + Clone
     /// Permissions granted to members with the role.
     #[cfg(feature = "permission-calculator")]
     fn permissions(&self) -> Permissions;
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl CacheableRole for Role {
@@ -141,6 +165,10 @@ impl CacheableRole for Role {
     fn permissions(&self) -> Permissions {
         self.permissions
     }
+
+    fn heap_size(&self) -> usize {
+        self.name.capacity() + self.unicode_emoji.as_ref().map_or(0, String::capacity)
+    }
 }
 
 /// Trait for a generic cached representation of a [`Channel`].
@@ -153,19 +181,31 @@ pub trait CacheableChannel:
     /// Type of the channel.
     fn kind(&self) -> ChannelType;
 
-    /// ID of the parent channel if this is a thread.
-    #[cfg(feature = "permission-calculator")]
+    /// ID of the parent channel, the category of a guild channel or the
+    /// channel of a thread.
     fn parent_id(&self) -> Option<Id<ChannelMarker>>;
 
     /// ID of the channel.
     fn id(&self) -> Id<ChannelMarker>;
 
+    /// Sorting position of the channel.
+    fn position(&self) -> Option<i32>;
+
     /// Permission overwrites for the channel.
     #[cfg(feature = "permission-calculator")]
     fn permission_overwrites(&self) -> Option<&[PermissionOverwrite]>;
 
     /// Set the last pin timestamp to a new timestamp.
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>);
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl CacheableChannel for Channel {
@@ -177,7 +217,6 @@ impl CacheableChannel for Channel {
         self.kind
     }
 
-    #[cfg(feature = "permission-calculator")]
     fn parent_id(&self) -> Option<Id<ChannelMarker>> {
         self.parent_id
     }
@@ -186,6 +225,10 @@ impl CacheableChannel for Channel {
         self.id
     }
 
+    fn position(&self) -> Option<i32> {
+        self.position
+    }
+
     #[cfg(feature = "permission-calculator")]
     fn permission_overwrites(&self) -> Option<&[PermissionOverwrite]> {
         self.permission_overwrites.as_deref()
@@ -194,6 +237,14 @@ impl CacheableChannel for Channel {
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>) {
         self.last_pin_timestamp = timestamp;
     }
+
+    fn heap_size(&self) -> usize {
+        self.name.as_ref().map_or(0, String::capacity)
+            + self.topic.as_ref().map_or(0, String::capacity)
+            + self.permission_overwrites.as_ref().map_or(0, vec_heap_size)
+            + self.available_tags.as_ref().map_or(0, vec_heap_size)
+            + self.applied_tags.as_ref().map_or(0, vec_heap_size)
+    }
 }
 
 /// Trait for a generic cached representation of a [`Guild`].
@@ -217,6 +268,15 @@ pub trait CacheableGuild: From<Guild> + PartialEq<Guild> + PartialEq<Self> + Clo
 
     /// Decrease the guild member count.
     fn decrease_member_count(&mut self, amount: u64);
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 /// Trait for a generic cached representation of a [`VoiceState`].
@@ -229,6 +289,15 @@ pub trait CacheableVoiceState:
 {
     /// ID of the channel this voice state belongs to.
     fn channel_id(&self) -> Id<ChannelMarker>;
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 /// Trait for a generic cached representation of a [`Message`].
@@ -238,6 +307,9 @@ pub trait CacheableMessage:
     /// Update the cached data with a [`MessageUpdate`] event.
     fn update_with_message_update(&mut self, message_update: &MessageUpdate);
 
+    /// Mutable getter for the poll attached to this message.
+    fn poll_mut(&mut self) -> Option<&mut Poll>;
+
     /// Reactions added to this message.
     fn reactions(&self) -> &[Reaction];
 
@@ -255,6 +327,15 @@ pub trait CacheableMessage:
 
     /// Remove a reaction from this message.
     fn remove_reaction(&mut self, idx: usize);
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 /// Trait for a generic cached representation of a [`CurrentUser`].
@@ -263,6 +344,15 @@ pub trait CacheableCurrentUser:
 {
     /// ID of the user.
     fn id(&self) -> Id<UserMarker>;
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl CacheableCurrentUser for CurrentUser {
@@ -277,15 +367,44 @@ pub trait CacheableSticker:
 {
     /// ID of the sticker.
     fn id(&self) -> Id<StickerMarker>;
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 /// Trait for a generic cached representation of a [`Emoji`].
-pub trait CacheableEmoji: From<Emoji> + PartialEq<Emoji> + PartialEq<Self> + Clone + Debug {}
+pub trait CacheableEmoji: From<Emoji> + PartialEq<Emoji> + PartialEq<Self> + Clone + Debug {
+    /// Name of the emoji.
+    fn name(&self) -> &str;
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
 
 /// Trait for a generic cached representation of a [`GuildIntegration`].
 pub trait CacheableGuildIntegration:
     From<GuildIntegration> + PartialEq<GuildIntegration> + PartialEq<Self> + Clone + Debug
 {
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl CacheableGuildIntegration for GuildIntegration {}
@@ -294,20 +413,116 @@ impl CacheableGuildIntegration for GuildIntegration {}
 pub trait CacheablePresence:
     From<Presence> + PartialEq<Presence> + PartialEq<Self> + Clone + Debug
 {
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+/// Trait for a generic cached representation of an [`AutoModerationRule`].
+pub trait CacheableAutoModerationRule:
+    From<AutoModerationRule> + PartialEq<AutoModerationRule> + PartialEq<Self> + Clone + Debug
+{
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl CacheableAutoModerationRule for AutoModerationRule {}
+
+/// Trait for a generic cached representation of an [`Entitlement`].
+pub trait CacheableEntitlement:
+    From<Entitlement> + PartialEq<Entitlement> + PartialEq<Self> + Clone + Debug
+{
+    /// Whether the one-time purchase entitlement has been consumed.
+    fn consumed(&self) -> bool;
+
+    /// Time at which the entitlement is no longer valid, if any.
+    fn ends_at(&self) -> Option<Timestamp>;
+
+    /// ID of the guild granted access to the entitlement's SKU.
+    fn guild_id(&self) -> Option<Id<GuildMarker>>;
+
+    /// ID of the entitlement's SKU.
+    fn sku_id(&self) -> Id<SkuMarker>;
+
+    /// ID of the user granted access to the entitlement's SKU.
+    fn user_id(&self) -> Option<Id<UserMarker>>;
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl CacheableEntitlement for Entitlement {
+    fn consumed(&self) -> bool {
+        self.consumed == Some(true)
+    }
+
+    fn ends_at(&self) -> Option<Timestamp> {
+        self.ends_at
+    }
+
+    fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        self.guild_id
+    }
+
+    fn sku_id(&self) -> Id<SkuMarker> {
+        self.sku_id
+    }
+
+    fn user_id(&self) -> Option<Id<UserMarker>> {
+        self.user_id
+    }
 }
 
 /// Trait for a generic cached representation of a [`StageInstance`].
 pub trait CacheableStageInstance:
     From<StageInstance> + PartialEq<StageInstance> + PartialEq<Self> + Clone + Debug
 {
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl CacheableStageInstance for StageInstance {}
 
 /// Trait for a generic cached representation of a [`User`].
-pub trait CacheableUser: From<User> + PartialEq<User> + PartialEq<Self> + Clone + Debug {}
+pub trait CacheableUser: From<User> + PartialEq<User> + PartialEq<Self> + Clone + Debug {
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
 
-impl CacheableUser for User {}
+impl CacheableUser for User {
+    fn heap_size(&self) -> usize {
+        self.name.capacity() + self.global_name.as_ref().map_or(0, String::capacity)
+    }
+}
 
 /// Trait for a generic cached representation of a [`GuildScheduledEvent`].
 pub trait CacheableGuildScheduledEvent:
@@ -328,6 +543,15 @@ pub trait CacheableGuildScheduledEvent:
         event_id: Id<ScheduledEventMarker>,
         user_id: Id<UserMarker>,
     );
+
+    /// Estimated number of bytes allocated on the heap by this value.
+    ///
+    /// Used to estimate memory usage in [`InMemoryCacheStats`]. Defaults to 0.
+    ///
+    /// [`InMemoryCacheStats`]: crate::InMemoryCacheStats
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl CacheableGuildScheduledEvent for GuildScheduledEvent {
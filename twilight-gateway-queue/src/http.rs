@@ -0,0 +1,227 @@
+//! HTTP server exposing a [`Queue`] and a [`Queue`] implementation using it.
+//!
+//! The protocol is shared with [`gateway-queue`]: shards request a permit via
+//! `GET /?shard={id}`, which is answered with a successful status once the
+//! shard may proceed.
+//!
+//! [`gateway-queue`]: https://github.com/twilight-rs/gateway-queue
+
+use super::{Queue, IDENTIFY_DELAY};
+use http_body_util::Empty;
+use hyper::{
+    body::Bytes, http::uri::InvalidUri, server::conn::http1, service::service_fn, Response,
+    StatusCode, Uri,
+};
+use hyper_util::{
+    client::legacy::{connect::HttpConnector, Client},
+    rt::{TokioExecutor, TokioIo, TokioTimer},
+};
+use std::{convert::Infallible, io, sync::Arc};
+use tokio::{net::TcpListener, sync::oneshot, time::sleep};
+
+/// Parse the shard ID from the query of a request's URI.
+fn shard(uri: &Uri) -> Option<u32> {
+    uri.query()?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("shard="))?
+        .parse()
+        .ok()
+}
+
+/// Create a response with an empty body.
+fn response(status: StatusCode) -> Response<Empty<Bytes>> {
+    let mut response = Response::new(Empty::new());
+    *response.status_mut() = status;
+
+    response
+}
+
+/// Serve the queue over HTTP on the listener.
+///
+/// Each request is enqueued into `queue`, responding with `200 OK` once the
+/// shard may proceed, `503 Service Unavailable` if the queue canceled the
+/// request, or `400 Bad Request` if the request had no valid shard ID.
+/// Requests whose connection is closed are dropped from the queue.
+///
+/// # Examples
+///
+/// Share an [`InMemoryQueue`] with shards in other processes:
+///
+/// ```no_run
+/// # let rt = tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap();
+/// use tokio::net::TcpListener;
+/// use twilight_gateway_queue::InMemoryQueue;
+///
+/// # rt.block_on(async {
+/// let listener = TcpListener::bind("0.0.0.0:8000").await?;
+/// twilight_gateway_queue::serve(listener, InMemoryQueue::default()).await?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if accepting a connection failed.
+///
+/// [`InMemoryQueue`]: crate::InMemoryQueue
+pub async fn serve<Q: Queue + Send + Sync + 'static>(
+    listener: TcpListener,
+    queue: Q,
+) -> io::Result<()> {
+    let queue = Arc::new(queue);
+
+    loop {
+        let (stream, address) = listener.accept().await?;
+        tracing::debug!(%address, "accepted connection");
+
+        let queue = Arc::clone(&queue);
+        let service = service_fn(move |request| {
+            let rx = shard(request.uri()).map(|shard| queue.enqueue(shard));
+
+            async move {
+                let status = match rx {
+                    Some(rx) => match rx.await {
+                        Ok(()) => StatusCode::OK,
+                        Err(_) => StatusCode::SERVICE_UNAVAILABLE,
+                    },
+                    None => StatusCode::BAD_REQUEST,
+                };
+
+                Ok::<_, Infallible>(response(status))
+            }
+        });
+
+        tokio::spawn(async move {
+            if let Err(source) = http1::Builder::new()
+                .timer(TokioTimer::new())
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                tracing::debug!(%address, ?source, "connection failed");
+            }
+        });
+    }
+}
+
+/// [`Queue`] implementation requesting permits from a server, such as one
+/// started via [`serve`] or [`gateway-queue`].
+///
+/// Only plain HTTP is supported. Failed requests are retried after
+/// [`IDENTIFY_DELAY`] by the requesting shard. The limits are owned by the
+/// server, so [`Queue::update`] has no effect.
+///
+/// Cloning the queue is cheap and shares the underlying connection pool.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twilight_gateway_queue::HttpQueue;
+///
+/// let queue = HttpQueue::new("http://127.0.0.1:8000")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`gateway-queue`]: https://github.com/twilight-rs/gateway-queue
+#[derive(Clone, Debug)]
+pub struct HttpQueue {
+    /// HTTP client sending the requests.
+    client: Client<HttpConnector, Empty<Bytes>>,
+    /// Base URI of the server, without a trailing slash.
+    uri: Arc<str>,
+}
+
+impl HttpQueue {
+    /// Creates a new `HttpQueue` requesting permits from the server at the
+    /// URI.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URI is invalid.
+    pub fn new(uri: &str) -> Result<Self, InvalidUri> {
+        let uri = uri.trim_end_matches('/');
+        format!("{uri}/?shard=0").parse::<Uri>()?;
+
+        Ok(Self {
+            client: Client::builder(TokioExecutor::new()).build_http(),
+            uri: uri.into(),
+        })
+    }
+
+    /// Request a permit for the shard and signal the shard.
+    async fn acquire(self, shard: u32, mut tx: oneshot::Sender<()>) {
+        let uri = format!("{}/?shard={shard}", self.uri)
+            .parse()
+            .expect("base URI is validated");
+
+        tokio::select! {
+            () = tx.closed() => {}
+            response = self.client.get(uri) => {
+                match response {
+                    Ok(response) if response.status().is_success() => {
+                        tracing::debug!(shard, "acquired permit");
+                        _ = tx.send(());
+
+                        return;
+                    }
+                    Ok(response) => {
+                        tracing::warn!(shard, status = %response.status(), "failed to acquire permit");
+                    }
+                    Err(source) => tracing::warn!(?source, shard, "failed to acquire permit"),
+                }
+
+                sleep(IDENTIFY_DELAY).await;
+            }
+        }
+    }
+}
+
+impl Queue for HttpQueue {
+    fn enqueue(&self, shard: u32) -> oneshot::Receiver<()> {
+        let (tx, rx) = oneshot::channel();
+
+        tokio::spawn(self.clone().acquire(shard, tx));
+
+        rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{serve, shard, HttpQueue};
+    use crate::{InMemoryQueue, Queue};
+    use hyper::Uri;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio::net::TcpListener;
+
+    assert_impl_all!(HttpQueue: Clone, Debug, Send, Sync, Queue);
+
+    #[test]
+    fn shard_query() {
+        let parse = |uri: &str| shard(&uri.parse::<Uri>().unwrap());
+
+        assert_eq!(Some(3), parse("/?shard=3"));
+        assert_eq!(Some(3), parse("/?foo=bar&shard=3"));
+        assert_eq!(None, parse("/"));
+        assert_eq!(None, parse("/?shard=three"));
+    }
+
+    #[test]
+    fn invalid_uri() {
+        assert!(HttpQueue::new("http://127.0.0.1:8000/").is_ok());
+        assert!(HttpQueue::new("http://invalid uri").is_err());
+    }
+
+    #[tokio::test]
+    async fn round_trip() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, InMemoryQueue::default()));
+
+        let queue = HttpQueue::new(&format!("http://{address}")).unwrap();
+        assert!(queue.enqueue(0).await.is_ok());
+    }
+}
@@ -261,6 +261,10 @@ impl Queue for InMemoryQueue {
 
         rx
     }
+
+    fn update(&self, max_concurrency: u16, remaining: u32, reset_after: Duration, total: u32) {
+        InMemoryQueue::update(self, max_concurrency, remaining, reset_after, total);
+    }
 }
 
 #[cfg(test)]
@@ -8,8 +8,16 @@
 )]
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
+#[cfg(feature = "http")]
+mod http;
 mod in_memory;
+#[cfg(feature = "redis")]
+mod redis;
 
+#[cfg(feature = "http")]
+pub use self::http::{serve, HttpQueue};
+#[cfg(feature = "redis")]
+pub use self::redis::RedisQueue;
 pub use in_memory::InMemoryQueue;
 
 use tokio::{sync::oneshot, time::Duration};
@@ -30,6 +38,21 @@ pub trait Queue {
     ///
     /// Closing the channel should causes the shard to requeue.
     fn enqueue(&self, id: u32) -> oneshot::Receiver<()>;
+
+    /// Update the queue's limits with new info from the [Get Gateway Bot]
+    /// endpoint.
+    ///
+    /// `max_concurrency` and the daily permits may change as the bot joins or
+    /// leaves guilds, so this may be regularly called. Does nothing by
+    /// default.
+    ///
+    /// # Panics
+    ///
+    /// Implementations may panic if `total` < `remaining`.
+    ///
+    /// [Get Gateway Bot]: https://discord.com/developers/docs/topics/gateway#get-gateway-bot
+    #[allow(unused_variables)]
+    fn update(&self, max_concurrency: u16, remaining: u32, reset_after: Duration, total: u32) {}
 }
 
 impl<T> Queue for &T
@@ -39,6 +62,10 @@ where
     fn enqueue(&self, shard: u32) -> oneshot::Receiver<()> {
         (**self).enqueue(shard)
     }
+
+    fn update(&self, max_concurrency: u16, remaining: u32, reset_after: Duration, total: u32) {
+        (**self).update(max_concurrency, remaining, reset_after, total);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,256 @@
+//! Redis based [`Queue`] implementation.
+
+use super::{Queue, IDENTIFY_DELAY, LIMIT_PERIOD};
+use ::redis::{aio::ConnectionManager, RedisError, Script};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicU16, AtomicU32, Ordering},
+        Arc,
+    },
+};
+use tokio::{
+    sync::oneshot,
+    time::{sleep, Duration},
+};
+
+/// Script atomically acquiring a permit.
+///
+/// Returns `0` if a permit was acquired, or else the number of milliseconds
+/// to wait before retrying.
+///
+/// Keys:
+///
+/// 1. bucket lock, held for [`IDENTIFY_DELAY`] after each permit
+/// 2. remaining daily permits, reset to the total after [`LIMIT_PERIOD`]
+///
+/// Arguments:
+///
+/// 1. [`IDENTIFY_DELAY`] in milliseconds
+/// 2. total daily permits
+/// 3. [`LIMIT_PERIOD`] in milliseconds
+const ACQUIRE: &str = r"
+local wait = redis.call('PTTL', KEYS[1])
+if wait > 0 then
+    return wait
+end
+
+local remaining = redis.call('GET', KEYS[2])
+if not remaining then
+    redis.call('SET', KEYS[2], ARGV[2], 'PX', ARGV[3])
+    remaining = ARGV[2]
+end
+
+if tonumber(remaining) <= 0 then
+    return math.max(redis.call('PTTL', KEYS[2]), 1)
+end
+
+redis.call('DECR', KEYS[2])
+redis.call('SET', KEYS[1], 1, 'PX', ARGV[1])
+
+return 0
+";
+
+/// Convert a duration to milliseconds, for use as a Redis expiry.
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis())
+        .unwrap_or(u64::MAX)
+        .max(1)
+}
+
+/// Redis based [`Queue`] implementation, coordinating identifies across
+/// processes and machines.
+///
+/// All queues sharing a Redis server and key prefix share their permits,
+/// without any of them acting as a leader. Each bucket's permits are
+/// acquired via an atomic script, so requests in different processes are not
+/// necessarily granted in the order they were made.
+///
+/// The keys are [hash tagged] by the prefix, supporting Redis clusters.
+///
+/// [`Queue::update`] overwrites the remaining permits shared by all queues,
+/// but only updates the other limits of this queue and its clones.
+///
+/// Cloning the queue is cheap and just clones the underlying connection
+/// manager.
+///
+/// **Note:** A `max_concurrency` of `0` processes all requests instantly,
+/// effectively disabling the queue.
+///
+/// # Examples
+///
+/// ```no_run
+/// # let rt = tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap();
+/// use redis::{aio::ConnectionManager, Client};
+/// use std::time::Duration;
+/// use twilight_gateway_queue::RedisQueue;
+///
+/// # rt.block_on(async {
+/// let client = Client::open("redis://127.0.0.1/")?;
+/// let connection = ConnectionManager::new(client).await?;
+/// let queue = RedisQueue::new(
+///     connection,
+///     "my-bot",
+///     1,
+///     1000,
+///     Duration::from_secs(60 * 60 * 24),
+///     1000,
+/// )
+/// .await?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+///
+/// [hash tagged]: https://redis.io/docs/latest/operate/oss_and_stack/reference/cluster-spec/#hash-tags
+#[derive(Clone)]
+pub struct RedisQueue {
+    /// Connection to the Redis server.
+    connection: ConnectionManager,
+    /// The maximum number of concurrent permits to grant.
+    max_concurrency: Arc<AtomicU16>,
+    /// Prefix of the keys, including the hash tag.
+    prefix: Arc<str>,
+    /// Script acquiring a permit.
+    script: Arc<Script>,
+    /// The number of daily permits to reset to.
+    total: Arc<AtomicU32>,
+}
+
+impl RedisQueue {
+    /// Creates a new `RedisQueue` with custom settings.
+    ///
+    /// The remaining permits are only set if no other queue with the same
+    /// prefix set them already.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if setting the remaining permits failed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total` < `remaining`.
+    pub async fn new(
+        mut connection: ConnectionManager,
+        prefix: &str,
+        max_concurrency: u16,
+        remaining: u32,
+        reset_after: Duration,
+        total: u32,
+    ) -> Result<Self, RedisError> {
+        assert!(total >= remaining);
+        let prefix = Arc::<str>::from(format!("{{{prefix}}}:twilight-gateway-queue"));
+
+        ::redis::cmd("SET")
+            .arg(format!("{prefix}:remaining"))
+            .arg(remaining)
+            .arg("NX")
+            .arg("PX")
+            .arg(millis(reset_after))
+            .query_async::<()>(&mut connection)
+            .await?;
+
+        Ok(Self {
+            connection,
+            max_concurrency: Arc::new(AtomicU16::new(max_concurrency)),
+            prefix,
+            script: Arc::new(Script::new(ACQUIRE)),
+            total: Arc::new(AtomicU32::new(total)),
+        })
+    }
+
+    /// Wait for a permit of the shard's bucket and signal the shard.
+    async fn acquire(mut self, shard: u32, tx: oneshot::Sender<()>) {
+        let max_concurrency = self.max_concurrency.load(Ordering::Relaxed);
+        if max_concurrency == 0 {
+            _ = tx.send(());
+            return;
+        }
+
+        let bucket = shard % u32::from(max_concurrency);
+        let mut invocation = self.script.prepare_invoke();
+        invocation
+            .key(format!("{}:bucket:{bucket}", self.prefix))
+            .key(format!("{}:remaining", self.prefix))
+            .arg(millis(IDENTIFY_DELAY))
+            .arg(self.total.load(Ordering::Relaxed))
+            .arg(millis(LIMIT_PERIOD));
+
+        while !tx.is_closed() {
+            match invocation.invoke_async::<u64>(&mut self.connection).await {
+                Ok(0) => {
+                    tracing::debug!(bucket, shard, "acquired permit");
+                    _ = tx.send(());
+
+                    return;
+                }
+                Ok(wait) => sleep(Duration::from_millis(wait)).await,
+                Err(source) => {
+                    tracing::warn!(?source, shard, "failed to acquire permit");
+                    sleep(IDENTIFY_DELAY).await;
+                }
+            }
+        }
+    }
+}
+
+impl Debug for RedisQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RedisQueue")
+            .field("max_concurrency", &self.max_concurrency)
+            .field("prefix", &self.prefix)
+            .field("total", &self.total)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Queue for RedisQueue {
+    fn enqueue(&self, shard: u32) -> oneshot::Receiver<()> {
+        let (tx, rx) = oneshot::channel();
+
+        tokio::spawn(self.clone().acquire(shard, tx));
+
+        rx
+    }
+
+    fn update(&self, max_concurrency: u16, remaining: u32, reset_after: Duration, total: u32) {
+        assert!(total >= remaining);
+        self.max_concurrency
+            .store(max_concurrency, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+
+        let mut connection = self.connection.clone();
+        let key = format!("{}:remaining", self.prefix);
+        tokio::spawn(async move {
+            if let Err(source) = ::redis::cmd("SET")
+                .arg(key)
+                .arg(remaining)
+                .arg("PX")
+                .arg(millis(reset_after))
+                .query_async::<()>(&mut connection)
+                .await
+            {
+                tracing::warn!(?source, "failed to update remaining permits");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{millis, RedisQueue};
+    use crate::Queue;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(RedisQueue: Clone, Debug, Send, Sync, Queue);
+
+    #[test]
+    fn expiry_millis() {
+        assert_eq!(1, millis(Duration::ZERO));
+        assert_eq!(5_000, millis(Duration::from_secs(5)));
+        assert_eq!(u64::MAX, millis(Duration::MAX));
+    }
+}
@@ -34,6 +34,22 @@ async fn update_fills_bucket() {
     assert!(now.elapsed() < IDENTIFY_DELAY, "ran serially");
 }
 
+#[tokio::test]
+async fn trait_update_fills_bucket() {
+    let queue = InMemoryQueue::new(1, 10, Duration::from_secs(60), 10);
+    let queue: &dyn Queue = &queue;
+    let now = Instant::now();
+
+    let t1 = queue.enqueue(0);
+    let t2 = queue.enqueue(1);
+    queue.update(2, 10, Duration::from_secs(60), 10);
+
+    _ = t1.await;
+    _ = t2.await;
+
+    assert!(now.elapsed() < IDENTIFY_DELAY, "ran serially");
+}
+
 #[tokio::test(start_paused = true)]
 async fn integration() {
     let mut queue = InMemoryQueue::new(1, 1000, Duration::ZERO, 1000);
@@ -6,7 +6,7 @@
 use crate::{
     command::Command,
     error::{ChannelError, ChannelErrorType},
-    json, CloseFrame,
+    CloseFrame, Encoding, Message, Priority,
 };
 use tokio::sync::mpsc;
 
@@ -18,22 +18,40 @@ pub struct MessageChannel {
     /// Sending half for users to send close frames via shards.
     pub close_tx: mpsc::Sender<CloseFrame<'static>>,
     /// Receiving half for shards to receive users' commands.
-    pub command_rx: mpsc::UnboundedReceiver<String>,
+    pub command_rx: mpsc::UnboundedReceiver<Message>,
     /// Sending half for users to send commands via shards.
-    pub command_tx: mpsc::UnboundedSender<String>,
+    pub command_tx: mpsc::UnboundedSender<Message>,
+    /// Encoding of sent commands.
+    pub encoding: Encoding,
+    /// Receiving half for shards to receive users' low priority commands.
+    pub low_priority_command_rx: mpsc::UnboundedReceiver<Message>,
+    /// Sending half for users to send low priority commands via shards.
+    pub low_priority_command_tx: mpsc::UnboundedSender<Message>,
 }
 
 impl MessageChannel {
-    /// Initialize a new message channel.
-    pub fn new() -> Self {
+    /// Initialize a new message channel for commands of the encoding.
+    pub fn new(encoding: Encoding) -> Self {
         let (command_tx, command_rx) = mpsc::unbounded_channel();
         let (close_tx, close_rx) = mpsc::channel(1);
+        let (low_priority_command_tx, low_priority_command_rx) = mpsc::unbounded_channel();
 
         Self {
             close_rx,
             close_tx,
             command_rx,
             command_tx,
+            encoding,
+            low_priority_command_rx,
+            low_priority_command_tx,
+        }
+    }
+
+    /// Sending half for users to send commands of the priority via shards.
+    pub const fn command_tx(&self, priority: Priority) -> &mpsc::UnboundedSender<Message> {
+        match priority {
+            Priority::Low => &self.low_priority_command_tx,
+            Priority::Normal => &self.command_tx,
         }
     }
 
@@ -42,6 +60,8 @@ impl MessageChannel {
         MessageSender {
             close: self.close_tx.clone(),
             command: self.command_tx.clone(),
+            encoding: self.encoding,
+            low_priority_command: self.low_priority_command_tx.clone(),
         }
     }
 }
@@ -54,7 +74,11 @@ pub struct MessageSender {
     /// Sending half of the close channel.
     close: mpsc::Sender<CloseFrame<'static>>,
     /// Sending half of the command channel.
-    command: mpsc::UnboundedSender<String>,
+    command: mpsc::UnboundedSender<Message>,
+    /// Encoding of sent commands.
+    encoding: Encoding,
+    /// Sending half of the low priority command channel.
+    low_priority_command: mpsc::UnboundedSender<Message>,
 }
 
 impl MessageSender {
@@ -74,17 +98,48 @@ impl MessageSender {
     /// closed.
     #[allow(clippy::missing_panics_doc)]
     pub fn command(&self, command: &impl Command) -> Result<(), ChannelError> {
-        self.send(json::to_string(command).expect("serialization cannot fail"))
+        self.send_message(self.encoding.command(command), Priority::Normal)
+    }
+
+    /// Send a command of the priority to the associated shard.
+    ///
+    /// Refer to [`Priority`] for how commands are prioritized.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChannelErrorType::Closed`] error type if the channel is
+    /// closed.
+    pub fn command_with_priority(
+        &self,
+        command: &impl Command,
+        priority: Priority,
+    ) -> Result<(), ChannelError> {
+        self.send_message(self.encoding.command(command), priority)
     }
 
     /// Send a JSON encoded gateway event to the associated shard.
     ///
+    /// Note that the gateway expects gateway events in the shard's configured
+    /// [`Encoding`], which [`command`] takes care of.
+    ///
     /// # Errors
     ///
     /// Returns a [`ChannelErrorType::Closed`] error type if the channel is
     /// closed.
+    ///
+    /// [`command`]: Self::command
     pub fn send(&self, json: String) -> Result<(), ChannelError> {
-        self.command.send(json).map_err(|source| ChannelError {
+        self.send_message(Message::Text(json), Priority::Normal)
+    }
+
+    /// Send a message of the priority to the associated shard.
+    fn send_message(&self, message: Message, priority: Priority) -> Result<(), ChannelError> {
+        let sender = match priority {
+            Priority::Low => &self.low_priority_command,
+            Priority::Normal => &self.command,
+        };
+
+        sender.send(message).map_err(|source| ChannelError {
             kind: ChannelErrorType::Closed,
             source: Some(Box::new(source)),
         })
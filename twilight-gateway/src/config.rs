@@ -1,9 +1,13 @@
 //! User configuration for shards.
 
-use crate::{queue::InMemoryQueue, Session};
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+use crate::inflater::Compression;
+use crate::{queue::InMemoryQueue, Encoding, EventTypeFlags, PresenceRotation, Proxy, Session};
+use bitflags::bitflags;
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     sync::Arc,
+    time::Duration,
 };
 use tokio_websockets::Connector;
 use twilight_model::gateway::{
@@ -11,6 +15,33 @@ use twilight_model::gateway::{
     Intents,
 };
 
+bitflags! {
+    /// Parts of maintaining a session a gateway proxy handles instead of the
+    /// shard.
+    ///
+    /// Only has an effect if the shard connects to a gateway proxy via
+    /// [`ConfigBuilder::proxy_url`].
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct ProxyHandling: u8 {
+        /// Proxy keeps the connection alive, so the shard doesn't send
+        /// heartbeats.
+        ///
+        /// Any received event is then considered a heartbeat ACK by the
+        /// [watchdog].
+        ///
+        /// [watchdog]: ConfigBuilder::watchdog
+        const HEARTBEAT = 1;
+        /// Proxy identifies and resumes, so the shard is active once
+        /// connected and always reconnects to the proxy.
+        ///
+        /// The [`queue`] and [`session`] are not used.
+        ///
+        /// [`queue`]: ConfigBuilder::queue
+        /// [`session`]: ConfigBuilder::session
+        const IDENTIFY = 1 << 1;
+    }
+}
+
 /// Wrapper for an authorization token with a debug implementation that redacts
 /// the string.
 #[derive(Clone, Default)]
@@ -40,6 +71,13 @@ impl Debug for Token {
 /// [`From<Config>`] implementation and then rebuilding it into a rew config.
 #[derive(Clone, Debug)]
 pub struct Config<Q = InMemoryQueue> {
+    /// Transport compression of received messages.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+    compression: Compression,
+    /// Proxy to tunnel connections through.
+    connect_proxy: Option<Proxy>,
+    /// Encoding of received events and sent commands.
+    encoding: Encoding,
     /// Identification properties the shard will use.
     identify_properties: Option<IdentifyProperties>,
     /// Intents that the shard requests when identifying with the gateway.
@@ -49,6 +87,10 @@ pub struct Config<Q = InMemoryQueue> {
     large_threshold: u64,
     /// Presence to set when identifying with the gateway.
     presence: Option<UpdatePresencePayload>,
+    /// Presences to rotate through while identified.
+    presence_rotation: Option<PresenceRotation>,
+    /// Session handling done by the gateway proxy.
+    proxy_handling: ProxyHandling,
     /// Gateway proxy URL.
     proxy_url: Option<Box<str>>,
     /// Queue in use by the shard.
@@ -70,6 +112,9 @@ pub struct Config<Q = InMemoryQueue> {
     /// The token is prefixed with "Bot ", which is required by Discord for
     /// authentication.
     token: Token,
+    /// Maximum duration without heartbeat ACKs or dispatch events before the
+    /// shard is reconnected.
+    watchdog: Option<Duration>,
 }
 
 impl Config {
@@ -84,6 +129,22 @@ impl Config {
 }
 
 impl<Q> Config<Q> {
+    /// Transport compression of received messages.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+    pub const fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Immutable reference to the proxy to tunnel connections through.
+    pub const fn connect_proxy(&self) -> Option<&Proxy> {
+        self.connect_proxy.as_ref()
+    }
+
+    /// Encoding of received events and sent commands.
+    pub const fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
     /// Immutable reference to the identification properties the shard will use.
     pub const fn identify_properties(&self) -> Option<&IdentifyProperties> {
         self.identify_properties.as_ref()
@@ -109,6 +170,18 @@ impl<Q> Config<Q> {
         self.presence.as_ref()
     }
 
+    /// Immutable reference to the presences to rotate through while
+    /// identified.
+    pub const fn presence_rotation(&self) -> Option<&PresenceRotation> {
+        self.presence_rotation.as_ref()
+    }
+
+    /// Parts of maintaining a session the gateway proxy handles instead of
+    /// the shard.
+    pub const fn proxy_handling(&self) -> ProxyHandling {
+        self.proxy_handling
+    }
+
     /// Immutable reference to the gateway proxy URL.
     pub fn proxy_url(&self) -> Option<&str> {
         self.proxy_url.as_deref()
@@ -132,6 +205,14 @@ impl<Q> Config<Q> {
         &self.token.inner
     }
 
+    /// Maximum duration without heartbeat ACKs or dispatch events before the
+    /// shard is reconnected, if the watchdog is enabled.
+    ///
+    /// Shards raise it to twice their heartbeat interval if shorter.
+    pub const fn watchdog(&self) -> Option<Duration> {
+        self.watchdog
+    }
+
     /// Url to connect to if the shard resumes on initialization.
     pub(crate) fn take_resume_url(&mut self) -> Option<Box<str>> {
         self.resume_url.take()
@@ -166,10 +247,16 @@ impl ConfigBuilder {
 
         Self {
             inner: Config {
+                #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+                compression: Compression::default(),
+                connect_proxy: None,
+                encoding: Encoding::default(),
                 identify_properties: None,
                 intents,
                 large_threshold: 50,
                 presence: None,
+                presence_rotation: None,
+                proxy_handling: ProxyHandling::empty(),
                 proxy_url: None,
                 queue: InMemoryQueue::default(),
                 ratelimit_messages: true,
@@ -177,6 +264,7 @@ impl ConfigBuilder {
                 session: None,
                 tls: Arc::new(Connector::new().unwrap()),
                 token: Token::new(token.into_boxed_str()),
+                watchdog: None,
             },
         }
     }
@@ -189,6 +277,49 @@ impl<Q> ConfigBuilder<Q> {
         self.inner
     }
 
+    /// Set the transport compression of received messages.
+    ///
+    /// Defaults to [`Compression::Zstd`] if the `zstd` feature is enabled and
+    /// [`Compression::Zlib`] otherwise.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+    pub const fn compression(mut self, compression: Compression) -> Self {
+        self.inner.compression = compression;
+
+        self
+    }
+
+    /// Set a proxy to tunnel connections through, for environments that
+    /// can't connect to the gateway directly.
+    ///
+    /// Connections to the [`proxy_url`] are also tunneled through the proxy.
+    ///
+    /// Defaults to connecting directly.
+    ///
+    /// [`proxy_url`]: Self::proxy_url
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn connect_proxy(mut self, connect_proxy: Proxy) -> Self {
+        self.inner.connect_proxy = Some(connect_proxy);
+
+        self
+    }
+
+    /// Set the encoding of received events and sent commands.
+    ///
+    /// Events are then received as [`Message`]s of the encoding, which
+    /// [`StreamExt::next_event`] parses either way, and commands sent via
+    /// [`Shard::command`] are encoded with it.
+    ///
+    /// Defaults to [`Encoding::Json`].
+    ///
+    /// [`Message`]: crate::Message
+    /// [`Shard::command`]: crate::Shard::command
+    /// [`StreamExt::next_event`]: crate::StreamExt::next_event
+    pub const fn encoding(mut self, encoding: Encoding) -> Self {
+        self.inner.encoding = encoding;
+
+        self
+    }
+
     /// Set the properties to identify with.
     ///
     /// This may be used if you want to set a different operating system, for
@@ -219,6 +350,48 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set the intents to those required to receive the event types.
+    ///
+    /// Refer to [`EventTypeFlags::intents`] for how the intents are derived.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{ConfigBuilder, EventTypeFlags, Intents};
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let event_types = EventTypeFlags::GUILD_CREATE | EventTypeFlags::MESSAGE_CREATE;
+    ///
+    /// let config = ConfigBuilder::new(token, Intents::empty())
+    ///     .intents_for(event_types)
+    ///     .build();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn intents_for(mut self, event_types: EventTypeFlags) -> Self {
+        self.inner.intents = event_types.intents();
+
+        self
+    }
+
+    /// Log a warning if some event types can never be received under the
+    /// configured intents.
+    ///
+    /// Refer to [`EventTypeFlags::unreceivable`] for retrieving these event
+    /// types instead.
+    pub fn warn_unreceivable(self, event_types: EventTypeFlags) -> Self {
+        let unreceivable = event_types.unreceivable(self.inner.intents);
+        if !unreceivable.is_empty() {
+            tracing::warn!(
+                intents = ?self.inner.intents,
+                ?unreceivable,
+                "event types can never be received under configured intents",
+            );
+        }
+
+        self
+    }
+
     /// Set the maximum number of members in a guild to load the member list.
     ///
     /// Default value is `50`. The minimum value is `50` and the maximum is
@@ -302,10 +475,98 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set the presences to rotate through while identified.
+    ///
+    /// The first presence is sent one interval after identifying, so set the
+    /// initial presence via [`presence`].
+    ///
+    /// Default is no rotation.
+    ///
+    /// # Examples
+    ///
+    /// Alternate between two activities every five minutes:
+    ///
+    /// ```no_run
+    /// use std::{env, time::Duration};
+    /// use twilight_gateway::{ConfigBuilder, Intents, PresenceRotation};
+    /// use twilight_model::gateway::{
+    ///     payload::outgoing::update_presence::UpdatePresencePayload,
+    ///     presence::{ActivityType, MinimalActivity, Status},
+    /// };
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let presence = |name: &str| {
+    ///     UpdatePresencePayload::new(
+    ///         vec![MinimalActivity {
+    ///             kind: ActivityType::Playing,
+    ///             name: name.to_owned(),
+    ///             url: None,
+    ///         }
+    ///         .into()],
+    ///         false,
+    ///         None,
+    ///         Status::Online,
+    ///     )
+    /// };
+    /// let first = presence("/help")?;
+    ///
+    /// let config = ConfigBuilder::new(env::var("DISCORD_TOKEN")?, Intents::empty())
+    ///     .presence(first.clone())
+    ///     .presence_rotation(PresenceRotation::new(
+    ///         [presence("twilight")?, first],
+    ///         Duration::from_secs(60 * 5),
+    ///     ))
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`presence`]: Self::presence
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn presence_rotation(mut self, presence_rotation: PresenceRotation) -> Self {
+        self.inner.presence_rotation = Some(presence_rotation);
+
+        self
+    }
+
+    /// Set the parts of maintaining a session the gateway proxy handles
+    /// instead of the shard.
+    ///
+    /// Proxies handling all of it provide a pre-authenticated stream of
+    /// events, which the shard passes on without identifying, resuming, or
+    /// heartbeating.
+    ///
+    /// Only has an effect if [`proxy_url`] is set. Defaults to the shard
+    /// handling everything itself.
+    ///
+    /// # Examples
+    ///
+    /// Connect to a gateway proxy that identifies and heartbeats itself:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{ConfigBuilder, Intents, ProxyHandling};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ConfigBuilder::new(env::var("DISCORD_TOKEN")?, Intents::empty())
+    ///     .proxy_url("ws://localhost:7878".to_owned())
+    ///     .proxy_handling(ProxyHandling::all())
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`proxy_url`]: Self::proxy_url
+    pub const fn proxy_handling(mut self, proxy_handling: ProxyHandling) -> Self {
+        self.inner.proxy_handling = proxy_handling;
+
+        self
+    }
+
     /// Set the proxy URL for connecting to the gateway.
     ///
-    /// Resumes are always done to the URL specified in [`resume_gateway_url`].
+    /// Resumes are always done to the URL specified in [`resume_gateway_url`],
+    /// unless the proxy handles identifying via [`proxy_handling`].
     ///
+    /// [`proxy_handling`]: Self::proxy_handling
     /// [`resume_gateway_url`]: twilight_model::gateway::payload::incoming::Ready::resume_gateway_url
     #[allow(clippy::missing_const_for_fn)]
     pub fn proxy_url(mut self, proxy_url: String) -> Self {
@@ -322,10 +583,16 @@ impl<Q> ConfigBuilder<Q> {
     /// turns itself into a no-op.
     pub fn queue<NewQ>(self, queue: NewQ) -> ConfigBuilder<NewQ> {
         let Config {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+            compression,
+            connect_proxy,
+            encoding,
             identify_properties,
             intents,
             large_threshold,
             presence,
+            presence_rotation,
+            proxy_handling,
             proxy_url,
             queue: _,
             ratelimit_messages,
@@ -333,14 +600,21 @@ impl<Q> ConfigBuilder<Q> {
             session,
             tls,
             token,
+            watchdog,
         } = self.inner;
 
         ConfigBuilder {
             inner: Config {
+                #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+                compression,
+                connect_proxy,
+                encoding,
                 identify_properties,
                 intents,
                 large_threshold,
                 presence,
+                presence_rotation,
+                proxy_handling,
                 proxy_url,
                 queue,
                 ratelimit_messages,
@@ -348,6 +622,7 @@ impl<Q> ConfigBuilder<Q> {
                 session,
                 tls,
                 token,
+                watchdog,
             },
         }
     }
@@ -390,6 +665,28 @@ impl<Q> ConfigBuilder<Q> {
 
         self
     }
+
+    /// Set a watchdog reconnecting the shard once it hasn't received a
+    /// heartbeat ACK or a dispatch event within a timeout.
+    ///
+    /// Connections may stall without being closed, in which case the shard
+    /// would otherwise wait for events indefinitely. Once the watchdog
+    /// detects a stall, the shard returns a
+    /// [`ReceiveMessageErrorType::Stalled`] error and resumes the session.
+    ///
+    /// The timeout is raised to twice the heartbeat interval if shorter, as
+    /// heartbeat ACKs are only received once per interval. It should also be
+    /// longer than the longest expected duration between dispatch events, as
+    /// shards of quiet bots are otherwise reconnected while healthy.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`ReceiveMessageErrorType::Stalled`]: crate::error::ReceiveMessageErrorType::Stalled
+    pub const fn watchdog(mut self, timeout: Duration) -> Self {
+        self.inner.watchdog = Some(timeout);
+
+        self
+    }
 }
 
 impl<Q> From<Config<Q>> for ConfigBuilder<Q> {
@@ -401,6 +698,7 @@ impl<Q> From<Config<Q>> for ConfigBuilder<Q> {
 #[cfg(test)]
 mod tests {
     use super::{Config, ConfigBuilder};
+    use crate::EventTypeFlags;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
     use twilight_model::gateway::Intents;
@@ -436,6 +734,16 @@ mod tests {
         drop(builder().large_threshold(251));
     }
 
+    #[tokio::test]
+    async fn intents_for() {
+        let event_types = EventTypeFlags::GUILD_CREATE | EventTypeFlags::VOICE_STATE_UPDATE;
+
+        assert_eq!(
+            Intents::GUILDS | Intents::GUILD_VOICE_STATES,
+            builder().intents_for(event_types).build().intents()
+        );
+    }
+
     #[tokio::test]
     async fn config_prefixes_bot_to_token() {
         const WITHOUT: &str = "test";
@@ -0,0 +1,580 @@
+//! Assignment of shards to worker processes.
+//!
+//! Workers hold leases in a shared [`LeaseStore`], which they regularly renew
+//! through their [`Coordinator`]. Every worker derives the same assignment
+//! from the set of workers with live leases via rendezvous hashing, so a
+//! worker joining or leaving only moves the shards it gains or loses.
+//!
+//! Shards moving between live workers are handed off: the new owner only
+//! starts them once a lease duration has passed, by which the previous owner
+//! has renewed its lease, learned of the move, and stopped them.
+
+use crate::ShardId;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    sync::oneshot,
+    time::{self, Duration, Instant},
+};
+
+/// Shards assigned to a worker.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Assignment {
+    /// Incremented each time the assigned or pending shards change.
+    pub generation: u64,
+    /// Shards being handed off to the worker, sorted by ID.
+    ///
+    /// They become part of [`shards`] once their previous owner has had the
+    /// time to stop them.
+    ///
+    /// [`shards`]: Self::shards
+    pub pending: Vec<u32>,
+    /// Shards assigned to the worker, sorted by ID.
+    pub shards: Vec<u32>,
+    /// Fencing token of the worker's lease.
+    ///
+    /// Greater than the tokens of all earlier leases, so external systems may
+    /// reject requests carrying an older token from a worker that lost its
+    /// lease without noticing.
+    pub token: u64,
+    /// Total number of shards.
+    pub total: u32,
+}
+
+impl Assignment {
+    /// IDs of the assigned shards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_gateway::{coordinator::Assignment, ShardId};
+    ///
+    /// let assignment = Assignment {
+    ///     generation: 1,
+    ///     pending: Vec::new(),
+    ///     shards: vec![5, 7],
+    ///     token: 1,
+    ///     total: 20,
+    /// };
+    /// assert_eq!(
+    ///     vec![ShardId::new(5, 20), ShardId::new(7, 20)],
+    ///     assignment.shard_ids().collect::<Vec<_>>()
+    /// );
+    /// ```
+    ///
+    /// Pass `shards` and `total` to [`create_iterator`] to create the shards.
+    ///
+    /// [`create_iterator`]: crate::create_iterator
+    pub fn shard_ids(&self) -> impl ExactSizeIterator<Item = ShardId> + '_ {
+        self.shards.iter().map(|&id| ShardId::new(id, self.total))
+    }
+}
+
+/// Leases of workers, as returned by [`LeaseStore::renew`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Renewal {
+    /// Fencing token of the renewed lease.
+    pub token: u64,
+    /// Names of the workers with live leases, including the renewing worker.
+    pub workers: Vec<Box<str>>,
+}
+
+/// Abstraction for types storing the leases of workers.
+///
+/// [`InMemoryLeaseStore`] shares leases between workers of a single process.
+/// Implementations backed by an external store, such as Redis or etcd, allow
+/// coordinating workers across processes without a leader.
+pub trait LeaseStore {
+    /// Acquire or renew the worker's lease for `ttl`.
+    ///
+    /// Acquiring, when `token` is `None`, always succeeds and issues a token
+    /// greater than all previously issued ones, taking over the lease from an
+    /// earlier holder of the worker's name. Renewing only succeeds if `token`
+    /// is the lease's current token and the lease hasn't expired.
+    ///
+    /// Send the worker's token and the live workers once acquired or renewed.
+    /// Closing the channel indicates the lease could not be renewed.
+    fn renew(&self, worker: &str, token: Option<u64>, ttl: Duration) -> oneshot::Receiver<Renewal>;
+
+    /// Release the worker's lease if `token` is its current token.
+    fn release(&self, worker: &str, token: u64);
+}
+
+impl<T> LeaseStore for &T
+where
+    T: LeaseStore,
+{
+    fn renew(&self, worker: &str, token: Option<u64>, ttl: Duration) -> oneshot::Receiver<Renewal> {
+        (**self).renew(worker, token, ttl)
+    }
+
+    fn release(&self, worker: &str, token: u64) {
+        (**self).release(worker, token);
+    }
+}
+
+/// Lease stored by an [`InMemoryLeaseStore`].
+#[derive(Debug)]
+struct StoredLease {
+    /// When the lease expires.
+    expires: Instant,
+    /// Fencing token of the lease.
+    token: u64,
+}
+
+/// [`InMemoryLeaseStore`]'s state.
+#[derive(Debug, Default)]
+struct Leases {
+    /// Leases by worker.
+    leases: BTreeMap<Box<str>, StoredLease>,
+    /// Last issued fencing token.
+    token: u64,
+}
+
+impl Leases {
+    /// Remove expired leases.
+    fn prune(&mut self, now: Instant) {
+        self.leases.retain(|worker, lease| {
+            let alive = lease.expires > now;
+            if !alive {
+                tracing::info!(worker = &**worker, "worker lease expired");
+            }
+
+            alive
+        });
+    }
+}
+
+/// Memory based [`LeaseStore`] implementation.
+///
+/// Cloning the store is cheap and shares the underlying leases.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryLeaseStore(Arc<Mutex<Leases>>);
+
+impl InMemoryLeaseStore {
+    /// Creates a new `InMemoryLeaseStore` without leases.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Names of the workers with live leases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    pub fn workers(&self) -> Vec<Box<str>> {
+        let mut leases = self.0.lock().expect("not poisoned");
+        leases.prune(Instant::now());
+
+        leases.leases.keys().cloned().collect()
+    }
+}
+
+impl LeaseStore for InMemoryLeaseStore {
+    fn renew(&self, worker: &str, token: Option<u64>, ttl: Duration) -> oneshot::Receiver<Renewal> {
+        let (tx, rx) = oneshot::channel();
+
+        let mut leases = self.0.lock().expect("not poisoned");
+        let now = Instant::now();
+        leases.prune(now);
+
+        let token = if let Some(token) = token {
+            match leases.leases.get_mut(worker) {
+                Some(lease) if lease.token == token => {
+                    lease.expires = now + ttl;
+
+                    token
+                }
+                _ => return rx,
+            }
+        } else {
+            leases.token += 1;
+            let token = leases.token;
+            leases.leases.insert(
+                worker.into(),
+                StoredLease {
+                    expires: now + ttl,
+                    token,
+                },
+            );
+            tracing::info!(worker, token, "worker lease acquired");
+
+            token
+        };
+
+        _ = tx.send(Renewal {
+            token,
+            workers: leases.leases.keys().cloned().collect(),
+        });
+
+        rx
+    }
+
+    fn release(&self, worker: &str, token: u64) {
+        let mut leases = self.0.lock().expect("not poisoned");
+        if leases
+            .leases
+            .get(worker)
+            .is_some_and(|lease| lease.token == token)
+        {
+            leases.leases.remove(worker);
+            tracing::info!(worker, "worker lease released");
+        }
+    }
+}
+
+/// Worker's view of the shard assignment.
+///
+/// Call [`heartbeat`] more often than the lease's `ttl`, such as every third
+/// of it, to renew the lease and learn of the current assignment. Start the
+/// [`shards`] of the assignment, and stop shards no longer part of it. Stop
+/// all shards if a heartbeat returns `None`.
+///
+/// A newly joined worker's shards are pending for a lease duration, in case
+/// another worker or a previous process of the same name still runs them.
+///
+/// # Examples
+///
+/// Take over a dead worker's shards:
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+/// use std::time::Duration;
+/// use twilight_gateway::coordinator::{Coordinator, InMemoryLeaseStore};
+///
+/// const TTL: Duration = Duration::from_secs(30);
+///
+/// let store = InMemoryLeaseStore::new();
+/// let mut a = Coordinator::new(store.clone(), "a", 10, TTL);
+/// let mut b = Coordinator::new(store, "b", 10, TTL);
+///
+/// let assignment = a.heartbeat().await.unwrap();
+/// assert!(assignment.shards.is_empty());
+/// assert_eq!(10, assignment.pending.len());
+/// b.heartbeat().await.unwrap();
+///
+/// for _ in 0..3 {
+///     tokio::time::sleep(TTL / 2).await;
+///     b.heartbeat().await.unwrap();
+/// }
+///
+/// // "a" stopped sending heartbeats, so "b" owns all shards.
+/// assert_eq!(10, b.heartbeat().await.unwrap().shards.len());
+/// assert!(a.heartbeat().await.is_none());
+/// # }
+/// ```
+///
+/// [`heartbeat`]: Self::heartbeat
+/// [`shards`]: Assignment::shards
+#[derive(Debug)]
+pub struct Coordinator<S = InMemoryLeaseStore> {
+    /// Generation of the current assignment.
+    generation: u64,
+    /// Shards being handed off to the worker by when they're ready.
+    handoffs: BTreeMap<u32, Instant>,
+    /// Shards assigned to the worker.
+    owned: BTreeSet<u32>,
+    /// When the lease was last renewed, if held.
+    renewed: Option<Instant>,
+    /// Store of the leases.
+    store: S,
+    /// Fencing token of the lease, if held.
+    token: Option<u64>,
+    /// Total number of shards.
+    total: u32,
+    /// Duration of the lease.
+    ttl: Duration,
+    /// Name of the worker.
+    worker: Box<str>,
+    /// Sorted workers with live leases as of the last heartbeat, if any.
+    workers: Option<Vec<Box<str>>>,
+}
+
+impl<S: LeaseStore> Coordinator<S> {
+    /// Creates a new `Coordinator` for the worker, assigning it a share of
+    /// `total` shards while its lease is renewed at most `ttl` apart.
+    pub fn new(store: S, worker: impl Into<Box<str>>, total: u32, ttl: Duration) -> Self {
+        Self {
+            generation: 0,
+            handoffs: BTreeMap::new(),
+            owned: BTreeSet::new(),
+            renewed: None,
+            store,
+            token: None,
+            total,
+            ttl,
+            worker: worker.into(),
+            workers: None,
+        }
+    }
+
+    /// Renew the worker's lease, acquiring it if not held, and return the
+    /// worker's current assignment.
+    ///
+    /// Returns `None` if the lease could not be renewed before it expired,
+    /// after which the next heartbeat acquires a new lease.
+    pub async fn heartbeat(&mut self) -> Option<Assignment> {
+        let now = Instant::now();
+        let deadline = self
+            .renewed
+            .map_or(now + self.ttl, |renewed| renewed + self.ttl);
+        let renewal = self.store.renew(&self.worker, self.token, self.ttl);
+
+        let Ok(Ok(renewal)) = time::timeout_at(deadline, renewal).await else {
+            if self.token.is_some() {
+                tracing::info!(worker = &*self.worker, "worker lease lost");
+            }
+            self.reset();
+
+            return None;
+        };
+
+        self.renewed = Some(now);
+        self.token = Some(renewal.token);
+        let mut workers = renewal.workers;
+        workers.sort_unstable();
+
+        let now = Instant::now();
+        let mut handoffs = BTreeMap::new();
+        let mut owned = BTreeSet::new();
+        for shard in 0..self.total {
+            if owner(&workers, shard) != Some(&*self.worker) {
+                continue;
+            }
+
+            if self.owned.contains(&shard) {
+                owned.insert(shard);
+                continue;
+            }
+
+            let ready = self.handoffs.get(&shard).copied().unwrap_or_else(|| {
+                let previous = self
+                    .workers
+                    .as_deref()
+                    .and_then(|previous| owner(previous, shard));
+
+                // The shard's previous owner stopped it when its lease ended.
+                match previous {
+                    Some(previous) if !workers.iter().any(|worker| **worker == *previous) => now,
+                    _ => now + self.ttl,
+                }
+            });
+
+            if ready <= now {
+                owned.insert(shard);
+            } else {
+                handoffs.insert(shard, ready);
+            }
+        }
+
+        if owned != self.owned || !handoffs.keys().eq(self.handoffs.keys()) {
+            self.generation += 1;
+        }
+        self.handoffs = handoffs;
+        self.owned = owned;
+        self.workers = Some(workers);
+
+        Some(Assignment {
+            generation: self.generation,
+            pending: self.handoffs.keys().copied().collect(),
+            shards: self.owned.iter().copied().collect(),
+            token: renewal.token,
+            total: self.total,
+        })
+    }
+
+    /// Release the worker's lease, handing its shards off to the remaining
+    /// workers.
+    ///
+    /// Stop the worker's shards before calling this.
+    pub fn leave(&mut self) {
+        if let Some(token) = self.token {
+            self.store.release(&self.worker, token);
+        }
+        self.reset();
+    }
+
+    /// Change the total number of shards, reassigning all shards.
+    ///
+    /// All workers must be updated to the same total.
+    pub fn update(&mut self, total: u32) {
+        if self.total != total {
+            self.handoffs.clear();
+            self.owned.clear();
+            self.total = total;
+            self.workers = None;
+        }
+    }
+
+    /// Forget the lease and assignment.
+    fn reset(&mut self) {
+        if !self.owned.is_empty() || !self.handoffs.is_empty() {
+            self.generation += 1;
+        }
+        self.handoffs.clear();
+        self.owned.clear();
+        self.renewed = None;
+        self.token = None;
+        self.workers = None;
+    }
+}
+
+/// Owner of the shard among the workers by rendezvous hashing.
+///
+/// The worker with the highest weight for the shard owns it, so only the
+/// shards of a joining or leaving worker change owner.
+fn owner(workers: &[Box<str>], shard: u32) -> Option<&str> {
+    workers
+        .iter()
+        .map(|worker| &**worker)
+        .max_by_key(|worker| (weight(worker, shard), *worker))
+}
+
+/// Weight of the worker for the shard.
+///
+/// Uses FNV-1a with a `SplitMix64` finalizer, as all workers must agree on it
+/// regardless of their Rust version.
+fn weight(worker: &str, shard: u32) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    // `0xff` never occurs in UTF-8, separating the name from the shard.
+    for byte in worker.bytes().chain([0xff]).chain(shard.to_le_bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{owner, Assignment, Coordinator, InMemoryLeaseStore, LeaseStore, Renewal};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{fmt::Debug, hash::Hash, time::Duration};
+    use tokio::time;
+
+    assert_impl_all!(Assignment: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(Coordinator: Debug, Send, Sync);
+    assert_impl_all!(InMemoryLeaseStore: Clone, Debug, LeaseStore, Send, Sync);
+    assert_impl_all!(Renewal: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_obj_safe!(LeaseStore);
+
+    const TOTAL: u32 = 64;
+
+    const TTL: Duration = Duration::from_secs(30);
+
+    fn workers(names: &[&str]) -> Vec<Box<str>> {
+        names.iter().map(|&name| name.into()).collect()
+    }
+
+    #[test]
+    fn rendezvous_is_stable() {
+        let before = workers(&["a", "b", "c"]);
+        let after = workers(&["a", "b", "c", "d"]);
+
+        let mut moved = 0;
+        for shard in 0..TOTAL {
+            let old = owner(&before, shard).unwrap();
+            let new = owner(&after, shard).unwrap();
+            if old != new {
+                assert_eq!("d", new);
+                moved += 1;
+            }
+        }
+
+        assert!(moved > 0);
+        assert!(moved < TOTAL / 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn handoff() {
+        let store = InMemoryLeaseStore::new();
+        let mut a = Coordinator::new(store.clone(), "a", TOTAL, TTL);
+
+        let assignment = a.heartbeat().await.unwrap();
+        assert_eq!(1, assignment.generation);
+        assert!(assignment.shards.is_empty());
+        assert_eq!(TOTAL as usize, assignment.pending.len());
+
+        time::advance(TTL / 2).await;
+        a.heartbeat().await.unwrap();
+        time::advance(TTL / 2).await;
+        let assignment = a.heartbeat().await.unwrap();
+        assert_eq!(2, assignment.generation);
+        assert_eq!((0..TOTAL).collect::<Vec<_>>(), assignment.shards);
+
+        let mut b = Coordinator::new(store.clone(), "b", TOTAL, TTL);
+        let joined = b.heartbeat().await.unwrap();
+        assert!(joined.shards.is_empty());
+
+        let assignment = a.heartbeat().await.unwrap();
+        assert_eq!(3, assignment.generation);
+        assert_eq!(
+            TOTAL as usize,
+            assignment.shards.len() + joined.pending.len()
+        );
+        assert!(assignment
+            .shards
+            .iter()
+            .all(|s| !joined.pending.contains(s)));
+
+        time::advance(TTL / 2).await;
+        a.heartbeat().await.unwrap();
+        assert!(b.heartbeat().await.unwrap().shards.is_empty());
+        time::advance(TTL / 2).await;
+        a.heartbeat().await.unwrap();
+        assert_eq!(joined.pending, b.heartbeat().await.unwrap().shards);
+
+        // Shards of a leaving worker are taken over immediately.
+        a.leave();
+        assert_eq!(vec![Box::from("b")], store.workers());
+        let assignment = b.heartbeat().await.unwrap();
+        assert!(assignment.pending.is_empty());
+        assert_eq!((0..TOTAL).collect::<Vec<_>>(), assignment.shards);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fencing() {
+        let store = InMemoryLeaseStore::new();
+        let mut old = Coordinator::new(store.clone(), "a", TOTAL, TTL);
+        let first = old.heartbeat().await.unwrap().token;
+
+        // A restarted process of the same name takes over the lease.
+        let mut new = Coordinator::new(store.clone(), "a", TOTAL, TTL);
+        let second = new.heartbeat().await.unwrap().token;
+        assert!(second > first);
+
+        assert!(old.heartbeat().await.is_none());
+        assert_eq!(second, new.heartbeat().await.unwrap().token);
+
+        // Releasing a stale lease doesn't affect the new holder.
+        store.release("a", first);
+        assert_eq!(vec![Box::from("a")], store.workers());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expired() {
+        let store = InMemoryLeaseStore::new();
+        let mut a = Coordinator::new(store.clone(), "a", TOTAL, TTL);
+        a.heartbeat().await.unwrap();
+
+        time::advance(TTL).await;
+        assert!(store.workers().is_empty());
+        assert!(a.heartbeat().await.is_none());
+
+        let assignment = a.heartbeat().await.unwrap();
+        assert_eq!(2, assignment.token);
+        assert_eq!(TOTAL as usize, assignment.pending.len());
+
+        a.update(TOTAL * 2);
+        let assignment = a.heartbeat().await.unwrap();
+        assert_eq!(TOTAL * 2, assignment.total);
+        assert_eq!(TOTAL as usize * 2, assignment.pending.len());
+    }
+}
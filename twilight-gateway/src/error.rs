@@ -1,11 +1,14 @@
 //! Errors returned by gateway operations.
 
-#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+#[cfg(feature = "etf")]
+pub use crate::etf::{EtfError, EtfErrorType};
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
 pub use crate::inflater::{CompressionError, CompressionErrorType};
 
 use std::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
+    time::Duration,
 };
 
 /// Sending a command over a channel failed.
@@ -134,6 +137,184 @@ pub enum StartRecommendedErrorType {
     Request,
 }
 
+/// Starting shards would exhaust the session start limit tracked by a
+/// [`SessionBudget`].
+///
+/// [`SessionBudget`]: crate::SessionBudget
+#[cfg(feature = "twilight-http")]
+#[derive(Debug)]
+pub struct SessionBudgetError {
+    /// Type of error.
+    pub(crate) kind: SessionBudgetErrorType,
+    /// Source error if available.
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+#[cfg(feature = "twilight-http")]
+impl SessionBudgetError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SessionBudgetErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (SessionBudgetErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+#[cfg(feature = "twilight-http")]
+impl Display for SessionBudgetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            SessionBudgetErrorType::Exhausted {
+                remaining,
+                requested,
+                reset_after,
+            } => {
+                f.write_str("starting ")?;
+                Display::fmt(requested, f)?;
+                f.write_str(" shards would exhaust the ")?;
+                Display::fmt(remaining, f)?;
+                f.write_str(" remaining sessions, which reset in ")?;
+
+                Debug::fmt(reset_after, f)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "twilight-http")]
+impl Error for SessionBudgetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`SessionBudgetError`] that occurred.
+#[cfg(feature = "twilight-http")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SessionBudgetErrorType {
+    /// Fewer sessions than shards may be started.
+    Exhausted {
+        /// Number of sessions that may still be started.
+        remaining: u32,
+        /// Number of shards requested to be started.
+        requested: u32,
+        /// Duration until the session start limit resets.
+        reset_after: Duration,
+    },
+}
+
+/// Tunneling a connection through a [`Proxy`] failed.
+///
+/// [`Proxy`]: crate::Proxy
+#[derive(Debug)]
+pub struct ProxyError {
+    /// Type of error.
+    pub(crate) kind: ProxyErrorType,
+    /// Source error if available.
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ProxyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ProxyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ProxyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Shortcut to create a new error for a failed authentication.
+    pub(crate) const fn authentication() -> Self {
+        Self {
+            kind: ProxyErrorType::Authentication,
+            source: None,
+        }
+    }
+
+    /// Shortcut to create a new error for an I/O error.
+    pub(crate) fn from_io(source: std::io::Error) -> Self {
+        Self {
+            kind: ProxyErrorType::Io,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    /// Shortcut to create a new error for an invalid response.
+    pub(crate) const fn invalid_response() -> Self {
+        Self {
+            kind: ProxyErrorType::InvalidResponse,
+            source: None,
+        }
+    }
+}
+
+impl Display for ProxyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ProxyErrorType::Authentication => f.write_str("failed to authenticate with the proxy"),
+            ProxyErrorType::InvalidResponse => f.write_str("proxy sent an invalid response"),
+            ProxyErrorType::Io => f.write_str("failed to communicate with the proxy"),
+            ProxyErrorType::Rejected { code } => {
+                f.write_str("proxy rejected the connection: code=")?;
+
+                Display::fmt(code, f)
+            }
+        }
+    }
+}
+
+impl Error for ProxyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ProxyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ProxyErrorType {
+    /// Proxy rejected the credentials or requires credentials that weren't
+    /// provided.
+    Authentication,
+    /// Proxy sent a response not following its protocol.
+    InvalidResponse,
+    /// Connecting to or communicating with the proxy failed.
+    ///
+    /// The associated error downcasts to [`std::io::Error`].
+    Io,
+    /// Proxy refused to open a tunnel to the gateway.
+    Rejected {
+        /// HTTP status code or SOCKS5 reply code.
+        code: u16,
+    },
+}
+
 /// Receiving the next Websocket message failed.
 #[derive(Debug)]
 pub struct ReceiveMessageError {
@@ -168,7 +349,7 @@ impl ReceiveMessageError {
     }
 
     /// Shortcut to create a new error for a message compression error.
-    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
     pub(crate) fn from_compression(source: CompressionError) -> Self {
         Self {
             kind: ReceiveMessageErrorType::Compression,
@@ -180,7 +361,7 @@ impl ReceiveMessageError {
 impl Display for ReceiveMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
-            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
             ReceiveMessageErrorType::Compression => {
                 f.write_str("binary message could not be decompressed")
             }
@@ -189,6 +370,9 @@ impl Display for ReceiveMessageError {
                 f.write_str(event)
             }
             ReceiveMessageErrorType::Reconnect => f.write_str("failed to reconnect to the gateway"),
+            ReceiveMessageErrorType::Stalled { .. } => {
+                f.write_str("shard is stalled and reconnecting")
+            }
         }
     }
 }
@@ -208,7 +392,7 @@ pub enum ReceiveMessageErrorType {
     /// Binary message could not be decompressed.
     ///
     /// The associated error downcasts to [`CompressionError`].
-    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
     Compression,
     /// Gateway event could not be deserialized.
     Deserializing {
@@ -218,21 +402,38 @@ pub enum ReceiveMessageErrorType {
         event: String,
     },
     /// Shard failed to reconnect to the gateway.
+    ///
+    /// The associated error downcasts to [`ProxyError`] if tunneling the
+    /// connection through the configured [`Proxy`] failed.
+    ///
+    /// [`Proxy`]: crate::Proxy
     Reconnect,
+    /// Shard hasn't received a heartbeat ACK or a dispatch event within the
+    /// timeout of its watchdog and is reconnecting.
+    ///
+    /// Configured via [`ConfigBuilder::watchdog`].
+    ///
+    /// [`ConfigBuilder::watchdog`]: crate::ConfigBuilder::watchdog
+    Stalled {
+        /// Duration since the last dispatch event.
+        since_event: Duration,
+        /// Duration since the last heartbeat ACK.
+        since_heartbeat_ack: Duration,
+    },
 }
 
 #[cfg(test)]
 mod tests {
     use super::{ReceiveMessageError, ReceiveMessageErrorType};
     use static_assertions::assert_impl_all;
-    use std::{error::Error, fmt::Debug};
+    use std::{error::Error, fmt::Debug, time::Duration};
 
     assert_impl_all!(ReceiveMessageErrorType: Debug, Send, Sync);
     assert_impl_all!(ReceiveMessageError: Error, Send, Sync);
 
     #[test]
     fn receive_message_error_display() {
-        let messages: [(ReceiveMessageErrorType, &str); 3] = [
+        let messages: [(ReceiveMessageErrorType, &str); 4] = [
             (
                 ReceiveMessageErrorType::Compression,
                 "binary message could not be decompressed",
@@ -247,6 +448,13 @@ mod tests {
                 ReceiveMessageErrorType::Reconnect,
                 "failed to reconnect to the gateway",
             ),
+            (
+                ReceiveMessageErrorType::Stalled {
+                    since_event: Duration::from_secs(60),
+                    since_heartbeat_ack: Duration::from_secs(20),
+                },
+                "shard is stalled and reconnecting",
+            ),
         ];
 
         for (kind, message) in messages {
@@ -0,0 +1,1294 @@
+//! Erlang External Term Format (ETF) encoding of gateway payloads.
+//!
+//! Enabled by the `etf` feature. Shards configured to use [`Encoding::Etf`]
+//! connect with `encoding=etf`, receive events as [`Message::Binary`]s, and
+//! encode sent commands in the format. ETF is cheaper to decode than JSON,
+//! which is noticeable for bots receiving many events.
+//!
+//! Terms are decoded into and encoded from the models through [`serde`],
+//! mirroring their JSON representation: binaries are strings, the `nil` atom
+//! is `null`, lists and tuples are arrays, and maps are objects.
+//!
+//! [`Encoding::Etf`]: crate::Encoding::Etf
+//! [`Message::Binary`]: crate::Message::Binary
+
+use crate::{
+    error::{ReceiveMessageError, ReceiveMessageErrorType},
+    EventTypeFlags,
+};
+use serde::{
+    de::{
+        self, value::SeqDeserializer, DeserializeSeed, EnumAccess, MapAccess, SeqAccess,
+        VariantAccess, Visitor,
+    },
+    ser::{self, Serialize},
+    Deserialize,
+};
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str,
+};
+use twilight_model::gateway::{
+    event::{GatewayEvent, GatewayEventDeserializer},
+    OpCode,
+};
+
+/// Version byte prefixing encoded terms.
+const VERSION: u8 = 131;
+
+/// Tag of a 64-bit float.
+const NEW_FLOAT_EXT: u8 = 70;
+/// Tag of an unsigned 8-bit integer.
+const SMALL_INTEGER_EXT: u8 = 97;
+/// Tag of a signed 32-bit integer.
+const INTEGER_EXT: u8 = 98;
+/// Tag of a float encoded as a string.
+const FLOAT_EXT: u8 = 99;
+/// Tag of a Latin-1 atom with a 16-bit length.
+const ATOM_EXT: u8 = 100;
+/// Tag of a tuple with an 8-bit arity.
+const SMALL_TUPLE_EXT: u8 = 104;
+/// Tag of a tuple with a 32-bit arity.
+const LARGE_TUPLE_EXT: u8 = 105;
+/// Tag of the empty list.
+const NIL_EXT: u8 = 106;
+/// Tag of a list of bytes.
+const STRING_EXT: u8 = 107;
+/// Tag of a list.
+const LIST_EXT: u8 = 108;
+/// Tag of a binary.
+const BINARY_EXT: u8 = 109;
+/// Tag of an integer with an 8-bit number of digits.
+const SMALL_BIG_EXT: u8 = 110;
+/// Tag of an integer with a 32-bit number of digits.
+const LARGE_BIG_EXT: u8 = 111;
+/// Tag of a Latin-1 atom with an 8-bit length.
+const SMALL_ATOM_EXT: u8 = 115;
+/// Tag of a map.
+const MAP_EXT: u8 = 116;
+/// Tag of a UTF-8 atom with a 16-bit length.
+const ATOM_UTF8_EXT: u8 = 118;
+/// Tag of a UTF-8 atom with an 8-bit length.
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+/// Encoding or decoding a term failed.
+#[derive(Debug)]
+pub struct EtfError {
+    /// Type of error.
+    kind: EtfErrorType,
+    /// Source error if available.
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl EtfError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &EtfErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (EtfErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Shortcut to create a new error from a type.
+    const fn new(kind: EtfErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EtfErrorType::Eof => f.write_str("term ended unexpectedly"),
+            EtfErrorType::IntegerTooLarge => f.write_str("integer does not fit in 64 bits"),
+            EtfErrorType::InvalidVersion { version } => {
+                f.write_str("term has an invalid version: ")?;
+
+                Display::fmt(version, f)
+            }
+            EtfErrorType::Message => {
+                f.write_str("term could not be serialized or deserialized: ")?;
+
+                if let Some(source) = &self.source {
+                    Display::fmt(source, f)?;
+                }
+
+                Ok(())
+            }
+            EtfErrorType::NotUtf8 => f.write_str("string is not UTF-8"),
+            EtfErrorType::TrailingBytes => f.write_str("term is followed by trailing bytes"),
+            EtfErrorType::UnsupportedTag { tag } => {
+                f.write_str("term has an unsupported tag: ")?;
+
+                Display::fmt(tag, f)
+            }
+        }
+    }
+}
+
+impl Error for EtfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+impl de::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            kind: EtfErrorType::Message,
+            source: Some(msg.to_string().into()),
+        }
+    }
+}
+
+impl ser::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        <Self as de::Error>::custom(msg)
+    }
+}
+
+/// Type of [`EtfError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EtfErrorType {
+    /// Term ended unexpectedly.
+    Eof,
+    /// Integer does not fit in 64 bits.
+    IntegerTooLarge,
+    /// Term does not start with the expected version byte.
+    InvalidVersion {
+        /// Provided version byte.
+        version: u8,
+    },
+    /// Model could not be serialized or deserialized from the term.
+    ///
+    /// The associated error contains the message of the model.
+    Message,
+    /// String is not UTF-8.
+    NotUtf8,
+    /// Term is followed by trailing bytes.
+    TrailingBytes,
+    /// Term has a tag that is not supported.
+    UnsupportedTag {
+        /// Provided tag.
+        tag: u8,
+    },
+}
+
+/// Deserialize an instance of type `T` from an ETF encoded term.
+///
+/// # Errors
+///
+/// Returns an [`EtfErrorType::InvalidVersion`] error type if the term isn't
+/// prefixed with the version byte.
+///
+/// Returns an [`EtfErrorType::TrailingBytes`] error type if the term isn't
+/// fully consumed.
+///
+/// Returns other error types if the term is invalid or does not match `T`.
+pub fn from_slice<'a, T: Deserialize<'a>>(input: &'a [u8]) -> Result<T, EtfError> {
+    let mut deserializer = Deserializer::new(input)?;
+    let value = T::deserialize(&mut deserializer)?;
+
+    if deserializer.input.is_empty() {
+        Ok(value)
+    } else {
+        Err(EtfError::new(EtfErrorType::TrailingBytes))
+    }
+}
+
+/// Serialize a value into an ETF encoded term.
+///
+/// # Errors
+///
+/// Returns an [`EtfErrorType::Message`] error type if the value's [`Serialize`]
+/// implementation fails.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EtfError> {
+    let mut serializer = Serializer {
+        output: vec![VERSION],
+    };
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.output)
+}
+
+/// Gateway event with only its opcode, sequence, and event type.
+#[derive(Deserialize)]
+struct Header<'a> {
+    /// Opcode of the gateway event.
+    op: u8,
+    /// Sequence of the gateway event.
+    #[serde(default)]
+    s: Option<u64>,
+    /// Dispatch event type of the gateway event.
+    #[serde(borrow, default)]
+    t: Option<Cow<'a, str>>,
+}
+
+/// Decode the opcode, sequence, and dispatch event type of an ETF encoded
+/// gateway event.
+#[allow(clippy::type_complexity)]
+pub(crate) fn header(event: &[u8]) -> Result<(u8, Option<u64>, Option<Cow<'_, str>>), EtfError> {
+    let header = from_slice::<Header<'_>>(event)?;
+
+    Ok((header.op, header.s, header.t))
+}
+
+/// Create a deserializing error of the event.
+fn deserializing(event: &[u8], source: EtfError) -> ReceiveMessageError {
+    ReceiveMessageError {
+        kind: ReceiveMessageErrorType::Deserializing {
+            event: String::from_utf8_lossy(event).into_owned(),
+        },
+        source: Some(Box::new(source)),
+    }
+}
+
+/// Decode the type of an ETF encoded gateway event, without deserializing
+/// its data.
+///
+/// Returns `None` if the event type is unknown.
+pub(crate) fn event_type(event: &[u8]) -> Result<Option<EventTypeFlags>, ReceiveMessageError> {
+    let (op, _, event_type) = header(event).map_err(|source| deserializing(event, source))?;
+
+    Ok(OpCode::from(op)
+        .and_then(|opcode| EventTypeFlags::try_from((opcode, event_type.as_deref())).ok()))
+}
+
+/// Parse an ETF encoded gateway event into a `GatewayEvent` if
+/// `wanted_event_types` contains its type.
+///
+/// # Errors
+///
+/// Returns a [`ReceiveMessageErrorType::Deserializing`] error if the *known*
+/// event could not be deserialized.
+pub fn parse(
+    event: &[u8],
+    wanted_event_types: EventTypeFlags,
+) -> Result<Option<GatewayEvent>, ReceiveMessageError> {
+    let deserializing = |source| deserializing(event, source);
+
+    let (op, _, event_type) = header(event).map_err(deserializing)?;
+
+    let Some(opcode) = OpCode::from(op) else {
+        return Ok(None);
+    };
+
+    let Ok(flags) = EventTypeFlags::try_from((opcode, event_type.as_deref())) else {
+        return Ok(None);
+    };
+
+    if wanted_event_types.contains(flags) {
+        let mut deserializer = Deserializer::new(event).map_err(deserializing)?;
+
+        GatewayEventDeserializer::new(op, event_type.as_deref())
+            .deserialize(&mut deserializer)
+            .map(Some)
+            .map_err(deserializing)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Term deserializer borrowing from its input.
+struct Deserializer<'de> {
+    /// Remaining input.
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    /// Create a new deserializer, consuming the version byte.
+    const fn new(input: &'de [u8]) -> Result<Self, EtfError> {
+        match input.split_first() {
+            Some((&VERSION, input)) => Ok(Self { input }),
+            Some((&version, _)) => Err(EtfError::new(EtfErrorType::InvalidVersion { version })),
+            None => Err(EtfError::new(EtfErrorType::Eof)),
+        }
+    }
+
+    /// Tag of the next term without consuming it.
+    fn peek(&self) -> Result<u8, EtfError> {
+        self.input
+            .first()
+            .copied()
+            .ok_or(EtfError::new(EtfErrorType::Eof))
+    }
+
+    /// Consume the next `len` bytes.
+    fn take(&mut self, len: usize) -> Result<&'de [u8], EtfError> {
+        if self.input.len() < len {
+            return Err(EtfError::new(EtfErrorType::Eof));
+        }
+
+        let (taken, input) = self.input.split_at(len);
+        self.input = input;
+
+        Ok(taken)
+    }
+
+    /// Consume a big endian encoded integer of `N` bytes.
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], EtfError> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    /// Consume a `u8`.
+    fn take_u8(&mut self) -> Result<u8, EtfError> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Consume a big endian `u16` length.
+    fn take_u16(&mut self) -> Result<usize, EtfError> {
+        Ok(u16::from_be_bytes(self.take_array()?).into())
+    }
+
+    /// Consume a big endian `u32` length.
+    fn take_u32(&mut self) -> Result<usize, EtfError> {
+        Ok(u32::from_be_bytes(self.take_array()?) as usize)
+    }
+
+    /// Consume the contents of an atom or string-like term, returning `None`
+    /// if the next term isn't one.
+    fn take_bytes(&mut self) -> Result<Option<&'de [u8]>, EtfError> {
+        let len = match self.peek()? {
+            ATOM_EXT | ATOM_UTF8_EXT | STRING_EXT => {
+                self.take_u8()?;
+                self.take_u16()?
+            }
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                self.take_u8()?;
+                self.take_u8()?.into()
+            }
+            BINARY_EXT => {
+                self.take_u8()?;
+                self.take_u32()?
+            }
+            _ => return Ok(None),
+        };
+
+        self.take(len).map(Some)
+    }
+
+    /// Consume the contents of an integer with `len` little endian digits.
+    fn take_big(&mut self, len: usize) -> Result<(bool, u64), EtfError> {
+        let negative = self.take_u8()? != 0;
+        let digits = self.take(len)?;
+
+        let mut value = 0u64;
+        for (idx, &digit) in digits.iter().enumerate() {
+            if digit == 0 {
+                continue;
+            }
+
+            if idx >= 8 {
+                return Err(EtfError::new(EtfErrorType::IntegerTooLarge));
+            }
+
+            value |= u64::from(digit) << (idx * 8);
+        }
+
+        Ok((negative, value))
+    }
+
+    /// Skip the next term.
+    fn skip(&mut self) -> Result<(), EtfError> {
+        if self.take_bytes()?.is_some() {
+            return Ok(());
+        }
+
+        match self.take_u8()? {
+            NEW_FLOAT_EXT => self.take(8).map(drop),
+            SMALL_INTEGER_EXT => self.take(1).map(drop),
+            INTEGER_EXT => self.take(4).map(drop),
+            FLOAT_EXT => self.take(31).map(drop),
+            SMALL_TUPLE_EXT => {
+                let len = self.take_u8()?;
+                (0..len).try_for_each(|_| self.skip())
+            }
+            LARGE_TUPLE_EXT => {
+                let len = self.take_u32()?;
+                (0..len).try_for_each(|_| self.skip())
+            }
+            NIL_EXT => Ok(()),
+            LIST_EXT => {
+                // Elements followed by the tail.
+                let len = self.take_u32()? + 1;
+                (0..len).try_for_each(|_| self.skip())
+            }
+            SMALL_BIG_EXT => {
+                let len = self.take_u8()?;
+                self.take(usize::from(len) + 1).map(drop)
+            }
+            LARGE_BIG_EXT => {
+                let len = self.take_u32()?;
+                self.take(len + 1).map(drop)
+            }
+            MAP_EXT => {
+                let len = self.take_u32()? * 2;
+                (0..len).try_for_each(|_| self.skip())
+            }
+            tag => Err(EtfError::new(EtfErrorType::UnsupportedTag { tag })),
+        }
+    }
+}
+
+/// Convert bytes to a string.
+fn to_str(bytes: &[u8]) -> Result<&str, EtfError> {
+    str::from_utf8(bytes).map_err(|source| EtfError {
+        kind: EtfErrorType::NotUtf8,
+        source: Some(Box::new(source)),
+    })
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let tag = self.peek()?;
+
+        match tag {
+            ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                match self.take_bytes()?.unwrap_or_default() {
+                    b"nil" => visitor.visit_unit(),
+                    b"true" => visitor.visit_bool(true),
+                    b"false" => visitor.visit_bool(false),
+                    atom => visitor.visit_borrowed_str(to_str(atom)?),
+                }
+            }
+            BINARY_EXT => {
+                let bytes = self.take_bytes()?.unwrap_or_default();
+
+                match str::from_utf8(bytes) {
+                    Ok(string) => visitor.visit_borrowed_str(string),
+                    Err(_) => visitor.visit_borrowed_bytes(bytes),
+                }
+            }
+            STRING_EXT => {
+                let bytes = self.take_bytes()?.unwrap_or_default();
+
+                visitor.visit_seq(SeqDeserializer::<_, EtfError>::new(bytes.iter().copied()))
+            }
+            _ => match self.take_u8()? {
+                NEW_FLOAT_EXT => visitor.visit_f64(f64::from_be_bytes(self.take_array()?)),
+                SMALL_INTEGER_EXT => visitor.visit_u8(self.take_u8()?),
+                INTEGER_EXT => visitor.visit_i32(i32::from_be_bytes(self.take_array()?)),
+                FLOAT_EXT => {
+                    let float = to_str(self.take(31)?)?.trim_end_matches('\0');
+
+                    visitor.visit_f64(float.parse().map_err(|source| EtfError {
+                        kind: EtfErrorType::Message,
+                        source: Some(Box::new(source)),
+                    })?)
+                }
+                SMALL_TUPLE_EXT => {
+                    let len = self.take_u8()?.into();
+
+                    Elements::new(self, len, false).seq(visitor)
+                }
+                LARGE_TUPLE_EXT => {
+                    let len = self.take_u32()?;
+
+                    Elements::new(self, len, false).seq(visitor)
+                }
+                NIL_EXT => Elements::new(self, 0, false).seq(visitor),
+                LIST_EXT => {
+                    let len = self.take_u32()?;
+
+                    Elements::new(self, len, true).seq(visitor)
+                }
+                SMALL_BIG_EXT | LARGE_BIG_EXT => {
+                    let len = if tag == SMALL_BIG_EXT {
+                        self.take_u8()?.into()
+                    } else {
+                        self.take_u32()?
+                    };
+
+                    match self.take_big(len)? {
+                        (false, value) => visitor.visit_u64(value),
+                        (true, value) => {
+                            let value = 0i64
+                                .checked_sub_unsigned(value)
+                                .ok_or(EtfError::new(EtfErrorType::IntegerTooLarge))?;
+
+                            visitor.visit_i64(value)
+                        }
+                    }
+                }
+                MAP_EXT => {
+                    let len = self.take_u32()?;
+
+                    Elements::new(self, len * 2, false).map(visitor)
+                }
+                tag => Err(EtfError::new(EtfErrorType::UnsupportedTag { tag })),
+            },
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.peek()? {
+            BINARY_EXT | STRING_EXT => {
+                visitor.visit_borrowed_bytes(self.take_bytes()?.unwrap_or_default())
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.peek()? {
+            MAP_EXT => {
+                self.take_u8()?;
+                let len = self.take_u32()?;
+
+                if len == 1 {
+                    visitor.visit_enum(self)
+                } else {
+                    Err(de::Error::invalid_length(len, &"a map with a single entry"))
+                }
+            }
+            _ => visitor.visit_enum(self),
+        }
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.skip()?;
+
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let nil = match self.peek()? {
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => self.input.get(1..5) == Some(b"\x03nil"),
+            ATOM_EXT | ATOM_UTF8_EXT => self.input.get(1..6) == Some(b"\x00\x03nil"),
+            _ => false,
+        };
+
+        if nil {
+            self.skip()?;
+
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.peek()? {
+            ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT | BINARY_EXT
+            | STRING_EXT => {
+                visitor.visit_borrowed_str(to_str(self.take_bytes()?.unwrap_or_default())?)
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char unit unit_struct
+        seq tuple tuple_struct map struct
+    }
+}
+
+impl<'de> EnumAccess<'de> for &mut Deserializer<'de> {
+    type Error = EtfError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(&mut *self)?;
+
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for &mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
+
+/// Access to the elements of a list, tuple, or map.
+struct Elements<'a, 'de> {
+    /// Deserializer of the elements.
+    de: &'a mut Deserializer<'de>,
+    /// Number of remaining elements, or map keys and values.
+    remaining: usize,
+    /// Whether the elements are followed by a list tail.
+    tail: bool,
+}
+
+impl<'a, 'de> Elements<'a, 'de> {
+    /// Create a new accessor of `len` elements.
+    fn new(de: &'a mut Deserializer<'de>, len: usize, tail: bool) -> Self {
+        Self {
+            de,
+            remaining: len,
+            tail,
+        }
+    }
+
+    /// Visit the elements as a sequence.
+    fn seq<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, EtfError> {
+        let value = visitor.visit_seq(&mut self)?;
+        self.end()?;
+
+        Ok(value)
+    }
+
+    /// Visit the elements as alternating map keys and values.
+    fn map<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, EtfError> {
+        let value = visitor.visit_map(&mut self)?;
+        self.end()?;
+
+        Ok(value)
+    }
+
+    /// Skip the elements not consumed by the visitor and the list tail.
+    fn end(self) -> Result<(), EtfError> {
+        let len = self.remaining + usize::from(self.tail);
+
+        (0..len).try_for_each(|_| self.de.skip())
+    }
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> MapAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining / 2)
+    }
+}
+
+/// Term serializer writing to a buffer.
+struct Serializer {
+    /// Encoded term.
+    output: Vec<u8>,
+}
+
+impl Serializer {
+    /// Write an atom.
+    fn atom(&mut self, atom: &str) {
+        self.output.push(SMALL_ATOM_UTF8_EXT);
+        self.output.push(atom.len().try_into().unwrap());
+        self.output.extend_from_slice(atom.as_bytes());
+    }
+
+    /// Write a binary.
+    fn binary(&mut self, bytes: &[u8]) -> Result<(), EtfError> {
+        let len = u32::try_from(bytes.len()).map_err(|source| EtfError {
+            kind: EtfErrorType::Message,
+            source: Some(Box::new(source)),
+        })?;
+
+        self.output.push(BINARY_EXT);
+        self.output.extend_from_slice(&len.to_be_bytes());
+        self.output.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    /// Write an integer.
+    #[allow(clippy::cast_possible_truncation)]
+    fn integer(&mut self, negative: bool, value: u64) {
+        if !negative && value <= u8::MAX.into() {
+            self.output.push(SMALL_INTEGER_EXT);
+            self.output.push(value as u8);
+        } else if let Ok(value) = i32::try_from(value) {
+            self.output.push(INTEGER_EXT);
+            let value = if negative { -value } else { value };
+            self.output.extend_from_slice(&value.to_be_bytes());
+        } else if negative && value == 1 << 31 {
+            self.output.push(INTEGER_EXT);
+            self.output.extend_from_slice(&i32::MIN.to_be_bytes());
+        } else {
+            let digits = value.to_le_bytes();
+            let len = 8 - value.leading_zeros() as usize / 8;
+
+            self.output.push(SMALL_BIG_EXT);
+            self.output.push(len as u8);
+            self.output.push(negative.into());
+            self.output.extend_from_slice(&digits[..len]);
+        }
+    }
+
+    /// Start a list or map, returning its compound serializer.
+    fn compound(&mut self, tag: u8, map: bool) -> Compound<'_> {
+        let start = self.output.len();
+        self.output.push(tag);
+        self.output.extend_from_slice(&[0; 4]);
+
+        Compound {
+            len: 0,
+            map,
+            ser: self,
+            start,
+        }
+    }
+
+    /// Start a map with a single entry keyed by a variant.
+    fn variant(&mut self, variant: &'static str) -> Result<(), EtfError> {
+        self.output.push(MAP_EXT);
+        self.output.extend_from_slice(&1u32.to_be_bytes());
+
+        self.binary(variant.as_bytes())
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = EtfError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.atom(if v { "true" } else { "false" });
+
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.integer(v.is_negative(), v.unsigned_abs());
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.integer(false, v);
+
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.output.push(NEW_FLOAT_EXT);
+        self.output.extend_from_slice(&v.to_be_bytes());
+
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.binary(v.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.binary(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.binary(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.atom("nil");
+
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.binary(variant.as_bytes())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.variant(variant)?;
+
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(self.compound(LIST_EXT, false))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.variant(variant)?;
+
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(self.compound(MAP_EXT, true))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.variant(variant)?;
+
+        self.serialize_map(Some(len))
+    }
+}
+
+/// Serializer of a list or map whose length is written once it's complete.
+struct Compound<'a> {
+    /// Number of elements or map entries serialized so far.
+    len: u32,
+    /// Whether the compound is a map.
+    map: bool,
+    /// Serializer of the elements.
+    ser: &'a mut Serializer,
+    /// Position of the compound's tag.
+    start: usize,
+}
+
+impl Compound<'_> {
+    /// Serialize an element or map key.
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.len += 1;
+
+        value.serialize(&mut *self.ser)
+    }
+
+    /// Write the length of the compound.
+    fn end(self) -> Result<(), EtfError> {
+        if self.map {
+            self.ser.output[self.start + 1..self.start + 5]
+                .copy_from_slice(&self.len.to_be_bytes());
+        } else if self.len == 0 {
+            self.ser.output.truncate(self.start);
+            self.ser.output.push(NIL_EXT);
+        } else {
+            self.ser.output[self.start + 1..self.start + 5]
+                .copy_from_slice(&self.len.to_be_bytes());
+            self.ser.output.push(NIL_EXT);
+        }
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Compound::end(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_slice, parse, to_vec, EtfError, EtfErrorType};
+    use crate::EventTypeFlags;
+    use serde::{Deserialize, Serialize};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::{
+            event::{DispatchEvent, GatewayEvent},
+            payload::{
+                incoming::{Hello, RoleDelete},
+                outgoing::Heartbeat,
+            },
+        },
+        id::{marker::GuildMarker, Id},
+    };
+
+    assert_impl_all!(EtfErrorType: Debug, Send, Sync);
+    assert_impl_all!(EtfError: Error, Send, Sync);
+
+    /// `%{op: 10, d: %{heartbeat_interval: 41250, _trace: ["gateway"]}, s: nil, t: nil}`
+    /// as encoded by Discord.
+    const HELLO: &[u8] = &[
+        131, 116, 0, 0, 0, 4, 100, 0, 1, 100, 116, 0, 0, 0, 2, 100, 0, 6, 95, 116, 114, 97, 99,
+        101, 108, 0, 0, 0, 1, 109, 0, 0, 0, 7, 103, 97, 116, 101, 119, 97, 121, 106, 100, 0, 18,
+        104, 101, 97, 114, 116, 98, 101, 97, 116, 95, 105, 110, 116, 101, 114, 118, 97, 108, 98, 0,
+        0, 161, 34, 100, 0, 2, 111, 112, 97, 10, 100, 0, 1, 115, 100, 0, 3, 110, 105, 108, 100, 0,
+        1, 116, 100, 0, 3, 110, 105, 108,
+    ];
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Model {
+        flag: bool,
+        guild_id: Id<GuildMarker>,
+        list: Vec<i64>,
+        name: String,
+        nothing: Option<u8>,
+        ratio: f64,
+    }
+
+    #[test]
+    fn parse_hello() {
+        let event = parse(HELLO, EventTypeFlags::all()).unwrap();
+
+        assert!(matches!(
+            event,
+            Some(GatewayEvent::Hello(Hello {
+                heartbeat_interval: 41250
+            }))
+        ));
+        assert!(parse(HELLO, EventTypeFlags::empty()).unwrap().is_none());
+    }
+
+    #[test]
+    fn parse_dispatch() {
+        // Discord encodes payloads like their JSON representation.
+        let json: serde_json::Value = serde_json::from_str(
+            r#"{"op":0,"s":2,"t":"GUILD_ROLE_DELETE","d":{"guild_id":"1","role_id":"2"}}"#,
+        )
+        .unwrap();
+        let encoded = to_vec(&json).unwrap();
+
+        let event = parse(&encoded, EventTypeFlags::ROLE_DELETE).unwrap();
+        assert!(matches!(
+            event,
+            Some(GatewayEvent::Dispatch(2, DispatchEvent::RoleDelete(RoleDelete { guild_id, role_id })))
+                if guild_id == Id::new(1) && role_id == Id::new(2)
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let model = Model {
+            flag: true,
+            guild_id: Id::new(1_234_567_890_123_456_789),
+            list: vec![0, 255, 256, -1, i64::from(i32::MIN), i64::MIN, i64::MAX],
+            name: "twilight".to_owned(),
+            nothing: None,
+            ratio: 0.5,
+        };
+
+        let encoded = to_vec(&model).unwrap();
+        assert_eq!(model, from_slice::<Model>(&encoded).unwrap());
+
+        let empty = to_vec(&Vec::<u8>::new()).unwrap();
+        assert_eq!([131, 106], empty.as_slice());
+    }
+
+    #[test]
+    fn heartbeat() {
+        let encoded = to_vec(&Heartbeat::new(Some(5))).unwrap();
+
+        assert_eq!(
+            [131, 116, 0, 0, 0, 2, 109, 0, 0, 0, 1, 100, 97, 5, 109, 0, 0, 0, 2, 111, 112, 97, 1],
+            encoded.as_slice()
+        );
+    }
+
+    #[test]
+    fn snowflake_integer() {
+        // Snowflakes are sent as integers.
+        let encoded = [131, 110, 8, 0, 21, 129, 233, 125, 244, 16, 34, 17];
+
+        assert_eq!(
+            Id::<GuildMarker>::new(1_234_567_890_123_456_789),
+            from_slice::<Id<GuildMarker>>(&encoded).unwrap()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            from_slice::<u8>(&[]).unwrap_err().kind(),
+            EtfErrorType::Eof
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[130, 97, 1]).unwrap_err().kind(),
+            EtfErrorType::InvalidVersion { version: 130 }
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 97, 1, 0]).unwrap_err().kind(),
+            EtfErrorType::TrailingBytes
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 90]).unwrap_err().kind(),
+            EtfErrorType::UnsupportedTag { tag: 90 }
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 109, 0, 0, 0, 1, 97])
+                .unwrap_err()
+                .kind(),
+            EtfErrorType::Message
+        ));
+    }
+}
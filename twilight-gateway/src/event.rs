@@ -1,7 +1,7 @@
 //! Optimization for skipping deserialization of unwanted events.
 
 use bitflags::bitflags;
-use twilight_model::gateway::{event::EventType, OpCode};
+use twilight_model::gateway::{event::EventType, Intents, OpCode};
 
 bitflags! {
     /// Important optimization for narrowing requested event types.
@@ -58,6 +58,12 @@ bitflags! {
         const ENTITLEMENT_DELETE = 1 << 77;
         /// An entitlement has been updated.
         const ENTITLEMENT_UPDATE = 1 << 78;
+        /// Dispatch event of a type which is not yet modeled.
+        ///
+        /// Such events are deserialized into [`Event::Unknown`].
+        ///
+        /// [`Event::Unknown`]: twilight_model::gateway::event::Event::Unknown
+        const UNKNOWN = 1 << 79;
         /// User has been banned from a guild.
         const BAN_ADD = 1;
         /// User has been unbanned from a guild.
@@ -139,9 +145,9 @@ bitflags! {
         /// Multiple messages have been deleted in a channel.
         const MESSAGE_DELETE_BULK = 1 << 21;
         /// Message poll vote has been added.
-        const MESSAGE_POLL_VOTE_ADD = 1 << 28;
+        const MESSAGE_POLL_VOTE_ADD = 1 << 80;
         /// Message poll vote has been removed.
-        const MESSAGE_POLL_VOTE_REMOVE = 1 << 29;
+        const MESSAGE_POLL_VOTE_REMOVE = 1 << 81;
         /// Message in a channel has been updated.
         const MESSAGE_UPDATE = 1 << 22;
         /// User's presence details are updated.
@@ -332,6 +338,159 @@ bitflags! {
     }
 }
 
+/// Event types and the intents they may be received under.
+///
+/// Guild intents precede direct message intents, as they're preferred for
+/// event types received under both.
+const INTENTS: &[(EventTypeFlags, Intents)] = &[
+    (
+        EventTypeFlags::AUTO_MODERATION_CONFIGURATION,
+        Intents::AUTO_MODERATION_CONFIGURATION,
+    ),
+    (
+        EventTypeFlags::AUTO_MODERATION_EXECUTION,
+        Intents::AUTO_MODERATION_EXECUTION,
+    ),
+    (EventTypeFlags::GUILDS, Intents::GUILDS),
+    (EventTypeFlags::GUILD_MODERATION, Intents::GUILD_MODERATION),
+    (
+        EventTypeFlags::GUILD_EMOJIS_AND_STICKERS,
+        Intents::GUILD_EMOJIS_AND_STICKERS,
+    ),
+    (
+        EventTypeFlags::GUILD_INTEGRATIONS,
+        Intents::GUILD_INTEGRATIONS,
+    ),
+    (EventTypeFlags::GUILD_INVITES, Intents::GUILD_INVITES),
+    (EventTypeFlags::GUILD_MEMBERS, Intents::GUILD_MEMBERS),
+    (EventTypeFlags::GUILD_MESSAGES, Intents::GUILD_MESSAGES),
+    (EventTypeFlags::MESSAGE_POLLS, Intents::GUILD_MESSAGE_POLLS),
+    (
+        EventTypeFlags::GUILD_MESSAGE_REACTIONS,
+        Intents::GUILD_MESSAGE_REACTIONS,
+    ),
+    (
+        EventTypeFlags::GUILD_MESSAGE_TYPING,
+        Intents::GUILD_MESSAGE_TYPING,
+    ),
+    (EventTypeFlags::GUILD_PRESENCES, Intents::GUILD_PRESENCES),
+    (
+        EventTypeFlags::GUILD_SCHEDULED_EVENTS,
+        Intents::GUILD_SCHEDULED_EVENTS,
+    ),
+    (
+        EventTypeFlags::GUILD_VOICE_STATES,
+        Intents::GUILD_VOICE_STATES,
+    ),
+    (EventTypeFlags::GUILD_WEBHOOKS, Intents::GUILD_WEBHOOKS),
+    (EventTypeFlags::DIRECT_MESSAGES, Intents::DIRECT_MESSAGES),
+    (EventTypeFlags::MESSAGE_POLLS, Intents::DIRECT_MESSAGE_POLLS),
+    (
+        EventTypeFlags::DIRECT_MESSAGE_REACTIONS,
+        Intents::DIRECT_MESSAGE_REACTIONS,
+    ),
+    (
+        EventTypeFlags::DIRECT_MESSAGE_TYPING,
+        Intents::DIRECT_MESSAGE_TYPING,
+    ),
+];
+
+impl EventTypeFlags {
+    /// Minimal intents under which each of the event types may be received.
+    ///
+    /// Event types received under multiple intents, such as
+    /// [`MESSAGE_CREATE`] in both guilds and direct messages, only require one
+    /// of them, preferring intents already required by other event types and
+    /// then guild intents. Add the direct message intents to also receive them
+    /// in direct messages. Event types not gated behind any intent, such as
+    /// [`READY`], require none.
+    ///
+    /// Note that the privileged [`Intents::MESSAGE_CONTENT`] is never
+    /// included, as it only affects the contents of events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_gateway::{EventTypeFlags, Intents};
+    ///
+    /// let event_types = EventTypeFlags::MEMBER_ADD | EventTypeFlags::READY;
+    /// assert_eq!(Intents::GUILD_MEMBERS, event_types.intents());
+    ///
+    /// let event_types = EventTypeFlags::MESSAGE_CREATE | EventTypeFlags::MESSAGE_UPDATE;
+    /// assert_eq!(Intents::GUILD_MESSAGES, event_types.intents());
+    /// ```
+    ///
+    /// [`MESSAGE_CREATE`]: Self::MESSAGE_CREATE
+    /// [`READY`]: Self::READY
+    pub fn intents(self) -> Intents {
+        let mut intents = Intents::empty();
+        let mut shared = Vec::new();
+
+        // Require the intents of event types only received under one intent.
+        for event_type in (0..u128::BITS)
+            .map(|bit| Self::from_bits_retain(1 << bit))
+            .filter(|event_type| self.contains(*event_type))
+        {
+            let mut under = INTENTS
+                .iter()
+                .filter(|(event_types, _)| event_types.contains(event_type))
+                .map(|(_, intents)| *intents);
+
+            match (under.next(), under.next()) {
+                (Some(event_intents), None) => intents |= event_intents,
+                (Some(_), Some(_)) => shared.push(event_type),
+                _ => {}
+            }
+        }
+
+        // Require the first intent of the remaining event types not yet
+        // receivable.
+        for event_type in shared {
+            if event_type.unreceivable(intents).is_empty() {
+                continue;
+            }
+
+            if let Some((_, event_intents)) = INTENTS
+                .iter()
+                .find(|(event_types, _)| event_types.contains(event_type))
+            {
+                intents |= *event_intents;
+            }
+        }
+
+        intents
+    }
+
+    /// Event types which can never be received under the intents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_gateway::{EventTypeFlags, Intents};
+    ///
+    /// let event_types = EventTypeFlags::MESSAGE_CREATE | EventTypeFlags::ROLE_CREATE;
+    /// assert_eq!(
+    ///     EventTypeFlags::ROLE_CREATE,
+    ///     event_types.unreceivable(Intents::GUILD_MESSAGES),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn unreceivable(self, intents: Intents) -> Self {
+        let (gated, receivable) = INTENTS.iter().fold(
+            (Self::empty(), Self::empty()),
+            |(gated, receivable), &(event_types, event_intents)| {
+                if intents.intersects(event_intents) {
+                    (gated | event_types, receivable | event_types)
+                } else {
+                    (gated | event_types, receivable)
+                }
+            },
+        );
+
+        (self & gated) - receivable
+    }
+}
+
 impl From<EventType> for EventTypeFlags {
     fn from(event_type: EventType) -> Self {
         match event_type {
@@ -408,6 +567,7 @@ impl From<EventType> for EventTypeFlags {
             EventType::VoiceServerUpdate => Self::VOICE_SERVER_UPDATE,
             EventType::VoiceStateUpdate => Self::VOICE_STATE_UPDATE,
             EventType::WebhooksUpdate => Self::WEBHOOKS_UPDATE,
+            EventType::Unknown => Self::UNKNOWN,
         }
     }
 }
@@ -422,6 +582,9 @@ impl TryFrom<(OpCode, Option<&str>)> for EventTypeFlags {
             (OpCode::InvalidSession, _) => Ok(Self::GATEWAY_INVALIDATE_SESSION),
             (OpCode::Hello, _) => Ok(Self::GATEWAY_HELLO),
             (OpCode::HeartbeatAck, _) => Ok(Self::GATEWAY_HEARTBEAT_ACK),
+            (OpCode::Dispatch, Some(event_type)) => {
+                Ok(EventType::try_from(event_type).map_or(Self::UNKNOWN, Self::from))
+            }
             (_, Some(event_type)) => EventType::try_from(event_type)
                 .map(Self::from)
                 .map_err(|_| ()),
@@ -435,7 +598,7 @@ mod tests {
     use super::EventTypeFlags;
     use static_assertions::assert_impl_all;
     use std::{fmt::Debug, hash::Hash};
-    use twilight_model::gateway::event::EventType;
+    use twilight_model::gateway::{event::EventType, Intents, OpCode};
 
     assert_impl_all!(
         EventTypeFlags: Copy,
@@ -448,4 +611,60 @@ mod tests {
         Send,
         Sync,
     );
+
+    #[test]
+    fn try_from_unknown() {
+        assert_eq!(
+            Ok(EventTypeFlags::UNKNOWN),
+            EventTypeFlags::try_from((OpCode::Dispatch, Some("NEW_EVENT")))
+        );
+        assert_eq!(
+            Ok(EventTypeFlags::MESSAGE_CREATE),
+            EventTypeFlags::try_from((OpCode::Dispatch, Some("MESSAGE_CREATE")))
+        );
+        assert_eq!(
+            EventTypeFlags::UNKNOWN,
+            EventTypeFlags::from(EventType::Unknown)
+        );
+    }
+
+    #[test]
+    fn intents() {
+        assert_eq!(Intents::empty(), EventTypeFlags::READY.intents());
+        assert_eq!(
+            Intents::GUILD_MESSAGES,
+            EventTypeFlags::MESSAGE_CREATE.intents()
+        );
+        assert_eq!(
+            Intents::GUILDS,
+            EventTypeFlags::THREAD_MEMBERS_UPDATE.intents()
+        );
+        assert_eq!(
+            Intents::GUILD_MEMBERS,
+            (EventTypeFlags::MEMBER_ADD | EventTypeFlags::THREAD_MEMBERS_UPDATE).intents()
+        );
+        assert_eq!(
+            Intents::GUILD_MESSAGE_POLLS,
+            EventTypeFlags::MESSAGE_POLLS.intents()
+        );
+        assert!(!EventTypeFlags::all()
+            .intents()
+            .intersects(Intents::DIRECT_MESSAGES | Intents::DIRECT_MESSAGE_POLLS));
+    }
+
+    #[test]
+    fn unreceivable() {
+        let event_types = EventTypeFlags::MESSAGE_CREATE | EventTypeFlags::READY;
+
+        assert_eq!(
+            EventTypeFlags::MESSAGE_CREATE,
+            event_types.unreceivable(Intents::GUILDS)
+        );
+        assert!(event_types
+            .unreceivable(Intents::DIRECT_MESSAGES)
+            .is_empty());
+        assert!(EventTypeFlags::all()
+            .unreceivable(EventTypeFlags::all().intents())
+            .is_empty());
+    }
 }
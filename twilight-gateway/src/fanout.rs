@@ -0,0 +1,362 @@
+//! Broadcasting the events of one or more shards to multiple subscribers.
+
+use crate::{
+    error::ReceiveMessageError, queue::Queue, stream::parse_message, EventTypeFlags, Message,
+    Shard, StreamExt as _,
+};
+use futures_core::Stream;
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use twilight_model::gateway::{event::Event, ShardId};
+
+/// Subscriber of a [`Fanout`].
+#[derive(Debug)]
+struct Subscriber {
+    /// Number of events the subscriber missed.
+    lagged: Arc<AtomicU64>,
+    /// Sending half of the subscription's bounded channel.
+    tx: mpsc::Sender<(ShardId, Event)>,
+    /// Event types the subscriber wants.
+    wanted_event_types: EventTypeFlags,
+}
+
+impl Subscriber {
+    /// Send the event to the subscriber, returning whether its subscription
+    /// is still open.
+    fn send(&self, shard_id: ShardId, event: Event, event_type: EventTypeFlags) -> bool {
+        match self.tx.try_send((shard_id, event)) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                self.lagged.fetch_add(1, Ordering::Relaxed);
+                tracing::debug!(%shard_id, ?event_type, "subscriber lagged");
+
+                true
+            }
+            Err(TrySendError::Closed(_)) => false,
+        }
+    }
+}
+
+/// Broadcasts the events of one or more shards to multiple subscribers, each
+/// with their own bounded buffer and wanted event types.
+///
+/// Events are sent to every [`Subscription`] wanting their type, along with
+/// the ID of the shard they were received on. The events are only
+/// deserialized once, no matter how many subscribers want them.
+///
+/// # Lag
+///
+/// Events are sent without waiting for subscribers, so a slow subscriber
+/// never delays the others or the shards. A subscriber whose buffer is full
+/// misses the event instead, which is counted by [`Subscription::lagged`].
+/// Size buffers to absorb bursts, such as the Guild Create events after
+/// identifying.
+///
+/// Close messages are always considered wanted and map onto
+/// [`Event::GatewayClose`]. Subscribers whose [`Subscription`] was dropped are
+/// removed.
+///
+/// # Examples
+///
+/// Update a cache and handle interactions of a group of shards in separate
+/// tasks:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use tokio_stream::StreamExt as _;
+/// use twilight_gateway::{Config, EventTypeFlags, Fanout, Intents, ShardGroup};
+/// use twilight_http::Client;
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let client = Client::new(token.clone());
+/// let config = Config::new(token, Intents::GUILDS);
+///
+/// let shards =
+///     twilight_gateway::create_recommended(&client, config, |_, builder| builder.build())
+///         .await?;
+/// let mut group = ShardGroup::new(shards);
+///
+/// let mut fanout = Fanout::new();
+/// let mut cache = fanout.subscribe(EventTypeFlags::GUILDS, 256);
+/// let mut interactions = fanout.subscribe(EventTypeFlags::INTERACTION_CREATE, 16);
+///
+/// tokio::spawn(async move {
+///     while let Some((shard_id, event)) = cache.next().await {
+///         // Update the cache.
+///     }
+/// });
+/// tokio::spawn(async move {
+///     while let Some((shard_id, event)) = interactions.next().await {
+///         // Respond to the interaction.
+///     }
+/// });
+///
+/// fanout.run(&mut group).await;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Default)]
+pub struct Fanout {
+    /// Subscribers to broadcast events to.
+    subscribers: Vec<Subscriber>,
+}
+
+impl Fanout {
+    /// Create a new fanout without subscribers.
+    pub const fn new() -> Self {
+        Self {
+            subscribers: Vec::new(),
+        }
+    }
+
+    /// Subscribe to the event types, buffering up to `capacity` events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn subscribe(
+        &mut self,
+        wanted_event_types: EventTypeFlags,
+        capacity: usize,
+    ) -> Subscription {
+        let (tx, rx) = mpsc::channel(capacity);
+        let lagged = Arc::new(AtomicU64::new(0));
+        self.subscribers.push(Subscriber {
+            lagged: Arc::clone(&lagged),
+            tx,
+            wanted_event_types,
+        });
+
+        Subscription { lagged, rx }
+    }
+
+    /// Whether there are no subscribers.
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+
+    /// Union of the event types wanted by the subscribers.
+    pub fn wanted_event_types(&self) -> EventTypeFlags {
+        self.subscribers
+            .iter()
+            .fold(EventTypeFlags::empty(), |wanted, subscriber| {
+                wanted | subscriber.wanted_event_types
+            })
+    }
+
+    /// Send the event to the subscribers wanting it.
+    ///
+    /// Subscribers whose buffer is full miss the event, refer to the
+    /// [type-level documentation](Self#lag).
+    pub fn send(&mut self, shard_id: ShardId, event: Event) {
+        let event_type = EventTypeFlags::from(event.kind());
+        let Some(last) = self
+            .subscribers
+            .iter()
+            .rposition(|subscriber| subscriber.wanted_event_types.contains(event_type))
+        else {
+            return;
+        };
+
+        // Clone the event for all but the last subscriber wanting it, which
+        // gets the event itself.
+        let mut closed = false;
+        for subscriber in &self.subscribers[..last] {
+            if subscriber.wanted_event_types.contains(event_type) {
+                closed |= !subscriber.send(shard_id, event.clone(), event_type);
+            }
+        }
+        closed |= !self.subscribers[last].send(shard_id, event, event_type);
+
+        if closed {
+            self.subscribers
+                .retain(|subscriber| !subscriber.tx.is_closed());
+        }
+    }
+
+    /// Broadcast the messages of a stream of multiple shards, such as a
+    /// [`ShardGroup`], until it ends or all subscribers are dropped.
+    ///
+    /// Errors receiving events are logged and skipped.
+    ///
+    /// [`ShardGroup`]: crate::ShardGroup
+    pub async fn run<St>(mut self, stream: &mut St)
+    where
+        St: Stream<Item = (ShardId, Result<Message, ReceiveMessageError>)> + Unpin + ?Sized,
+    {
+        while !self.is_empty() {
+            let Some((shard_id, item)) = next(stream).await else {
+                break;
+            };
+
+            match item.and_then(|message| parse_message(message, self.wanted_event_types())) {
+                Ok(Some(event)) => self.send(shard_id, event),
+                Ok(None) => {}
+                Err(source) => tracing::warn!(?source, %shard_id, "error receiving event"),
+            }
+        }
+    }
+
+    /// Broadcast the events of a single shard until it's closed or all
+    /// subscribers are dropped.
+    ///
+    /// Errors receiving events are logged and skipped.
+    pub async fn run_shard<Q: Queue + Unpin>(mut self, shard: &mut Shard<Q>) {
+        let shard_id = shard.id();
+
+        while !self.is_empty() {
+            let Some(item) = shard.next_event(self.wanted_event_types()).await else {
+                break;
+            };
+
+            match item {
+                Ok(event) => self.send(shard_id, event),
+                Err(source) => tracing::warn!(?source, %shard_id, "error receiving event"),
+            }
+        }
+    }
+}
+
+/// Stream of the events wanted by a [`Fanout`] subscriber, along with the ID
+/// of the shard they were received on.
+///
+/// Ends once the [`Fanout`] is dropped.
+///
+/// May be obtained via [`Fanout::subscribe`].
+#[derive(Debug)]
+pub struct Subscription {
+    /// Number of events the subscription missed.
+    lagged: Arc<AtomicU64>,
+    /// Receiving half of the bounded channel.
+    rx: mpsc::Receiver<(ShardId, Event)>,
+}
+
+impl Subscription {
+    /// Number of events missed because the subscription's buffer was full.
+    pub fn lagged(&self) -> u64 {
+        self.lagged.load(Ordering::Relaxed)
+    }
+}
+
+impl Stream for Subscription {
+    type Item = (ShardId, Event);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// Receive the next item of a stream.
+async fn next<St: Stream + Unpin + ?Sized>(stream: &mut St) -> Option<St::Item> {
+    std::future::poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fanout, Subscription};
+    use crate::{EventTypeFlags, Message};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio_stream::StreamExt;
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::incoming::{GuildDelete, MessageDelete},
+            CloseFrame, ShardId,
+        },
+        id::Id,
+    };
+
+    assert_impl_all!(Fanout: Debug, Default, Send, Sync);
+    assert_impl_all!(Subscription: Debug, Send, Sync, Unpin);
+
+    const MESSAGE_DELETE: &str =
+        r#"{"op":0,"s":1,"t":"MESSAGE_DELETE","d":{"id":"1","channel_id":"2"}}"#;
+    const RESUMED: &str = r#"{"op":0,"s":2,"t":"RESUMED","d":{}}"#;
+
+    #[tokio::test]
+    async fn send() {
+        let mut fanout = Fanout::new();
+        let mut guilds = fanout.subscribe(EventTypeFlags::GUILD_DELETE, 1);
+        let mut resumed = fanout.subscribe(EventTypeFlags::RESUMED, 1);
+        let dropped = fanout.subscribe(EventTypeFlags::GUILD_DELETE, 1);
+        drop(dropped);
+        assert_eq!(
+            EventTypeFlags::GUILD_DELETE | EventTypeFlags::RESUMED,
+            fanout.wanted_event_types()
+        );
+
+        let guild_delete = Event::GuildDelete(GuildDelete {
+            id: Id::new(1),
+            unavailable: false,
+        });
+        fanout.send(ShardId::ONE, guild_delete.clone());
+        assert_eq!(2, fanout.subscribers.len());
+        assert_eq!(Some((ShardId::ONE, guild_delete)), guilds.next().await);
+
+        let close = Event::GatewayClose(Some(CloseFrame::NORMAL));
+        fanout.send(ShardId::ONE, close.clone());
+        assert_eq!(Some((ShardId::ONE, close.clone())), guilds.next().await);
+        assert_eq!(Some((ShardId::ONE, close)), resumed.next().await);
+
+        drop(fanout);
+        assert!(guilds.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn lagged() {
+        let mut fanout = Fanout::new();
+        let slow = fanout.subscribe(EventTypeFlags::RESUMED, 1);
+        let mut fast = fanout.subscribe(EventTypeFlags::RESUMED, 2);
+
+        fanout.send(ShardId::ONE, Event::Resumed);
+        fanout.send(ShardId::ONE, Event::Resumed);
+        assert_eq!(1, slow.lagged());
+        assert_eq!(0, fast.lagged());
+
+        drop(fanout);
+        assert_eq!(1, slow.collect::<Vec<_>>().await.len());
+        assert_eq!(2, (&mut fast).collect::<Vec<_>>().await.len());
+    }
+
+    #[tokio::test]
+    async fn run() {
+        let shard_0 = ShardId::new(0, 2);
+        let shard_1 = ShardId::new(1, 2);
+
+        let mut fanout = Fanout::new();
+        let mut messages = fanout.subscribe(EventTypeFlags::MESSAGE_DELETE, 2);
+        let mut resumed = fanout.subscribe(EventTypeFlags::RESUMED, 2);
+        assert_eq!(
+            EventTypeFlags::MESSAGE_DELETE | EventTypeFlags::RESUMED,
+            fanout.wanted_event_types()
+        );
+
+        let mut stream = tokio_stream::iter([
+            (shard_0, Ok(Message::Text(MESSAGE_DELETE.to_owned()))),
+            (shard_1, Ok(Message::Text(RESUMED.to_owned()))),
+            (shard_1, Ok(Message::Close(Some(CloseFrame::NORMAL)))),
+        ]);
+
+        fanout.run(&mut stream).await;
+
+        let message_delete = Event::MessageDelete(MessageDelete {
+            channel_id: Id::new(2),
+            guild_id: None,
+            id: Id::new(1),
+        });
+        let close = Event::GatewayClose(Some(CloseFrame::NORMAL));
+        assert_eq!(Some((shard_0, message_delete)), messages.next().await);
+        assert_eq!(Some((shard_1, close.clone())), messages.next().await);
+        assert_eq!(Some((shard_1, Event::Resumed)), resumed.next().await);
+        assert_eq!(Some((shard_1, close)), resumed.next().await);
+        assert!(messages.next().await.is_none());
+    }
+}
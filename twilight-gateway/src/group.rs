@@ -0,0 +1,303 @@
+//! Group of shards which can be resharded at runtime.
+
+use crate::{
+    error::ReceiveMessageError,
+    queue::{InMemoryQueue, Queue},
+    CloseFrame, Command, Message, Shard, ShardId, ShardState, ShutdownBehavior,
+};
+use futures_core::Stream;
+use std::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Group of shards streaming their messages together, supporting changing the
+/// total number of shards without a full restart.
+///
+/// Calling [`reshard`] starts a new generation of shards next to the current
+/// one. Messages of both generations are yielded by the same stream,
+/// distinguishable by the [`ShardId::total`] of the yielded shard ID. The new
+/// generation replaces the current one once all of its shards are
+/// [identified], or earlier if [`cutover`] is called. The replaced shards are
+/// then closed and drained, yielding their remaining messages, including their
+/// final close message, before being dropped.
+///
+/// Dispatch events may be received by both generations while they are
+/// running at the same time.
+///
+/// # Examples
+///
+/// Reshard to Discord's recommended number of shards every eight hours:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{env, time::Duration};
+/// use tokio::time;
+/// use tokio_stream::StreamExt as _;
+/// use twilight_gateway::{Config, Intents, ShardGroup};
+/// use twilight_http::Client;
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let client = Client::new(token.clone());
+/// let config = Config::new(token, Intents::GUILDS);
+///
+/// let shards =
+///     twilight_gateway::create_recommended(&client, config.clone(), |_, builder| builder.build())
+///         .await?;
+/// let mut group = ShardGroup::new(shards);
+/// let mut interval = time::interval(Duration::from_secs(60 * 60 * 8));
+/// interval.tick().await;
+///
+/// loop {
+///     tokio::select! {
+///         _ = interval.tick() => {
+///             let shards = twilight_gateway::create_recommended(
+///                 &client,
+///                 config.clone(),
+///                 |_, builder| builder.build(),
+///             )
+///             .await?;
+///             group.reshard(shards);
+///         }
+///         Some((shard_id, item)) = group.next() => {
+///             tracing::debug!(?item, %shard_id, "received message");
+///         }
+///     }
+/// }
+/// # }
+/// ```
+///
+/// [`cutover`]: Self::cutover
+/// [`reshard`]: Self::reshard
+/// [identified]: crate::ShardState::is_identified
+#[derive(Debug)]
+pub struct ShardGroup<Q = InMemoryQueue> {
+    /// Shards of the current generation.
+    current: Vec<Shard<Q>>,
+    /// Closed shards of previous generations.
+    draining: Vec<Shard<Q>>,
+    /// Index of the current shard to poll first.
+    index: usize,
+    /// Shards of the next generation.
+    next: Vec<Shard<Q>>,
+}
+
+impl<Q> ShardGroup<Q> {
+    /// Create a new group from the current generation of shards.
+    pub fn new(shards: impl IntoIterator<Item = Shard<Q>>) -> Self {
+        Self {
+            current: shards.into_iter().collect(),
+            draining: Vec::new(),
+            index: 0,
+            next: Vec::new(),
+        }
+    }
+
+    /// Immutable reference to the shards of the current generation.
+    pub fn current(&self) -> &[Shard<Q>] {
+        &self.current
+    }
+
+    /// Immutable reference to the shards of the next generation.
+    ///
+    /// Empty if the group isn't resharding.
+    pub fn next_generation(&self) -> &[Shard<Q>] {
+        &self.next
+    }
+
+    /// Whether a next generation of shards is running.
+    pub fn is_resharding(&self) -> bool {
+        !self.next.is_empty()
+    }
+
+    /// Queue a command to be sent to the gateway by the shards of the current
+    /// and next generation.
+    ///
+    /// Refer to [`broadcast`] for details.
+    ///
+    /// [`broadcast`]: crate::broadcast
+    pub fn command(&self, command: &impl Command) {
+        crate::broadcast(self.current.iter().chain(&self.next), command);
+    }
+
+    /// Start running a next generation of shards.
+    ///
+    /// Shards of an already running next generation are closed and drained.
+    pub fn reshard(&mut self, shards: impl IntoIterator<Item = Shard<Q>>) {
+        let next = mem::replace(&mut self.next, shards.into_iter().collect());
+        self.drain(next, &CloseFrame::NORMAL);
+    }
+
+    /// Replace the current generation with the next one, closing and draining
+    /// the current generation's shards.
+    ///
+    /// Has no effect if the group isn't resharding.
+    pub fn cutover(&mut self) {
+        if !self.is_resharding() {
+            return;
+        }
+
+        tracing::debug!("cutting over to next generation of shards");
+        let current = mem::replace(&mut self.current, mem::take(&mut self.next));
+        self.index = 0;
+        self.drain(current, &CloseFrame::NORMAL);
+    }
+
+    /// Close the shards of all generations, ending the stream once their
+    /// remaining messages are yielded.
+    ///
+    /// Refer to [`shutdown`] for shutting down shards outside of a group.
+    ///
+    /// [`shutdown`]: crate::shutdown
+    pub fn shutdown(&mut self, behavior: ShutdownBehavior) {
+        tracing::debug!(?behavior, "shutting down shards");
+        let close_frame = behavior.close_frame();
+        let current = mem::take(&mut self.current);
+        let next = mem::take(&mut self.next);
+        self.index = 0;
+        self.drain(current, &close_frame);
+        self.drain(next, &close_frame);
+    }
+
+    /// Close the shards and poll them until they're closed.
+    ///
+    /// Disconnected shards are dropped immediately.
+    fn drain(&mut self, shards: Vec<Shard<Q>>, close_frame: &CloseFrame<'static>) {
+        for shard in shards {
+            if matches!(shard.state(), ShardState::Disconnected { .. }) {
+                continue;
+            }
+
+            shard.close(close_frame.clone());
+            self.draining.push(shard);
+        }
+    }
+}
+
+impl<Q: Queue + Unpin> ShardGroup<Q> {
+    /// Poll the shard at the index, removing it if it returned `None`.
+    fn poll_shard(
+        shards: &mut Vec<Shard<Q>>,
+        index: usize,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let id = shards[index].id();
+        match Pin::new(&mut shards[index]).poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some((id, item))),
+            Poll::Ready(None) => {
+                shards.remove(index);
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<Q: Queue + Unpin> Stream for ShardGroup<Q> {
+    type Item = (ShardId, Result<Message, ReceiveMessageError>);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        let mut index = 0;
+        while index < this.draining.len() {
+            match Self::poll_shard(&mut this.draining, index, cx) {
+                Poll::Ready(Some(item)) => {
+                    if matches!(item.1, Ok(Message::Close(_))) {
+                        this.draining.remove(index);
+                    }
+
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => {}
+                Poll::Pending => index += 1,
+            }
+        }
+
+        let mut index = 0;
+        while index < this.next.len() {
+            match Self::poll_shard(&mut this.next, index, cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.next.iter().all(|shard| shard.state().is_identified()) {
+                        this.cutover();
+                    }
+
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => {}
+                Poll::Pending => index += 1,
+            }
+        }
+
+        for _ in 0..this.current.len() {
+            if this.index >= this.current.len() {
+                this.index = 0;
+            }
+
+            let index = this.index;
+            this.index += 1;
+            match Self::poll_shard(&mut this.current, index, cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) => this.index = index,
+                Poll::Pending => {}
+            }
+        }
+
+        if this.current.is_empty() && this.next.is_empty() && this.draining.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShardGroup;
+    use crate::{Config, Intents, Shard, ShardId, ShutdownBehavior};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio_stream::StreamExt;
+
+    assert_impl_all!(ShardGroup: Debug, Send);
+
+    fn shards(total: u32) -> impl Iterator<Item = Shard> {
+        let config = Config::new("token".to_owned(), Intents::empty());
+
+        crate::create_iterator(0..total, total, config, |_, builder| builder.build())
+    }
+
+    #[tokio::test]
+    async fn reshard() {
+        let mut group = ShardGroup::new(shards(2));
+        assert!(!group.is_resharding());
+        assert!(group.next_generation().is_empty());
+
+        group.cutover();
+        assert_eq!(2, group.current().len());
+
+        group.reshard(shards(3));
+        assert!(group.is_resharding());
+        assert_eq!(3, group.next_generation().len());
+
+        group.cutover();
+        assert!(!group.is_resharding());
+        assert!(group.draining.is_empty());
+        assert_eq!(
+            vec![ShardId::new(0, 3), ShardId::new(1, 3), ShardId::new(2, 3)],
+            group.current().iter().map(Shard::id).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn shutdown() {
+        let mut group = ShardGroup::new(shards(2));
+        group.reshard(shards(3));
+
+        group.shutdown(ShutdownBehavior::Resume);
+        assert!(group.current().is_empty());
+        assert!(!group.is_resharding());
+        assert!(group.next().await.is_none());
+    }
+}
@@ -3,16 +3,20 @@
 //! The [`Inflater`] decompresses messages sent over the gateway by reusing a
 //! common buffer to minimize the amount of allocations in the hot path.
 //!
-//! A compressed message buffer is used to store incomplete messages and gets,
-//! if used, shrank every minute to the size of the most recent completed
-//! message.
+//! When using zlib, a compressed message buffer is used to store incomplete
+//! messages and gets, if used, shrank every minute to the size of the most
+//! recent completed message.
 
+use crate::{Encoding, Message};
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 use flate2::{Decompress, FlushDecompress};
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     time::Instant,
 };
+#[cfg(feature = "zstd")]
+use zstd_safe::{DCtx, InBuffer, OutBuffer, ResetDirective};
 
 /// An operation relating to compression failed.
 #[derive(Debug)]
@@ -70,7 +74,41 @@ pub enum CompressionErrorType {
     NotUtf8,
 }
 
+/// Transport compression of gateway messages.
+///
+/// Defaults to [`Zstd`] if the `zstd` feature is enabled and [`Zlib`]
+/// otherwise.
+///
+/// [`Zlib`]: Self::Zlib
+/// [`Zstd`]: Self::Zstd
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// `zlib-stream` transport compression.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    #[cfg_attr(not(feature = "zstd"), default)]
+    Zlib,
+    /// `zstd-stream` transport compression, which has a better compression
+    /// ratio than zlib.
+    #[cfg(feature = "zstd")]
+    #[default]
+    Zstd,
+}
+
+impl Compression {
+    /// Value of the `compress` query parameter.
+    pub(crate) const fn query(self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Self::Zlib => "zlib-stream",
+            #[cfg(feature = "zstd")]
+            Self::Zstd => "zstd-stream",
+        }
+    }
+}
+
 /// Whether the message is incomplete.
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 fn is_incomplete_message(message: &[u8]) -> bool {
     /// The "magic number" deciding if a message is done or if another
     /// message needs to be read.
@@ -83,6 +121,58 @@ fn is_incomplete_message(message: &[u8]) -> bool {
     message.len() < 4 || message[(message.len() - 4)..] != ZLIB_SUFFIX
 }
 
+/// Decompressor of a compression algorithm with a dictionary of past data.
+enum Decompressor {
+    /// Zlib decompressor.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    Zlib(Decompress),
+    /// Zstd decompressor.
+    #[cfg(feature = "zstd")]
+    Zstd {
+        /// Decompression context.
+        ctx: DCtx<'static>,
+        /// Total number of bytes processed.
+        processed: u64,
+        /// Total number of bytes produced.
+        produced: u64,
+    },
+}
+
+impl Decompressor {
+    /// Create a new decompressor for a compression algorithm.
+    fn new(compression: Compression) -> Self {
+        match compression {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Compression::Zlib => Self::Zlib(Decompress::new(true)),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Self::Zstd {
+                ctx: DCtx::create(),
+                processed: 0,
+                produced: 0,
+            },
+        }
+    }
+}
+
+impl Debug for Decompressor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Self::Zlib(decompress) => f.debug_tuple("Zlib").field(decompress).finish(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd {
+                processed,
+                produced,
+                ..
+            } => f
+                .debug_struct("Zstd")
+                .field("processed", processed)
+                .field("produced", produced)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
 /// Gateway event decompressor.
 ///
 /// Each received compressed event gets inflated into a [`String`] who's input
@@ -106,9 +196,11 @@ pub struct Inflater {
     /// Common decompressed message buffer.
     buffer: Box<[u8]>,
     /// Per event compressed message buffer.
+    ///
+    /// Only used by zlib, as zstd messages are always complete.
     compressed: Vec<u8>,
-    /// Zlib decompressor with a dictionary of past data.
-    decompress: Decompress,
+    /// Decompressor with a dictionary of past data.
+    decompressor: Decompressor,
     /// When the compression buffer last shrank.
     last_shrank: Instant,
 }
@@ -118,15 +210,25 @@ impl Inflater {
     const BUFFER_SIZE: usize = 32 * 1024;
 
     /// Create a new inflator for a shard.
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(compression: Compression) -> Self {
         Self {
             buffer: vec![0; Self::BUFFER_SIZE].into_boxed_slice(),
             compressed: Vec::new(),
-            decompress: Decompress::new(true),
+            decompressor: Decompressor::new(compression),
             last_shrank: Instant::now(),
         }
     }
 
+    /// Compression algorithm of the decompressed messages.
+    pub const fn compression(&self) -> Compression {
+        match self.decompressor {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Decompressor::Zlib(_) => Compression::Zlib,
+            #[cfg(feature = "zstd")]
+            Decompressor::Zstd { .. } => Compression::Zstd,
+        }
+    }
+
     /// Clear the compressed buffer and periodically shrink its capacity.
     fn clear(&mut self) {
         if self.compressed.capacity() != 0 && self.last_shrank.elapsed().as_secs() > 60 {
@@ -143,7 +245,7 @@ impl Inflater {
         self.compressed.clear();
     }
 
-    /// Decompress message.
+    /// Decompress message into a message of the encoding.
     ///
     /// Returns `None` if the message is incomplete, saving its content to be
     /// combined with the next one.
@@ -154,68 +256,41 @@ impl Inflater {
     /// message could not be decompressed.
     ///
     /// Returns a [`CompressionErrorType::NotUtf8`] error type if the
-    /// decompressed message is not UTF-8.
-    pub(crate) fn inflate(&mut self, message: &[u8]) -> Result<Option<String>, CompressionError> {
-        // Complete message. Tries to bypass the `self.compressed` buffer if the
-        // message is incomplete.
-        let message = if self.compressed.is_empty() {
-            if is_incomplete_message(message) {
-                tracing::trace!("received incomplete message");
-                self.compressed.extend_from_slice(message);
-                return Ok(None);
+    /// decompressed message is not UTF-8 while the encoding is
+    /// [`Encoding::Json`].
+    pub(crate) fn inflate(
+        &mut self,
+        message: &[u8],
+        encoding: Encoding,
+    ) -> Result<Option<Message>, CompressionError> {
+        let decompressed = match &mut self.decompressor {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Decompressor::Zlib(decompress) => {
+                match inflate_zlib(decompress, &mut self.buffer, &mut self.compressed, message)? {
+                    Some(decompressed) => decompressed,
+                    None => return Ok(None),
+                }
             }
-            message
-        } else {
-            self.compressed.extend_from_slice(message);
-            if is_incomplete_message(&self.compressed) {
-                tracing::trace!("received incomplete message");
-                return Ok(None);
+            #[cfg(feature = "zstd")]
+            Decompressor::Zstd {
+                ctx,
+                processed,
+                produced,
+            } => {
+                let decompressed = decompress_zstd(ctx, &mut self.buffer, message)?;
+                *processed += message.len() as u64;
+                *produced += decompressed.len() as u64;
+
+                decompressed
             }
-            &self.compressed
         };
 
-        let processed_pre = self.processed();
-
-        let mut processed = 0;
-
-        // Decompressed message. `Vec::extend_from_slice` efficiently allocates
-        // only what's necessary.
-        let mut decompressed = Vec::new();
-
-        loop {
-            let produced_pre = self.produced();
-
-            // Use Sync to ensure data is flushed to the buffer.
-            self.decompress
-                .decompress(
-                    &message[processed..],
-                    &mut self.buffer,
-                    FlushDecompress::Sync,
-                )
-                .map_err(|source| CompressionError {
-                    kind: CompressionErrorType::Decompressing,
-                    source: Some(Box::new(source)),
-                })?;
-
-            processed = (self.processed() - processed_pre).try_into().unwrap();
-            let produced = (self.produced() - produced_pre).try_into().unwrap();
-
-            decompressed.extend_from_slice(&self.buffer[..produced]);
-
-            // Break when message has been fully decompressed.
-            if processed == message.len() {
-                break;
-            }
-
-            tracing::trace!(bytes.compressed.remaining = message.len() - processed);
-        }
-
         {
             #[allow(clippy::cast_precision_loss)]
             let total_percentage_compressed =
                 self.processed() as f64 * 100.0 / self.produced() as f64;
             let total_percentage_saved = 100.0 - total_percentage_compressed;
-            let total_kib_saved = (self.produced() - self.processed()) / 1024;
+            let total_kib_saved = self.produced().saturating_sub(self.processed()) / 1024;
 
             tracing::trace!(
                 bytes.compressed = message.len(),
@@ -227,35 +302,177 @@ impl Inflater {
 
         self.clear();
 
-        String::from_utf8(decompressed)
-            .map(Some)
-            .map_err(|source| CompressionError {
-                kind: CompressionErrorType::NotUtf8,
-                source: Some(Box::new(source)),
-            })
+        match encoding {
+            #[cfg(feature = "etf")]
+            Encoding::Etf => Ok(Some(Message::Binary(decompressed))),
+            Encoding::Json => String::from_utf8(decompressed)
+                .map(|json| Some(Message::Text(json)))
+                .map_err(|source| CompressionError {
+                    kind: CompressionErrorType::NotUtf8,
+                    source: Some(Box::new(source)),
+                }),
+        }
     }
 
     /// Reset the inflater's state.
     pub(crate) fn reset(&mut self) {
         self.compressed = Vec::new();
-        self.decompress.reset(true);
+
+        match &mut self.decompressor {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Decompressor::Zlib(decompress) => decompress.reset(true),
+            #[cfg(feature = "zstd")]
+            Decompressor::Zstd {
+                ctx,
+                processed,
+                produced,
+            } => {
+                // Resetting only the session can't fail.
+                let _ = ctx.reset(ResetDirective::SessionOnly);
+                *processed = 0;
+                *produced = 0;
+            }
+        }
     }
 
     /// Total number of bytes processed.
+    #[allow(clippy::missing_const_for_fn)]
     pub fn processed(&self) -> u64 {
-        self.decompress.total_in()
+        match &self.decompressor {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Decompressor::Zlib(decompress) => decompress.total_in(),
+            #[cfg(feature = "zstd")]
+            Decompressor::Zstd { processed, .. } => *processed,
+        }
     }
 
     /// Total number of bytes produced.
+    #[allow(clippy::missing_const_for_fn)]
     pub fn produced(&self) -> u64 {
-        self.decompress.total_out()
+        match &self.decompressor {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Decompressor::Zlib(decompress) => decompress.total_out(),
+            #[cfg(feature = "zstd")]
+            Decompressor::Zstd { produced, .. } => *produced,
+        }
+    }
+}
+
+/// Decompress a zlib message.
+///
+/// Returns `None` if the message is incomplete, saving its content to the
+/// compressed buffer to be combined with the next one.
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+fn inflate_zlib(
+    decompress: &mut Decompress,
+    buffer: &mut [u8],
+    compressed: &mut Vec<u8>,
+    message: &[u8],
+) -> Result<Option<Vec<u8>>, CompressionError> {
+    // Complete message. Tries to bypass the `compressed` buffer if the
+    // message is incomplete.
+    let message = if compressed.is_empty() {
+        if is_incomplete_message(message) {
+            tracing::trace!("received incomplete message");
+            compressed.extend_from_slice(message);
+            return Ok(None);
+        }
+        message
+    } else {
+        compressed.extend_from_slice(message);
+        if is_incomplete_message(compressed) {
+            tracing::trace!("received incomplete message");
+            return Ok(None);
+        }
+        compressed
+    };
+
+    let processed_pre = decompress.total_in();
+
+    let mut processed = 0;
+
+    // Decompressed message. `Vec::extend_from_slice` efficiently allocates
+    // only what's necessary.
+    let mut decompressed = Vec::new();
+
+    loop {
+        let produced_pre = decompress.total_out();
+
+        // Use Sync to ensure data is flushed to the buffer.
+        decompress
+            .decompress(&message[processed..], buffer, FlushDecompress::Sync)
+            .map_err(|source| CompressionError {
+                kind: CompressionErrorType::Decompressing,
+                source: Some(Box::new(source)),
+            })?;
+
+        processed = (decompress.total_in() - processed_pre).try_into().unwrap();
+        let produced = (decompress.total_out() - produced_pre).try_into().unwrap();
+
+        decompressed.extend_from_slice(&buffer[..produced]);
+
+        // Break when message has been fully decompressed.
+        if processed == message.len() {
+            break;
+        }
+
+        tracing::trace!(bytes.compressed.remaining = message.len() - processed);
+    }
+
+    Ok(Some(decompressed))
+}
+
+/// Decompress a zstd message.
+///
+/// Messages are always complete since Discord flushes the stream after each
+/// one.
+#[cfg(feature = "zstd")]
+fn decompress_zstd(
+    ctx: &mut DCtx<'static>,
+    buffer: &mut [u8],
+    message: &[u8],
+) -> Result<Vec<u8>, CompressionError> {
+    let mut input = InBuffer::around(message);
+
+    // Decompressed message. `Vec::extend_from_slice` efficiently allocates
+    // only what's necessary.
+    let mut decompressed = Vec::new();
+
+    loop {
+        let mut output = OutBuffer::around(&mut *buffer);
+
+        ctx.decompress_stream(&mut output, &mut input)
+            .map_err(|code| CompressionError {
+                kind: CompressionErrorType::Decompressing,
+                source: Some(zstd_safe::get_error_name(code).into()),
+            })?;
+
+        let produced = output.pos();
+        decompressed.extend_from_slice(&buffer[..produced]);
+
+        // Break when the message has been fully decompressed and flushed,
+        // which is the case once the buffer isn't filled anymore.
+        if input.pos() == message.len() && produced < buffer.len() {
+            break;
+        }
+
+        tracing::trace!(bytes.compressed.remaining = message.len() - input.pos());
     }
+
+    Ok(decompressed)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Inflater;
+    use super::{Compression, Inflater};
+    use crate::{Encoding, Message};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Compression: Clone, Copy, Debug, Default, Eq, Send, Sync);
+    assert_impl_all!(Inflater: Debug, Send, Sync);
 
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     const MESSAGE: &[u8] = &[
         120, 156, 52, 201, 65, 10, 131, 48, 16, 5, 208, 187, 252, 117, 82, 98, 169, 32, 115, 21,
         35, 50, 53, 67, 27, 136, 81, 226, 216, 82, 66, 238, 222, 110, 186, 123, 240, 42, 20, 148,
@@ -266,52 +483,132 @@ mod tests {
     ];
     const OUTPUT: &str = r#"{"t":null,"s":null,"op":10,"d":{"heartbeat_interval":41250,"_trace":["[\"gateway-prd-main-858d\",{\"micros\":0.0}]"]}}"#;
 
+    /// Message of the decompressed JSON.
+    fn text(json: &str) -> Option<Message> {
+        Some(Message::Text(json.to_owned()))
+    }
+
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     #[test]
     fn decompress_single_segment() {
-        let mut inflator = Inflater::new();
+        let mut inflator = Inflater::new(Compression::Zlib);
         assert!(inflator.compressed.is_empty());
-        assert_eq!(inflator.inflate(MESSAGE).unwrap(), Some(OUTPUT.to_owned()));
+        assert_eq!(
+            inflator.inflate(MESSAGE, Encoding::Json).unwrap(),
+            text(OUTPUT)
+        );
 
         assert!(inflator.compressed.is_empty());
     }
 
+    #[cfg(all(feature = "etf", any(feature = "zlib-stock", feature = "zlib-simd")))]
+    #[test]
+    fn decompress_etf() {
+        let mut inflator = Inflater::new(Compression::Zlib);
+        assert_eq!(
+            inflator.inflate(MESSAGE, Encoding::Etf).unwrap(),
+            Some(Message::Binary(OUTPUT.into()))
+        );
+    }
+
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     #[test]
     fn decompress_split_message() {
-        let mut inflator = Inflater::new();
+        let mut inflator = Inflater::new(Compression::Zlib);
         assert!(inflator.compressed.is_empty());
         assert_eq!(
-            inflator.inflate(&MESSAGE[0..MESSAGE.len() / 2]).unwrap(),
+            inflator
+                .inflate(&MESSAGE[0..MESSAGE.len() / 2], Encoding::Json)
+                .unwrap(),
             None
         );
         assert!(!inflator.compressed.is_empty());
 
         assert_eq!(
-            inflator.inflate(&MESSAGE[MESSAGE.len() / 2..]).unwrap(),
-            Some(OUTPUT.to_owned()),
+            inflator
+                .inflate(&MESSAGE[MESSAGE.len() / 2..], Encoding::Json)
+                .unwrap(),
+            text(OUTPUT),
         );
         assert!(inflator.compressed.is_empty());
     }
 
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     #[test]
     fn invalid_is_none() {
-        let mut inflator = Inflater::new();
-        assert_eq!(inflator.inflate(&[]).unwrap(), None);
+        let mut inflator = Inflater::new(Compression::Zlib);
+        assert_eq!(inflator.inflate(&[], Encoding::Json).unwrap(), None);
 
         assert_eq!(
-            inflator.inflate(&MESSAGE[..MESSAGE.len() - 2]).unwrap(),
+            inflator
+                .inflate(&MESSAGE[..MESSAGE.len() - 2], Encoding::Json)
+                .unwrap(),
             None
         );
     }
 
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     #[test]
     fn reset() {
-        let mut inflator = Inflater::new();
+        let mut inflator = Inflater::new(Compression::Zlib);
         assert_eq!(
-            inflator.inflate(&MESSAGE[..MESSAGE.len() - 2]).unwrap(),
+            inflator
+                .inflate(&MESSAGE[..MESSAGE.len() - 2], Encoding::Json)
+                .unwrap(),
             None
         );
 
         inflator.reset();
-        assert_eq!(inflator.inflate(MESSAGE).unwrap(), Some(OUTPUT.to_owned()));
+        assert_eq!(
+            inflator.inflate(MESSAGE, Encoding::Json).unwrap(),
+            text(OUTPUT)
+        );
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn decompress_zstd() {
+        use zstd_safe::{CCtx, InBuffer, OutBuffer};
+
+        const SECOND: &str = r#"{"t":null,"s":null,"op":11,"d":null}"#;
+
+        /// Compress a message, flushing the stream as Discord does.
+        fn compress(ctx: &mut CCtx<'_>, message: &str) -> Vec<u8> {
+            let mut compressed = Vec::with_capacity(1024);
+            let mut input = InBuffer::around(message.as_bytes());
+            let mut output = OutBuffer::around(&mut compressed);
+            ctx.compress_stream(&mut output, &mut input).unwrap();
+            while ctx.flush_stream(&mut output).unwrap() != 0 {}
+            compressed
+        }
+
+        let mut ctx = CCtx::create();
+        let first = compress(&mut ctx, OUTPUT);
+        let second = compress(&mut ctx, SECOND);
+
+        let mut inflator = Inflater::new(Compression::Zstd);
+        assert_eq!(inflator.compression(), Compression::Zstd);
+        assert_eq!(
+            inflator.inflate(&first, Encoding::Json).unwrap(),
+            text(OUTPUT)
+        );
+        assert_eq!(
+            inflator.inflate(&second, Encoding::Json).unwrap(),
+            text(SECOND)
+        );
+        assert_eq!(inflator.processed(), (first.len() + second.len()) as u64);
+        assert_eq!(inflator.produced(), (OUTPUT.len() + SECOND.len()) as u64);
+
+        inflator.reset();
+        assert_eq!(inflator.processed(), 0);
+        let mut ctx = CCtx::create();
+        let first = compress(&mut ctx, OUTPUT);
+        assert_eq!(
+            inflator.inflate(&first, Encoding::Json).unwrap(),
+            text(OUTPUT)
+        );
+
+        let mut inflator = Inflater::new(Compression::Zstd);
+        assert!(inflator.inflate(&[0; 8], Encoding::Json).is_err());
     }
 }
@@ -16,6 +16,25 @@ use twilight_model::gateway::{
     OpCode,
 };
 
+/// Parse the type of a JSON encoded gateway event, without deserializing its
+/// data.
+///
+/// Returns `None` if the event type is unknown.
+pub(crate) fn event_type(event: &str) -> Result<Option<EventTypeFlags>, ReceiveMessageError> {
+    let Some(gateway_deserializer) = GatewayEventDeserializer::from_json(event) else {
+        return Err(ReceiveMessageError {
+            kind: ReceiveMessageErrorType::Deserializing {
+                event: event.to_owned(),
+            },
+            source: None,
+        });
+    };
+
+    Ok(OpCode::from(gateway_deserializer.op()).and_then(|opcode| {
+        EventTypeFlags::try_from((opcode, gateway_deserializer.event_type())).ok()
+    }))
+}
+
 /// Parse a JSON encoded gateway event into a `GatewayEvent` if
 /// `wanted_event_types` contains its type.
 ///
@@ -1,6 +1,13 @@
 //! Statistics about the latency of a shard, useful for debugging.
 
-use std::time::{Duration, Instant};
+use futures_core::Stream;
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
 
 /// [`Shard`]'s gateway connection latency.
 ///
@@ -24,12 +31,17 @@ pub struct Latency {
     recent: [Duration; Self::RECENT_LEN],
     /// When the last heartbeat was sent.
     sent: Option<Instant>,
+    /// Rolling window of latencies from oldest to newest.
+    window: VecDeque<Duration>,
 }
 
 impl Latency {
     /// Number of recent latencies to store.
     const RECENT_LEN: usize = 5;
 
+    /// Number of latencies to store in the rolling window.
+    const WINDOW_LEN: usize = 100;
+
     /// Create a new instance for tracking shard latency.
     pub(crate) const fn new() -> Self {
         Self {
@@ -38,6 +50,7 @@ impl Latency {
             received: None,
             recent: [Duration::MAX; Self::RECENT_LEN],
             sent: None,
+            window: VecDeque::new(),
         }
     }
 
@@ -51,6 +64,38 @@ impl Latency {
         self.latency_sum.checked_div(self.periods)
     }
 
+    /// Lowest latency in the rolling window of the last 100 heartbeat periods.
+    ///
+    /// Returns [`None`] if no heartbeat periods have been recorded.
+    pub fn min(&self) -> Option<Duration> {
+        self.window.iter().min().copied()
+    }
+
+    /// 95th percentile latency in the rolling window of the last 100
+    /// heartbeat periods.
+    ///
+    /// Returns [`None`] if no heartbeat periods have been recorded.
+    pub fn p95(&self) -> Option<Duration> {
+        self.percentile(95)
+    }
+
+    /// 99th percentile latency in the rolling window of the last 100
+    /// heartbeat periods.
+    ///
+    /// Returns [`None`] if no heartbeat periods have been recorded.
+    pub fn p99(&self) -> Option<Duration> {
+        self.percentile(99)
+    }
+
+    /// Nearest-rank percentile latency in the rolling window.
+    fn percentile(&self, percentile: usize) -> Option<Duration> {
+        let mut sorted = Vec::from(self.window.clone());
+        sorted.sort_unstable();
+        let rank = (sorted.len() * percentile).div_ceil(100);
+
+        sorted.get(rank.checked_sub(1)?).copied()
+    }
+
     /// Number of recorded heartbeat periods.
     pub const fn periods(&self) -> u32 {
         self.periods
@@ -69,6 +114,22 @@ impl Latency {
         &self.recent[0..maybe_zero_idx.unwrap_or(Self::RECENT_LEN)]
     }
 
+    /// Average latency in the rolling window of the last 100 heartbeat
+    /// periods.
+    ///
+    /// Unlike [`average`], recovers quickly from past latency spikes.
+    ///
+    /// Returns [`None`] if no heartbeat periods have been recorded.
+    ///
+    /// [`average`]: Self::average
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn rolling_average(&self) -> Option<Duration> {
+        // The window holds at most `WINDOW_LEN` latencies.
+        let len = self.window.len() as u32;
+
+        self.window.iter().sum::<Duration>().checked_div(len)
+    }
+
     /// When the last heartbeat received an acknowledgement.
     pub const fn received(&self) -> Option<Instant> {
         self.received
@@ -102,6 +163,11 @@ impl Latency {
         self.latency_sum += period_latency;
         self.recent.rotate_right(1);
         self.recent[0] = period_latency;
+
+        if self.window.len() == Self::WINDOW_LEN {
+            self.window.pop_front();
+        }
+        self.window.push_back(period_latency);
     }
 
     /// Record that a heartbeat was sent, beginning a new period.
@@ -115,13 +181,37 @@ impl Latency {
     }
 }
 
+/// Stream of a [`Shard`]'s heartbeat latencies as they are recorded.
+///
+/// Latencies are only recorded while the shard is being polled, and the stream
+/// is never exhausted before the shard is dropped.
+///
+/// May be obtained via [`Shard::latency_samples`].
+///
+/// [`Shard`]: crate::Shard
+/// [`Shard::latency_samples`]: crate::Shard::latency_samples
+#[derive(Debug)]
+pub struct LatencySamples {
+    /// Receiving half of recorded latencies.
+    pub(crate) rx: mpsc::UnboundedReceiver<Duration>,
+}
+
+impl Stream for LatencySamples {
+    type Item = Duration;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Latency;
+    use super::{Latency, LatencySamples};
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, time::Duration};
+    use std::{collections::VecDeque, fmt::Debug, time::Duration};
 
     assert_impl_all!(Latency: Clone, Debug, Send, Sync);
+    assert_impl_all!(LatencySamples: Debug, Send, Sync, Unpin);
 
     const fn default_latency() -> Latency {
         Latency {
@@ -136,6 +226,7 @@ mod tests {
                 Duration::from_millis(40),
             ],
             sent: None,
+            window: VecDeque::new(),
         }
     }
 
@@ -170,6 +261,7 @@ mod tests {
             received: None,
             recent: [Duration::MAX; Latency::RECENT_LEN],
             sent: None,
+            window: VecDeque::new(),
         };
         assert!(no_recents.recent().is_empty());
 
@@ -183,7 +275,7 @@ mod tests {
                 Duration::MAX,
                 Duration::MAX,
             ],
-            ..no_recents
+            ..no_recents.clone()
         };
         assert_eq!(
             [Duration::from_millis(40), Duration::from_millis(50)],
@@ -240,6 +332,29 @@ mod tests {
         assert_eq!(latency.recent().len(), 1);
     }
 
+    #[test]
+    fn window() {
+        let mut latency = Latency::new();
+        assert!(latency.min().is_none());
+        assert!(latency.rolling_average().is_none());
+        assert!(latency.p95().is_none());
+        assert!(latency.p99().is_none());
+
+        latency.window = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(latency.min(), Some(Duration::from_millis(1)));
+        assert_eq!(
+            latency.rolling_average(),
+            Some(Duration::from_micros(50_500))
+        );
+        assert_eq!(latency.p95(), Some(Duration::from_millis(95)));
+        assert_eq!(latency.p99(), Some(Duration::from_millis(99)));
+
+        latency.record_sent();
+        latency.record_received();
+        assert_eq!(latency.window.len(), Latency::WINDOW_LEN);
+        assert_eq!(latency.min(), Some(latency.recent()[0]));
+    }
+
     #[test]
     #[should_panic(expected = "period completed multiple times")]
     fn record_completed_period() {
@@ -13,39 +13,62 @@
     clippy::unnecessary_wraps
 )]
 
+pub mod coordinator;
 pub mod error;
+#[cfg(feature = "etf")]
+pub mod etf;
 
 mod channel;
 mod command;
 mod config;
 mod event;
-#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+mod fanout;
+mod group;
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
 mod inflater;
 mod json;
 mod latency;
 mod message;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod payload;
+mod presence;
+mod proxy;
 mod ratelimiter;
 mod session;
+#[cfg(feature = "twilight-http")]
+mod session_budget;
 mod shard;
+mod shutdown;
 mod stream;
+mod watchdog;
 
-#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-pub use self::inflater::Inflater;
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+pub use self::inflater::{Compression, Inflater};
 pub use self::{
     channel::MessageSender,
     command::Command,
-    config::{Config, ConfigBuilder},
+    config::{Config, ConfigBuilder, ProxyHandling},
     event::EventTypeFlags,
+    fanout::{Fanout, Subscription},
+    group::ShardGroup,
     json::parse,
-    latency::Latency,
-    message::Message,
-    ratelimiter::CommandRatelimiter,
-    session::Session,
+    latency::{Latency, LatencySamples},
+    message::{Encoding, Message},
+    payload::{LargestPayload, PayloadStats},
+    presence::PresenceRotation,
+    proxy::{Proxy, ProxyKind},
+    ratelimiter::{CommandRatelimiter, Priority},
+    session::{ResumeState, Session},
     shard::{Shard, ShardState},
-    stream::StreamExt,
+    shutdown::{shutdown, ShutdownBehavior},
+    stream::{RawEvent, StreamExt},
 };
 pub use twilight_model::gateway::{CloseFrame, Intents, ShardId};
 
+#[cfg(feature = "twilight-http")]
+pub use self::session_budget::{SessionBudget, SessionBudgetQueue};
+
 #[doc(no_inline)]
 pub use twilight_gateway_queue as queue;
 #[doc(no_inline)]
@@ -54,7 +77,13 @@ pub use twilight_model::gateway::event::{Event, EventType};
 #[cfg(feature = "twilight-http")]
 use self::error::{StartRecommendedError, StartRecommendedErrorType};
 #[cfg(feature = "twilight-http")]
+use self::queue::Queue;
+#[cfg(feature = "twilight-http")]
+use std::time::Duration;
+#[cfg(feature = "twilight-http")]
 use twilight_http::Client;
+#[cfg(feature = "twilight-http")]
+use twilight_model::gateway::connection_info::BotConnectionInfo;
 
 /// Discord Gateway API version used by this crate.
 pub const API_VERSION: u8 = 10;
@@ -156,6 +185,7 @@ where
 /// may be performed in the callback.
 ///
 /// Internally calls [`create_iterator`] with the values from [`GetGatewayAuthed`].
+/// The config's queue is [updated] with the returned session start limit.
 ///
 /// # Errors
 ///
@@ -170,6 +200,7 @@ where
 /// Panics if loading TLS certificates fails.
 ///
 /// [`GetGatewayAuthed`]: twilight_http::request::GetGatewayAuthed
+/// [updated]: Queue::update
 #[cfg(feature = "twilight-http")]
 pub async fn create_recommended<F, Q>(
     client: &Client,
@@ -178,25 +209,96 @@ pub async fn create_recommended<F, Q>(
 ) -> Result<impl ExactSizeIterator<Item = Shard<Q>>, StartRecommendedError>
 where
     F: Fn(ShardId, ConfigBuilder<Q>) -> Config<Q>,
-    Q: Clone,
+    Q: Clone + Queue,
 {
+    let info = gateway_info(client).await?;
+
+    let limit = info.session_start_limit;
+    config.queue().update(
+        limit.max_concurrency,
+        limit.remaining,
+        Duration::from_millis(limit.reset_after),
+        limit.total,
+    );
+
+    Ok(create_iterator(
+        0..info.shards,
+        info.shards,
+        config,
+        per_shard_config,
+    ))
+}
+
+/// Fetch the recommended number of shards and session start limit.
+#[cfg(feature = "twilight-http")]
+async fn gateway_info(client: &Client) -> Result<BotConnectionInfo, StartRecommendedError> {
     let request = client.gateway().authed();
     let response = request.await.map_err(|source| StartRecommendedError {
         kind: StartRecommendedErrorType::Request,
         source: Some(Box::new(source)),
     })?;
-    let info = response
+
+    response
         .model()
         .await
         .map_err(|source| StartRecommendedError {
             kind: StartRecommendedErrorType::Deserializing,
             source: Some(Box::new(source)),
-        })?;
+        })
+}
 
-    Ok(create_iterator(
-        0..info.shards,
-        info.shards,
-        config,
-        per_shard_config,
-    ))
+/// Queue a command to be sent to the gateway by each of the shards.
+///
+/// The command is only serialized once per [`Encoding`] of the shards. Each
+/// shard sends it as soon as its own [command ratelimiter] permits,
+/// independently of the other shards.
+///
+/// # Examples
+///
+/// Update the presence of all shards:
+///
+/// ```no_run
+/// use std::env;
+/// use twilight_gateway::{Config, Intents};
+/// use twilight_model::gateway::{
+///     payload::outgoing::UpdatePresence,
+///     presence::{Activity, ActivityType, MinimalActivity, Status},
+/// };
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let config = Config::new(token, Intents::GUILDS);
+/// let shards = twilight_gateway::create_iterator(0..10, 10, config, |_, builder| builder.build())
+///     .collect::<Vec<_>>();
+///
+/// let activity = Activity::from(MinimalActivity {
+///     kind: ActivityType::Playing,
+///     name: "twilight".to_owned(),
+///     url: None,
+/// });
+/// let command = UpdatePresence::new([activity], false, None, Status::Online)?;
+/// twilight_gateway::broadcast(&shards, &command);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [command ratelimiter]: CommandRatelimiter
+pub fn broadcast<'a, Q: 'a>(
+    shards: impl IntoIterator<Item = &'a Shard<Q>>,
+    command: &impl Command,
+) {
+    let mut encoded: Option<(Encoding, Message)> = None;
+
+    for shard in shards {
+        let encoding = shard.config().encoding();
+        let message = match &encoded {
+            Some((cached, message)) if *cached == encoding => message.clone(),
+            _ => {
+                let message = encoding.command(command);
+                encoded = Some((encoding, message.clone()));
+
+                message
+            }
+        };
+
+        shard.send_message(message, Priority::Normal);
+    }
 }
@@ -0,0 +1,100 @@
+//! Recording of per shard metrics via the [`metrics`] facade.
+//!
+//! Every metric is labeled with the `shard` number. Recorded metrics:
+//!
+//! - `twilight_gateway_events_total`: counter of received dispatch events,
+//!   additionally labeled with the `event_type`
+//! - `twilight_gateway_heartbeat_latency_seconds`: histogram of heartbeat
+//!   latencies
+//! - `twilight_gateway_identifies_total`: counter of sent identifies
+//! - `twilight_gateway_payload_bytes_total`: counter of received bytes, before
+//!   decompression
+//! - `twilight_gateway_queue_wait_seconds`: histogram of time spent waiting on
+//!   the identify queue
+//! - `twilight_gateway_reconnects_total`: counter of disconnects the shard
+//!   reconnects after
+//! - `twilight_gateway_resumes_total`: counter of sent resumes
+//! - `twilight_gateway_stalls_total`: counter of stalls detected by the
+//!   watchdog
+//!
+//! [`metrics`]: ::metrics
+
+use ::metrics::{counter, histogram};
+use std::time::Duration;
+use twilight_model::gateway::ShardId;
+
+/// Name of the label containing the shard number.
+const SHARD: &str = "shard";
+
+/// Record a received dispatch event.
+pub fn event(shard: ShardId, event_type: &str) {
+    counter!(
+        "twilight_gateway_events_total",
+        SHARD => shard.number().to_string(),
+        "event_type" => event_type.to_owned(),
+    )
+    .increment(1);
+}
+
+/// Record a heartbeat's latency.
+pub fn heartbeat_latency(shard: ShardId, latency: Duration) {
+    histogram!(
+        "twilight_gateway_heartbeat_latency_seconds",
+        SHARD => shard.number().to_string(),
+    )
+    .record(latency);
+}
+
+/// Record a sent identify.
+pub fn identify(shard: ShardId) {
+    counter!(
+        "twilight_gateway_identifies_total",
+        SHARD => shard.number().to_string(),
+    )
+    .increment(1);
+}
+
+/// Record the number of bytes of a received message.
+pub fn payload_bytes(shard: ShardId, bytes: usize) {
+    counter!(
+        "twilight_gateway_payload_bytes_total",
+        SHARD => shard.number().to_string(),
+    )
+    .increment(bytes as u64);
+}
+
+/// Record the time spent waiting on the identify queue.
+pub fn queue_wait(shard: ShardId, wait: Duration) {
+    histogram!(
+        "twilight_gateway_queue_wait_seconds",
+        SHARD => shard.number().to_string(),
+    )
+    .record(wait);
+}
+
+/// Record a disconnect the shard reconnects after.
+pub fn reconnect(shard: ShardId) {
+    counter!(
+        "twilight_gateway_reconnects_total",
+        SHARD => shard.number().to_string(),
+    )
+    .increment(1);
+}
+
+/// Record a sent resume.
+pub fn resume(shard: ShardId) {
+    counter!(
+        "twilight_gateway_resumes_total",
+        SHARD => shard.number().to_string(),
+    )
+    .increment(1);
+}
+
+/// Record a stall detected by the watchdog.
+pub fn stall(shard: ShardId) {
+    counter!(
+        "twilight_gateway_stalls_total",
+        SHARD => shard.number().to_string(),
+    )
+    .increment(1);
+}
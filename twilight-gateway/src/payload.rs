@@ -0,0 +1,223 @@
+//! Statistics about the payloads received by a shard, useful for quantifying
+//! bandwidth.
+
+use std::collections::HashMap;
+use twilight_model::{
+    gateway::OpCode,
+    id::{marker::GuildMarker, Id},
+};
+
+/// [`Shard`]'s received payload statistics.
+///
+/// Counts the bytes received over the gateway connection before and after
+/// decompression, as well as the number of payloads per opcode, and tracks the
+/// largest payload.
+///
+/// Unlike [`Shard::inflater`], is not reset when reconnecting to the gateway.
+///
+/// May be obtained via [`Shard::payload_stats`].
+///
+/// # Examples
+///
+/// Calculate the percentage of bytes saved by compression and log the largest
+/// [`GuildCreate`] payload:
+///
+/// ```
+/// # use twilight_gateway::{Intents, Shard, ShardId};
+/// # #[tokio::main] async fn main() {
+/// # let shard = Shard::new(ShardId::ONE, String::new(), Intents::empty());
+/// let stats = shard.payload_stats();
+/// let percentage_saved =
+///     100.0 - stats.compressed_bytes() as f64 * 100.0 / stats.decompressed_bytes() as f64;
+///
+/// if let Some(largest) = stats.largest() {
+///     if let Some(guild_id) = largest.guild_id() {
+///         tracing::info!(bytes = largest.bytes(), %guild_id, "largest guild create");
+///     }
+/// }
+/// # }
+/// ```
+///
+/// [`GuildCreate`]: twilight_model::gateway::payload::incoming::GuildCreate
+/// [`Shard`]: crate::Shard
+/// [`Shard::inflater`]: crate::Shard::inflater
+/// [`Shard::payload_stats`]: crate::Shard::payload_stats
+#[derive(Clone, Debug, Default)]
+pub struct PayloadStats {
+    /// Number of bytes received before decompression.
+    compressed_bytes: u64,
+    /// Number of bytes of the payloads after decompression.
+    decompressed_bytes: u64,
+    /// Largest payload.
+    largest: Option<LargestPayload>,
+    /// Number of payloads per opcode.
+    opcodes: HashMap<OpCode, u64>,
+    /// Number of payloads.
+    payloads: u64,
+}
+
+impl PayloadStats {
+    /// Create a new instance for tracking received payloads.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bytes received over the gateway connection, before
+    /// decompression.
+    ///
+    /// Equal to [`decompressed_bytes`] if transport compression is disabled,
+    /// aside from WebSocket control messages.
+    ///
+    /// [`decompressed_bytes`]: Self::decompressed_bytes
+    pub const fn compressed_bytes(&self) -> u64 {
+        self.compressed_bytes
+    }
+
+    /// Number of bytes of the received payloads, after decompression.
+    pub const fn decompressed_bytes(&self) -> u64 {
+        self.decompressed_bytes
+    }
+
+    /// Largest received payload, by its decompressed size.
+    ///
+    /// Returns [`None`] if no payloads have been received.
+    pub const fn largest(&self) -> Option<&LargestPayload> {
+        self.largest.as_ref()
+    }
+
+    /// Number of received payloads.
+    pub const fn payloads(&self) -> u64 {
+        self.payloads
+    }
+
+    /// Number of received payloads of an opcode.
+    pub fn payloads_of(&self, opcode: OpCode) -> u64 {
+        self.opcodes.get(&opcode).copied().unwrap_or_default()
+    }
+
+    /// Record the size of a message received over the gateway connection.
+    pub(crate) fn record_received(&mut self, bytes: usize) {
+        self.compressed_bytes += bytes as u64;
+    }
+
+    /// Record a received payload, returning whether it's the largest so far.
+    pub(crate) fn record_payload(&mut self, opcode: Option<OpCode>, bytes: usize) -> bool {
+        self.decompressed_bytes += bytes as u64;
+        self.payloads += 1;
+        if let Some(opcode) = opcode {
+            *self.opcodes.entry(opcode).or_default() += 1;
+        }
+
+        self.largest
+            .as_ref()
+            .map_or(true, |largest| bytes > largest.bytes)
+    }
+
+    /// Record the largest payload.
+    pub(crate) fn record_largest(&mut self, largest: LargestPayload) {
+        self.largest = Some(largest);
+    }
+}
+
+/// Largest payload received by a shard.
+///
+/// May be obtained via [`PayloadStats::largest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LargestPayload {
+    /// Decompressed size of the payload in bytes.
+    bytes: usize,
+    /// Type of the payload's dispatch event.
+    event_type: Option<Box<str>>,
+    /// ID of the guild of the payload's [`GuildCreate`] event.
+    ///
+    /// [`GuildCreate`]: twilight_model::gateway::payload::incoming::GuildCreate
+    guild_id: Option<Id<GuildMarker>>,
+    /// Opcode of the payload.
+    opcode: Option<OpCode>,
+}
+
+impl LargestPayload {
+    /// Create a new instance of the largest payload.
+    pub(crate) const fn new(
+        bytes: usize,
+        event_type: Option<Box<str>>,
+        guild_id: Option<Id<GuildMarker>>,
+        opcode: Option<OpCode>,
+    ) -> Self {
+        Self {
+            bytes,
+            event_type,
+            guild_id,
+            opcode,
+        }
+    }
+
+    /// Decompressed size of the payload in bytes.
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Type of the payload's event, if it's a dispatch event.
+    pub fn event_type(&self) -> Option<&str> {
+        self.event_type.as_deref()
+    }
+
+    /// ID of the guild, if the payload is a [`GuildCreate`] event.
+    ///
+    /// [`GuildCreate`]: twilight_model::gateway::payload::incoming::GuildCreate
+    pub const fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        self.guild_id
+    }
+
+    /// Opcode of the payload, if it's known.
+    pub const fn opcode(&self) -> Option<OpCode> {
+        self.opcode
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LargestPayload, PayloadStats};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{gateway::OpCode, id::Id};
+
+    assert_impl_all!(LargestPayload: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(PayloadStats: Clone, Debug, Default, Send, Sync);
+
+    #[test]
+    fn record() {
+        let mut stats = PayloadStats::new();
+        assert!(stats.largest().is_none());
+
+        stats.record_received(10);
+        assert!(stats.record_payload(Some(OpCode::Hello), 30));
+        stats.record_largest(LargestPayload::new(30, None, None, Some(OpCode::Hello)));
+
+        stats.record_received(100);
+        assert!(stats.record_payload(Some(OpCode::Dispatch), 500));
+        stats.record_largest(LargestPayload::new(
+            500,
+            Some("GUILD_CREATE".into()),
+            Some(Id::new(1)),
+            Some(OpCode::Dispatch),
+        ));
+
+        stats.record_received(5);
+        assert!(!stats.record_payload(Some(OpCode::Dispatch), 20));
+        assert!(!stats.record_payload(None, 500));
+
+        assert_eq!(115, stats.compressed_bytes());
+        assert_eq!(1050, stats.decompressed_bytes());
+        assert_eq!(4, stats.payloads());
+        assert_eq!(2, stats.payloads_of(OpCode::Dispatch));
+        assert_eq!(1, stats.payloads_of(OpCode::Hello));
+        assert_eq!(0, stats.payloads_of(OpCode::Heartbeat));
+
+        let largest = stats.largest().unwrap();
+        assert_eq!(500, largest.bytes());
+        assert_eq!(Some("GUILD_CREATE"), largest.event_type());
+        assert_eq!(Some(Id::new(1)), largest.guild_id());
+        assert_eq!(Some(OpCode::Dispatch), largest.opcode());
+    }
+}
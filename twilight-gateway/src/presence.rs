@@ -0,0 +1,92 @@
+//! Rotating a shard's presence on an interval.
+
+use std::time::Duration;
+use twilight_model::gateway::payload::outgoing::update_presence::UpdatePresencePayload;
+
+/// Presences for a [`Shard`] to rotate through on an interval.
+///
+/// The rotation only runs while the shard is identified and pauses while it's
+/// reconnecting, continuing with the next presence afterwards. Presence
+/// updates are sent as [low priority] commands.
+///
+/// May be set via [`ConfigBuilder::presence_rotation`].
+///
+/// [`ConfigBuilder::presence_rotation`]: crate::ConfigBuilder::presence_rotation
+/// [`Shard`]: crate::Shard
+/// [low priority]: crate::Priority::Low
+#[derive(Clone, Debug)]
+pub struct PresenceRotation {
+    /// Period between presence updates.
+    interval: Duration,
+    /// Presences to rotate through.
+    presences: Box<[UpdatePresencePayload]>,
+}
+
+impl PresenceRotation {
+    /// Create a new rotation through the presences, updating the presence
+    /// every `interval`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `presences` is empty or `interval` is zero.
+    pub fn new(
+        presences: impl IntoIterator<Item = UpdatePresencePayload>,
+        interval: Duration,
+    ) -> Self {
+        let presences = presences.into_iter().collect::<Box<_>>();
+        assert!(!presences.is_empty(), "presences must not be empty");
+        assert!(!interval.is_zero(), "interval must not be zero");
+
+        Self {
+            interval,
+            presences,
+        }
+    }
+
+    /// Period between presence updates.
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Immutable reference to the presences to rotate through.
+    pub const fn presences(&self) -> &[UpdatePresencePayload] {
+        &self.presences
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PresenceRotation;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::gateway::{
+        payload::outgoing::update_presence::UpdatePresencePayload, presence::Status,
+    };
+
+    assert_impl_all!(PresenceRotation: Clone, Debug, Send, Sync);
+
+    fn presence(status: Status) -> UpdatePresencePayload {
+        UpdatePresencePayload {
+            activities: Vec::new(),
+            afk: false,
+            since: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn new() {
+        let rotation = PresenceRotation::new(
+            [presence(Status::Online), presence(Status::Idle)],
+            Duration::from_secs(60),
+        );
+        assert_eq!(Duration::from_secs(60), rotation.interval());
+        assert_eq!(2, rotation.presences().len());
+    }
+
+    #[test]
+    #[should_panic(expected = "presences must not be empty")]
+    fn empty() {
+        PresenceRotation::new([], Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,487 @@
+//! Tunneling of gateway connections through HTTP CONNECT and SOCKS5 proxies.
+
+use crate::error::{ProxyError, ProxyErrorType};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+/// Maximum length of the response to an HTTP CONNECT request.
+const HTTP_RESPONSE_LENGTH_MAX: usize = 8 * 1024;
+
+/// SOCKS protocol version.
+const SOCKS5_VERSION: u8 = 5;
+
+/// Version of the SOCKS5 username and password authentication.
+const SOCKS5_AUTH_VERSION: u8 = 1;
+
+/// Protocol of a [`Proxy`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ProxyKind {
+    /// HTTP proxy tunneling connections via `CONNECT` requests.
+    Http,
+    /// SOCKS5 proxy.
+    Socks5,
+}
+
+/// Username and password with a debug implementation that redacts the
+/// password.
+#[derive(Clone)]
+struct Credentials {
+    /// Password, redacted in the Debug implementation.
+    password: Box<str>,
+    /// Username.
+    username: Box<str>,
+}
+
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Credentials")
+            .field("password", &"<redacted>")
+            .field("username", &self.username)
+            .finish()
+    }
+}
+
+/// Proxy to tunnel gateway connections through.
+///
+/// The TLS session is still established with Discord; the proxy only relays
+/// the encrypted connection. This is unlike [`ConfigBuilder::proxy_url`],
+/// which connects to a gateway proxy speaking Discord's gateway protocol.
+///
+/// Configured via [`ConfigBuilder::connect_proxy`].
+///
+/// # Examples
+///
+/// Connect through a SOCKS5 proxy requiring authentication:
+///
+/// ```no_run
+/// use std::env;
+/// use twilight_gateway::{ConfigBuilder, Intents, Proxy};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let proxy = Proxy::socks5("proxy.internal:1080".to_owned())
+///     .credentials("twilight".to_owned(), env::var("PROXY_PASSWORD")?);
+///
+/// let config = ConfigBuilder::new(env::var("DISCORD_TOKEN")?, Intents::empty())
+///     .connect_proxy(proxy)
+///     .build();
+/// # Ok(()) }
+/// ```
+///
+/// [`ConfigBuilder::connect_proxy`]: crate::ConfigBuilder::connect_proxy
+/// [`ConfigBuilder::proxy_url`]: crate::ConfigBuilder::proxy_url
+#[derive(Clone, Debug)]
+pub struct Proxy {
+    /// Address of the proxy, as `host:port`.
+    address: Box<str>,
+    /// Credentials to authenticate with the proxy.
+    credentials: Option<Credentials>,
+    /// Protocol of the proxy.
+    kind: ProxyKind,
+}
+
+impl Proxy {
+    /// Create an HTTP proxy tunneling connections via `CONNECT` requests.
+    ///
+    /// The address is in the `host:port` form, such as `proxy.internal:3128`.
+    pub fn http(address: String) -> Self {
+        Self {
+            address: address.into_boxed_str(),
+            credentials: None,
+            kind: ProxyKind::Http,
+        }
+    }
+
+    /// Create a SOCKS5 proxy.
+    ///
+    /// The address is in the `host:port` form, such as `proxy.internal:1080`.
+    /// The proxy resolves the gateway's host.
+    pub fn socks5(address: String) -> Self {
+        Self {
+            address: address.into_boxed_str(),
+            credentials: None,
+            kind: ProxyKind::Socks5,
+        }
+    }
+
+    /// Set the username and password to authenticate with the proxy.
+    ///
+    /// HTTP proxies receive them via basic authentication and SOCKS5 proxies
+    /// via username and password authentication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the proxy is a SOCKS5 proxy and the username or password is
+    /// longer than 255 bytes.
+    #[must_use]
+    #[track_caller]
+    pub fn credentials(mut self, username: String, password: String) -> Self {
+        if self.kind == ProxyKind::Socks5 {
+            assert!(
+                username.len() <= u8::MAX.into() && password.len() <= u8::MAX.into(),
+                "SOCKS5 username and password must be at most 255 bytes long"
+            );
+        }
+
+        self.credentials = Some(Credentials {
+            password: password.into_boxed_str(),
+            username: username.into_boxed_str(),
+        });
+
+        self
+    }
+
+    /// Immutable reference to the address of the proxy.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Protocol of the proxy.
+    pub const fn kind(&self) -> ProxyKind {
+        self.kind
+    }
+
+    /// Connect to the proxy and open a tunnel to a host.
+    pub(crate) async fn connect(&self, host: &str, port: u16) -> Result<TcpStream, ProxyError> {
+        let mut stream = TcpStream::connect(&*self.address)
+            .await
+            .map_err(ProxyError::from_io)?;
+
+        match self.kind {
+            ProxyKind::Http => self.connect_http(&mut stream, host, port).await?,
+            ProxyKind::Socks5 => self.connect_socks5(&mut stream, host, port).await?,
+        }
+
+        Ok(stream)
+    }
+
+    /// Open a tunnel via an HTTP `CONNECT` request.
+    async fn connect_http(
+        &self,
+        stream: &mut TcpStream,
+        host: &str,
+        port: u16,
+    ) -> Result<(), ProxyError> {
+        let authority = if host.contains(':') {
+            format!("[{host}]:{port}")
+        } else {
+            format!("{host}:{port}")
+        };
+        let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+        if let Some(credentials) = &self.credentials {
+            let basic = format!("{}:{}", credentials.username, credentials.password);
+            request.push_str("Proxy-Authorization: Basic ");
+            request.push_str(&STANDARD.encode(basic));
+            request.push_str("\r\n");
+        }
+        request.push_str("\r\n");
+
+        stream
+            .write_all(request.as_bytes())
+            .await
+            .map_err(ProxyError::from_io)?;
+
+        // Read byte by byte to not consume any data sent through the tunnel.
+        let mut response = Vec::new();
+        while !response.ends_with(b"\r\n\r\n") {
+            if response.len() >= HTTP_RESPONSE_LENGTH_MAX {
+                return Err(ProxyError::invalid_response());
+            }
+
+            response.push(stream.read_u8().await.map_err(ProxyError::from_io)?);
+        }
+
+        let status = response
+            .split(|&byte| byte == b' ')
+            .nth(1)
+            .and_then(|status| std::str::from_utf8(status).ok())
+            .and_then(|status| status.parse::<u16>().ok())
+            .filter(|_| response.starts_with(b"HTTP/1."))
+            .ok_or_else(ProxyError::invalid_response)?;
+
+        if !(200..300).contains(&status) {
+            return Err(ProxyError {
+                kind: ProxyErrorType::Rejected { code: status },
+                source: None,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Open a tunnel via a SOCKS5 `CONNECT` command.
+    async fn connect_socks5(
+        &self,
+        stream: &mut TcpStream,
+        host: &str,
+        port: u16,
+    ) -> Result<(), ProxyError> {
+        /// No authentication method.
+        const NO_AUTH: u8 = 0;
+        /// Username and password authentication method.
+        const USERNAME_PASSWORD: u8 = 2;
+        /// No acceptable authentication method.
+        const NO_ACCEPTABLE: u8 = 0xFF;
+
+        let greeting: &[u8] = if self.credentials.is_some() {
+            &[SOCKS5_VERSION, 2, NO_AUTH, USERNAME_PASSWORD]
+        } else {
+            &[SOCKS5_VERSION, 1, NO_AUTH]
+        };
+        stream
+            .write_all(greeting)
+            .await
+            .map_err(ProxyError::from_io)?;
+
+        let mut reply = [0; 2];
+        stream
+            .read_exact(&mut reply)
+            .await
+            .map_err(ProxyError::from_io)?;
+
+        match (reply, &self.credentials) {
+            ([SOCKS5_VERSION, NO_AUTH], _) => {}
+            ([SOCKS5_VERSION, USERNAME_PASSWORD], Some(credentials)) => {
+                let mut request = vec![SOCKS5_AUTH_VERSION];
+                for field in [&credentials.username, &credentials.password] {
+                    request.push(field.len().try_into().expect("length checked"));
+                    request.extend_from_slice(field.as_bytes());
+                }
+                stream
+                    .write_all(&request)
+                    .await
+                    .map_err(ProxyError::from_io)?;
+
+                stream
+                    .read_exact(&mut reply)
+                    .await
+                    .map_err(ProxyError::from_io)?;
+                if reply[1] != 0 {
+                    return Err(ProxyError::authentication());
+                }
+            }
+            ([SOCKS5_VERSION, NO_ACCEPTABLE], _) => return Err(ProxyError::authentication()),
+            _ => return Err(ProxyError::invalid_response()),
+        }
+
+        let host_length = u8::try_from(host.len()).map_err(|_| ProxyError::invalid_response())?;
+        let mut request = vec![SOCKS5_VERSION, 1, 0, 3, host_length];
+        request.extend_from_slice(host.as_bytes());
+        request.extend_from_slice(&port.to_be_bytes());
+        stream
+            .write_all(&request)
+            .await
+            .map_err(ProxyError::from_io)?;
+
+        let mut reply = [0; 4];
+        stream
+            .read_exact(&mut reply)
+            .await
+            .map_err(ProxyError::from_io)?;
+        let [SOCKS5_VERSION, code, _, address_type] = reply else {
+            return Err(ProxyError::invalid_response());
+        };
+        if code != 0 {
+            return Err(ProxyError {
+                kind: ProxyErrorType::Rejected { code: code.into() },
+                source: None,
+            });
+        }
+
+        // Skip the bound address and port.
+        let address_length = match address_type {
+            1 => 4,
+            3 => stream.read_u8().await.map_err(ProxyError::from_io)?.into(),
+            4 => 16,
+            _ => return Err(ProxyError::invalid_response()),
+        };
+        let mut bound = vec![0; address_length + 2];
+        stream
+            .read_exact(&mut bound)
+            .await
+            .map_err(ProxyError::from_io)?;
+
+        Ok(())
+    }
+}
+
+/// Host, port and whether TLS is used of a WebSocket URL.
+pub(crate) fn target(url: &str) -> Option<(&str, u16, bool)> {
+    let (rest, port, tls) = if let Some(rest) = url.strip_prefix("wss://") {
+        (rest, 443, true)
+    } else {
+        (url.strip_prefix("ws://")?, 80, false)
+    };
+    let authority = rest.split(['/', '?']).next()?;
+
+    let (host, port) = if let Some(rest) = authority.strip_prefix('[') {
+        let (host, rest) = rest.split_once(']')?;
+
+        match rest.strip_prefix(':') {
+            Some(port) => (host, port.parse().ok()?),
+            None => (host, port),
+        }
+    } else {
+        match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().ok()?),
+            None => (authority, port),
+        }
+    };
+
+    Some((host, port, tls))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Proxy, ProxyKind};
+    use crate::error::ProxyErrorType;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    assert_impl_all!(Proxy: Clone, Debug, Send, Sync);
+    assert_impl_all!(ProxyKind: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    /// Bind a local listener, returning it and its address.
+    async fn listener() -> (TcpListener, String) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        (listener, address)
+    }
+
+    /// Read exactly a number of bytes.
+    async fn read(stream: &mut TcpStream, length: usize) -> Vec<u8> {
+        let mut buf = vec![0; length];
+        stream.read_exact(&mut buf).await.unwrap();
+
+        buf
+    }
+
+    #[test]
+    fn credentials_debug() {
+        let proxy = Proxy::http("localhost:3128".to_owned())
+            .credentials("user".to_owned(), "hunter2".to_owned());
+
+        assert!(!format!("{proxy:?}").contains("hunter2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 255 bytes")]
+    fn socks5_credentials_length() {
+        _ = Proxy::socks5("localhost:1080".to_owned()).credentials("a".repeat(256), String::new());
+    }
+
+    #[test]
+    fn target() {
+        assert_eq!(
+            Some(("gateway.discord.gg", 443, true)),
+            super::target("wss://gateway.discord.gg/?v=10&encoding=json")
+        );
+        assert_eq!(
+            Some(("localhost", 8080, false)),
+            super::target("ws://localhost:8080?v=10")
+        );
+        assert_eq!(Some(("::1", 80, false)), super::target("ws://[::1]/"));
+        assert_eq!(None, super::target("https://gateway.discord.gg"));
+    }
+
+    #[tokio::test]
+    async fn http() {
+        let (listener, address) = listener().await;
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let expected = b"CONNECT gateway.discord.gg:443 HTTP/1.1\r\n\
+                Host: gateway.discord.gg:443\r\n\
+                Proxy-Authorization: Basic dXNlcjpwYXNz\r\n\r\n";
+            assert_eq!(&expected[..], read(&mut stream, expected.len()).await);
+
+            stream
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\ntunneled")
+                .await
+                .unwrap();
+        });
+
+        let proxy = Proxy::http(address).credentials("user".to_owned(), "pass".to_owned());
+        let mut stream = proxy.connect("gateway.discord.gg", 443).await.unwrap();
+
+        assert_eq!(b"tunneled", &read(&mut stream, 8).await[..]);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn http_rejected() {
+        let (listener, address) = listener().await;
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let error = Proxy::http(address)
+            .connect("gateway.discord.gg", 443)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ProxyErrorType::Rejected { code: 407 }
+        ));
+    }
+
+    #[tokio::test]
+    async fn socks5() {
+        let (listener, address) = listener().await;
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            assert_eq!(vec![5, 2, 0, 2], read(&mut stream, 4).await);
+            stream.write_all(&[5, 2]).await.unwrap();
+
+            assert_eq!(b"\x01\x04user\x04pass", &read(&mut stream, 11).await[..]);
+            stream.write_all(&[1, 0]).await.unwrap();
+
+            let mut expected = vec![5, 1, 0, 3, 18];
+            expected.extend_from_slice(b"gateway.discord.gg");
+            expected.extend_from_slice(&443_u16.to_be_bytes());
+            assert_eq!(expected, read(&mut stream, expected.len()).await);
+
+            stream
+                .write_all(&[5, 0, 0, 1, 127, 0, 0, 1, 0, 80])
+                .await
+                .unwrap();
+            stream.write_all(b"tunneled").await.unwrap();
+        });
+
+        let proxy = Proxy::socks5(address).credentials("user".to_owned(), "pass".to_owned());
+        let mut stream = proxy.connect("gateway.discord.gg", 443).await.unwrap();
+
+        assert_eq!(b"tunneled", &read(&mut stream, 8).await[..]);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn socks5_authentication() {
+        let (listener, address) = listener().await;
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            read(&mut stream, 3).await;
+            stream.write_all(&[5, 0xFF]).await.unwrap();
+        });
+
+        let error = Proxy::socks5(address)
+            .connect("gateway.discord.gg", 443)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ProxyErrorType::Authentication));
+    }
+}
@@ -9,6 +9,15 @@
 //! the (t - [`PERIOD`], t] window is never exceeded. See
 //! <https://hechao.li/2018/06/25/Rate-Limiter-Part1> for an overview of it and
 //! other alternative algorithms.
+//!
+//! # Priorities
+//!
+//! Heartbeats bypass the ratelimiter with reserved capacity, identifies and
+//! resumes are sent before any queued user commands, and user commands are
+//! sent in order of their [`Priority`]. [`Priority::Low`] commands are only
+//! sent while more than [`LOW_PRIORITY_RESERVED`] permits are available,
+//! keeping them from exhausting the capacity needed by [`Priority::Normal`]
+//! commands.
 
 use std::{
     future::Future,
@@ -20,9 +29,29 @@ use tokio::time::{sleep_until, Duration, Instant, Sleep};
 /// Number of commands allowed in a [`PERIOD`].
 const COMMANDS_PER_PERIOD: u8 = 120;
 
+/// Number of permits [`Priority::Low`] commands may not use.
+const LOW_PRIORITY_RESERVED: u8 = 20;
+
 /// Gateway ratelimiter period duration.
 const PERIOD: Duration = Duration::from_secs(60);
 
+/// Priority of a command sent over the gateway.
+///
+/// Refer to the [module-level documentation] for how commands are
+/// prioritized.
+///
+/// [module-level documentation]: self#priorities
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Priority {
+    /// Commands which may be delayed while the ratelimiter is almost
+    /// exhausted, such as presence updates and member chunk requests.
+    Low,
+    /// Commands sent as soon as the ratelimiter allows.
+    #[default]
+    Normal,
+}
+
 /// Ratelimiter for sending commands over the gateway to Discord.
 #[derive(Debug)]
 pub struct CommandRatelimiter {
@@ -30,6 +59,9 @@ pub struct CommandRatelimiter {
     delay: Pin<Box<Sleep>>,
     /// Ordered queue of instants when a permit elapses.
     instants: Vec<Instant>,
+    /// Future that completes the next time the ratelimiter allows a
+    /// [`Priority::Low`] permit.
+    low_priority_delay: Pin<Box<Sleep>>,
 }
 
 impl CommandRatelimiter {
@@ -39,13 +71,16 @@ impl CommandRatelimiter {
 
         let now = Instant::now();
         let mut delay = Box::pin(sleep_until(now));
+        let mut low_priority_delay = Box::pin(sleep_until(now));
 
-        // Hack to register the timer.
+        // Hack to register the timers.
         delay.as_mut().reset(now);
+        low_priority_delay.as_mut().reset(now);
 
         Self {
             delay,
             instants: Vec::with_capacity(allotted.into()),
+            low_priority_delay,
         }
     }
 
@@ -59,6 +94,14 @@ impl CommandRatelimiter {
         self.max() - used_permits as u8
     }
 
+    /// Number of available permits for commands of the priority.
+    pub fn available_for(&self, priority: Priority) -> u8 {
+        match priority {
+            Priority::Low => self.available().saturating_sub(LOW_PRIORITY_RESERVED),
+            Priority::Normal => self.available(),
+        }
+    }
+
     /// Maximum number of available permits.
     #[allow(clippy::cast_possible_truncation)]
     pub fn max(&self) -> u8 {
@@ -72,6 +115,19 @@ impl CommandRatelimiter {
         })
     }
 
+    /// Duration until all used permits are available again.
+    ///
+    /// This is when [`available`] resets to [`max`] if no further commands
+    /// are sent.
+    ///
+    /// [`available`]: Self::available
+    /// [`max`]: Self::max
+    pub fn reset_after(&self) -> Duration {
+        self.instants.last().map_or(Duration::ZERO, |elapsed| {
+            elapsed.saturating_duration_since(Instant::now())
+        })
+    }
+
     /// Polls for a permit.
     ///
     /// # Return value
@@ -122,6 +178,43 @@ impl CommandRatelimiter {
             Poll::Ready(())
         }
     }
+
+    /// Polls for readiness for commands of the priority.
+    ///
+    /// # Return value
+    ///
+    /// The function returns:
+    ///
+    /// * `Poll::Pending` if the ratelimiter has no spare capacity for the
+    ///   priority
+    /// * `Poll::Ready` if the ratelimiter has spare capacity for the priority.
+    pub(crate) fn poll_ready_for(&mut self, priority: Priority, cx: &mut Context<'_>) -> Poll<()> {
+        ready!(self.poll_ready(cx));
+
+        if priority == Priority::Normal {
+            return Poll::Ready(());
+        }
+
+        let now = Instant::now();
+        let elapsed_permits = self.instants.partition_point(|&elapsed| elapsed <= now);
+        let used_permits = self.instants.len() - elapsed_permits;
+        // Wait for all permits to be available if none are left after
+        // reserving capacity, the deadline otherwise not being in `instants`.
+        let allowed_permits = usize::from(self.max().saturating_sub(LOW_PRIORITY_RESERVED)).max(1);
+
+        if used_permits < allowed_permits {
+            return Poll::Ready(());
+        }
+
+        let new_deadline = self.instants[elapsed_permits + used_permits - allowed_permits];
+        if self.low_priority_delay.deadline() != new_deadline {
+            tracing::debug!(duration = ?(new_deadline - now), "low priority ratelimited");
+            self.low_priority_delay.as_mut().reset(new_deadline);
+        }
+        _ = self.low_priority_delay.as_mut().poll(cx);
+
+        Poll::Pending
+    }
 }
 
 /// Calculates the number of non reserved commands for heartbeating (which
@@ -156,12 +249,15 @@ fn nonreserved_commands_per_reset(heartbeat_interval: Duration) -> u8 {
 
 #[cfg(test)]
 mod tests {
-    use super::{nonreserved_commands_per_reset, CommandRatelimiter, PERIOD};
+    use super::{
+        nonreserved_commands_per_reset, CommandRatelimiter, Priority, LOW_PRIORITY_RESERVED, PERIOD,
+    };
     use static_assertions::assert_impl_all;
     use std::{fmt::Debug, future::poll_fn, task::Poll, time::Duration};
     use tokio::time;
 
     assert_impl_all!(CommandRatelimiter: Debug, Send, Sync);
+    assert_impl_all!(Priority: Clone, Copy, Debug, Default, Eq, Send, Sync);
 
     #[test]
     fn nonreserved_commands() {
@@ -203,6 +299,22 @@ mod tests {
         assert_eq!(ratelimiter.available(), ratelimiter.max());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn reset_after() {
+        let mut ratelimiter = CommandRatelimiter::new(HEARTBEAT_INTERVAL);
+        assert_eq!(ratelimiter.reset_after(), Duration::ZERO);
+
+        poll_fn(|cx| ratelimiter.poll_acquire(cx)).await;
+        time::advance(PERIOD / 2).await;
+        poll_fn(|cx| ratelimiter.poll_acquire(cx)).await;
+        assert_eq!(ratelimiter.next_available(), PERIOD / 2);
+        assert_eq!(ratelimiter.reset_after(), PERIOD);
+
+        time::advance(PERIOD).await;
+        assert_eq!(ratelimiter.reset_after(), Duration::ZERO);
+        assert_eq!(ratelimiter.available(), ratelimiter.max());
+    }
+
     #[tokio::test(start_paused = true)]
     async fn half_reset() {
         let mut ratelimiter = CommandRatelimiter::new(HEARTBEAT_INTERVAL);
@@ -266,4 +378,55 @@ mod tests {
         })
         .await;
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn low_priority() {
+        let mut ratelimiter = CommandRatelimiter::new(HEARTBEAT_INTERVAL);
+        let allowed = ratelimiter.max() - LOW_PRIORITY_RESERVED;
+
+        assert_eq!(allowed, ratelimiter.available_for(Priority::Low));
+        for _ in 0..allowed {
+            poll_fn(|cx| ratelimiter.poll_ready_for(Priority::Low, cx)).await;
+            poll_fn(|cx| ratelimiter.poll_acquire(cx)).await;
+        }
+        assert_eq!(0, ratelimiter.available_for(Priority::Low));
+        assert_eq!(
+            LOW_PRIORITY_RESERVED,
+            ratelimiter.available_for(Priority::Normal)
+        );
+
+        poll_fn(|cx| {
+            assert!(ratelimiter.poll_ready_for(Priority::Low, cx).is_pending());
+            assert!(ratelimiter.poll_ready_for(Priority::Normal, cx).is_ready());
+            Poll::Ready(())
+        })
+        .await;
+
+        // Waits until the first permit elapses.
+        let start = time::Instant::now();
+        poll_fn(|cx| ratelimiter.poll_ready_for(Priority::Low, cx)).await;
+        assert_eq!(PERIOD, start.elapsed());
+        assert_eq!(allowed, ratelimiter.available_for(Priority::Low));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn low_priority_no_spare_capacity() {
+        let now = time::Instant::now();
+        let mut ratelimiter = CommandRatelimiter {
+            delay: Box::pin(time::sleep_until(now)),
+            instants: Vec::with_capacity(LOW_PRIORITY_RESERVED.into()),
+            low_priority_delay: Box::pin(time::sleep_until(now)),
+        };
+        assert!(ratelimiter.max() <= LOW_PRIORITY_RESERVED);
+
+        poll_fn(|cx| ratelimiter.poll_ready_for(Priority::Low, cx)).await;
+        poll_fn(|cx| ratelimiter.poll_acquire(cx)).await;
+        time::advance(Duration::from_secs(1)).await;
+        poll_fn(|cx| ratelimiter.poll_acquire(cx)).await;
+
+        // Waits until all permits elapse.
+        let start = time::Instant::now();
+        poll_fn(|cx| ratelimiter.poll_ready_for(Priority::Low, cx)).await;
+        assert_eq!(PERIOD, start.elapsed());
+    }
 }
@@ -18,6 +18,7 @@ use std::mem;
 /// application's process needs to be restarted, then this session
 /// information—which can be (de)serialized via serde—can be stored, the
 /// application restarted, and then used again via [`ConfigBuilder::session`].
+/// [`ResumeState`] additionally stores the URL to resume the session at.
 ///
 /// If the delay between disconnecting from the gateway and reconnecting isn't
 /// too long and Discord hasn't invalidated the session, then the session will
@@ -91,14 +92,105 @@ impl Session {
     }
 }
 
+/// State required to resume a shard's gateway session.
+///
+/// Combines the [`Session`] with the URL to resume it at. It can be
+/// (de)serialized via serde, making it possible to persist the state of every
+/// shard before shutting down and resume the sessions after restarting via
+/// [`Shard::with_resume_state`], without re-identifying every shard.
+///
+/// Shards must be closed with [`CloseFrame::RESUME`] to keep their sessions
+/// resumable; closing them with [`CloseFrame::NORMAL`] invalidates them.
+///
+/// # Examples
+///
+/// Persist the resume state of a shard before shutting down, and resume its
+/// session after restarting:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{env, fs};
+/// use tokio_stream::StreamExt;
+/// use twilight_gateway::{CloseFrame, Config, Intents, Message, ResumeState, Shard, ShardId};
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// # let mut shard = Shard::new(ShardId::ONE, token.clone(), Intents::empty());
+///
+/// shard.close(CloseFrame::RESUME);
+/// while let Some(item) = shard.next().await {
+///     if let Ok(Message::Close(_)) = item {
+///         break;
+///     }
+/// }
+///
+/// if let Some(state) = shard.resume_state() {
+///     fs::write("state.json", serde_json::to_vec(&state)?)?;
+/// }
+///
+/// // After restarting.
+/// let state: ResumeState = serde_json::from_slice(&fs::read("state.json")?)?;
+/// let config = Config::new(token, Intents::empty());
+/// let shard = Shard::with_resume_state(ShardId::ONE, config, state);
+/// # Ok(()) }
+/// ```
+///
+/// [`CloseFrame::NORMAL`]: crate::CloseFrame::NORMAL
+/// [`CloseFrame::RESUME`]: crate::CloseFrame::RESUME
+/// [`Shard::with_resume_state`]: crate::Shard::with_resume_state
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ResumeState {
+    /// URL to resume the session at.
+    ///
+    /// The default gateway URL is used if not present.
+    resume_url: Option<Box<str>>,
+    /// Gateway session to resume.
+    session: Session,
+}
+
+impl ResumeState {
+    /// Create a new resume state from a session and the URL to resume it at.
+    pub fn new(session: Session, resume_url: Option<String>) -> Self {
+        Self {
+            resume_url: resume_url.map(String::into_boxed_str),
+            session,
+        }
+    }
+
+    /// Consume the resume state, returning its session and resume URL.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_parts(self) -> (Session, Option<Box<str>>) {
+        (self.session, self.resume_url)
+    }
+
+    /// URL to resume the session at.
+    pub fn resume_url(&self) -> Option<&str> {
+        self.resume_url.as_deref()
+    }
+
+    /// Gateway session to resume.
+    pub const fn session(&self) -> &Session {
+        &self.session
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Session;
+    use super::{ResumeState, Session};
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
 
+    assert_impl_all!(
+        ResumeState: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
     assert_impl_all!(
         Session: Clone,
         Debug,
@@ -134,6 +226,41 @@ mod tests {
         );
     }
 
+    /// Test that resume states deserialize and serialize the same way.
+    #[test]
+    fn resume_state_serde() {
+        const RESUME_URL: &str = "wss://gateway-us-east1-b.discord.gg";
+
+        let value = ResumeState::new(
+            Session::new(1, "id".to_owned()),
+            Some(RESUME_URL.to_owned()),
+        );
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ResumeState",
+                    len: 2,
+                },
+                Token::Str("resume_url"),
+                Token::Some,
+                Token::Str(RESUME_URL),
+                Token::Str("session"),
+                Token::Struct {
+                    name: "Session",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::Str("id"),
+                Token::Str("sequence"),
+                Token::U64(1),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
     /// Test that session getters return the provided values.
     #[test]
     fn session() {
@@ -0,0 +1,375 @@
+//! Local tracking of the daily number of sessions shards may start.
+
+use crate::{
+    error::{SessionBudgetError, SessionBudgetErrorType, StartRecommendedError},
+    queue::Queue,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{sync::oneshot, time::Instant};
+use twilight_http::Client;
+use twilight_model::gateway::connection_info::BotConnectionInfo;
+
+/// Duration after which a reset session start limit resets again.
+const RESET_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Cached gateway information and the locally tracked session start limit.
+#[derive(Debug)]
+struct Budget {
+    /// When the gateway information was fetched.
+    fetched_at: Instant,
+    /// Gateway information as fetched from Discord.
+    info: BotConnectionInfo,
+    /// Number of sessions that may still be started.
+    remaining: u32,
+    /// When the session start limit resets.
+    reset_at: Instant,
+}
+
+impl Budget {
+    /// Create a budget from gateway information fetched at an instant.
+    fn new(info: BotConnectionInfo, now: Instant) -> Self {
+        let limit = &info.session_start_limit;
+
+        Self {
+            fetched_at: now,
+            remaining: limit.remaining,
+            reset_at: now + Duration::from_millis(limit.reset_after),
+            info,
+        }
+    }
+
+    /// Reset the remaining sessions if the limit has reset at an instant.
+    ///
+    /// The next reset is estimated, as Discord only starts the next period
+    /// once a session is started.
+    fn roll(&mut self, now: Instant) {
+        if now >= self.reset_at {
+            self.remaining = self.info.session_start_limit.total;
+            self.reset_at = now + RESET_INTERVAL;
+        }
+    }
+}
+
+/// Cache of the [`GetGatewayAuthed`] response tracking the remaining number of
+/// sessions shards may start.
+///
+/// Discord limits the number of times shards may identify per day; once the
+/// limit is exhausted, the bot's token is reset. The budget counts identifies
+/// locally, either via [`record_identify`] or by wrapping a shard's queue via
+/// [`queue`], and [`check`] errors if a planned start of shards would
+/// exhaust the limit.
+///
+/// Gateway information is fetched again once the cache has expired, which
+/// also accounts for sessions started by other processes.
+///
+/// # Examples
+///
+/// Check that there are enough sessions left to start the recommended number
+/// of shards:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{env, time::Duration};
+/// use twilight_gateway::{queue::InMemoryQueue, ConfigBuilder, Intents, SessionBudget};
+/// use twilight_http::Client;
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let client = Client::new(token.clone());
+///
+/// let budget = SessionBudget::new(Duration::from_secs(60 * 60));
+/// let info = budget.info(&client).await?;
+/// budget.check(info.shards)?;
+///
+/// let queue = budget.queue(InMemoryQueue::default());
+/// let config = ConfigBuilder::new(token, Intents::GUILDS)
+///     .queue(queue)
+///     .build();
+/// let shards =
+///     twilight_gateway::create_iterator(0..info.shards, info.shards, config, |_, builder| {
+///         builder.build()
+///     });
+/// # Ok(()) }
+/// ```
+///
+/// [`GetGatewayAuthed`]: twilight_http::request::GetGatewayAuthed
+/// [`check`]: Self::check
+/// [`queue`]: Self::queue
+/// [`record_identify`]: Self::record_identify
+#[derive(Clone, Debug)]
+pub struct SessionBudget {
+    /// Budget, if the gateway information was fetched.
+    budget: Arc<Mutex<Option<Budget>>>,
+    /// Duration to cache the gateway information for.
+    ttl: Duration,
+}
+
+impl SessionBudget {
+    /// Create a budget caching the gateway information for a duration.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            budget: Arc::default(),
+            ttl,
+        }
+    }
+
+    /// Gateway information with the locally tracked session start limit,
+    /// fetching it from Discord if it isn't cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StartRecommendedErrorType::Deserializing`] error type if
+    /// the response body failed to deserialize.
+    ///
+    /// Returns a [`StartRecommendedErrorType::Request`] error type if the
+    /// request failed to complete.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    ///
+    /// [`StartRecommendedErrorType::Deserializing`]: crate::error::StartRecommendedErrorType::Deserializing
+    /// [`StartRecommendedErrorType::Request`]: crate::error::StartRecommendedErrorType::Request
+    pub async fn info(&self, client: &Client) -> Result<BotConnectionInfo, StartRecommendedError> {
+        if let Some(info) = self.cached(Instant::now()) {
+            return Ok(info);
+        }
+
+        let info = crate::gateway_info(client).await?;
+        let now = Instant::now();
+
+        *self.budget.lock().expect("budget poisoned") = Some(Budget::new(info, now));
+
+        Ok(self.cached(now).expect("just fetched"))
+    }
+
+    /// Number of sessions that may still be started, if the gateway
+    /// information was fetched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    pub fn remaining(&self) -> Option<u32> {
+        let now = Instant::now();
+        let mut budget = self.budget.lock().expect("budget poisoned");
+        let budget = budget.as_mut()?;
+        budget.roll(now);
+
+        Some(budget.remaining)
+    }
+
+    /// Count a session started by a shard identifying.
+    ///
+    /// This is called by queues created via [`queue`].
+    ///
+    /// [`queue`]: Self::queue
+    pub fn record_identify(&self) {
+        self.record_identify_at(Instant::now());
+    }
+
+    /// Ensure that starting a number of shards doesn't exhaust the session
+    /// start limit.
+    ///
+    /// A warning is logged if there won't be enough sessions left afterwards
+    /// to restart the recommended number of shards.
+    ///
+    /// Does nothing if the gateway information wasn't fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SessionBudgetErrorType::Exhausted`] error type if fewer
+    /// sessions than shards may be started.
+    pub fn check(&self, shards: u32) -> Result<(), SessionBudgetError> {
+        self.check_at(shards, Instant::now())
+    }
+
+    /// Wrap a queue to count the sessions started by shards identifying
+    /// through it.
+    pub fn queue<Q: Queue>(&self, queue: Q) -> SessionBudgetQueue<Q> {
+        SessionBudgetQueue {
+            budget: self.clone(),
+            queue,
+        }
+    }
+
+    /// Gateway information with the locally tracked session start limit if
+    /// it's cached at an instant.
+    fn cached(&self, now: Instant) -> Option<BotConnectionInfo> {
+        let mut budget = self.budget.lock().expect("budget poisoned");
+        let budget = budget
+            .as_mut()
+            .filter(|budget| now.saturating_duration_since(budget.fetched_at) < self.ttl)?;
+        budget.roll(now);
+
+        let mut info = budget.info.clone();
+        info.session_start_limit.remaining = budget.remaining;
+        info.session_start_limit.reset_after = budget
+            .reset_at
+            .saturating_duration_since(now)
+            .as_millis()
+            .try_into()
+            .unwrap_or(u64::MAX);
+
+        Some(info)
+    }
+
+    /// Count a session started at an instant.
+    fn record_identify_at(&self, now: Instant) {
+        if let Some(budget) = self.budget.lock().expect("budget poisoned").as_mut() {
+            budget.roll(now);
+            budget.remaining = budget.remaining.saturating_sub(1);
+        }
+    }
+
+    /// Ensure that starting a number of shards at an instant doesn't exhaust
+    /// the session start limit.
+    fn check_at(&self, shards: u32, now: Instant) -> Result<(), SessionBudgetError> {
+        let mut budget = self.budget.lock().expect("budget poisoned");
+        let Some(budget) = budget.as_mut() else {
+            return Ok(());
+        };
+        budget.roll(now);
+
+        let Some(left) = budget.remaining.checked_sub(shards) else {
+            return Err(SessionBudgetError {
+                kind: SessionBudgetErrorType::Exhausted {
+                    remaining: budget.remaining,
+                    requested: shards,
+                    reset_after: budget.reset_at.saturating_duration_since(now),
+                },
+                source: None,
+            });
+        };
+
+        if left < budget.info.shards {
+            tracing::warn!(
+                remaining = left,
+                recommended_shards = budget.info.shards,
+                "not enough sessions left to restart all shards"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Queue counting the sessions started by shards in a [`SessionBudget`].
+///
+/// Created via [`SessionBudget::queue`].
+#[derive(Clone, Debug)]
+pub struct SessionBudgetQueue<Q> {
+    /// Budget to count sessions in.
+    budget: SessionBudget,
+    /// Wrapped queue.
+    queue: Q,
+}
+
+impl<Q: Queue> Queue for SessionBudgetQueue<Q> {
+    fn enqueue(&self, id: u32) -> oneshot::Receiver<()> {
+        self.budget.record_identify();
+
+        self.queue.enqueue(id)
+    }
+
+    fn update(&self, max_concurrency: u16, remaining: u32, reset_after: Duration, total: u32) {
+        self.queue
+            .update(max_concurrency, remaining, reset_after, total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Budget, SessionBudget, SessionBudgetQueue};
+    use crate::{
+        error::SessionBudgetErrorType,
+        queue::{InMemoryQueue, Queue},
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+    use tokio::time::Instant;
+    use twilight_model::gateway::{connection_info::BotConnectionInfo, SessionStartLimit};
+
+    assert_impl_all!(SessionBudget: Clone, Debug, Send, Sync);
+    assert_impl_all!(SessionBudgetQueue<InMemoryQueue>: Clone, Debug, Queue, Send, Sync);
+
+    const RESET_AFTER: Duration = Duration::from_secs(60);
+    const TTL: Duration = Duration::from_secs(10);
+
+    fn budget(now: Instant) -> SessionBudget {
+        let info = BotConnectionInfo {
+            session_start_limit: SessionStartLimit {
+                max_concurrency: 1,
+                remaining: 5,
+                reset_after: u64::try_from(RESET_AFTER.as_millis()).unwrap(),
+                total: 1000,
+            },
+            shards: 2,
+            url: "wss://gateway.discord.gg".to_owned(),
+        };
+
+        let budget = SessionBudget::new(TTL);
+        *budget.budget.lock().unwrap() = Some(Budget::new(info, now));
+
+        budget
+    }
+
+    #[test]
+    fn unfetched() {
+        let budget = SessionBudget::new(TTL);
+        budget.record_identify();
+
+        assert_eq!(None, budget.remaining());
+        assert!(budget.check(u32::MAX).is_ok());
+    }
+
+    #[test]
+    fn cached() {
+        let now = Instant::now();
+        let budget = budget(now);
+        budget.record_identify_at(now);
+
+        let info = budget.cached(now + Duration::from_secs(1)).unwrap();
+        assert_eq!(4, info.session_start_limit.remaining);
+        assert_eq!(59_000, info.session_start_limit.reset_after);
+
+        assert!(budget.cached(now + TTL).is_none());
+    }
+
+    #[test]
+    fn check() {
+        let now = Instant::now();
+        let budget = budget(now);
+
+        assert!(budget.check_at(5, now).is_ok());
+
+        for _ in 0..4 {
+            budget.record_identify_at(now);
+        }
+
+        let error = budget.check_at(2, now).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            SessionBudgetErrorType::Exhausted {
+                remaining: 1,
+                requested: 2,
+                reset_after: RESET_AFTER,
+            }
+        ));
+
+        // The limit resets to the total after the reset.
+        assert!(budget.check_at(2, now + RESET_AFTER).is_ok());
+        assert_eq!(Some(1000), budget.remaining());
+    }
+
+    #[tokio::test]
+    async fn queue() {
+        let budget = budget(Instant::now());
+        let queue = budget.queue(InMemoryQueue::default());
+
+        queue.enqueue(0).await.unwrap();
+
+        assert_eq!(Some(4), budget.remaining());
+    }
+}
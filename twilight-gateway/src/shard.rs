@@ -6,17 +6,20 @@
 //! information about what a shard is in the context of Discord's gateway API,
 //! refer to the documentation for [`Shard`].
 
-#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
 use crate::inflater::Inflater;
 use crate::{
     channel::{MessageChannel, MessageSender},
     error::{ReceiveMessageError, ReceiveMessageErrorType},
     json,
-    latency::Latency,
+    latency::{Latency, LatencySamples},
+    payload::{LargestPayload, PayloadStats},
+    proxy,
     queue::{InMemoryQueue, Queue},
-    ratelimiter::CommandRatelimiter,
-    session::Session,
-    Command, Config, Message, ShardId, API_VERSION,
+    ratelimiter::{CommandRatelimiter, Priority},
+    session::{ResumeState, Session},
+    watchdog::Watchdog,
+    Command, Config, Message, ProxyHandling, ShardId, API_VERSION,
 };
 use futures_core::Stream;
 use futures_sink::Sink;
@@ -30,6 +33,7 @@ use serde::{de::DeserializeOwned, Deserialize};
 use std::io::ErrorKind as IoErrorKind;
 use std::{
     env::consts::OS,
+    error::Error,
     fmt,
     future::Future,
     pin::Pin,
@@ -38,38 +42,61 @@ use std::{
 };
 use tokio::{
     net::TcpStream,
-    sync::oneshot,
+    sync::{mpsc, oneshot},
     time::{self, Duration, Instant, Interval, MissedTickBehavior},
 };
-use tokio_websockets::{ClientBuilder, Error as WebsocketError, Limits, MaybeTlsStream};
-use twilight_model::gateway::{
-    event::GatewayEventDeserializer,
-    payload::{
-        incoming::Hello,
-        outgoing::{
-            identify::{IdentifyInfo, IdentifyProperties},
-            Heartbeat, Identify, Resume,
+use tokio_websockets::{ClientBuilder, Connector, Error as WebsocketError, Limits, MaybeTlsStream};
+use twilight_model::{
+    gateway::{
+        event::GatewayEventDeserializer,
+        payload::{
+            incoming::Hello,
+            outgoing::{
+                identify::{IdentifyInfo, IdentifyProperties},
+                Heartbeat, Identify, Resume, UpdatePresence,
+            },
         },
+        CloseCode, CloseFrame, Intents, OpCode,
     },
-    CloseCode, CloseFrame, Intents, OpCode,
+    id::{marker::GuildMarker, Id},
 };
 
 /// URL of the Discord gateway.
 const GATEWAY_URL: &str = "wss://gateway.discord.gg";
 
-/// Query argument with zlib-stream enabled.
-#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-const COMPRESSION_FEATURES: &str = "&compress=zlib-stream";
+/// Payload of a received gateway event.
+#[derive(Clone, Copy, Debug)]
+enum Payload<'a> {
+    /// ETF encoded event.
+    #[cfg(feature = "etf")]
+    Etf(&'a [u8]),
+    /// JSON encoded event.
+    Json(&'a str),
+}
 
-/// No query arguments due to compression being disabled.
-#[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-const COMPRESSION_FEATURES: &str = "";
+impl Payload<'_> {
+    /// Length of the payload in bytes.
+    const fn len(self) -> usize {
+        match self {
+            #[cfg(feature = "etf")]
+            Self::Etf(etf) => etf.len(),
+            Self::Json(json) => json.len(),
+        }
+    }
+}
 
 /// [`tokio_websockets`] library Websocket connection.
 type Connection = tokio_websockets::WebSocketStream<MaybeTlsStream<TcpStream>>;
 
+/// Error establishing a WebSocket connection, either a [`WebsocketError`] or,
+/// if tunneled through a [`Proxy`], a [`ProxyError`].
+///
+/// [`Proxy`]: crate::Proxy
+/// [`ProxyError`]: crate::error::ProxyError
+type ConnectionError = Box<dyn Error + Send + Sync>;
+
 /// Wrapper struct around an `async fn` with a `Debug` implementation.
-struct ConnectionFuture(Pin<Box<dyn Future<Output = Result<Connection, WebsocketError>> + Send>>);
+struct ConnectionFuture(Pin<Box<dyn Future<Output = Result<Connection, ConnectionError>> + Send>>);
 
 impl fmt::Debug for ConnectionFuture {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -173,6 +200,15 @@ struct MinimalReady {
     session_id: String,
 }
 
+/// Minimal [`GuildCreate`] for light deserialization.
+///
+/// [`GuildCreate`]: twilight_model::gateway::payload::incoming::GuildCreate
+#[derive(Deserialize)]
+struct MinimalGuildCreate {
+    /// ID of the guild.
+    id: Id<GuildMarker>,
+}
+
 /// Pending outgoing message indicator.
 #[derive(Debug)]
 struct Pending {
@@ -184,9 +220,9 @@ struct Pending {
 
 impl Pending {
     /// Constructor for a pending gateway event.
-    const fn text(json: String, is_heartbeat: bool) -> Option<Self> {
+    const fn new(message: Message, is_heartbeat: bool) -> Option<Self> {
         Some(Self {
-            gateway_event: Some(Message::Text(json)),
+            gateway_event: Some(message),
             is_heartbeat,
         })
     }
@@ -286,11 +322,19 @@ pub struct Shard<Q = InMemoryQueue> {
     id: ShardId,
     /// Identify queue receiver.
     identify_rx: Option<oneshot::Receiver<()>>,
-    /// Zlib decompressor.
-    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    /// When the identify queue was entered.
+    #[cfg(feature = "metrics")]
+    identify_queued: Option<Instant>,
+    /// Transport decompressor.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
     inflater: Inflater,
     /// Potentially pending outgoing message.
     pending: Option<Pending>,
+    /// Index of the next presence of the [`Config::presence_rotation`].
+    presence_index: usize,
+    /// Interval of the [`Config::presence_rotation`], present while
+    /// identified.
+    presence_interval: Option<Interval>,
     /// Recent heartbeat latency statistics.
     ///
     /// The latency is reset on receiving [`GatewayEvent::Hello`] as the host
@@ -298,6 +342,10 @@ pub struct Shard<Q = InMemoryQueue> {
     ///
     /// [`GatewayEvent::Hello`]: twilight_model::gateway::event::GatewayEvent::Hello
     latency: Latency,
+    /// Sending halves of [`LatencySamples`] streams.
+    latency_subscribers: Vec<mpsc::UnboundedSender<Duration>>,
+    /// Received payload statistics.
+    payload_stats: PayloadStats,
     /// Command ratelimiter, if it was enabled via
     /// [`Config::ratelimit_messages`].
     ratelimiter: Option<CommandRatelimiter>,
@@ -308,11 +356,18 @@ pub struct Shard<Q = InMemoryQueue> {
     /// The shard may not have an active session if it hasn't yet identified and
     /// received a `READY` dispatch event response.
     session: Option<Session>,
+    /// Span of the current connection, covering its identify or resume.
+    ///
+    /// Replaced when reconnecting, parenting the spans of polling the shard.
+    span: tracing::Span,
     /// Current state of the shard.
     state: ShardState,
     /// Messages from the user to be relayed and sent over the Websocket
     /// connection.
     user_channel: MessageChannel,
+    /// Watchdog detecting a stall, present while active if it was enabled
+    /// via [`Config::watchdog`].
+    watchdog: Option<Watchdog>,
 }
 
 impl Shard {
@@ -331,6 +386,10 @@ impl<Q> Shard<Q> {
         if session.is_none() {
             resume_url = None;
         }
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+        let compression = config.compression();
+
+        let user_channel = MessageChannel::new(config.encoding());
 
         Self {
             config,
@@ -340,20 +399,45 @@ impl<Q> Shard<Q> {
             heartbeat_interval_event: false,
             id: shard_id,
             identify_rx: None,
-            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-            inflater: Inflater::new(),
+            #[cfg(feature = "metrics")]
+            identify_queued: None,
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
+            inflater: Inflater::new(compression),
             pending: None,
+            presence_index: 0,
+            presence_interval: None,
             latency: Latency::new(),
+            latency_subscribers: Vec::new(),
+            payload_stats: PayloadStats::new(),
             ratelimiter: None,
             resume_url,
             session,
+            span: tracing::Span::none(),
             state: ShardState::Disconnected {
                 reconnect_attempts: 0,
             },
-            user_channel: MessageChannel::new(),
+            user_channel,
+            watchdog: None,
         }
     }
 
+    /// Create a new shard resuming a persisted gateway session.
+    ///
+    /// Overrides the [session] and [resume URL] of the configuration. Refer to
+    /// [`ResumeState`] for how to persist it.
+    ///
+    /// [resume URL]: crate::ConfigBuilder::resume_url
+    /// [session]: crate::ConfigBuilder::session
+    pub fn with_resume_state(shard_id: ShardId, config: Config<Q>, state: ResumeState) -> Self {
+        let (session, resume_url) = state.into_parts();
+
+        let mut shard = Self::with_config(shard_id, config);
+        shard.resume_url = resume_url;
+        shard.session = Some(session);
+
+        shard
+    }
+
     /// Immutable reference to the configuration used to instantiate this shard.
     pub const fn config(&self) -> &Config<Q> {
         &self.config
@@ -364,10 +448,10 @@ impl<Q> Shard<Q> {
         self.id
     }
 
-    /// Zlib decompressor statistics.
+    /// Decompressor statistics.
     ///
     /// Reset when reconnecting to the gateway.
-    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
     pub const fn inflater(&self) -> &Inflater {
         &self.inflater
     }
@@ -385,6 +469,28 @@ impl<Q> Shard<Q> {
         &self.latency
     }
 
+    /// Received payload statistics, including compressed and decompressed
+    /// bytes, payloads per opcode, and the largest payload.
+    ///
+    /// Unlike [`inflater`], is not reset when reconnecting to the gateway.
+    ///
+    /// [`inflater`]: Self::inflater
+    pub const fn payload_stats(&self) -> &PayloadStats {
+        &self.payload_stats
+    }
+
+    /// Stream of heartbeat latencies as they are recorded.
+    ///
+    /// Unlike [`latency`], is not reset when reconnecting to the gateway.
+    ///
+    /// [`latency`]: Self::latency
+    pub fn latency_samples(&mut self) -> LatencySamples {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.latency_subscribers.push(tx);
+
+        LatencySamples { rx }
+    }
+
     /// Statistics about the number of available commands and when the command
     /// ratelimiter will refresh.
     ///
@@ -404,6 +510,17 @@ impl<Q> Shard<Q> {
         self.resume_url.as_deref()
     }
 
+    /// State required to resume the active gateway session, such as after
+    /// restarting.
+    ///
+    /// Not present if the shard has no active session. Refer to
+    /// [`ResumeState`] for how to persist it.
+    pub fn resume_state(&self) -> Option<ResumeState> {
+        self.session
+            .clone()
+            .map(|session| ResumeState::new(session, self.resume_url().map(ToOwned::to_owned)))
+    }
+
     /// Immutable reference to the active gateway session.
     ///
     /// An active session may not be present if the shard had its session
@@ -419,15 +536,33 @@ impl<Q> Shard<Q> {
     /// [`send`]: Self::send
     #[allow(clippy::missing_panics_doc)]
     pub fn command(&self, command: &impl Command) {
-        self.send(json::to_string(command).expect("serialization cannot fail"));
+        self.send_message(self.config.encoding().command(command), Priority::Normal);
     }
 
-    /// Queue a JSON encoded gateway event to be sent to the gateway.
+    /// Queue a command of the priority to be sent to the gateway.
+    ///
+    /// Refer to [`Priority`] for how commands are prioritized.
     #[allow(clippy::missing_panics_doc)]
+    pub fn command_with_priority(&self, command: &impl Command, priority: Priority) {
+        self.send_message(self.config.encoding().command(command), priority);
+    }
+
+    /// Queue a JSON encoded gateway event to be sent to the gateway.
+    ///
+    /// Note that the gateway expects gateway events in the shard's configured
+    /// [`Encoding`], which [`command`] takes care of.
+    ///
+    /// [`Encoding`]: crate::Encoding
+    /// [`command`]: Self::command
     pub fn send(&self, json: String) {
+        self.send_message(Message::Text(json), Priority::Normal);
+    }
+
+    /// Queue a message of the priority to be sent to the gateway.
+    pub(crate) fn send_message(&self, message: Message, priority: Priority) {
         self.user_channel
-            .command_tx
-            .send(json)
+            .command_tx(priority)
+            .send(message)
             .expect("channel open");
     }
 
@@ -456,7 +591,7 @@ impl<Q> Shard<Q> {
     /// while let Some(item) = shard.next().await {
     ///     match item {
     ///         Ok(Message::Close(_)) => break,
-    ///         Ok(Message::Text(_)) => unimplemented!(),
+    ///         Ok(_) => unimplemented!(),
     ///         Err(source) => unimplemented!(),
     ///     }
     /// }
@@ -505,7 +640,9 @@ impl<Q> Shard<Q> {
     fn disconnect(&mut self, initiator: CloseInitiator) {
         // May not send any additional WebSocket messages.
         self.heartbeat_interval = None;
+        self.presence_interval = None;
         self.ratelimiter = None;
+        self.watchdog = None;
         // Abort identify.
         self.identify_rx = None;
         self.state = match initiator {
@@ -514,6 +651,11 @@ impl<Q> Shard<Q> {
                 reconnect_attempts: 0,
             },
         };
+        #[cfg(feature = "metrics")]
+        if self.state.is_disconnected() {
+            crate::metrics::reconnect(self.id);
+        }
+
         if let CloseInitiator::Shard(frame) = initiator {
             // Not resuming, drop session and resume URL.
             // https://discord.com/developers/docs/topics/gateway#initiating-a-disconnect
@@ -528,7 +670,7 @@ impl<Q> Shard<Q> {
         }
     }
 
-    /// Parse a JSON message into an event with minimal data for [processing].
+    /// Parse a message into an event with minimal data for [processing].
     ///
     /// # Errors
     ///
@@ -538,14 +680,15 @@ impl<Q> Shard<Q> {
     ///
     /// [processing]: Self::process
     fn parse_event<T: DeserializeOwned>(
-        json: &str,
+        event: Payload<'_>,
     ) -> Result<MinimalEvent<T>, ReceiveMessageError> {
-        json::from_str::<MinimalEvent<T>>(json).map_err(|source| ReceiveMessageError {
-            kind: ReceiveMessageErrorType::Deserializing {
-                event: json.to_owned(),
-            },
-            source: Some(Box::new(source)),
-        })
+        let result: Result<_, Box<dyn Error + Send + Sync>> = match event {
+            #[cfg(feature = "etf")]
+            Payload::Etf(etf) => crate::etf::from_slice::<MinimalEvent<T>>(etf).map_err(Into::into),
+            Payload::Json(json) => json::from_str::<MinimalEvent<T>>(json).map_err(Into::into),
+        };
+
+        result.map_err(|source| deserializing(event, Some(source)))
     }
 
     /// Send and flush the pending message.
@@ -564,7 +707,7 @@ impl<Q> Shard<Q> {
 
         if let Some(message) = &pending.gateway_event {
             if let Some(ratelimiter) = self.ratelimiter.as_mut() {
-                if message.is_text() && !pending.is_heartbeat {
+                if !message.is_close() && !pending.is_heartbeat {
                     ready!(ratelimiter.poll_acquire(cx));
                 }
             }
@@ -593,6 +736,60 @@ impl<Q> Shard<Q> {
 }
 
 impl<Q: Queue> Shard<Q> {
+    /// Start the [`Config::presence_rotation`]'s interval, if any.
+    fn start_presence_rotation(&mut self) {
+        if let Some(rotation) = self.config.presence_rotation() {
+            let period = rotation.interval();
+            let mut interval = time::interval_at(Instant::now() + period, period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            self.presence_interval = Some(interval);
+        }
+    }
+
+    /// Start the watchdog, if it was enabled via [`Config::watchdog`].
+    fn start_watchdog(&mut self) {
+        let heartbeat_interval = self
+            .heartbeat_interval
+            .as_ref()
+            .map_or(Duration::ZERO, Interval::period);
+        self.watchdog = self
+            .config
+            .watchdog()
+            .map(|timeout| Watchdog::new(timeout, heartbeat_interval));
+    }
+
+    /// Whether the gateway proxy handles part of maintaining the session, as
+    /// configured via [`Config::proxy_handling`].
+    fn proxy_handles(&self, handling: ProxyHandling) -> bool {
+        self.config.proxy_url().is_some() && self.config.proxy_handling().contains(handling)
+    }
+
+    /// Poll for the next presence of the [`Config::presence_rotation`].
+    ///
+    /// Waits for low priority ratelimit permits before the interval.
+    fn poll_next_presence(&mut self, cx: &mut Context<'_>) -> Poll<Message> {
+        let Some(interval) = self.presence_interval.as_mut() else {
+            return Poll::Pending;
+        };
+        if let Some(ratelimiter) = self.ratelimiter.as_mut() {
+            ready!(ratelimiter.poll_ready_for(Priority::Low, cx));
+        }
+        ready!(interval.poll_tick(cx));
+
+        let presences = self
+            .config
+            .presence_rotation()
+            .expect("interval is only set with a rotation")
+            .presences();
+        let presence = presences[self.presence_index % presences.len()].clone();
+        self.presence_index = (self.presence_index + 1) % presences.len();
+
+        Poll::Ready(self.config.encoding().command(&UpdatePresence {
+            d: presence,
+            op: OpCode::PresenceUpdate,
+        }))
+    }
+
     /// Updates the shard's internal state from a gateway event by recording
     /// and/or responding to certain Discord events.
     ///
@@ -601,36 +798,72 @@ impl<Q: Queue> Shard<Q> {
     /// Returns a [`ReceiveMessageErrorType::Deserializing`] error type if the
     /// gateway event isn't a recognized structure.
     #[allow(clippy::too_many_lines)]
-    fn process(&mut self, event: &str) -> Result<(), ReceiveMessageError> {
+    #[tracing::instrument(
+        fields(event_type, opcode, sequence),
+        level = "debug",
+        name = "event",
+        skip_all
+    )]
+    fn process(&mut self, event: Payload<'_>) -> Result<(), ReceiveMessageError> {
+        let parts = match event {
+            #[cfg(feature = "etf")]
+            Payload::Etf(etf) => crate::etf::header(etf).map_err(Into::into),
+            Payload::Json(json) => GatewayEventDeserializer::from_json(json)
+                .map(GatewayEventDeserializer::into_parts)
+                .ok_or_else(|| "missing opcode".into()),
+        };
+
         let (raw_opcode, maybe_sequence, maybe_event_type) =
-            GatewayEventDeserializer::from_json(event)
-                .ok_or(ReceiveMessageError {
-                    kind: ReceiveMessageErrorType::Deserializing {
-                        event: event.to_owned(),
-                    },
-                    source: Some("missing opcode".into()),
-                })?
-                .into_parts();
+            parts.map_err(|source| deserializing(event, Some(source)))?;
+        let span = tracing::Span::current();
+        span.record("opcode", raw_opcode);
+        if let Some(sequence) = maybe_sequence {
+            span.record("sequence", sequence);
+        }
+        if let Some(event_type) = &maybe_event_type {
+            span.record("event_type", event_type.as_ref());
+        }
+
+        let opcode = OpCode::from(raw_opcode);
+        if self.payload_stats.record_payload(opcode, event.len()) {
+            let guild_id = if maybe_event_type.as_deref() == Some("GUILD_CREATE") {
+                Self::parse_event::<MinimalGuildCreate>(event)
+                    .ok()
+                    .map(|event| event.data.id)
+            } else {
+                None
+            };
+            self.payload_stats.record_largest(LargestPayload::new(
+                event.len(),
+                maybe_event_type.as_deref().map(Into::into),
+                guild_id,
+                opcode,
+            ));
+        }
 
         if self.latency.sent().is_some() {
             self.heartbeat_interval_event = true;
         }
 
-        match OpCode::from(raw_opcode) {
+        if self.proxy_handles(ProxyHandling::HEARTBEAT) {
+            if let Some(watchdog) = self.watchdog.as_mut() {
+                watchdog.heartbeat_ack();
+            }
+        }
+
+        match opcode {
             Some(OpCode::Dispatch) => {
-                let event_type = maybe_event_type.ok_or(ReceiveMessageError {
-                    kind: ReceiveMessageErrorType::Deserializing {
-                        event: event.to_owned(),
-                    },
-                    source: Some("missing dispatch event type".into()),
+                let event_type = maybe_event_type.ok_or_else(|| {
+                    deserializing(event, Some("missing dispatch event type".into()))
                 })?;
-                let sequence = maybe_sequence.ok_or(ReceiveMessageError {
-                    kind: ReceiveMessageErrorType::Deserializing {
-                        event: event.to_owned(),
-                    },
-                    source: Some("missing sequence".into()),
-                })?;
-                tracing::debug!(%event_type, %sequence, "received dispatch");
+                let sequence = maybe_sequence
+                    .ok_or_else(|| deserializing(event, Some("missing sequence".into())))?;
+                tracing::debug!("received dispatch");
+                #[cfg(feature = "metrics")]
+                crate::metrics::event(self.id, &event_type);
+                if let Some(watchdog) = self.watchdog.as_mut() {
+                    watchdog.event();
+                }
 
                 match event_type.as_ref() {
                     "READY" => {
@@ -639,8 +872,14 @@ impl<Q: Queue> Shard<Q> {
                         self.resume_url = Some(event.data.resume_gateway_url);
                         self.session = Some(Session::new(sequence, event.data.session_id));
                         self.state = ShardState::Active;
+                        self.start_presence_rotation();
+                        self.start_watchdog();
+                    }
+                    "RESUMED" => {
+                        self.state = ShardState::Active;
+                        self.start_presence_rotation();
+                        self.start_watchdog();
                     }
-                    "RESUMED" => self.state = ShardState::Active,
                     _ => {}
                 }
 
@@ -650,9 +889,10 @@ impl<Q: Queue> Shard<Q> {
             }
             Some(OpCode::Heartbeat) => {
                 tracing::debug!("received heartbeat");
-                self.pending = Pending::text(
-                    json::to_string(&Heartbeat::new(self.session().map(Session::sequence)))
-                        .expect("serialization cannot fail"),
+                self.pending = Pending::new(
+                    self.config
+                        .encoding()
+                        .command(&Heartbeat::new(self.session().map(Session::sequence))),
                     true,
                 );
             }
@@ -661,6 +901,14 @@ impl<Q: Queue> Shard<Q> {
                 if requested {
                     tracing::debug!("received heartbeat ack");
                     self.latency.record_received();
+                    if let Some(watchdog) = self.watchdog.as_mut() {
+                        watchdog.heartbeat_ack();
+                    }
+                    let latency = self.latency.recent()[0];
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::heartbeat_latency(self.id, latency);
+                    self.latency_subscribers
+                        .retain(|tx| tx.send(latency).is_ok());
                 } else {
                     tracing::info!("received unrequested heartbeat ack");
                 }
@@ -677,27 +925,37 @@ impl<Q: Queue> Shard<Q> {
                     self.ratelimiter = Some(CommandRatelimiter::new(heartbeat_interval));
                 }
 
-                let mut interval = time::interval_at(Instant::now() + jitter, heartbeat_interval);
-                interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
-                self.heartbeat_interval = Some(interval);
+                if !self.proxy_handles(ProxyHandling::HEARTBEAT) {
+                    let mut interval =
+                        time::interval_at(Instant::now() + jitter, heartbeat_interval);
+                    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                    self.heartbeat_interval = Some(interval);
+                }
 
                 // Reset `Latency` since the shard might have connected to a new
                 // remote which invalidates the recorded latencies.
                 self.latency = Latency::new();
 
-                if let Some(session) = &self.session {
-                    self.pending = Pending::text(
-                        json::to_string(&Resume::new(
+                if self.proxy_handles(ProxyHandling::IDENTIFY) {
+                    tracing::debug!("gateway proxy handles identifying");
+                } else if let Some(session) = &self.session {
+                    self.pending = Pending::new(
+                        self.config.encoding().command(&Resume::new(
                             session.sequence(),
                             session.id(),
                             self.config.token(),
-                        ))
-                        .expect("serialization cannot fail"),
+                        )),
                         false,
                     );
                     self.state = ShardState::Resuming;
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::resume(self.id);
                 } else {
                     self.identify_rx = Some(self.config.queue().enqueue(self.id.number()));
+                    #[cfg(feature = "metrics")]
+                    {
+                        self.identify_queued = Some(Instant::now());
+                    }
                 }
             }
             Some(OpCode::InvalidSession) => {
@@ -724,7 +982,7 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
     type Item = Result<Message, ReceiveMessageError>;
 
     #[allow(clippy::too_many_lines)]
-    #[tracing::instrument(fields(id = %self.id), name = "shard", skip_all)]
+    #[tracing::instrument(fields(id = %self.id), name = "shard", parent = &self.span, skip_all)]
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let message = loop {
             match self.state {
@@ -740,30 +998,65 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                 }
                 ShardState::Disconnected { reconnect_attempts } if self.connection.is_none() => {
                     if self.connection_future.is_none() {
-                        let base_url = self
-                            .resume_url
-                            .as_deref()
-                            .or_else(|| self.config.proxy_url())
-                            .unwrap_or(GATEWAY_URL);
-                        let uri = format!(
-                            "{base_url}/?v={API_VERSION}&encoding=json{COMPRESSION_FEATURES}"
+                        let base_url = if self.proxy_handles(ProxyHandling::IDENTIFY) {
+                            self.config.proxy_url()
+                        } else {
+                            self.resume_url
+                                .as_deref()
+                                .or_else(|| self.config.proxy_url())
+                        }
+                        .unwrap_or(GATEWAY_URL);
+                        #[allow(unused_mut)]
+                        let mut uri = format!(
+                            "{base_url}/?v={API_VERSION}&encoding={}",
+                            self.config.encoding().query()
                         );
+                        #[cfg(any(
+                            feature = "zlib-stock",
+                            feature = "zlib-simd",
+                            feature = "zstd"
+                        ))]
+                        {
+                            uri.push_str("&compress=");
+                            uri.push_str(self.config.compression().query());
+                        }
 
-                        tracing::debug!(url = base_url, "connecting to gateway");
+                        let span = tracing::info_span!(
+                            parent: None,
+                            "connection",
+                            id = %self.id,
+                            reconnect_attempts,
+                            resume = self.session.is_some(),
+                        );
+                        span.in_scope(|| tracing::debug!(url = base_url, "connecting to gateway"));
+                        self.span = span;
 
+                        let proxy = self.config.connect_proxy().cloned();
                         let tls = self.config.tls.clone();
                         self.connection_future = Some(ConnectionFuture(Box::pin(async move {
                             let secs = 2u8.saturating_pow(reconnect_attempts.into());
                             time::sleep(Duration::from_secs(secs.into())).await;
 
-                            Ok(ClientBuilder::new()
+                            let builder = ClientBuilder::new()
                                 .uri(&uri)
                                 .expect("URL should be valid")
                                 .limits(Limits::unlimited())
-                                .connector(&tls)
-                                .connect()
-                                .await?
-                                .0)
+                                .connector(&tls);
+
+                            let Some(proxy) = proxy else {
+                                return Ok(builder.connect().await?.0);
+                            };
+
+                            let (host, port, secure) =
+                                proxy::target(&uri).ok_or(WebsocketError::UnsupportedScheme)?;
+                            let stream = proxy.connect(host, port).await?;
+                            let stream = if secure {
+                                tls.wrap(host, stream).await?
+                            } else {
+                                Connector::Plain.wrap(host, stream).await?
+                            };
+
+                            Ok(builder.connect_on(stream).await?.0)
                         })));
                     }
 
@@ -773,8 +1066,18 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                     match res {
                         Ok(connection) => {
                             self.connection = Some(connection);
-                            self.state = ShardState::Identifying;
-                            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+                            if self.proxy_handles(ProxyHandling::IDENTIFY) {
+                                self.state = ShardState::Active;
+                                self.start_presence_rotation();
+                                self.start_watchdog();
+                            } else {
+                                self.state = ShardState::Identifying;
+                            }
+                            #[cfg(any(
+                                feature = "zlib-stock",
+                                feature = "zlib-simd",
+                                feature = "zstd"
+                            ))]
                             self.inflater.reset();
                         }
                         Err(source) => {
@@ -785,7 +1088,7 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
 
                             return Poll::Ready(Some(Err(ReceiveMessageError {
                                 kind: ReceiveMessageErrorType::Reconnect,
-                                source: Some(Box::new(source)),
+                                source: Some(source),
                             })));
                         }
                     }
@@ -825,9 +1128,10 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                     self.disconnect(CloseInitiator::Shard(CloseFrame::RESUME));
                 } else {
                     tracing::debug!("sending heartbeat");
-                    self.pending = Pending::text(
-                        json::to_string(&Heartbeat::new(self.session().map(Session::sequence)))
-                            .expect("serialization cannot fail"),
+                    self.pending = Pending::new(
+                        self.config
+                            .encoding()
+                            .command(&Heartbeat::new(self.session().map(Session::sequence))),
                         true,
                     );
                     self.heartbeat_interval_event = false;
@@ -838,6 +1142,29 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                 }
             }
 
+            if let Some(Poll::Ready(stall)) = self
+                .watchdog
+                .as_mut()
+                .map(|watchdog| watchdog.poll_stall(cx))
+            {
+                tracing::warn!(
+                    since_event = ?stall.since_event,
+                    since_heartbeat_ack = ?stall.since_heartbeat_ack,
+                    "shard is stalled, reconnecting"
+                );
+                #[cfg(feature = "metrics")]
+                crate::metrics::stall(self.id);
+                self.disconnect(CloseInitiator::Shard(CloseFrame::RESUME));
+
+                return Poll::Ready(Some(Err(ReceiveMessageError {
+                    kind: ReceiveMessageErrorType::Stalled {
+                        since_event: stall.since_event,
+                        since_heartbeat_ack: stall.since_heartbeat_ack,
+                    },
+                    source: None,
+                })));
+            }
+
             let not_ratelimited = self
                 .ratelimiter
                 .as_mut()
@@ -855,8 +1182,8 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                     }
 
                     tracing::debug!("sending identify");
-                    self.pending = Pending::text(
-                        json::to_string(&Identify::new(IdentifyInfo {
+                    self.pending = Pending::new(
+                        self.config.encoding().command(&Identify::new(IdentifyInfo {
                             compress: false,
                             intents: self.config.intents(),
                             large_threshold: self.config.large_threshold(),
@@ -868,11 +1195,17 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                                 .unwrap_or_else(default_identify_properties),
                             shard: Some(self.id),
                             token: self.config.token().to_owned(),
-                        }))
-                        .expect("serialization cannot fail"),
+                        })),
                         false,
                     );
                     self.identify_rx = None;
+                    #[cfg(feature = "metrics")]
+                    {
+                        crate::metrics::identify(self.id);
+                        if let Some(queued) = self.identify_queued.take() {
+                            crate::metrics::queue_wait(self.id, queued.elapsed());
+                        }
+                    }
 
                     if ready!(self.poll_flush_pending(cx)).is_err() {
                         return Poll::Ready(Some(Ok(Message::ABNORMAL_CLOSE)));
@@ -881,11 +1214,37 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
             }
 
             if not_ratelimited && self.state.is_identified() {
-                if let Poll::Ready(command) = self.user_channel.command_rx.poll_recv(cx) {
-                    let command = command.expect("shard owns channel");
+                let command = match self.user_channel.command_rx.poll_recv(cx) {
+                    Poll::Ready(command) => Some(command),
+                    Poll::Pending
+                        if self.ratelimiter.as_mut().map_or(true, |ratelimiter| {
+                            ratelimiter.poll_ready_for(Priority::Low, cx).is_ready()
+                        }) =>
+                    {
+                        match self.user_channel.low_priority_command_rx.poll_recv(cx) {
+                            Poll::Ready(command) => Some(command),
+                            Poll::Pending => None,
+                        }
+                    }
+                    Poll::Pending => None,
+                };
 
-                    tracing::debug!("sending command from user channel");
-                    self.pending = Pending::text(command, false);
+                let command = match command {
+                    Some(command) => {
+                        tracing::debug!("sending command from user channel");
+                        Some(command.expect("shard owns channel"))
+                    }
+                    None => match self.poll_next_presence(cx) {
+                        Poll::Ready(presence) => {
+                            tracing::debug!("sending presence from rotation");
+                            Some(presence)
+                        }
+                        Poll::Pending => None,
+                    },
+                };
+
+                if let Some(command) = command {
+                    self.pending = Pending::new(command, false);
 
                     if ready!(self.poll_flush_pending(cx)).is_err() {
                         return Poll::Ready(Some(Ok(Message::ABNORMAL_CLOSE)));
@@ -895,14 +1254,19 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
 
             match ready!(Pin::new(self.connection.as_mut().unwrap()).poll_next(cx)) {
                 Some(Ok(message)) => {
-                    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+                    self.payload_stats
+                        .record_received(message.as_payload().len());
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::payload_bytes(self.id, message.as_payload().len());
+                    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd", feature = "zstd"))]
                     if message.is_binary() {
+                        let encoding = self.config.encoding();
                         if let Some(decompressed) = self
                             .inflater
-                            .inflate(message.as_payload())
+                            .inflate(message.as_payload(), encoding)
                             .map_err(ReceiveMessageError::from_compression)?
                         {
-                            break Message::Text(decompressed);
+                            break decompressed;
                         };
                     }
                     if let Some(message) = Message::from_websocket_msg(&message) {
@@ -952,8 +1316,14 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                     self.disconnect(CloseInitiator::Gateway(frame.as_ref().map(|f| f.code)));
                 }
             }
+            #[cfg(feature = "etf")]
+            Message::Binary(event) => {
+                self.process(Payload::Etf(event))?;
+            }
+            #[cfg(not(feature = "etf"))]
+            Message::Binary(_) => {}
             Message::Text(event) => {
-                self.process(event)?;
+                self.process(Payload::Json(event))?;
             }
         }
 
@@ -961,6 +1331,23 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
     }
 }
 
+/// Create a new error for an event that could not be deserialized.
+fn deserializing(
+    event: Payload<'_>,
+    source: Option<Box<dyn Error + Send + Sync>>,
+) -> ReceiveMessageError {
+    let event = match event {
+        #[cfg(feature = "etf")]
+        Payload::Etf(etf) => String::from_utf8_lossy(etf).into_owned(),
+        Payload::Json(json) => json.to_owned(),
+    };
+
+    ReceiveMessageError {
+        kind: ReceiveMessageErrorType::Deserializing { event },
+        source,
+    }
+}
+
 /// Default identify properties to use when the user hasn't customized it in
 /// [`Config::identify_properties`].
 ///
@@ -972,9 +1359,44 @@ fn default_identify_properties() -> IdentifyProperties {
 #[cfg(test)]
 mod tests {
     use super::Shard;
+    use crate::{Config, ConfigBuilder, Intents, ProxyHandling, ResumeState, Session, ShardId};
     use static_assertions::{assert_impl_all, assert_not_impl_any};
     use std::fmt::Debug;
 
     assert_impl_all!(Shard: Debug, Send);
     assert_not_impl_any!(Shard: Sync);
+
+    #[tokio::test]
+    async fn resume_state() {
+        let config = Config::new("token".to_owned(), Intents::empty());
+        assert!(Shard::with_config(ShardId::ONE, config.clone())
+            .resume_state()
+            .is_none());
+
+        let state = ResumeState::new(
+            Session::new(5, "id".to_owned()),
+            Some("wss://gateway-us-east1-b.discord.gg".to_owned()),
+        );
+        let shard = Shard::with_resume_state(ShardId::ONE, config, state.clone());
+
+        assert_eq!(Some(&Session::new(5, "id".to_owned())), shard.session());
+        assert_eq!(state.resume_url(), shard.resume_url());
+        assert_eq!(Some(state), shard.resume_state());
+    }
+
+    #[tokio::test]
+    async fn proxy_handles() {
+        let config = ConfigBuilder::new("token".to_owned(), Intents::empty())
+            .proxy_handling(ProxyHandling::IDENTIFY)
+            .build();
+        let shard = Shard::with_config(ShardId::ONE, config.clone());
+        assert!(!shard.proxy_handles(ProxyHandling::IDENTIFY));
+
+        let config = ConfigBuilder::from(config)
+            .proxy_url("ws://localhost:7878".to_owned())
+            .build();
+        let shard = Shard::with_config(ShardId::ONE, config);
+        assert!(shard.proxy_handles(ProxyHandling::IDENTIFY));
+        assert!(!shard.proxy_handles(ProxyHandling::HEARTBEAT));
+    }
 }
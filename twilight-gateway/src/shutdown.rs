@@ -0,0 +1,131 @@
+//! Closing many shards at once, waiting for them to finish.
+
+use crate::{queue::Queue, CloseFrame, Message, Shard, ShardState};
+use futures_core::Stream;
+use std::{
+    future::poll_fn,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// How shards should close their gateway connection when shutting down.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ShutdownBehavior {
+    /// Invalidate the sessions, showing the application's bot as offline.
+    ///
+    /// Closes with [`CloseFrame::NORMAL`].
+    Invalidate,
+    /// Keep the sessions alive to be resumed soon, such as after a deploy.
+    ///
+    /// Closes with [`CloseFrame::RESUME`]. Persist each shard's
+    /// [`ResumeState`] after shutting down to resume its session later.
+    ///
+    /// [`ResumeState`]: crate::ResumeState
+    Resume,
+}
+
+impl ShutdownBehavior {
+    /// Close frame to close the shards with.
+    pub const fn close_frame(self) -> CloseFrame<'static> {
+        match self {
+            Self::Invalidate => CloseFrame::NORMAL,
+            Self::Resume => CloseFrame::RESUME,
+        }
+    }
+}
+
+/// Close the shards, resolving once all of them are closed.
+///
+/// The shards are polled concurrently until they return [`Message::Close`],
+/// discarding their remaining messages. Disconnected shards are skipped.
+///
+/// # Examples
+///
+/// Shut down the shards, keeping their sessions for the next process:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_gateway::{Config, Intents, ShutdownBehavior};
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let config = Config::new(token, Intents::GUILDS);
+/// let mut shards =
+///     twilight_gateway::create_iterator(0..10, 10, config, |_, builder| builder.build())
+///         .collect::<Vec<_>>();
+///
+/// // Run the shards...
+///
+/// twilight_gateway::shutdown(&mut shards, ShutdownBehavior::Resume).await;
+/// let states = shards
+///     .iter()
+///     .filter_map(|shard| Some((shard.id(), shard.resume_state()?)))
+///     .collect::<Vec<_>>();
+/// # Ok(()) }
+/// ```
+pub async fn shutdown<'a, Q: Queue + Unpin + 'a>(
+    shards: impl IntoIterator<Item = &'a mut Shard<Q>>,
+    behavior: ShutdownBehavior,
+) {
+    let close_frame = behavior.close_frame();
+    let mut shards = shards
+        .into_iter()
+        .filter(|shard| !matches!(shard.state(), ShardState::Disconnected { .. }))
+        .inspect(|shard| shard.close(close_frame.clone()))
+        .collect::<Vec<_>>();
+
+    poll_fn(|cx| {
+        shards.retain_mut(|shard| poll_close(shard, cx).is_pending());
+
+        if shards.is_empty() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+}
+
+/// Poll the shard until it's closed.
+fn poll_close<Q: Queue + Unpin>(shard: &mut Shard<Q>, cx: &mut Context<'_>) -> Poll<()> {
+    loop {
+        match Pin::new(&mut *shard).poll_next(cx) {
+            Poll::Ready(Some(Ok(Message::Close(_))) | None) => return Poll::Ready(()),
+            Poll::Ready(Some(Ok(_))) => {}
+            Poll::Ready(Some(Err(source))) => {
+                tracing::debug!(shard_id = %shard.id(), ?source, "error while shutting down");
+            }
+            Poll::Pending => return Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShutdownBehavior;
+    use crate::{CloseFrame, Config, Intents, Shard, ShardId};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(ShutdownBehavior: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn close_frame() {
+        assert_eq!(
+            CloseFrame::NORMAL,
+            ShutdownBehavior::Invalidate.close_frame()
+        );
+        assert_eq!(CloseFrame::RESUME, ShutdownBehavior::Resume.close_frame());
+    }
+
+    #[tokio::test]
+    async fn disconnected() {
+        let config = Config::new("token".to_owned(), Intents::empty());
+        let mut shards = [
+            Shard::with_config(ShardId::new(0, 2), config.clone()),
+            Shard::with_config(ShardId::new(1, 2), config),
+        ];
+
+        super::shutdown(&mut shards, ShutdownBehavior::Invalidate).await;
+    }
+}
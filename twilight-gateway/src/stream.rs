@@ -1,7 +1,8 @@
 //! Convenient `Stream` extension trait for message deserialization.
 
-use crate::{error::ReceiveMessageError, EventTypeFlags, Message};
+use crate::{error::ReceiveMessageError, json::parse, EventTypeFlags, Message};
 use futures_core::Stream;
+use twilight_model::gateway::event::Event;
 
 /// An extension trait for the [`Stream`] trait.
 ///
@@ -70,17 +71,125 @@ pub trait StreamExt: Stream {
     {
         private::NextEvent::new(self, wanted_event_types)
     }
+
+    /// Consumes and returns the next wanted [`RawEvent`] in the stream or
+    /// `None` if the stream is finished.
+    ///
+    /// Like [`next_event`], but only the type of the events is decoded, their
+    /// data isn't deserialized. Useful for services forwarding events
+    /// elsewhere, such as to other processes.
+    ///
+    /// Close messages are always considered wanted.
+    ///
+    /// Equivalent to:
+    ///
+    /// ```ignore
+    /// async fn next_raw_event(&mut self, wanted_event_types: EventTypeFlags) -> Option<Result<RawEvent, ReceiveMessageError>>
+    /// ```
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. The returned future only holds onto a
+    /// reference to the underlying stream, so dropping it will never lose a
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use twilight_gateway::{Intents, Shard, ShardId};
+    /// # #[tokio::main] async fn main() {
+    /// # let mut shard = Shard::new(ShardId::ONE, String::new(), Intents::empty());
+    /// use twilight_gateway::{EventTypeFlags, Message, StreamExt as _};
+    ///
+    /// let wanted_event_types = EventTypeFlags::MESSAGE_CREATE | EventTypeFlags::MESSAGE_UPDATE;
+    ///
+    /// while let Some(item) = shard.next_raw_event(wanted_event_types).await {
+    ///     let Ok(event) = item else {
+    ///         tracing::warn!(source = ?item.unwrap_err(), "error receiving event");
+    ///
+    ///         continue;
+    ///     };
+    ///
+    ///     if let Message::Text(json) = event.into_message() {
+    ///         // Forward the payload elsewhere.
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`next_event`]: Self::next_event
+    fn next_raw_event(
+        &mut self,
+        wanted_event_types: EventTypeFlags,
+    ) -> private::NextRawEvent<'_, Self>
+    where
+        Self: Unpin,
+    {
+        private::NextRawEvent::new(self, wanted_event_types)
+    }
 }
 
 impl<St: ?Sized> StreamExt for St where St: Stream<Item = Result<Message, ReceiveMessageError>> {}
 
+/// Parse a message into an event if its type is wanted.
+///
+/// Close messages are always considered wanted and map onto
+/// [`Event::GatewayClose`].
+pub(crate) fn parse_message(
+    message: Message,
+    wanted_event_types: EventTypeFlags,
+) -> Result<Option<Event>, ReceiveMessageError> {
+    match message {
+        #[cfg(feature = "etf")]
+        Message::Binary(etf) => {
+            crate::etf::parse(&etf, wanted_event_types).map(|opt| opt.map(Into::into))
+        }
+        #[cfg(not(feature = "etf"))]
+        Message::Binary(_) => Ok(None),
+        Message::Text(json) => parse(json, wanted_event_types).map(|opt| opt.map(Into::into)),
+        Message::Close(frame) => Ok(Some(Event::GatewayClose(frame))),
+    }
+}
+
+/// Gateway event whose data wasn't deserialized.
+///
+/// Returned by [`StreamExt::next_raw_event`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawEvent {
+    /// Type of the event, if it's not a close message.
+    event_type: Option<EventTypeFlags>,
+    /// Message containing the event.
+    message: Message,
+}
+
+impl RawEvent {
+    /// Type of the event.
+    ///
+    /// `None` if the message is a close message.
+    pub const fn event_type(&self) -> Option<EventTypeFlags> {
+        self.event_type
+    }
+
+    /// Immutable reference to the message containing the event.
+    pub const fn message(&self) -> &Message {
+        &self.message
+    }
+
+    /// Consume the event, returning the message containing it.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+}
+
 mod private {
     //! Private module to hide the returned type from the [`next_event`](super::StreamExt::next_event)
     //! method.
     //!
     //! Effectively disallows consumers from implementing the trait.
 
-    use crate::{error::ReceiveMessageError, json::parse, EventTypeFlags, Message};
+    use super::{parse_message, RawEvent};
+    use crate::{error::ReceiveMessageError, EventTypeFlags, Message};
     use futures_core::Stream;
     use std::{
         future::Future,
@@ -111,9 +220,65 @@ mod private {
 
         fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
             let events = self.events;
-            let try_from_message = |message| match message {
-                Message::Text(json) => parse(json, events).map(|opt| opt.map(Into::into)),
-                Message::Close(frame) => Ok(Some(Event::GatewayClose(frame))),
+            let try_from_message = |message| parse_message(message, events);
+
+            loop {
+                match ready!(Pin::new(&mut self.stream).poll_next(cx)) {
+                    Some(item) => {
+                        if let Some(event) = item.and_then(try_from_message).transpose() {
+                            return Poll::Ready(Some(event));
+                        }
+                    }
+                    None => return Poll::Ready(None),
+                }
+            }
+        }
+    }
+
+    /// Future for the [`next_raw_event`](super::StreamExt::next_raw_event)
+    /// method.
+    pub struct NextRawEvent<'a, St: ?Sized> {
+        /// Gateway event types to return.
+        events: EventTypeFlags,
+        /// Inner wrapped stream.
+        stream: &'a mut St,
+    }
+
+    impl<'a, St: ?Sized> NextRawEvent<'a, St> {
+        /// Create a new future.
+        pub fn new(stream: &'a mut St, events: EventTypeFlags) -> Self {
+            Self { events, stream }
+        }
+    }
+
+    impl<St: ?Sized + Stream<Item = Result<Message, ReceiveMessageError>> + Unpin> Future
+        for NextRawEvent<'_, St>
+    {
+        type Output = Option<Result<RawEvent, ReceiveMessageError>>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let events = self.events;
+            let try_from_message = |message: Message| {
+                let event_type = match &message {
+                    #[cfg(feature = "etf")]
+                    Message::Binary(etf) => crate::etf::event_type(etf)?,
+                    #[cfg(not(feature = "etf"))]
+                    Message::Binary(_) => None,
+                    Message::Text(json) => crate::json::event_type(json)?,
+                    Message::Close(_) => {
+                        return Ok(Some(RawEvent {
+                            event_type: None,
+                            message,
+                        }))
+                    }
+                };
+
+                Ok(event_type
+                    .filter(|event_type| events.contains(*event_type))
+                    .map(|event_type| RawEvent {
+                        event_type: Some(event_type),
+                        message,
+                    }))
             };
 
             loop {
@@ -129,3 +294,49 @@ mod private {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RawEvent;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(RawEvent: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[tokio::test]
+    async fn next_raw_event() {
+        use super::StreamExt;
+        use crate::{EventTypeFlags, Message};
+
+        const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+        const MESSAGE_DELETE: &str =
+            r#"{"op":0,"s":1,"t":"MESSAGE_DELETE","d":{"id":"1","channel_id":"2"}}"#;
+
+        let mut stream = tokio_stream::iter([
+            Ok(Message::Text(HELLO.to_owned())),
+            Ok(Message::Text(MESSAGE_DELETE.to_owned())),
+            Ok(Message::Close(None)),
+        ]);
+
+        let event = stream
+            .next_raw_event(EventTypeFlags::MESSAGE_DELETE)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(EventTypeFlags::MESSAGE_DELETE), event.event_type());
+        assert_eq!(
+            Message::Text(MESSAGE_DELETE.to_owned()),
+            event.into_message()
+        );
+
+        let event = stream
+            .next_raw_event(EventTypeFlags::MESSAGE_DELETE)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(None, event.event_type());
+        assert_eq!(&Message::Close(None), event.message());
+
+        assert!(stream.next_raw_event(EventTypeFlags::all()).await.is_none());
+    }
+}
@@ -0,0 +1,134 @@
+//! Detection of stalled shards.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use tokio::time::{self, Duration, Instant, Sleep};
+
+/// Minimum timeout, in heartbeat intervals.
+///
+/// Heartbeat ACKs are only received once per heartbeat interval, and the first
+/// heartbeat is delayed by up to one interval of jitter, so shorter timeouts
+/// would detect healthy shards as stalled.
+const MIN_HEARTBEAT_INTERVALS: u32 = 2;
+
+/// Durations since a stalled shard last received heartbeat ACKs and dispatch
+/// events.
+#[derive(Clone, Copy, Debug)]
+pub struct Stall {
+    /// Duration since the last dispatch event.
+    pub since_event: Duration,
+    /// Duration since the last heartbeat ACK.
+    pub since_heartbeat_ack: Duration,
+}
+
+/// Watchdog detecting shards that haven't received a heartbeat ACK or a
+/// dispatch event within a timeout.
+///
+/// Configured via [`ConfigBuilder::watchdog`].
+///
+/// [`ConfigBuilder::watchdog`]: crate::ConfigBuilder::watchdog
+#[derive(Debug)]
+pub struct Watchdog {
+    /// When the last dispatch event was received.
+    last_event: Instant,
+    /// When the last heartbeat ACK was received.
+    last_heartbeat_ack: Instant,
+    /// Sleep until the earliest instant the shard may be stalled.
+    sleep: Pin<Box<Sleep>>,
+    /// Maximum duration without heartbeat ACKs or dispatch events.
+    timeout: Duration,
+}
+
+impl Watchdog {
+    /// Create a watchdog for a shard that just became active.
+    ///
+    /// The timeout is raised to [`MIN_HEARTBEAT_INTERVALS`] heartbeat
+    /// intervals if shorter.
+    pub fn new(timeout: Duration, heartbeat_interval: Duration) -> Self {
+        let timeout = timeout.max(heartbeat_interval.saturating_mul(MIN_HEARTBEAT_INTERVALS));
+        let now = Instant::now();
+
+        Self {
+            last_event: now,
+            last_heartbeat_ack: now,
+            sleep: Box::pin(time::sleep_until(now + timeout)),
+            timeout,
+        }
+    }
+
+    /// Record a received dispatch event.
+    pub fn event(&mut self) {
+        self.last_event = Instant::now();
+    }
+
+    /// Record a received heartbeat ACK.
+    pub fn heartbeat_ack(&mut self) {
+        self.last_heartbeat_ack = Instant::now();
+    }
+
+    /// Poll whether the shard is stalled.
+    pub fn poll_stall(&mut self, cx: &mut Context<'_>) -> Poll<Stall> {
+        loop {
+            ready!(self.sleep.as_mut().poll(cx));
+
+            let now = Instant::now();
+            let stall = Stall {
+                since_event: now.saturating_duration_since(self.last_event),
+                since_heartbeat_ack: now.saturating_duration_since(self.last_heartbeat_ack),
+            };
+
+            if stall.since_event >= self.timeout || stall.since_heartbeat_ack >= self.timeout {
+                return Poll::Ready(stall);
+            }
+
+            let deadline = self.last_event.min(self.last_heartbeat_ack) + self.timeout;
+            self.sleep.as_mut().reset(deadline);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Watchdog, MIN_HEARTBEAT_INTERVALS};
+    use std::future::poll_fn;
+    use tokio::time::{self, Duration, Instant};
+
+    const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(20);
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    #[tokio::test(start_paused = true)]
+    async fn stall() {
+        let mut watchdog = Watchdog::new(TIMEOUT, HEARTBEAT_INTERVAL);
+
+        time::advance(Duration::from_secs(30)).await;
+        watchdog.event();
+        watchdog.heartbeat_ack();
+
+        // Only heartbeat ACKs are received afterwards.
+        time::advance(Duration::from_secs(40)).await;
+        watchdog.heartbeat_ack();
+
+        let start = Instant::now();
+        let stall = poll_fn(|cx| watchdog.poll_stall(cx)).await;
+
+        assert_eq!(Duration::from_secs(20), start.elapsed());
+        assert_eq!(TIMEOUT, stall.since_event);
+        assert_eq!(Duration::from_secs(20), stall.since_heartbeat_ack);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn min_timeout() {
+        let heartbeat_interval = Duration::from_secs(45);
+        let mut watchdog = Watchdog::new(Duration::from_secs(10), heartbeat_interval);
+
+        let start = Instant::now();
+        let stall = poll_fn(|cx| watchdog.poll_stall(cx)).await;
+
+        let timeout = heartbeat_interval * MIN_HEARTBEAT_INTERVALS;
+        assert_eq!(timeout, start.elapsed());
+        assert_eq!(timeout, stall.since_heartbeat_ack);
+    }
+}
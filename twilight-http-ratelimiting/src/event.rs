@@ -0,0 +1,47 @@
+//! Events emitted by ratelimiters about encountered ratelimits.
+//!
+//! Events allow monitoring ratelimits, such as alerting on frequent `429 Too
+//! Many Requests` responses before Cloudflare bans the application's IP
+//! address for exceeding the invalid request limit.
+//!
+//! Refer to [`InMemoryRatelimiter::events`] for receiving events from the
+//! default ratelimiter.
+//!
+//! [`InMemoryRatelimiter::events`]: crate::InMemoryRatelimiter::events
+
+use crate::{headers::RatelimitScope, request::Path};
+use std::time::Duration;
+
+/// Event about a ratelimit encountered by a ratelimiter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RatelimitEvent {
+    /// Bucket has no remaining tickets; requests to it wait until it resets.
+    BucketExhausted {
+        /// Path of the bucket.
+        path: Path,
+        /// Duration until the bucket resets.
+        reset_after: Duration,
+    },
+    /// Request was ratelimited, receiving a `429 Too Many Requests` response.
+    Ratelimited {
+        /// Path of the request.
+        path: Path,
+        /// Duration to wait before retrying.
+        retry_after: Duration,
+        /// Scope of the ratelimit.
+        ///
+        /// Requests ratelimited with a [`RatelimitScope::Shared`] scope don't
+        /// count towards the invalid request limit.
+        scope: RatelimitScope,
+    },
+}
+
+impl RatelimitEvent {
+    /// Path the event relates to.
+    pub const fn path(&self) -> &Path {
+        match self {
+            Self::BucketExhausted { path, .. } | Self::Ratelimited { path, .. } => path,
+        }
+    }
+}
@@ -265,6 +265,8 @@ pub struct Present {
     reset_after: u64,
     /// When the bucket resets, as a Unix timestamp in milliseconds.
     reset: u64,
+    /// Number of seconds to wait before retrying.
+    retry_after: Option<u64>,
     /// Scope of the ratelimit.
     scope: Option<RatelimitScope>,
 }
@@ -307,7 +309,17 @@ impl Present {
         self.reset
     }
 
+    /// Number of seconds to wait before retrying.
+    ///
+    /// Only present if the request was ratelimited.
+    #[must_use]
+    pub const fn retry_after(&self) -> Option<u64> {
+        self.retry_after
+    }
+
     /// Scope of the ratelimit.
+    ///
+    /// Only present if the request was ratelimited.
     #[must_use]
     pub const fn scope(&self) -> Option<RatelimitScope> {
         self.scope
@@ -536,6 +548,7 @@ impl RatelimitHeaders {
             reset: reset.ok_or_else(|| HeaderParsingError::missing(HeaderName::Reset))?,
             reset_after: reset_after
                 .ok_or_else(|| HeaderParsingError::missing(HeaderName::ResetAfter))?,
+            retry_after,
             scope,
         }))
     }
@@ -727,6 +740,10 @@ mod tests {
                 HttpHeaderName::from_static("x-ratelimit-scope"),
                 HeaderValue::from_static("shared"),
             );
+            map.insert(
+                HttpHeaderName::from_static("retry-after"),
+                HeaderValue::from_static("65"),
+            );
 
             map
         };
@@ -758,6 +775,11 @@ mod tests {
             RatelimitHeaders::Present(present)
             if present.reset == 1_470_173_023_123
         ));
+        assert!(matches!(
+            &headers,
+            RatelimitHeaders::Present(present)
+            if present.retry_after() == Some(65)
+        ));
         assert!(matches!(
             headers,
             RatelimitHeaders::Present(present)
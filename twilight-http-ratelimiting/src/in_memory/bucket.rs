@@ -3,8 +3,14 @@
 //! consumed by the [`BucketQueueTask`] that manages the ratelimit for the bucket
 //! and respects the global ratelimit.
 
-use super::GlobalLockPair;
-use crate::{headers::RatelimitHeaders, request::Path, ticket::TicketNotifier};
+use super::{GlobalLockPair, GlobalRatelimit};
+use crate::{
+    event::RatelimitEvent,
+    headers::{RatelimitHeaders, RatelimitScope},
+    request::Path,
+    ticket::TicketNotifier,
+    Bucket as InfoBucket,
+};
 use std::{
     collections::HashMap,
     sync::{
@@ -15,6 +21,7 @@ use std::{
 };
 use tokio::{
     sync::{
+        broadcast::Sender,
         mpsc::{self, UnboundedReceiver, UnboundedSender},
         Mutex as AsyncMutex,
     },
@@ -83,6 +90,18 @@ impl Bucket {
         self.reset_after.load(Ordering::Relaxed)
     }
 
+    /// Snapshot of this bucket's ratelimit information.
+    pub fn info(&self) -> InfoBucket {
+        let started_at = *self.started_at.lock().expect("bucket poisoned");
+
+        InfoBucket::new(
+            self.limit(),
+            self.remaining(),
+            Duration::from_millis(self.reset_after()),
+            started_at,
+        )
+    }
+
     /// Time remaining until this bucket will reset.
     pub fn time_remaining(&self) -> TimeRemaining {
         let reset_after = self.reset_after();
@@ -188,8 +207,12 @@ pub(super) struct BucketQueueTask {
     bucket: Arc<Bucket>,
     /// All buckets managed by the associated [`super::InMemoryRatelimiter`].
     buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    /// Sender of ratelimit events.
+    events: Sender<RatelimitEvent>,
     /// Global ratelimit data.
     global: Arc<GlobalLockPair>,
+    /// Global requests per second limit.
+    global_ratelimit: Arc<GlobalRatelimit>,
     /// The [`Path`] this [`Bucket`] belongs to.
     path: Path,
 }
@@ -202,13 +225,17 @@ impl BucketQueueTask {
     pub const fn new(
         bucket: Arc<Bucket>,
         buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+        events: Sender<RatelimitEvent>,
         global: Arc<GlobalLockPair>,
+        global_ratelimit: Arc<GlobalRatelimit>,
         path: Path,
     ) -> Self {
         Self {
             bucket,
             buckets,
+            events,
             global,
+            global_ratelimit,
             path,
         }
     }
@@ -222,6 +249,18 @@ impl BucketQueueTask {
                 drop(self.global.0.lock().await);
             }
 
+            // Don't use up the global ratelimit for canceled tickets.
+            if queue_tx.is_closed() {
+                tracing::debug!("ticket canceled");
+
+                continue;
+            }
+
+            // Interaction callbacks are not bound to the global ratelimit.
+            if !matches!(self.path, Path::InteractionCallback(_)) {
+                self.global_ratelimit.acquire().await;
+            }
+
             let Some(ticket_headers) = queue_tx.available() else {
                 continue;
             };
@@ -254,13 +293,40 @@ impl BucketQueueTask {
     async fn handle_headers(&self, headers: &RatelimitHeaders) {
         let ratelimits = match headers {
             RatelimitHeaders::Global(global) => {
-                self.lock_global(Duration::from_secs(global.retry_after()))
-                    .await;
+                let retry_after = Duration::from_secs(global.retry_after());
+                self.emit(RatelimitEvent::Ratelimited {
+                    path: self.path.clone(),
+                    retry_after,
+                    scope: global.scope().unwrap_or(RatelimitScope::Global),
+                });
+                self.lock_global(retry_after).await;
 
                 None
             }
             RatelimitHeaders::None => return,
             RatelimitHeaders::Present(present) => {
+                // The scope is only sent with `429 Too Many Requests`
+                // responses.
+                if let Some(scope) = present.scope() {
+                    let retry_after = present.retry_after().map_or_else(
+                        || Duration::from_millis(present.reset_after()),
+                        Duration::from_secs,
+                    );
+
+                    self.emit(RatelimitEvent::Ratelimited {
+                        path: self.path.clone(),
+                        retry_after,
+                        scope,
+                    });
+                }
+
+                if present.remaining() == 0 {
+                    self.emit(RatelimitEvent::BucketExhausted {
+                        path: self.path.clone(),
+                        reset_after: Duration::from_millis(present.reset_after()),
+                    });
+                }
+
                 Some((present.limit(), present.remaining(), present.reset_after()))
             }
         };
@@ -269,6 +335,11 @@ impl BucketQueueTask {
         self.bucket.update(ratelimits);
     }
 
+    /// Send an event to the ratelimiter's event receivers, if there are any.
+    fn emit(&self, event: RatelimitEvent) {
+        let _res = self.events.send(event);
+    }
+
     /// Lock the global ratelimit for a specified duration.
     async fn lock_global(&self, wait: Duration) {
         tracing::debug!(path=?self.path, "request got global ratelimited");
@@ -0,0 +1,56 @@
+//! Global requests per second limit used by the [`super::InMemoryRatelimiter`].
+
+use std::{sync::Mutex, time::Duration};
+use tokio::time::{sleep, Instant};
+
+/// Length of a global ratelimit window.
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// Fixed window limiting the number of requests per second across all
+/// buckets.
+#[derive(Debug)]
+pub struct GlobalRatelimit {
+    /// Maximum number of requests per window.
+    limit: u32,
+    /// Start of the current window and the number of requests made within it.
+    window: Mutex<(Instant, u32)>,
+}
+
+impl GlobalRatelimit {
+    /// Create a new global ratelimit allowing `limit` requests per second.
+    pub fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            window: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    /// Wait until a request may be made without exceeding the limit.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut window = self.window.lock().expect("global window poisoned");
+                let elapsed = window.0.elapsed();
+
+                if elapsed >= WINDOW {
+                    *window = (Instant::now(), 0);
+                }
+
+                if window.1 < self.limit {
+                    window.1 += 1;
+
+                    return;
+                }
+
+                WINDOW.saturating_sub(elapsed)
+            };
+
+            tracing::debug!(
+                milliseconds=%wait.as_millis(),
+                "waiting for global ratelimit window to pass",
+            );
+
+            sleep(wait).await;
+        }
+    }
+}
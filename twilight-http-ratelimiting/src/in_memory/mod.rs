@@ -1,14 +1,19 @@
 //! In-memory based default [`Ratelimiter`] implementation used in `twilight-http`.
 
 mod bucket;
+mod global;
 
-use self::bucket::{Bucket, BucketQueueTask};
+use self::{
+    bucket::{Bucket, BucketQueueTask},
+    global::GlobalRatelimit,
+};
 use super::{
     ticket::{self, TicketNotifier},
-    Bucket as InfoBucket, Ratelimiter,
+    Ratelimiter,
 };
 use crate::{
-    request::Path, GetBucketFuture, GetTicketFuture, HasBucketFuture, IsGloballyLockedFuture,
+    event::RatelimitEvent, request::Path, GetBucketFuture, GetBucketsFuture, GetTicketFuture,
+    HasBucketFuture, IsGloballyLockedFuture,
 };
 use std::{
     collections::hash_map::{Entry, HashMap},
@@ -17,9 +22,19 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc, Mutex,
     },
-    time::Duration,
 };
-use tokio::sync::Mutex as AsyncMutex;
+use tokio::sync::{
+    broadcast::{self, Receiver, Sender},
+    Mutex as AsyncMutex,
+};
+
+/// Number of requests per second Discord allows by default across all
+/// buckets.
+pub const DEFAULT_GLOBAL_RATELIMIT: u32 = 50;
+
+/// Number of events buffered for receivers of
+/// [`InMemoryRatelimiter::events`].
+pub const EVENT_CAPACITY: usize = 64;
 
 /// Global lock. We use a pair to avoid actually locking the mutex every check.
 /// This allows futures to only wait on the global lock when a global ratelimit
@@ -53,13 +68,22 @@ impl GlobalLockPair {
 /// your own [`Ratelimiter`] that uses a shared storage backend
 /// or use the [HTTP proxy].
 ///
+/// Requests are limited to [`DEFAULT_GLOBAL_RATELIMIT`] requests per second
+/// across all buckets by default. Bots approved for a raised global ratelimit
+/// may increase it via [`with_global_ratelimit`].
+///
+/// [`with_global_ratelimit`]: Self::with_global_ratelimit
 /// [HTTP proxy]: https://twilight.rs/chapter_2_multi-serviced_approach.html#http-proxy-ratelimiting
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct InMemoryRatelimiter {
     /// Mapping of [`Path`]s to their associated [`Bucket`]s.
     buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    /// Sender of ratelimit events.
+    events: Sender<RatelimitEvent>,
     /// Global ratelimit data.
     global: Arc<GlobalLockPair>,
+    /// Global requests per second limit.
+    global_ratelimit: Arc<GlobalRatelimit>,
 }
 
 impl InMemoryRatelimiter {
@@ -72,6 +96,65 @@ impl InMemoryRatelimiter {
         Self::default()
     }
 
+    /// Create a new in-memory ratelimiter with a custom global ratelimit.
+    ///
+    /// Requests across all buckets are limited to the provided number of
+    /// requests per second. Only bots approved for a raised global ratelimit
+    /// by Discord should set this above [`DEFAULT_GLOBAL_RATELIMIT`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is zero.
+    #[must_use]
+    pub fn with_global_ratelimit(requests_per_second: u32) -> Self {
+        assert!(
+            requests_per_second > 0,
+            "global ratelimit must be at least one request per second"
+        );
+
+        Self {
+            buckets: Arc::default(),
+            events: broadcast::channel(EVENT_CAPACITY).0,
+            global: Arc::default(),
+            global_ratelimit: Arc::new(GlobalRatelimit::new(requests_per_second)),
+        }
+    }
+
+    /// Receiver of events about encountered ratelimits.
+    ///
+    /// Events are only sent while there is at least one receiver. Receivers
+    /// lagging more than [`EVENT_CAPACITY`] events behind miss the oldest
+    /// events.
+    ///
+    /// # Examples
+    ///
+    /// Log every `429 Too Many Requests` response:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http_ratelimiting::{InMemoryRatelimiter, RatelimitEvent};
+    ///
+    /// let ratelimiter = InMemoryRatelimiter::new();
+    /// let mut events = ratelimiter.events();
+    ///
+    /// // Pass a clone of the ratelimiter to the HTTP client here.
+    ///
+    /// while let Ok(event) = events.recv().await {
+    ///     if let RatelimitEvent::Ratelimited {
+    ///         path,
+    ///         retry_after,
+    ///         scope,
+    ///     } = event
+    ///     {
+    ///         println!("{scope} ratelimit on {path:?}, retrying after {retry_after:?}");
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn events(&self) -> Receiver<RatelimitEvent> {
+        self.events.subscribe()
+    }
+
     /// Enqueue the [`TicketNotifier`] to the [`Path`]'s [`Bucket`].
     ///
     /// Returns the new [`Bucket`] if none existed.
@@ -103,26 +186,34 @@ impl InMemoryRatelimiter {
     }
 }
 
+impl Default for InMemoryRatelimiter {
+    fn default() -> Self {
+        Self::with_global_ratelimit(DEFAULT_GLOBAL_RATELIMIT)
+    }
+}
+
 impl Ratelimiter for InMemoryRatelimiter {
     fn bucket(&self, path: &Path) -> GetBucketFuture {
-        self.buckets
+        let bucket = self
+            .buckets
             .lock()
             .expect("buckets poisoned")
             .get(path)
-            .map_or_else(
-                || Box::pin(future::ready(Ok(None))),
-                |bucket| {
-                    let started_at = bucket.started_at.lock().expect("bucket poisoned");
-                    let reset_after = Duration::from_millis(bucket.reset_after());
-
-                    Box::pin(future::ready(Ok(Some(InfoBucket::new(
-                        bucket.limit(),
-                        bucket.remaining(),
-                        reset_after,
-                        *started_at,
-                    )))))
-                },
-            )
+            .map(|bucket| bucket.info());
+
+        Box::pin(future::ready(Ok(bucket)))
+    }
+
+    fn buckets(&self) -> GetBucketsFuture {
+        let buckets = self
+            .buckets
+            .lock()
+            .expect("buckets poisoned")
+            .iter()
+            .map(|(path, bucket)| (path.clone(), bucket.info()))
+            .collect();
+
+        Box::pin(future::ready(Ok(buckets)))
     }
 
     fn is_globally_locked(&self) -> IsGloballyLockedFuture {
@@ -149,7 +240,9 @@ impl Ratelimiter for InMemoryRatelimiter {
                 BucketQueueTask::new(
                     bucket,
                     Arc::clone(&self.buckets),
+                    self.events.clone(),
                     Arc::clone(&self.global),
+                    Arc::clone(&self.global_ratelimit),
                     path,
                 )
                 .run(),
@@ -159,3 +252,131 @@ impl Ratelimiter for InMemoryRatelimiter {
         Box::pin(future::ready(Ok(rx)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryRatelimiter;
+    use crate::{
+        event::RatelimitEvent,
+        headers::{RatelimitHeaders, RatelimitScope},
+        request::Path,
+        Ratelimiter,
+    };
+    use std::{error::Error, time::Duration};
+    use tokio::time::Instant;
+
+    #[tokio::test]
+    async fn buckets() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        assert!(ratelimiter.buckets().await?.is_empty());
+
+        let path = Path::ChannelsIdMessages(1);
+        let sender = ratelimiter.wait_for_ticket(path.clone()).await?;
+        let headers = RatelimitHeaders::from_pairs(
+            [
+                ("x-ratelimit-bucket", "bucket".as_bytes()),
+                ("x-ratelimit-limit", "5".as_bytes()),
+                ("x-ratelimit-remaining", "4".as_bytes()),
+                ("x-ratelimit-reset", "1573795260.333".as_bytes()),
+                ("x-ratelimit-reset-after", "10.000".as_bytes()),
+            ]
+            .into_iter(),
+        )?;
+        sender.headers(Some(headers)).expect("ratelimiter is alive");
+        tokio::task::yield_now().await;
+
+        let buckets = ratelimiter.buckets().await?;
+        assert_eq!(1, buckets.len());
+
+        let (bucket_path, bucket) = &buckets[0];
+        assert_eq!(&path, bucket_path);
+        assert_eq!(5, bucket.limit());
+        assert_eq!(4, bucket.remaining());
+        assert!(bucket.reset_at().is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let mut events = ratelimiter.events();
+
+        let path = Path::ChannelsIdMessages(1);
+        let sender = ratelimiter.wait_for_ticket(path.clone()).await?;
+        let headers = RatelimitHeaders::from_pairs(
+            [
+                ("retry-after", "3".as_bytes()),
+                ("x-ratelimit-bucket", "bucket".as_bytes()),
+                ("x-ratelimit-limit", "5".as_bytes()),
+                ("x-ratelimit-remaining", "0".as_bytes()),
+                ("x-ratelimit-reset", "1573795260.333".as_bytes()),
+                ("x-ratelimit-reset-after", "2.500".as_bytes()),
+                ("x-ratelimit-scope", "shared".as_bytes()),
+            ]
+            .into_iter(),
+        )?;
+        sender.headers(Some(headers)).expect("ratelimiter is alive");
+
+        assert_eq!(
+            RatelimitEvent::Ratelimited {
+                path: path.clone(),
+                retry_after: Duration::from_secs(3),
+                scope: RatelimitScope::Shared,
+            },
+            events.recv().await?
+        );
+        assert_eq!(
+            RatelimitEvent::BucketExhausted {
+                path,
+                reset_after: Duration::from_millis(2500),
+            },
+            events.recv().await?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn global_ratelimit() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::with_global_ratelimit(2);
+        let start = Instant::now();
+
+        for channel_id in 1..=2 {
+            drop(
+                ratelimiter
+                    .wait_for_ticket(Path::ChannelsIdMessages(channel_id))
+                    .await?,
+            );
+        }
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        drop(
+            ratelimiter
+                .wait_for_ticket(Path::ChannelsIdMessages(3))
+                .await?,
+        );
+
+        assert!(start.elapsed() >= Duration::from_secs(1));
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn global_ratelimit_canceled() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::with_global_ratelimit(1);
+        let start = Instant::now();
+
+        drop(ratelimiter.ticket(Path::ChannelsIdMessages(1)).await?);
+        drop(
+            ratelimiter
+                .wait_for_ticket(Path::ChannelsIdMessages(2))
+                .await?,
+        );
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
 #![warn(
     clippy::missing_const_for_fn,
@@ -12,17 +13,24 @@
     clippy::unnecessary_wraps
 )]
 
+pub mod event;
 pub mod headers;
 pub mod in_memory;
+#[cfg(feature = "redis")]
+pub mod redis;
 pub mod request;
 pub mod ticket;
 
 pub use self::{
+    event::RatelimitEvent,
     headers::RatelimitHeaders,
     in_memory::InMemoryRatelimiter,
     request::{Method, Path},
 };
 
+#[cfg(feature = "redis")]
+pub use self::redis::RedisRatelimiter;
+
 use self::ticket::{TicketReceiver, TicketSender};
 use std::{
     error::Error,
@@ -33,6 +41,7 @@ use std::{
 };
 
 /// A bucket containing ratelimiting information for a [`Path`].
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Bucket {
     /// Total number of tickets allotted in a cycle.
     limit: u64,
@@ -92,6 +101,15 @@ impl Bucket {
         self.started_at
     }
 
+    /// When the bucket will refresh.
+    ///
+    /// May return `None` if the refresh timer has not been started yet.
+    #[must_use]
+    pub fn reset_at(&self) -> Option<Instant> {
+        self.started_at
+            .map(|started_at| started_at + self.reset_after)
+    }
+
     /// How long until the bucket will refresh.
     ///
     /// May return `None` if the refresh timer has not been started yet or
@@ -111,6 +129,10 @@ pub type GenericError = Box<dyn Error + Send + Sync>;
 pub type GetBucketFuture =
     Pin<Box<dyn Future<Output = Result<Option<Bucket>, GenericError>> + Send + 'static>>;
 
+/// Future returned by [`Ratelimiter::buckets`].
+pub type GetBucketsFuture =
+    Pin<Box<dyn Future<Output = Result<Vec<(Path, Bucket)>, GenericError>> + Send + 'static>>;
+
 /// Future returned by [`Ratelimiter::is_globally_locked`].
 pub type IsGloballyLockedFuture =
     Pin<Box<dyn Future<Output = Result<bool, GenericError>> + Send + 'static>>;
@@ -147,6 +169,16 @@ pub trait Ratelimiter: Debug + Send + Sync {
     /// Retrieve the basic information of the bucket for a given path.
     fn bucket(&self, path: &Path) -> GetBucketFuture;
 
+    /// Retrieve the basic information of all active buckets.
+    ///
+    /// Useful for monitoring purposes, such as displaying the current
+    /// ratelimit state on a dashboard.
+    ///
+    /// Defaults to no buckets, for ratelimiters unable to list them.
+    fn buckets(&self) -> GetBucketsFuture {
+        Box::pin(std::future::ready(Ok(Vec::new())))
+    }
+
     /// Whether the ratelimiter is currently globally locked.
     fn is_globally_locked(&self) -> IsGloballyLockedFuture;
 
@@ -0,0 +1,577 @@
+//! Redis based [`Ratelimiter`] implementation for multi-process bots.
+//!
+//! Bucket and global ratelimit state is stored in Redis so that multiple
+//! processes sharing one bot token coordinate with each other. Bucket keys
+//! expire when Discord's reported reset time elapses, so no background cleanup
+//! is necessary.
+//!
+//! Bucket keys are named after a hash of their [`Path`], so that webhook tokens
+//! contained in paths never end up in key names. Paths are additionally stored
+//! as JSON inside of buckets to [list] them, except for paths containing
+//! webhook tokens.
+//!
+//! [list]: crate::Ratelimiter::buckets
+
+use crate::{
+    headers::RatelimitHeaders,
+    in_memory::DEFAULT_GLOBAL_RATELIMIT,
+    request::Path,
+    ticket::{self, TicketNotifier},
+    Bucket, GenericError, GetBucketFuture, GetBucketsFuture, GetTicketFuture, HasBucketFuture,
+    IsGloballyLockedFuture, Ratelimiter,
+};
+use redis::{aio::ConnectionManager, AsyncCommands, RedisResult, Script};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::time::{sleep, timeout};
+
+/// Atomically try to acquire a ticket, reserving capacity of the global
+/// ratelimit.
+///
+/// `KEYS[1]` is the global lock key, `KEYS[2]` the bucket key, and `KEYS[3]`
+/// the key counting requests of the current one-second global window.
+/// `ARGV[1]` is the number of requests allowed per second. Returns the number
+/// of milliseconds to wait before trying again, or `0` if a ticket was
+/// acquired.
+const ACQUIRE_SCRIPT: &str = r"
+local global = redis.call('PTTL', KEYS[1])
+if global > 0 then
+    return global
+end
+
+local count = tonumber(redis.call('GET', KEYS[3]) or '0')
+if count >= tonumber(ARGV[1]) then
+    local ttl = redis.call('PTTL', KEYS[3])
+    if ttl > 0 then
+        return ttl
+    end
+
+    redis.call('DEL', KEYS[3])
+end
+
+local remaining = redis.call('HGET', KEYS[2], 'remaining')
+if remaining then
+    if tonumber(remaining) > 0 then
+        redis.call('HINCRBY', KEYS[2], 'remaining', -1)
+    else
+        local ttl = redis.call('PTTL', KEYS[2])
+        if ttl > 0 then
+            return ttl
+        end
+    end
+end
+
+if redis.call('INCR', KEYS[3]) == 1 then
+    redis.call('PEXPIRE', KEYS[3], 1000)
+end
+
+return 0
+";
+
+/// Default prefix of all keys managed by the ratelimiter.
+const DEFAULT_KEY_PREFIX: &str = "twilight-ratelimit";
+
+/// Timeout to wait for response headers after granting a ticket.
+const WAIT: Duration = Duration::from_secs(10);
+
+/// Ratelimiter storing ratelimit information in Redis.
+///
+/// All processes using the same Redis instance and [key prefix] share their
+/// ratelimit state, including the global ratelimit. Tickets reserve capacity
+/// of the global ratelimit when granted, limiting requests across all
+/// processes to [`DEFAULT_GLOBAL_RATELIMIT`] requests per second by default,
+/// configurable via [`global_ratelimit`]. Unlike the
+/// [`InMemoryRatelimiter`], tickets for the same [`Path`] are not guaranteed
+/// to be granted in order of retrieval across processes.
+///
+/// [`InMemoryRatelimiter`]: crate::InMemoryRatelimiter
+/// [`global_ratelimit`]: Self::global_ratelimit
+/// [key prefix]: Self::key_prefix
+#[derive(Clone)]
+pub struct RedisRatelimiter {
+    /// Connection to the Redis instance.
+    connection: ConnectionManager,
+    /// Global requests per second limit.
+    global_ratelimit: u32,
+    /// Prefix of all keys managed by this ratelimiter.
+    key_prefix: Arc<str>,
+    /// Script atomically acquiring a ticket.
+    script: Arc<Script>,
+}
+
+impl RedisRatelimiter {
+    /// Create a new Redis ratelimiter using the default key prefix.
+    pub fn new(connection: ConnectionManager) -> Self {
+        Self {
+            connection,
+            global_ratelimit: DEFAULT_GLOBAL_RATELIMIT,
+            key_prefix: Arc::from(DEFAULT_KEY_PREFIX),
+            script: Arc::new(Script::new(ACQUIRE_SCRIPT)),
+        }
+    }
+
+    /// Set the number of requests per second allowed across all buckets and
+    /// processes.
+    ///
+    /// Only bots approved for a raised global ratelimit by Discord should set
+    /// this above the default of [`DEFAULT_GLOBAL_RATELIMIT`]. Processes
+    /// sharing a key prefix must use the same global ratelimit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is zero.
+    #[must_use]
+    pub const fn global_ratelimit(mut self, requests_per_second: u32) -> Self {
+        assert!(
+            requests_per_second > 0,
+            "global ratelimit must be at least one request per second"
+        );
+
+        self.global_ratelimit = requests_per_second;
+
+        self
+    }
+
+    /// Set the prefix of all keys managed by the ratelimiter.
+    ///
+    /// Bots using separate tokens but sharing a Redis instance must use
+    /// distinct prefixes.
+    ///
+    /// Defaults to `twilight-ratelimit`.
+    #[must_use]
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = Arc::from(key_prefix.into());
+
+        self
+    }
+
+    /// Key of the global ratelimit.
+    fn global_key(&self) -> String {
+        format!("{}:global", self.key_prefix)
+    }
+
+    /// Key counting the requests of the current global ratelimit window.
+    fn global_count_key(&self) -> String {
+        format!("{}:global-count", self.key_prefix)
+    }
+
+    /// Key of the bucket associated with a [`Path`].
+    fn bucket_key(&self, path: &Path) -> String {
+        format!("{}:bucket:{:016x}", self.key_prefix, path_hash(path))
+    }
+
+    /// Retrieve the basic information of the bucket stored at a key and its
+    /// path, if stored.
+    async fn fetch_bucket(&self, bucket_key: &str) -> RedisResult<Option<(Bucket, Option<Path>)>> {
+        let mut connection = self.connection.clone();
+
+        let (fields, ttl): (HashMap<String, String>, i64) = redis::pipe()
+            .hgetall(bucket_key)
+            .pttl(bucket_key)
+            .query_async(&mut connection)
+            .await?;
+
+        let field = |name| fields.get(name).and_then(|value| value.parse().ok());
+        let (Some(limit), Some(remaining)) = (field("limit"), field("remaining")) else {
+            return Ok(None);
+        };
+
+        let path = fields
+            .get("path")
+            .and_then(|path| serde_json::from_str(path).ok());
+        let reset_after = Duration::from_millis(ttl.try_into().unwrap_or_default());
+        let bucket = Bucket::new(limit, remaining, reset_after, Some(Instant::now()));
+
+        Ok(Some((bucket, path)))
+    }
+
+    /// Wait until a ticket for the bucket has been acquired.
+    async fn acquire(&self, bucket_key: &str) -> RedisResult<()> {
+        let mut connection = self.connection.clone();
+        let global_key = self.global_key();
+        let global_count_key = self.global_count_key();
+
+        loop {
+            let wait: u64 = self
+                .script
+                .key(&global_key)
+                .key(bucket_key)
+                .key(&global_count_key)
+                .arg(self.global_ratelimit)
+                .invoke_async(&mut connection)
+                .await?;
+
+            if wait == 0 {
+                return Ok(());
+            }
+
+            tracing::debug!(milliseconds = wait, "waiting for ratelimit to pass");
+            sleep(Duration::from_millis(wait)).await;
+        }
+    }
+
+    /// Update the stored ratelimit state.
+    async fn handle_headers(
+        &self,
+        path: &Path,
+        bucket_key: &str,
+        headers: &RatelimitHeaders,
+    ) -> RedisResult<()> {
+        let mut connection = self.connection.clone();
+
+        match headers {
+            RatelimitHeaders::Global(global) => {
+                tracing::debug!("request got global ratelimited");
+
+                redis::cmd("SET")
+                    .arg(self.global_key())
+                    .arg(1)
+                    .arg("PX")
+                    .arg(global.retry_after().saturating_mul(1000).max(1))
+                    .query_async(&mut connection)
+                    .await
+            }
+            RatelimitHeaders::None => Ok(()),
+            RatelimitHeaders::Present(present) => {
+                let mut pipe = redis::pipe();
+                pipe.atomic()
+                    .hset_multiple(
+                        bucket_key,
+                        &[
+                            ("limit", present.limit()),
+                            ("remaining", present.remaining()),
+                        ],
+                    )
+                    .ignore();
+
+                if !contains_token(path) {
+                    pipe.hset(bucket_key, "path", encode_path(path)).ignore();
+                }
+
+                pipe.pexpire(
+                    bucket_key,
+                    present.reset_after().max(1).try_into().unwrap_or(i64::MAX),
+                )
+                .ignore()
+                .query_async(&mut connection)
+                .await
+            }
+        }
+    }
+
+    /// Grant the ticket once available and process the returned headers.
+    async fn process(self, path: Path, notifier: TicketNotifier) {
+        let bucket_key = self.bucket_key(&path);
+
+        if let Err(source) = self.acquire(&bucket_key).await {
+            tracing::warn!(%source, "failed to acquire ticket, granting anyway");
+        }
+
+        let Some(ticket_headers) = notifier.available() else {
+            return;
+        };
+
+        match timeout(WAIT, ticket_headers).await {
+            Ok(Ok(Some(headers))) => {
+                if let Err(source) = self.handle_headers(&path, &bucket_key, &headers).await {
+                    tracing::warn!(%source, "failed to update bucket");
+                }
+            }
+            Ok(Ok(None)) => {
+                tracing::debug!("request aborted");
+            }
+            Ok(Err(_)) => {
+                tracing::debug!("ticket channel closed");
+            }
+            Err(_) => {
+                tracing::debug!("receiver timed out");
+            }
+        }
+    }
+}
+
+impl Debug for RedisRatelimiter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RedisRatelimiter")
+            .field("global_ratelimit", &self.global_ratelimit)
+            .field("key_prefix", &self.key_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Ratelimiter for RedisRatelimiter {
+    fn bucket(&self, path: &Path) -> GetBucketFuture {
+        let ratelimiter = self.clone();
+        let bucket_key = self.bucket_key(path);
+
+        Box::pin(async move {
+            Ok(ratelimiter
+                .fetch_bucket(&bucket_key)
+                .await?
+                .map(|(bucket, _)| bucket))
+        })
+    }
+
+    fn buckets(&self) -> GetBucketsFuture {
+        let ratelimiter = self.clone();
+        let bucket_prefix = format!("{}:bucket:", self.key_prefix);
+
+        Box::pin(async move {
+            let mut connection = ratelimiter.connection.clone();
+            let mut bucket_keys = Vec::new();
+            let mut iter = connection
+                .scan_match::<_, String>(format!("{bucket_prefix}*"))
+                .await?;
+
+            while let Some(bucket_key) = iter.next_item().await {
+                bucket_keys.push(bucket_key);
+            }
+
+            drop(iter);
+
+            let mut buckets = Vec::with_capacity(bucket_keys.len());
+
+            for bucket_key in bucket_keys {
+                match ratelimiter.fetch_bucket(&bucket_key).await? {
+                    Some((bucket, Some(path))) => buckets.push((path, bucket)),
+                    Some((_, None)) => {
+                        tracing::debug!(%bucket_key, "skipping bucket of unlisted path");
+                    }
+                    None => {}
+                }
+            }
+
+            Ok(buckets)
+        })
+    }
+
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture {
+        let mut connection = self.connection.clone();
+        let global_key = self.global_key();
+
+        Box::pin(async move {
+            connection
+                .exists(global_key)
+                .await
+                .map_err(|source| Box::new(source) as GenericError)
+        })
+    }
+
+    fn has(&self, path: &Path) -> HasBucketFuture {
+        let mut connection = self.connection.clone();
+        let bucket_key = self.bucket_key(path);
+
+        Box::pin(async move {
+            connection
+                .exists(bucket_key)
+                .await
+                .map_err(|source| Box::new(source) as GenericError)
+        })
+    }
+
+    fn ticket(&self, path: Path) -> GetTicketFuture {
+        tracing::debug!("getting ticket for path: {path:?}");
+
+        let (tx, rx) = ticket::channel();
+
+        tokio::spawn(self.clone().process(path, tx));
+
+        Box::pin(std::future::ready(Ok(rx)))
+    }
+}
+
+/// Encode a [`Path`] as JSON, which is stable across versions of this crate.
+fn encode_path(path: &Path) -> String {
+    serde_json::to_string(path).expect("paths are serializable")
+}
+
+/// Whether a [`Path`] contains a webhook token, which must not be stored.
+const fn contains_token(path: &Path) -> bool {
+    matches!(
+        path,
+        Path::WebhooksIdToken(..) | Path::WebhooksIdTokenMessagesId(..)
+    )
+}
+
+/// Hash of a [`Path`] naming its bucket key.
+///
+/// Uses FNV-1a, as all processes must agree on the bucket keys regardless of
+/// their Rust version.
+fn path_hash(path: &Path) -> u64 {
+    encode_path(path)
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_path, path_hash, RedisRatelimiter};
+    use crate::{
+        headers::RatelimitHeaders,
+        request::{Method, Path},
+        Ratelimiter,
+    };
+    use redis::AsyncCommands;
+    use static_assertions::assert_impl_all;
+    use std::{
+        env,
+        error::Error,
+        fmt::Debug,
+        process,
+        time::{Duration, Instant},
+    };
+
+    assert_impl_all!(RedisRatelimiter: Clone, Debug, Ratelimiter, Send, Sync);
+
+    /// Connect to the Redis instance at `REDIS_URL`, using a key prefix unique
+    /// to the test and deleting its keys left by previous runs.
+    async fn ratelimiter(test: &str) -> Result<RedisRatelimiter, Box<dyn Error + Send + Sync>> {
+        let url = env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_owned());
+        let mut connection = redis::Client::open(url)?.get_connection_manager().await?;
+        let key_prefix = format!("twilight-test:{}:{test}", process::id());
+
+        let keys: Vec<String> = connection.keys(format!("{key_prefix}:*")).await?;
+        if !keys.is_empty() {
+            let () = connection.del(keys).await?;
+        }
+
+        Ok(RedisRatelimiter::new(connection).key_prefix(key_prefix))
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> RatelimitHeaders {
+        let pairs = pairs.iter().map(|(name, value)| (*name, value.as_bytes()));
+
+        RatelimitHeaders::from_pairs(pairs).unwrap()
+    }
+
+    #[ignore = "requires a Redis instance at REDIS_URL"]
+    #[tokio::test]
+    async fn bucket_exhausted() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = ratelimiter("bucket_exhausted").await?;
+        let path = Path::ChannelsIdMessages(1);
+
+        let tx = ratelimiter.wait_for_ticket(path.clone()).await?;
+        tx.headers(Some(headers(&[
+            ("x-ratelimit-bucket", "abc"),
+            ("x-ratelimit-limit", "1"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1700000000.000"),
+            ("x-ratelimit-reset-after", "0.500"),
+        ])))
+        .unwrap();
+
+        // Wait for the headers to be stored.
+        while !ratelimiter.has(&path).await? {
+            tokio::task::yield_now().await;
+        }
+
+        let bucket = ratelimiter.bucket(&path).await?.unwrap();
+        assert_eq!(1, bucket.limit());
+        assert_eq!(0, bucket.remaining());
+        assert_eq!(path, ratelimiter.buckets().await?[0].0);
+
+        let start = Instant::now();
+        drop(ratelimiter.wait_for_ticket(path.clone()).await?);
+        assert!(start.elapsed() >= Duration::from_millis(400));
+
+        drop(
+            ratelimiter
+                .wait_for_ticket(Path::ChannelsIdMessages(2))
+                .await?,
+        );
+
+        Ok(())
+    }
+
+    #[ignore = "requires a Redis instance at REDIS_URL"]
+    #[tokio::test]
+    async fn global_lock() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = ratelimiter("global_lock").await?;
+
+        let tx = ratelimiter
+            .wait_for_ticket(Path::ChannelsIdMessages(1))
+            .await?;
+        tx.headers(Some(headers(&[
+            ("retry-after", "1"),
+            ("x-ratelimit-global", "true"),
+        ])))
+        .unwrap();
+
+        while !ratelimiter.is_globally_locked().await? {
+            tokio::task::yield_now().await;
+        }
+
+        let start = Instant::now();
+        drop(
+            ratelimiter
+                .wait_for_ticket(Path::ChannelsIdMessages(2))
+                .await?,
+        );
+        assert!(start.elapsed() >= Duration::from_millis(500));
+        assert!(!ratelimiter.is_globally_locked().await?);
+
+        Ok(())
+    }
+
+    #[ignore = "requires a Redis instance at REDIS_URL"]
+    #[tokio::test]
+    async fn global_ratelimit() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = ratelimiter("global_ratelimit").await?.global_ratelimit(2);
+        // Another process sharing the key prefix and global ratelimit.
+        let other = ratelimiter.clone();
+        let start = Instant::now();
+
+        drop(
+            ratelimiter
+                .wait_for_ticket(Path::ChannelsIdMessages(1))
+                .await?,
+        );
+        drop(other.wait_for_ticket(Path::ChannelsIdMessages(2)).await?);
+        assert!(start.elapsed() < Duration::from_millis(500));
+
+        drop(
+            ratelimiter
+                .wait_for_ticket(Path::ChannelsIdMessages(3))
+                .await?,
+        );
+        assert!(start.elapsed() >= Duration::from_millis(900));
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_round_trip() {
+        let paths = [
+            Path::ApplicationsMe,
+            Path::ChannelsIdMessages(123),
+            Path::ChannelsIdMessagesId(Method::Delete, 123),
+            Path::GuildsTemplatesCode("abc".to_owned()),
+            Path::WebhooksIdTokenMessagesId(123, "token.with-chars_1".to_owned()),
+        ];
+
+        for path in paths {
+            let decoded = serde_json::from_str::<Path>(&encode_path(&path)).unwrap();
+            assert_eq!(path, decoded);
+        }
+
+        assert!(serde_json::from_str::<Path>(r#""Unknown""#).is_err());
+    }
+
+    #[test]
+    fn path_hash_stable() {
+        assert_eq!(
+            0x1de8_609e_3031_37b2,
+            path_hash(&Path::ChannelsIdMessages(123))
+        );
+        assert_ne!(
+            path_hash(&Path::WebhooksIdToken(123, "a".to_owned())),
+            path_hash(&Path::WebhooksIdToken(123, "b".to_owned()))
+        );
+    }
+}
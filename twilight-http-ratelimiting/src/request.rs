@@ -18,6 +18,7 @@ use std::{
 ///
 /// [method]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Methods
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "redis", derive(serde::Deserialize, serde::Serialize))]
 #[non_exhaustive]
 pub enum Method {
     /// Delete a resource.
@@ -115,6 +116,7 @@ pub enum PathParseErrorType {
 /// An enum representing a path, most useful for ratelimiting implementations.
 // If adding to this enum, be sure to add to the `TryFrom` impl.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "redis", derive(serde::Deserialize, serde::Serialize))]
 #[non_exhaustive]
 pub enum Path {
     /// Operating on global commands.
@@ -272,6 +274,10 @@ pub enum Path {
     OauthApplicationsMe,
     /// Operating on the current authorization's information.
     OauthMe,
+    /// Operating on OAuth2 access tokens.
+    OauthToken,
+    /// Revoking an OAuth2 access or refresh token.
+    OauthTokenRevoke,
     /// Operating on stage instances.
     StageInstances,
     /// Operating on sticker packs.
@@ -280,6 +286,8 @@ pub enum Path {
     Stickers,
     /// Operating on a sticker.
     UsersId,
+    /// Operating on the user's role connection to an application.
+    UsersIdApplicationsIdRoleConnection(u64),
     /// Operating on the user's private channels.
     UsersIdChannels,
     /// Operating on the user's connections.
@@ -458,7 +466,12 @@ impl FromStr for Path {
             ["stickers", _] => Stickers,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
             ["oauth2", "@me"] => OauthMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
             ["users", _] => UsersId,
+            ["users", _, "applications", id, "role-connection"] => {
+                UsersIdApplicationsIdRoleConnection(parse_id(id)?)
+            }
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
             ["users", _, "guilds"] => UsersIdGuilds,
@@ -105,6 +105,13 @@ impl TicketNotifier {
 
         Some(TicketHeaders(rx))
     }
+
+    /// Whether the consumer has dropped their [`TicketReceiver`] half, meaning
+    /// the ticket has been canceled.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
 }
 
 /// Channel receiver to wait for availability of a ratelimit ticket.
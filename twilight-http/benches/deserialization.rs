@@ -0,0 +1,60 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use std::hint::black_box;
+use twilight_model::guild::Member;
+
+fn members(count: u64) -> Vec<u8> {
+    let members = (1..=count)
+        .map(|id| {
+            serde_json::json!({
+                "avatar": null,
+                "communication_disabled_until": null,
+                "deaf": false,
+                "flags": 0,
+                "joined_at": "2015-04-26T06:26:56.936000+00:00",
+                "mute": false,
+                "nick": "member nickname",
+                "pending": false,
+                "roles": ["100", "200", "300"],
+                "user": {
+                    "accent_color": null,
+                    "avatar": "a_1234567890abcdef1234567890abcdef",
+                    "banner": null,
+                    "bot": false,
+                    "discriminator": "0",
+                    "global_name": "Global Name",
+                    "id": id.to_string(),
+                    "public_flags": 0,
+                    "username": "username",
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_vec(&members).unwrap()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("member list");
+
+    for count in [100, 1000] {
+        let bytes = members(count);
+
+        group.bench_with_input(BenchmarkId::new("serde_json", count), &bytes, |b, bytes| {
+            b.iter(|| serde_json::from_slice::<Vec<Member>>(black_box(bytes)).unwrap());
+        });
+
+        group.bench_with_input(BenchmarkId::new("simd-json", count), &bytes, |b, bytes| {
+            // simd-json parses in place, so give every iteration its own copy.
+            b.iter_batched(
+                || bytes.clone(),
+                |mut bytes| simd_json::from_slice::<Vec<Member>>(black_box(&mut bytes)).unwrap(),
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,952 @@
+use serde::{Deserialize, Serialize};
+
+/// Code of an error returned by the API.
+///
+/// Refer to [Discord Docs/JSON Error Codes] for more information.
+///
+/// [Discord Docs/JSON Error Codes]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u64", into = "u64")]
+pub enum ErrorCode {
+    /// General error, such as one not fitting a more specific code.
+    GeneralError,
+    /// Unknown account.
+    UnknownAccount,
+    /// Unknown application.
+    UnknownApplication,
+    /// Unknown channel.
+    UnknownChannel,
+    /// Unknown guild.
+    UnknownGuild,
+    /// Unknown integration.
+    UnknownIntegration,
+    /// Unknown invite.
+    UnknownInvite,
+    /// Unknown member.
+    UnknownMember,
+    /// Unknown message.
+    UnknownMessage,
+    /// Unknown permission overwrite.
+    UnknownPermissionOverwrite,
+    /// Unknown provider.
+    UnknownProvider,
+    /// Unknown role.
+    UnknownRole,
+    /// Unknown token.
+    UnknownToken,
+    /// Unknown user.
+    UnknownUser,
+    /// Unknown emoji.
+    UnknownEmoji,
+    /// Unknown webhook.
+    UnknownWebhook,
+    /// Unknown webhook service.
+    UnknownWebhookService,
+    /// Unknown session.
+    UnknownSession,
+    /// Unknown asset.
+    UnknownAsset,
+    /// Unknown ban.
+    UnknownBan,
+    /// Unknown SKU.
+    UnknownSku,
+    /// Unknown store listing.
+    UnknownStoreListing,
+    /// Unknown entitlement.
+    UnknownEntitlement,
+    /// Unknown build.
+    UnknownBuild,
+    /// Unknown lobby.
+    UnknownLobby,
+    /// Unknown branch.
+    UnknownBranch,
+    /// Unknown store directory layout.
+    UnknownStoreDirectoryLayout,
+    /// Unknown redistributable.
+    UnknownRedistributable,
+    /// Unknown gift code.
+    UnknownGiftCode,
+    /// Unknown stream.
+    UnknownStream,
+    /// Unknown premium server subscribe cooldown.
+    UnknownPremiumServerSubscribeCooldown,
+    /// Unknown guild template.
+    UnknownGuildTemplate,
+    /// Unknown discoverable server category.
+    UnknownDiscoverableServerCategory,
+    /// Unknown sticker.
+    UnknownSticker,
+    /// Unknown sticker pack.
+    UnknownStickerPack,
+    /// Unknown interaction.
+    UnknownInteraction,
+    /// Unknown application command.
+    UnknownApplicationCommand,
+    /// Unknown voice state.
+    UnknownVoiceState,
+    /// Unknown application command permissions.
+    UnknownApplicationCommandPermissions,
+    /// Unknown stage instance.
+    UnknownStageInstance,
+    /// Unknown guild member verification form.
+    UnknownGuildMemberVerificationForm,
+    /// Unknown guild welcome screen.
+    UnknownGuildWelcomeScreen,
+    /// Unknown guild scheduled event.
+    UnknownGuildScheduledEvent,
+    /// Unknown guild scheduled event user.
+    UnknownGuildScheduledEventUser,
+    /// Unknown tag.
+    UnknownTag,
+    /// Unknown sound.
+    UnknownSound,
+    /// Bots cannot use this endpoint.
+    BotsCannotUseEndpoint,
+    /// Only bots can use this endpoint.
+    OnlyBotsCanUseEndpoint,
+    /// Explicit content cannot be sent to the desired recipients.
+    ExplicitContentCannotBeSent,
+    /// Not authorized to perform this action on this application.
+    NotAuthorizedForApplication,
+    /// Action cannot be performed due to slowmode ratelimits.
+    SlowmodeRatelimited,
+    /// Only the owner of this account can perform this action.
+    OnlyOwnerCanPerformAction,
+    /// Message cannot be edited due to announcement ratelimits.
+    AnnouncementEditRatelimited,
+    /// Under minimum age.
+    UnderMinimumAge,
+    /// Channel has hit the write ratelimit.
+    ChannelWriteRatelimited,
+    /// Server has hit the write ratelimit.
+    ServerWriteRatelimited,
+    /// Stage topic, server name, server description, or channel names contain disallowed words.
+    DisallowedWords,
+    /// Guild premium subscription level too low.
+    GuildPremiumTierTooLow,
+    /// Maximum number of guilds reached (100).
+    MaximumGuilds,
+    /// Maximum number of friends reached (1000).
+    MaximumFriends,
+    /// Maximum number of pins reached for the channel (50).
+    MaximumPins,
+    /// Maximum number of recipients reached (10).
+    MaximumRecipients,
+    /// Maximum number of guild roles reached (250).
+    MaximumRoles,
+    /// Maximum number of webhooks reached (15).
+    MaximumWebhooks,
+    /// Maximum number of emojis reached.
+    MaximumEmojis,
+    /// Maximum number of reactions reached (20).
+    MaximumReactions,
+    /// Maximum number of group DMs reached (10).
+    MaximumGroupDms,
+    /// Maximum number of guild channels reached (500).
+    MaximumGuildChannels,
+    /// Maximum number of attachments in a message reached (10).
+    MaximumAttachments,
+    /// Maximum number of invites reached (1000).
+    MaximumInvites,
+    /// Maximum number of animated emojis reached.
+    MaximumAnimatedEmojis,
+    /// Maximum number of server members reached.
+    MaximumServerMembers,
+    /// Maximum number of server categories has been reached (5).
+    MaximumServerCategories,
+    /// Guild already has a template.
+    GuildAlreadyHasTemplate,
+    /// Maximum number of application commands reached.
+    MaximumApplicationCommands,
+    /// Maximum number of thread participants has been reached (1000).
+    MaximumThreadParticipants,
+    /// Maximum number of daily application command creates has been reached (200).
+    MaximumDailyApplicationCommandCreates,
+    /// Maximum number of bans for non-guild members have been exceeded.
+    MaximumNonGuildMemberBans,
+    /// Maximum number of bans fetches has been reached.
+    MaximumBanFetches,
+    /// Maximum number of uncompleted guild scheduled events reached (100).
+    MaximumUncompletedScheduledEvents,
+    /// Maximum number of stickers reached.
+    MaximumStickers,
+    /// Maximum number of prune requests has been reached.
+    MaximumPruneRequests,
+    /// Maximum number of guild widget settings updates has been reached.
+    MaximumWidgetSettingsUpdates,
+    /// Maximum number of soundboard sounds reached.
+    MaximumSoundboardSounds,
+    /// Maximum number of edits to messages older than 1 hour reached.
+    MaximumOldMessageEdits,
+    /// Maximum number of pinned threads in a forum channel has been reached.
+    MaximumPinnedThreads,
+    /// Maximum number of tags in a forum channel has been reached.
+    MaximumForumTags,
+    /// Bitrate is too high for channel of this type.
+    BitrateTooHigh,
+    /// Maximum number of premium emojis reached (25).
+    MaximumPremiumEmojis,
+    /// Maximum number of webhooks per guild reached (1000).
+    MaximumGuildWebhooks,
+    /// Maximum number of channel permission overwrites reached (1000).
+    MaximumChannelPermissionOverwrites,
+    /// The channels for this guild are too large.
+    GuildChannelsTooLarge,
+    /// Unauthorized; provide a valid token and try again.
+    Unauthorized,
+    /// Account must be verified to perform this action.
+    AccountVerificationRequired,
+    /// Opening direct messages too fast.
+    OpeningDirectMessagesTooFast,
+    /// Send messages has been temporarily disabled.
+    SendMessagesTemporarilyDisabled,
+    /// Request entity too large; try sending something smaller in size.
+    RequestEntityTooLarge,
+    /// This feature has been temporarily disabled server-side.
+    FeatureTemporarilyDisabled,
+    /// User is banned from this guild.
+    UserBannedFromGuild,
+    /// Connection has been revoked.
+    ConnectionRevoked,
+    /// Only consumable SKUs can be consumed.
+    OnlyConsumableSkus,
+    /// Only sandbox entitlements can be deleted.
+    OnlySandboxEntitlements,
+    /// Target user is not connected to voice.
+    TargetUserNotConnectedToVoice,
+    /// Message has already been crossposted.
+    MessageAlreadyCrossposted,
+    /// An application command with that name already exists.
+    ApplicationCommandNameExists,
+    /// Application interaction failed to send.
+    ApplicationInteractionFailedToSend,
+    /// Cannot send a message in a forum channel.
+    CannotSendMessageInForumChannel,
+    /// Interaction has already been acknowledged.
+    InteractionAlreadyAcknowledged,
+    /// Tag names must be unique.
+    TagNamesMustBeUnique,
+    /// Service resource is being ratelimited.
+    ServiceResourceRatelimited,
+    /// There are no tags available that can be set by non-moderators.
+    NoTagsAvailable,
+    /// A tag is required to create a forum post in this channel.
+    TagRequired,
+    /// An entitlement has already been granted for this resource.
+    EntitlementAlreadyGranted,
+    /// This interaction has hit the maximum number of follow up messages.
+    MaximumFollowUpMessages,
+    /// Cloudflare is blocking the request.
+    CloudflareBlockingRequest,
+    /// Missing access.
+    MissingAccess,
+    /// Invalid account type.
+    InvalidAccountType,
+    /// Cannot execute action on a DM channel.
+    CannotExecuteOnDmChannel,
+    /// Guild widget disabled.
+    GuildWidgetDisabled,
+    /// Cannot edit a message authored by another user.
+    CannotEditMessageByOtherUser,
+    /// Cannot send an empty message.
+    CannotSendEmptyMessage,
+    /// Cannot send messages to this user.
+    CannotSendMessagesToUser,
+    /// Cannot send messages in a non-text channel.
+    CannotSendMessagesInNonTextChannel,
+    /// Channel verification level is too high for you to gain access.
+    ChannelVerificationLevelTooHigh,
+    /// OAuth2 application does not have a bot.
+    OAuth2ApplicationHasNoBot,
+    /// OAuth2 application limit reached.
+    OAuth2ApplicationLimitReached,
+    /// Invalid OAuth2 state.
+    InvalidOAuth2State,
+    /// You lack permissions to perform that action.
+    MissingPermissions,
+    /// Invalid authentication token provided.
+    InvalidAuthenticationToken,
+    /// Note was too long.
+    NoteTooLong,
+    /// Provided too few or too many messages to delete.
+    InvalidMessageDeleteCount,
+    /// Invalid MFA level.
+    InvalidMfaLevel,
+    /// A message can only be pinned to the channel it was sent in.
+    CannotPinMessageInOtherChannel,
+    /// Invite code was either invalid or taken.
+    InvalidInviteCode,
+    /// Cannot execute action on a system message.
+    CannotExecuteOnSystemMessage,
+    /// Cannot execute action on this channel type.
+    CannotExecuteOnChannelType,
+    /// Invalid OAuth2 access token provided.
+    InvalidOAuth2AccessToken,
+    /// Missing required OAuth2 scope.
+    MissingOAuth2Scope,
+    /// Invalid webhook token provided.
+    InvalidWebhookToken,
+    /// Invalid role.
+    InvalidRole,
+    /// Invalid recipients.
+    InvalidRecipients,
+    /// A message provided was too old to bulk delete.
+    MessageTooOldToBulkDelete,
+    /// Invalid form body or invalid `Content-Type` provided.
+    InvalidFormBody,
+    /// An invite was accepted to a guild the application's bot is not in.
+    InviteAcceptedToGuildWithoutBot,
+    /// Invalid activity action.
+    InvalidActivityAction,
+    /// Invalid API version provided.
+    InvalidApiVersion,
+    /// File uploaded exceeds the maximum size.
+    FileTooLarge,
+    /// Invalid file uploaded.
+    InvalidFileUploaded,
+    /// Cannot self-redeem this gift.
+    CannotSelfRedeemGift,
+    /// Invalid guild.
+    InvalidGuild,
+    /// Invalid SKU.
+    InvalidSku,
+    /// Invalid request origin.
+    InvalidRequestOrigin,
+    /// Invalid message type.
+    InvalidMessageType,
+    /// Payment source required to redeem gift.
+    PaymentSourceRequired,
+    /// Cannot modify a system webhook.
+    CannotModifySystemWebhook,
+    /// Cannot delete a channel required for community guilds.
+    CannotDeleteRequiredCommunityChannel,
+    /// Cannot edit stickers within a message.
+    CannotEditMessageStickers,
+    /// Invalid sticker sent.
+    InvalidSticker,
+    /// Tried to perform an operation on an archived thread.
+    ThreadArchived,
+    /// Invalid thread notification settings.
+    InvalidThreadNotificationSettings,
+    /// `before` value is earlier than the thread creation date.
+    BeforeEarlierThanThreadCreation,
+    /// Community server channels must be text channels.
+    CommunityChannelsMustBeText,
+    /// The entity type of the event is different from the entity being started.
+    EventEntityTypeMismatch,
+    /// This server is not available in your location.
+    ServerNotAvailableInLocation,
+    /// This server needs monetization enabled to perform this action.
+    MonetizationRequired,
+    /// This server needs more boosts to perform this action.
+    MoreBoostsRequired,
+    /// The request body contains invalid JSON.
+    InvalidJson,
+    /// The provided file is invalid.
+    InvalidFile,
+    /// The provided file type is invalid.
+    InvalidFileType,
+    /// The provided file duration exceeds maximum of 5.2 seconds.
+    FileDurationTooLong,
+    /// Owner cannot be pending member.
+    OwnerCannotBePendingMember,
+    /// Ownership cannot be transferred to a bot user.
+    OwnershipCannotBeTransferredToBot,
+    /// Failed to resize asset below the maximum size.
+    FailedToResizeAsset,
+    /// Cannot mix subscription and non subscription roles for an emoji.
+    CannotMixSubscriptionRoles,
+    /// Cannot convert between premium emoji and normal emoji.
+    CannotConvertEmojiType,
+    /// Uploaded file not found.
+    UploadedFileNotFound,
+    /// The specified emoji is invalid.
+    InvalidEmojiSpecified,
+    /// Voice messages do not support additional content.
+    VoiceMessagesWithAdditionalContent,
+    /// Voice messages must have a single audio attachment.
+    VoiceMessagesWithMultipleAudioAttachments,
+    /// Voice messages must have supporting metadata.
+    VoiceMessagesWithoutMetadata,
+    /// Voice messages cannot be edited.
+    CannotEditVoiceMessage,
+    /// Cannot delete guild subscription integration.
+    CannotDeleteGuildSubscriptionIntegration,
+    /// You cannot send voice messages in this channel.
+    CannotSendVoiceMessagesInChannel,
+    /// The user account must first be verified.
+    UserAccountMustBeVerified,
+    /// The provided file does not have a valid duration.
+    FileDurationInvalid,
+    /// You do not have permission to send this sticker.
+    NoPermissionToSendSticker,
+    /// Two factor is required for this operation.
+    TwoFactorRequired,
+    /// No users with `DiscordTag` exist.
+    NoUsersWithDiscordTag,
+    /// Reaction was blocked.
+    ReactionBlocked,
+    /// User cannot use burst reactions.
+    CannotUseBurstReactions,
+    /// Application not yet available.
+    ApplicationNotYetAvailable,
+    /// API resource is currently overloaded.
+    ApiResourceOverloaded,
+    /// The stage is already open.
+    StageAlreadyOpen,
+    /// Cannot reply without permission to read message history.
+    CannotReplyWithoutReadMessageHistory,
+    /// A thread has already been created for this message.
+    ThreadAlreadyCreated,
+    /// Thread is locked.
+    ThreadLocked,
+    /// Maximum number of active threads reached.
+    MaximumActiveThreads,
+    /// Maximum number of active announcement threads reached.
+    MaximumActiveAnnouncementThreads,
+    /// Invalid JSON for uploaded Lottie file.
+    InvalidLottieJson,
+    /// Uploaded Lotties cannot contain rasterized images such as PNG or JPEG.
+    LottieContainsRasterizedImages,
+    /// Sticker maximum framerate exceeded.
+    StickerMaximumFramerateExceeded,
+    /// Sticker frame count exceeds maximum of 1000 frames.
+    StickerFrameCountTooLarge,
+    /// Lottie animation maximum dimensions exceeded.
+    LottieDimensionsTooLarge,
+    /// Sticker frame rate is either too small or too large.
+    StickerFrameRateInvalid,
+    /// Sticker animation duration exceeds maximum of 5 seconds.
+    StickerAnimationTooLong,
+    /// Cannot update a finished event.
+    CannotUpdateFinishedEvent,
+    /// Failed to create stage needed for stage event.
+    FailedToCreateStageForEvent,
+    /// Message was blocked by automatic moderation.
+    BlockedByAutoModeration,
+    /// Title was blocked by automatic moderation.
+    TitleBlockedByAutoModeration,
+    /// Webhooks posted to forum channels must have a thread name or thread ID.
+    ForumWebhookMissingThread,
+    /// Webhooks posted to forum channels cannot have both a thread name and thread ID.
+    ForumWebhookThreadNameAndId,
+    /// Webhooks can only create threads in forum channels.
+    WebhooksCanOnlyCreateThreadsInForums,
+    /// Webhook services cannot be used in forum channels.
+    WebhookServicesCannotBeUsedInForums,
+    /// Message blocked by harmful links filter.
+    BlockedByHarmfulLinksFilter,
+    /// Cannot enable onboarding, requirements are not met.
+    CannotEnableOnboarding,
+    /// Cannot update onboarding while below requirements.
+    CannotUpdateOnboarding,
+    /// Failed to ban users.
+    FailedToBanUsers,
+    /// Poll voting blocked.
+    PollVotingBlocked,
+    /// Poll expired.
+    PollExpired,
+    /// Invalid channel type for poll creation.
+    InvalidPollChannelType,
+    /// Cannot edit a poll message.
+    CannotEditPollMessage,
+    /// Cannot use an emoji included with the poll.
+    CannotUseEmojiInPoll,
+    /// Cannot expire a non-poll message.
+    CannotExpireNonPollMessage,
+    /// Variant value is unknown to the library.
+    Unknown(u64),
+}
+
+impl From<u64> for ErrorCode {
+    #[allow(clippy::too_many_lines)]
+    fn from(value: u64) -> Self {
+        match value {
+            0 => Self::GeneralError,
+            10001 => Self::UnknownAccount,
+            10002 => Self::UnknownApplication,
+            10003 => Self::UnknownChannel,
+            10004 => Self::UnknownGuild,
+            10005 => Self::UnknownIntegration,
+            10006 => Self::UnknownInvite,
+            10007 => Self::UnknownMember,
+            10008 => Self::UnknownMessage,
+            10009 => Self::UnknownPermissionOverwrite,
+            10010 => Self::UnknownProvider,
+            10011 => Self::UnknownRole,
+            10012 => Self::UnknownToken,
+            10013 => Self::UnknownUser,
+            10014 => Self::UnknownEmoji,
+            10015 => Self::UnknownWebhook,
+            10016 => Self::UnknownWebhookService,
+            10020 => Self::UnknownSession,
+            10021 => Self::UnknownAsset,
+            10026 => Self::UnknownBan,
+            10027 => Self::UnknownSku,
+            10028 => Self::UnknownStoreListing,
+            10029 => Self::UnknownEntitlement,
+            10030 => Self::UnknownBuild,
+            10031 => Self::UnknownLobby,
+            10032 => Self::UnknownBranch,
+            10033 => Self::UnknownStoreDirectoryLayout,
+            10036 => Self::UnknownRedistributable,
+            10038 => Self::UnknownGiftCode,
+            10049 => Self::UnknownStream,
+            10050 => Self::UnknownPremiumServerSubscribeCooldown,
+            10057 => Self::UnknownGuildTemplate,
+            10059 => Self::UnknownDiscoverableServerCategory,
+            10060 => Self::UnknownSticker,
+            10061 => Self::UnknownStickerPack,
+            10062 => Self::UnknownInteraction,
+            10063 => Self::UnknownApplicationCommand,
+            10065 => Self::UnknownVoiceState,
+            10066 => Self::UnknownApplicationCommandPermissions,
+            10067 => Self::UnknownStageInstance,
+            10068 => Self::UnknownGuildMemberVerificationForm,
+            10069 => Self::UnknownGuildWelcomeScreen,
+            10070 => Self::UnknownGuildScheduledEvent,
+            10071 => Self::UnknownGuildScheduledEventUser,
+            10087 => Self::UnknownTag,
+            10097 => Self::UnknownSound,
+            20001 => Self::BotsCannotUseEndpoint,
+            20002 => Self::OnlyBotsCanUseEndpoint,
+            20009 => Self::ExplicitContentCannotBeSent,
+            20012 => Self::NotAuthorizedForApplication,
+            20016 => Self::SlowmodeRatelimited,
+            20018 => Self::OnlyOwnerCanPerformAction,
+            20022 => Self::AnnouncementEditRatelimited,
+            20024 => Self::UnderMinimumAge,
+            20028 => Self::ChannelWriteRatelimited,
+            20029 => Self::ServerWriteRatelimited,
+            20031 => Self::DisallowedWords,
+            20035 => Self::GuildPremiumTierTooLow,
+            30001 => Self::MaximumGuilds,
+            30002 => Self::MaximumFriends,
+            30003 => Self::MaximumPins,
+            30004 => Self::MaximumRecipients,
+            30005 => Self::MaximumRoles,
+            30007 => Self::MaximumWebhooks,
+            30008 => Self::MaximumEmojis,
+            30010 => Self::MaximumReactions,
+            30011 => Self::MaximumGroupDms,
+            30013 => Self::MaximumGuildChannels,
+            30015 => Self::MaximumAttachments,
+            30016 => Self::MaximumInvites,
+            30018 => Self::MaximumAnimatedEmojis,
+            30019 => Self::MaximumServerMembers,
+            30030 => Self::MaximumServerCategories,
+            30031 => Self::GuildAlreadyHasTemplate,
+            30032 => Self::MaximumApplicationCommands,
+            30033 => Self::MaximumThreadParticipants,
+            30034 => Self::MaximumDailyApplicationCommandCreates,
+            30035 => Self::MaximumNonGuildMemberBans,
+            30037 => Self::MaximumBanFetches,
+            30038 => Self::MaximumUncompletedScheduledEvents,
+            30039 => Self::MaximumStickers,
+            30040 => Self::MaximumPruneRequests,
+            30042 => Self::MaximumWidgetSettingsUpdates,
+            30045 => Self::MaximumSoundboardSounds,
+            30046 => Self::MaximumOldMessageEdits,
+            30047 => Self::MaximumPinnedThreads,
+            30048 => Self::MaximumForumTags,
+            30052 => Self::BitrateTooHigh,
+            30056 => Self::MaximumPremiumEmojis,
+            30058 => Self::MaximumGuildWebhooks,
+            30060 => Self::MaximumChannelPermissionOverwrites,
+            30061 => Self::GuildChannelsTooLarge,
+            40001 => Self::Unauthorized,
+            40002 => Self::AccountVerificationRequired,
+            40003 => Self::OpeningDirectMessagesTooFast,
+            40004 => Self::SendMessagesTemporarilyDisabled,
+            40005 => Self::RequestEntityTooLarge,
+            40006 => Self::FeatureTemporarilyDisabled,
+            40007 => Self::UserBannedFromGuild,
+            40012 => Self::ConnectionRevoked,
+            40018 => Self::OnlyConsumableSkus,
+            40019 => Self::OnlySandboxEntitlements,
+            40032 => Self::TargetUserNotConnectedToVoice,
+            40033 => Self::MessageAlreadyCrossposted,
+            40041 => Self::ApplicationCommandNameExists,
+            40043 => Self::ApplicationInteractionFailedToSend,
+            40058 => Self::CannotSendMessageInForumChannel,
+            40060 => Self::InteractionAlreadyAcknowledged,
+            40061 => Self::TagNamesMustBeUnique,
+            40062 => Self::ServiceResourceRatelimited,
+            40066 => Self::NoTagsAvailable,
+            40067 => Self::TagRequired,
+            40074 => Self::EntitlementAlreadyGranted,
+            40094 => Self::MaximumFollowUpMessages,
+            40333 => Self::CloudflareBlockingRequest,
+            50001 => Self::MissingAccess,
+            50002 => Self::InvalidAccountType,
+            50003 => Self::CannotExecuteOnDmChannel,
+            50004 => Self::GuildWidgetDisabled,
+            50005 => Self::CannotEditMessageByOtherUser,
+            50006 => Self::CannotSendEmptyMessage,
+            50007 => Self::CannotSendMessagesToUser,
+            50008 => Self::CannotSendMessagesInNonTextChannel,
+            50009 => Self::ChannelVerificationLevelTooHigh,
+            50010 => Self::OAuth2ApplicationHasNoBot,
+            50011 => Self::OAuth2ApplicationLimitReached,
+            50012 => Self::InvalidOAuth2State,
+            50013 => Self::MissingPermissions,
+            50014 => Self::InvalidAuthenticationToken,
+            50015 => Self::NoteTooLong,
+            50016 => Self::InvalidMessageDeleteCount,
+            50017 => Self::InvalidMfaLevel,
+            50019 => Self::CannotPinMessageInOtherChannel,
+            50020 => Self::InvalidInviteCode,
+            50021 => Self::CannotExecuteOnSystemMessage,
+            50024 => Self::CannotExecuteOnChannelType,
+            50025 => Self::InvalidOAuth2AccessToken,
+            50026 => Self::MissingOAuth2Scope,
+            50027 => Self::InvalidWebhookToken,
+            50028 => Self::InvalidRole,
+            50033 => Self::InvalidRecipients,
+            50034 => Self::MessageTooOldToBulkDelete,
+            50035 => Self::InvalidFormBody,
+            50036 => Self::InviteAcceptedToGuildWithoutBot,
+            50039 => Self::InvalidActivityAction,
+            50041 => Self::InvalidApiVersion,
+            50045 => Self::FileTooLarge,
+            50046 => Self::InvalidFileUploaded,
+            50054 => Self::CannotSelfRedeemGift,
+            50055 => Self::InvalidGuild,
+            50057 => Self::InvalidSku,
+            50067 => Self::InvalidRequestOrigin,
+            50068 => Self::InvalidMessageType,
+            50070 => Self::PaymentSourceRequired,
+            50073 => Self::CannotModifySystemWebhook,
+            50074 => Self::CannotDeleteRequiredCommunityChannel,
+            50080 => Self::CannotEditMessageStickers,
+            50081 => Self::InvalidSticker,
+            50083 => Self::ThreadArchived,
+            50084 => Self::InvalidThreadNotificationSettings,
+            50085 => Self::BeforeEarlierThanThreadCreation,
+            50086 => Self::CommunityChannelsMustBeText,
+            50091 => Self::EventEntityTypeMismatch,
+            50095 => Self::ServerNotAvailableInLocation,
+            50097 => Self::MonetizationRequired,
+            50101 => Self::MoreBoostsRequired,
+            50109 => Self::InvalidJson,
+            50110 => Self::InvalidFile,
+            50123 => Self::InvalidFileType,
+            50124 => Self::FileDurationTooLong,
+            50131 => Self::OwnerCannotBePendingMember,
+            50132 => Self::OwnershipCannotBeTransferredToBot,
+            50138 => Self::FailedToResizeAsset,
+            50144 => Self::CannotMixSubscriptionRoles,
+            50145 => Self::CannotConvertEmojiType,
+            50146 => Self::UploadedFileNotFound,
+            50151 => Self::InvalidEmojiSpecified,
+            50159 => Self::VoiceMessagesWithAdditionalContent,
+            50160 => Self::VoiceMessagesWithMultipleAudioAttachments,
+            50161 => Self::VoiceMessagesWithoutMetadata,
+            50162 => Self::CannotEditVoiceMessage,
+            50163 => Self::CannotDeleteGuildSubscriptionIntegration,
+            50173 => Self::CannotSendVoiceMessagesInChannel,
+            50178 => Self::UserAccountMustBeVerified,
+            50192 => Self::FileDurationInvalid,
+            50600 => Self::NoPermissionToSendSticker,
+            60003 => Self::TwoFactorRequired,
+            80004 => Self::NoUsersWithDiscordTag,
+            90001 => Self::ReactionBlocked,
+            90002 => Self::CannotUseBurstReactions,
+            110_001 => Self::ApplicationNotYetAvailable,
+            130_000 => Self::ApiResourceOverloaded,
+            150_006 => Self::StageAlreadyOpen,
+            160_002 => Self::CannotReplyWithoutReadMessageHistory,
+            160_004 => Self::ThreadAlreadyCreated,
+            160_005 => Self::ThreadLocked,
+            160_006 => Self::MaximumActiveThreads,
+            160_007 => Self::MaximumActiveAnnouncementThreads,
+            170_001 => Self::InvalidLottieJson,
+            170_002 => Self::LottieContainsRasterizedImages,
+            170_003 => Self::StickerMaximumFramerateExceeded,
+            170_004 => Self::StickerFrameCountTooLarge,
+            170_005 => Self::LottieDimensionsTooLarge,
+            170_006 => Self::StickerFrameRateInvalid,
+            170_007 => Self::StickerAnimationTooLong,
+            180_000 => Self::CannotUpdateFinishedEvent,
+            180_002 => Self::FailedToCreateStageForEvent,
+            200_000 => Self::BlockedByAutoModeration,
+            200_001 => Self::TitleBlockedByAutoModeration,
+            220_001 => Self::ForumWebhookMissingThread,
+            220_002 => Self::ForumWebhookThreadNameAndId,
+            220_003 => Self::WebhooksCanOnlyCreateThreadsInForums,
+            220_004 => Self::WebhookServicesCannotBeUsedInForums,
+            240_000 => Self::BlockedByHarmfulLinksFilter,
+            350_000 => Self::CannotEnableOnboarding,
+            350_001 => Self::CannotUpdateOnboarding,
+            500_000 => Self::FailedToBanUsers,
+            520_000 => Self::PollVotingBlocked,
+            520_001 => Self::PollExpired,
+            520_002 => Self::InvalidPollChannelType,
+            520_003 => Self::CannotEditPollMessage,
+            520_004 => Self::CannotUseEmojiInPoll,
+            520_006 => Self::CannotExpireNonPollMessage,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ErrorCode> for u64 {
+    #[allow(clippy::too_many_lines)]
+    fn from(value: ErrorCode) -> Self {
+        match value {
+            ErrorCode::GeneralError => 0,
+            ErrorCode::UnknownAccount => 10001,
+            ErrorCode::UnknownApplication => 10002,
+            ErrorCode::UnknownChannel => 10003,
+            ErrorCode::UnknownGuild => 10004,
+            ErrorCode::UnknownIntegration => 10005,
+            ErrorCode::UnknownInvite => 10006,
+            ErrorCode::UnknownMember => 10007,
+            ErrorCode::UnknownMessage => 10008,
+            ErrorCode::UnknownPermissionOverwrite => 10009,
+            ErrorCode::UnknownProvider => 10010,
+            ErrorCode::UnknownRole => 10011,
+            ErrorCode::UnknownToken => 10012,
+            ErrorCode::UnknownUser => 10013,
+            ErrorCode::UnknownEmoji => 10014,
+            ErrorCode::UnknownWebhook => 10015,
+            ErrorCode::UnknownWebhookService => 10016,
+            ErrorCode::UnknownSession => 10020,
+            ErrorCode::UnknownAsset => 10021,
+            ErrorCode::UnknownBan => 10026,
+            ErrorCode::UnknownSku => 10027,
+            ErrorCode::UnknownStoreListing => 10028,
+            ErrorCode::UnknownEntitlement => 10029,
+            ErrorCode::UnknownBuild => 10030,
+            ErrorCode::UnknownLobby => 10031,
+            ErrorCode::UnknownBranch => 10032,
+            ErrorCode::UnknownStoreDirectoryLayout => 10033,
+            ErrorCode::UnknownRedistributable => 10036,
+            ErrorCode::UnknownGiftCode => 10038,
+            ErrorCode::UnknownStream => 10049,
+            ErrorCode::UnknownPremiumServerSubscribeCooldown => 10050,
+            ErrorCode::UnknownGuildTemplate => 10057,
+            ErrorCode::UnknownDiscoverableServerCategory => 10059,
+            ErrorCode::UnknownSticker => 10060,
+            ErrorCode::UnknownStickerPack => 10061,
+            ErrorCode::UnknownInteraction => 10062,
+            ErrorCode::UnknownApplicationCommand => 10063,
+            ErrorCode::UnknownVoiceState => 10065,
+            ErrorCode::UnknownApplicationCommandPermissions => 10066,
+            ErrorCode::UnknownStageInstance => 10067,
+            ErrorCode::UnknownGuildMemberVerificationForm => 10068,
+            ErrorCode::UnknownGuildWelcomeScreen => 10069,
+            ErrorCode::UnknownGuildScheduledEvent => 10070,
+            ErrorCode::UnknownGuildScheduledEventUser => 10071,
+            ErrorCode::UnknownTag => 10087,
+            ErrorCode::UnknownSound => 10097,
+            ErrorCode::BotsCannotUseEndpoint => 20001,
+            ErrorCode::OnlyBotsCanUseEndpoint => 20002,
+            ErrorCode::ExplicitContentCannotBeSent => 20009,
+            ErrorCode::NotAuthorizedForApplication => 20012,
+            ErrorCode::SlowmodeRatelimited => 20016,
+            ErrorCode::OnlyOwnerCanPerformAction => 20018,
+            ErrorCode::AnnouncementEditRatelimited => 20022,
+            ErrorCode::UnderMinimumAge => 20024,
+            ErrorCode::ChannelWriteRatelimited => 20028,
+            ErrorCode::ServerWriteRatelimited => 20029,
+            ErrorCode::DisallowedWords => 20031,
+            ErrorCode::GuildPremiumTierTooLow => 20035,
+            ErrorCode::MaximumGuilds => 30001,
+            ErrorCode::MaximumFriends => 30002,
+            ErrorCode::MaximumPins => 30003,
+            ErrorCode::MaximumRecipients => 30004,
+            ErrorCode::MaximumRoles => 30005,
+            ErrorCode::MaximumWebhooks => 30007,
+            ErrorCode::MaximumEmojis => 30008,
+            ErrorCode::MaximumReactions => 30010,
+            ErrorCode::MaximumGroupDms => 30011,
+            ErrorCode::MaximumGuildChannels => 30013,
+            ErrorCode::MaximumAttachments => 30015,
+            ErrorCode::MaximumInvites => 30016,
+            ErrorCode::MaximumAnimatedEmojis => 30018,
+            ErrorCode::MaximumServerMembers => 30019,
+            ErrorCode::MaximumServerCategories => 30030,
+            ErrorCode::GuildAlreadyHasTemplate => 30031,
+            ErrorCode::MaximumApplicationCommands => 30032,
+            ErrorCode::MaximumThreadParticipants => 30033,
+            ErrorCode::MaximumDailyApplicationCommandCreates => 30034,
+            ErrorCode::MaximumNonGuildMemberBans => 30035,
+            ErrorCode::MaximumBanFetches => 30037,
+            ErrorCode::MaximumUncompletedScheduledEvents => 30038,
+            ErrorCode::MaximumStickers => 30039,
+            ErrorCode::MaximumPruneRequests => 30040,
+            ErrorCode::MaximumWidgetSettingsUpdates => 30042,
+            ErrorCode::MaximumSoundboardSounds => 30045,
+            ErrorCode::MaximumOldMessageEdits => 30046,
+            ErrorCode::MaximumPinnedThreads => 30047,
+            ErrorCode::MaximumForumTags => 30048,
+            ErrorCode::BitrateTooHigh => 30052,
+            ErrorCode::MaximumPremiumEmojis => 30056,
+            ErrorCode::MaximumGuildWebhooks => 30058,
+            ErrorCode::MaximumChannelPermissionOverwrites => 30060,
+            ErrorCode::GuildChannelsTooLarge => 30061,
+            ErrorCode::Unauthorized => 40001,
+            ErrorCode::AccountVerificationRequired => 40002,
+            ErrorCode::OpeningDirectMessagesTooFast => 40003,
+            ErrorCode::SendMessagesTemporarilyDisabled => 40004,
+            ErrorCode::RequestEntityTooLarge => 40005,
+            ErrorCode::FeatureTemporarilyDisabled => 40006,
+            ErrorCode::UserBannedFromGuild => 40007,
+            ErrorCode::ConnectionRevoked => 40012,
+            ErrorCode::OnlyConsumableSkus => 40018,
+            ErrorCode::OnlySandboxEntitlements => 40019,
+            ErrorCode::TargetUserNotConnectedToVoice => 40032,
+            ErrorCode::MessageAlreadyCrossposted => 40033,
+            ErrorCode::ApplicationCommandNameExists => 40041,
+            ErrorCode::ApplicationInteractionFailedToSend => 40043,
+            ErrorCode::CannotSendMessageInForumChannel => 40058,
+            ErrorCode::InteractionAlreadyAcknowledged => 40060,
+            ErrorCode::TagNamesMustBeUnique => 40061,
+            ErrorCode::ServiceResourceRatelimited => 40062,
+            ErrorCode::NoTagsAvailable => 40066,
+            ErrorCode::TagRequired => 40067,
+            ErrorCode::EntitlementAlreadyGranted => 40074,
+            ErrorCode::MaximumFollowUpMessages => 40094,
+            ErrorCode::CloudflareBlockingRequest => 40333,
+            ErrorCode::MissingAccess => 50001,
+            ErrorCode::InvalidAccountType => 50002,
+            ErrorCode::CannotExecuteOnDmChannel => 50003,
+            ErrorCode::GuildWidgetDisabled => 50004,
+            ErrorCode::CannotEditMessageByOtherUser => 50005,
+            ErrorCode::CannotSendEmptyMessage => 50006,
+            ErrorCode::CannotSendMessagesToUser => 50007,
+            ErrorCode::CannotSendMessagesInNonTextChannel => 50008,
+            ErrorCode::ChannelVerificationLevelTooHigh => 50009,
+            ErrorCode::OAuth2ApplicationHasNoBot => 50010,
+            ErrorCode::OAuth2ApplicationLimitReached => 50011,
+            ErrorCode::InvalidOAuth2State => 50012,
+            ErrorCode::MissingPermissions => 50013,
+            ErrorCode::InvalidAuthenticationToken => 50014,
+            ErrorCode::NoteTooLong => 50015,
+            ErrorCode::InvalidMessageDeleteCount => 50016,
+            ErrorCode::InvalidMfaLevel => 50017,
+            ErrorCode::CannotPinMessageInOtherChannel => 50019,
+            ErrorCode::InvalidInviteCode => 50020,
+            ErrorCode::CannotExecuteOnSystemMessage => 50021,
+            ErrorCode::CannotExecuteOnChannelType => 50024,
+            ErrorCode::InvalidOAuth2AccessToken => 50025,
+            ErrorCode::MissingOAuth2Scope => 50026,
+            ErrorCode::InvalidWebhookToken => 50027,
+            ErrorCode::InvalidRole => 50028,
+            ErrorCode::InvalidRecipients => 50033,
+            ErrorCode::MessageTooOldToBulkDelete => 50034,
+            ErrorCode::InvalidFormBody => 50035,
+            ErrorCode::InviteAcceptedToGuildWithoutBot => 50036,
+            ErrorCode::InvalidActivityAction => 50039,
+            ErrorCode::InvalidApiVersion => 50041,
+            ErrorCode::FileTooLarge => 50045,
+            ErrorCode::InvalidFileUploaded => 50046,
+            ErrorCode::CannotSelfRedeemGift => 50054,
+            ErrorCode::InvalidGuild => 50055,
+            ErrorCode::InvalidSku => 50057,
+            ErrorCode::InvalidRequestOrigin => 50067,
+            ErrorCode::InvalidMessageType => 50068,
+            ErrorCode::PaymentSourceRequired => 50070,
+            ErrorCode::CannotModifySystemWebhook => 50073,
+            ErrorCode::CannotDeleteRequiredCommunityChannel => 50074,
+            ErrorCode::CannotEditMessageStickers => 50080,
+            ErrorCode::InvalidSticker => 50081,
+            ErrorCode::ThreadArchived => 50083,
+            ErrorCode::InvalidThreadNotificationSettings => 50084,
+            ErrorCode::BeforeEarlierThanThreadCreation => 50085,
+            ErrorCode::CommunityChannelsMustBeText => 50086,
+            ErrorCode::EventEntityTypeMismatch => 50091,
+            ErrorCode::ServerNotAvailableInLocation => 50095,
+            ErrorCode::MonetizationRequired => 50097,
+            ErrorCode::MoreBoostsRequired => 50101,
+            ErrorCode::InvalidJson => 50109,
+            ErrorCode::InvalidFile => 50110,
+            ErrorCode::InvalidFileType => 50123,
+            ErrorCode::FileDurationTooLong => 50124,
+            ErrorCode::OwnerCannotBePendingMember => 50131,
+            ErrorCode::OwnershipCannotBeTransferredToBot => 50132,
+            ErrorCode::FailedToResizeAsset => 50138,
+            ErrorCode::CannotMixSubscriptionRoles => 50144,
+            ErrorCode::CannotConvertEmojiType => 50145,
+            ErrorCode::UploadedFileNotFound => 50146,
+            ErrorCode::InvalidEmojiSpecified => 50151,
+            ErrorCode::VoiceMessagesWithAdditionalContent => 50159,
+            ErrorCode::VoiceMessagesWithMultipleAudioAttachments => 50160,
+            ErrorCode::VoiceMessagesWithoutMetadata => 50161,
+            ErrorCode::CannotEditVoiceMessage => 50162,
+            ErrorCode::CannotDeleteGuildSubscriptionIntegration => 50163,
+            ErrorCode::CannotSendVoiceMessagesInChannel => 50173,
+            ErrorCode::UserAccountMustBeVerified => 50178,
+            ErrorCode::FileDurationInvalid => 50192,
+            ErrorCode::NoPermissionToSendSticker => 50600,
+            ErrorCode::TwoFactorRequired => 60003,
+            ErrorCode::NoUsersWithDiscordTag => 80004,
+            ErrorCode::ReactionBlocked => 90001,
+            ErrorCode::CannotUseBurstReactions => 90002,
+            ErrorCode::ApplicationNotYetAvailable => 110_001,
+            ErrorCode::ApiResourceOverloaded => 130_000,
+            ErrorCode::StageAlreadyOpen => 150_006,
+            ErrorCode::CannotReplyWithoutReadMessageHistory => 160_002,
+            ErrorCode::ThreadAlreadyCreated => 160_004,
+            ErrorCode::ThreadLocked => 160_005,
+            ErrorCode::MaximumActiveThreads => 160_006,
+            ErrorCode::MaximumActiveAnnouncementThreads => 160_007,
+            ErrorCode::InvalidLottieJson => 170_001,
+            ErrorCode::LottieContainsRasterizedImages => 170_002,
+            ErrorCode::StickerMaximumFramerateExceeded => 170_003,
+            ErrorCode::StickerFrameCountTooLarge => 170_004,
+            ErrorCode::LottieDimensionsTooLarge => 170_005,
+            ErrorCode::StickerFrameRateInvalid => 170_006,
+            ErrorCode::StickerAnimationTooLong => 170_007,
+            ErrorCode::CannotUpdateFinishedEvent => 180_000,
+            ErrorCode::FailedToCreateStageForEvent => 180_002,
+            ErrorCode::BlockedByAutoModeration => 200_000,
+            ErrorCode::TitleBlockedByAutoModeration => 200_001,
+            ErrorCode::ForumWebhookMissingThread => 220_001,
+            ErrorCode::ForumWebhookThreadNameAndId => 220_002,
+            ErrorCode::WebhooksCanOnlyCreateThreadsInForums => 220_003,
+            ErrorCode::WebhookServicesCannotBeUsedInForums => 220_004,
+            ErrorCode::BlockedByHarmfulLinksFilter => 240_000,
+            ErrorCode::CannotEnableOnboarding => 350_000,
+            ErrorCode::CannotUpdateOnboarding => 350_001,
+            ErrorCode::FailedToBanUsers => 500_000,
+            ErrorCode::PollVotingBlocked => 520_000,
+            ErrorCode::PollExpired => 520_001,
+            ErrorCode::InvalidPollChannelType => 520_002,
+            ErrorCode::CannotEditPollMessage => 520_003,
+            ErrorCode::CannotUseEmojiInPoll => 520_004,
+            ErrorCode::CannotExpireNonPollMessage => 520_006,
+            ErrorCode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorCode;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&ErrorCode::GeneralError, &[Token::U64(0)]);
+        serde_test::assert_tokens(&ErrorCode::UnknownAccount, &[Token::U64(10001)]);
+        serde_test::assert_tokens(&ErrorCode::InvalidFormBody, &[Token::U64(50035)]);
+        serde_test::assert_tokens(
+            &ErrorCode::CannotExpireNonPollMessage,
+            &[Token::U64(520_006)],
+        );
+        serde_test::assert_tokens(&ErrorCode::Unknown(1), &[Token::U64(1)]);
+    }
+
+    #[test]
+    fn conversion() {
+        let codes = [0, 10001, 20001, 30001, 40001, 50001, 160_002, 520_006];
+
+        for code in codes {
+            assert_eq!(code, u64::from(ErrorCode::from(code)));
+            assert!(!matches!(ErrorCode::from(code), ErrorCode::Unknown(_)));
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use serde::{
+    de::{DeserializeSeed, Deserializer, MapAccess, Visitor},
+    ser::{SerializeMap, Serializer},
+    Deserialize, Serialize,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Key of the list of errors within the nested `errors` object.
+const ERRORS_KEY: &str = "_errors";
+
+/// Error of a field within the request body.
+///
+/// Discord returns these as a nested `errors` object mirroring the structure
+/// of the request body; each error contains the path to the field it belongs
+/// to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct FieldError {
+    /// Code of the error, such as `BASE_TYPE_MAX_LENGTH`.
+    pub code: String,
+    /// Human readable message provided by the API.
+    pub message: String,
+    /// Path to the field, such as `["embeds", "0", "title"]`.
+    ///
+    /// Indices of array elements are included as strings. The path is empty
+    /// for errors concerning the request body as a whole.
+    pub path: Vec<String>,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (idx, segment) in self.path.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(".")?;
+            }
+
+            f.write_str(segment)?;
+        }
+
+        if !self.path.is_empty() {
+            f.write_str(": ")?;
+        }
+
+        f.write_str(&self.code)?;
+        f.write_str(": ")?;
+
+        f.write_str(&self.message)
+    }
+}
+
+/// Error within the `_errors` list of a field.
+#[derive(Deserialize, Serialize)]
+struct Detail<T> {
+    code: T,
+    message: T,
+}
+
+/// Deserialize the nested `errors` object into a list of [`FieldError`]s.
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<FieldError>, D::Error> {
+    let mut errors = Vec::new();
+
+    deserializer.deserialize_map(FieldErrorsVisitor {
+        errors: &mut errors,
+        path: &mut Vec::new(),
+    })?;
+
+    Ok(errors)
+}
+
+/// Serialize a list of [`FieldError`]s into a nested `errors` object.
+pub fn serialize<S: Serializer>(errors: &[FieldError], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut root = Node::default();
+
+    for error in errors {
+        let node = error.path.iter().fold(&mut root, |node, segment| {
+            node.children.entry(segment).or_default()
+        });
+
+        node.errors.push(Detail {
+            code: &error.code,
+            message: &error.message,
+        });
+    }
+
+    root.serialize(serializer)
+}
+
+/// Visitor of a level of the nested `errors` object.
+struct FieldErrorsVisitor<'a> {
+    /// Errors collected so far.
+    errors: &'a mut Vec<FieldError>,
+    /// Path to the current level.
+    path: &'a mut Vec<String>,
+}
+
+impl<'de> DeserializeSeed<'de> for FieldErrorsVisitor<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldErrorsVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a map of field errors")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == ERRORS_KEY {
+                for detail in map.next_value::<Vec<Detail<String>>>()? {
+                    self.errors.push(FieldError {
+                        code: detail.code,
+                        message: detail.message,
+                        path: self.path.clone(),
+                    });
+                }
+            } else {
+                self.path.push(key);
+                map.next_value_seed(FieldErrorsVisitor {
+                    errors: &mut *self.errors,
+                    path: &mut *self.path,
+                })?;
+                self.path.pop();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Level of the nested `errors` object to serialize.
+#[derive(Default)]
+struct Node<'a> {
+    /// Nested fields.
+    children: BTreeMap<&'a str, Node<'a>>,
+    /// Errors of this field.
+    errors: Vec<Detail<&'a str>>,
+}
+
+impl Serialize for Node<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = self.children.len() + usize::from(!self.errors.is_empty());
+        let mut map = serializer.serialize_map(Some(len))?;
+
+        if !self.errors.is_empty() {
+            map.serialize_entry(ERRORS_KEY, &self.errors)?;
+        }
+
+        for (key, node) in &self.children {
+            map.serialize_entry(key, node)?;
+        }
+
+        map.end()
+    }
+}
@@ -1,3 +1,8 @@
+mod code;
+mod field;
+
+pub use self::{code::ErrorCode, field::FieldError};
+
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -26,16 +31,59 @@ impl Display for ApiError {
 #[non_exhaustive]
 pub struct GeneralApiError {
     pub code: u64,
+    /// Errors of individual fields within the request body.
+    ///
+    /// Parsed from the nested `errors` object, which is only present for
+    /// some error codes such as [`ErrorCode::InvalidFormBody`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "field")]
+    pub errors: Vec<FieldError>,
     pub message: String,
 }
 
+impl GeneralApiError {
+    /// Typed code of the error.
+    ///
+    /// # Examples
+    ///
+    /// Check whether a message to a user couldn't be sent:
+    ///
+    /// ```
+    /// use twilight_http::api_error::{ErrorCode, GeneralApiError};
+    ///
+    /// # fn check(error: &GeneralApiError) {
+    /// if error.error_code() == ErrorCode::CannotSendMessagesToUser {
+    ///     println!("user has direct messages disabled");
+    /// }
+    /// # }
+    /// ```
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from(self.code)
+    }
+}
+
 impl Display for GeneralApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_str("Error code ")?;
         Display::fmt(&self.code, f)?;
         f.write_str(": ")?;
 
-        f.write_str(&self.message)
+        f.write_str(&self.message)?;
+
+        if !self.errors.is_empty() {
+            f.write_str(" (")?;
+
+            for (idx, error) in self.errors.iter().enumerate() {
+                if idx > 0 {
+                    f.write_str(", ")?;
+                }
+
+                Display::fmt(error, f)?;
+            }
+
+            f.write_str(")")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -131,7 +179,8 @@ impl PartialEq for RatelimitedApiError {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApiError, GeneralApiError, MessageApiError, MessageApiErrorEmbedField, RatelimitedApiError,
+        ApiError, ErrorCode, FieldError, GeneralApiError, MessageApiError,
+        MessageApiErrorEmbedField, RatelimitedApiError,
     };
     use serde_test::Token;
 
@@ -139,6 +188,7 @@ mod tests {
     fn api_error_deser() {
         let expected = GeneralApiError {
             code: 10001,
+            errors: Vec::new(),
             message: "Unknown account".to_owned(),
         };
 
@@ -252,4 +302,39 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn api_error_fields() -> Result<(), serde_json::Error> {
+        let json = r#"{"code":50035,"errors":{"_errors":[{"code":"DICT_TYPE_CONVERT","message":"Only dictionaries may be used in a DictType"}],"embeds":{"0":{"title":{"_errors":[{"code":"BASE_TYPE_MAX_LENGTH","message":"Must be 256 or fewer in length."}]}}}},"message":"Invalid Form Body"}"#;
+
+        let ApiError::General(error) = serde_json::from_str(json)? else {
+            panic!("expected a general error");
+        };
+
+        assert_eq!(ErrorCode::InvalidFormBody, error.error_code());
+        assert_eq!(
+            [
+                FieldError {
+                    code: "DICT_TYPE_CONVERT".to_owned(),
+                    message: "Only dictionaries may be used in a DictType".to_owned(),
+                    path: Vec::new(),
+                },
+                FieldError {
+                    code: "BASE_TYPE_MAX_LENGTH".to_owned(),
+                    message: "Must be 256 or fewer in length.".to_owned(),
+                    path: vec!["embeds".to_owned(), "0".to_owned(), "title".to_owned()],
+                },
+            ],
+            error.errors.as_slice()
+        );
+        assert_eq!(
+            "Error code 50035: Invalid Form Body (DICT_TYPE_CONVERT: Only dictionaries may be \
+             used in a DictType, embeds.0.title: BASE_TYPE_MAX_LENGTH: Must be 256 or fewer in \
+             length.)",
+            error.to_string()
+        );
+        assert_eq!(json, serde_json::to_string(&error)?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,513 @@
+//! Downloading files such as attachments, avatars, and emojis from Discord's
+//! CDN.
+//!
+//! Downloads share the [`Client`]'s timeout, but are not ratelimited and do
+//! not send the client's token. They always use the default HTTP connector,
+//! even if a [custom connector] or [Unix socket] is configured for the API.
+//!
+//! # Examples
+//!
+//! Mirror an attachment, refusing files larger than 8 megabytes:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_http::Client;
+//!
+//! let client = Client::new(std::env::var("DISCORD_TOKEN")?);
+//!
+//! let url = "https://cdn.discordapp.com/attachments/1/2/image.png";
+//! let file = client.download(url).max_size(8 * 1024 * 1024).await?;
+//! let bytes = file.bytes().await?;
+//!
+//! println!("downloaded {} bytes", bytes.len());
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [Unix socket]: crate::client::ClientBuilder::unix_socket
+//! [custom connector]: crate::client::ClientBuilder::connector
+
+use crate::{client::Connector, response::StatusCode};
+use futures_core::Stream;
+use http::{header::CONTENT_TYPE, Uri};
+use http_body_util::Full;
+use hyper::body::{Body, Bytes, Incoming};
+use hyper_util::client::legacy::Client as HyperClient;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::{Future, IntoFuture},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+/// Hosts files may be downloaded from.
+const HOSTS: &[&str] = &["cdn.discordapp.com", "media.discordapp.net"];
+
+/// Downloading a file from the CDN failed.
+#[derive(Debug)]
+pub struct DownloadError {
+    /// Type of error that occurred.
+    kind: DownloadErrorType,
+    /// Source error if available.
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl DownloadError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &DownloadErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (DownloadErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            DownloadErrorType::BuildingRequest => f.write_str("failed to build the request"),
+            DownloadErrorType::ChunkingResponse => f.write_str("chunking the response failed"),
+            DownloadErrorType::InvalidUrl { url } => {
+                f.write_str("url ")?;
+                f.write_str(url)?;
+
+                f.write_str(" is not a discord cdn url")
+            }
+            DownloadErrorType::RequestError => f.write_str("sending the request failed"),
+            DownloadErrorType::RequestTimedOut => f.write_str("request timed out"),
+            DownloadErrorType::Status { status } => {
+                f.write_str("response error: status code ")?;
+
+                Display::fmt(status, f)
+            }
+            DownloadErrorType::TooLarge { max_size } => {
+                f.write_str("file is larger than the maximum size of ")?;
+                Display::fmt(max_size, f)?;
+
+                f.write_str(" bytes")
+            }
+        }
+    }
+}
+
+impl Error for DownloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`DownloadError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DownloadErrorType {
+    /// Building the request failed.
+    BuildingRequest,
+    /// Receiving a chunk of the response body failed.
+    ChunkingResponse,
+    /// URL is not a valid URL of Discord's CDN.
+    InvalidUrl {
+        /// Provided URL.
+        url: String,
+    },
+    /// Sending the request failed.
+    RequestError,
+    /// Response was not received within the client's timeout.
+    RequestTimedOut,
+    /// CDN responded with a non-success status code.
+    Status {
+        /// Status code of the response.
+        status: StatusCode,
+    },
+    /// File is larger than the configured maximum size.
+    TooLarge {
+        /// Configured maximum size in bytes.
+        max_size: u64,
+    },
+}
+
+/// Future returned by [`DownloadFile`].
+pub type DownloadFuture =
+    Pin<Box<dyn Future<Output = Result<CdnFile, DownloadError>> + Send + 'static>>;
+
+/// Download a file from Discord's CDN.
+///
+/// Created via [`Client::download`].
+///
+/// [`Client::download`]: crate::Client::download
+#[must_use = "requests must be configured and executed"]
+pub struct DownloadFile<'a> {
+    /// HTTP client to send the request with.
+    http: &'a HyperClient<Connector, Full<Bytes>>,
+    /// Maximum size of the file in bytes.
+    max_size: Option<u64>,
+    /// Duration to wait for the response before timing out.
+    timeout: Duration,
+    /// URL of the file.
+    url: String,
+}
+
+impl<'a> DownloadFile<'a> {
+    /// Create a new request to download a file.
+    pub(crate) const fn new(
+        http: &'a HyperClient<Connector, Full<Bytes>>,
+        timeout: Duration,
+        url: String,
+    ) -> Self {
+        Self {
+            http,
+            max_size: None,
+            timeout,
+            url,
+        }
+    }
+
+    /// Set the maximum size of the file in bytes.
+    ///
+    /// Files announcing a larger size are rejected before their body is
+    /// received; streams of files exceeding the size mid-download return a
+    /// [`DownloadErrorType::TooLarge`] error.
+    ///
+    /// Defaults to no limit.
+    pub const fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+
+        self
+    }
+}
+
+impl IntoFuture for DownloadFile<'_> {
+    type Output = Result<CdnFile, DownloadError>;
+
+    type IntoFuture = DownloadFuture;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http.clone();
+        let max_size = self.max_size;
+        let request = build_request(&self.url);
+        let timeout = self.timeout;
+
+        Box::pin(async move {
+            let response = tokio::time::timeout(timeout, http.request(request?))
+                .await
+                .map_err(|source| DownloadError {
+                    kind: DownloadErrorType::RequestTimedOut,
+                    source: Some(Box::new(source)),
+                })?
+                .map_err(|source| DownloadError {
+                    kind: DownloadErrorType::RequestError,
+                    source: Some(Box::new(source)),
+                })?;
+
+            let status = response.status();
+
+            if !status.is_success() {
+                return Err(DownloadError {
+                    kind: DownloadErrorType::Status {
+                        status: StatusCode::new(status.as_u16()),
+                    },
+                    source: None,
+                });
+            }
+
+            let file = CdnFile {
+                content_length: response.body().size_hint().exact(),
+                content_type: response
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .map(ToOwned::to_owned),
+                incoming: response.into_body(),
+                max_size,
+                received: 0,
+                terminated: false,
+            };
+
+            if let (Some(max_size), Some(length)) = (max_size, file.content_length) {
+                if length > max_size {
+                    return Err(DownloadError {
+                        kind: DownloadErrorType::TooLarge { max_size },
+                        source: None,
+                    });
+                }
+            }
+
+            Ok(file)
+        })
+    }
+}
+
+/// Validate the URL and build the request.
+fn build_request(url: &str) -> Result<hyper::Request<Full<Bytes>>, DownloadError> {
+    let invalid_url = || DownloadError {
+        kind: DownloadErrorType::InvalidUrl {
+            url: url.to_owned(),
+        },
+        source: None,
+    };
+
+    let uri = url.parse::<Uri>().map_err(|_| invalid_url())?;
+
+    let allowed = uri.host().is_some_and(|host| {
+        HOSTS
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(host))
+    });
+
+    if uri.scheme_str() != Some("https") || !allowed {
+        return Err(invalid_url());
+    }
+
+    hyper::Request::get(uri)
+        .body(Full::default())
+        .map_err(|source| DownloadError {
+            kind: DownloadErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })
+}
+
+/// File being downloaded from Discord's CDN.
+///
+/// The body is streamed as chunks of [`Bytes`] via the [`Stream`]
+/// implementation, which ends after the first error; use [`bytes`] to receive
+/// the whole body at once.
+///
+/// [`bytes`]: Self::bytes
+#[derive(Debug)]
+pub struct CdnFile {
+    /// Size of the file in bytes, if announced.
+    content_length: Option<u64>,
+    /// Value of the `Content-Type` header.
+    content_type: Option<String>,
+    /// Body of the response.
+    incoming: Incoming,
+    /// Maximum size of the file in bytes.
+    max_size: Option<u64>,
+    /// Number of bytes received so far.
+    received: u64,
+    /// Whether the stream ended, either completely or due to an error.
+    terminated: bool,
+}
+
+impl CdnFile {
+    /// Size of the file in bytes, if announced by the CDN.
+    pub const fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// MIME type of the file, if announced by the CDN.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Receive the entire body of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DownloadErrorType::ChunkingResponse`] error type if
+    /// receiving a chunk failed.
+    ///
+    /// Returns a [`DownloadErrorType::TooLarge`] error type if the file
+    /// exceeds the configured maximum size.
+    pub async fn bytes(mut self) -> Result<Vec<u8>, DownloadError> {
+        let mut bytes = Vec::with_capacity(
+            self.content_length
+                .and_then(|length| usize::try_from(length).ok())
+                .unwrap_or_default(),
+        );
+
+        while let Some(chunk) = std::future::poll_fn(|cx| Pin::new(&mut self).poll_next(cx)).await {
+            bytes.extend_from_slice(&chunk?);
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl Stream for CdnFile {
+    type Item = Result<Bytes, DownloadError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let frame = match Pin::new(&mut self.incoming).poll_frame(cx) {
+                Poll::Ready(Some(Ok(frame))) => frame,
+                Poll::Ready(Some(Err(source))) => {
+                    self.terminated = true;
+
+                    return Poll::Ready(Some(Err(DownloadError {
+                        kind: DownloadErrorType::ChunkingResponse,
+                        source: Some(Box::new(source)),
+                    })));
+                }
+                Poll::Ready(None) => {
+                    self.terminated = true;
+
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let Ok(data) = frame.into_data() else {
+                continue;
+            };
+
+            self.received += data.len() as u64;
+
+            if let Some(max_size) = self.max_size {
+                if self.received > max_size {
+                    self.terminated = true;
+
+                    return Poll::Ready(Some(Err(DownloadError {
+                        kind: DownloadErrorType::TooLarge { max_size },
+                        source: None,
+                    })));
+                }
+            }
+
+            return Poll::Ready(Some(Ok(data)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_request, CdnFile, DownloadError, DownloadErrorType, DownloadFile};
+    use futures_core::Stream;
+    use http_body_util::Full;
+    use hyper::body::Bytes;
+    use hyper_util::rt::TokioIo;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, future::IntoFuture, pin::Pin};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    assert_impl_all!(CdnFile: Debug, Send, Stream, Sync);
+    assert_impl_all!(DownloadError: Error, Send, Sync);
+    assert_impl_all!(DownloadFile<'_>: IntoFuture, Send, Sync);
+
+    #[test]
+    fn urls() {
+        assert!(build_request("https://cdn.discordapp.com/emojis/1.png").is_ok());
+        assert!(build_request("https://media.discordapp.net/attachments/1/2/a.png").is_ok());
+        assert!(build_request("https://CDN.DiscordApp.com/emojis/1.png").is_ok());
+
+        for url in [
+            "http://cdn.discordapp.com/emojis/1.png",
+            "https://example.com/emojis/1.png",
+            "https://cdn.discordapp.com.example.com/emojis/1.png",
+            "not a url",
+        ] {
+            assert!(matches!(
+                build_request(url).unwrap_err().kind(),
+                DownloadErrorType::InvalidUrl { .. }
+            ));
+        }
+    }
+
+    /// Receive the next item of a file.
+    async fn next(file: &mut CdnFile) -> Option<Result<Bytes, DownloadError>> {
+        std::future::poll_fn(|cx| Pin::new(&mut *file).poll_next(cx)).await
+    }
+
+    #[tokio::test]
+    async fn too_large_ends_stream() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut request = Vec::new();
+
+            while !request.ends_with(b"\r\n\r\n") {
+                let mut buf = [0; 1024];
+                let read = stream.read(&mut buf).await?;
+
+                if read == 0 {
+                    break;
+                }
+
+                request.extend_from_slice(&buf[..read]);
+            }
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\n\r\n\
+                    4\r\naaaa\r\n4\r\naaaa\r\n4\r\naaaa\r\n0\r\n\r\n",
+                )
+                .await?;
+
+            Ok::<_, std::io::Error>(stream)
+        });
+
+        let stream = TcpStream::connect(addr).await?;
+        let (mut sender, connection) =
+            hyper::client::conn::http1::handshake(TokioIo::new(stream)).await?;
+        tokio::spawn(connection);
+        let response = sender
+            .send_request(hyper::Request::get("/").body(Full::<Bytes>::default())?)
+            .await?;
+
+        let mut file = CdnFile {
+            content_length: None,
+            content_type: None,
+            incoming: response.into_body(),
+            max_size: Some(6),
+            received: 0,
+            terminated: false,
+        };
+
+        assert_eq!(Bytes::from_static(b"aaaa"), next(&mut file).await.unwrap()?);
+        assert!(matches!(
+            next(&mut file).await.unwrap().unwrap_err().kind(),
+            DownloadErrorType::TooLarge { max_size: 6 }
+        ));
+        assert!(next(&mut file).await.is_none());
+
+        Ok(())
+    }
+
+    /// Downloads must not be sent to a Unix socket intended for the API.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_not_used() -> Result<(), Box<dyn Error + Send + Sync>> {
+        use std::time::Duration;
+        use tokio::{net::UnixListener, time};
+
+        let path =
+            std::env::temp_dir().join(format!("twilight-http-cdn-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path)?;
+
+        let client = crate::Client::builder()
+            .timeout(Duration::from_millis(100))
+            .unix_socket(&path)
+            .build();
+        _ = client
+            .download("https://cdn.discordapp.com/emojis/1.png")
+            .await;
+
+        let accepted = time::timeout(Duration::ZERO, listener.accept()).await;
+        std::fs::remove_file(&path)?;
+        assert!(accepted.is_err());
+
+        Ok(())
+    }
+}
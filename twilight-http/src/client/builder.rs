@@ -1,11 +1,24 @@
-use super::Token;
-use crate::{client::connector, Client};
-use http::header::HeaderMap;
-use hyper_util::rt::TokioExecutor;
+use super::{Token, TWILIGHT_USER_AGENT};
+use crate::{
+    client::{
+        circuit_breaker::{CircuitBreaker, CircuitBreakerScope},
+        connector::{Connector, Io},
+    },
+    response::cache::ResponseCache,
+    Client,
+};
+use http::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Uri,
+};
+use hyper_util::rt::{TokioExecutor, TokioTimer};
 use std::{
+    error::Error,
     sync::{atomic::AtomicBool, Arc},
     time::Duration,
 };
+use tokio::sync::Semaphore;
+use tower_service::Service;
 use twilight_http_ratelimiting::{InMemoryRatelimiter, Ratelimiter};
 use twilight_model::channel::message::AllowedMentions;
 
@@ -13,14 +26,23 @@ use twilight_model::channel::message::AllowedMentions;
 #[derive(Debug)]
 #[must_use = "has no effect if not built into a Client"]
 pub struct ClientBuilder {
+    circuit_breaker: Option<(u32, Duration, CircuitBreakerScope)>,
+    connector: Option<Connector>,
+    http2_only: bool,
+    max_concurrent_requests: Option<usize>,
+    max_response_body_size: Option<usize>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     pub(crate) proxy: Option<Box<str>>,
     pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     remember_invalid_token: bool,
+    response_cache: Option<usize>,
     pub(crate) default_headers: Option<HeaderMap>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
     pub(crate) timeout: Duration,
     pub(super) token: Option<Token>,
     pub(crate) use_http: bool,
+    user_agent: Option<HeaderValue>,
 }
 
 impl ClientBuilder {
@@ -31,10 +53,28 @@ impl ClientBuilder {
 
     /// Build the [`Client`].
     pub fn build(self) -> Client {
-        let connector = connector::create();
+        let mut builder = hyper_util::client::legacy::Client::builder(TokioExecutor::new());
+        builder.pool_timer(TokioTimer::new());
+
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder.pool_idle_timeout(timeout);
+        }
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder.pool_max_idle_per_host(max);
+        }
 
-        let http =
-            hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(connector);
+        // Custom connectors, such as Unix sockets to API proxies, only reach
+        // the API, not the CDN.
+        #[cfg(feature = "cdn")]
+        let cdn = self
+            .connector
+            .is_some()
+            .then(|| builder.build(Connector::default()));
+
+        let http = builder
+            .http2_only(self.http2_only)
+            .build(self.connector.unwrap_or_default());
 
         let token_invalidated = if self.remember_invalid_token {
             Some(Arc::new(AtomicBool::new(false)))
@@ -43,18 +83,132 @@ impl ClientBuilder {
         };
 
         Client {
+            body_size_max: self.max_response_body_size,
+            #[cfg(feature = "cdn")]
+            cdn: cdn.unwrap_or_else(|| http.clone()),
             http,
+            circuit_breaker: self.circuit_breaker.map(|(threshold, cooldown, scope)| {
+                Arc::new(CircuitBreaker::new(threshold, cooldown, scope))
+            }),
+            concurrency: self
+                .max_concurrent_requests
+                .map(|max| (max, Arc::new(Semaphore::new(max)))),
             default_headers: self.default_headers,
             proxy: self.proxy,
-            ratelimiter: self.ratelimiter,
+            ratelimiter: self.ratelimiter.map(Arc::from),
+            response_cache: self
+                .response_cache
+                .map(|max_entries| Arc::new(ResponseCache::new(max_entries))),
             timeout: self.timeout,
             token_invalidated,
             token: self.token,
             default_allowed_mentions: self.default_allowed_mentions,
             use_http: self.use_http,
+            user_agent: self
+                .user_agent
+                .unwrap_or(HeaderValue::from_static(TWILIGHT_USER_AGENT)),
         }
     }
 
+    /// Set a custom connector to establish connections with.
+    ///
+    /// This may be used to configure custom TLS settings or to connect to a
+    /// local API proxy. Requests are still sent to the [proxy] if one is
+    /// set.
+    ///
+    /// Defaults to a connector using the TLS backend enabled via crate
+    /// features. [CDN downloads] always use the default connector.
+    ///
+    /// [CDN downloads]: crate::cdn
+    /// [proxy]: Self::proxy
+    pub fn connector<C>(mut self, connector: C) -> Self
+    where
+        C: Service<Uri> + Clone + Send + Sync + 'static,
+        C::Error: Into<Box<dyn Error + Send + Sync>>,
+        C::Future: Send + 'static,
+        C::Response: Io,
+    {
+        self.connector = Some(Connector::new(connector));
+
+        self
+    }
+
+    /// Send all requests through the Unix domain socket at the given path.
+    ///
+    /// This is useful for deployments fronting Discord with a local API
+    /// proxy, such as [twilight's HTTP proxy server]. Connections are not
+    /// encrypted. [CDN downloads] still connect to Discord's CDN directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .unix_socket("/run/twilight-http-proxy.sock")
+    ///     .build();
+    /// ```
+    ///
+    /// [CDN downloads]: crate::cdn
+    /// [twilight's HTTP proxy server]: https://github.com/twilight-rs/http-proxy
+    #[cfg(unix)]
+    pub fn unix_socket(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.connector = Some(Connector::new(
+            crate::client::connector::UnixConnector::new(path.into()),
+        ));
+        self.use_http = true;
+
+        self
+    }
+
+    /// Send requests over HTTP/2 with prior knowledge, without falling back
+    /// to HTTP/1.
+    ///
+    /// This is useful for local API proxies supporting HTTP/2 over
+    /// unencrypted connections. Connections to Discord encrypted via a TLS
+    /// backend already negotiate HTTP/2 if it's supported, so this setting
+    /// isn't necessary for them.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .proxy("twilight_http_proxy.internal".to_owned(), true)
+    ///     .http2_only(true)
+    ///     .build();
+    /// ```
+    pub const fn http2_only(mut self, http2_only: bool) -> Self {
+        self.http2_only = http2_only;
+
+        self
+    }
+
+    /// Set how long idle connections are kept in the connection pool before
+    /// being closed.
+    ///
+    /// The default is 90 seconds.
+    pub const fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Set the maximum number of idle connections kept in the connection pool
+    /// per host.
+    ///
+    /// Setting this to zero disables reusing connections.
+    ///
+    /// Unlimited by default.
+    pub const fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+
+        self
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
     pub fn default_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
@@ -90,6 +244,27 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the number of requests per second the default
+    /// [`InMemoryRatelimiter`] allows across all routes.
+    ///
+    /// Only bots approved for a raised global ratelimit by Discord should set
+    /// this above the default of 50 requests per second.
+    ///
+    /// This replaces any ratelimiter previously set via [`ratelimiter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is zero.
+    ///
+    /// [`ratelimiter`]: Self::ratelimiter
+    pub fn global_ratelimit(mut self, requests_per_second: u32) -> Self {
+        self.ratelimiter = Some(Box::new(InMemoryRatelimiter::with_global_ratelimit(
+            requests_per_second,
+        )));
+
+        self
+    }
+
     /// Set a ratelimiter to use.
     ///
     /// If the argument is `None` then the client's ratelimiter will be skipped
@@ -114,12 +289,73 @@ impl ClientBuilder {
     }
 
     /// Set a group headers which are sent in every request.
+    ///
+    /// This replaces any headers previously added via [`default_header`].
+    ///
+    /// [`default_header`]: Self::default_header
     pub fn default_headers(mut self, headers: HeaderMap) -> Self {
         self.default_headers.replace(headers);
 
         self
     }
 
+    /// Add a header which is sent in every request.
+    ///
+    /// Default headers take precedence over headers set by the client, such as
+    /// the user agent.
+    ///
+    /// # Examples
+    ///
+    /// Identify the service sending requests to an HTTP proxy:
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    /// use http::header::{HeaderName, HeaderValue};
+    ///
+    /// let client = Client::builder()
+    ///     .default_header(
+    ///         HeaderName::from_static("x-service"),
+    ///         HeaderValue::from_static("moderation"),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers
+            .get_or_insert_with(HeaderMap::new)
+            .insert(name, value);
+
+        self
+    }
+
+    /// Append an identifier to the user agent sent in every request.
+    ///
+    /// The user agent then takes the form of `DiscordBot (url, version)
+    /// Twilight-rs suffix`, keeping the format required by Discord.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the suffix contains characters not allowed in header values,
+    /// such as newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .user_agent_suffix("moderation-service/1.2.0")
+    ///     .build();
+    /// ```
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        let user_agent = format!("{TWILIGHT_USER_AGENT} {}", suffix.into());
+        self.user_agent = Some(
+            HeaderValue::from_str(&user_agent)
+                .expect("user agent suffix must be a valid header value"),
+        );
+
+        self
+    }
+
     /// Whether to remember whether the client has encountered an Unauthorized
     /// response status.
     ///
@@ -133,6 +369,127 @@ impl ClientBuilder {
         self
     }
 
+    /// Cache the bodies of up to `max_entries` responses to `GET` requests
+    /// with an `ETag` header.
+    ///
+    /// Subsequent requests to the same path send the `ETag` via the
+    /// `If-None-Match` header; if Discord responds with `304 Not Modified`,
+    /// the cached body is returned with a `200 OK` status code instead. The
+    /// oldest entry is evicted once the cache is full.
+    ///
+    /// Disabled by default.
+    pub const fn response_cache(mut self, max_entries: usize) -> Self {
+        self.response_cache = Some(max_entries);
+
+        self
+    }
+
+    /// Limit the number of requests that may be in flight at the same time.
+    ///
+    /// Requests exceeding the limit wait for an earlier request to finish
+    /// before waiting for the ratelimiter and being sent. A request is
+    /// finished once the body of its response is read or the response is
+    /// dropped. This prevents bursts of requests, such as when assigning a role to
+    /// many members, from opening a connection each. Time spent waiting isn't
+    /// included in the [timeout].
+    ///
+    /// The number of requests currently in flight is available via
+    /// [`Client::in_flight_requests`].
+    ///
+    /// Unlimited by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the limit is zero.
+    ///
+    /// [timeout]: Self::timeout
+    pub const fn max_concurrent_requests(mut self, max: usize) -> Self {
+        assert!(max > 0, "max concurrent requests must be more than zero");
+
+        self.max_concurrent_requests = Some(max);
+
+        self
+    }
+
+    /// Fail requests fast after `threshold` consecutive server errors or
+    /// timeouts.
+    ///
+    /// Once tripped, requests fail with an [`ErrorType::CircuitOpen`] error
+    /// type without being sent until the `cooldown` has elapsed. This avoids
+    /// hammering the API during Discord incidents. The first request sent
+    /// after the cooldown trips the circuit again if it fails, while any
+    /// response other than a server error resets the count of failures.
+    ///
+    /// The scope determines whether failures are counted per route or across
+    /// the whole client.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// Stop sending requests to a route for 30 seconds after 5 failures:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use twilight_http::{client::CircuitBreakerScope, Client};
+    ///
+    /// let client = Client::builder()
+    ///     .circuit_breaker(5, Duration::from_secs(30), CircuitBreakerScope::Route)
+    ///     .build();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the threshold is zero.
+    ///
+    /// [`ErrorType::CircuitOpen`]: crate::error::ErrorType::CircuitOpen
+    pub const fn circuit_breaker(
+        mut self,
+        threshold: u32,
+        cooldown: Duration,
+        scope: CircuitBreakerScope,
+    ) -> Self {
+        assert!(
+            threshold > 0,
+            "circuit breaker threshold must be more than zero"
+        );
+
+        self.circuit_breaker = Some((threshold, cooldown, scope));
+
+        self
+    }
+
+    /// Limit the size of response bodies read by the client.
+    ///
+    /// Reading a larger body fails with a
+    /// [`DeserializeBodyErrorType::BodyTooLarge`] error type instead of
+    /// buffering it into memory, which protects against misbehaving proxies
+    /// or endpoints. The limit applies to both compressed and decompressed
+    /// bodies. Error responses with a larger body fail with an
+    /// [`ErrorType::ChunkingResponse`] error type.
+    ///
+    /// Unlimited by default.
+    ///
+    /// # Examples
+    ///
+    /// Limit response bodies to 8 mebibytes:
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .max_response_body_size(8 * 1024 * 1024)
+    ///     .build();
+    /// ```
+    ///
+    /// [`DeserializeBodyErrorType::BodyTooLarge`]: crate::response::DeserializeBodyErrorType::BodyTooLarge
+    /// [`ErrorType::ChunkingResponse`]: crate::error::ErrorType::ChunkingResponse
+    pub const fn max_response_body_size(mut self, max: usize) -> Self {
+        self.max_response_body_size = Some(max);
+
+        self
+    }
+
     /// Set the token to use for HTTP requests.
     pub fn token(mut self, mut token: String) -> Self {
         let is_bot = token.starts_with("Bot ");
@@ -148,20 +505,46 @@ impl ClientBuilder {
 
         self
     }
+
+    /// Set an OAuth2 Bearer token to use for HTTP requests.
+    ///
+    /// The `"Bearer "` prefix is added if not already present. Refer to
+    /// [`TokenType::Bearer`] for the routes the client may be used for.
+    ///
+    /// [`TokenType::Bearer`]: super::TokenType::Bearer
+    pub fn bearer_token(mut self, mut access_token: String) -> Self {
+        if !access_token.starts_with("Bearer ") {
+            access_token.insert_str(0, "Bearer ");
+        }
+
+        self.token
+            .replace(Token::new(access_token.into_boxed_str()));
+
+        self
+    }
 }
 
 impl Default for ClientBuilder {
     fn default() -> Self {
         #[allow(clippy::box_default)]
         Self {
+            circuit_breaker: None,
+            connector: None,
+            http2_only: false,
+            max_concurrent_requests: None,
+            max_response_body_size: None,
             default_allowed_mentions: None,
             default_headers: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
             proxy: None,
             ratelimiter: Some(Box::new(InMemoryRatelimiter::default())),
             remember_invalid_token: true,
+            response_cache: None,
             timeout: Duration::from_secs(10),
             token: None,
             use_http: false,
+            user_agent: None,
         }
     }
 }
@@ -182,4 +565,104 @@ mod tests {
         );
         assert!(format!("{:?}", ClientBuilder::new()).contains("token: None"));
     }
+
+    #[tokio::test]
+    async fn headers() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        use crate::mock::{MockResponse, MockTransport};
+        use http::header::{HeaderName, HeaderValue, USER_AGENT};
+        use twilight_http_ratelimiting::{Method, Path};
+
+        let transport = MockTransport::new();
+        transport.route(
+            Method::Get,
+            Path::Gateway,
+            MockResponse::json(&serde_json::json!({ "url": "wss://gateway.discord.gg" })),
+        );
+        let client = transport
+            .client_builder()
+            .ratelimiter(None)
+            .default_header(
+                HeaderName::from_static("x-service"),
+                HeaderValue::from_static("moderation"),
+            )
+            .user_agent_suffix("moderation/1.0")
+            .build();
+
+        client.gateway().await?;
+        let requests = transport.requests();
+        let user_agent = requests[0].headers[USER_AGENT].to_str()?;
+
+        assert_eq!("moderation", requests[0].headers["x-service"]);
+        assert!(user_agent.starts_with("DiscordBot ("));
+        assert!(user_agent.ends_with(") Twilight-rs moderation/1.0"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn http2_only() -> Result<(), Box<dyn std::error::Error>> {
+        use tokio::{io::AsyncReadExt, net::TcpListener};
+
+        const PREFACE: &[u8] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let client = ClientBuilder::new()
+            .proxy(listener.local_addr()?.to_string(), true)
+            .http2_only(true)
+            .pool_idle_timeout(std::time::Duration::from_secs(5))
+            .pool_max_idle_per_host(1)
+            .ratelimiter(None)
+            .build();
+
+        let request = tokio::spawn(async move { client.gateway().await });
+        let (mut stream, _) = listener.accept().await?;
+        let mut buf = [0; PREFACE.len()];
+        stream.read_exact(&mut buf).await?;
+        request.abort();
+
+        assert_eq!(PREFACE, buf);
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket() -> Result<(), Box<dyn std::error::Error>> {
+        use tokio::{
+            io::{AsyncReadExt, AsyncWriteExt},
+            net::UnixListener,
+        };
+
+        let path = std::env::temp_dir().join(format!("twilight-http-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path)?;
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut buf = [0; 1024];
+            let read = stream.read(&mut buf).await?;
+            let body = br#"{"id":"1","username":"twilight","discriminator":"0001","avatar":null}"#;
+            let head = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+                body.len(),
+            );
+            stream.write_all(head.as_bytes()).await?;
+            stream.write_all(body).await?;
+
+            Ok::<_, std::io::Error>(String::from_utf8_lossy(&buf[..read]).into_owned())
+        });
+
+        let client = ClientBuilder::new()
+            .token("Bot foo".to_owned())
+            .unix_socket(&path)
+            .build();
+        let response = client.user(twilight_model::id::Id::new(1)).await?;
+        let request = server.await??;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(200, response.status().get());
+        assert!(request.starts_with("GET /api/v10/users/1 HTTP/1.1"));
+
+        Ok(())
+    }
 }
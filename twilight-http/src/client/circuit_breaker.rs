@@ -0,0 +1,205 @@
+//! Circuit breaker failing requests fast after repeated server errors.
+//!
+//! Enabled via [`ClientBuilder::circuit_breaker`].
+//!
+//! [`ClientBuilder::circuit_breaker`]: crate::client::ClientBuilder::circuit_breaker
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use twilight_http_ratelimiting::request::Path;
+
+/// Which requests share the failures counted by a circuit breaker.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CircuitBreakerScope {
+    /// Failures of any request trip the circuit for all requests of the
+    /// client.
+    Client,
+    /// Failures of requests to a route only trip the circuit for that route.
+    ///
+    /// Routes are distinguished the same way as by the ratelimiter, by their
+    /// [`Path`].
+    Route,
+}
+
+/// Failures of a circuit.
+#[derive(Debug)]
+struct State {
+    /// Number of consecutive failures.
+    failures: u32,
+    /// When the circuit was last tripped.
+    opened_at: Option<Instant>,
+}
+
+/// Tracker of consecutive failures of circuits, keyed by route if the scope
+/// is [`CircuitBreakerScope::Route`].
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    cooldown: Duration,
+    scope: CircuitBreakerScope,
+    states: Mutex<HashMap<Option<Path>, State>>,
+    threshold: u32,
+}
+
+impl CircuitBreaker {
+    /// Create a circuit breaker tripping after `threshold` consecutive
+    /// failures for a cooldown period.
+    pub fn new(threshold: u32, cooldown: Duration, scope: CircuitBreakerScope) -> Self {
+        Self {
+            cooldown,
+            scope,
+            states: Mutex::default(),
+            threshold,
+        }
+    }
+
+    /// Circuit of a route.
+    pub fn circuit(self: &Arc<Self>, path: &Path) -> Circuit {
+        let key = match self.scope {
+            CircuitBreakerScope::Client => None,
+            CircuitBreakerScope::Route => Some(path.clone()),
+        };
+
+        Circuit {
+            breaker: Arc::clone(self),
+            key,
+        }
+    }
+}
+
+/// Circuit requests to a route are counted in.
+#[derive(Debug)]
+pub(crate) struct Circuit {
+    breaker: Arc<CircuitBreaker>,
+    key: Option<Path>,
+}
+
+impl Circuit {
+    /// Remaining duration of the cooldown if the circuit is tripped.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after_at(Instant::now())
+    }
+
+    /// Count a server error or timeout of a request.
+    pub fn failure(&self) {
+        self.failure_at(Instant::now());
+    }
+
+    /// Count a request that received a response other than a server error,
+    /// closing the circuit and resetting its failures.
+    pub fn success(&self) {
+        self.breaker
+            .states
+            .lock()
+            .expect("states poisoned")
+            .remove(&self.key);
+    }
+
+    /// Remaining duration of the cooldown at an instant.
+    fn retry_after_at(&self, now: Instant) -> Option<Duration> {
+        let states = self.breaker.states.lock().expect("states poisoned");
+        let opened_at = states.get(&self.key)?.opened_at?;
+
+        self.breaker
+            .cooldown
+            .checked_sub(now.saturating_duration_since(opened_at))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Count a failure at an instant, tripping the circuit once the threshold
+    /// is reached.
+    ///
+    /// Failures after the cooldown has elapsed immediately trip the circuit
+    /// again.
+    fn failure_at(&self, now: Instant) {
+        let mut states = self.breaker.states.lock().expect("states poisoned");
+        let state = states.entry(self.key.clone()).or_insert(State {
+            failures: 0,
+            opened_at: None,
+        });
+
+        state.failures = state.failures.saturating_add(1);
+
+        if state.failures >= self.breaker.threshold {
+            state.opened_at = Some(now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Circuit, CircuitBreaker, CircuitBreakerScope};
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        hash::Hash,
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+    use twilight_http_ratelimiting::request::Path;
+
+    assert_impl_all!(
+        CircuitBreakerScope: Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(Circuit: Debug, Send, Sync);
+    assert_impl_all!(CircuitBreaker: Debug, Send, Sync);
+
+    const COOLDOWN: Duration = Duration::from_secs(30);
+
+    #[test]
+    fn trip_and_cooldown() {
+        let breaker = Arc::new(CircuitBreaker::new(2, COOLDOWN, CircuitBreakerScope::Route));
+        let circuit = breaker.circuit(&Path::Gateway);
+        let start = Instant::now();
+
+        circuit.failure_at(start);
+        assert_eq!(None, circuit.retry_after_at(start));
+
+        circuit.failure_at(start);
+        assert_eq!(Some(COOLDOWN), circuit.retry_after_at(start));
+        assert_eq!(
+            Some(Duration::from_secs(20)),
+            circuit.retry_after_at(start + Duration::from_secs(10))
+        );
+        assert_eq!(None, circuit.retry_after_at(start + COOLDOWN));
+
+        // A failure after the cooldown trips the circuit again immediately.
+        let later = start + COOLDOWN;
+        circuit.failure_at(later);
+        assert_eq!(Some(COOLDOWN), circuit.retry_after_at(later));
+
+        circuit.success();
+        assert_eq!(None, circuit.retry_after_at(later));
+        circuit.failure_at(later);
+        assert_eq!(None, circuit.retry_after_at(later));
+    }
+
+    #[test]
+    fn scope() {
+        let route = Arc::new(CircuitBreaker::new(1, COOLDOWN, CircuitBreakerScope::Route));
+        route.circuit(&Path::Gateway).failure();
+
+        assert!(route.circuit(&Path::Gateway).retry_after().is_some());
+        assert!(route.circuit(&Path::GatewayBot).retry_after().is_none());
+
+        let client = Arc::new(CircuitBreaker::new(
+            1,
+            COOLDOWN,
+            CircuitBreakerScope::Client,
+        ));
+        client.circuit(&Path::Gateway).failure();
+
+        assert!(client.circuit(&Path::Gateway).retry_after().is_some());
+        assert!(client.circuit(&Path::GatewayBot).retry_after().is_some());
+    }
+}
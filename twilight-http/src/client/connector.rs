@@ -1,5 +1,18 @@
 //! HTTP connectors with different features.
 
+use http::Uri;
+use hyper::rt::{Read, Write};
+use hyper_util::client::legacy::connect::{Connected, Connection};
+use std::{
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::{poll_fn, Future},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower_service::Service;
+
 /// HTTPS connector using `rustls` as a TLS backend.
 #[cfg(any(
     feature = "rustls-native-roots",
@@ -25,25 +38,141 @@ type HttpConnector = hyper_hickory::TokioHickoryHttpConnector;
 #[cfg(not(feature = "hickory"))]
 type HttpConnector = hyper_util::client::legacy::connect::HttpConnector;
 
-/// Re-exported generic connector for use in the client.
+/// Default connector with the enabled TLS backend.
 #[cfg(any(
     feature = "native-tls",
     feature = "rustls-native-roots",
     feature = "rustls-platform-verifier",
     feature = "rustls-webpki-roots",
 ))]
-pub type Connector = HttpsConnector<HttpConnector>;
-/// Re-exported generic connector for use in the client.
+type DefaultConnector = HttpsConnector<HttpConnector>;
+/// Default connector with the enabled TLS backend.
 #[cfg(not(any(
     feature = "native-tls",
     feature = "rustls-native-roots",
     feature = "rustls-platform-verifier",
     feature = "rustls-webpki-roots"
 )))]
-pub type Connector = HttpConnector;
+type DefaultConnector = HttpConnector;
+
+/// Generic error returned by connectors.
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// Future returned by [`Connector`].
+type ConnectFuture = Pin<Box<dyn Future<Output = Result<Box<dyn Io>, BoxError>> + Send>>;
+
+/// Connection created by a [custom connector].
+///
+/// Implemented for all types meeting its bounds, such as
+/// [`TokioIo<TcpStream>`], so it never needs to be implemented manually.
+///
+/// [`TokioIo<TcpStream>`]: hyper_util::rt::TokioIo
+/// [custom connector]: super::ClientBuilder::connector
+pub trait Io: Connection + Read + Send + Unpin + Write + 'static {}
+
+impl<T: Connection + Read + Send + Unpin + Write + 'static> Io for T {}
+
+impl Connection for Box<dyn Io> {
+    fn connected(&self) -> Connected {
+        (**self).connected()
+    }
+}
+
+/// Type-erased connector for use in the client.
+///
+/// Wraps either the default connector or a custom one configured via
+/// [`ClientBuilder::connector`].
+///
+/// [`ClientBuilder::connector`]: super::ClientBuilder::connector
+#[derive(Clone)]
+pub struct Connector(Arc<dyn Fn(Uri) -> ConnectFuture + Send + Sync>);
+
+impl Connector {
+    /// Wrap a connector.
+    pub fn new<C>(connector: C) -> Self
+    where
+        C: Service<Uri> + Clone + Send + Sync + 'static,
+        C::Error: Into<BoxError>,
+        C::Future: Send + 'static,
+        C::Response: Io,
+    {
+        Self(Arc::new(move |uri| {
+            let mut connector = connector.clone();
+
+            Box::pin(async move {
+                poll_fn(|cx| connector.poll_ready(cx))
+                    .await
+                    .map_err(Into::into)?;
+                let io = connector.call(uri).await.map_err(Into::into)?;
+
+                Ok(Box::new(io) as Box<dyn Io>)
+            })
+        }))
+    }
+}
+
+impl Debug for Connector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Connector").finish_non_exhaustive()
+    }
+}
+
+impl Default for Connector {
+    fn default() -> Self {
+        Self::new(create())
+    }
+}
+
+impl Service<Uri> for Connector {
+    type Response = Box<dyn Io>;
+    type Error = BoxError;
+    type Future = ConnectFuture;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        (self.0)(uri)
+    }
+}
+
+/// Connector connecting to a Unix domain socket regardless of the URI.
+#[cfg(unix)]
+#[derive(Clone, Debug)]
+pub struct UnixConnector(Arc<std::path::Path>);
+
+#[cfg(unix)]
+impl UnixConnector {
+    /// Create a connector to the socket at the given path.
+    pub fn new(path: std::path::PathBuf) -> Self {
+        Self(Arc::from(path))
+    }
+}
+
+#[cfg(unix)]
+impl Service<Uri> for UnixConnector {
+    type Response = hyper_util::rt::TokioIo<tokio::net::UnixStream>;
+    type Error = std::io::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _: Uri) -> Self::Future {
+        let path = Arc::clone(&self.0);
+
+        Box::pin(async move {
+            tokio::net::UnixStream::connect(&*path)
+                .await
+                .map(hyper_util::rt::TokioIo::new)
+        })
+    }
+}
 
 /// Create a connector with the specified features.
-pub fn create() -> Connector {
+fn create() -> DefaultConnector {
     #[cfg(not(feature = "hickory"))]
     let mut connector = HttpConnector::new();
     #[cfg(feature = "hickory")]
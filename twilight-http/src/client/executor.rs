@@ -0,0 +1,207 @@
+use crate::{
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, ResponseFuture},
+    Client, Error, Response,
+};
+use std::{future::IntoFuture, sync::Arc};
+
+/// Object safe executor of [`Request`]s.
+///
+/// Application code taking a `&dyn RequestExecutor`, or being generic over
+/// one, instead of a [`Client`] can be given wrappers of the client, such as
+/// ones recording or rewriting requests, and test doubles of it, such as a
+/// client using the `mock` feature's transport.
+///
+/// Requests are built from a [`Route`] via [`Request::from_route`] or
+/// [`Request::builder`], and the response's body is deserialized via
+/// [`Response::model_as`]. Typed request builders are executed via
+/// [`RequestExecutorExt::execute_typed`] instead, keeping the type of their
+/// response.
+///
+/// # Examples
+///
+/// Get the gateway's URL through any executor:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::{client::RequestExecutor, request::Request, routing::Route, Client};
+/// use twilight_model::gateway::connection_info::ConnectionInfo;
+///
+/// async fn gateway_url(
+///     http: &dyn RequestExecutor,
+/// ) -> Result<String, Box<dyn std::error::Error>> {
+///     let request = Request::from_route(&Route::GetGateway);
+///     let info = http.execute(request).await?.model_as::<ConnectionInfo>().await?;
+///
+///     Ok(info.url)
+/// }
+///
+/// let client = Client::new("my token".to_owned());
+/// println!("{}", gateway_url(&client).await?);
+/// # Ok(()) }
+/// ```
+///
+/// [`Route`]: crate::routing::Route
+pub trait RequestExecutor: Send + Sync {
+    /// Execute a request, returning a future resolving to its response.
+    fn execute(&self, request: Request) -> ResponseFuture<EmptyBody>;
+}
+
+/// Extension of [`RequestExecutor`]s executing typed request builders.
+///
+/// Implemented for every executor, including `dyn RequestExecutor`.
+///
+/// # Examples
+///
+/// Get the gateway's URL through any executor, keeping the response's type:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::{
+///     client::{RequestExecutor, RequestExecutorExt},
+///     Client,
+/// };
+///
+/// let client = Client::new("my token".to_owned());
+/// let executor: &dyn RequestExecutor = &client;
+///
+/// let info = executor
+///     .execute_typed(client.gateway())
+///     .await?
+///     .model()
+///     .await?;
+/// println!("{}", info.url);
+/// # Ok(()) }
+/// ```
+pub trait RequestExecutorExt: RequestExecutor {
+    /// Execute a typed request builder, returning a future resolving to its
+    /// typed response.
+    ///
+    /// Errors building the request are returned by the future.
+    fn execute_typed<R, T>(&self, request: R) -> ResponseFuture<T>
+    where
+        R: IntoFuture<Output = Result<Response<T>, Error>> + TryIntoRequest,
+    {
+        match request.try_into_request() {
+            Ok(request) => self.execute(request).cast(),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl<E: RequestExecutor + ?Sized> RequestExecutorExt for E {}
+
+impl RequestExecutor for Client {
+    fn execute(&self, request: Request) -> ResponseFuture<EmptyBody> {
+        self.request(request)
+    }
+}
+
+impl<T: RequestExecutor + ?Sized> RequestExecutor for &T {
+    fn execute(&self, request: Request) -> ResponseFuture<EmptyBody> {
+        (**self).execute(request)
+    }
+}
+
+impl<T: RequestExecutor + ?Sized> RequestExecutor for Arc<T> {
+    fn execute(&self, request: Request) -> ResponseFuture<EmptyBody> {
+        (**self).execute(request)
+    }
+}
+
+impl<T: RequestExecutor + ?Sized> RequestExecutor for Box<T> {
+    fn execute(&self, request: Request) -> ResponseFuture<EmptyBody> {
+        (**self).execute(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestExecutor, RequestExecutorExt};
+    use crate::{
+        mock::{MockResponse, MockTransport},
+        request::Request,
+        response::{marker::EmptyBody, ResponseFuture},
+        routing::Route,
+        Client,
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        error::Error,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::gateway::connection_info::ConnectionInfo;
+
+    assert_obj_safe!(RequestExecutor);
+    assert_impl_all!(Client: RequestExecutor);
+    assert_impl_all!(Arc<dyn RequestExecutor>: RequestExecutor);
+    assert_impl_all!(Box<dyn RequestExecutor>: RequestExecutor);
+
+    /// Executor counting the requests passed to the inner executor.
+    struct Counting<E> {
+        count: AtomicUsize,
+        inner: E,
+    }
+
+    impl<E: RequestExecutor> RequestExecutor for Counting<E> {
+        fn execute(&self, request: Request) -> ResponseFuture<EmptyBody> {
+            self.count.fetch_add(1, Ordering::Relaxed);
+
+            self.inner.execute(request)
+        }
+    }
+
+    async fn gateway_url(http: &dyn RequestExecutor) -> Result<String, Box<dyn Error>> {
+        let request = Request::from_route(&Route::GetGateway);
+        let info = http
+            .execute(request)
+            .await?
+            .model_as::<ConnectionInfo>()
+            .await?;
+
+        Ok(info.url)
+    }
+
+    async fn gateway_url_typed(
+        client: &Client,
+        http: &dyn RequestExecutor,
+    ) -> Result<String, Box<dyn Error>> {
+        let info = http.execute_typed(client.gateway()).await?.model().await?;
+
+        Ok(info.url)
+    }
+
+    #[tokio::test]
+    async fn wrapped() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.route(
+            Method::Get,
+            Path::Gateway,
+            MockResponse::json(&serde_json::json!({ "url": "wss://gateway.discord.gg" })),
+        );
+        let client = transport.client_builder().ratelimiter(None).build();
+
+        let executor = Counting {
+            count: AtomicUsize::new(0),
+            inner: client,
+        };
+
+        assert_eq!("wss://gateway.discord.gg", gateway_url(&executor).await?);
+        assert_eq!(1, executor.count.load(Ordering::Relaxed));
+
+        assert_eq!(
+            "wss://gateway.discord.gg",
+            gateway_url_typed(&executor.inner, &executor).await?
+        );
+        assert_eq!(2, executor.count.load(Ordering::Relaxed));
+        assert_eq!(2, transport.requests().len());
+
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
         },
         interaction::{
             CreateFollowup, CreateResponse, DeleteFollowup, DeleteResponse, GetFollowup,
-            GetResponse, UpdateFollowup, UpdateResponse,
+            GetResponse, RespondOrDefer, UpdateFollowup, UpdateResponse,
         },
     },
     Client,
@@ -80,6 +80,23 @@ impl<'a> InteractionClient<'a> {
         CreateResponse::new(self.client, interaction_id, interaction_token, response)
     }
 
+    /// Respond to an interaction, deferring the response if it isn't ready
+    /// within the deadline.
+    ///
+    /// Refer to [`RespondOrDefer`] for more information.
+    pub const fn respond_or_defer(
+        &'a self,
+        interaction_id: Id<InteractionMarker>,
+        interaction_token: &'a str,
+    ) -> RespondOrDefer<'a> {
+        RespondOrDefer::new(
+            self.client,
+            self.application_id,
+            interaction_id,
+            interaction_token,
+        )
+    }
+
     /// Delete the original message, by its token.
     ///
     /// This endpoint is not bound to the application's global rate limit.
@@ -1,9 +1,25 @@
 mod builder;
+pub(crate) mod circuit_breaker;
 mod connector;
+mod executor;
 mod interaction;
+mod oauth2;
+mod webhook;
+
+pub use self::{
+    builder::ClientBuilder,
+    circuit_breaker::CircuitBreakerScope,
+    connector::Io,
+    executor::{RequestExecutor, RequestExecutorExt},
+    interaction::InteractionClient,
+    oauth2::OAuth2Client,
+    webhook::WebhookClient,
+};
 
-pub use self::{builder::ClientBuilder, interaction::InteractionClient};
+pub(crate) use self::connector::Connector;
 
+#[cfg(feature = "cdn")]
+use crate::cdn::DownloadFile;
 use crate::request::application::{
     emoji::{
         AddApplicationEmoji, DeleteApplicationEmoji, ListApplicationEmojis, UpdateApplicationEmoji,
@@ -15,14 +31,14 @@ use crate::request::application::{
 };
 #[allow(deprecated)]
 use crate::{
-    client::connector::Connector,
+    client::circuit_breaker::{Circuit, CircuitBreaker},
     error::{Error, ErrorType},
     request::{
         channel::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
             message::{
-                CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, GetChannelMessages,
-                GetMessage, UpdateMessage,
+                CreateMessage, CrosspostMessage, DeleteManyMessages, DeleteMessage, DeleteMessages,
+                GetChannelMessages, GetMessage, UpdateMessage,
             },
             reaction::{
                 delete_reaction::TargetUser, CreateReaction, DeleteAllReaction, DeleteAllReactions,
@@ -82,14 +98,17 @@ use crate::{
             SyncTemplate, UpdateTemplate,
         },
         user::{
-            CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections,
-            GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser, LeaveGuild,
-            UpdateCurrentUser,
+            CreatePrivateChannel, GetCurrentUser, GetCurrentUserApplicationRoleConnection,
+            GetCurrentUserConnections, GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser,
+            LeaveGuild, UpdateCurrentUser, UpdateCurrentUserApplicationRoleConnection,
         },
         GetCurrentAuthorizationInformation, GetGateway, GetUserApplicationInfo, GetVoiceRegions,
-        Method, Request, UpdateCurrentUserApplication,
+        Method, Request, TryIntoRequest, UpdateCurrentUserApplication,
+    },
+    response::{
+        cache::{CacheKey, ResponseCache},
+        ResponseFuture, ResponseMetadata,
     },
-    response::ResponseFuture,
     API_VERSION,
 };
 use http::header::{
@@ -107,8 +126,8 @@ use std::{
     },
     time::Duration,
 };
-use tokio::time;
-use twilight_http_ratelimiting::Ratelimiter;
+use tokio::sync::Semaphore;
+use twilight_http_ratelimiting::{request::Path, Ratelimiter};
 use twilight_model::{
     channel::{message::AllowedMentions, ChannelType},
     guild::{
@@ -149,6 +168,17 @@ impl Token {
     }
 }
 
+impl Token {
+    /// Type of the token, determined by its prefix.
+    fn kind(&self) -> TokenType {
+        if self.inner.starts_with("Bearer ") {
+            TokenType::Bearer
+        } else {
+            TokenType::Bot
+        }
+    }
+}
+
 impl Debug for Token {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_str("<redacted>")
@@ -163,6 +193,47 @@ impl Deref for Token {
     }
 }
 
+/// Type of an authorization token.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TokenType {
+    /// OAuth2 access token acting on behalf of a user.
+    ///
+    /// Bearer tokens may only be used for routes acting on behalf of the
+    /// authorized user, such as the current user, their guilds, connections,
+    /// and role connections, as well as application commands if authorized
+    /// with the [`APPLICATIONS_COMMANDS_UPDATE`] scope.
+    ///
+    /// [`APPLICATIONS_COMMANDS_UPDATE`]: twilight_model::oauth::scope::APPLICATIONS_COMMANDS_UPDATE
+    Bearer,
+    /// Bot token.
+    Bot,
+}
+
+impl TokenType {
+    /// Whether requests to a path may be authenticated with this type of
+    /// token.
+    pub const fn supports(self, path: &Path) -> bool {
+        match self {
+            Self::Bearer => matches!(
+                path,
+                Path::ApplicationCommand(_)
+                    | Path::ApplicationCommandId(_)
+                    | Path::ApplicationGuildCommand(_)
+                    | Path::ApplicationGuildCommandId(_)
+                    | Path::OauthMe
+                    | Path::UsersId
+                    | Path::UsersIdApplicationsIdRoleConnection(_)
+                    | Path::UsersIdChannels
+                    | Path::UsersIdConnections
+                    | Path::UsersIdGuilds
+                    | Path::UsersIdGuildsIdMember
+            ),
+            Self::Bot => true,
+        }
+    }
+}
+
 /// Twilight's http client.
 ///
 /// Almost all of the client methods require authentication, and as such, the client must be
@@ -175,8 +246,8 @@ impl Deref for Token {
 ///
 /// # OAuth2
 ///
-/// To use Bearer tokens prefix the token with `"Bearer "`, including the space
-/// at the end like so:
+/// To use Bearer tokens create the client via [`Client::bearer`], or prefix
+/// the token with `"Bearer "`, including the space at the end like so:
 ///
 /// ```no_run
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -184,12 +255,16 @@ impl Deref for Token {
 /// use twilight_http::Client;
 ///
 /// let bearer = env::var("BEARER_TOKEN")?;
-/// let token = format!("Bearer {bearer}");
 ///
-/// let client = Client::new(token);
+/// let client = Client::bearer(bearer);
 /// # Ok(()) }
 /// ```
 ///
+/// Bearer tokens may only be used for routes acting on behalf of the
+/// authorized user; refer to [`TokenType::Bearer`] for more information.
+/// Requests to other routes return an [`ErrorType::UnsupportedTokenType`]
+/// error without being sent.
+///
 /// # Using the client in multiple tasks
 ///
 /// To use a client instance in multiple tasks, consider wrapping it in an
@@ -236,11 +311,21 @@ impl Deref for Token {
 /// [here]: https://discord.com/developers/applications
 #[derive(Debug)]
 pub struct Client {
+    /// Maximum size of response bodies in bytes.
+    body_size_max: Option<usize>,
+    /// HTTP client to download files from the CDN with.
+    #[cfg(feature = "cdn")]
+    cdn: HyperClient<Connector, Full<Bytes>>,
+    /// Circuit breaker configured via [`ClientBuilder::circuit_breaker`].
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// Maximum number of requests in flight and the semaphore limiting them.
+    concurrency: Option<(usize, Arc<Semaphore>)>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     default_headers: Option<HeaderMap>,
     http: HyperClient<Connector, Full<Bytes>>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    ratelimiter: Option<Arc<dyn Ratelimiter>>,
+    response_cache: Option<Arc<ResponseCache>>,
     timeout: Duration,
     /// Whether the token has been invalidated.
     ///
@@ -249,6 +334,9 @@ pub struct Client {
     token_invalidated: Option<Arc<AtomicBool>>,
     token: Option<Token>,
     use_http: bool,
+    /// User agent including the suffix set via
+    /// [`ClientBuilder::user_agent_suffix`].
+    user_agent: HeaderValue,
 }
 
 impl Client {
@@ -257,6 +345,14 @@ impl Client {
         ClientBuilder::default().token(token).build()
     }
 
+    /// Create a new client with an OAuth2 Bearer token.
+    ///
+    /// The `"Bearer "` prefix is added if not already present. Refer to
+    /// [`TokenType::Bearer`] for the routes the client may be used for.
+    pub fn bearer(access_token: String) -> Self {
+        ClientBuilder::default().bearer_token(access_token).build()
+    }
+
     /// Create a new builder to create a client.
     ///
     /// Refer to its documentation for more information.
@@ -317,6 +413,37 @@ impl Client {
         InteractionClient::new(self, application_id)
     }
 
+    /// Create an interface for using a webhook via its token.
+    ///
+    /// Requests made through it authenticate with the webhook's token, so the
+    /// client itself doesn't need a token. Refer to [`WebhookClient`] for
+    /// more information.
+    pub const fn webhook_client<'a>(
+        &'a self,
+        webhook_id: Id<WebhookMarker>,
+        token: &'a str,
+    ) -> WebhookClient<'a> {
+        WebhookClient::new(self, webhook_id, token)
+    }
+
+    /// Create an interface for OAuth2 token flows of an application.
+    ///
+    /// Requests made through it authenticate with the application's client ID
+    /// and secret, so the client itself doesn't need a token. Refer to
+    /// [`OAuth2Client`] for more information.
+    pub const fn oauth2<'a>(
+        &'a self,
+        client_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+    ) -> OAuth2Client<'a> {
+        OAuth2Client::new(self, client_id, client_secret)
+    }
+
+    /// Type of the configured token, if any.
+    pub fn token_type(&self) -> Option<TokenType> {
+        self.token.as_ref().map(Token::kind)
+    }
+
     /// Get an immutable reference to the default [`AllowedMentions`] for sent
     /// messages.
     pub const fn default_allowed_mentions(&self) -> Option<&AllowedMentions> {
@@ -328,7 +455,29 @@ impl Client {
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
     pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
-        self.ratelimiter.as_ref().map(AsRef::as_ref)
+        self.ratelimiter.as_deref()
+    }
+
+    /// Number of requests currently in flight.
+    ///
+    /// This will return `None` if no limit has been configured via
+    /// [`ClientBuilder::max_concurrent_requests`].
+    pub fn in_flight_requests(&self) -> Option<usize> {
+        self.concurrency
+            .as_ref()
+            .map(|(max, semaphore)| max - semaphore.available_permits())
+    }
+
+    /// Download a file from Discord's CDN, such as an attachment, avatar, or
+    /// emoji.
+    ///
+    /// Only URLs of `cdn.discordapp.com` and `media.discordapp.net` are
+    /// accepted. Refer to the [`cdn`] module for more information.
+    ///
+    /// [`cdn`]: crate::cdn
+    #[cfg(feature = "cdn")]
+    pub fn download(&self, url: impl Into<String>) -> DownloadFile<'_> {
+        DownloadFile::new(&self.cdn, self.timeout, url.into())
     }
 
     /// Get an auto moderation rule in a guild.
@@ -737,6 +886,28 @@ impl Client {
         GetCurrentUserConnections::new(self)
     }
 
+    /// Get the current user's role connection to an application.
+    ///
+    /// Requires a Bearer token with the `role_connections.write` `OAuth2`
+    /// scope.
+    pub const fn current_user_application_role_connection(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> GetCurrentUserApplicationRoleConnection<'_> {
+        GetCurrentUserApplicationRoleConnection::new(self, application_id)
+    }
+
+    /// Update the current user's role connection to an application.
+    ///
+    /// Requires a Bearer token with the `role_connections.write` `OAuth2`
+    /// scope.
+    pub const fn update_current_user_application_role_connection(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> UpdateCurrentUserApplicationRoleConnection<'_> {
+        UpdateCurrentUserApplicationRoleConnection::new(self, application_id)
+    }
+
     /// Returns a list of guilds for the current user.
     ///
     /// # Examples
@@ -984,6 +1155,28 @@ impl Client {
     }
 
     /// Modify the guild onboarding flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`PromptsInvalid`] if there are more than 15
+    /// prompts, or of type [`PromptOptionsInvalid`] if a prompt has fewer than
+    /// 1 or more than 50 options.
+    ///
+    /// Returns an error of type [`PromptTitleInvalid`] or
+    /// [`OptionTitleInvalid`] if the title of a prompt or option is invalid,
+    /// and an error of type [`OptionDescriptionInvalid`] if the description
+    /// of an option is too long.
+    ///
+    /// Returns an error of type [`ChannelsInvalid`] if the onboarding flow is
+    /// enabled but fewer than 7 channels count towards the constraints of its
+    /// mode.
+    ///
+    /// [`ChannelsInvalid`]: twilight_validate::onboarding::OnboardingValidationErrorType::ChannelsInvalid
+    /// [`OptionDescriptionInvalid`]: twilight_validate::onboarding::OnboardingValidationErrorType::OptionDescriptionInvalid
+    /// [`OptionTitleInvalid`]: twilight_validate::onboarding::OnboardingValidationErrorType::OptionTitleInvalid
+    /// [`PromptOptionsInvalid`]: twilight_validate::onboarding::OnboardingValidationErrorType::PromptOptionsInvalid
+    /// [`PromptTitleInvalid`]: twilight_validate::onboarding::OnboardingValidationErrorType::PromptTitleInvalid
+    /// [`PromptsInvalid`]: twilight_validate::onboarding::OnboardingValidationErrorType::PromptsInvalid
     pub const fn update_guild_onboarding(
         &self,
         guild_id: Id<GuildMarker>,
@@ -1480,6 +1673,32 @@ impl Client {
         DeleteMessages::new(self, channel_id, message_ids)
     }
 
+    /// Delete any number of messages by [`Id<ChannelMarker>`] and a list of
+    /// [`Id<MessageMarker>`]s.
+    ///
+    /// Unlike [`delete_messages`], messages are split into as many bulk delete
+    /// requests of up to 100 messages as needed, and messages older than two
+    /// weeks are deleted individually. The outcome of each message is
+    /// reported in the returned [`DeleteManyMessagesOutcome`].
+    ///
+    /// Refer to [`DeleteManyMessages`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ValidationErrorType::AuditReason`] if the
+    /// audit log reason is invalid.
+    ///
+    /// [`DeleteManyMessagesOutcome`]: crate::request::channel::message::DeleteManyMessagesOutcome
+    /// [`ValidationErrorType::AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    /// [`delete_messages`]: Self::delete_messages
+    pub const fn delete_many_messages<'a>(
+        &'a self,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &'a [Id<MessageMarker>],
+    ) -> DeleteManyMessages<'a> {
+        DeleteManyMessages::new(self, channel_id, message_ids)
+    }
+
     /// Update a message by [`Id<ChannelMarker>`] and [`Id<MessageMarker>`].
     ///
     /// You can pass [`None`] to any of the methods to remove the associated
@@ -2861,6 +3080,50 @@ impl Client {
         }
     }
 
+    /// Validate a request builder and serialize its body without sending it,
+    /// returning the [`Request`] that would have been sent.
+    ///
+    /// This is useful in tests and for previewing destructive bulk
+    /// operations.
+    ///
+    /// # Examples
+    ///
+    /// Preview the request to delete a message:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    /// let request = client.dry_run(client.delete_message(Id::new(1), Id::new(2)))?;
+    ///
+    /// println!("{:?} {}", request.method(), request.path());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::Unauthorized`] error type if the configured
+    /// token has become invalid due to expiration, revocation, etc.
+    ///
+    /// Returns the error of converting the request builder via
+    /// [`TryIntoRequest`] if validating or serializing it failed.
+    #[allow(clippy::result_large_err)]
+    pub fn dry_run(&self, request: impl TryIntoRequest) -> Result<Request, Error> {
+        if let Some(token_invalidated) = self.token_invalidated.as_ref() {
+            if token_invalidated.load(Ordering::Relaxed) {
+                return Err(Error {
+                    kind: ErrorType::Unauthorized,
+                    source: None,
+                });
+            }
+        }
+
+        request.try_into_request()
+    }
+
+    #[allow(clippy::too_many_lines)]
     fn try_request<T>(&self, request: Request) -> Result<ResponseFuture<T>, Error> {
         if let Some(token_invalidated) = self.token_invalidated.as_ref() {
             if token_invalidated.load(Ordering::Relaxed) {
@@ -2878,9 +3141,22 @@ impl Client {
             method,
             path,
             ratelimit_path,
+            timeout,
             use_authorization_token,
         } = request;
 
+        let circuit = self
+            .circuit_breaker
+            .as_ref()
+            .map(|breaker| breaker.circuit(&ratelimit_path));
+
+        if let Some(retry_after) = circuit.as_ref().and_then(Circuit::retry_after) {
+            return Err(Error {
+                kind: ErrorType::CircuitOpen { retry_after },
+                source: None,
+            });
+        }
+
         let protocol = if self.use_http { "http" } else { "https" };
         let host = self.proxy.as_deref().unwrap_or("discord.com");
 
@@ -2890,7 +3166,14 @@ impl Client {
         let mut builder = hyper::Request::builder().method(method.name()).uri(&url);
 
         if use_authorization_token {
-            if let Some(token) = self.token.as_deref() {
+            if let Some(token) = self.token.as_ref() {
+                if !token.kind().supports(&ratelimit_path) {
+                    return Err(Error {
+                        kind: ErrorType::UnsupportedTokenType,
+                        source: None,
+                    });
+                }
+
                 let value = HeaderValue::from_str(token).map_err(|source| {
                     let name = AUTHORIZATION.to_string();
 
@@ -2925,7 +3208,7 @@ impl Client {
                 HeaderValue::from_static("br"),
             );
 
-            headers.insert(USER_AGENT, HeaderValue::from_static(TWILIGHT_USER_AGENT));
+            headers.insert(USER_AGENT, self.user_agent.clone());
 
             if let Some(req_headers) = req_headers {
                 for (maybe_name, value) in req_headers {
@@ -2942,6 +3225,22 @@ impl Client {
             }
         }
 
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "request",
+            method = method.name(),
+            ratelimit_path = ?ratelimit_path,
+            route = %path,
+            bucket = tracing::field::Empty,
+            status = tracing::field::Empty,
+        );
+
+        let cache_key = self
+            .response_cache
+            .as_ref()
+            .filter(|_| method == Method::Get)
+            .map(|cache| CacheKey::new(cache, path, builder.headers_mut()));
+
         let try_req = if let Some(form) = form {
             builder.body(Full::from(form.build()))
         } else if let Some(bytes) = body {
@@ -2962,19 +3261,66 @@ impl Client {
             .then(|| self.token_invalidated.clone())
             .flatten();
 
-        Ok(if let Some(ratelimiter) = &self.ratelimiter {
-            let tx_future = ratelimiter.wait_for_ticket(ratelimit_path);
+        let timeout = timeout.unwrap_or(self.timeout);
+
+        let metadata = ResponseMetadata::new(method, ratelimit_path.clone());
+
+        let future = if let Some(ratelimiter) = &self.ratelimiter {
+            // Only request a ticket once polled, after acquiring a permit of
+            // the concurrency limit.
+            let ratelimiter = Arc::clone(ratelimiter);
+            let tx_future =
+                Box::pin(async move { ratelimiter.wait_for_ticket(ratelimit_path).await });
+
+            ResponseFuture::ratelimit(
+                invalid_token,
+                cache_key,
+                metadata,
+                inner,
+                timeout,
+                tx_future,
+            )
+        } else {
+            ResponseFuture::new(invalid_token, cache_key, metadata, inner, timeout)
+        };
+
+        let future = if let Some((_, semaphore)) = &self.concurrency {
+            future.limit(Arc::clone(semaphore))
+        } else {
+            future
+        };
 
-            ResponseFuture::ratelimit(invalid_token, inner, self.timeout, tx_future)
+        let future = if let Some(max) = self.body_size_max {
+            future.limit_body_size(max)
         } else {
-            ResponseFuture::new(Box::pin(time::timeout(self.timeout, inner)), invalid_token)
-        })
+            future
+        };
+
+        let future = if let Some(circuit) = circuit {
+            future.circuit(circuit)
+        } else {
+            future
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = future.instrument(span);
+
+        Ok(future)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Client;
+    use super::{CircuitBreakerScope, Client, TokenType};
+    use crate::{
+        error::ErrorType,
+        mock::{MockResponse, MockTransport},
+        request::Method,
+    };
+    use std::{error::Error, future::IntoFuture, time::Duration};
+    use tokio::time::{self, Instant};
+    use twilight_http_ratelimiting::Path;
+    use twilight_model::id::Id;
 
     #[test]
     fn client_debug_with_token() {
@@ -2983,4 +3329,114 @@ mod tests {
         );
         assert!(format!("{:?}", Client::builder().build()).contains("token: None"));
     }
+
+    #[test]
+    fn token_type() {
+        assert_eq!(
+            Some(TokenType::Bot),
+            Client::new("foo".to_owned()).token_type()
+        );
+        assert_eq!(
+            Some(TokenType::Bearer),
+            Client::bearer("foo".to_owned()).token_type()
+        );
+        assert_eq!(
+            Some(TokenType::Bearer),
+            Client::new("Bearer foo".to_owned()).token_type()
+        );
+        assert_eq!(None, Client::builder().build().token_type());
+    }
+
+    #[tokio::test]
+    async fn bearer_unsupported_route() {
+        let client = Client::bearer("foo".to_owned());
+        let error = client.gateway().await.unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::UnsupportedTokenType));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn max_concurrent_requests() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.route(
+            Method::Get,
+            Path::Gateway,
+            MockResponse::json(&serde_json::json!({ "url": "wss://gateway.discord.gg" }))
+                .delay(Duration::from_secs(1)),
+        );
+        let client = transport
+            .client_builder()
+            .max_concurrent_requests(1)
+            .ratelimiter(None)
+            .build();
+        assert_eq!(Some(0), client.in_flight_requests());
+        assert_eq!(None, Client::builder().build().in_flight_requests());
+
+        let start = Instant::now();
+        let first = client.gateway().await?;
+        assert_eq!(Some(1), client.in_flight_requests());
+
+        // The permit is held until the body of the first response is read.
+        let second = tokio::spawn(client.gateway().into_future());
+        time::sleep(Duration::from_secs(5)).await;
+        assert!(!second.is_finished());
+        assert_eq!(1, transport.requests().len());
+
+        first.model().await?;
+        second.await??.model().await?;
+        assert_eq!(Some(0), client.in_flight_requests());
+        assert_eq!(2, transport.requests().len());
+        assert_eq!(Duration::from_secs(7), start.elapsed());
+
+        Ok(())
+    }
+
+    #[test]
+    fn dry_run() {
+        let client = Client::new("foo".to_owned());
+
+        let request = client
+            .dry_run(client.create_message(Id::new(1)).content("test"))
+            .unwrap();
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("channels/1/messages", request.path());
+        assert_eq!(Some(br#"{"content":"test"}"#.as_slice()), request.body());
+
+        let content = "a".repeat(2001);
+        let error = client
+            .dry_run(client.create_message(Id::new(1)).content(&content))
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::Validation));
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.route(Method::Get, Path::Gateway, MockResponse::server_error(500));
+        let client = transport
+            .client_builder()
+            .circuit_breaker(2, Duration::from_secs(60), CircuitBreakerScope::Route)
+            .ratelimiter(None)
+            .build();
+
+        for _ in 0..2 {
+            let error = client.gateway().await.unwrap_err();
+            assert!(matches!(
+                error.kind(),
+                ErrorType::Response { status, .. } if status.get() == 500
+            ));
+        }
+
+        let error = client.gateway().await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::CircuitOpen { retry_after } if *retry_after <= Duration::from_secs(60)
+        ));
+
+        assert_eq!(2, transport.requests().len());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,109 @@
+use crate::{
+    request::oauth2::{
+        ClientCredentialsGrant, Credentials, ExchangeCode, RefreshToken, RevokeToken,
+    },
+    Client,
+};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use twilight_model::id::{marker::ApplicationMarker, Id};
+
+/// Client interface for OAuth2 token flows.
+///
+/// Requests authenticate with the application's client ID and secret instead
+/// of the client's token, so dashboard backends may use a [`Client`] built
+/// without a token. Use the returned access tokens as Bearer tokens to make
+/// requests on behalf of users.
+///
+/// # Examples
+///
+/// Exchange an authorization code received by the redirect URI for an access
+/// token:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// let client = Client::builder().build();
+/// let client_secret = env::var("CLIENT_SECRET")?;
+///
+/// let token = client
+///     .oauth2(Id::new(1), &client_secret)
+///     .exchange_code("code", "https://example.com/callback")
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("access token expires in {} seconds", token.expires_in);
+/// # Ok(()) }
+/// ```
+pub struct OAuth2Client<'a> {
+    client: &'a Client,
+    credentials: Credentials<'a>,
+}
+
+impl<'a> OAuth2Client<'a> {
+    /// Create a new interface for OAuth2 token flows.
+    pub(super) const fn new(
+        client: &'a Client,
+        client_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+    ) -> Self {
+        Self {
+            client,
+            credentials: Credentials {
+                client_id,
+                client_secret,
+            },
+        }
+    }
+
+    /// ID of the application.
+    pub const fn client_id(&self) -> Id<ApplicationMarker> {
+        self.credentials.client_id
+    }
+
+    /// Exchange an authorization code for an access token.
+    ///
+    /// The redirect URI must match the one used to retrieve the code.
+    pub const fn exchange_code(&self, code: &'a str, redirect_uri: &'a str) -> ExchangeCode<'a> {
+        ExchangeCode::new(self.client, self.credentials, code, redirect_uri)
+    }
+
+    /// Exchange a refresh token for a new access token.
+    pub const fn refresh_token(&self, refresh_token: &'a str) -> RefreshToken<'a> {
+        RefreshToken::new(self.client, self.credentials, refresh_token)
+    }
+
+    /// Retrieve an access token for the application's owner with the given
+    /// [scopes].
+    ///
+    /// [scopes]: twilight_model::oauth::scope
+    pub const fn client_credentials(&self, scopes: &'a [&'a str]) -> ClientCredentialsGrant<'a> {
+        ClientCredentialsGrant::new(self.client, self.credentials, scopes)
+    }
+
+    /// Revoke an access or refresh token.
+    pub const fn revoke_token(&self, token: &'a str) -> RevokeToken<'a> {
+        RevokeToken::new(self.client, self.credentials, token)
+    }
+}
+
+impl Debug for OAuth2Client<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("OAuth2Client")
+            .field("client", &self.client)
+            .field("credentials", &self.credentials)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OAuth2Client;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(OAuth2Client<'_>: Debug, Send, Sync);
+}
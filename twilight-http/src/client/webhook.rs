@@ -0,0 +1,153 @@
+use crate::{
+    request::channel::webhook::{
+        DeleteWebhook, DeleteWebhookMessage, ExecuteWebhook, GetWebhook, GetWebhookMessage,
+        UpdateWebhookMessage, UpdateWebhookWithToken,
+    },
+    Client,
+};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use twilight_model::id::{
+    marker::{MessageMarker, WebhookMarker},
+    Id,
+};
+
+/// Client interface for using a webhook via its token.
+///
+/// Requests made through this interface authenticate with the webhook's token
+/// instead of the client's token, so webhook-only services may use a
+/// [`Client`] built without a token.
+///
+/// # Examples
+///
+/// Parse a webhook URL with [`twilight_util::link::webhook::parse`] and
+/// execute the webhook:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::Client;
+/// use twilight_util::link::webhook;
+///
+/// let client = Client::builder().build();
+/// let url = "https://discord.com/api/webhooks/1/token";
+///
+/// let (webhook_id, Some(token)) = webhook::parse(url)? else {
+///     panic!("webhook url has no token");
+/// };
+///
+/// client
+///     .webhook_client(webhook_id, token)
+///     .execute()
+///     .content("Pinkie...")
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`twilight_util::link::webhook::parse`]: https://docs.rs/twilight-util/latest/twilight_util/link/webhook/fn.parse.html
+pub struct WebhookClient<'a> {
+    client: &'a Client,
+    token: &'a str,
+    webhook_id: Id<WebhookMarker>,
+}
+
+impl<'a> WebhookClient<'a> {
+    /// Create a new interface for using a webhook.
+    pub(super) const fn new(
+        client: &'a Client,
+        webhook_id: Id<WebhookMarker>,
+        token: &'a str,
+    ) -> Self {
+        Self {
+            client,
+            token,
+            webhook_id,
+        }
+    }
+
+    /// ID of the webhook.
+    pub const fn id(&self) -> Id<WebhookMarker> {
+        self.webhook_id
+    }
+
+    /// Get the webhook.
+    pub const fn webhook(&self) -> GetWebhook<'a> {
+        GetWebhook::new(self.client, self.webhook_id).token(self.token)
+    }
+
+    /// Update the webhook.
+    pub const fn update(&self) -> UpdateWebhookWithToken<'a> {
+        UpdateWebhookWithToken::new(self.client, self.webhook_id, self.token)
+    }
+
+    /// Delete the webhook.
+    pub const fn delete(&self) -> DeleteWebhook<'a> {
+        DeleteWebhook::new(self.client, self.webhook_id).token(self.token)
+    }
+
+    /// Execute the webhook, sending a message to its channel.
+    ///
+    /// Refer to [`Client::execute_webhook`] for more information.
+    pub const fn execute(&self) -> ExecuteWebhook<'a> {
+        ExecuteWebhook::new(self.client, self.webhook_id, self.token)
+    }
+
+    /// Get a message executed by the webhook.
+    pub const fn message(&self, message_id: Id<MessageMarker>) -> GetWebhookMessage<'a> {
+        GetWebhookMessage::new(self.client, self.webhook_id, self.token, message_id)
+    }
+
+    /// Update a message executed by the webhook.
+    ///
+    /// Refer to [`Client::update_webhook_message`] for more information.
+    pub const fn update_message(&self, message_id: Id<MessageMarker>) -> UpdateWebhookMessage<'a> {
+        UpdateWebhookMessage::new(self.client, self.webhook_id, self.token, message_id)
+    }
+
+    /// Delete a message executed by the webhook.
+    pub const fn delete_message(&self, message_id: Id<MessageMarker>) -> DeleteWebhookMessage<'a> {
+        DeleteWebhookMessage::new(self.client, self.webhook_id, self.token, message_id)
+    }
+}
+
+impl Debug for WebhookClient<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("WebhookClient")
+            .field("client", &self.client)
+            .field("token", &"<redacted>")
+            .field("webhook_id", &self.webhook_id)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebhookClient;
+    use crate::{request::TryIntoRequest, Client};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(WebhookClient<'_>: Debug, Send, Sync);
+
+    #[test]
+    fn token_not_used() -> Result<(), Box<dyn std::error::Error>> {
+        let client = Client::new("Bot foo".to_owned());
+        let webhook = client.webhook_client(Id::new(1), "token");
+
+        assert!(!webhook
+            .webhook()
+            .try_into_request()?
+            .use_authorization_token());
+        assert!(!webhook
+            .delete()
+            .try_into_request()?
+            .use_authorization_token());
+        assert!(!webhook
+            .delete_message(Id::new(2))
+            .try_into_request()?
+            .use_authorization_token());
+        assert!(!format!("{webhook:?}").contains("\"token\""));
+
+        Ok(())
+    }
+}
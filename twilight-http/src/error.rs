@@ -1,10 +1,15 @@
-use crate::{api_error::ApiError, json::JsonError, response::StatusCode};
+use crate::{
+    api_error::ApiError,
+    json::JsonError,
+    response::{ResponseMetadata, StatusCode},
+};
 use http::Response;
 use hyper::body::Incoming;
 use std::{
     error::Error as StdError,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     str,
+    time::Duration,
 };
 
 #[derive(Debug)]
@@ -52,6 +57,10 @@ impl Display for Error {
         match &self.kind {
             ErrorType::BuildingRequest => f.write_str("failed to build the request"),
             ErrorType::ChunkingResponse => f.write_str("Chunking the response failed"),
+            ErrorType::CircuitOpen { retry_after } => {
+                f.write_str("circuit breaker is open after repeated failures, retry after ")?;
+                Debug::fmt(retry_after, f)
+            }
             ErrorType::CreatingHeader { name, .. } => {
                 f.write_str("Parsing the value for header {}")?;
                 f.write_str(name)?;
@@ -87,6 +96,9 @@ impl Display for Error {
             ErrorType::Unauthorized => {
                 f.write_str("token in use is invalid, expired, or is revoked")
             }
+            ErrorType::UnsupportedTokenType => {
+                f.write_str("route can't be used with the type of the token in use")
+            }
             ErrorType::Validation => f.write_str("request fields have invalid values"),
         }
     }
@@ -105,6 +117,16 @@ impl StdError for Error {
 pub enum ErrorType {
     BuildingRequest,
     ChunkingResponse,
+    /// Circuit breaker is open after repeated server errors or timeouts, and
+    /// the request wasn't sent.
+    ///
+    /// Refer to [`ClientBuilder::circuit_breaker`] for more information.
+    ///
+    /// [`ClientBuilder::circuit_breaker`]: crate::client::ClientBuilder::circuit_breaker
+    CircuitOpen {
+        /// Remaining duration of the cooldown.
+        retry_after: Duration,
+    },
     CreatingHeader {
         name: String,
     },
@@ -119,6 +141,11 @@ pub enum ErrorType {
     Response {
         body: Vec<u8>,
         error: ApiError,
+        /// Metadata about the request and the ratelimit headers of the
+        /// response.
+        ///
+        /// Boxed to keep the size of [`Error`] down.
+        metadata: Box<ResponseMetadata>,
         status: StatusCode,
     },
     /// API service is unavailable. Consider re-sending the request at a
@@ -133,6 +160,13 @@ pub enum ErrorType {
     /// This can occur if a bot token is invalidated or an access token expires
     /// or is revoked. Recreate the client to configure a new token.
     Unauthorized,
+    /// Route can't be requested with the type of the configured token.
+    ///
+    /// Bearer tokens may only be used for routes acting on behalf of the
+    /// authorized user, such as [`Client::current_user_guilds`].
+    ///
+    /// [`Client::current_user_guilds`]: crate::Client::current_user_guilds
+    UnsupportedTokenType,
     /// A field failed validation requirements during request building.
     ///
     /// The inputs of request methods for fields are validated for correctness.
@@ -197,6 +231,10 @@ impl Debug for ErrorType {
         match self {
             Self::BuildingRequest => f.write_str("BuildingRequest"),
             Self::ChunkingResponse => f.write_str("ChunkingResponse"),
+            Self::CircuitOpen { retry_after } => f
+                .debug_struct("CircuitOpen")
+                .field("retry_after", retry_after)
+                .finish(),
             Self::CreatingHeader { name } => f
                 .debug_struct("CreatingHeader")
                 .field("name", name)
@@ -218,6 +256,7 @@ impl Debug for ErrorType {
             Self::Response {
                 body,
                 error,
+                metadata,
                 status,
             } => {
                 let mut debug = f.debug_struct("Response");
@@ -229,6 +268,7 @@ impl Debug for ErrorType {
                 debug
                     .field("body", body)
                     .field("error", error)
+                    .field("metadata", metadata)
                     .field("status", status)
                     .finish()
             }
@@ -237,6 +277,7 @@ impl Debug for ErrorType {
                 .field("response", response)
                 .finish(),
             Self::Unauthorized => f.write_str("Unauthorized"),
+            Self::UnsupportedTokenType => f.write_str("UnsupportedTokenType"),
             Self::Validation => f.write_str("Validation"),
         }
     }
@@ -247,8 +288,10 @@ mod tests {
     use super::ErrorType;
     use crate::{
         api_error::{ApiError, GeneralApiError},
-        response::StatusCode,
+        request::Method,
+        response::{ResponseMetadata, StatusCode},
     };
+    use twilight_http_ratelimiting::request::Path;
 
     /// Ensure
     #[test]
@@ -291,8 +334,10 @@ mod tests {
             body,
             error: ApiError::General(GeneralApiError {
                 code: 0,
+                errors: Vec::new(),
                 message: "401: Unauthorized".to_owned(),
             }),
+            metadata: Box::new(ResponseMetadata::new(Method::Get, Path::UsersId)),
             status: StatusCode::new(401),
         };
 
@@ -322,9 +367,15 @@ mod tests {
     error: General(
         GeneralApiError {
             code: 0,
+            errors: [],
             message: \"401: Unauthorized\",
         },
     ),
+    metadata: ResponseMetadata {
+        method: Get,
+        ratelimit_headers: None,
+        route: UsersId,
+    },
     status: StatusCode(
         401,
     ),
@@ -1,3 +1,4 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
 #![warn(clippy::missing_const_for_fn, clippy::pedantic, unsafe_code)]
 #![allow(
@@ -7,8 +8,12 @@
 )]
 
 pub mod api_error;
+#[cfg(feature = "cdn")]
+pub mod cdn;
 pub mod client;
 pub mod error;
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
 pub mod request;
 pub mod response;
 pub mod routing;
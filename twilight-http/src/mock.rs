@@ -0,0 +1,564 @@
+//! In-memory transport for testing code using the [`Client`].
+//!
+//! A [`MockTransport`] answers requests with canned [`MockResponse`]s per
+//! route instead of sending them to Discord, and records all requests for
+//! later assertions. Ratelimits and server errors can be simulated via
+//! [`MockResponse::ratelimited`] and [`MockResponse::server_error`].
+//!
+//! Requests to routes without a response are answered with a `404 Not Found`
+//! status code.
+//!
+//! # Examples
+//!
+//! Answer requests for the current user and assert that a request was made:
+//!
+//! ```
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_http::mock::{MockResponse, MockTransport};
+//! use twilight_http_ratelimiting::{Method, Path};
+//!
+//! let transport = MockTransport::new();
+//! transport.route(
+//!     Method::Get,
+//!     Path::UsersId,
+//!     MockResponse::json(&serde_json::json!({
+//!         "avatar": null,
+//!         "discriminator": "0001",
+//!         "id": "1",
+//!         "mfa_enabled": false,
+//!         "username": "twilight",
+//!     })),
+//! );
+//!
+//! let client = transport.client();
+//! let user = client.current_user().await?.model().await?;
+//!
+//! assert_eq!("twilight", user.name);
+//! assert_eq!("/users/@me", transport.requests()[0].path);
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client`]: crate::Client
+
+use crate::{client::ClientBuilder, Client, API_VERSION};
+use http::{
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
+    Request, Response, StatusCode, Uri,
+};
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    body::{Bytes, Incoming},
+    rt::{Read, ReadBufCursor, Write},
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::{
+    client::legacy::connect::{Connected, Connection},
+    rt::TokioIo,
+};
+use serde::Serialize;
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::Infallible,
+    future::{self, Ready},
+    io::Error as IoError,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::io::DuplexStream;
+use tower_service::Service;
+use twilight_http_ratelimiting::{headers::RatelimitScope, Method, Path};
+
+/// Size of the buffer of in-memory connections.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Format a duration as seconds with millisecond precision, as Discord does
+/// in ratelimit headers.
+fn secs_header_value(duration: Duration) -> HeaderValue {
+    let value = format!("{}.{:03}", duration.as_secs(), duration.subsec_millis());
+
+    HeaderValue::from_str(&value).expect("seconds are a valid header value")
+}
+
+/// Canned response returned by a [`MockTransport`].
+#[derive(Clone, Debug)]
+#[must_use = "has no effect if not added to a route"]
+pub struct MockResponse {
+    /// Body of the response.
+    body: Bytes,
+    /// Duration to wait before sending the response.
+    delay: Duration,
+    /// Headers of the response.
+    headers: HeaderMap,
+    /// Status code of the response.
+    status: StatusCode,
+}
+
+impl MockResponse {
+    /// Create an empty response with a status code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the status code is not in the range of 100 to 999.
+    pub fn new(status: u16) -> Self {
+        Self {
+            body: Bytes::new(),
+            delay: Duration::ZERO,
+            headers: HeaderMap::new(),
+            status: StatusCode::from_u16(status).expect("status code is invalid"),
+        }
+    }
+
+    /// Create a `200 OK` response with a value serialized as JSON.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can't be serialized.
+    pub fn json<T: Serialize + ?Sized>(value: &T) -> Self {
+        Self::new(200)
+            .body(serde_json::to_vec(value).expect("value is serializable"))
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+    }
+
+    /// Create a `429 Too Many Requests` response.
+    ///
+    /// The response contains the same headers and body as Discord's, so the
+    /// client's ratelimiter reacts to it: a [`RatelimitScope::Global`] scope
+    /// locks all requests for the duration, while the [`RatelimitScope::User`]
+    /// and [`RatelimitScope::Shared`] scopes exhaust the bucket of the route
+    /// until the duration elapsed.
+    pub fn ratelimited(retry_after: Duration, scope: RatelimitScope) -> Self {
+        let global = scope == RatelimitScope::Global;
+        let body = serde_json::json!({
+            "global": global,
+            "message": "You are being rate limited.",
+            "retry_after": retry_after.as_secs_f64(),
+        });
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let retry_after_secs = retry_after.as_secs_f64().ceil() as u64;
+
+        let response = Self::json(&body)
+            .status(429)
+            .header(
+                HeaderName::from_static("retry-after"),
+                HeaderValue::from(retry_after_secs),
+            )
+            .header(
+                HeaderName::from_static("x-ratelimit-scope"),
+                HeaderValue::from_static(match scope {
+                    RatelimitScope::Global => "global",
+                    RatelimitScope::Shared => "shared",
+                    RatelimitScope::User => "user",
+                }),
+            );
+
+        if global {
+            return response.header(
+                HeaderName::from_static("x-ratelimit-global"),
+                HeaderValue::from_static("true"),
+            );
+        }
+
+        let reset = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            + retry_after;
+
+        response
+            .header(
+                HeaderName::from_static("x-ratelimit-bucket"),
+                HeaderValue::from_static("mock"),
+            )
+            .header(
+                HeaderName::from_static("x-ratelimit-limit"),
+                HeaderValue::from(1),
+            )
+            .header(
+                HeaderName::from_static("x-ratelimit-remaining"),
+                HeaderValue::from(0),
+            )
+            .header(
+                HeaderName::from_static("x-ratelimit-reset"),
+                secs_header_value(reset),
+            )
+            .header(
+                HeaderName::from_static("x-ratelimit-reset-after"),
+                secs_header_value(retry_after),
+            )
+    }
+
+    /// Create a server error response, such as `500 Internal Server Error` or
+    /// `502 Bad Gateway`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the status code is not in the range of 100 to 999.
+    pub fn server_error(status: u16) -> Self {
+        let response = Self::new(status);
+        let message = format!(
+            "{}: {}",
+            status,
+            response.status.canonical_reason().unwrap_or_default()
+        );
+
+        Self::json(&serde_json::json!({ "code": 0, "message": message })).status(status)
+    }
+
+    /// Set the body of the response.
+    pub fn body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = body.into();
+
+        self
+    }
+
+    /// Wait for a duration before sending the response, simulating a slow
+    /// connection or server.
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+
+        self
+    }
+
+    /// Add a header to the response.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+
+        self
+    }
+
+    /// Set the status code of the response.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the status code is not in the range of 100 to 999.
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = StatusCode::from_u16(status).expect("status code is invalid");
+
+        self
+    }
+
+    /// Convert into a response to send over the connection.
+    fn into_response(self) -> Response<Full<Bytes>> {
+        let mut response = Response::new(Full::new(self.body));
+        *response.headers_mut() = self.headers;
+        *response.status_mut() = self.status;
+
+        response
+    }
+}
+
+/// Request received by a [`MockTransport`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct MockRequest {
+    /// Body of the request.
+    pub body: Bytes,
+    /// Headers of the request.
+    pub headers: HeaderMap,
+    /// Method of the request.
+    pub method: Method,
+    /// Path of the request relative to the API version, such as
+    /// `/channels/1/messages?limit=10`.
+    pub path: String,
+}
+
+/// Routes and recorded requests of a [`MockTransport`].
+#[derive(Debug, Default)]
+struct State {
+    /// Requests received so far.
+    requests: Vec<MockRequest>,
+    /// Queued responses for each route.
+    routes: HashMap<(Method, Path), VecDeque<MockResponse>>,
+}
+
+/// In-memory transport answering requests with canned responses.
+///
+/// Refer to the [module-level documentation][`self`] for more information.
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockTransport {
+    /// Create a new transport without any routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a response for requests to a route.
+    ///
+    /// Responses added for the same route are returned in order; the last one
+    /// is returned for all remaining requests.
+    pub fn route(&self, method: Method, path: Path, response: MockResponse) -> &Self {
+        self.state()
+            .routes
+            .entry((method, path))
+            .or_default()
+            .push_back(response);
+
+        self
+    }
+
+    /// Requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state().requests.clone()
+    }
+
+    /// Create a builder for a client sending requests to this transport.
+    pub fn client_builder(&self) -> ClientBuilder {
+        Client::builder().connector(MockConnector(self.clone()))
+    }
+
+    /// Create a client sending requests to this transport.
+    pub fn client(&self) -> Client {
+        self.client_builder().build()
+    }
+
+    /// Lock the routes and recorded requests.
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("state poisoned")
+    }
+
+    /// Record a request and determine its response.
+    fn respond(&self, request: MockRequest) -> MockResponse {
+        let mut state = self.state();
+
+        let path = request
+            .path
+            .strip_prefix('/')
+            .and_then(|path| path.split('?').next())
+            .and_then(|path| Path::try_from((request.method, path)).ok());
+        let method = request.method;
+        state.requests.push(request);
+
+        let Some(queue) = path.and_then(|path| state.routes.get_mut(&(method, path))) else {
+            return Self::not_found();
+        };
+
+        if queue.len() > 1 {
+            queue.pop_front()
+        } else {
+            queue.front().cloned()
+        }
+        .unwrap_or_else(Self::not_found)
+    }
+
+    /// Response to requests to routes without responses.
+    fn not_found() -> MockResponse {
+        MockResponse::json(&serde_json::json!({ "code": 0, "message": "404: Not Found" }))
+            .status(404)
+    }
+}
+
+/// Connector creating in-memory connections to a [`MockTransport`].
+#[derive(Clone, Debug)]
+struct MockConnector(MockTransport);
+
+impl Service<Uri> for MockConnector {
+    type Response = MockStream;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _: Uri) -> Self::Future {
+        let (client, server) = tokio::io::duplex(BUFFER_SIZE);
+        let transport = self.0.clone();
+
+        tokio::spawn(async move {
+            let service = service_fn(move |request: Request<Incoming>| {
+                let transport = transport.clone();
+
+                async move {
+                    let (parts, body) = request.into_parts();
+                    let body = body.collect().await?.to_bytes();
+
+                    let request = MockRequest {
+                        body,
+                        headers: parts.headers,
+                        method: method(&parts.method),
+                        path: parts
+                            .uri
+                            .path_and_query()
+                            .map_or_else(|| parts.uri.path(), |path| path.as_str())
+                            .trim_start_matches(&format!("/api/v{API_VERSION}"))
+                            .to_owned(),
+                    };
+
+                    let response = transport.respond(request);
+                    tokio::time::sleep(response.delay).await;
+
+                    Ok::<_, hyper::Error>(response.into_response())
+                }
+            });
+
+            http1::Builder::new()
+                .serve_connection(TokioIo::new(server), service)
+                .await
+        });
+
+        future::ready(Ok(MockStream(TokioIo::new(client))))
+    }
+}
+
+/// Convert an HTTP method into a ratelimiter method.
+const fn method(method: &http::Method) -> Method {
+    match *method {
+        http::Method::DELETE => Method::Delete,
+        http::Method::PATCH => Method::Patch,
+        http::Method::POST => Method::Post,
+        http::Method::PUT => Method::Put,
+        _ => Method::Get,
+    }
+}
+
+/// In-memory connection to a [`MockTransport`].
+struct MockStream(TokioIo<DuplexStream>);
+
+impl Connection for MockStream {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl Read for MockStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: ReadBufCursor<'_>,
+    ) -> Poll<Result<(), IoError>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl Write for MockStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, IoError>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), IoError>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), IoError>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockResponse, MockTransport};
+    use crate::{api_error::ApiError, error::ErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use twilight_http_ratelimiting::{
+        headers::{RatelimitHeaders, RatelimitScope},
+        Method, Path,
+    };
+    use twilight_model::id::Id;
+
+    assert_impl_all!(MockResponse: Clone, Debug, Send, Sync);
+    assert_impl_all!(MockTransport: Clone, Debug, Default, Send, Sync);
+
+    fn ratelimit_headers(response: &MockResponse) -> RatelimitHeaders {
+        let pairs = response
+            .headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_bytes()));
+
+        RatelimitHeaders::from_pairs(pairs).unwrap()
+    }
+
+    #[test]
+    fn ratelimited_headers() {
+        let retry_after = Duration::from_millis(1500);
+
+        for scope in [RatelimitScope::Shared, RatelimitScope::User] {
+            let response = MockResponse::ratelimited(retry_after, scope);
+            let RatelimitHeaders::Present(present) = ratelimit_headers(&response) else {
+                panic!("headers of scope {scope:?} aren't present");
+            };
+
+            assert_eq!(Some("mock"), present.bucket());
+            assert_eq!(1, present.limit());
+            assert_eq!(0, present.remaining());
+            assert_eq!(1500, present.reset_after());
+            assert_eq!(Some(scope), present.scope());
+        }
+
+        let response = MockResponse::ratelimited(retry_after, RatelimitScope::Global);
+        assert!(matches!(
+            ratelimit_headers(&response),
+            RatelimitHeaders::Global(global) if global.retry_after() == 2
+        ));
+    }
+
+    #[tokio::test]
+    async fn responses() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let transport = MockTransport::new();
+        transport
+            .route(
+                Method::Get,
+                Path::ChannelsIdMessages(1),
+                MockResponse::ratelimited(Duration::from_millis(10), RatelimitScope::User),
+            )
+            .route(
+                Method::Get,
+                Path::ChannelsIdMessages(1),
+                MockResponse::json(&[0; 0]),
+            )
+            .route(Method::Get, Path::UsersId, MockResponse::server_error(500));
+        let client = transport.client_builder().ratelimiter(None).build();
+
+        let error = client
+            .channel_messages(Id::new(1))
+            .limit(10)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response {
+                error: ApiError::Ratelimited(_),
+                status,
+                ..
+            } if status.get() == 429
+        ));
+
+        let messages = client
+            .channel_messages(Id::new(1))
+            .limit(10)
+            .await?
+            .models()
+            .await?;
+        assert!(messages.is_empty());
+
+        let error = client.current_user().await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.get() == 500
+        ));
+
+        let error = client.channel(Id::new(1)).await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.get() == 404
+        ));
+
+        let requests = transport.requests();
+        assert_eq!(4, requests.len());
+        assert_eq!(Method::Get, requests[0].method);
+        assert_eq!("/channels/1/messages?limit=10", requests[0].path);
+        assert_eq!("/users/@me", requests[2].path);
+
+        Ok(())
+    }
+}
@@ -4,11 +4,13 @@ mod delete_followup;
 mod delete_response;
 mod get_followup;
 mod get_response;
+mod respond_or_defer;
 mod update_followup;
 mod update_response;
 
 pub use self::{
     create_followup::CreateFollowup, create_response::CreateResponse,
     delete_followup::DeleteFollowup, delete_response::DeleteResponse, get_followup::GetFollowup,
-    get_response::GetResponse, update_followup::UpdateFollowup, update_response::UpdateResponse,
+    get_response::GetResponse, respond_or_defer::RespondOrDefer, update_followup::UpdateFollowup,
+    update_response::UpdateResponse,
 };
@@ -0,0 +1,323 @@
+use super::{CreateResponse, UpdateResponse};
+use crate::{client::Client, error::Error};
+use std::{future::Future, pin::pin, time::Duration};
+use twilight_model::{
+    channel::message::MessageFlags,
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+    id::{
+        marker::{ApplicationMarker, InteractionMarker},
+        Id,
+    },
+};
+
+/// Default duration to wait for the response before deferring it.
+const DEFAULT_DEADLINE: Duration = Duration::from_secs(2);
+
+/// Respond to an interaction, deferring the response if it isn't ready in
+/// time.
+///
+/// Discord requires interactions to be responded to within three seconds.
+/// If the response future doesn't resolve within the [deadline], a deferred
+/// response is sent instead and the original response is edited with the
+/// [`InteractionResponseData`] of the eventual response. Only the message
+/// fields of the data, such as the content and embeds, are used for the edit;
+/// the [`ephemeral`] flag of the deferred response can't be changed
+/// afterwards.
+///
+/// The type of the deferred response depends on the [kind] of the eventual
+/// response: [`UpdateMessage`] responses of component and modal interactions
+/// are deferred with a [`DeferredUpdateMessage`] response, editing the
+/// message the component is attached to, while all others are deferred with a
+/// [`DeferredChannelMessageWithSource`] response.
+///
+/// # Examples
+///
+/// Respond to a command whose response takes a while to compute:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+///     id::Id,
+/// };
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let application_id = Id::new(1);
+/// let interaction_id = Id::new(2);
+///
+/// client
+///     .interaction(application_id)
+///     .respond_or_defer(interaction_id, "token here")
+///     .ephemeral(true)
+///     .respond(async {
+///         InteractionResponse {
+///             kind: InteractionResponseType::ChannelMessageWithSource,
+///             data: Some(InteractionResponseData {
+///                 content: Some("done".to_owned()),
+///                 ..Default::default()
+///             }),
+///         }
+///     })
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`DeferredChannelMessageWithSource`]: InteractionResponseType::DeferredChannelMessageWithSource
+/// [`DeferredUpdateMessage`]: InteractionResponseType::DeferredUpdateMessage
+/// [`UpdateMessage`]: InteractionResponseType::UpdateMessage
+/// [`ephemeral`]: Self::ephemeral
+/// [deadline]: Self::deadline
+/// [kind]: Self::kind
+#[must_use = "has no effect unless a response is passed to `respond`"]
+pub struct RespondOrDefer<'a> {
+    application_id: Id<ApplicationMarker>,
+    deadline: Duration,
+    ephemeral: bool,
+    http: &'a Client,
+    interaction_id: Id<InteractionMarker>,
+    interaction_token: &'a str,
+    kind: InteractionResponseType,
+}
+
+impl<'a> RespondOrDefer<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        interaction_id: Id<InteractionMarker>,
+        interaction_token: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            deadline: DEFAULT_DEADLINE,
+            ephemeral: false,
+            http,
+            interaction_id,
+            interaction_token,
+            kind: InteractionResponseType::ChannelMessageWithSource,
+        }
+    }
+
+    /// Set the duration to wait for the response before deferring it.
+    ///
+    /// Defaults to two seconds, leaving time for the deferred response to
+    /// reach Discord.
+    pub const fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = deadline;
+
+        self
+    }
+
+    /// Set whether the deferred response is only visible to the user who
+    /// invoked the interaction.
+    ///
+    /// Has no effect on [`DeferredUpdateMessage`] responses, which don't
+    /// create a message.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`DeferredUpdateMessage`]: InteractionResponseType::DeferredUpdateMessage
+    pub const fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+
+        self
+    }
+
+    /// Set the type of the eventual response, determining the type of the
+    /// deferred response.
+    ///
+    /// Set to [`UpdateMessage`] when responding to component and modal
+    /// interactions by updating their message.
+    ///
+    /// Defaults to [`ChannelMessageWithSource`].
+    ///
+    /// [`ChannelMessageWithSource`]: InteractionResponseType::ChannelMessageWithSource
+    /// [`UpdateMessage`]: InteractionResponseType::UpdateMessage
+    pub const fn kind(mut self, kind: InteractionResponseType) -> Self {
+        self.kind = kind;
+
+        self
+    }
+
+    /// Wait for the response, sending it or deferring it if the deadline is
+    /// exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending the response, the deferred response, or
+    /// the edit of the deferred response failed.
+    pub async fn respond(
+        self,
+        response: impl Future<Output = InteractionResponse>,
+    ) -> Result<(), Error> {
+        let mut response = pin!(response);
+
+        if let Ok(response) = tokio::time::timeout(self.deadline, response.as_mut()).await {
+            CreateResponse::new(
+                self.http,
+                self.interaction_id,
+                self.interaction_token,
+                &response,
+            )
+            .await?;
+
+            return Ok(());
+        }
+
+        let deferred = match self.kind {
+            InteractionResponseType::DeferredUpdateMessage
+            | InteractionResponseType::UpdateMessage => InteractionResponse {
+                kind: InteractionResponseType::DeferredUpdateMessage,
+                data: None,
+            },
+            _ => InteractionResponse {
+                kind: InteractionResponseType::DeferredChannelMessageWithSource,
+                data: self.ephemeral.then(|| InteractionResponseData {
+                    flags: Some(MessageFlags::EPHEMERAL),
+                    ..Default::default()
+                }),
+            },
+        };
+
+        CreateResponse::new(
+            self.http,
+            self.interaction_id,
+            self.interaction_token,
+            &deferred,
+        )
+        .await?;
+
+        let Some(data) = response.await.data else {
+            return Ok(());
+        };
+
+        let mut update =
+            UpdateResponse::new(self.http, self.application_id, self.interaction_token);
+
+        if let Some(components) = &data.components {
+            update = update.components(Some(components));
+        }
+
+        if let Some(content) = &data.content {
+            update = update.content(Some(content));
+        }
+
+        if let Some(embeds) = &data.embeds {
+            update = update.embeds(Some(embeds));
+        }
+
+        if let Some(allowed_mentions) = &data.allowed_mentions {
+            update = update.allowed_mentions(Some(allowed_mentions));
+        }
+
+        if let Some(attachments) = &data.attachments {
+            update = update.attachments(attachments);
+        }
+
+        update.await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RespondOrDefer;
+    use crate::mock::{MockRequest, MockResponse, MockTransport};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, time::Duration};
+    use tokio::time;
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::{
+        http::interaction::{
+            InteractionResponse, InteractionResponseData, InteractionResponseType,
+        },
+        id::Id,
+    };
+
+    assert_impl_all!(RespondOrDefer<'_>: Send, Sync);
+
+    fn body(request: &MockRequest) -> &str {
+        std::str::from_utf8(&request.body).unwrap()
+    }
+
+    fn response(content: &str) -> InteractionResponse {
+        InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(InteractionResponseData {
+                content: Some(content.to_owned()),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn respond() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport
+            .route(
+                Method::Post,
+                Path::InteractionCallback(2),
+                MockResponse::new(204),
+            )
+            .route(
+                Method::Patch,
+                Path::WebhooksIdTokenMessagesId(1, "token".to_owned()),
+                MockResponse::json(&serde_json::json!({})),
+            );
+        let client = transport.client_builder().ratelimiter(None).build();
+        let interaction = client.interaction(Id::new(1));
+
+        interaction
+            .respond_or_defer(Id::new(2), "token")
+            .respond(async { response("fast") })
+            .await?;
+
+        interaction
+            .respond_or_defer(Id::new(2), "token")
+            .deadline(Duration::from_millis(10))
+            .ephemeral(true)
+            .respond(async {
+                time::sleep(Duration::from_millis(100)).await;
+
+                response("slow")
+            })
+            .await?;
+
+        interaction
+            .respond_or_defer(Id::new(2), "token")
+            .deadline(Duration::from_millis(10))
+            .ephemeral(true)
+            .kind(InteractionResponseType::UpdateMessage)
+            .respond(async {
+                time::sleep(Duration::from_millis(100)).await;
+
+                response("updated")
+            })
+            .await?;
+
+        let requests = transport.requests();
+        assert_eq!(5, requests.len());
+
+        assert_eq!(Method::Post, requests[0].method);
+        assert_eq!("/interactions/2/token/callback", requests[0].path);
+        assert!(body(&requests[0]).contains(r#""content":"fast""#));
+        assert!(body(&requests[0]).contains(r#""type":4"#));
+
+        assert_eq!("/interactions/2/token/callback", requests[1].path);
+        assert_eq!(r#"{"type":5,"data":{"flags":64}}"#, body(&requests[1]));
+
+        assert_eq!(Method::Patch, requests[2].method);
+        assert_eq!("/webhooks/1/token/messages/@original", requests[2].path);
+        assert!(body(&requests[2]).contains(r#""content":"slow""#));
+
+        assert_eq!("/interactions/2/token/callback", requests[3].path);
+        assert_eq!(r#"{"type":6}"#, body(&requests[3]));
+
+        assert_eq!("/webhooks/1/token/messages/@original", requests[4].path);
+        assert!(body(&requests[4]).contains(r#""content":"updated""#));
+
+        Ok(())
+    }
+}
@@ -38,13 +38,17 @@ impl<'a> AttachmentManager<'a> {
             .iter()
             .map(|attachment| PartialAttachment {
                 description: attachment.description.as_deref(),
+                duration_millis: attachment.duration_millis,
                 filename: Some(attachment.filename.as_ref()),
                 id: attachment.id,
+                waveform: attachment.waveform.as_deref(),
             })
             .chain(self.ids.iter().map(|id| PartialAttachment {
                 description: None,
+                duration_millis: None,
                 filename: None,
                 id: id.get(),
+                waveform: None,
             }))
             .collect()
     }
@@ -78,9 +82,61 @@ impl Default for AttachmentManager<'_> {
 pub struct PartialAttachment<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
+    /// Duration of the audio file in milliseconds, if the attachment is a
+    /// voice message.
+    ///
+    /// Serialized as `duration_secs`, in fractional seconds.
+    #[serde(
+        default,
+        rename = "duration_secs",
+        skip_serializing_if = "Option::is_none",
+        with = "duration_secs"
+    )]
+    pub duration_millis: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filename: Option<&'a str>,
     pub id: u64,
+    /// Base64 encoded sampled waveform of the audio file, if the attachment is
+    /// a voice message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waveform: Option<&'a str>,
+}
+
+/// (De)serialize a duration in milliseconds as fractional seconds.
+mod duration_secs {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        let Some(secs) = Option::<f64>::deserialize(deserializer)? else {
+            return Ok(None);
+        };
+
+        if !secs.is_finite() || secs < 0.0 {
+            return Err(D::Error::custom(
+                "duration must be a non-negative number of seconds",
+            ));
+        }
+
+        Ok(Some((secs * 1000.0).round() as u64))
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::ref_option,
+        clippy::trivially_copy_pass_by_ref
+    )]
+    pub fn serialize<S: Serializer>(
+        millis: &Option<u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match millis {
+            Some(millis) => serializer.serialize_some(&(*millis as f64 / 1000.0)),
+            None => serializer.serialize_none(),
+        }
+    }
 }
 
 /// Count the number of digits in a given number.
@@ -165,4 +221,20 @@ mod tests {
         assert_eq!(1, num_digits(1));
         assert_eq!(2, num_digits(10));
     }
+
+    #[test]
+    fn partial_attachment_voice_message() {
+        let partial = PartialAttachment {
+            description: None,
+            duration_millis: Some(1500),
+            filename: Some("voice.ogg"),
+            id: 1,
+            waveform: Some("Zm9v"),
+        };
+
+        assert_eq!(
+            r#"{"duration_secs":1.5,"filename":"voice.ogg","id":1,"waveform":"Zm9v"}"#,
+            serde_json::to_string(&partial).unwrap()
+        );
+    }
 }
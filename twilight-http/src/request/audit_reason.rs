@@ -20,11 +20,12 @@ mod private {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
-            thread::UpdateThread,
+            message::{DeleteManyMessages, DeleteMessage, DeleteMessages},
+            stage::{CreateStageInstance, DeleteStageInstance, UpdateStageInstance},
+            thread::{CreateForumThread, CreateThread, CreateThreadFromMessage, UpdateThread},
             webhook::{CreateWebhook, DeleteWebhook, DeleteWebhookMessage, UpdateWebhook},
-            CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
-            UpdateChannelPermission,
+            CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin,
+            FollowNewsChannel, UpdateChannel, UpdateChannelPermission,
         },
         guild::{
             auto_moderation::{
@@ -35,10 +36,10 @@ mod private {
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole, UpdateRolePositions},
-            sticker::{CreateGuildSticker, UpdateGuildSticker},
+            sticker::{CreateGuildSticker, DeleteGuildSticker, UpdateGuildSticker},
             update_guild_onboarding::UpdateGuildOnboarding,
             CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild, UpdateGuildMfa,
-            UpdateGuildWidgetSettings,
+            UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
         },
         scheduled_event::{
             CreateGuildExternalScheduledEvent, CreateGuildScheduledEvent,
@@ -55,6 +56,7 @@ mod private {
     impl Sealed for CreateAutoModerationRule<'_> {}
     impl Sealed for CreateBan<'_> {}
     impl Sealed for CreateEmoji<'_> {}
+    impl Sealed for CreateForumThread<'_> {}
     impl Sealed for CreateGuildChannel<'_> {}
     impl Sealed for CreateGuildExternalScheduledEvent<'_> {}
     impl Sealed for CreateGuildPrune<'_> {}
@@ -65,20 +67,27 @@ mod private {
     impl Sealed for CreateInvite<'_> {}
     impl Sealed for CreatePin<'_> {}
     impl Sealed for CreateRole<'_> {}
+    impl Sealed for CreateStageInstance<'_> {}
+    impl Sealed for CreateThread<'_> {}
+    impl Sealed for CreateThreadFromMessage<'_> {}
     impl Sealed for CreateWebhook<'_> {}
     impl Sealed for DeleteAutoModerationRule<'_> {}
     impl Sealed for DeleteBan<'_> {}
     impl Sealed for DeleteChannel<'_> {}
     impl Sealed for DeleteChannelPermissionConfigured<'_> {}
     impl Sealed for DeleteEmoji<'_> {}
+    impl Sealed for DeleteGuildSticker<'_> {}
     impl Sealed for DeleteGuildIntegration<'_> {}
     impl Sealed for DeleteInvite<'_> {}
+    impl Sealed for DeleteManyMessages<'_> {}
     impl Sealed for DeleteMessage<'_> {}
     impl Sealed for DeleteMessages<'_> {}
     impl Sealed for DeletePin<'_> {}
     impl Sealed for DeleteRole<'_> {}
+    impl Sealed for DeleteStageInstance<'_> {}
     impl Sealed for DeleteWebhook<'_> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
+    impl Sealed for FollowNewsChannel<'_> {}
     impl Sealed for RemoveMember<'_> {}
     impl Sealed for RemoveRoleFromMember<'_> {}
     impl Sealed for UpdateAutoModerationRule<'_> {}
@@ -93,9 +102,11 @@ mod private {
     impl Sealed for UpdateGuildOnboarding<'_> {}
     impl Sealed for UpdateGuildScheduledEvent<'_> {}
     impl Sealed for UpdateGuildSticker<'_> {}
+    impl Sealed for UpdateGuildWelcomeScreen<'_> {}
     impl Sealed for UpdateGuildWidgetSettings<'_> {}
     impl Sealed for UpdateRole<'_> {}
     impl Sealed for UpdateRolePositions<'_> {}
+    impl Sealed for UpdateStageInstance<'_> {}
     impl Sealed for UpdateThread<'_> {}
     impl Sealed for UpdateWebhook<'_> {}
 }
@@ -106,10 +117,12 @@ mod tests {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
+            message::{DeleteManyMessages, DeleteMessage, DeleteMessages},
+            stage::{CreateStageInstance, DeleteStageInstance, UpdateStageInstance},
+            thread::{CreateForumThread, CreateThread, CreateThreadFromMessage},
             webhook::{CreateWebhook, DeleteWebhook, UpdateWebhook},
-            CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
-            UpdateChannelPermission,
+            CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin,
+            FollowNewsChannel, UpdateChannel, UpdateChannelPermission,
         },
         guild::{
             ban::{CreateBan, DeleteBan},
@@ -117,36 +130,49 @@ mod tests {
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole, UpdateRolePositions},
-            sticker::{CreateGuildSticker, UpdateGuildSticker},
+            sticker::{CreateGuildSticker, DeleteGuildSticker, UpdateGuildSticker},
             CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild,
+            UpdateGuildWelcomeScreen,
         },
         user::UpdateCurrentUser,
+        Request, TryIntoRequest, REASON_HEADER_NAME,
     };
+    use crate::Client;
     use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::error::Error;
+    use twilight_model::{channel::ChannelType, id::Id};
 
     assert_obj_safe!(AuditLogReason<'_>);
 
     assert_impl_all!(AddRoleToMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateBan<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateEmoji<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateForumThread<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateGuildChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateGuildPrune<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateGuildSticker<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateInvite<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreatePin<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateRole<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateStageInstance<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateThread<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateThreadFromMessage<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateWebhook<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteBan<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteChannelPermissionConfigured<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteEmoji<'_>: AuditLogReason<'static>);
+    assert_impl_all!(DeleteGuildSticker<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteGuildIntegration<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteInvite<'_>: AuditLogReason<'static>);
+    assert_impl_all!(DeleteManyMessages<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessage<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessages<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeletePin<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteRole<'_>: AuditLogReason<'static>);
+    assert_impl_all!(DeleteStageInstance<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteWebhook<'_>: AuditLogReason<'static>);
+    assert_impl_all!(FollowNewsChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(RemoveMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(RemoveRoleFromMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateChannel<'_>: AuditLogReason<'static>);
@@ -157,7 +183,85 @@ mod tests {
     assert_impl_all!(UpdateGuild<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuildMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuildSticker<'_>: AuditLogReason<'static>);
+    assert_impl_all!(UpdateGuildWelcomeScreen<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateRole<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateRolePositions<'_>: AuditLogReason<'static>);
+    assert_impl_all!(UpdateStageInstance<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateWebhook<'_>: AuditLogReason<'static>);
+
+    const REASON: &str = "spam";
+
+    fn assert_reason(request: &Request) {
+        assert_eq!(
+            Some(REASON),
+            request
+                .headers()
+                .and_then(|headers| headers.get(REASON_HEADER_NAME))
+                .and_then(|value| value.to_str().ok())
+        );
+    }
+
+    #[test]
+    fn headers() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        assert_reason(
+            &client
+                .create_forum_thread(Id::new(1), "name")
+                .reason(REASON)
+                .message()
+                .content("content")
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .create_stage_instance(Id::new(1), "topic")
+                .reason(REASON)
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .create_thread(Id::new(1), "name", ChannelType::PublicThread)
+                .reason(REASON)
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .create_thread_from_message(Id::new(1), Id::new(2), "name")
+                .reason(REASON)
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .delete_guild_sticker(Id::new(1), Id::new(2))
+                .reason(REASON)
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .delete_stage_instance(Id::new(1))
+                .reason(REASON)
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .follow_news_channel(Id::new(1), Id::new(2))
+                .reason(REASON)
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .update_guild_welcome_screen(Id::new(1))
+                .reason(REASON)
+                .try_into_request()?,
+        );
+        assert_reason(
+            &client
+                .update_stage_instance(Id::new(1))
+                .reason(REASON)
+                .try_into_request()?,
+        );
+
+        Ok(())
+    }
 }
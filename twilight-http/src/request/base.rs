@@ -5,6 +5,7 @@ use crate::{
 };
 use http::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Serialize;
+use std::time::Duration;
 
 /// Builder to create a customized request.
 ///
@@ -71,6 +72,7 @@ impl RequestBuilder {
             method,
             path: path_and_query,
             ratelimit_path,
+            timeout: None,
             use_authorization_token: true,
         }))
     }
@@ -129,6 +131,16 @@ impl RequestBuilder {
         self
     }
 
+    /// Set the duration to wait for a response before timing out,
+    /// overriding the client's timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        if let Ok(request) = self.0.as_mut() {
+            request.timeout = Some(timeout);
+        }
+
+        self
+    }
+
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
@@ -150,6 +162,7 @@ pub struct Request {
     pub(crate) method: Method,
     pub(crate) path: String,
     pub(crate) ratelimit_path: Path,
+    pub(crate) timeout: Option<Duration>,
     pub(crate) use_authorization_token: bool,
 }
 
@@ -205,6 +218,7 @@ impl Request {
             method: route.method(),
             path: route.to_string(),
             ratelimit_path: route.to_path(),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -239,6 +253,11 @@ impl Request {
         &self.ratelimit_path
     }
 
+    /// Duration to wait for a response before timing out, if overridden.
+    pub const fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
     /// Whether to use the client's authorization token in the request.
     pub const fn use_authorization_token(&self) -> bool {
         self.use_authorization_token
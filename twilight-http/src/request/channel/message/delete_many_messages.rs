@@ -0,0 +1,328 @@
+use super::{DeleteMessage, DeleteMessages};
+use crate::{client::Client, error::Error, request::AuditLogReason};
+use std::{
+    collections::HashSet,
+    future::{Future, IntoFuture},
+    pin::Pin,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker},
+    Id,
+};
+use twilight_validate::{
+    channel::CHANNEL_BULK_DELETE_MESSAGES_MAX,
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
+
+/// Discord epoch of snowflakes, as the number of milliseconds since the Unix
+/// epoch.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Maximum age of messages in milliseconds to be deleted in bulk.
+///
+/// Discord doesn't bulk delete messages older than two weeks. A minute is
+/// subtracted to account for clock drift and the time until the request is
+/// processed.
+const BULK_DELETE_AGE_MAX: u64 = 14 * 24 * 60 * 60 * 1000 - 60 * 1000;
+
+/// Delete any number of messages by [`Id<ChannelMarker>`] and a list of
+/// [`Id<MessageMarker>`]s, using as few requests as possible.
+///
+/// Messages younger than two weeks are deleted in bulk, in chunks of up to
+/// 100 messages. Messages older than that, as determined by the timestamp of
+/// their ID, and chunks of a single message are deleted individually.
+/// Duplicate IDs are ignored. Requests are sent one after another.
+///
+/// Errors of individual requests don't stop the remaining messages from being
+/// deleted; they are collected in the [`DeleteManyMessagesOutcome`] instead.
+/// See [Discord Docs/Bulk Delete Messages].
+///
+/// # Examples
+///
+/// Delete messages and log the ones which couldn't be deleted:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::{request::AuditLogReason, Client};
+/// use twilight_model::id::Id;
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let message_ids = (1..=250).map(Id::new).collect::<Vec<_>>();
+///
+/// let outcome = client
+///     .delete_many_messages(Id::new(1), &message_ids)
+///     .reason("purge")
+///     .await?;
+///
+/// for (message_id, error) in outcome.failed() {
+///     eprintln!("failed to delete message {message_id}: {error}");
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [Discord Docs/Bulk Delete Messages]: https://discord.com/developers/docs/resources/channel#bulk-delete-messages
+#[must_use = "requests must be configured and executed"]
+pub struct DeleteManyMessages<'a> {
+    channel_id: Id<ChannelMarker>,
+    http: &'a Client,
+    message_ids: &'a [Id<MessageMarker>],
+    reason: Result<Option<&'a str>, ValidationError>,
+}
+
+impl<'a> DeleteManyMessages<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &'a [Id<MessageMarker>],
+    ) -> Self {
+        Self {
+            channel_id,
+            http,
+            message_ids,
+            reason: Ok(None),
+        }
+    }
+
+    /// Delete the messages, bulk deleting them where possible.
+    async fn exec(self) -> Result<DeleteManyMessagesOutcome, Error> {
+        let reason = self.reason.map_err(Error::validation)?;
+        let (recent, old) = partition(self.message_ids, now());
+        let mut outcome = DeleteManyMessagesOutcome::default();
+
+        let (bulk, single) = split_chunks(&recent);
+
+        for chunk in bulk {
+            let mut request = DeleteMessages::new(self.http, self.channel_id, chunk);
+
+            if let Some(reason) = reason {
+                request = request.reason(reason);
+            }
+
+            match request.await {
+                Ok(_) => outcome.deleted.extend_from_slice(chunk),
+                Err(source) => outcome.failed.push((chunk.to_vec(), source)),
+            }
+        }
+
+        for message_id in single.into_iter().chain(old) {
+            let mut request = DeleteMessage::new(self.http, self.channel_id, message_id);
+
+            if let Some(reason) = reason {
+                request = request.reason(reason);
+            }
+
+            match request.await {
+                Ok(_) => outcome.deleted.push(message_id),
+                Err(source) => outcome.failed.push((vec![message_id], source)),
+            }
+        }
+
+        Ok(outcome)
+    }
+}
+
+impl<'a> AuditLogReason<'a> for DeleteManyMessages<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
+    }
+}
+
+impl<'a> IntoFuture for DeleteManyMessages<'a> {
+    type Output = Result<DeleteManyMessagesOutcome, Error>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.exec())
+    }
+}
+
+/// Outcome of a [`DeleteManyMessages`] request.
+///
+/// A failed bulk delete request fails all of the messages in its chunk, which
+/// then share the error.
+#[derive(Debug, Default)]
+pub struct DeleteManyMessagesOutcome {
+    /// IDs of the deleted messages.
+    deleted: Vec<Id<MessageMarker>>,
+    /// IDs of the messages which failed to be deleted, along with the error of
+    /// their request.
+    failed: Vec<(Vec<Id<MessageMarker>>, Error)>,
+}
+
+impl DeleteManyMessagesOutcome {
+    /// IDs of the deleted messages.
+    pub fn deleted(&self) -> &[Id<MessageMarker>] {
+        &self.deleted
+    }
+
+    /// IDs of the messages which failed to be deleted, along with the error
+    /// of their request.
+    pub fn failed(&self) -> impl Iterator<Item = (Id<MessageMarker>, &Error)> {
+        self.failed
+            .iter()
+            .flat_map(|(message_ids, error)| message_ids.iter().map(move |id| (*id, error)))
+    }
+
+    /// Whether all of the messages were deleted.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Current time as the number of milliseconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| {
+            duration.as_millis().try_into().unwrap_or(u64::MAX)
+        })
+}
+
+/// Deduplicate message IDs and partition them into those which may be deleted
+/// in bulk and those which are too old, relative to the Unix timestamp `now`
+/// in milliseconds.
+fn partition(
+    message_ids: &[Id<MessageMarker>],
+    now: u64,
+) -> (Vec<Id<MessageMarker>>, Vec<Id<MessageMarker>>) {
+    let mut seen = HashSet::with_capacity(message_ids.len());
+
+    message_ids
+        .iter()
+        .copied()
+        .filter(|id| seen.insert(*id))
+        .partition(|id| now.saturating_sub((id.get() >> 22) + DISCORD_EPOCH) < BULK_DELETE_AGE_MAX)
+}
+
+/// Split message IDs into chunks to be deleted in bulk and a remainder of one
+/// message to be deleted individually, as bulk deletes require at least two
+/// messages.
+fn split_chunks(
+    message_ids: &[Id<MessageMarker>],
+) -> (Vec<&[Id<MessageMarker>]>, Option<Id<MessageMarker>>) {
+    let mut bulk = message_ids
+        .chunks(CHANNEL_BULK_DELETE_MESSAGES_MAX)
+        .collect::<Vec<_>>();
+
+    match bulk.last() {
+        Some([message_id]) => {
+            let message_id = *message_id;
+            bulk.pop();
+
+            (bulk, Some(message_id))
+        }
+        _ => (bulk, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        partition, split_chunks, DeleteManyMessages, DeleteManyMessagesOutcome, DISCORD_EPOCH,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::{marker::MessageMarker, Id};
+
+    assert_impl_all!(DeleteManyMessages<'_>: Send, Sync);
+    assert_impl_all!(DeleteManyMessagesOutcome: Debug, Default, Send, Sync);
+
+    /// Current time used by the tests: 2024-01-01T00:00:00Z.
+    const NOW: u64 = 1_704_067_200_000;
+
+    /// Create a message ID with a timestamp some days before [`NOW`].
+    fn message_id(days_ago: u64, increment: u64) -> Id<MessageMarker> {
+        let timestamp = NOW - days_ago * 24 * 60 * 60 * 1000 - DISCORD_EPOCH;
+
+        Id::new(timestamp << 22 | increment)
+    }
+
+    #[test]
+    fn partition_by_age() {
+        let recent = [message_id(0, 1), message_id(13, 2)];
+        let old = [message_id(14, 3), message_id(365, 4)];
+        let message_ids = [recent[0], old[0], recent[1], recent[0], old[1], old[0]];
+
+        assert_eq!(
+            (recent.to_vec(), old.to_vec()),
+            partition(&message_ids, NOW)
+        );
+    }
+
+    #[test]
+    fn split_into_chunks() {
+        let message_ids = (0..201).map(|i| message_id(0, i)).collect::<Vec<_>>();
+
+        let (bulk, single) = split_chunks(&message_ids);
+        assert_eq!(vec![&message_ids[..100], &message_ids[100..200]], bulk);
+        assert_eq!(Some(message_ids[200]), single);
+
+        let (bulk, single) = split_chunks(&message_ids[..102]);
+        assert_eq!(vec![&message_ids[..100], &message_ids[100..102]], bulk);
+        assert!(single.is_none());
+
+        let (bulk, single) = split_chunks(&message_ids[..1]);
+        assert!(bulk.is_empty());
+        assert_eq!(Some(message_ids[0]), single);
+
+        assert_eq!((Vec::new(), None), split_chunks(&[]));
+    }
+
+    #[tokio::test]
+    async fn delete() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        use crate::mock::{MockResponse, MockTransport};
+        use twilight_http_ratelimiting::{Method, Path};
+
+        let recent = (0..102)
+            .map(|i| Id::new((super::now() - DISCORD_EPOCH) << 22 | i))
+            .collect::<Vec<_>>();
+        let old = Id::new(1);
+
+        let transport = MockTransport::new();
+        transport
+            .route(
+                Method::Post,
+                Path::ChannelsIdMessagesBulkDelete(1),
+                MockResponse::new(204),
+            )
+            .route(
+                Method::Post,
+                Path::ChannelsIdMessagesBulkDelete(1),
+                MockResponse::server_error(500),
+            )
+            .route(
+                Method::Delete,
+                Path::ChannelsIdMessagesId(Method::Delete, 1),
+                MockResponse::new(204),
+            );
+        let client = transport.client_builder().ratelimiter(None).build();
+
+        let mut message_ids = recent.clone();
+        message_ids.push(old);
+        let outcome = client
+            .delete_many_messages(Id::new(1), &message_ids)
+            .await?;
+
+        let mut deleted = recent[..100].to_vec();
+        deleted.push(old);
+        assert_eq!(deleted, outcome.deleted());
+        assert!(!outcome.is_complete());
+        assert_eq!(
+            recent[100..].to_vec(),
+            outcome.failed().map(|(id, _)| id).collect::<Vec<_>>()
+        );
+
+        let requests = transport.requests();
+        assert_eq!(3, requests.len());
+        assert_eq!("/channels/1/messages/bulk-delete", requests[0].path);
+        assert_eq!("/channels/1/messages/bulk-delete", requests[1].path);
+        assert_eq!("/channels/1/messages/1", requests[2].path);
+
+        Ok(())
+    }
+}
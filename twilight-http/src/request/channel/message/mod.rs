@@ -2,6 +2,7 @@ pub mod create_message;
 pub mod update_message;
 
 mod crosspost_message;
+mod delete_many_messages;
 mod delete_message;
 mod delete_messages;
 mod get_channel_messages;
@@ -9,9 +10,13 @@ mod get_channel_messages_configured;
 mod get_message;
 
 pub use self::{
-    create_message::CreateMessage, crosspost_message::CrosspostMessage,
-    delete_message::DeleteMessage, delete_messages::DeleteMessages,
+    create_message::CreateMessage,
+    crosspost_message::CrosspostMessage,
+    delete_many_messages::{DeleteManyMessages, DeleteManyMessagesOutcome},
+    delete_message::DeleteMessage,
+    delete_messages::DeleteMessages,
     get_channel_messages::GetChannelMessages,
-    get_channel_messages_configured::GetChannelMessagesConfigured, get_message::GetMessage,
+    get_channel_messages_configured::GetChannelMessagesConfigured,
+    get_message::GetMessage,
     update_message::UpdateMessage,
 };
@@ -1,12 +1,13 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{self, AuditLogReason, Request, TryIntoRequest},
     response::{marker::EmptyBody, Response, ResponseFuture},
     routing::Route,
 };
 use std::future::IntoFuture;
 use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
 
 /// Delete the stage instance of a stage channel.
 ///
@@ -15,11 +16,24 @@ use twilight_model::id::{marker::ChannelMarker, Id};
 pub struct DeleteStageInstance<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
+    reason: Result<Option<&'a str>, ValidationError>,
 }
 
 impl<'a> DeleteStageInstance<'a> {
     pub(crate) const fn new(http: &'a Client, channel_id: Id<ChannelMarker>) -> Self {
-        Self { channel_id, http }
+        Self {
+            channel_id,
+            http,
+            reason: Ok(None),
+        }
+    }
+}
+
+impl<'a> AuditLogReason<'a> for DeleteStageInstance<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
     }
 }
 
@@ -40,8 +54,14 @@ impl IntoFuture for DeleteStageInstance<'_> {
 
 impl TryIntoRequest for DeleteStageInstance<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::DeleteStageInstance {
+        let mut request = Request::builder(&Route::DeleteStageInstance {
             channel_id: self.channel_id.get(),
-        }))
+        });
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
     }
 }
@@ -6,7 +6,7 @@ use self::message::CreateForumThreadMessageFields;
 use crate::{
     client::Client,
     error::Error,
-    request::{attachment::AttachmentManager, Nullable, Request},
+    request::{self, attachment::AttachmentManager, AuditLogReason, Nullable, Request},
     response::ResponseFuture,
     routing::Route,
 };
@@ -18,6 +18,13 @@ use twilight_model::{
         Id,
     },
 };
+use twilight_validate::{
+    channel::{
+        applied_tags as validate_applied_tags, rate_limit_per_user as validate_rate_limit_per_user,
+        ChannelValidationError,
+    },
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
 
 #[derive(Deserialize, Serialize)]
 pub struct ForumThread {
@@ -45,10 +52,13 @@ struct CreateForumThreadFields<'a> {
 /// [`SEND_MESSAGES`]: twilight_model::guild::Permissions::SEND_MESSAGES
 #[must_use = "requests must be configured and executed"]
 pub struct CreateForumThread<'a> {
+    applied_tags: Result<Option<&'a [Id<TagMarker>]>, ChannelValidationError>,
     attachment_manager: AttachmentManager<'a>,
     channel_id: Id<ChannelMarker>,
     fields: CreateForumThreadFields<'a>,
     http: &'a Client,
+    rate_limit_per_user: Result<Option<u16>, ChannelValidationError>,
+    reason: Result<Option<&'a str>, ValidationError>,
 }
 
 impl<'a> CreateForumThread<'a> {
@@ -58,6 +68,7 @@ impl<'a> CreateForumThread<'a> {
         name: &'a str,
     ) -> Self {
         Self {
+            applied_tags: Ok(None),
             attachment_manager: AttachmentManager::new(),
             channel_id,
             fields: CreateForumThreadFields {
@@ -77,12 +88,23 @@ impl<'a> CreateForumThread<'a> {
                 rate_limit_per_user: None,
             },
             http,
+            rate_limit_per_user: Ok(None),
+            reason: Ok(None),
         }
     }
 
     /// Set the forum thread's applied tags.
-    pub const fn applied_tags(mut self, applied_tags: &'a [Id<TagMarker>]) -> Self {
-        self.fields.applied_tags = Some(applied_tags);
+    ///
+    /// At most 5 tags may be applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AppliedTagsInvalid`] if too many tags are
+    /// applied.
+    ///
+    /// [`AppliedTagsInvalid`]: twilight_validate::channel::ChannelValidationErrorType::AppliedTagsInvalid
+    pub fn applied_tags(mut self, applied_tags: &'a [Id<TagMarker>]) -> Self {
+        self.applied_tags = validate_applied_tags(applied_tags).and(Ok(Some(applied_tags)));
 
         self
     }
@@ -105,6 +127,25 @@ impl<'a> CreateForumThread<'a> {
         CreateForumThreadMessage::new(self)
     }
 
+    /// Set the number of seconds that a user must wait before they are able
+    /// to send another message in the thread.
+    ///
+    /// The minimum is 0 and the maximum is 21600. This is also known as "Slow
+    /// Mode".
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RateLimitPerUserInvalid`] if the rate limit
+    /// is invalid.
+    ///
+    /// [`RateLimitPerUserInvalid`]: twilight_validate::channel::ChannelValidationErrorType::RateLimitPerUserInvalid
+    pub fn rate_limit_per_user(mut self, rate_limit_per_user: u16) -> Self {
+        self.rate_limit_per_user =
+            validate_rate_limit_per_user(rate_limit_per_user).and(Ok(Some(rate_limit_per_user)));
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -122,6 +163,9 @@ impl<'a> CreateForumThread<'a> {
             channel_id: self.channel_id.get(),
         });
 
+        self.fields.applied_tags = self.applied_tags.map_err(Error::validation)?;
+        self.fields.rate_limit_per_user = self.rate_limit_per_user.map_err(Error::validation)?;
+
         // Set the default allowed mentions if required.
         if self.fields.message.allowed_mentions.is_none() {
             if let Some(allowed_mentions) = self.http.default_allowed_mentions() {
@@ -150,6 +194,18 @@ impl<'a> CreateForumThread<'a> {
             request = request.json(&self.fields);
         }
 
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
         request.build()
     }
 }
+
+impl<'a> AuditLogReason<'a> for CreateForumThread<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
+    }
+}
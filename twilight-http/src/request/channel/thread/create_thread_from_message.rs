@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{self, AuditLogReason, Request, TryIntoRequest},
     response::{Response, ResponseFuture},
     routing::Route,
 };
@@ -14,7 +14,10 @@ use twilight_model::{
         Id,
     },
 };
-use twilight_validate::channel::{name as validate_name, ChannelValidationError};
+use twilight_validate::{
+    channel::{name as validate_name, ChannelValidationError},
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
 
 #[derive(Serialize)]
 struct CreateThreadFromMessageFields<'a> {
@@ -49,6 +52,7 @@ pub struct CreateThreadFromMessage<'a> {
     fields: Result<CreateThreadFromMessageFields<'a>, ChannelValidationError>,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    reason: Result<Option<&'a str>, ValidationError>,
 }
 
 impl<'a> CreateThreadFromMessage<'a> {
@@ -73,6 +77,7 @@ impl<'a> CreateThreadFromMessage<'a> {
             fields,
             http,
             message_id,
+            reason: Ok(None),
         }
     }
 
@@ -89,6 +94,14 @@ impl<'a> CreateThreadFromMessage<'a> {
     }
 }
 
+impl<'a> AuditLogReason<'a> for CreateThreadFromMessage<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
+    }
+}
+
 impl IntoFuture for CreateThreadFromMessage<'_> {
     type Output = Result<Response<Channel>, Error>;
 
@@ -108,11 +121,16 @@ impl TryIntoRequest for CreateThreadFromMessage<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
 
-        Request::builder(&Route::CreateThreadFromMessage {
+        let mut request = Request::builder(&Route::CreateThreadFromMessage {
             channel_id: self.channel_id.get(),
             message_id: self.message_id.get(),
         })
-        .json(&fields)
-        .build()
+        .json(&fields);
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
     }
 }
@@ -22,6 +22,7 @@ pub struct GetThreadMember<'a> {
     channel_id: Id<ChannelMarker>,
     http: &'a Client,
     user_id: Id<UserMarker>,
+    with_member: Option<bool>,
 }
 
 impl<'a> GetThreadMember<'a> {
@@ -34,8 +35,16 @@ impl<'a> GetThreadMember<'a> {
             channel_id,
             http,
             user_id,
+            with_member: None,
         }
     }
+
+    /// Include the associated guild member.
+    pub const fn with_member(mut self, with_member: bool) -> Self {
+        self.with_member = Some(with_member);
+
+        self
+    }
 }
 
 impl IntoFuture for GetThreadMember<'_> {
@@ -58,6 +67,7 @@ impl TryIntoRequest for GetThreadMember<'_> {
         Ok(Request::from_route(&Route::GetThreadMember {
             channel_id: self.channel_id.get(),
             user_id: self.user_id.get(),
+            with_member: self.with_member,
         }))
     }
 }
@@ -16,8 +16,8 @@ use twilight_model::{
 };
 use twilight_validate::{
     channel::{
-        name as validate_name, rate_limit_per_user as validate_rate_limit_per_user,
-        ChannelValidationError,
+        applied_tags as validate_applied_tags, name as validate_name,
+        rate_limit_per_user as validate_rate_limit_per_user, ChannelValidationError,
     },
     request::{audit_reason as validate_audit_reason, ValidationError},
 };
@@ -71,10 +71,25 @@ impl<'a> UpdateThread<'a> {
     }
 
     /// Set the forum thread's applied tags.
+    ///
+    /// At most 5 tags may be applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AppliedTagsInvalid`] if too many tags are
+    /// applied.
+    ///
+    /// [`AppliedTagsInvalid`]: twilight_validate::channel::ChannelValidationErrorType::AppliedTagsInvalid
     pub fn applied_tags(mut self, applied_tags: Option<&'a [Id<TagMarker>]>) -> Self {
-        if let Ok(fields) = self.fields.as_mut() {
+        self.fields = self.fields.and_then(|mut fields| {
+            if let Some(applied_tags) = applied_tags {
+                validate_applied_tags(applied_tags)?;
+            }
+
             fields.applied_tags = Some(Nullable(applied_tags));
-        }
+
+            Ok(fields)
+        });
 
         self
     }
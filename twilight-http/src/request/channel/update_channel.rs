@@ -17,8 +17,9 @@ use twilight_model::{
 };
 use twilight_validate::{
     channel::{
-        bitrate as validate_bitrate, forum_topic as validate_forum_topic, name as validate_name,
-        topic as validate_topic, user_limit as validate_user_limit, ChannelValidationError,
+        bitrate as validate_bitrate, forum_tags as validate_forum_tags,
+        forum_topic as validate_forum_topic, name as validate_name, topic as validate_topic,
+        user_limit as validate_user_limit, ChannelValidationError,
     },
     request::{audit_reason as validate_audit_reason, ValidationError},
 };
@@ -108,10 +109,31 @@ impl<'a> UpdateChannel<'a> {
     }
 
     /// Set the available tags for the forum.
+    ///
+    /// At most 20 tags may be available, and each tag's name must be at most 20
+    /// characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ForumTagsInvalid`] if there are too many
+    /// tags.
+    ///
+    /// Returns an error of type [`ForumTagEmojiInvalid`] if a tag has both a
+    /// custom and a unicode emoji.
+    ///
+    /// Returns an error of type [`ForumTagNameInvalid`] if a tag's name is too
+    /// long.
+    ///
+    /// [`ForumTagEmojiInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagEmojiInvalid
+    /// [`ForumTagNameInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagNameInvalid
+    /// [`ForumTagsInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagsInvalid
     pub fn available_tags(mut self, available_tags: &'a [ForumTag]) -> Self {
-        if let Ok(fields) = self.fields.as_mut() {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_forum_tags(available_tags)?;
             fields.available_tags = Some(available_tags);
-        }
+
+            Ok(fields)
+        });
 
         self
     }
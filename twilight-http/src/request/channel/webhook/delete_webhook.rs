@@ -70,6 +70,12 @@ impl TryIntoRequest for DeleteWebhook<'_> {
             token: self.fields.token,
         });
 
+        // If a webhook token has been configured, then we don't need to use
+        // the client's authorization token.
+        if self.fields.token.is_some() {
+            request = request.use_authorization_token(false);
+        }
+
         if let Some(reason) = self.reason.map_err(Error::validation)? {
             request = request.headers(request::audit_header(reason)?);
         }
@@ -19,12 +19,14 @@ use twilight_model::{
 use twilight_validate::request::{
     audit_reason as validate_audit_reason,
     auto_moderation_action_metadata_duration_seconds as validate_auto_moderation_action_metadata_duration_seconds,
+    auto_moderation_actions as validate_auto_moderation_actions,
     auto_moderation_block_action_custom_message_limit as validate_auto_moderation_block_action_custom_message_limit,
     auto_moderation_exempt_channels as validate_auto_moderation_exempt_channels,
     auto_moderation_exempt_roles as validate_auto_moderation_exempt_roles,
     auto_moderation_metadata_keyword_allow_list as validate_auto_moderation_metadata_keyword_allow_list,
     auto_moderation_metadata_keyword_filter as validate_auto_moderation_metadata_keyword_filter,
     auto_moderation_metadata_mention_total_limit as validate_auto_moderation_metadata_mention_total_limit,
+    auto_moderation_metadata_preset_allow_list as validate_auto_moderation_metadata_preset_allow_list,
     auto_moderation_metadata_regex_patterns as validate_auto_moderation_metadata_regex_patterns,
     ValidationError,
 };
@@ -62,6 +64,8 @@ struct CreateAutoModerationRuleFieldsTriggerMetadata<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     presets: Option<&'a [AutoModerationKeywordPresetType]>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    mention_raid_protection_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     mention_total_limit: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     regex_patterns: Option<&'a [&'a str]>,
@@ -74,11 +78,28 @@ struct CreateAutoModerationRuleFields<'a> {
     event_type: AutoModerationEventType,
     exempt_channels: Option<&'a [Id<ChannelMarker>]>,
     exempt_roles: Option<&'a [Id<RoleMarker>]>,
+    #[serde(skip)]
+    mention_raid_protection_enabled: Option<bool>,
     name: &'a str,
     trigger_metadata: Option<CreateAutoModerationRuleFieldsTriggerMetadata<'a>>,
     trigger_type: Option<AutoModerationTriggerType>,
 }
 
+impl CreateAutoModerationRuleFields<'_> {
+    /// Append an action, ensuring that there aren't too many actions.
+    fn push_action(
+        &mut self,
+        kind: AutoModerationActionType,
+        metadata: CreateAutoModerationRuleFieldsActionMetadata,
+    ) -> Result<(), ValidationError> {
+        let actions = self.actions.get_or_insert_with(Vec::new);
+        validate_auto_moderation_actions(actions.len() + 1)?;
+        actions.push(CreateAutoModerationRuleFieldsAction { kind, metadata });
+
+        Ok(())
+    }
+}
+
 /// Create an auto moderation rule within a guild.
 ///
 /// Requires the [`MANAGE_GUILD`] permission.
@@ -127,6 +148,7 @@ impl<'a> CreateAutoModerationRule<'a> {
                 event_type,
                 exempt_channels: None,
                 exempt_roles: None,
+                mention_raid_protection_enabled: None,
                 name,
                 trigger_metadata: None,
                 trigger_type: None,
@@ -139,17 +161,43 @@ impl<'a> CreateAutoModerationRule<'a> {
 
     /// Append an action of type [`BlockMessage`].
     ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoModerationActions`] if too many actions
+    /// were appended.
+    ///
+    /// [`AutoModerationActions`]: twilight_validate::request::ValidationErrorType::AutoModerationActions
     /// [`BlockMessage`]: AutoModerationActionType::BlockMessage
     pub fn action_block_message(mut self) -> Self {
-        self.fields = self.fields.map(|mut fields| {
-            fields.actions.get_or_insert_with(Vec::new).push(
-                CreateAutoModerationRuleFieldsAction {
-                    kind: AutoModerationActionType::BlockMessage,
-                    metadata: CreateAutoModerationRuleFieldsActionMetadata::default(),
-                },
-            );
+        self.fields = self.fields.and_then(|mut fields| {
+            fields.push_action(
+                AutoModerationActionType::BlockMessage,
+                CreateAutoModerationRuleFieldsActionMetadata::default(),
+            )?;
 
-            fields
+            Ok(fields)
+        });
+
+        self
+    }
+
+    /// Append an action of type [`BlockMemberInteraction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoModerationActions`] if too many actions
+    /// were appended.
+    ///
+    /// [`AutoModerationActions`]: twilight_validate::request::ValidationErrorType::AutoModerationActions
+    /// [`BlockMemberInteraction`]: AutoModerationActionType::BlockMemberInteraction
+    pub fn action_block_member_interaction(mut self) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            fields.push_action(
+                AutoModerationActionType::BlockMemberInteraction,
+                CreateAutoModerationRuleFieldsActionMetadata::default(),
+            )?;
+
+            Ok(fields)
         });
 
         self
@@ -162,20 +210,22 @@ impl<'a> CreateAutoModerationRule<'a> {
     /// Returns a [`ValidationErrorType::AutoModerationBlockActionCustomMessageLimit`] if the custom message length
     /// is invalid.
     ///
+    /// Returns a [`ValidationErrorType::AutoModerationActions`] if too many
+    /// actions were appended.
+    ///
+    /// [`ValidationErrorType::AutoModerationActions`]: twilight_validate::request::ValidationErrorType::AutoModerationActions
     /// [`ValidationErrorType::AutoModerationBlockActionCustomMessageLimit`]: twilight_validate::request::ValidationErrorType::AutoModerationBlockActionCustomMessageLimit
     /// [`BlockMessage`]: AutoModerationActionType::BlockMessage
     pub fn action_block_message_with_explanation(mut self, custom_message: &'a str) -> Self {
         self.fields = self.fields.and_then(|mut fields| {
             validate_auto_moderation_block_action_custom_message_limit(custom_message)?;
-            fields.actions.get_or_insert_with(Vec::new).push(
-                CreateAutoModerationRuleFieldsAction {
-                    kind: AutoModerationActionType::BlockMessage,
-                    metadata: CreateAutoModerationRuleFieldsActionMetadata {
-                        custom_message: Some(String::from(custom_message)),
-                        ..Default::default()
-                    },
+            fields.push_action(
+                AutoModerationActionType::BlockMessage,
+                CreateAutoModerationRuleFieldsActionMetadata {
+                    custom_message: Some(String::from(custom_message)),
+                    ..Default::default()
                 },
-            );
+            )?;
 
             Ok(fields)
         });
@@ -185,20 +235,24 @@ impl<'a> CreateAutoModerationRule<'a> {
 
     /// Append an action of type [`SendAlertMessage`].
     ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoModerationActions`] if too many actions
+    /// were appended.
+    ///
+    /// [`AutoModerationActions`]: twilight_validate::request::ValidationErrorType::AutoModerationActions
     /// [`SendAlertMessage`]: AutoModerationActionType::SendAlertMessage
     pub fn action_send_alert_message(mut self, channel_id: Id<ChannelMarker>) -> Self {
-        self.fields = self.fields.map(|mut fields| {
-            fields.actions.get_or_insert_with(Vec::new).push(
-                CreateAutoModerationRuleFieldsAction {
-                    kind: AutoModerationActionType::SendAlertMessage,
-                    metadata: CreateAutoModerationRuleFieldsActionMetadata {
-                        channel_id: Some(channel_id),
-                        ..Default::default()
-                    },
+        self.fields = self.fields.and_then(|mut fields| {
+            fields.push_action(
+                AutoModerationActionType::SendAlertMessage,
+                CreateAutoModerationRuleFieldsActionMetadata {
+                    channel_id: Some(channel_id),
+                    ..Default::default()
                 },
-            );
+            )?;
 
-            fields
+            Ok(fields)
         });
 
         self
@@ -211,20 +265,22 @@ impl<'a> CreateAutoModerationRule<'a> {
     /// Returns [`ValidationErrorType::AutoModerationActionMetadataDurationSeconds`] if the duration
     /// is invalid.
     ///
+    /// Returns [`ValidationErrorType::AutoModerationActions`] if too many
+    /// actions were appended.
+    ///
     /// [`Timeout`]: AutoModerationActionType::Timeout
     /// [`ValidationErrorType::AutoModerationActionMetadataDurationSeconds`]: twilight_validate::request::ValidationErrorType::AutoModerationActionMetadataDurationSeconds
+    /// [`ValidationErrorType::AutoModerationActions`]: twilight_validate::request::ValidationErrorType::AutoModerationActions
     pub fn action_timeout(mut self, duration_seconds: u32) -> Self {
         self.fields = self.fields.and_then(|mut fields| {
             validate_auto_moderation_action_metadata_duration_seconds(duration_seconds)?;
-            fields.actions.get_or_insert_with(Vec::new).push(
-                CreateAutoModerationRuleFieldsAction {
-                    kind: AutoModerationActionType::Timeout,
-                    metadata: CreateAutoModerationRuleFieldsActionMetadata {
-                        duration_seconds: Some(duration_seconds),
-                        ..Default::default()
-                    },
+            fields.push_action(
+                AutoModerationActionType::Timeout,
+                CreateAutoModerationRuleFieldsActionMetadata {
+                    duration_seconds: Some(duration_seconds),
+                    ..Default::default()
                 },
-            );
+            )?;
 
             Ok(fields)
         });
@@ -283,6 +339,23 @@ impl<'a> CreateAutoModerationRule<'a> {
         self
     }
 
+    /// Set whether mention raids are automatically detected.
+    ///
+    /// Only applies to rules with the trigger type [`MentionSpam`], refer to
+    /// [`with_mention_spam`].
+    ///
+    /// [`MentionSpam`]: AutoModerationTriggerType::MentionSpam
+    /// [`with_mention_spam`]: Self::with_mention_spam
+    pub fn mention_raid_protection_enabled(mut self, enabled: bool) -> Self {
+        self.fields = self.fields.map(|mut fields| {
+            fields.mention_raid_protection_enabled = Some(enabled);
+
+            fields
+        });
+
+        self
+    }
+
     /// Create the request with the trigger type [`Keyword`], then execute it.
     ///
     /// Rules of this type require the `keyword_filter`, `regex_patterns` and
@@ -335,6 +408,7 @@ impl<'a> CreateAutoModerationRule<'a> {
                 allow_list: Some(allow_list),
                 keyword_filter: Some(keyword_filter),
                 presets: None,
+                mention_raid_protection_enabled: None,
                 mention_total_limit: None,
                 regex_patterns: Some(regex_patterns),
             });
@@ -347,6 +421,48 @@ impl<'a> CreateAutoModerationRule<'a> {
         self.exec()
     }
 
+    /// Create the request with the trigger type [`MemberProfile`], then
+    /// execute it.
+    ///
+    /// Rules of this type check the names of members when they join or update
+    /// their profile, and require the `keyword_filter`, `regex_patterns` and
+    /// `allow_list` fields specified, and this method ensures this. See
+    /// [Discord Docs/Trigger Metadata].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`with_keyword`].
+    ///
+    /// [`MemberProfile`]: AutoModerationTriggerType::MemberProfile
+    /// [Discord Docs/Trigger Metadata]: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata
+    /// [`with_keyword`]: Self::with_keyword
+    pub fn with_member_profile(
+        mut self,
+        keyword_filter: &'a [&'a str],
+        regex_patterns: &'a [&'a str],
+        allow_list: &'a [&'a str],
+    ) -> ResponseFuture<AutoModerationRule> {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_auto_moderation_metadata_keyword_allow_list(allow_list)?;
+            validate_auto_moderation_metadata_keyword_filter(keyword_filter)?;
+            validate_auto_moderation_metadata_regex_patterns(regex_patterns)?;
+            fields.trigger_metadata = Some(CreateAutoModerationRuleFieldsTriggerMetadata {
+                allow_list: Some(allow_list),
+                keyword_filter: Some(keyword_filter),
+                presets: None,
+                mention_raid_protection_enabled: None,
+                mention_total_limit: None,
+                regex_patterns: Some(regex_patterns),
+            });
+
+            fields.trigger_type = Some(AutoModerationTriggerType::MemberProfile);
+
+            Ok(fields)
+        });
+
+        self.exec()
+    }
+
     /// Create the request with the trigger type [`Spam`], then execute it.
     ///
     /// [`Spam`]: AutoModerationTriggerType::Spam
@@ -384,11 +500,12 @@ impl<'a> CreateAutoModerationRule<'a> {
         allow_list: &'a [&'a str],
     ) -> ResponseFuture<AutoModerationRule> {
         self.fields = self.fields.and_then(|mut fields| {
-            validate_auto_moderation_metadata_keyword_allow_list(allow_list)?;
+            validate_auto_moderation_metadata_preset_allow_list(allow_list)?;
             fields.trigger_metadata = Some(CreateAutoModerationRuleFieldsTriggerMetadata {
                 allow_list: Some(allow_list),
                 keyword_filter: None,
                 presets: Some(presets),
+                mention_raid_protection_enabled: None,
                 mention_total_limit: None,
                 regex_patterns: None,
             });
@@ -425,6 +542,7 @@ impl<'a> CreateAutoModerationRule<'a> {
                 allow_list: None,
                 keyword_filter: None,
                 presets: None,
+                mention_raid_protection_enabled: fields.mention_raid_protection_enabled,
                 mention_total_limit: Some(mention_total_limit),
                 regex_patterns: None,
             });
@@ -17,7 +17,13 @@ use twilight_model::{
         Id,
     },
 };
-use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+use twilight_validate::request::{
+    audit_reason as validate_audit_reason, auto_moderation_action as validate_action,
+    auto_moderation_actions as validate_actions,
+    auto_moderation_exempt_channels as validate_exempt_channels,
+    auto_moderation_exempt_roles as validate_exempt_roles,
+    auto_moderation_trigger_metadata as validate_trigger_metadata, ValidationError,
+};
 
 #[derive(Serialize)]
 struct UpdateAutoModerationRuleFields<'a> {
@@ -38,7 +44,7 @@ struct UpdateAutoModerationRuleFields<'a> {
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateAutoModerationRule<'a> {
     auto_moderation_rule_id: Id<AutoModerationRuleMarker>,
-    fields: UpdateAutoModerationRuleFields<'a>,
+    fields: Result<UpdateAutoModerationRuleFields<'a>, ValidationError>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Result<Option<&'a str>, ValidationError>,
@@ -52,7 +58,7 @@ impl<'a> UpdateAutoModerationRule<'a> {
     ) -> Self {
         Self {
             auto_moderation_rule_id,
-            fields: UpdateAutoModerationRuleFields {
+            fields: Ok(UpdateAutoModerationRuleFields {
                 actions: None,
                 enabled: None,
                 event_type: None,
@@ -60,7 +66,7 @@ impl<'a> UpdateAutoModerationRule<'a> {
                 exempt_roles: None,
                 name: None,
                 trigger_metadata: None,
-            },
+            }),
             guild_id,
             http,
             reason: Ok(None),
@@ -68,43 +74,90 @@ impl<'a> UpdateAutoModerationRule<'a> {
     }
 
     /// Set the list of actions.
-    pub const fn actions(mut self, actions: &'a [AutoModerationAction]) -> Self {
-        self.fields.actions = Some(actions);
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoModerationActions`] if there are too
+    /// many actions.
+    ///
+    /// Otherwise, refer to the errors section of
+    /// [`twilight_validate::request::auto_moderation_action`] for a list of
+    /// errors that may be returned as a result of validating each action.
+    ///
+    /// [`AutoModerationActions`]: twilight_validate::request::ValidationErrorType::AutoModerationActions
+    pub fn actions(mut self, actions: &'a [AutoModerationAction]) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_actions(actions.len())?;
+            actions.iter().try_for_each(validate_action)?;
+            fields.actions = Some(actions);
+
+            Ok(fields)
+        });
 
         self
     }
 
     /// Set whether the rule is enabled.
-    pub const fn enabled(mut self, enabled: bool) -> Self {
-        self.fields.enabled = Some(enabled);
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.enabled = Some(enabled);
+        }
 
         self
     }
 
     /// Set the rule's event type.
-    pub const fn event_type(mut self, event_type: AutoModerationEventType) -> Self {
-        self.fields.event_type = Some(event_type);
+    pub fn event_type(mut self, event_type: AutoModerationEventType) -> Self {
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.event_type = Some(event_type);
+        }
 
         self
     }
 
     /// Set the channels where the rule does not apply.
-    pub const fn exempt_channels(mut self, exempt_channels: &'a [Id<ChannelMarker>]) -> Self {
-        self.fields.exempt_channels = Some(exempt_channels);
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoModerationExemptChannels`] if there are
+    /// too many channels.
+    ///
+    /// [`AutoModerationExemptChannels`]: twilight_validate::request::ValidationErrorType::AutoModerationExemptChannels
+    pub fn exempt_channels(mut self, exempt_channels: &'a [Id<ChannelMarker>]) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_exempt_channels(exempt_channels)?;
+            fields.exempt_channels = Some(exempt_channels);
+
+            Ok(fields)
+        });
 
         self
     }
 
     /// Set the roles to which the rule does not apply.
-    pub const fn exempt_roles(mut self, exempt_roles: &'a [Id<RoleMarker>]) -> Self {
-        self.fields.exempt_roles = Some(exempt_roles);
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoModerationExemptRoles`] if there are too
+    /// many roles.
+    ///
+    /// [`AutoModerationExemptRoles`]: twilight_validate::request::ValidationErrorType::AutoModerationExemptRoles
+    pub fn exempt_roles(mut self, exempt_roles: &'a [Id<RoleMarker>]) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_exempt_roles(exempt_roles)?;
+            fields.exempt_roles = Some(exempt_roles);
+
+            Ok(fields)
+        });
 
         self
     }
 
     /// Set the rule's name.
-    pub const fn name(mut self, name: &'a str) -> Self {
-        self.fields.name = Some(name);
+    pub fn name(mut self, name: &'a str) -> Self {
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.name = Some(name);
+        }
 
         self
     }
@@ -112,11 +165,19 @@ impl<'a> UpdateAutoModerationRule<'a> {
     /// Set the trigger metadata.
     ///
     /// Care must be taken to set the correct metadata based on the rule's type.
-    pub const fn trigger_metadata(
-        mut self,
-        trigger_metadata: &'a AutoModerationTriggerMetadata,
-    ) -> Self {
-        self.fields.trigger_metadata = Some(trigger_metadata);
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::request::auto_moderation_trigger_metadata`] for a
+    /// list of errors that may be returned.
+    pub fn trigger_metadata(mut self, trigger_metadata: &'a AutoModerationTriggerMetadata) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_trigger_metadata(trigger_metadata)?;
+            fields.trigger_metadata = Some(trigger_metadata);
+
+            Ok(fields)
+        });
 
         self
     }
@@ -147,11 +208,12 @@ impl IntoFuture for UpdateAutoModerationRule<'_> {
 
 impl TryIntoRequest for UpdateAutoModerationRule<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
+        let fields = self.fields.map_err(Error::validation)?;
         let mut request = Request::builder(&Route::UpdateAutoModerationRule {
             auto_moderation_rule_id: self.auto_moderation_rule_id.get(),
             guild_id: self.guild_id.get(),
         })
-        .json(&self.fields);
+        .json(&fields);
 
         if let Some(reason) = self.reason.map_err(Error::validation)? {
             request = request.headers(request::audit_header(reason)?);
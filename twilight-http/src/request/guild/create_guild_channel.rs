@@ -21,7 +21,7 @@ use twilight_model::{
 };
 use twilight_validate::{
     channel::{
-        bitrate as validate_bitrate, name as validate_name,
+        bitrate as validate_bitrate, forum_tags as validate_forum_tags, name as validate_name,
         rate_limit_per_user as validate_rate_limit_per_user, topic as validate_topic,
         ChannelValidationError,
     },
@@ -120,10 +120,31 @@ impl<'a> CreateGuildChannel<'a> {
     }
 
     /// Set the available tags for the forum.
+    ///
+    /// At most 20 tags may be available, and each tag's name must be at most 20
+    /// characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ForumTagsInvalid`] if there are too many
+    /// tags.
+    ///
+    /// Returns an error of type [`ForumTagEmojiInvalid`] if a tag has both a
+    /// custom and a unicode emoji.
+    ///
+    /// Returns an error of type [`ForumTagNameInvalid`] if a tag's name is too
+    /// long.
+    ///
+    /// [`ForumTagEmojiInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagEmojiInvalid
+    /// [`ForumTagNameInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagNameInvalid
+    /// [`ForumTagsInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagsInvalid
     pub fn available_tags(mut self, available_tags: &'a [ForumTag]) -> Self {
-        if let Ok(fields) = self.fields.as_mut() {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_forum_tags(available_tags)?;
             fields.available_tags = Some(available_tags);
-        }
+
+            Ok(fields)
+        });
 
         self
     }
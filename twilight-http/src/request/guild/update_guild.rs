@@ -267,6 +267,27 @@ impl<'a> UpdateGuild<'a> {
     }
 
     /// Set the guild's [`SystemChannelFlags`].
+    ///
+    /// # Examples
+    ///
+    /// Suppress role subscription purchase notifications and their sticker
+    /// reply buttons:
+    ///
+    /// ```no_run
+    /// use twilight_http::Client;
+    /// use twilight_model::{guild::SystemChannelFlags, id::Id};
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("my token".to_owned());
+    ///
+    /// let flags = SystemChannelFlags::SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATIONS
+    ///     | SystemChannelFlags::SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATION_REPLIES;
+    ///
+    /// client
+    ///     .update_guild(Id::new(1))
+    ///     .system_channel_flags(Some(flags))
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
     pub fn system_channel_flags(
         mut self,
         system_channel_flags: Option<SystemChannelFlags>,
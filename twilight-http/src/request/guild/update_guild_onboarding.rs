@@ -21,7 +21,15 @@ use crate::{
     routing::Route,
 };
 
-use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+use twilight_validate::{
+    onboarding::{
+        channels as validate_channels, option_description as validate_option_description,
+        option_title as validate_option_title, prompt_options as validate_prompt_options,
+        prompt_title as validate_prompt_title, prompts as validate_prompts,
+        OnboardingValidationError,
+    },
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct UpdateOnboardingPromptOption {
@@ -54,6 +62,39 @@ pub struct UpdateGuildOnboardingFields {
     pub mode: OnboardingMode,
 }
 
+impl UpdateGuildOnboardingFields {
+    /// Ensure the prompts, their options, and the channels of the onboarding
+    /// flow are valid.
+    fn validate(&self) -> Result<(), OnboardingValidationError> {
+        validate_prompts(self.prompts.len())?;
+
+        for prompt in &self.prompts {
+            validate_prompt_title(&prompt.title)?;
+            validate_prompt_options(prompt.options.len())?;
+
+            for option in &prompt.options {
+                validate_option_title(&option.title)?;
+
+                if let Some(description) = &option.description {
+                    validate_option_description(description)?;
+                }
+            }
+        }
+
+        if self.enabled {
+            let option_channel_ids = self
+                .prompts
+                .iter()
+                .flat_map(|prompt| &prompt.options)
+                .flat_map(|option| &option.channel_ids);
+
+            validate_channels(self.mode, &self.default_channel_ids, option_channel_ids)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Update the guild's onboarding flow
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateGuildOnboarding<'a> {
@@ -103,6 +144,8 @@ impl IntoFuture for UpdateGuildOnboarding<'_> {
 
 impl TryIntoRequest for UpdateGuildOnboarding<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
+        self.fields.validate().map_err(Error::validation)?;
+
         let mut request = Request::builder(&Route::UpdateGuildOnboarding {
             guild_id: self.guild_id.get(),
         });
@@ -32,6 +32,28 @@
 //! # Ok(()) }
 //! ```
 //!
+//! # Timeouts
+//!
+//! The client's timeout may be overridden for a single request via the
+//! [`RequestTimeout`] trait, such as to give large uploads more time:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # use twilight_model::id::Id;
+//! #
+//! # let channel_id = Id::new(1);
+//! use std::time::Duration;
+//! use twilight_http::{client::Client, request::RequestTimeout};
+//!
+//! let client = Client::new(std::env::var("DISCORD_TOKEN")?);
+//! client
+//!     .create_message(channel_id)
+//!     .content("slow")
+//!     .timeout(Duration::from_secs(60))
+//!     .await?;
+//! # Ok(()) }
+//! ```
+//!
 //! [`Client`]: crate::client::Client
 //! [`CreateMessage`]: channel::message::CreateMessage
 //! [`Response`]: crate::Response
@@ -44,6 +66,7 @@ pub mod application;
 pub mod attachment;
 pub mod channel;
 pub mod guild;
+pub mod oauth2;
 pub mod poll;
 pub mod scheduled_event;
 pub mod sticker;
@@ -58,6 +81,7 @@ mod get_gateway_authed;
 mod get_user_application;
 mod get_voice_regions;
 mod multipart;
+mod timeout;
 mod try_into_request;
 mod update_user_application;
 
@@ -70,6 +94,7 @@ pub use self::{
     get_user_application::GetUserApplicationInfo,
     get_voice_regions::GetVoiceRegions,
     multipart::Form,
+    timeout::RequestTimeout,
     try_into_request::TryIntoRequest,
     update_user_application::UpdateCurrentUserApplication,
 };
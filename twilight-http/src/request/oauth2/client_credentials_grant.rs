@@ -0,0 +1,95 @@
+use super::Credentials;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::oauth::AccessToken;
+
+/// Retrieve an access token for the application's owner.
+///
+/// If the application is owned by a team, only the [`IDENTIFY`] and
+/// [`APPLICATIONS_COMMANDS_UPDATE`] scopes may be requested.
+///
+/// Refer to [Discord Docs/Client Credentials Grant][1].
+///
+/// [`APPLICATIONS_COMMANDS_UPDATE`]: twilight_model::oauth::scope::APPLICATIONS_COMMANDS_UPDATE
+/// [`IDENTIFY`]: twilight_model::oauth::scope::IDENTIFY
+/// [1]: https://discord.com/developers/docs/topics/oauth2#client-credentials-grant
+#[derive(Debug)]
+#[must_use = "requests must be configured and executed"]
+pub struct ClientCredentialsGrant<'a> {
+    credentials: Credentials<'a>,
+    http: &'a Client,
+    scopes: &'a [&'a str],
+}
+
+impl<'a> ClientCredentialsGrant<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        credentials: Credentials<'a>,
+        scopes: &'a [&'a str],
+    ) -> Self {
+        Self {
+            credentials,
+            http,
+            scopes,
+        }
+    }
+}
+
+impl IntoFuture for ClientCredentialsGrant<'_> {
+    type Output = Result<Response<AccessToken>, Error>;
+
+    type IntoFuture = ResponseFuture<AccessToken>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for ClientCredentialsGrant<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let scope = self.scopes.join(" ");
+
+        self.credentials.request(
+            &Route::CreateAccessToken,
+            &[("grant_type", "client_credentials"), ("scope", &scope)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientCredentialsGrant;
+    use crate::{client::Client, request::TryIntoRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_model::{id::Id, oauth::scope};
+
+    assert_impl_all!(ClientCredentialsGrant<'_>: IntoFuture, Send, Sync);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::builder().build();
+        let req = client
+            .oauth2(Id::new(1), "secret")
+            .client_credentials(&[scope::IDENTIFY, scope::APPLICATIONS_COMMANDS_UPDATE])
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(b"client_id=1&client_secret=secret&grant_type=client_credentials&scope=identify%20applications.commands.update".as_slice()),
+            req.body()
+        );
+
+        Ok(())
+    }
+}
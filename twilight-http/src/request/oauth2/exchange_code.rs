@@ -0,0 +1,98 @@
+use super::Credentials;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::oauth::AccessToken;
+
+/// Exchange an authorization code for an access token.
+///
+/// Refer to [Discord Docs/Authorization Code Grant][1].
+///
+/// [1]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant
+#[derive(Debug)]
+#[must_use = "requests must be configured and executed"]
+pub struct ExchangeCode<'a> {
+    code: &'a str,
+    credentials: Credentials<'a>,
+    http: &'a Client,
+    redirect_uri: &'a str,
+}
+
+impl<'a> ExchangeCode<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        credentials: Credentials<'a>,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> Self {
+        Self {
+            code,
+            credentials,
+            http,
+            redirect_uri,
+        }
+    }
+}
+
+impl IntoFuture for ExchangeCode<'_> {
+    type Output = Result<Response<AccessToken>, Error>;
+
+    type IntoFuture = ResponseFuture<AccessToken>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for ExchangeCode<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        self.credentials.request(
+            &Route::CreateAccessToken,
+            &[
+                ("grant_type", "authorization_code"),
+                ("code", self.code),
+                ("redirect_uri", self.redirect_uri),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExchangeCode;
+    use crate::{client::Client, request::TryIntoRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(ExchangeCode<'_>: IntoFuture, Send, Sync);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::builder().build();
+        let req = client
+            .oauth2(Id::new(1), "secret")
+            .exchange_code("code", "https://example.com")
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, req.method());
+        assert_eq!(&Path::OauthToken, req.ratelimit_path());
+        assert_eq!(
+            Some(b"client_id=1&client_secret=secret&grant_type=authorization_code&code=code&redirect_uri=https%3A%2F%2Fexample.com".as_slice()),
+            req.body()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+//! Requests for OAuth2 token flows.
+//!
+//! Created via [`OAuth2Client`].
+//!
+//! [`OAuth2Client`]: crate::client::OAuth2Client
+
+mod client_credentials_grant;
+mod exchange_code;
+mod refresh_token;
+mod revoke_token;
+
+pub use self::{
+    client_credentials_grant::ClientCredentialsGrant,
+    exchange_code::ExchangeCode,
+    refresh_token::RefreshToken,
+    revoke_token::{RevokeToken, TokenTypeHint},
+};
+
+use crate::{error::Error, request::Request, routing::Route};
+use http::header::{HeaderValue, CONTENT_TYPE};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult, Write},
+    iter,
+};
+use twilight_model::id::{marker::ApplicationMarker, Id};
+
+/// Credentials of the application performing an OAuth2 flow.
+#[derive(Clone, Copy)]
+pub(crate) struct Credentials<'a> {
+    /// ID of the application.
+    pub client_id: Id<ApplicationMarker>,
+    /// Secret of the application.
+    pub client_secret: &'a str,
+}
+
+impl Credentials<'_> {
+    /// Build a request to an OAuth2 token endpoint with the credentials and
+    /// additional form fields.
+    #[allow(clippy::result_large_err)]
+    pub fn request(self, route: &Route<'_>, fields: &[(&str, &str)]) -> Result<Request, Error> {
+        let client_id = self.client_id.to_string();
+        let mut body = String::new();
+
+        for (name, value) in [
+            ("client_id", client_id.as_str()),
+            ("client_secret", self.client_secret),
+        ]
+        .iter()
+        .chain(fields)
+        {
+            if !body.is_empty() {
+                body.push('&');
+            }
+
+            encode(&mut body, name);
+            body.push('=');
+            encode(&mut body, value);
+        }
+
+        Request::builder(route)
+            .body(body.into_bytes())
+            .headers(iter::once((
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/x-www-form-urlencoded"),
+            )))
+            .use_authorization_token(false)
+            .build()
+    }
+}
+
+impl Debug for Credentials<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Credentials")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Percent-encode a form field.
+fn encode(buf: &mut String, value: &str) {
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            buf.push(char::from(byte));
+        } else {
+            let _ = write!(buf, "%{byte:02X}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Credentials;
+    use crate::routing::Route;
+    use http::header::CONTENT_TYPE;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn std::error::Error>> {
+        let credentials = Credentials {
+            client_id: Id::new(1),
+            client_secret: "secret",
+        };
+        let request = credentials.request(
+            &Route::CreateAccessToken,
+            &[("redirect_uri", "https://example.com/a b?c=d&e")],
+        )?;
+
+        assert!(!request.use_authorization_token());
+        assert_eq!(
+            Some(b"client_id=1&client_secret=secret&redirect_uri=https%3A%2F%2Fexample.com%2Fa%20b%3Fc%3Dd%26e".as_slice()),
+            request.body()
+        );
+        assert_eq!(
+            Some("application/x-www-form-urlencoded"),
+            request
+                .headers()
+                .and_then(|headers| headers.get(CONTENT_TYPE))
+                .and_then(|value| value.to_str().ok())
+        );
+        assert!(!format!("{credentials:?}").contains("secret\""));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use super::Credentials;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::oauth::AccessToken;
+
+/// Exchange a refresh token for a new access token.
+///
+/// Refer to [Discord Docs/Refresh Token Exchange][1].
+///
+/// [1]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant-refresh-token-exchange-example
+#[derive(Debug)]
+#[must_use = "requests must be configured and executed"]
+pub struct RefreshToken<'a> {
+    credentials: Credentials<'a>,
+    http: &'a Client,
+    token: &'a str,
+}
+
+impl<'a> RefreshToken<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        credentials: Credentials<'a>,
+        token: &'a str,
+    ) -> Self {
+        Self {
+            credentials,
+            http,
+            token,
+        }
+    }
+}
+
+impl IntoFuture for RefreshToken<'_> {
+    type Output = Result<Response<AccessToken>, Error>;
+
+    type IntoFuture = ResponseFuture<AccessToken>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RefreshToken<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        self.credentials.request(
+            &Route::CreateAccessToken,
+            &[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", self.token),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RefreshToken;
+    use crate::{client::Client, request::TryIntoRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(RefreshToken<'_>: IntoFuture, Send, Sync);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::builder().build();
+        let req = client
+            .oauth2(Id::new(1), "secret")
+            .refresh_token("token")
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(
+                b"client_id=1&client_secret=secret&grant_type=refresh_token&refresh_token=token"
+                    .as_slice()
+            ),
+            req.body()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use super::Credentials;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+
+/// Type of token being revoked.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TokenTypeHint {
+    /// Token is an access token.
+    AccessToken,
+    /// Token is a refresh token.
+    RefreshToken,
+}
+
+impl TokenTypeHint {
+    /// Name of the token type in requests.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::AccessToken => "access_token",
+            Self::RefreshToken => "refresh_token",
+        }
+    }
+}
+
+/// Revoke an access or refresh token.
+///
+/// Revoking either token of an authorization invalidates both.
+///
+/// Refer to [Discord Docs/Token Revocation][1].
+///
+/// [1]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant-token-revocation-example
+#[derive(Debug)]
+#[must_use = "requests must be configured and executed"]
+pub struct RevokeToken<'a> {
+    credentials: Credentials<'a>,
+    http: &'a Client,
+    token: &'a str,
+    token_type_hint: Option<TokenTypeHint>,
+}
+
+impl<'a> RevokeToken<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        credentials: Credentials<'a>,
+        token: &'a str,
+    ) -> Self {
+        Self {
+            credentials,
+            http,
+            token,
+            token_type_hint: None,
+        }
+    }
+
+    /// Set the type of the token being revoked.
+    pub const fn token_type_hint(mut self, token_type_hint: TokenTypeHint) -> Self {
+        self.token_type_hint = Some(token_type_hint);
+
+        self
+    }
+}
+
+impl IntoFuture for RevokeToken<'_> {
+    type Output = Result<Response<EmptyBody>, Error>;
+
+    type IntoFuture = ResponseFuture<EmptyBody>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RevokeToken<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let mut fields = vec![("token", self.token)];
+
+        if let Some(token_type_hint) = self.token_type_hint {
+            fields.push(("token_type_hint", token_type_hint.name()));
+        }
+
+        self.credentials.request(&Route::RevokeToken, &fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RevokeToken, TokenTypeHint};
+    use crate::{client::Client, request::TryIntoRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(RevokeToken<'_>: IntoFuture, Send, Sync);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::builder().build();
+        let req = client
+            .oauth2(Id::new(1), "secret")
+            .revoke_token("token")
+            .token_type_hint(TokenTypeHint::RefreshToken)
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, req.method());
+        assert_eq!(&Path::OauthTokenRevoke, req.ratelimit_path());
+        assert_eq!(
+            Some(
+                b"client_id=1&client_secret=secret&token=token&token_type_hint=refresh_token"
+                    .as_slice()
+            ),
+            req.body()
+        );
+
+        Ok(())
+    }
+}
@@ -25,8 +25,10 @@ use twilight_model::{
     util::Timestamp,
 };
 use twilight_validate::request::{
-    audit_reason as validate_audit_reason, scheduled_event_name as validate_scheduled_event_name,
-    ValidationError,
+    audit_reason as validate_audit_reason,
+    scheduled_event_end_time as validate_scheduled_event_end_time,
+    scheduled_event_location as validate_scheduled_event_location,
+    scheduled_event_name as validate_scheduled_event_name, ValidationError,
 };
 
 #[derive(Serialize)]
@@ -148,12 +150,21 @@ impl<'a> CreateGuildScheduledEvent<'a> {
 
     /// Create an external scheduled event in a guild.
     ///
-    /// The name must be between 1 and 100 characters in length.
+    /// The name and location must be between 1 and 100 characters in length,
+    /// and the event must end after it starts.
     ///
     /// # Errors
     ///
     /// Returns an error of type [`ScheduledEventName`] if the name is invalid.
     ///
+    /// Returns an error of type [`ScheduledEventLocation`] if the location is
+    /// invalid.
+    ///
+    /// Returns an error of type [`ScheduledEventEndTime`] if the end time is
+    /// not after the start time.
+    ///
+    /// [`ScheduledEventEndTime`]: twilight_validate::request::ValidationErrorType::ScheduledEventEndTime
+    /// [`ScheduledEventLocation`]: twilight_validate::request::ValidationErrorType::ScheduledEventLocation
     /// [`ScheduledEventName`]: twilight_validate::request::ValidationErrorType::ScheduledEventName
     pub fn external(
         mut self,
@@ -164,6 +175,8 @@ impl<'a> CreateGuildScheduledEvent<'a> {
     ) -> CreateGuildExternalScheduledEvent<'a> {
         self.fields = self.fields.and_then(|mut fields| {
             validate_scheduled_event_name(name)?;
+            validate_scheduled_event_location(location)?;
+            validate_scheduled_event_end_time(*scheduled_start_time, *scheduled_end_time)?;
 
             fields.name.replace(name);
 
@@ -19,6 +19,9 @@ use twilight_model::{
 use twilight_validate::request::{
     audit_reason as validate_audit_reason,
     scheduled_event_description as validate_scheduled_event_description,
+    scheduled_event_end_time as validate_scheduled_event_end_time,
+    scheduled_event_entity_fields as validate_scheduled_event_entity_fields,
+    scheduled_event_location as validate_scheduled_event_location,
     scheduled_event_name as validate_scheduled_event_name, ValidationError,
 };
 
@@ -54,12 +57,19 @@ struct UpdateGuildScheduledEventFields<'a> {
 ///
 /// When changing the entity type to [`EntityType::External`], the `channel_id`
 /// field is cleared and the [`channel_id`] method has no effect.  Additionally,
-/// you must set a location with [`location`].
+/// you must set a location with [`location`] and an end time with
+/// [`scheduled_end_time`], otherwise the request fails with an error of type
+/// [`ScheduledEventEntityFields`].
 ///
+/// If both a start and an end time are set, the event must end after it
+/// starts, otherwise the request fails with an error of type
+/// [`ScheduledEventEndTime`].
+///
+/// [`ScheduledEventEndTime`]: twilight_validate::request::ValidationErrorType::ScheduledEventEndTime
+/// [`ScheduledEventEntityFields`]: twilight_validate::request::ValidationErrorType::ScheduledEventEntityFields
 /// [`channel_id`]: UpdateGuildScheduledEvent::channel_id
 /// [`location`]: UpdateGuildScheduledEvent::location
-/// [`channel_id`]: UpdateGuildScheduledEvent::channel_id
-/// [`location`]: UpdateGuildScheduledEvent::location
+/// [`scheduled_end_time`]: UpdateGuildScheduledEvent::scheduled_end_time
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateGuildScheduledEvent<'a> {
     guild_id: Id<GuildMarker>,
@@ -174,12 +184,24 @@ impl<'a> UpdateGuildScheduledEvent<'a> {
     ///
     /// This only functions if the event's [`EntityType`] is [`External`].
     ///
+    /// Must be between 1 and 100 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ScheduledEventLocation`] if the location is
+    /// invalid.
+    ///
     /// [`External`]: EntityType::External
+    /// [`ScheduledEventLocation`]: twilight_validate::request::ValidationErrorType::ScheduledEventLocation
     pub fn location(mut self, location: Option<&'a str>) -> Self {
-        self.fields = self.fields.map(|mut fields| {
+        self.fields = self.fields.and_then(|mut fields| {
+            if let Some(location) = location {
+                validate_scheduled_event_location(location)?;
+            }
+
             fields.entity_metadata = Some(EntityMetadataFields { location });
 
-            fields
+            Ok(fields)
         });
 
         self
@@ -277,6 +299,34 @@ impl IntoFuture for UpdateGuildScheduledEvent<'_> {
 impl TryIntoRequest for UpdateGuildScheduledEvent<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
+        let scheduled_end_time = fields
+            .scheduled_end_time
+            .as_ref()
+            .and_then(|scheduled_end_time| scheduled_end_time.0);
+
+        if let (Some(scheduled_start_time), Some(scheduled_end_time)) =
+            (fields.scheduled_start_time, scheduled_end_time)
+        {
+            validate_scheduled_event_end_time(*scheduled_start_time, *scheduled_end_time)
+                .map_err(Error::validation)?;
+        }
+
+        // Channels of stage instance and voice events may already be set, so
+        // only the fields of external events can be ensured.
+        if fields.entity_type == Some(EntityType::External) {
+            let has_location = fields
+                .entity_metadata
+                .as_ref()
+                .is_some_and(|metadata| metadata.location.is_some());
+
+            validate_scheduled_event_entity_fields(
+                EntityType::External,
+                false,
+                has_location,
+                scheduled_end_time.is_some(),
+            )
+            .map_err(Error::validation)?;
+        }
 
         Request::builder(&Route::UpdateGuildScheduledEvent {
             guild_id: self.guild_id.get(),
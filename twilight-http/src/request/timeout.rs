@@ -0,0 +1,54 @@
+use super::TryIntoRequest;
+use crate::response::ResponseFuture;
+use std::{future::IntoFuture, time::Duration};
+
+/// Override the client's timeout for a single request.
+///
+/// Implemented for all request builders, including those of the
+/// [`InteractionClient`]. Refer to [`ResponseFuture::set_timeout`] for more
+/// information.
+///
+/// [`InteractionClient`]: crate::client::InteractionClient
+pub trait RequestTimeout<T>: IntoFuture<IntoFuture = ResponseFuture<T>> + TryIntoRequest {
+    /// Set the duration to wait for a response before timing out, returning
+    /// the future of the request.
+    fn timeout(self, timeout: Duration) -> ResponseFuture<T>
+    where
+        Self: Sized,
+    {
+        let mut future = self.into_future();
+        future.set_timeout(timeout);
+
+        future
+    }
+}
+
+impl<R: IntoFuture<IntoFuture = ResponseFuture<T>> + TryIntoRequest, T> RequestTimeout<T> for R {}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestTimeout;
+    use crate::{error::ErrorType, Client};
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn timeout() -> Result<(), Box<dyn std::error::Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let client = Client::builder()
+            .proxy(listener.local_addr()?.to_string(), true)
+            .ratelimiter(None)
+            .timeout(Duration::from_secs(60))
+            .build();
+
+        let error = client
+            .gateway()
+            .timeout(Duration::from_millis(10))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+
+        Ok(())
+    }
+}
@@ -80,6 +80,7 @@ mod private {
             UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfa,
             UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
         },
+        oauth2::{ClientCredentialsGrant, ExchangeCode, RefreshToken, RevokeToken},
         poll::{EndPoll, GetAnswerVoters},
         scheduled_event::{
             CreateGuildExternalScheduledEvent, CreateGuildStageInstanceScheduledEvent,
@@ -93,9 +94,9 @@ mod private {
         },
         update_user_application::UpdateCurrentUserApplication,
         user::{
-            CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections,
-            GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser, LeaveGuild,
-            UpdateCurrentUser,
+            CreatePrivateChannel, GetCurrentUser, GetCurrentUserApplicationRoleConnection,
+            GetCurrentUserConnections, GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser,
+            LeaveGuild, UpdateCurrentUser, UpdateCurrentUserApplicationRoleConnection,
         },
         GetCurrentAuthorizationInformation, GetGateway, GetGatewayAuthed, GetUserApplicationInfo,
         GetVoiceRegions,
@@ -107,6 +108,7 @@ mod private {
     impl Sealed for AddGuildMember<'_> {}
     impl Sealed for AddRoleToMember<'_> {}
     impl Sealed for AddThreadMember<'_> {}
+    impl Sealed for ClientCredentialsGrant<'_> {}
     impl Sealed for CreateAutoModerationRule<'_> {}
     impl Sealed for CreateBan<'_> {}
     impl Sealed for CreateEmoji<'_> {}
@@ -173,6 +175,7 @@ mod private {
     impl Sealed for DeleteApplicationEmoji<'_> {}
     impl Sealed for UpdateApplicationEmoji<'_> {}
     impl Sealed for EndPoll<'_> {}
+    impl Sealed for ExchangeCode<'_> {}
     impl Sealed for ExecuteWebhook<'_> {}
     impl Sealed for ExecuteWebhookAndWait<'_> {}
     impl Sealed for FollowNewsChannel<'_> {}
@@ -190,6 +193,7 @@ mod private {
     impl Sealed for GetChannelWebhooks<'_> {}
     impl Sealed for GetCommandPermissions<'_> {}
     impl Sealed for GetCurrentUser<'_> {}
+    impl Sealed for GetCurrentUserApplicationRoleConnection<'_> {}
     impl Sealed for GetCurrentAuthorizationInformation<'_> {}
     impl Sealed for GetCurrentUserConnections<'_> {}
     impl Sealed for GetCurrentUserGuildMember<'_> {}
@@ -251,9 +255,11 @@ mod private {
     impl Sealed for JoinThread<'_> {}
     impl Sealed for LeaveGuild<'_> {}
     impl Sealed for LeaveThread<'_> {}
+    impl Sealed for RefreshToken<'_> {}
     impl Sealed for RemoveMember<'_> {}
     impl Sealed for RemoveRoleFromMember<'_> {}
     impl Sealed for RemoveThreadMember<'_> {}
+    impl Sealed for RevokeToken<'_> {}
     impl Sealed for SearchGuildMembers<'_> {}
     impl Sealed for SetGlobalCommands<'_> {}
     impl Sealed for SetGuildCommands<'_> {}
@@ -264,6 +270,7 @@ mod private {
     impl Sealed for UpdateCommandPermissions<'_> {}
     impl Sealed for UpdateCurrentMember<'_> {}
     impl Sealed for UpdateCurrentUser<'_> {}
+    impl Sealed for UpdateCurrentUserApplicationRoleConnection<'_> {}
     impl Sealed for UpdateCurrentUserVoiceState<'_> {}
     impl Sealed for UpdateEmoji<'_> {}
     impl Sealed for UpdateFollowup<'_> {}
@@ -0,0 +1,57 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    application::ApplicationRoleConnection,
+    id::{marker::ApplicationMarker, Id},
+};
+
+/// Get the current user's role connection to an application.
+///
+/// Requires a Bearer token with the [`ROLE_CONNECTIONS_WRITE`] scope.
+///
+/// [`ROLE_CONNECTIONS_WRITE`]: twilight_model::oauth::scope::ROLE_CONNECTIONS_WRITE
+#[must_use = "requests must be configured and executed"]
+pub struct GetCurrentUserApplicationRoleConnection<'a> {
+    application_id: Id<ApplicationMarker>,
+    http: &'a Client,
+}
+
+impl<'a> GetCurrentUserApplicationRoleConnection<'a> {
+    pub(crate) const fn new(http: &'a Client, application_id: Id<ApplicationMarker>) -> Self {
+        Self {
+            application_id,
+            http,
+        }
+    }
+}
+
+impl IntoFuture for GetCurrentUserApplicationRoleConnection<'_> {
+    type Output = Result<Response<ApplicationRoleConnection>, Error>;
+
+    type IntoFuture = ResponseFuture<ApplicationRoleConnection>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetCurrentUserApplicationRoleConnection<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(
+            &Route::GetUserApplicationRoleConnection {
+                application_id: self.application_id.get(),
+            },
+        ))
+    }
+}
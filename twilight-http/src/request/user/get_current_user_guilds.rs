@@ -18,6 +18,7 @@ struct GetCurrentUserGuildsFields {
     after: Option<Id<GuildMarker>>,
     before: Option<Id<GuildMarker>>,
     limit: Option<u16>,
+    with_counts: Option<bool>,
 }
 
 /// Returns a list of guilds for the current user.
@@ -58,6 +59,7 @@ impl<'a> GetCurrentUserGuilds<'a> {
                 after: None,
                 before: None,
                 limit: None,
+                with_counts: None,
             }),
             http,
         }
@@ -103,6 +105,15 @@ impl<'a> GetCurrentUserGuilds<'a> {
 
         self
     }
+
+    /// Include approximate member and presence counts for each guild.
+    pub fn with_counts(mut self, with_counts: bool) -> Self {
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.with_counts = Some(with_counts);
+        }
+
+        self
+    }
 }
 
 impl IntoFuture for GetCurrentUserGuilds<'_> {
@@ -128,6 +139,7 @@ impl TryIntoRequest for GetCurrentUserGuilds<'_> {
             after: fields.after.map(Id::get),
             before: fields.before.map(Id::get),
             limit: fields.limit,
+            with_counts: fields.with_counts,
         }))
     }
 }
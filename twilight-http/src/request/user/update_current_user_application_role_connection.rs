@@ -0,0 +1,130 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{collections::HashMap, future::IntoFuture};
+use twilight_model::{
+    application::ApplicationRoleConnection,
+    id::{marker::ApplicationMarker, Id},
+};
+
+#[derive(Serialize)]
+struct UpdateCurrentUserApplicationRoleConnectionFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_username: Option<&'a str>,
+}
+
+/// Update the current user's role connection to an application.
+///
+/// Requires a Bearer token with the [`ROLE_CONNECTIONS_WRITE`] scope.
+///
+/// [`ROLE_CONNECTIONS_WRITE`]: twilight_model::oauth::scope::ROLE_CONNECTIONS_WRITE
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateCurrentUserApplicationRoleConnection<'a> {
+    application_id: Id<ApplicationMarker>,
+    fields: UpdateCurrentUserApplicationRoleConnectionFields<'a>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateCurrentUserApplicationRoleConnection<'a> {
+    pub(crate) const fn new(http: &'a Client, application_id: Id<ApplicationMarker>) -> Self {
+        Self {
+            application_id,
+            fields: UpdateCurrentUserApplicationRoleConnectionFields {
+                metadata: None,
+                platform_name: None,
+                platform_username: None,
+            },
+            http,
+        }
+    }
+
+    /// Set the mapping of the application's role connection metadata keys to
+    /// their values for the user.
+    ///
+    /// Values are stringified integers, booleans, or ISO 8601 timestamps
+    /// depending on the type of the metadata, with a maximum length of 100
+    /// characters.
+    pub const fn metadata(mut self, metadata: &'a HashMap<String, String>) -> Self {
+        self.fields.metadata = Some(metadata);
+
+        self
+    }
+
+    /// Set the vanity name of the platform the application is linked to.
+    ///
+    /// The maximum length is 50 characters.
+    pub const fn platform_name(mut self, platform_name: &'a str) -> Self {
+        self.fields.platform_name = Some(platform_name);
+
+        self
+    }
+
+    /// Set the username of the user on the platform.
+    ///
+    /// The maximum length is 100 characters.
+    pub const fn platform_username(mut self, platform_username: &'a str) -> Self {
+        self.fields.platform_username = Some(platform_username);
+
+        self
+    }
+}
+
+impl IntoFuture for UpdateCurrentUserApplicationRoleConnection<'_> {
+    type Output = Result<Response<ApplicationRoleConnection>, Error>;
+
+    type IntoFuture = ResponseFuture<ApplicationRoleConnection>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateCurrentUserApplicationRoleConnection<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Request::builder(&Route::UpdateUserApplicationRoleConnection {
+            application_id: self.application_id.get(),
+        })
+        .json(&self.fields)
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateCurrentUserApplicationRoleConnection;
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::{collections::HashMap, error::Error};
+    use twilight_model::id::Id;
+
+    #[test]
+    fn body() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("Bearer token".to_owned());
+        let metadata = HashMap::from([("level".to_owned(), "10".to_owned())]);
+
+        let request = UpdateCurrentUserApplicationRoleConnection::new(&client, Id::new(1))
+            .metadata(&metadata)
+            .platform_name("Equestria")
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"metadata":{"level":"10"},"platform_name":"Equestria"}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+//! Cache of response bodies validated via `ETag`s.
+//!
+//! Enabled via [`ClientBuilder::response_cache`].
+//!
+//! [`ClientBuilder::response_cache`]: crate::client::ClientBuilder::response_cache
+
+use http::header::{HeaderMap, HeaderValue, IF_NONE_MATCH};
+use hyper::body::Bytes;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+/// Cached body of a response.
+#[derive(Clone, Debug)]
+pub(crate) struct Entry {
+    /// Body of the response.
+    pub body: Bytes,
+    /// Value of the `ETag` header of the response.
+    pub etag: HeaderValue,
+}
+
+/// Entries of the cache alongside the order they were inserted in.
+#[derive(Debug, Default)]
+struct Entries {
+    map: HashMap<String, Entry>,
+    order: VecDeque<String>,
+}
+
+/// Cache mapping the paths of `GET` requests to the body of their last
+/// response with an `ETag`.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    entries: Mutex<Entries>,
+    max_entries: usize,
+}
+
+impl ResponseCache {
+    /// Create a cache holding at most the given number of entries.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: Mutex::default(),
+            max_entries,
+        }
+    }
+
+    /// Cached response to a path.
+    pub fn entry(&self, key: &str) -> Option<Entry> {
+        self.entries
+            .lock()
+            .expect("entries poisoned")
+            .map
+            .get(key)
+            .cloned()
+    }
+
+    /// Cache the body of a response, evicting the oldest entry if the cache
+    /// is full.
+    pub fn insert(&self, key: String, etag: HeaderValue, body: Bytes) {
+        if self.max_entries == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().expect("entries poisoned");
+
+        if !entries.map.contains_key(&key) {
+            while entries.map.len() >= self.max_entries {
+                let Some(oldest) = entries.order.pop_front() else {
+                    break;
+                };
+
+                entries.map.remove(&oldest);
+            }
+
+            entries.order.push_back(key.clone());
+        }
+
+        entries.map.insert(key, Entry { body, etag });
+    }
+}
+
+/// Body of a response related to the cache.
+#[derive(Debug)]
+pub(crate) enum CachedBody {
+    /// Server responded with `304 Not Modified`; this is the cached body.
+    Hit(Bytes),
+    /// Body is to be cached once received.
+    Store {
+        /// Cache to store the body in.
+        cache: Arc<ResponseCache>,
+        /// Value of the `ETag` header of the response.
+        etag: HeaderValue,
+        /// Path of the request.
+        key: String,
+    },
+}
+
+/// Reference to the cache entry of a request.
+#[derive(Debug)]
+pub(crate) struct CacheKey {
+    /// Cache the request's response is stored in.
+    pub cache: Arc<ResponseCache>,
+    /// Cached response whose `ETag` is sent via the `If-None-Match` header,
+    /// if any.
+    ///
+    /// Kept until the response is received, so that a `304 Not Modified`
+    /// response can be served even if the entry has been evicted since.
+    pub entry: Option<Entry>,
+    /// Path of the request.
+    pub key: String,
+}
+
+impl CacheKey {
+    /// Reference the cache entry of a path, sending the cached `ETag` via the
+    /// `If-None-Match` header.
+    pub fn new(cache: &Arc<ResponseCache>, key: String, headers: Option<&mut HeaderMap>) -> Self {
+        let entry = headers.and_then(|headers| {
+            let entry = cache.entry(&key)?;
+            headers.insert(IF_NONE_MATCH, entry.etag.clone());
+
+            Some(entry)
+        });
+
+        Self {
+            cache: Arc::clone(cache),
+            entry,
+            key,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseCache;
+    use crate::Client;
+    use http::header::HeaderValue;
+    use hyper::body::Bytes;
+    use std::future::IntoFuture;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    const BODY: &str = r#"{"url":"wss://gateway.discord.gg"}"#;
+
+    #[test]
+    fn eviction() {
+        let cache = ResponseCache::new(2);
+        let etag = HeaderValue::from_static("\"a\"");
+
+        cache.insert("1".to_owned(), etag.clone(), Bytes::from_static(b"1"));
+        cache.insert("2".to_owned(), etag.clone(), Bytes::from_static(b"2"));
+        cache.insert("1".to_owned(), etag.clone(), Bytes::from_static(b"3"));
+        assert_eq!(
+            Some(Bytes::from_static(b"3")),
+            cache.entry("1").map(|entry| entry.body)
+        );
+
+        cache.insert("3".to_owned(), etag.clone(), Bytes::from_static(b"4"));
+        assert!(cache.entry("1").is_none());
+        assert!(cache.entry("2").is_some());
+        assert_eq!(
+            Some(Bytes::from_static(b"4")),
+            cache.entry("3").map(|entry| entry.body)
+        );
+    }
+
+    /// Respond to requests with an `ETag`, or with `304 Not Modified` if
+    /// revalidating it.
+    async fn serve(listener: TcpListener) -> Result<(), std::io::Error> {
+        loop {
+            let (mut stream, _) = listener.accept().await?;
+            let mut request = Vec::new();
+
+            loop {
+                let mut buf = [0; 1024];
+                let read = stream.read(&mut buf).await?;
+
+                if read == 0 {
+                    break;
+                }
+
+                request.extend_from_slice(&buf[..read]);
+
+                if !request.ends_with(b"\r\n\r\n") {
+                    continue;
+                }
+
+                let revalidating = String::from_utf8_lossy(&request)
+                    .to_lowercase()
+                    .contains("if-none-match: \"a\"");
+                request.clear();
+
+                let response = if revalidating {
+                    "HTTP/1.1 304 Not Modified\r\netag: \"a\"\r\nx-revalidated: 1\r\n\r\n"
+                        .to_owned()
+                } else {
+                    format!(
+                        "HTTP/1.1 200 OK\r\netag: \"a\"\r\ncontent-length: {}\r\n\r\n{BODY}",
+                        BODY.len()
+                    )
+                };
+
+                stream.write_all(response.as_bytes()).await?;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn not_modified() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let client = Client::builder()
+            .proxy(listener.local_addr()?.to_string(), true)
+            .ratelimiter(None)
+            .response_cache(1)
+            .build();
+
+        tokio::spawn(serve(listener));
+
+        let response = client.gateway().await?;
+        assert!(response.status().is_success());
+        assert_eq!("wss://gateway.discord.gg", response.model().await?.url);
+
+        let response = client.gateway().await?;
+        assert_eq!(200, response.status().get());
+        assert!(response.headers().any(|(name, _)| name == "x-revalidated"));
+        assert_eq!("wss://gateway.discord.gg", response.model().await?.url);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn not_modified_evicted() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let client = Client::builder()
+            .proxy(listener.local_addr()?.to_string(), true)
+            .ratelimiter(None)
+            .response_cache(1)
+            .build();
+        tokio::spawn(serve(listener));
+
+        client.gateway().await?.bytes().await?;
+
+        // Evict the entry of the gateway's route while revalidating it.
+        let revalidation = client.gateway().into_future();
+        client.gateway().authed().await?.bytes().await?;
+
+        let response = revalidation.await?;
+        assert!(response.headers().any(|(name, _)| name == "x-revalidated"));
+        assert_eq!("wss://gateway.discord.gg", response.model().await?.url);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
-use super::{Response, StatusCode};
+use super::{
+    cache::{CacheKey, CachedBody, Entry},
+    Response, ResponseMetadata, StatusCode,
+};
 use crate::{
     api_error::ApiError,
+    client::circuit_breaker::Circuit,
     error::{Error, ErrorType},
 };
-use http::StatusCode as HyperStatusCode;
+use http::{header::ETAG, StatusCode as HyperStatusCode};
 use hyper_util::client::legacy::ResponseFuture as HyperResponseFuture;
 use std::{
     future::Future,
@@ -17,7 +21,10 @@ use std::{
     task::{Context, Poll},
     time::Duration,
 };
-use tokio::time::{self, Timeout};
+use tokio::{
+    sync::{AcquireError, OwnedSemaphorePermit, Semaphore},
+    time::{self, Timeout},
+};
 use twilight_http_ratelimiting::{ticket::TicketSender, RatelimitHeaders, WaitForTicketFuture};
 
 type Output<T> = Result<Response<T>, Error>;
@@ -30,6 +37,7 @@ enum InnerPoll<T> {
 
 struct Chunking {
     future: Pin<Box<dyn Future<Output = Result<Vec<u8>, Error>> + Send + Sync + 'static>>,
+    metadata: ResponseMetadata,
     status: HyperStatusCode,
 }
 
@@ -55,6 +63,7 @@ impl Chunking {
             kind: ErrorType::Response {
                 body: bytes,
                 error,
+                metadata: Box::new(self.metadata),
                 status: StatusCode::new(self.status.as_u16()),
             },
             source: None,
@@ -73,12 +82,20 @@ impl Failed {
 }
 
 struct InFlight {
+    body_size_max: Option<usize>,
+    cache_key: Option<CacheKey>,
+    circuit: Option<Circuit>,
     future: Pin<Box<Timeout<HyperResponseFuture>>>,
     invalid_token: Option<Arc<AtomicBool>>,
+    metadata: ResponseMetadata,
+    /// Permit of the client's concurrency limit, handed to the response to be
+    /// released once its body is read.
+    permit: Option<OwnedSemaphorePermit>,
     tx: Option<TicketSender>,
 }
 
 impl InFlight {
+    #[allow(clippy::too_many_lines)]
     fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
         let resp = match Pin::new(&mut self.future).poll(cx) {
             Poll::Ready(Ok(Ok(resp))) => resp,
@@ -89,10 +106,14 @@ impl InFlight {
                 }))
             }
             Poll::Ready(Err(source)) => {
+                if let Some(circuit) = &self.circuit {
+                    circuit.failure();
+                }
+
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RequestTimedOut,
                     source: Some(Box::new(source)),
-                }))
+                }));
             }
             Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::InFlight(self)),
         };
@@ -106,15 +127,38 @@ impl InFlight {
             }
         }
 
-        if let Some(tx) = self.tx {
-            let headers = resp
+        if let Some(circuit) = &self.circuit {
+            if resp.status().is_server_error() {
+                circuit.failure();
+            } else {
+                circuit.success();
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("status", resp.status().as_u16());
+
+            if let Some(bucket) = resp
                 .headers()
-                .iter()
-                .map(|(key, value)| (key.as_str(), value.as_bytes()));
+                .get(twilight_http_ratelimiting::headers::HeaderName::BUCKET)
+                .and_then(|value| value.to_str().ok())
+            {
+                span.record("bucket", bucket);
+            }
+        }
 
-            match RatelimitHeaders::from_pairs(headers) {
+        let headers = resp
+            .headers()
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_bytes()));
+        let ratelimit_headers = RatelimitHeaders::from_pairs(headers);
+
+        if let Some(tx) = self.tx {
+            match &ratelimit_headers {
                 Ok(v) => {
-                    let _res = tx.headers(Some(v));
+                    let _res = tx.headers(Some(v.clone()));
                 }
                 Err(source) => {
                     tracing::warn!("header parsing failed: {source:?}; {resp:?}");
@@ -124,8 +168,44 @@ impl InFlight {
             }
         }
 
+        let mut metadata = self.metadata;
+        metadata.record_response(ratelimit_headers.ok());
+        let permit = self.permit;
+
         let status = resp.status();
 
+        if let Some(CacheKey { cache, entry, key }) = self.cache_key {
+            if status == HyperStatusCode::NOT_MODIFIED {
+                if let Some(Entry { body, .. }) = entry {
+                    let mut resp = resp;
+                    *resp.status_mut() = HyperStatusCode::OK;
+
+                    return InnerPoll::Ready(Ok(Response::cached(
+                        resp,
+                        self.body_size_max,
+                        CachedBody::Hit(body),
+                        metadata,
+                    )
+                    .hold(permit)));
+                }
+            } else if status.is_success() {
+                if let Some(etag) = resp.headers().get(ETAG).cloned() {
+                    #[cfg(feature = "decompression")]
+                    let mut resp = resp;
+                    #[cfg(feature = "decompression")]
+                    resp.headers_mut().remove(http::header::CONTENT_LENGTH);
+
+                    return InnerPoll::Ready(Ok(Response::cached(
+                        resp,
+                        self.body_size_max,
+                        CachedBody::Store { cache, etag, key },
+                        metadata,
+                    )
+                    .hold(permit)));
+                }
+            }
+        }
+
         if status.is_success() {
             #[cfg(feature = "decompression")]
             let mut resp = resp;
@@ -133,7 +213,9 @@ impl InFlight {
             #[cfg(feature = "decompression")]
             resp.headers_mut().remove(http::header::CONTENT_LENGTH);
 
-            return InnerPoll::Ready(Ok(Response::new(resp)));
+            return InnerPoll::Ready(Ok(
+                Response::new(resp, self.body_size_max, metadata).hold(permit)
+            ));
         }
 
         match status {
@@ -149,8 +231,11 @@ impl InFlight {
             _ => {}
         }
 
-        let fut = async {
-            Response::<()>::new(resp)
+        let body_size_max = self.body_size_max;
+        let chunking_metadata = metadata.clone();
+        let fut = async move {
+            Response::<()>::new(resp, body_size_max, chunking_metadata)
+                .hold(permit)
                 .bytes()
                 .await
                 .map_err(|source| Error {
@@ -161,13 +246,72 @@ impl InFlight {
 
         InnerPoll::Advance(ResponseFutureStage::Chunking(Chunking {
             future: Box::pin(fut),
+            metadata,
             status,
         }))
     }
 }
 
+/// Request waiting for a permit of the client's concurrency limit, before
+/// waiting for a ratelimit ticket and being sent.
+struct Queued {
+    permit: Pin<Box<dyn Future<Output = Result<OwnedSemaphorePermit, AcquireError>> + Send + Sync>>,
+    /// Stage to advance to once the permit is acquired.
+    stage: Box<ResponseFutureStage>,
+}
+
+impl Queued {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        // The semaphore is never closed.
+        let permit = match self.permit.as_mut().poll(cx) {
+            Poll::Ready(permit) => permit.ok(),
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Queued(self)),
+        };
+
+        let mut stage = *self.stage;
+
+        match &mut stage {
+            ResponseFutureStage::RatelimitQueue(queue) => queue.permit = permit,
+            ResponseFutureStage::Unsent(unsent) => unsent.permit = permit,
+            _ => {}
+        }
+
+        InnerPoll::Advance(stage)
+    }
+}
+
+/// Stage sending a request.
+#[allow(clippy::too_many_arguments)]
+fn send(
+    body_size_max: Option<usize>,
+    cache_key: Option<CacheKey>,
+    circuit: Option<Circuit>,
+    invalid_token: Option<Arc<AtomicBool>>,
+    metadata: ResponseMetadata,
+    permit: Option<OwnedSemaphorePermit>,
+    response_future: HyperResponseFuture,
+    timeout: Duration,
+    tx: Option<TicketSender>,
+) -> ResponseFutureStage {
+    ResponseFutureStage::InFlight(InFlight {
+        body_size_max,
+        cache_key,
+        circuit,
+        future: Box::pin(time::timeout(timeout, response_future)),
+        invalid_token,
+        metadata,
+        permit,
+        tx,
+    })
+}
+
 struct RatelimitQueue {
+    body_size_max: Option<usize>,
+    cache_key: Option<CacheKey>,
+    circuit: Option<Circuit>,
     invalid_token: Option<Arc<AtomicBool>>,
+    metadata: ResponseMetadata,
+    permit: Option<OwnedSemaphorePermit>,
     response_future: HyperResponseFuture,
     timeout: Duration,
     pre_flight_check: Option<Box<dyn FnOnce() -> bool + Send + 'static>>,
@@ -196,11 +340,45 @@ impl RatelimitQueue {
             }
         }
 
-        InnerPoll::Advance(ResponseFutureStage::InFlight(InFlight {
-            future: Box::pin(time::timeout(self.timeout, self.response_future)),
-            invalid_token: self.invalid_token,
-            tx: Some(tx),
-        }))
+        InnerPoll::Advance(send(
+            self.body_size_max,
+            self.cache_key,
+            self.circuit,
+            self.invalid_token,
+            self.metadata,
+            self.permit,
+            self.response_future,
+            self.timeout,
+            Some(tx),
+        ))
+    }
+}
+
+/// Request that hasn't been sent yet and doesn't need to wait for a ticket.
+struct Unsent {
+    body_size_max: Option<usize>,
+    cache_key: Option<CacheKey>,
+    circuit: Option<Circuit>,
+    invalid_token: Option<Arc<AtomicBool>>,
+    metadata: ResponseMetadata,
+    permit: Option<OwnedSemaphorePermit>,
+    response_future: HyperResponseFuture,
+    timeout: Duration,
+}
+
+impl Unsent {
+    fn poll<T>(self, _: &mut Context<'_>) -> InnerPoll<T> {
+        InnerPoll::Advance(send(
+            self.body_size_max,
+            self.cache_key,
+            self.circuit,
+            self.invalid_token,
+            self.metadata,
+            self.permit,
+            self.response_future,
+            self.timeout,
+            None,
+        ))
     }
 }
 
@@ -209,7 +387,9 @@ enum ResponseFutureStage {
     Completed,
     Failed(Failed),
     InFlight(InFlight),
+    Queued(Queued),
     RatelimitQueue(RatelimitQueue),
+    Unsent(Unsent),
 }
 
 /// Future that will resolve to a [`Response`].
@@ -224,6 +404,9 @@ enum ResponseFutureStage {
 ///
 /// # Errors
 ///
+/// Returns an [`ErrorType::CircuitOpen`] error type if the client's
+/// [circuit breaker] is open for the route.
+///
 /// Returns an [`ErrorType::Json`] error type if serializing the response body
 /// of the request failed.
 ///
@@ -237,7 +420,8 @@ enum ResponseFutureStage {
 /// failed.
 ///
 /// Returns an [`ErrorType::RequestTimedOut`] error type if the request timed
-/// out. The timeout value is configured via [`ClientBuilder::timeout`].
+/// out. The timeout value is configured via [`ClientBuilder::timeout`] and
+/// may be overridden per request via [`ResponseFuture::set_timeout`].
 ///
 /// Returns an [`ErrorType::Response`] error type if the request failed.
 ///
@@ -245,6 +429,7 @@ enum ResponseFutureStage {
 /// is unavailable.
 ///
 /// [`ClientBuilder::timeout`]: crate::client::ClientBuilder::timeout
+/// [`ErrorType::CircuitOpen`]: crate::error::ErrorType::CircuitOpen
 /// [`ErrorType::Json`]: crate::error::ErrorType::Json
 /// [`ErrorType::Parsing`]: crate::error::ErrorType::Parsing
 /// [`ErrorType::RequestCanceled`]: crate::error::ErrorType::RequestCanceled
@@ -253,23 +438,37 @@ enum ResponseFutureStage {
 /// [`ErrorType::Response`]: crate::error::ErrorType::Response
 /// [`ErrorType::ServiceUnavailable`]: crate::error::ErrorType::ServiceUnavailable
 /// [`Response`]: super::Response
+/// [circuit breaker]: crate::client::ClientBuilder::circuit_breaker
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct ResponseFuture<T> {
     phantom: PhantomData<T>,
+    /// Span the request is polled in.
+    #[cfg(feature = "tracing")]
+    span: Option<tracing::Span>,
     stage: ResponseFutureStage,
 }
 
 impl<T> ResponseFuture<T> {
     pub(crate) const fn new(
-        future: Pin<Box<Timeout<HyperResponseFuture>>>,
         invalid_token: Option<Arc<AtomicBool>>,
+        cache_key: Option<CacheKey>,
+        metadata: ResponseMetadata,
+        response_future: HyperResponseFuture,
+        timeout: Duration,
     ) -> Self {
         Self {
             phantom: PhantomData,
-            stage: ResponseFutureStage::InFlight(InFlight {
-                future,
+            #[cfg(feature = "tracing")]
+            span: None,
+            stage: ResponseFutureStage::Unsent(Unsent {
+                body_size_max: None,
+                cache_key,
+                circuit: None,
                 invalid_token,
-                tx: None,
+                metadata,
+                permit: None,
+                response_future,
+                timeout,
             }),
         }
     }
@@ -331,7 +530,7 @@ impl<T> ResponseFuture<T> {
         &mut self,
         pre_flight: Box<dyn FnOnce() -> bool + Send + 'static>,
     ) -> bool {
-        if let ResponseFutureStage::RatelimitQueue(queue) = &mut self.stage {
+        if let ResponseFutureStage::RatelimitQueue(queue) = self.unsent_stage() {
             queue.pre_flight_check = Some(pre_flight);
 
             true
@@ -340,23 +539,157 @@ impl<T> ResponseFuture<T> {
         }
     }
 
+    /// Set the duration to wait for a response before timing out, overriding
+    /// the client's [timeout].
+    ///
+    /// Time spent waiting for the ratelimiter is not included in the timeout.
+    ///
+    /// This function will be a no-op if the request has failed or has already
+    /// been sent.
+    ///
+    /// Returns whether the timeout was set.
+    ///
+    /// # Examples
+    ///
+    /// Allow a message with a large attachment a minute to be uploaded:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::{env, future::IntoFuture, time::Duration};
+    /// use twilight_http::Client;
+    /// use twilight_model::{http::attachment::Attachment, id::Id};
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let attachments = [Attachment::from_bytes(
+    ///     "video.mp4".to_owned(),
+    ///     std::fs::read("video.mp4")?,
+    ///     1,
+    /// )];
+    ///
+    /// let mut req = client
+    ///     .create_message(Id::new(1))
+    ///     .attachments(&attachments)
+    ///     .into_future();
+    /// req.set_timeout(Duration::from_secs(60));
+    /// req.await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [timeout]: crate::client::ClientBuilder::timeout
+    pub fn set_timeout(&mut self, timeout: Duration) -> bool {
+        match self.unsent_stage() {
+            ResponseFutureStage::RatelimitQueue(queue) => {
+                queue.timeout = timeout;
+
+                true
+            }
+            ResponseFutureStage::Unsent(unsent) => {
+                unsent.timeout = timeout;
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Poll the request within a span.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn instrument(mut self, span: tracing::Span) -> Self {
+        self.span = Some(span);
+
+        self
+    }
+
+    /// Stage of the request before it's sent, looking through the wait for a
+    /// permit of the client's concurrency limit.
+    fn unsent_stage(&mut self) -> &mut ResponseFutureStage {
+        match &mut self.stage {
+            ResponseFutureStage::Queued(queued) => &mut queued.stage,
+            stage => stage,
+        }
+    }
+
+    /// Wait for a permit of the client's concurrency limit before waiting for
+    /// a ratelimit ticket and sending the request.
+    ///
+    /// The permit is held until the body of the response is read.
+    pub(crate) fn limit(mut self, semaphore: Arc<Semaphore>) -> Self {
+        if matches!(
+            self.stage,
+            ResponseFutureStage::RatelimitQueue(_) | ResponseFutureStage::Unsent(_)
+        ) {
+            let stage = mem::replace(&mut self.stage, ResponseFutureStage::Completed);
+            self.stage = ResponseFutureStage::Queued(Queued {
+                permit: Box::pin(semaphore.acquire_owned()),
+                stage: Box::new(stage),
+            });
+        }
+
+        self
+    }
+
+    /// Fail reading the body of the response once it's larger than a maximum
+    /// size.
+    pub(crate) fn limit_body_size(mut self, max: usize) -> Self {
+        match self.unsent_stage() {
+            ResponseFutureStage::RatelimitQueue(queue) => queue.body_size_max = Some(max),
+            ResponseFutureStage::Unsent(unsent) => unsent.body_size_max = Some(max),
+            _ => {}
+        }
+
+        self
+    }
+
+    /// Count the outcome of the request in a circuit of the client's circuit
+    /// breaker.
+    pub(crate) fn circuit(mut self, circuit: Circuit) -> Self {
+        match self.unsent_stage() {
+            ResponseFutureStage::RatelimitQueue(queue) => queue.circuit = Some(circuit),
+            ResponseFutureStage::Unsent(unsent) => unsent.circuit = Some(circuit),
+            _ => {}
+        }
+
+        self
+    }
+
+    /// Change the type the response's body deserializes into.
+    pub(crate) fn cast<U>(self) -> ResponseFuture<U> {
+        ResponseFuture {
+            phantom: PhantomData,
+            #[cfg(feature = "tracing")]
+            span: self.span,
+            stage: self.stage,
+        }
+    }
+
     pub(crate) const fn error(source: Error) -> Self {
         Self {
             phantom: PhantomData,
+            #[cfg(feature = "tracing")]
+            span: None,
             stage: ResponseFutureStage::Failed(Failed { source }),
         }
     }
 
     pub(crate) fn ratelimit(
         invalid_token: Option<Arc<AtomicBool>>,
+        cache_key: Option<CacheKey>,
+        metadata: ResponseMetadata,
         response_future: HyperResponseFuture,
         timeout: Duration,
         wait_for_sender: WaitForTicketFuture,
     ) -> Self {
         Self {
             phantom: PhantomData,
+            #[cfg(feature = "tracing")]
+            span: None,
             stage: ResponseFutureStage::RatelimitQueue(RatelimitQueue {
+                body_size_max: None,
+                cache_key,
+                circuit: None,
                 invalid_token,
+                metadata,
+                permit: None,
                 response_future,
                 timeout,
                 pre_flight_check: None,
@@ -370,6 +703,11 @@ impl<T: Unpin> Future for ResponseFuture<T> {
     type Output = Output<T>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        #[cfg(feature = "tracing")]
+        let span = self.span.clone();
+        #[cfg(feature = "tracing")]
+        let _entered = span.as_ref().map(tracing::Span::enter);
+
         loop {
             let stage = mem::replace(&mut self.stage, ResponseFutureStage::Completed);
 
@@ -378,7 +716,9 @@ impl<T: Unpin> Future for ResponseFuture<T> {
                 ResponseFutureStage::Completed => panic!("future already completed"),
                 ResponseFutureStage::Failed(failed) => failed.poll(cx),
                 ResponseFutureStage::InFlight(in_flight) => in_flight.poll(cx),
+                ResponseFutureStage::Queued(queued) => queued.poll(cx),
                 ResponseFutureStage::RatelimitQueue(queue) => queue.poll(cx),
+                ResponseFutureStage::Unsent(unsent) => unsent.poll(cx),
             };
 
             match result {
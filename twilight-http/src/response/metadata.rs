@@ -0,0 +1,113 @@
+use crate::request::Method;
+use twilight_http_ratelimiting::{request::Path, RatelimitHeaders};
+
+/// Metadata about the request of a [`Response`] and its ratelimit headers.
+///
+/// The metadata is owned and may be cloned, so it remains available after the
+/// response is consumed, such as by [`Response::model_with_metadata`]. It's
+/// also included in [`ErrorType::Response`] errors.
+///
+/// May be obtained via [`Response::metadata`].
+///
+/// # Examples
+///
+/// Log the bucket of a request along with its route:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_http_ratelimiting::RatelimitHeaders;
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let (user, metadata) = client
+///     .current_user()
+///     .await?
+///     .model_with_metadata()
+///     .await?;
+///
+/// if let Some(RatelimitHeaders::Present(present)) = metadata.ratelimit_headers() {
+///     println!(
+///         "{:?} of {} has {} of {} requests remaining",
+///         metadata.route(),
+///         present.bucket().unwrap_or("unknown bucket"),
+///         present.remaining(),
+///         present.limit(),
+///     );
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`ErrorType::Response`]: crate::error::ErrorType::Response
+/// [`Response`]: super::Response
+/// [`Response::metadata`]: super::Response::metadata
+/// [`Response::model_with_metadata`]: super::Response::model_with_metadata
+#[derive(Clone, Debug)]
+pub struct ResponseMetadata {
+    /// Method of the request.
+    method: Method,
+    /// Parsed ratelimit headers of the response.
+    ratelimit_headers: Option<RatelimitHeaders>,
+    /// Route of the request.
+    route: Path,
+}
+
+impl ResponseMetadata {
+    /// Create metadata for a request that hasn't been sent yet.
+    pub(crate) const fn new(method: Method, route: Path) -> Self {
+        Self {
+            method,
+            ratelimit_headers: None,
+            route,
+        }
+    }
+
+    /// Record the ratelimit headers of the response.
+    pub(crate) fn record_response(&mut self, ratelimit_headers: Option<RatelimitHeaders>) {
+        self.ratelimit_headers = ratelimit_headers;
+    }
+
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Parsed ratelimit headers of the response.
+    ///
+    /// Returns [`None`] if the headers are malformed.
+    pub const fn ratelimit_headers(&self) -> Option<&RatelimitHeaders> {
+        self.ratelimit_headers.as_ref()
+    }
+
+    /// Route of the request, as used for ratelimiting.
+    ///
+    /// Its variant names the route, such as [`Path::ChannelsIdMessages`] for
+    /// requests getting or creating messages in a channel.
+    pub const fn route(&self) -> &Path {
+        &self.route
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseMetadata;
+    use crate::request::Method;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_http_ratelimiting::{request::Path, RatelimitHeaders};
+
+    assert_impl_all!(ResponseMetadata: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn record_response() {
+        let mut metadata = ResponseMetadata::new(Method::Get, Path::UsersId);
+        assert!(metadata.ratelimit_headers().is_none());
+
+        metadata.record_response(Some(RatelimitHeaders::None));
+        assert_eq!(Method::Get, metadata.method());
+        assert!(metadata
+            .ratelimit_headers()
+            .is_some_and(RatelimitHeaders::is_none));
+        assert_eq!(&Path::UsersId, metadata.route());
+    }
+}
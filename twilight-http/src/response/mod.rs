@@ -46,19 +46,21 @@
 
 pub mod marker;
 
+pub(crate) mod cache;
 pub(crate) mod future;
 
+mod metadata;
 mod status_code;
 
-pub use self::{future::ResponseFuture, status_code::StatusCode};
+pub use self::{future::ResponseFuture, metadata::ResponseMetadata, status_code::StatusCode};
 
-use self::marker::ListBody;
+use self::{cache::CachedBody, marker::ListBody};
 use http::{
     header::{HeaderValue, Iter as HeaderMapIter},
     Response as HyperResponse,
 };
-use http_body_util::BodyExt;
-use hyper::body::{Bytes, Incoming};
+use http_body_util::{BodyExt, Collected, LengthLimitError, Limited};
+use hyper::body::{Body, Bytes, Incoming};
 use serde::de::DeserializeOwned;
 use std::{
     error::Error,
@@ -69,6 +71,7 @@ use std::{
     pin::Pin,
     task::{Context, Poll},
 };
+use tokio::sync::OwnedSemaphorePermit;
 
 /// Failure when processing a response body.
 #[derive(Debug)]
@@ -108,6 +111,12 @@ impl Display for DeserializeBodyError {
             &DeserializeBodyErrorType::BodyNotUtf8 { .. } => {
                 f.write_str("response body is not a utf-8 valid string")
             }
+            DeserializeBodyErrorType::BodyTooLarge { max } => {
+                f.write_str("response body is larger than the maximum of ")?;
+                Display::fmt(max, f)?;
+
+                f.write_str(" bytes")
+            }
             DeserializeBodyErrorType::Chunking { .. } => {
                 f.write_str("failed to chunk response body")
             }
@@ -140,6 +149,14 @@ pub enum DeserializeBodyErrorType {
         /// valid string.
         bytes: Vec<u8>,
     },
+    /// Response body is larger than the maximum size configured via
+    /// [`ClientBuilder::max_response_body_size`].
+    ///
+    /// [`ClientBuilder::max_response_body_size`]: crate::client::ClientBuilder::max_response_body_size
+    BodyTooLarge {
+        /// Maximum size of response bodies in bytes.
+        max: usize,
+    },
     /// Response body couldn't be chunked.
     Chunking,
     /// Decompressing the response failed.
@@ -175,24 +192,73 @@ pub enum DeserializeBodyErrorType {
 /// ```
 #[derive(Debug)]
 pub struct Response<T> {
+    /// Maximum size of the body in bytes.
+    body_size_max: Option<usize>,
+    cache: Option<CachedBody>,
     inner: HyperResponse<Incoming>,
+    /// Metadata about the request and its ratelimit headers.
+    metadata: ResponseMetadata,
+    /// Permit of the client's concurrency limit, released once the body is
+    /// read or the response is dropped.
+    permit: Option<OwnedSemaphorePermit>,
     phantom: PhantomData<T>,
 }
 
 impl<T> Response<T> {
-    pub(crate) const fn new(inner: HyperResponse<Incoming>) -> Self {
+    pub(crate) const fn new(
+        inner: HyperResponse<Incoming>,
+        body_size_max: Option<usize>,
+        metadata: ResponseMetadata,
+    ) -> Self {
+        Self {
+            body_size_max,
+            cache: None,
+            inner,
+            metadata,
+            permit: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Create a response whose body is retrieved from or stored in the
+    /// response cache.
+    pub(crate) const fn cached(
+        inner: HyperResponse<Incoming>,
+        body_size_max: Option<usize>,
+        cache: CachedBody,
+        metadata: ResponseMetadata,
+    ) -> Self {
         Self {
+            body_size_max,
+            cache: Some(cache),
             inner,
+            metadata,
+            permit: None,
             phantom: PhantomData,
         }
     }
 
+    /// Hold a permit of the client's concurrency limit until the body is read.
+    pub(crate) fn hold(mut self, permit: Option<OwnedSemaphorePermit>) -> Self {
+        self.permit = permit;
+
+        self
+    }
+
     /// Iterator of the response headers.
     #[must_use = "creating an iterator of the headers has no use on its own"]
     pub fn headers(&self) -> HeaderIter<'_> {
         HeaderIter(self.inner.headers().iter())
     }
 
+    /// Metadata about the request and its ratelimit headers.
+    ///
+    /// Clone the metadata to keep it around after consuming the response.
+    #[must_use = "retrieving the metadata has no use on its own"]
+    pub const fn metadata(&self) -> &ResponseMetadata {
+        &self.metadata
+    }
+
     /// Status code of the response.
     #[must_use = "retrieving the status code has no use on its own"]
     pub fn status(&self) -> StatusCode {
@@ -229,11 +295,25 @@ impl<T> Response<T> {
     ///
     /// # Errors
     ///
+    /// Returns a [`DeserializeBodyErrorType::BodyTooLarge`] error type if the
+    /// response body is larger than the [maximum size].
+    ///
     /// Returns a [`DeserializeBodyErrorType::Chunking`] error type if the
     /// response body could not be entirely read.
     ///
     /// [`text`]: Self::text
+    /// [maximum size]: crate::client::ClientBuilder::max_response_body_size
     pub fn bytes(self) -> BytesFuture {
+        let store = match self.cache {
+            Some(CachedBody::Hit(bytes)) => {
+                return BytesFuture {
+                    inner: Box::pin(async move { Ok(bytes) }),
+                }
+            }
+            Some(CachedBody::Store { cache, etag, key }) => Some((cache, etag, key)),
+            None => None,
+        };
+
         #[cfg(feature = "decompression")]
         let compressed = self
             .inner
@@ -242,23 +322,26 @@ impl<T> Response<T> {
             .is_some();
 
         let body = self.inner.into_body();
+        let permit = self.permit;
+        let size_max = self.body_size_max;
 
         let fut = async move {
-            {
-                #[cfg(feature = "decompression")]
-                if compressed {
-                    return decompress(body).await;
-                }
+            #[cfg(feature = "decompression")]
+            let bytes = if compressed {
+                decompress(body, size_max).await?
+            } else {
+                collect(body, size_max).await?.to_bytes()
+            };
+            #[cfg(not(feature = "decompression"))]
+            let bytes = collect(body, size_max).await?.to_bytes();
 
-                Ok(body
-                    .collect()
-                    .await
-                    .map_err(|source| DeserializeBodyError {
-                        kind: DeserializeBodyErrorType::Chunking,
-                        source: Some(Box::new(source)),
-                    })?
-                    .to_bytes())
+            if let Some((cache, etag, key)) = store {
+                cache.insert(key, etag, bytes.clone());
             }
+
+            drop(permit);
+
+            Ok(bytes)
         };
 
         BytesFuture {
@@ -300,6 +383,49 @@ impl<T> Response<T> {
     pub fn text(self) -> TextFuture {
         TextFuture(self.bytes())
     }
+
+    /// Consume the response, chunking the body and then deserializing it into
+    /// a type of your choosing.
+    ///
+    /// This is useful for deserializing into lighter-weight types or types
+    /// with fields not yet supported by `twilight-model`, while still going
+    /// through the client's ratelimiting.
+    ///
+    /// # Examples
+    ///
+    /// Deserialize only the ID and name of the current user:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use serde::Deserialize;
+    /// use std::env;
+    /// use twilight_http::Client;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct PartialUser {
+    ///     id: String,
+    ///     username: String,
+    /// }
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let response = client.current_user().await?;
+    /// let user = response.model_as::<PartialUser>().await?;
+    ///
+    /// println!("{} is {}", user.id, user.username);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeserializeBodyErrorType::Chunking`] error type if the
+    /// response body could not be entirely read.
+    ///
+    /// Returns a [`DeserializeBodyErrorType::Deserializing`] error type if the
+    /// response body could not be deserialized into the type.
+    pub fn model_as<U: DeserializeOwned>(self) -> ModelFuture<U> {
+        ModelFuture::new(self.bytes())
+    }
 }
 
 impl<T: DeserializeOwned> Response<T> {
@@ -316,6 +442,22 @@ impl<T: DeserializeOwned> Response<T> {
     pub fn model(self) -> ModelFuture<T> {
         ModelFuture::new(self.bytes())
     }
+
+    /// Consume the response, chunking the body and then deserializing it into
+    /// the request's matching model along with the response's metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeserializeBodyErrorType::Chunking`] error type if the
+    /// response body could not be entirely read.
+    ///
+    /// Returns a [`DeserializeBodyErrorType::Deserializing`] error type if the
+    /// response body could not be deserialized into the target model.
+    pub fn model_with_metadata(self) -> ModelWithMetadataFuture<T> {
+        let metadata = self.metadata.clone();
+
+        ModelWithMetadataFuture::new(self.model(), metadata)
+    }
 }
 
 impl<T: DeserializeOwned> Response<ListBody<T>> {
@@ -348,7 +490,31 @@ impl<T: DeserializeOwned> Response<ListBody<T>> {
     /// Returns a [`DeserializeBodyErrorType::Deserializing`] error type if the
     /// response body could not be deserialized into a list of something.
     pub fn models(self) -> ModelFuture<Vec<T>> {
-        Response::<Vec<T>>::new(self.inner).model()
+        Response::<Vec<T>> {
+            body_size_max: self.body_size_max,
+            cache: self.cache,
+            inner: self.inner,
+            metadata: self.metadata,
+            permit: self.permit,
+            phantom: PhantomData,
+        }
+        .model()
+    }
+
+    /// Consume the response, chunking the body and then deserializing it into
+    /// a list of something along with the response's metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeserializeBodyErrorType::Chunking`] error type if the
+    /// response body could not be entirely read.
+    ///
+    /// Returns a [`DeserializeBodyErrorType::Deserializing`] error type if the
+    /// response body could not be deserialized into a list of something.
+    pub fn models_with_metadata(self) -> ModelWithMetadataFuture<Vec<T>> {
+        let metadata = self.metadata.clone();
+
+        ModelWithMetadataFuture::new(self.models(), metadata)
     }
 }
 
@@ -457,7 +623,7 @@ impl Future for BytesFuture {
 
 /// Future resolving to a deserialized model.
 ///
-/// Obtained via [`Response::model`].
+/// Obtained via [`Response::model`] or [`Response::model_as`].
 ///
 /// # Examples
 ///
@@ -519,6 +685,53 @@ impl<T: DeserializeOwned + Unpin> Future for ModelFuture<T> {
     }
 }
 
+/// Future resolving to a deserialized model of a response body along with the
+/// response's [`ResponseMetadata`].
+///
+/// Obtained via [`Response::model_with_metadata`] or
+/// [`Response::models_with_metadata`].
+///
+/// # Errors
+///
+/// Returns a [`DeserializeBodyErrorType::Chunking`] error type if the
+/// response body could not be entirely read.
+///
+/// Returns a [`DeserializeBodyErrorType::Deserializing`] error type if the
+/// response body could not be deserialized into a model.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ModelWithMetadataFuture<T> {
+    future: ModelFuture<T>,
+    metadata: Option<ResponseMetadata>,
+}
+
+impl<T> ModelWithMetadataFuture<T> {
+    const fn new(future: ModelFuture<T>, metadata: ResponseMetadata) -> Self {
+        Self {
+            future,
+            metadata: Some(metadata),
+        }
+    }
+}
+
+impl<T: DeserializeOwned + Unpin> Future for ModelWithMetadataFuture<T> {
+    type Output = Result<(T, ResponseMetadata), DeserializeBodyError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.future).poll(cx) {
+            Poll::Ready(Ok(model)) => {
+                let metadata = self
+                    .metadata
+                    .take()
+                    .expect("future polled after completion");
+
+                Poll::Ready(Ok((model, metadata)))
+            }
+            Poll::Ready(Err(source)) => Poll::Ready(Err(source)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
 /// Future resolving to the text of a response body.
 ///
 /// The body of the response is chunked and aggregated into a string.
@@ -575,23 +788,48 @@ impl Future for TextFuture {
     }
 }
 
+/// Accumulate a body, failing once it's larger than the maximum size.
+async fn collect<B>(
+    body: B,
+    size_max: Option<usize>,
+) -> Result<Collected<B::Data>, DeserializeBodyError>
+where
+    B: Body,
+    B::Error: Error + Send + Sync + 'static,
+{
+    let Some(max) = size_max else {
+        return body.collect().await.map_err(|source| DeserializeBodyError {
+            kind: DeserializeBodyErrorType::Chunking,
+            source: Some(Box::new(source)),
+        });
+    };
+
+    Limited::new(body, max)
+        .collect()
+        .await
+        .map_err(|source| DeserializeBodyError {
+            kind: if source.is::<LengthLimitError>() {
+                DeserializeBodyErrorType::BodyTooLarge { max }
+            } else {
+                DeserializeBodyErrorType::Chunking
+            },
+            source: Some(source),
+        })
+}
+
+/// Accumulate a compressed body and decompress it, failing once either the
+/// body or its decompressed contents are larger than the maximum size.
 #[cfg(feature = "decompression")]
-async fn decompress<B: hyper::body::Body>(body: B) -> Result<Bytes, DeserializeBodyError>
+async fn decompress<B>(body: B, size_max: Option<usize>) -> Result<Bytes, DeserializeBodyError>
 where
-    <B as hyper::body::Body>::Error: Send + Sync + Error + 'static,
+    B: Body,
+    B::Error: Error + Send + Sync + 'static,
 {
     use brotli_decompressor::Decompressor;
     use hyper::body::Buf;
     use std::io::Read;
 
-    let aggregate = body
-        .collect()
-        .await
-        .map_err(|source| DeserializeBodyError {
-            kind: DeserializeBodyErrorType::Chunking,
-            source: Some(Box::new(source)),
-        })?
-        .aggregate();
+    let aggregate = collect(body, size_max).await?.aggregate();
 
     // Determine the size of the entire buffer, in order to create the
     // decompressed and compressed buffers.
@@ -599,13 +837,26 @@ where
 
     let mut buf = Vec::with_capacity(size);
 
+    // Read one byte past the maximum size to detect that it was exceeded.
+    let limit = size_max
+        .and_then(|max| u64::try_from(max).ok())
+        .map_or(u64::MAX, |max| max.saturating_add(1));
+
     Decompressor::new(aggregate.reader(), size)
+        .take(limit)
         .read_to_end(&mut buf)
         .map_err(|_| DeserializeBodyError {
             kind: DeserializeBodyErrorType::Decompressing,
             source: None,
         })?;
 
+    if let Some(max) = size_max.filter(|max| buf.len() > *max) {
+        return Err(DeserializeBodyError {
+            kind: DeserializeBodyErrorType::BodyTooLarge { max },
+            source: None,
+        });
+    }
+
     Ok(buf.into())
 }
 
@@ -614,7 +865,7 @@ mod tests {
     use super::{
         marker::{EmptyBody, ListBody},
         BytesFuture, DeserializeBodyError, DeserializeBodyErrorType, HeaderIter, ModelFuture,
-        Response, TextFuture,
+        ModelWithMetadataFuture, Response, TextFuture,
     };
     use static_assertions::assert_impl_all;
     use std::{fmt::Debug, future::Future, iter::FusedIterator};
@@ -628,6 +879,7 @@ mod tests {
     assert_impl_all!(DeserializeBodyError: Debug, Send, Sync);
     assert_impl_all!(HeaderIter<'_>: Debug, FusedIterator, Iterator, Send, Sync);
     assert_impl_all!(ModelFuture<Emoji>: Future);
+    assert_impl_all!(ModelWithMetadataFuture<Emoji>: Future);
     assert_impl_all!(Response<EmptyBody>: Debug, Send, Sync);
     assert_impl_all!(Response<ListBody<Message>>: Debug, Send, Sync);
     assert_impl_all!(TextFuture: Future);
@@ -679,12 +931,107 @@ mod tests {
             3,
         ];
 
-        let decompressed = decompress(Full::new(COMPRESSED.as_slice())).await?;
+        let decompressed = decompress(Full::new(COMPRESSED.as_slice()), None).await?;
 
         let deserialized = serde_json::from_slice::<Invite>(&decompressed)?;
 
         assert_eq!(deserialized.code, "twilight-rs");
 
+        // The compressed body fits, but the decompressed body doesn't.
+        let error = decompress(Full::new(COMPRESSED.as_slice()), Some(COMPRESSED.len()))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            DeserializeBodyErrorType::BodyTooLarge { max } if *max == COMPRESSED.len()
+        ));
+
         Ok(())
     }
+
+    #[tokio::test]
+    async fn body_size_max() {
+        use super::collect;
+        use http_body_util::Full;
+
+        let body = || Full::new(b"0123456789".as_slice());
+
+        let bytes = collect(body(), Some(10)).await.unwrap().to_bytes();
+        assert_eq!(b"0123456789".as_slice(), bytes);
+
+        let error = collect(body(), Some(9)).await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            DeserializeBodyErrorType::BodyTooLarge { max: 9 }
+        ));
+        assert_eq!(
+            "response body is larger than the maximum of 9 bytes",
+            error.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn metadata() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        use crate::{
+            mock::{MockResponse, MockTransport},
+            request::Method,
+        };
+        use http::header::{HeaderName, HeaderValue};
+        use twilight_http_ratelimiting::{request::Path, RatelimitHeaders};
+        use twilight_model::id::Id;
+
+        let transport = MockTransport::new();
+        let mut response = MockResponse::json(&[0; 0]);
+        for (name, value) in [
+            ("x-ratelimit-bucket", "abc"),
+            ("x-ratelimit-limit", "5"),
+            ("x-ratelimit-remaining", "4"),
+            ("x-ratelimit-reset", "1700000000.000"),
+            ("x-ratelimit-reset-after", "1.000"),
+        ] {
+            response = response.header(
+                HeaderName::from_static(name),
+                HeaderValue::from_static(value),
+            );
+        }
+        transport.route(Method::Get, Path::ChannelsIdMessages(1), response);
+        let client = transport.client_builder().ratelimiter(None).build();
+
+        let (messages, metadata) = client
+            .channel_messages(Id::new(1))
+            .await?
+            .models_with_metadata()
+            .await?;
+        assert!(messages.is_empty());
+        assert_eq!(Method::Get, metadata.method());
+        assert_eq!(&Path::ChannelsIdMessages(1), metadata.route());
+        assert!(matches!(
+            metadata.ratelimit_headers(),
+            Some(RatelimitHeaders::Present(present))
+                if present.bucket() == Some("abc") && present.remaining() == 4
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn error_metadata() {
+        use crate::{
+            error::ErrorType,
+            mock::{MockResponse, MockTransport},
+            request::Method,
+        };
+        use twilight_http_ratelimiting::request::Path;
+
+        let transport = MockTransport::new();
+        transport.route(Method::Get, Path::UsersId, MockResponse::server_error(500));
+        let client = transport.client_builder().ratelimiter(None).build();
+
+        let error = client.current_user().await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { metadata, status, .. }
+                if status.get() == 500 && metadata.route() == &Path::UsersId
+        ));
+    }
 }
@@ -40,6 +40,8 @@ pub enum Route<'a> {
         /// ID of the member.
         user_id: u64,
     },
+    /// Route information to exchange an OAuth2 grant for an access token.
+    CreateAccessToken,
     /// Route information to create an auto moderation rule.
     CreateAutoModerationRule {
         /// ID of the guild.
@@ -728,6 +730,8 @@ pub enum Route<'a> {
         before: Option<u64>,
         /// The maximum number of guilds to get.
         limit: Option<u16>,
+        /// Whether to include approximate member and presence counts.
+        with_counts: Option<bool>,
     },
     /// Route information to get an original interaction response message.
     GetInteractionOriginal {
@@ -860,6 +864,8 @@ pub enum Route<'a> {
         channel_id: u64,
         /// ID of the member.
         user_id: u64,
+        /// Whether to include the associated member object.
+        with_member: Option<bool>,
     },
     /// Route information to get members of a thread.
     GetThreadMembers {
@@ -879,6 +885,12 @@ pub enum Route<'a> {
         /// ID of the target user.
         user_id: u64,
     },
+    /// Route information to get the current user's role connection to an
+    /// application.
+    GetUserApplicationRoleConnection {
+        /// ID of the application.
+        application_id: u64,
+    },
     /// Route information to get the current user's connections.
     GetUserConnections,
     /// Route information to get the current user's private channels and groups.
@@ -956,6 +968,8 @@ pub enum Route<'a> {
         /// ID of the member.
         user_id: u64,
     },
+    /// Route information to revoke an OAuth2 access or refresh token.
+    RevokeToken,
     /// Route information to search for members in a guild.
     SearchGuildMembers {
         /// ID of the guild to search in.
@@ -1162,6 +1176,12 @@ pub enum Route<'a> {
         /// The template code.
         template_code: &'a str,
     },
+    /// Route information to update the current user's role connection to an
+    /// application.
+    UpdateUserApplicationRoleConnection {
+        /// ID of the application.
+        application_id: u64,
+    },
     /// Route information to update a user's voice state.
     UpdateUserVoiceState {
         /// ID of the guild.
@@ -1308,6 +1328,7 @@ impl<'a> Route<'a> {
             | Self::GetTemplates { .. }
             | Self::GetThreadMember { .. }
             | Self::GetThreadMembers { .. }
+            | Self::GetUserApplicationRoleConnection { .. }
             | Self::GetUserConnections
             | Self::GetUserPrivateChannels
             | Self::GetUser { .. }
@@ -1351,6 +1372,7 @@ impl<'a> Route<'a> {
             | Self::CreateEmoji { .. }
             | Self::CreateForumThread { .. }
             | Self::CreateGuild
+            | Self::CreateAccessToken
             | Self::CreateAutoModerationRule { .. }
             | Self::CreateGuildFromTemplate { .. }
             | Self::CreateGuildIntegration { .. }
@@ -1374,6 +1396,7 @@ impl<'a> Route<'a> {
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
+            | Self::RevokeToken
             | Self::SyncGuildIntegration { .. } => Method::Post,
             Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
@@ -1387,7 +1410,8 @@ impl<'a> Route<'a> {
             | Self::SyncTemplate { .. }
             | Self::UpdateCommandPermissions { .. }
             | Self::UpdateGuildOnboarding { .. }
-            | Self::UpdatePermissionOverwrite { .. } => Method::Put,
+            | Self::UpdatePermissionOverwrite { .. }
+            | Self::UpdateUserApplicationRoleConnection { .. } => Method::Put,
         }
     }
 
@@ -1437,6 +1461,7 @@ impl<'a> Route<'a> {
             | Self::RemoveThreadMember { channel_id, .. } => {
                 Path::ChannelsIdThreadMembers(channel_id)
             }
+            Self::CreateAccessToken => Path::OauthToken,
             Self::CreateAutoModerationRule { guild_id, .. }
             | Self::GetGuildAutoModerationRules { guild_id, .. } => {
                 Path::GuildsIdAutoModerationRules(guild_id)
@@ -1696,12 +1721,17 @@ impl<'a> Route<'a> {
             }
             Self::GetSKUs { application_id } => Path::ApplicationIdSKUs(application_id),
             Self::GetSticker { .. } => Path::Stickers,
+            Self::GetUserApplicationRoleConnection { application_id }
+            | Self::UpdateUserApplicationRoleConnection { application_id } => {
+                Path::UsersIdApplicationsIdRoleConnection(application_id)
+            }
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
             Self::InteractionCallback { interaction_id, .. } => {
                 Path::InteractionCallback(interaction_id)
             }
             Self::LeaveGuild { .. } => Path::UsersIdGuildsId,
+            Self::RevokeToken => Path::OauthTokenRevoke,
             Self::SearchGuildMembers { guild_id, .. } => Path::GuildsIdMembersSearch(guild_id),
             Self::SyncGuildIntegration { guild_id, .. } => {
                 Path::GuildsIdIntegrationsIdSync(guild_id)
@@ -1792,10 +1822,6 @@ impl Display for Route<'_> {
                 channel_id,
                 user_id,
             }
-            | Route::GetThreadMember {
-                channel_id,
-                user_id,
-            }
             | Route::RemoveThreadMember {
                 channel_id,
                 user_id,
@@ -1806,6 +1832,7 @@ impl Display for Route<'_> {
 
                 Display::fmt(user_id, f)
             }
+            Route::CreateAccessToken => f.write_str("oauth2/token"),
             Route::CreateAutoModerationRule { guild_id, .. }
             | Route::GetGuildAutoModerationRules { guild_id, .. } => {
                 f.write_str("guilds/")?;
@@ -2731,6 +2758,7 @@ impl Display for Route<'_> {
                 after,
                 before,
                 limit,
+                with_counts,
             } => {
                 f.write_str("users/@me/guilds")?;
 
@@ -2738,7 +2766,8 @@ impl Display for Route<'_> {
 
                 query_formatter.write_opt_param("after", after.as_ref())?;
                 query_formatter.write_opt_param("before", before.as_ref())?;
-                query_formatter.write_opt_param("limit", limit.as_ref())
+                query_formatter.write_opt_param("limit", limit.as_ref())?;
+                query_formatter.write_opt_param("with_counts", with_counts.as_ref())
             }
             Route::GetInvite { code, with_counts } => {
                 f.write_str("invites/")?;
@@ -2865,6 +2894,20 @@ impl Display for Route<'_> {
 
                 Display::fmt(sticker_id, f)
             }
+            Route::GetThreadMember {
+                channel_id,
+                user_id,
+                with_member,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/thread-members/")?;
+                Display::fmt(user_id, f)?;
+
+                let mut query_formatter = QueryStringFormatter::new(f);
+
+                query_formatter.write_opt_param("with_member", with_member.as_ref())
+            }
             Route::GetThreadMembers {
                 after,
                 channel_id,
@@ -2881,6 +2924,13 @@ impl Display for Route<'_> {
                 query_formatter.write_opt_param("limit", limit.as_ref())?;
                 query_formatter.write_opt_param("with_member", with_member.as_ref())
             }
+            Route::GetUserApplicationRoleConnection { application_id }
+            | Route::UpdateUserApplicationRoleConnection { application_id } => {
+                f.write_str("users/@me/applications/")?;
+                Display::fmt(application_id, f)?;
+
+                f.write_str("/role-connection")
+            }
             Route::GetUserConnections => f.write_str("users/@me/connections"),
             Route::GetUser { user_id } => {
                 f.write_str("users/")?;
@@ -2924,6 +2974,7 @@ impl Display for Route<'_> {
 
                 Display::fmt(message_id, f)
             }
+            Route::RevokeToken => f.write_str("oauth2/token/revoke"),
             Route::SearchGuildMembers {
                 guild_id,
                 limit,
@@ -3191,11 +3242,22 @@ mod tests {
         let route = Route::GetThreadMember {
             channel_id: CHANNEL_ID,
             user_id: USER_ID,
+            with_member: None,
         };
         assert_eq!(
             route.to_string(),
             format!("channels/{CHANNEL_ID}/thread-members/{USER_ID}")
         );
+
+        let route = Route::GetThreadMember {
+            channel_id: CHANNEL_ID,
+            user_id: USER_ID,
+            with_member: Some(true),
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/thread-members/{USER_ID}?with_member=true")
+        );
     }
 
     #[test]
@@ -3366,6 +3428,28 @@ mod tests {
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/integrations"));
     }
 
+    #[test]
+    fn get_guilds() {
+        let route = Route::GetGuilds {
+            after: Some(GUILD_ID),
+            before: None,
+            limit: Some(50),
+            with_counts: Some(true),
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("users/@me/guilds?after={GUILD_ID}&limit=50&with_counts=true")
+        );
+
+        let route = Route::GetGuilds {
+            after: None,
+            before: None,
+            limit: None,
+            with_counts: None,
+        };
+        assert_eq!(route.to_string(), "users/@me/guilds");
+    }
+
     #[test]
     fn get_guild_integrations() {
         let route = Route::GetGuildIntegrations { guild_id: GUILD_ID };
@@ -4124,6 +4208,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_access_token() {
+        let route = Route::CreateAccessToken;
+        assert_eq!(route.to_string(), "oauth2/token");
+    }
+
+    #[test]
+    fn revoke_token() {
+        let route = Route::RevokeToken;
+        assert_eq!(route.to_string(), "oauth2/token/revoke");
+    }
+
     #[test]
     fn get_current_authorization_info() {
         let route = Route::GetCurrentAuthorizationInformation;
@@ -4334,6 +4430,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_user_application_role_connection() {
+        let route = Route::GetUserApplicationRoleConnection {
+            application_id: APPLICATION_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("users/@me/applications/{APPLICATION_ID}/role-connection")
+        );
+    }
+
+    #[test]
+    fn update_user_application_role_connection() {
+        let route = Route::UpdateUserApplicationRoleConnection {
+            application_id: APPLICATION_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("users/@me/applications/{APPLICATION_ID}/role-connection")
+        );
+    }
+
     #[test]
     fn get_user_connections() {
         let route = Route::GetUserConnections;
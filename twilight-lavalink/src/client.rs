@@ -1,7 +1,7 @@
 //! Client to manage nodes and players.
 
 use crate::{
-    model::VoiceUpdate,
+    model::{PluginDecoders, VoiceUpdate},
     node::{IncomingEvents, Node, NodeConfig, NodeError, Resume},
     player::{Player, PlayerManager},
 };
@@ -10,8 +10,9 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     net::SocketAddr,
-    sync::Arc,
+    sync::{Arc, Weak},
 };
+use tokio::sync::mpsc::{self, UnboundedReceiver};
 use twilight_model::{
     gateway::{event::Event, payload::incoming::VoiceServerUpdate, ShardId},
     id::{
@@ -87,6 +88,18 @@ pub enum ClientErrorType {
 /// information about the active playing information of a guild and allows you to send events to the
 /// connected node, such as [`Play`] events.
 ///
+/// # Failover
+///
+/// When the connection to a node is lost and can't be re-established, its
+/// players are migrated to the best remaining node. Their voice connections
+/// are re-established and their tracks resumed at their last known positions.
+/// The players are removed if no other node is available.
+///
+/// Migrated players are replaced by new players linked to the other node, so
+/// players should be retrieved from the [`PlayerManager`] instead of being held
+/// on to. The node keeps reconnecting in the background and is used for
+/// players again once it recovers.
+///
 /// # Using a Lavalink client in multiple tasks
 ///
 /// To use a Lavalink client instance in multiple tasks, consider wrapping it in
@@ -97,13 +110,14 @@ pub enum ClientErrorType {
 /// [`process`]: Self::process
 #[derive(Debug)]
 pub struct Lavalink {
-    nodes: DashMap<SocketAddr, Arc<Node>>,
+    nodes: Arc<DashMap<SocketAddr, Arc<Node>>>,
     players: PlayerManager,
+    plugins: PluginDecoders,
     resume: Option<Resume>,
     shard_count: u32,
     user_id: Id<UserMarker>,
-    server_updates: DashMap<Id<GuildMarker>, VoiceServerUpdate>,
-    sessions: DashMap<Id<GuildMarker>, Box<str>>,
+    server_updates: Arc<DashMap<Id<GuildMarker>, VoiceServerUpdate>>,
+    sessions: Arc<DashMap<Id<GuildMarker>, Box<str>>>,
 }
 
 impl Lavalink {
@@ -139,16 +153,32 @@ impl Lavalink {
 
     fn _new_with_resume(user_id: Id<UserMarker>, shard_count: u32, resume: Option<Resume>) -> Self {
         Self {
-            nodes: DashMap::new(),
+            nodes: Arc::new(DashMap::new()),
             players: PlayerManager::new(),
+            plugins: PluginDecoders::new(),
             resume,
             shard_count,
             user_id,
-            server_updates: DashMap::new(),
-            sessions: DashMap::new(),
+            server_updates: Arc::new(DashMap::new()),
+            sessions: Arc::new(DashMap::new()),
         }
     }
 
+    /// Set the decoders of the plugin events received from nodes added
+    /// afterwards.
+    ///
+    /// Plugin events are received as [`IncomingEvent::Plugin`], whose typed
+    /// event is available via [`PluginEvent::decoded`].
+    ///
+    /// [`IncomingEvent::Plugin`]: crate::model::IncomingEvent::Plugin
+    /// [`PluginEvent::decoded`]: crate::model::PluginEvent::decoded
+    #[must_use = "has no effect if not used"]
+    pub fn plugin_decoders(mut self, decoders: PluginDecoders) -> Self {
+        self.plugins = decoders;
+
+        self
+    }
+
     /// Process an event into the Lavalink client.
     ///
     /// **Note**: calling this method in your event loop is required. See the
@@ -278,10 +308,13 @@ impl Lavalink {
         let config = NodeConfig {
             address,
             authorization: authorization.into(),
+            plugins: self.plugins.clone(),
             resume: self.resume.clone(),
             user_id: self.user_id,
         };
 
+        self.start_failover();
+
         let (node, rx) = Node::connect(config, self.players.clone()).await?;
         let node = Arc::new(node);
         self.nodes.insert(address, Arc::clone(&node));
@@ -323,26 +356,7 @@ impl Lavalink {
     ///
     /// [`Node::penalty`]: crate::node::Node::penalty
     pub async fn best(&self) -> Result<Arc<Node>, ClientError> {
-        let mut lowest = i32::MAX;
-        let mut best = None;
-
-        for node in &self.nodes {
-            if node.sender().is_closed() {
-                continue;
-            }
-
-            let penalty = node.value().penalty().await;
-
-            if penalty < lowest {
-                lowest = penalty;
-                best.replace(node.clone());
-            }
-        }
-
-        best.ok_or(ClientError {
-            kind: ClientErrorType::NodesUnconfigured,
-            source: None,
-        })
+        best(&self.nodes).await
     }
 
     /// Retrieve an immutable reference to the player manager.
@@ -373,6 +387,29 @@ impl Lavalink {
         Ok(self.players().get_or_insert(guild_id, node))
     }
 
+    /// Spawn the task failing over players of disconnected nodes, unless it's
+    /// already running.
+    ///
+    /// The task only keeps weak references to the client's state so that it
+    /// ends once the client and its nodes are dropped.
+    fn start_failover(&self) {
+        if self.players.failover.get().is_some() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        if self.players.failover.set(tx).is_ok() {
+            tokio::spawn(failover(
+                rx,
+                Arc::downgrade(&self.nodes),
+                Arc::downgrade(&self.players.players),
+                Arc::downgrade(&self.server_updates),
+                Arc::downgrade(&self.sessions),
+            ));
+        }
+    }
+
     /// Clear out the map of guild states/updates for a shard that are waiting
     /// for their other half.
     ///
@@ -391,6 +428,90 @@ impl Lavalink {
     }
 }
 
+/// Determine the node with the lowest penalty score among connected nodes.
+async fn best(nodes: &DashMap<SocketAddr, Arc<Node>>) -> Result<Arc<Node>, ClientError> {
+    let mut lowest = i32::MAX;
+    let mut best = None;
+
+    for node in nodes {
+        if !node.is_available() {
+            continue;
+        }
+
+        let penalty = node.value().penalty().await;
+
+        if penalty < lowest {
+            lowest = penalty;
+            best.replace(node.clone());
+        }
+    }
+
+    best.ok_or(ClientError {
+        kind: ClientErrorType::NodesUnconfigured,
+        source: None,
+    })
+}
+
+/// Migrate the players of nodes whose connection ended to the best remaining
+/// node, until the client is dropped.
+async fn failover(
+    mut rx: UnboundedReceiver<SocketAddr>,
+    nodes: Weak<DashMap<SocketAddr, Arc<Node>>>,
+    players: Weak<DashMap<Id<GuildMarker>, Arc<Player>>>,
+    server_updates: Weak<DashMap<Id<GuildMarker>, VoiceServerUpdate>>,
+    sessions: Weak<DashMap<Id<GuildMarker>, Box<str>>>,
+) {
+    while let Some(address) = rx.recv().await {
+        let (Some(nodes), Some(players), Some(server_updates), Some(sessions)) = (
+            nodes.upgrade(),
+            players.upgrade(),
+            server_updates.upgrade(),
+            sessions.upgrade(),
+        ) else {
+            break;
+        };
+
+        tracing::debug!("node {address} disconnected, failing over its players");
+
+        let disconnected = players
+            .iter()
+            .filter(|player| player.node().config().address == address)
+            .map(|player| Arc::clone(player.value()))
+            .collect::<Vec<_>>();
+
+        if disconnected.is_empty() {
+            continue;
+        }
+
+        let Ok(node) = best(&nodes).await else {
+            tracing::warn!(
+                "no node to fail over {} players of node {address} to",
+                disconnected.len(),
+            );
+            players.retain(|_, player| player.node().config().address != address);
+
+            continue;
+        };
+
+        for player in disconnected {
+            let guild_id = player.guild_id();
+            let voice_update = server_updates
+                .get(&guild_id)
+                .zip(sessions.get(&guild_id))
+                .map(|(server, session)| {
+                    VoiceUpdate::new(guild_id, session.as_ref(), server.clone())
+                });
+
+            let migrated = Arc::new(player.migrate(Arc::clone(&node)));
+            players.insert(guild_id, Arc::clone(&migrated));
+
+            if let Err(source) = migrated.restore(voice_update) {
+                tracing::warn!("failed to fail over player for guild {guild_id}: {source}");
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ClientError, ClientErrorType, Lavalink};
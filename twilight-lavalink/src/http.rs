@@ -1,9 +1,12 @@
 //! Models to deserialize responses into and functions to create `http` crate
 //! requests.
+//!
+//! Models and requests of version 4 of the Lavalink REST API are in the [`v4`]
+//! module.
 
 use http::{
-    header::{HeaderValue, AUTHORIZATION},
-    Error as HttpError, Request,
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    Error as HttpError, Method, Request,
 };
 use percent_encoding::NON_ALPHANUMERIC;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -128,21 +131,29 @@ pub enum IpBlockType {
 #[non_exhaustive]
 pub struct IpBlock {
     /// The IP version of the IP block.
+    #[serde(rename = "type")]
     pub kind: IpBlockType,
-    /// The size of the block's addresses.
-    pub size: u64,
+    /// The number of addresses in the block.
+    ///
+    /// This is a string because IPv6 blocks may be larger than a [`u64`].
+    pub size: String,
 }
 
 /// The type of route planner in use.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
-#[serde(rename_all = "PascalCase")]
 pub enum RoutePlannerType {
+    /// A Balancing IP route planner.
+    #[serde(rename = "BalancingIpRoutePlanner")]
+    BalancingIp,
     /// A Nano IP route planner.
+    #[serde(rename = "NanoIpRoutePlanner")]
     NanoIp,
     /// A Rotating IP route planner.
+    #[serde(rename = "RotatingIpRoutePlanner")]
     RotatingIp,
     /// A Rotating Nano IP route planner.
+    #[serde(rename = "RotatingNanoIpRoutePlanner")]
     RotatingNanoIp,
 }
 
@@ -151,12 +162,42 @@ pub enum RoutePlannerType {
 #[non_exhaustive]
 #[serde(untagged)]
 pub enum RoutePlanner {
+    /// Information about a Rotating Nano IP route planner.
+    //
+    // Route planners are deserialized in order, and the details of the other
+    // planners are subsets of the details of rotating nano IP planners, so
+    // these come first and balancing IP planners come last.
+    RotatingNanoIp(RotatingNanoIpRoutePlanner),
     /// Information about a Nano IP route planner.
     NanoIp(NanoIpRoutePlanner),
     /// Information about a Rotating IP route planner.
     RotatingIp(RotatingIpRoutePlanner),
-    /// Information about a Rotating Nano IP route planner.
-    RotatingNanoIp(RotatingNanoIpRoutePlanner),
+    /// Information about a Balancing IP route planner.
+    BalancingIp(BalancingIpRoutePlanner),
+}
+
+/// A Balancing IP planner.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct BalancingIpRoutePlanner {
+    /// The type of planner that is currently active.
+    ///
+    /// For this planner, this is always [`RoutePlannerType::BalancingIp`]
+    pub class: RoutePlannerType,
+    /// The details of the currently active balancing IP route planner.
+    pub details: BalancingIpDetails,
+}
+
+/// Information about a Balancing IP planner.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct BalancingIpDetails {
+    /// A list of IP addresses in the range that are failing.
+    pub failing_addresses: Vec<FailingAddress>,
+    /// The associated IP block.
+    pub ip_block: IpBlock,
 }
 
 /// A Nano IP planner.
@@ -210,9 +251,12 @@ pub struct RotatingIpDetails {
     /// The associated IP block.
     pub ip_block: IpBlock,
     /// The current offset used within the IP block.
-    pub ip_index: u64,
+    ///
+    /// This is a string because offsets within IPv6 blocks may be larger than
+    /// a [`u64`].
+    pub ip_index: String,
     /// The number of rotations that have happened since the server started.
-    pub rotate_index: u64,
+    pub rotate_index: String,
 }
 
 /// A Rotating Nano IP planner.
@@ -271,7 +315,7 @@ pub fn load_track(
 /// Get the configured route planner for a node by address.
 ///
 /// The response will include a body which can be deserialized into a
-/// [`RoutePlanner`].
+/// [`RoutePlanner`], or no body if the node has no route planner.
 ///
 /// # Errors
 ///
@@ -280,7 +324,7 @@ pub fn get_route_planner(
     address: SocketAddr,
     authorization: impl AsRef<str>,
 ) -> Result<Request<&'static [u8]>, HttpError> {
-    let mut req = Request::get(format!("{address}/routeplanner/status"));
+    let mut req = Request::get(format!("http://{address}/routeplanner/status"));
 
     let auth_value = HeaderValue::from_str(authorization.as_ref())?;
     req = req.header(AUTHORIZATION, auth_value);
@@ -301,10 +345,15 @@ pub fn unmark_failed_address(
     authorization: impl AsRef<str>,
     route_address: impl Into<IpAddr>,
 ) -> Result<Request<Vec<u8>>, HttpError> {
-    let mut req = Request::post(format!("{}/routeplanner/status", node_address.into()));
+    let mut req = Request::post(format!(
+        "http://{}/routeplanner/free/address",
+        node_address.into()
+    ));
 
     let auth_value = HeaderValue::from_str(authorization.as_ref())?;
-    req = req.header(AUTHORIZATION, auth_value);
+    req = req
+        .header(AUTHORIZATION, auth_value)
+        .header(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
     req.body(
         serde_json::to_vec(&serde_json::json!({
@@ -314,18 +363,442 @@ pub fn unmark_failed_address(
     )
 }
 
+/// Unmark all failed IP addresses, meaning that they can be used again.
+///
+/// The response will not include a body on success.
+///
+/// # Errors
+///
+/// See the documentation for [`http::Error`].
+pub fn unmark_all_failed_addresses(
+    node_address: impl Into<SocketAddr>,
+    authorization: impl AsRef<str>,
+) -> Result<Request<&'static [u8]>, HttpError> {
+    let mut req = Request::post(format!(
+        "http://{}/routeplanner/free/all",
+        node_address.into()
+    ));
+
+    let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+    req = req.header(AUTHORIZATION, auth_value);
+
+    req.body(b"")
+}
+
+/// Send a request to an endpoint of a plugin, such as the search endpoint of
+/// the `LavaSrc` plugin.
+///
+/// The path is relative to the node's address and may include a query, such
+/// as `/v4/loadsearch?query=spsearch%3Aname`. A body is sent as JSON.
+///
+/// The response body is specific to the plugin, and may be deserialized into
+/// the plugin's own models.
+///
+/// # Errors
+///
+/// See the documentation for [`http::Error`].
+pub fn plugin_request(
+    address: impl Into<SocketAddr>,
+    authorization: impl AsRef<str>,
+    method: Method,
+    path: impl AsRef<str>,
+    body: Option<Vec<u8>>,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let mut req = Request::builder().method(method).uri(format!(
+        "http://{}{}",
+        address.into(),
+        path.as_ref()
+    ));
+
+    let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+    req = req.header(AUTHORIZATION, auth_value);
+
+    if body.is_some() {
+        req = req.header(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    }
+
+    req.body(body.unwrap_or_default())
+}
+
+pub mod v4 {
+    //! Models to deserialize responses of version 4 of the Lavalink REST API
+    //! into and functions to create requests to it.
+
+    use super::deserialize_selected_track;
+    use crate::model::incoming::Exception;
+    use http::{
+        header::{HeaderValue, AUTHORIZATION},
+        Error as HttpError, Request,
+    };
+    use percent_encoding::NON_ALPHANUMERIC;
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use std::net::SocketAddr;
+
+    /// Result of loading tracks, by type of result.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(content = "data", rename_all = "camelCase", tag = "loadType")]
+    pub enum LoadResult {
+        /// There were no matches.
+        Empty {},
+        /// Loading the results failed.
+        Error(Exception),
+        /// A playlist was found.
+        Playlist(Playlist),
+        /// Some results were found.
+        Search(Vec<Track>),
+        /// A single track was found.
+        Track(Box<Track>),
+    }
+
+    /// A playlist within a load result.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Playlist {
+        /// Information about the playlist.
+        pub info: PlaylistInfo,
+        /// Additional information about the playlist provided by plugins.
+        #[serde(default)]
+        pub plugin_info: Value,
+        /// The tracks of the playlist.
+        pub tracks: Vec<Track>,
+    }
+
+    /// Information about a playlist within a load result.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct PlaylistInfo {
+        /// The name of the playlist.
+        pub name: String,
+        /// The index of the selected track within the playlist, if any.
+        #[serde(default, deserialize_with = "deserialize_selected_track")]
+        pub selected_track: Option<u64>,
+    }
+
+    /// A track within a load result.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Track {
+        /// The base64 encoded track.
+        pub encoded: String,
+        /// Details about the track, such as the author and title.
+        pub info: TrackInfo,
+        /// Additional information about the track provided by plugins.
+        #[serde(default)]
+        pub plugin_info: Value,
+        /// Custom data attached to the track by clients.
+        #[serde(default)]
+        pub user_data: Value,
+    }
+
+    /// Details about a track, such as the author.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct TrackInfo {
+        /// The URL of the artwork of the track, if any.
+        pub artwork_url: Option<String>,
+        /// The name of the author.
+        pub author: String,
+        /// The identifier of the track within its source.
+        pub identifier: String,
+        /// Whether the track is seekable.
+        pub is_seekable: bool,
+        /// Whether the track is a stream.
+        pub is_stream: bool,
+        /// The International Standard Recording Code of the track, if any.
+        pub isrc: Option<String>,
+        /// The length of the track in milliseconds.
+        pub length: u64,
+        /// The position of the track in milliseconds.
+        pub position: u64,
+        /// The name of the source of the track, such as `youtube`.
+        pub source_name: String,
+        /// The title of the track.
+        pub title: String,
+        /// The URI of the track, if any.
+        pub uri: Option<String>,
+    }
+
+    /// Get a list of tracks that match an identifier.
+    ///
+    /// The response will include a body which can be deserialized into a
+    /// [`LoadResult`].
+    ///
+    /// # Errors
+    ///
+    /// See the documentation for [`http::Error`].
+    pub fn load_track(
+        address: SocketAddr,
+        identifier: impl AsRef<str>,
+        authorization: impl AsRef<str>,
+    ) -> Result<Request<&'static [u8]>, HttpError> {
+        let identifier =
+            percent_encoding::percent_encode(identifier.as_ref().as_bytes(), NON_ALPHANUMERIC);
+        let url = format!("http://{address}/v4/loadtracks?identifier={identifier}");
+
+        let mut req = Request::get(url);
+
+        let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+        req = req.header(AUTHORIZATION, auth_value);
+
+        req.body(b"")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{LoadResult, Playlist, PlaylistInfo, Track, TrackInfo};
+        use crate::model::incoming::{Exception, ExceptionSeverity};
+        use serde::{Deserialize, Serialize};
+        use serde_json::json;
+        use static_assertions::{assert_fields, assert_impl_all};
+        use std::fmt::Debug;
+
+        assert_impl_all!(
+            LoadResult: Clone,
+            Debug,
+            Deserialize<'static>,
+            Eq,
+            PartialEq,
+            Send,
+            Serialize,
+            Sync,
+        );
+        assert_fields!(Playlist: info, plugin_info, tracks);
+        assert_impl_all!(
+            Playlist: Clone,
+            Debug,
+            Deserialize<'static>,
+            Eq,
+            PartialEq,
+            Send,
+            Serialize,
+            Sync,
+        );
+        assert_fields!(PlaylistInfo: name, selected_track);
+        assert_impl_all!(
+            PlaylistInfo: Clone,
+            Debug,
+            Deserialize<'static>,
+            Eq,
+            PartialEq,
+            Send,
+            Serialize,
+            Sync,
+        );
+        assert_fields!(Track: encoded, info, plugin_info, user_data);
+        assert_impl_all!(
+            Track: Clone,
+            Debug,
+            Deserialize<'static>,
+            Eq,
+            PartialEq,
+            Send,
+            Serialize,
+            Sync,
+        );
+        assert_fields!(
+            TrackInfo: artwork_url,
+            author,
+            identifier,
+            is_seekable,
+            is_stream,
+            isrc,
+            length,
+            position,
+            source_name,
+            title,
+            uri
+        );
+        assert_impl_all!(
+            TrackInfo: Clone,
+            Debug,
+            Deserialize<'static>,
+            Eq,
+            PartialEq,
+            Send,
+            Serialize,
+            Sync,
+        );
+
+        const V4_TRACK: &str = r#"{
+            "encoded": "QAAAjQIAJVJpY2sgQXN0bGV5IC0gTmV2ZXIgR29ubmEgR2l2ZSBZb3UgVXA=",
+            "info": {
+                "identifier": "dQw4w9WgXcQ",
+                "isSeekable": true,
+                "author": "RickAstleyVEVO",
+                "length": 212000,
+                "isStream": false,
+                "position": 0,
+                "title": "Rick Astley - Never Gonna Give You Up",
+                "uri": "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+                "artworkUrl": null,
+                "isrc": null,
+                "sourceName": "youtube"
+            },
+            "pluginInfo": {},
+            "userData": {}
+        }"#;
+
+        fn v4_track() -> Track {
+            Track {
+                encoded: "QAAAjQIAJVJpY2sgQXN0bGV5IC0gTmV2ZXIgR29ubmEgR2l2ZSBZb3UgVXA=".to_owned(),
+                info: TrackInfo {
+                    artwork_url: None,
+                    author: "RickAstleyVEVO".to_owned(),
+                    identifier: "dQw4w9WgXcQ".to_owned(),
+                    is_seekable: true,
+                    is_stream: false,
+                    isrc: None,
+                    length: 212_000,
+                    position: 0,
+                    source_name: "youtube".to_owned(),
+                    title: "Rick Astley - Never Gonna Give You Up".to_owned(),
+                    uri: Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_owned()),
+                },
+                plugin_info: json!({}),
+                user_data: json!({}),
+            }
+        }
+
+        #[test]
+        fn v4_load_result_track() {
+            let json = format!(r#"{{"loadType": "track", "data": {V4_TRACK}}}"#);
+            let expected = LoadResult::Track(Box::new(v4_track()));
+
+            assert_eq!(expected, serde_json::from_str(&json).unwrap());
+        }
+
+        #[test]
+        fn v4_load_result_playlist() {
+            let json = format!(
+                r#"{{
+                    "loadType": "playlist",
+                    "data": {{
+                        "info": {{"name": "Example", "selectedTrack": 0}},
+                        "pluginInfo": {{"url": "https://example.com"}},
+                        "tracks": [{V4_TRACK}]
+                    }}
+                }}"#
+            );
+            let expected = LoadResult::Playlist(Playlist {
+                info: PlaylistInfo {
+                    name: "Example".to_owned(),
+                    selected_track: Some(0),
+                },
+                plugin_info: json!({"url": "https://example.com"}),
+                tracks: vec![v4_track()],
+            });
+
+            assert_eq!(expected, serde_json::from_str(&json).unwrap());
+
+            let json = r#"{
+                "loadType": "playlist",
+                "data": {
+                    "info": {"name": "Example", "selectedTrack": -1},
+                    "pluginInfo": {},
+                    "tracks": []
+                }
+            }"#;
+
+            assert!(matches!(
+                serde_json::from_str(json).unwrap(),
+                LoadResult::Playlist(Playlist {
+                    info: PlaylistInfo {
+                        selected_track: None,
+                        ..
+                    },
+                    ..
+                })
+            ));
+        }
+
+        #[test]
+        fn v4_load_result_search() {
+            let json = format!(r#"{{"loadType": "search", "data": [{V4_TRACK}]}}"#);
+            let expected = LoadResult::Search(vec![v4_track()]);
+
+            assert_eq!(expected, serde_json::from_str(&json).unwrap());
+        }
+
+        #[test]
+        fn v4_load_result_empty() {
+            let value = LoadResult::Empty {};
+            let json = r#"{"loadType":"empty","data":{}}"#;
+
+            assert_eq!(value, serde_json::from_str(json).unwrap());
+            assert_eq!(json, serde_json::to_string(&value).unwrap());
+        }
+
+        #[test]
+        fn v4_load_result_error() {
+            let json = r#"{
+                "loadType": "error",
+                "data": {
+                    "message": "The uploader has not made this video available in your country.",
+                    "severity": "COMMON",
+                    "cause": "com.sedmelluq.discord.lavaplayer.tools.FriendlyException"
+                }
+            }"#;
+            let expected = LoadResult::Error(Exception {
+                cause: "com.sedmelluq.discord.lavaplayer.tools.FriendlyException".to_owned(),
+                message: Some(
+                    "The uploader has not made this video available in your country.".to_owned(),
+                ),
+                severity: ExceptionSeverity::Common,
+            });
+
+            assert_eq!(expected, serde_json::from_str(json).unwrap());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        FailingAddress, IpBlock, IpBlockType, LoadType, LoadedTracks, NanoIpDetails,
-        NanoIpRoutePlanner, PlaylistInfo, RotatingIpDetails, RotatingIpRoutePlanner,
-        RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner, RoutePlannerType, Track,
-        TrackInfo,
+        BalancingIpDetails, BalancingIpRoutePlanner, FailingAddress, IpBlock, IpBlockType,
+        LoadType, LoadedTracks, NanoIpDetails, NanoIpRoutePlanner, PlaylistInfo, RotatingIpDetails,
+        RotatingIpRoutePlanner, RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner,
+        RoutePlannerType, Track, TrackInfo,
+    };
+    use http::{
+        header::{AUTHORIZATION, CONTENT_TYPE},
+        Method,
     };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        net::{Ipv4Addr, SocketAddr},
+    };
+
+    assert_fields!(BalancingIpDetails: failing_addresses, ip_block);
+    assert_impl_all!(
+        BalancingIpDetails: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(BalancingIpRoutePlanner: class, details);
+    assert_impl_all!(
+        BalancingIpRoutePlanner: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
 
     assert_fields!(FailingAddress: address, failing_timestamp, failing_time);
     assert_impl_all!(
@@ -524,6 +997,113 @@ mod tests {
         Sync
     );
 
+    #[test]
+    fn route_planner() {
+        let json = r#"{
+            "class": "RotatingNanoIpRoutePlanner",
+            "details": {
+                "ipBlock": {
+                    "type": "Inet6Address",
+                    "size": "1208925819614629174706176"
+                },
+                "failingAddresses": [
+                    {
+                        "address": "/1.0.0.0",
+                        "failingTimestamp": 1573520707545,
+                        "failingTime": "Mon Nov 11 20:05:07 EST 2019"
+                    }
+                ],
+                "blockIndex": "0",
+                "currentAddressIndex": 36792023813
+            }
+        }"#;
+        let expected = RoutePlanner::RotatingNanoIp(RotatingNanoIpRoutePlanner {
+            class: RoutePlannerType::RotatingNanoIp,
+            details: RotatingNanoIpDetails {
+                block_index: "0".to_owned(),
+                current_address_index: 36_792_023_813,
+                failing_addresses: vec![FailingAddress {
+                    address: "/1.0.0.0".to_owned(),
+                    failing_timestamp: 1_573_520_707_545,
+                    failing_time: "Mon Nov 11 20:05:07 EST 2019".to_owned(),
+                }],
+                ip_block: IpBlock {
+                    kind: IpBlockType::Inet6,
+                    size: "1208925819614629174706176".to_owned(),
+                },
+            },
+        });
+        assert_eq!(expected, serde_json::from_str(json).unwrap());
+
+        let json = r#"{
+            "class": "BalancingIpRoutePlanner",
+            "details": {
+                "ipBlock": {
+                    "type": "Inet4Address",
+                    "size": "256"
+                },
+                "failingAddresses": []
+            }
+        }"#;
+        assert!(matches!(
+            serde_json::from_str(json).unwrap(),
+            RoutePlanner::BalancingIp(_)
+        ));
+    }
+
+    #[test]
+    fn route_planner_requests() {
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, 2333));
+
+        let req = super::get_route_planner(address, "auth").unwrap();
+        assert_eq!(&Method::GET, req.method());
+        assert_eq!("http://127.0.0.1:2333/routeplanner/status", req.uri());
+        assert_eq!("auth", req.headers()[AUTHORIZATION]);
+
+        let req = super::unmark_failed_address(address, "auth", Ipv4Addr::new(1, 0, 0, 0)).unwrap();
+        assert_eq!(&Method::POST, req.method());
+        assert_eq!("http://127.0.0.1:2333/routeplanner/free/address", req.uri());
+        assert_eq!(br#"{"address":"1.0.0.0"}"#, req.body().as_slice());
+
+        let req = super::unmark_all_failed_addresses(address, "auth").unwrap();
+        assert_eq!(&Method::POST, req.method());
+        assert_eq!("http://127.0.0.1:2333/routeplanner/free/all", req.uri());
+    }
+
+    #[test]
+    fn plugin_request() {
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, 2333));
+
+        let req = super::plugin_request(
+            address,
+            "auth",
+            Method::GET,
+            "/v4/loadsearch?query=spsearch%3Aname",
+            None,
+        )
+        .unwrap();
+        assert_eq!(&Method::GET, req.method());
+        assert_eq!(
+            "http://127.0.0.1:2333/v4/loadsearch?query=spsearch%3Aname",
+            req.uri()
+        );
+        assert_eq!("auth", req.headers()[AUTHORIZATION]);
+        assert!(!req.headers().contains_key(CONTENT_TYPE));
+
+        let body = br#"{"segments":["sponsor"]}"#.to_vec();
+        let req = super::plugin_request(
+            address,
+            "auth",
+            Method::PUT,
+            "/v4/sessions/1/players/2/sponsorblock/categories",
+            Some(body.clone()),
+        )
+        .unwrap();
+        assert_eq!(&Method::PUT, req.method());
+        assert_eq!("application/json", req.headers()[CONTENT_TYPE]);
+        assert_eq!(body, *req.body());
+    }
+
     #[test]
     pub fn test_deserialize_playlist_info_negative_selected_track() {
         let value = PlaylistInfo {
@@ -14,6 +14,8 @@ pub enum Opcode {
     Equalizer,
     /// Meta information about a track starting or ending.
     Event,
+    /// Set the filters of a player.
+    Filters,
     /// Pause a player.
     Pause,
     /// Play a track.
@@ -35,7 +37,7 @@ pub enum Opcode {
 pub mod outgoing {
     //! Events that clients send to Lavalink.
 
-    use super::Opcode;
+    use super::{plugin::PluginMessage, Opcode};
     use serde::{Deserialize, Serialize};
     use twilight_model::{
         gateway::payload::incoming::VoiceServerUpdate,
@@ -51,6 +53,8 @@ pub mod outgoing {
         Destroy(Destroy),
         /// Equalize a player.
         Equalizer(Equalizer),
+        /// Set the filters of a player.
+        Filters(Box<Filters>),
         /// Pause or unpause a player.
         Pause(Pause),
         /// Play a track.
@@ -63,6 +67,11 @@ pub mod outgoing {
         VoiceUpdate(VoiceUpdate),
         /// Set the volume of a player.
         Volume(Volume),
+        /// A message to a plugin.
+        //
+        // Messages are deserialized in order, and any object deserializes into
+        // a plugin message, so it comes last.
+        Plugin(PluginMessage),
     }
 
     impl From<Destroy> for OutgoingEvent {
@@ -77,6 +86,12 @@ pub mod outgoing {
         }
     }
 
+    impl From<Filters> for OutgoingEvent {
+        fn from(event: Filters) -> OutgoingEvent {
+            Self::Filters(Box::new(event))
+        }
+    }
+
     impl From<Pause> for OutgoingEvent {
         fn from(event: Pause) -> OutgoingEvent {
             Self::Pause(event)
@@ -89,6 +104,12 @@ pub mod outgoing {
         }
     }
 
+    impl From<PluginMessage> for OutgoingEvent {
+        fn from(message: PluginMessage) -> OutgoingEvent {
+            Self::Plugin(message)
+        }
+    }
+
     impl From<Seek> for OutgoingEvent {
         fn from(event: Seek) -> OutgoingEvent {
             Self::Seek(event)
@@ -197,6 +218,300 @@ pub mod outgoing {
         }
     }
 
+    /// Set the filters of a player, replacing any previously set filters.
+    ///
+    /// Filters that are [`None`] are disabled.
+    ///
+    /// # Examples
+    ///
+    /// Create a nightcore effect by speeding up the track and raising its
+    /// pitch:
+    ///
+    /// ```
+    /// use twilight_lavalink::model::{Filters, Timescale};
+    /// use twilight_model::id::Id;
+    ///
+    /// let mut filters = Filters::new(Id::new(1));
+    /// filters.timescale = Some(Timescale::new(1.2, 1.2, 1.0));
+    /// ```
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Filters {
+        /// Mix of the left and right channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub channel_mix: Option<ChannelMix>,
+        /// Distortion of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub distortion: Option<Distortion>,
+        /// The bands to use as part of the equalizer.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub equalizer: Option<Vec<EqualizerBand>>,
+        /// The guild ID of the player.
+        pub guild_id: Id<GuildMarker>,
+        /// Elimination of a band of frequencies, such as vocals.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub karaoke: Option<Karaoke>,
+        /// Suppression of high frequencies.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub low_pass: Option<LowPass>,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// Rotation of the audio around the stereo channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rotation: Option<Rotation>,
+        /// Speed, pitch, and rate of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timescale: Option<Timescale>,
+        /// Oscillation of the volume.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tremolo: Option<Tremolo>,
+        /// Oscillation of the pitch.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub vibrato: Option<Vibrato>,
+        /// The volume of the player from 0 to 5. 1 is the default.
+        ///
+        /// Volumes above 1 may cause clipping.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub volume: Option<f64>,
+    }
+
+    impl Filters {
+        /// Create a new filters event without any filters.
+        ///
+        /// Sending it disables all filters of the player.
+        pub const fn new(guild_id: Id<GuildMarker>) -> Self {
+            Self {
+                channel_mix: None,
+                distortion: None,
+                equalizer: None,
+                guild_id,
+                karaoke: None,
+                low_pass: None,
+                op: Opcode::Filters,
+                rotation: None,
+                timescale: None,
+                tremolo: None,
+                vibrato: None,
+                volume: None,
+            }
+        }
+    }
+
+    impl From<Id<GuildMarker>> for Filters {
+        fn from(guild_id: Id<GuildMarker>) -> Self {
+            Self::new(guild_id)
+        }
+    }
+
+    /// Mix of the left and right channels of the filters event.
+    ///
+    /// Each factor is from 0 to 1. Setting all factors to 0.5 makes both
+    /// channels play the same audio.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct ChannelMix {
+        /// The factor of the left channel mixed into the left channel.
+        pub left_to_left: f64,
+        /// The factor of the left channel mixed into the right channel.
+        pub left_to_right: f64,
+        /// The factor of the right channel mixed into the left channel.
+        pub right_to_left: f64,
+        /// The factor of the right channel mixed into the right channel.
+        pub right_to_right: f64,
+    }
+
+    impl ChannelMix {
+        /// Create a new channel mix filter.
+        pub const fn new(
+            left_to_left: f64,
+            left_to_right: f64,
+            right_to_left: f64,
+            right_to_right: f64,
+        ) -> Self {
+            Self {
+                left_to_left,
+                left_to_right,
+                right_to_left,
+                right_to_right,
+            }
+        }
+    }
+
+    /// Distortion filter of the filters event.
+    ///
+    /// Each sample is transformed using the sine, cosine, and tangent
+    /// functions, each with an offset and a scale, followed by an overall
+    /// offset and scale.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Distortion {
+        /// The offset of the cosine function.
+        pub cos_offset: f64,
+        /// The scale of the cosine function.
+        pub cos_scale: f64,
+        /// The overall offset.
+        pub offset: f64,
+        /// The overall scale.
+        pub scale: f64,
+        /// The offset of the sine function.
+        pub sin_offset: f64,
+        /// The scale of the sine function.
+        pub sin_scale: f64,
+        /// The offset of the tangent function.
+        pub tan_offset: f64,
+        /// The scale of the tangent function.
+        pub tan_scale: f64,
+    }
+
+    impl Distortion {
+        /// Create a new distortion filter without any distortion.
+        ///
+        /// All offsets are 0 and all scales are 1; set only the fields that
+        /// should distort the audio.
+        pub const fn new() -> Self {
+            Self {
+                cos_offset: 0.0,
+                cos_scale: 1.0,
+                offset: 0.0,
+                scale: 1.0,
+                sin_offset: 0.0,
+                sin_scale: 1.0,
+                tan_offset: 0.0,
+                tan_scale: 1.0,
+            }
+        }
+    }
+
+    impl Default for Distortion {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Karaoke filter of the filters event, eliminating a band of frequencies.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Karaoke {
+        /// The center frequency of the band to eliminate, in hertz.
+        pub filter_band: f64,
+        /// The width of the band to eliminate.
+        pub filter_width: f64,
+        /// The level of the effect, from 0 to 1.
+        pub level: f64,
+        /// The level of the effect on mono audio, from 0 to 1.
+        pub mono_level: f64,
+    }
+
+    impl Karaoke {
+        /// Create a new karaoke filter.
+        pub const fn new(level: f64, mono_level: f64, filter_band: f64, filter_width: f64) -> Self {
+            Self {
+                filter_band,
+                filter_width,
+                level,
+                mono_level,
+            }
+        }
+    }
+
+    /// Low pass filter of the filters event, suppressing high frequencies.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct LowPass {
+        /// The smoothing factor. Values of 1 or less disable the filter.
+        pub smoothing: f64,
+    }
+
+    impl LowPass {
+        /// Create a new low pass filter.
+        pub const fn new(smoothing: f64) -> Self {
+            Self { smoothing }
+        }
+    }
+
+    /// Rotation filter of the filters event, panning the audio around the
+    /// stereo channels.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Rotation {
+        /// The frequency of the rotation in hertz.
+        ///
+        /// For example, 0.2 is similar to the 8D audio effect.
+        pub rotation_hz: f64,
+    }
+
+    impl Rotation {
+        /// Create a new rotation filter.
+        pub const fn new(rotation_hz: f64) -> Self {
+            Self { rotation_hz }
+        }
+    }
+
+    /// Timescale filter of the filters event.
+    ///
+    /// Each factor is at least 0. 1 is the default.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Timescale {
+        /// The pitch factor.
+        pub pitch: f64,
+        /// The rate factor, changing both the speed and the pitch.
+        pub rate: f64,
+        /// The speed factor.
+        pub speed: f64,
+    }
+
+    impl Timescale {
+        /// Create a new timescale filter.
+        pub const fn new(speed: f64, pitch: f64, rate: f64) -> Self {
+            Self { pitch, rate, speed }
+        }
+    }
+
+    /// Tremolo filter of the filters event, oscillating the volume.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Tremolo {
+        /// The depth of the oscillation, greater than 0 and at most 1.
+        pub depth: f64,
+        /// The frequency of the oscillation in hertz, greater than 0.
+        pub frequency: f64,
+    }
+
+    impl Tremolo {
+        /// Create a new tremolo filter.
+        pub const fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
+        }
+    }
+
+    /// Vibrato filter of the filters event, oscillating the pitch.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Vibrato {
+        /// The depth of the oscillation, greater than 0 and at most 1.
+        pub depth: f64,
+        /// The frequency of the oscillation in hertz, greater than 0 and at
+        /// most 14.
+        pub frequency: f64,
+    }
+
+    impl Vibrato {
+        /// Create a new vibrato filter.
+        pub const fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
+        }
+    }
+
     /// Pause or unpause a player.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -440,12 +755,19 @@ pub mod outgoing {
 pub mod incoming {
     //! Events that Lavalink sends to clients.
 
-    use super::Opcode;
-    use serde::{Deserialize, Serialize};
+    use super::{plugin::PluginEvent, Opcode};
+    use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize};
+    use serde_json::{Map, Value};
     use twilight_model::id::{marker::GuildMarker, Id};
 
     /// An incoming event from a Lavalink node.
-    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    ///
+    /// Events are deserialized by their opcode and type. Events of an unknown
+    /// opcode or type are [`Plugin`] events, while events of a known opcode
+    /// and type that fail to deserialize are errors.
+    ///
+    /// [`Plugin`]: Self::Plugin
+    #[derive(Clone, Debug, PartialEq, Serialize)]
     #[non_exhaustive]
     #[serde(untagged)]
     pub enum IncomingEvent {
@@ -455,10 +777,56 @@ pub mod incoming {
         Stats(Stats),
         /// A track ended.
         TrackEnd(TrackEnd),
+        /// A track threw an exception.
+        TrackException(TrackException),
+        /// A track got stuck.
+        TrackStuck(TrackStuck),
         /// A track started.
         TrackStart(TrackStart),
         /// The voice websocket connection was closed.
         WeboscketClosed(WebsocketClosed),
+        /// An event that isn't known to this crate, such as an event of a
+        /// plugin.
+        Plugin(PluginEvent),
+    }
+
+    impl<'de> Deserialize<'de> for IncomingEvent {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            /// Deserialize an event of a known opcode and type.
+            type Known = fn(Value) -> Result<IncomingEvent, serde_json::Error>;
+
+            let payload = Map::<String, Value>::deserialize(deserializer)?;
+            let op = payload.get("op").and_then(Value::as_str);
+            let kind = payload.get("type").and_then(Value::as_str);
+
+            let known: Option<Known> = match (op, kind) {
+                (Some("playerUpdate"), _) => {
+                    Some(|value| PlayerUpdate::deserialize(value).map(Self::PlayerUpdate))
+                }
+                (Some("stats"), _) => Some(|value| Stats::deserialize(value).map(Self::Stats)),
+                (Some("event"), Some("TrackEndEvent")) => {
+                    Some(|value| TrackEnd::deserialize(value).map(Self::TrackEnd))
+                }
+                (Some("event"), Some("TrackExceptionEvent")) => {
+                    Some(|value| TrackException::deserialize(value).map(Self::TrackException))
+                }
+                (Some("event"), Some("TrackStartEvent")) => {
+                    Some(|value| TrackStart::deserialize(value).map(Self::TrackStart))
+                }
+                (Some("event"), Some("TrackStuckEvent")) => {
+                    Some(|value| TrackStuck::deserialize(value).map(Self::TrackStuck))
+                }
+                (Some("event"), Some("WebSocketClosedEvent")) => {
+                    Some(|value| WebsocketClosed::deserialize(value).map(Self::WeboscketClosed))
+                }
+                _ => None,
+            };
+
+            match known {
+                Some(known) => known(Value::Object(payload)).map_err(DeError::custom),
+                None => Ok(Self::Plugin(PluginEvent::new(payload))),
+            }
+        }
     }
 
     impl From<PlayerUpdate> for IncomingEvent {
@@ -467,12 +835,75 @@ pub mod incoming {
         }
     }
 
+    impl From<PluginEvent> for IncomingEvent {
+        fn from(event: PluginEvent) -> IncomingEvent {
+            Self::Plugin(event)
+        }
+    }
+
     impl From<Stats> for IncomingEvent {
         fn from(event: Stats) -> IncomingEvent {
             Self::Stats(event)
         }
     }
 
+    impl From<TrackEnd> for IncomingEvent {
+        fn from(event: TrackEnd) -> IncomingEvent {
+            Self::TrackEnd(event)
+        }
+    }
+
+    impl From<TrackException> for IncomingEvent {
+        fn from(event: TrackException) -> IncomingEvent {
+            Self::TrackException(event)
+        }
+    }
+
+    impl From<TrackStart> for IncomingEvent {
+        fn from(event: TrackStart) -> IncomingEvent {
+            Self::TrackStart(event)
+        }
+    }
+
+    impl From<TrackStuck> for IncomingEvent {
+        fn from(event: TrackStuck) -> IncomingEvent {
+            Self::TrackStuck(event)
+        }
+    }
+
+    impl From<WebsocketClosed> for IncomingEvent {
+        fn from(event: WebsocketClosed) -> IncomingEvent {
+            Self::WeboscketClosed(event)
+        }
+    }
+
+    /// Exception thrown while playing a track.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Exception {
+        /// The cause of the exception.
+        pub cause: String,
+        /// The message of the exception, if any.
+        pub message: Option<String>,
+        /// The severity of the exception.
+        pub severity: ExceptionSeverity,
+    }
+
+    /// Severity of an [`Exception`].
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum ExceptionSeverity {
+        /// The cause is known and expected, such as a track being unavailable.
+        Common,
+        /// The cause is unknown, such as a bug in Lavalink.
+        Fault,
+        /// The cause might not be exactly known, but is possibly caused by
+        /// outside factors, such as the source of the track.
+        Suspicious,
+    }
+
     /// An update about the information of a player.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -570,9 +1001,15 @@ pub mod incoming {
         /// A track for a player ended.
         #[serde(rename = "TrackEndEvent")]
         End,
+        /// A track for a player threw an exception.
+        #[serde(rename = "TrackExceptionEvent")]
+        Exception,
         /// A track for a player started.
         #[serde(rename = "TrackStartEvent")]
         Start,
+        /// A track for a player got stuck.
+        #[serde(rename = "TrackStuckEvent")]
+        Stuck,
         /// The voice websocket connection to Discord has been closed.
         #[serde(rename = "WebSocketClosedEvent")]
         WebsocketClosed,
@@ -598,6 +1035,24 @@ pub mod incoming {
         pub track: String,
     }
 
+    /// A track threw an exception.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct TrackException {
+        /// The exception that was thrown.
+        pub exception: Exception,
+        /// The guild ID of the player.
+        pub guild_id: Id<GuildMarker>,
+        /// The type of track event.
+        #[serde(rename = "type")]
+        pub kind: TrackEventType,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// The base64 track that was affected.
+        pub track: String,
+    }
+
     /// A track started.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -614,6 +1069,25 @@ pub mod incoming {
         pub track: String,
     }
 
+    /// A track got stuck, not providing any audio.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct TrackStuck {
+        /// The guild ID of the player.
+        pub guild_id: Id<GuildMarker>,
+        /// The type of track event.
+        #[serde(rename = "type")]
+        pub kind: TrackEventType,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// The threshold in milliseconds without audio after which the track
+        /// is considered stuck.
+        pub threshold_ms: u64,
+        /// The base64 track that was affected.
+        pub track: String,
+    }
+
     /// The voice websocket connection to Discord has been closed.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -635,28 +1109,335 @@ pub mod incoming {
     }
 }
 
+pub mod plugin {
+    //! Events and messages of Lavalink plugins.
+    //!
+    //! Plugins extend Lavalink with events and messages of their own, such as
+    //! the segment events of the `SponsorBlock` plugin. Incoming events that
+    //! aren't known to this crate are received as [`PluginEvent`]s preserving
+    //! their JSON, which [`PluginDecoders`] decode into typed events.
+    //!
+    //! # Examples
+    //!
+    //! Decode the events of the `SponsorBlock` plugin about skipped segments:
+    //!
+    //! ```
+    //! use serde::Deserialize;
+    //! use twilight_lavalink::model::plugin::{PluginDecoders, PluginEvent};
+    //!
+    //! #[derive(Deserialize)]
+    //! struct SegmentSkipped {
+    //!     segment: Segment,
+    //! }
+    //!
+    //! #[derive(Deserialize)]
+    //! struct Segment {
+    //!     category: String,
+    //! }
+    //!
+    //! let decoders =
+    //!     PluginDecoders::new().event::<SegmentSkipped>("sponsorblock", "SegmentSkipped");
+    //!
+    //! let mut event: PluginEvent = serde_json::from_str(
+    //!     r#"{
+    //!         "op": "event",
+    //!         "type": "SegmentSkipped",
+    //!         "guildId": "1",
+    //!         "segment": {"category": "sponsor", "start": 0, "end": 3000}
+    //!     }"#,
+    //! )?;
+    //! decoders.decode(&mut event);
+    //!
+    //! assert_eq!(Some("sponsorblock"), event.plugin());
+    //! let skipped = event.decoded::<SegmentSkipped>().expect("decoded");
+    //! assert_eq!("sponsor", skipped.segment.category);
+    //! # Ok::<(), serde_json::Error>(())
+    //! ```
+
+    use serde::{de::DeserializeOwned, ser::Error as _, Deserialize, Serialize};
+    use serde_json::{Map, Value};
+    use std::{
+        any::Any,
+        fmt::{Debug, Formatter, Result as FmtResult},
+        sync::Arc,
+    };
+    use twilight_model::id::{marker::GuildMarker, Id};
+
+    /// Decoder of plugin events into a typed event.
+    type Decoder = Arc<dyn Fn(&PluginEvent) -> Option<Arc<dyn Any + Send + Sync>> + Send + Sync>;
+
+    /// Decoders of plugin events by plugin name.
+    ///
+    /// Decoders are tried in the order they were registered, and the first
+    /// decoder that decodes an event determines the event's [plugin] and
+    /// [decoded] event.
+    ///
+    /// Set for new nodes via [`Lavalink::plugin_decoders`] or
+    /// [`NodeConfig::plugins`].
+    ///
+    /// [`Lavalink::plugin_decoders`]: crate::client::Lavalink::plugin_decoders
+    /// [`NodeConfig::plugins`]: crate::node::NodeConfig::plugins
+    /// [decoded]: PluginEvent::decoded
+    /// [plugin]: PluginEvent::plugin
+    #[derive(Clone, Default)]
+    pub struct PluginDecoders {
+        /// Decoders and the names of their plugins.
+        decoders: Vec<(String, Decoder)>,
+    }
+
+    impl PluginDecoders {
+        /// Create a registry without any decoders.
+        pub const fn new() -> Self {
+            Self {
+                decoders: Vec::new(),
+            }
+        }
+
+        /// Register a decoder of events of a plugin.
+        ///
+        /// The decoder returns `None` for events that aren't of the plugin.
+        #[must_use = "has no effect if not used"]
+        pub fn register<T, F>(mut self, plugin: impl Into<String>, decode: F) -> Self
+        where
+            T: Any + Send + Sync,
+            F: Fn(&PluginEvent) -> Option<T> + Send + Sync + 'static,
+        {
+            self.decoders.push((
+                plugin.into(),
+                Arc::new(move |event| {
+                    decode(event).map(|decoded| Arc::new(decoded) as Arc<dyn Any + Send + Sync>)
+                }),
+            ));
+
+            self
+        }
+
+        /// Register a decoder deserializing events of a plugin with a type.
+        ///
+        /// The type of events is their `type` field, such as `SegmentSkipped`
+        /// for events of the `SponsorBlock` plugin. Events of the type that fail
+        /// to deserialize aren't decoded.
+        #[must_use = "has no effect if not used"]
+        pub fn event<T>(self, plugin: impl Into<String>, kind: impl Into<String>) -> Self
+        where
+            T: Any + DeserializeOwned + Send + Sync,
+        {
+            let kind = kind.into();
+
+            self.register(plugin, move |event| {
+                if event.kind() != Some(kind.as_str()) {
+                    return None;
+                }
+
+                event.deserialize::<T>().ok()
+            })
+        }
+
+        /// Decode an event with the first decoder that decodes it.
+        ///
+        /// Nodes decode the plugin events they receive, so this usually
+        /// doesn't need to be called.
+        pub fn decode(&self, event: &mut PluginEvent) {
+            for (plugin, decoder) in &self.decoders {
+                if let Some(data) = decoder(event) {
+                    event.decoded = Some(Decoded {
+                        data,
+                        plugin: plugin.clone(),
+                    });
+
+                    return;
+                }
+            }
+        }
+    }
+
+    impl Debug for PluginDecoders {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.debug_struct("PluginDecoders")
+                .field(
+                    "plugins",
+                    &self
+                        .decoders
+                        .iter()
+                        .map(|(plugin, _)| plugin)
+                        .collect::<Vec<_>>(),
+                )
+                .finish()
+        }
+    }
+
+    impl Eq for PluginDecoders {}
+
+    /// Decoders are equal if they're the same decoders of the same plugins.
+    impl PartialEq for PluginDecoders {
+        fn eq(&self, other: &Self) -> bool {
+            self.decoders.len() == other.decoders.len()
+                && self.decoders.iter().zip(&other.decoders).all(
+                    |((plugin, decoder), (other_plugin, other_decoder))| {
+                        plugin == other_plugin && Arc::ptr_eq(decoder, other_decoder)
+                    },
+                )
+        }
+    }
+
+    /// Typed event decoded from a plugin event.
+    #[derive(Clone)]
+    struct Decoded {
+        /// Typed event.
+        data: Arc<dyn Any + Send + Sync>,
+        /// Name of the plugin whose decoder decoded the event.
+        plugin: String,
+    }
+
+    /// An incoming event that isn't known to this crate, such as an event of
+    /// a plugin.
+    ///
+    /// The JSON of the event is preserved, and may be decoded into a typed
+    /// event by [`PluginDecoders`].
+    #[derive(Clone, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct PluginEvent {
+        /// Typed event, if the event was decoded.
+        #[serde(skip)]
+        decoded: Option<Decoded>,
+        /// JSON object of the event.
+        payload: Map<String, Value>,
+    }
+
+    impl PluginEvent {
+        /// Create an event from its JSON object.
+        pub const fn new(payload: Map<String, Value>) -> Self {
+            Self {
+                decoded: None,
+                payload,
+            }
+        }
+
+        /// Typed event decoded by the decoder of the event's plugin, if it is
+        /// of the type.
+        pub fn decoded<T: Any>(&self) -> Option<&T> {
+            self.decoded.as_ref()?.data.downcast_ref()
+        }
+
+        /// Deserialize the JSON of the event.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the event fails to deserialize into the type.
+        pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+            T::deserialize(&self.payload)
+        }
+
+        /// ID of the guild of the player the event is for, if any.
+        pub fn guild_id(&self) -> Option<Id<GuildMarker>> {
+            self.payload.get("guildId")?.as_str()?.parse().ok()
+        }
+
+        /// Type of the event, if any.
+        ///
+        /// This is the `type` field of events whose opcode is `event`.
+        pub fn kind(&self) -> Option<&str> {
+            self.payload.get("type")?.as_str()
+        }
+
+        /// Opcode of the event, if any.
+        pub fn op(&self) -> Option<&str> {
+            self.payload.get("op")?.as_str()
+        }
+
+        /// JSON object of the event.
+        pub const fn payload(&self) -> &Map<String, Value> {
+            &self.payload
+        }
+
+        /// Name of the plugin whose decoder decoded the event, if it was
+        /// decoded.
+        pub fn plugin(&self) -> Option<&str> {
+            Some(&self.decoded.as_ref()?.plugin)
+        }
+    }
+
+    impl Debug for PluginEvent {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.debug_struct("PluginEvent")
+                .field("payload", &self.payload)
+                .field("plugin", &self.plugin())
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl PartialEq for PluginEvent {
+        fn eq(&self, other: &Self) -> bool {
+            self.payload == other.payload && self.plugin() == other.plugin()
+        }
+    }
+
+    /// An outgoing message to a plugin.
+    ///
+    /// The message is sent as its JSON object.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(transparent)]
+    pub struct PluginMessage {
+        /// JSON object of the message.
+        payload: Map<String, Value>,
+    }
+
+    impl PluginMessage {
+        /// Create a message from its JSON object.
+        pub const fn new(payload: Map<String, Value>) -> Self {
+            Self { payload }
+        }
+
+        /// Create a message by serializing a value into a JSON object.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the value fails to serialize or doesn't
+        /// serialize into an object.
+        pub fn from_serialize<T: Serialize>(message: &T) -> Result<Self, serde_json::Error> {
+            match serde_json::to_value(message)? {
+                Value::Object(payload) => Ok(Self::new(payload)),
+                _ => Err(serde_json::Error::custom(
+                    "plugin messages must be JSON objects",
+                )),
+            }
+        }
+
+        /// JSON object of the message.
+        pub const fn payload(&self) -> &Map<String, Value> {
+            &self.payload
+        }
+    }
+}
+
 pub use self::{
     incoming::{
-        IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames, StatsMemory,
-        TrackEnd, TrackEventType, TrackStart, WebsocketClosed,
+        Exception, ExceptionSeverity, IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats,
+        StatsCpu, StatsFrames, StatsMemory, TrackEnd, TrackEventType, TrackException, TrackStart,
+        TrackStuck, WebsocketClosed,
     },
     outgoing::{
-        Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, Stop, VoiceUpdate,
+        ChannelMix, Destroy, Distortion, Equalizer, EqualizerBand, Filters, Karaoke, LowPass,
+        OutgoingEvent, Pause, Play, Rotation, Seek, Stop, Timescale, Tremolo, Vibrato, VoiceUpdate,
         Volume,
     },
+    plugin::{PluginDecoders, PluginEvent, PluginMessage},
 };
 
 #[cfg(test)]
 mod tests {
     use super::{
         incoming::{
-            IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames,
-            StatsMemory, TrackEnd, TrackEventType, TrackStart, WebsocketClosed,
+            Exception, ExceptionSeverity, IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats,
+            StatsCpu, StatsFrames, StatsMemory, TrackEnd, TrackEventType, TrackException,
+            TrackStart, TrackStuck, WebsocketClosed,
         },
         outgoing::{
-            Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, Stop, VoiceUpdate,
-            Volume,
+            ChannelMix, Destroy, Distortion, Equalizer, EqualizerBand, Filters, Karaoke, LowPass,
+            OutgoingEvent, Pause, Play, Rotation, Seek, Stop, Timescale, Tremolo, Vibrato,
+            VoiceUpdate, Volume,
         },
+        plugin::{PluginDecoders, PluginMessage},
         Opcode,
     };
     use serde::{Deserialize, Serialize};
@@ -668,6 +1449,21 @@ mod tests {
         id::{marker::GuildMarker, Id},
     };
 
+    assert_fields!(
+        ChannelMix: left_to_left,
+        left_to_right,
+        right_to_left,
+        right_to_right
+    );
+    assert_impl_all!(
+        ChannelMix: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(Destroy: guild_id, op);
     assert_impl_all!(
         Destroy: Clone,
@@ -691,6 +1487,26 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(
+        Distortion: cos_offset,
+        cos_scale,
+        offset,
+        scale,
+        sin_offset,
+        sin_scale,
+        tan_offset,
+        tan_scale
+    );
+    assert_impl_all!(
+        Distortion: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(Equalizer: bands, guild_id, op);
     assert_impl_all!(
         Equalizer: Clone,
@@ -702,12 +1518,63 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(
+        Filters: channel_mix,
+        distortion,
+        equalizer,
+        guild_id,
+        karaoke,
+        low_pass,
+        op,
+        rotation,
+        timescale,
+        tremolo,
+        vibrato,
+        volume
+    );
+    assert_impl_all!(
+        Filters: Clone,
+        Debug,
+        Deserialize<'static>,
+        From<Id<GuildMarker>>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Exception: cause, message, severity);
+    assert_impl_all!(
+        Exception: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        ExceptionSeverity: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         IncomingEvent: Clone,
         Debug,
         Deserialize<'static>,
         From<PlayerUpdate>,
         From<Stats>,
+        From<TrackEnd>,
+        From<TrackException>,
+        From<TrackStart>,
+        From<TrackStuck>,
+        From<WebsocketClosed>,
         PartialEq,
         Send,
         Serialize,
@@ -719,6 +1586,7 @@ mod tests {
         Deserialize<'static>,
         From<Destroy>,
         From<Equalizer>,
+        From<Filters>,
         From<Pause>,
         From<Play>,
         From<Seek>,
@@ -730,6 +1598,26 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Karaoke: filter_band, filter_width, level, mono_level);
+    assert_impl_all!(
+        Karaoke: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(LowPass: smoothing);
+    assert_impl_all!(
+        LowPass: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(Pause: guild_id, op, pause);
     assert_impl_all!(
         Pause: Clone,
@@ -786,6 +1674,16 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Rotation: rotation_hz);
+    assert_impl_all!(
+        Rotation: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(Seek: guild_id, op, position);
     assert_impl_all!(
         Seek: Clone,
@@ -858,6 +1756,16 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Timescale: pitch, rate, speed);
+    assert_impl_all!(
+        Timescale: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(TrackEnd: guild_id, kind, op, reason, track);
     assert_impl_all!(
         TrackEnd: Clone,
@@ -878,6 +1786,16 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(TrackException: exception, guild_id, kind, op, track);
+    assert_impl_all!(
+        TrackException: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(TrackStart: guild_id, kind, op, track);
     assert_impl_all!(
         TrackStart: Clone,
@@ -888,6 +1806,36 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(TrackStuck: guild_id, kind, op, threshold_ms, track);
+    assert_impl_all!(
+        TrackStuck: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Tremolo: depth, frequency);
+    assert_impl_all!(
+        Tremolo: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Vibrato: depth, frequency);
+    assert_impl_all!(
+        Vibrato: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(WebsocketClosed: guild_id, kind, op, code, reason, by_remote);
     assert_impl_all!(
         WebsocketClosed: Clone,
@@ -922,6 +1870,216 @@ mod tests {
         Sync,
     );
 
+    #[test]
+    fn filters() {
+        let mut filters = Filters::new(Id::new(1));
+        filters.equalizer = Some(vec![EqualizerBand::new(0, 0.25)]);
+        filters.low_pass = Some(LowPass::new(20.0));
+        filters.timescale = Some(Timescale::new(1.2, 1.2, 1.0));
+        filters.volume = Some(0.5);
+
+        serde_test::assert_tokens(
+            &filters,
+            &[
+                Token::Struct {
+                    name: "Filters",
+                    len: 6,
+                },
+                Token::Str("equalizer"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "EqualizerBand",
+                    len: 2,
+                },
+                Token::Str("band"),
+                Token::I64(0),
+                Token::Str("gain"),
+                Token::F64(0.25),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("guildId"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("lowPass"),
+                Token::Some,
+                Token::Struct {
+                    name: "LowPass",
+                    len: 1,
+                },
+                Token::Str("smoothing"),
+                Token::F64(20.0),
+                Token::StructEnd,
+                Token::Str("op"),
+                Token::UnitVariant {
+                    name: "Opcode",
+                    variant: "filters",
+                },
+                Token::Str("timescale"),
+                Token::Some,
+                Token::Struct {
+                    name: "Timescale",
+                    len: 3,
+                },
+                Token::Str("pitch"),
+                Token::F64(1.2),
+                Token::Str("rate"),
+                Token::F64(1.0),
+                Token::Str("speed"),
+                Token::F64(1.2),
+                Token::StructEnd,
+                Token::Str("volume"),
+                Token::Some,
+                Token::F64(0.5),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn track_events() {
+        let json = r#"{
+            "op": "event",
+            "type": "TrackStuckEvent",
+            "guildId": "1",
+            "track": "QAAAjQIAJVJpY2sgQXN0bGV5",
+            "thresholdMs": 10000
+        }"#;
+        let expected = IncomingEvent::TrackStuck(TrackStuck {
+            guild_id: Id::new(1),
+            kind: TrackEventType::Stuck,
+            op: Opcode::Event,
+            threshold_ms: 10_000,
+            track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+        });
+        assert_eq!(expected, serde_json::from_str(json).unwrap());
+
+        let json = r#"{
+            "op": "event",
+            "type": "TrackExceptionEvent",
+            "guildId": "1",
+            "track": "QAAAjQIAJVJpY2sgQXN0bGV5",
+            "exception": {
+                "message": "This video is unavailable",
+                "severity": "COMMON",
+                "cause": "com.sedmelluq.discord.lavaplayer.tools.FriendlyException"
+            }
+        }"#;
+        let expected = IncomingEvent::TrackException(TrackException {
+            exception: Exception {
+                cause: "com.sedmelluq.discord.lavaplayer.tools.FriendlyException".to_owned(),
+                message: Some("This video is unavailable".to_owned()),
+                severity: ExceptionSeverity::Common,
+            },
+            guild_id: Id::new(1),
+            kind: TrackEventType::Exception,
+            op: Opcode::Event,
+            track: "QAAAjQIAJVJpY2sgQXN0bGV5".to_owned(),
+        });
+        assert_eq!(expected, serde_json::from_str(json).unwrap());
+
+        let json = r#"{
+            "op": "event",
+            "type": "TrackStartEvent",
+            "guildId": "1",
+            "track": "QAAAjQIAJVJpY2sgQXN0bGV5"
+        }"#;
+        assert!(matches!(
+            serde_json::from_str(json).unwrap(),
+            IncomingEvent::TrackStart(_)
+        ));
+    }
+
+    #[test]
+    fn plugin_events() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct SegmentSkipped {
+            segment: Segment,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Segment {
+            category: String,
+        }
+
+        let json = r#"{
+            "op": "event",
+            "type": "SegmentSkipped",
+            "guildId": "1",
+            "segment": {"category": "sponsor", "start": 0, "end": 3000}
+        }"#;
+        let IncomingEvent::Plugin(mut event) = serde_json::from_str(json).unwrap() else {
+            panic!("expected a plugin event");
+        };
+
+        assert_eq!(Some("event"), event.op());
+        assert_eq!(Some("SegmentSkipped"), event.kind());
+        assert_eq!(Some(Id::new(1)), event.guild_id());
+        assert!(event.plugin().is_none());
+        assert!(event.decoded::<SegmentSkipped>().is_none());
+
+        PluginDecoders::new()
+            .event::<SegmentSkipped>("sponsorblock", "SegmentsLoaded")
+            .decode(&mut event);
+        assert!(event.plugin().is_none());
+
+        PluginDecoders::new()
+            .register("other", |_| None::<()>)
+            .event::<SegmentSkipped>("sponsorblock", "SegmentSkipped")
+            .decode(&mut event);
+        assert_eq!(Some("sponsorblock"), event.plugin());
+        assert_eq!(
+            Some(&SegmentSkipped {
+                segment: Segment {
+                    category: "sponsor".to_owned()
+                }
+            }),
+            event.decoded()
+        );
+        assert!(event.decoded::<Segment>().is_none());
+
+        // Known events are still deserialized into their own variants.
+        let json =
+            r#"{"op": "playerUpdate", "guildId": "1", "state": {"connected": true, "time": 1}}"#;
+        assert!(matches!(
+            serde_json::from_str(json).unwrap(),
+            IncomingEvent::PlayerUpdate(_)
+        ));
+
+        // Malformed known events are errors rather than plugin events.
+        let json = r#"{"op": "playerUpdate", "guildId": "1"}"#;
+        assert!(serde_json::from_str::<IncomingEvent>(json).is_err());
+        let json = r#"{"op": "event", "type": "TrackEndEvent", "guildId": "1"}"#;
+        assert!(serde_json::from_str::<IncomingEvent>(json).is_err());
+
+        // Unknown opcodes are plugin events too.
+        let json = r#"{"op": "sponsorblock", "guildId": "1"}"#;
+        assert!(matches!(
+            serde_json::from_str(json).unwrap(),
+            IncomingEvent::Plugin(event) if event.op() == Some("sponsorblock")
+        ));
+    }
+
+    #[test]
+    fn plugin_messages() {
+        let message = PluginMessage::from_serialize(&serde_json::json!({
+            "op": "lyrics",
+            "guildId": "1",
+        }))
+        .unwrap();
+        let event = OutgoingEvent::from(message.clone());
+
+        assert_eq!(
+            r#"{"guildId":"1","op":"lyrics"}"#,
+            serde_json::to_string(&event).unwrap()
+        );
+        assert_eq!(message, PluginMessage::new(message.payload().clone()));
+        assert!(PluginMessage::from_serialize(&1).is_err());
+
+        let event: OutgoingEvent = serde_json::from_str(r#"{"lyrics": true}"#).unwrap();
+        assert!(matches!(event, OutgoingEvent::Plugin(_)));
+    }
+
     #[test]
     fn stats_frames_not_provided() {
         const LAVALINK_LOAD: f64 = 0.276_119_402_985_074_65;
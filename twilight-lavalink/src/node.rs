@@ -18,8 +18,11 @@
 //! [`Lavalink`]: crate::client::Lavalink
 
 use crate::{
-    model::{IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, Stats, StatsCpu, StatsMemory},
-    player::PlayerManager,
+    model::{
+        IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, PluginDecoders, Stats, StatsCpu,
+        StatsMemory,
+    },
+    player::{PlayerEvent, PlayerManager},
 };
 use futures_util::{
     lock::BiLock,
@@ -32,6 +35,10 @@ use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     net::SocketAddr,
     pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
     time::Duration,
 };
@@ -230,6 +237,8 @@ pub struct NodeConfig {
     pub address: SocketAddr,
     /// The password to use when authenticating.
     pub authorization: String,
+    /// Decoders of the plugin events received from the node.
+    pub plugins: PluginDecoders,
     /// The details for resuming a Lavalink session, if any.
     ///
     /// Set this to `None` to disable resume capability.
@@ -254,6 +263,7 @@ impl Debug for NodeConfig {
         f.debug_struct("NodeConfig")
             .field("address", &self.address)
             .field("authorization", &Redacted)
+            .field("plugins", &self.plugins)
             .field("resume", &self.resume)
             .field("user_id", &self.user_id)
             .finish()
@@ -264,6 +274,9 @@ impl Debug for NodeConfig {
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub struct Resume {
+    /// The key identifying the session to resume, if not the address of the
+    /// node.
+    key: Option<String>,
     /// The number of seconds that the Lavalink server will allow the session to
     /// be resumed for after a disconnect.
     ///
@@ -275,13 +288,39 @@ impl Resume {
     /// Configure resume capability, providing the number of seconds that the
     /// Lavalink server should queue events for when the connection is resumed.
     pub const fn new(seconds: u64) -> Self {
-        Self { timeout: seconds }
+        Self {
+            key: None,
+            timeout: seconds,
+        }
+    }
+
+    /// Like [`new`], but with a custom key identifying the session.
+    ///
+    /// [`new`]: Self::new
+    pub fn with_key(seconds: u64, key: impl Into<String>) -> Self {
+        Self {
+            key: Some(key.into()),
+            timeout: seconds,
+        }
+    }
+
+    /// The custom key identifying the session, if any.
+    ///
+    /// The address of the node is used if this is `None`, which is the
+    /// default.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Key identifying the session to resume for a node.
+    fn session_key(&self, address: SocketAddr) -> String {
+        self.key.clone().unwrap_or_else(|| address.to_string())
     }
 }
 
 impl Default for Resume {
     fn default() -> Self {
-        Self { timeout: 60 }
+        Self::new(60)
     }
 }
 
@@ -311,6 +350,7 @@ impl NodeConfig {
         Self {
             address,
             authorization,
+            plugins: PluginDecoders::new(),
             resume,
             user_id,
         }
@@ -325,6 +365,9 @@ impl NodeConfig {
 /// [module]: crate
 #[derive(Debug)]
 pub struct Node {
+    /// Whether the node is connected, or reconnecting after a dropped
+    /// connection while its players fail over to other nodes.
+    available: Arc<AtomicBool>,
     config: NodeConfig,
     lavalink_tx: UnboundedSender<OutgoingEvent>,
     players: PlayerManager,
@@ -381,8 +424,14 @@ impl Node {
 
         tracing::debug!("starting connection to {}", config.address);
 
-        let (conn_loop, lavalink_tx, lavalink_rx) =
-            Connection::connect(config.clone(), players.clone(), bilock_right).await?;
+        let available = Arc::new(AtomicBool::new(true));
+        let (conn_loop, lavalink_tx, lavalink_rx) = Connection::connect(
+            Arc::clone(&available),
+            config.clone(),
+            players.clone(),
+            bilock_right,
+        )
+        .await?;
 
         tracing::debug!("started connection to {}", config.address);
 
@@ -390,6 +439,7 @@ impl Node {
 
         Ok((
             Self {
+                available,
                 config,
                 lavalink_tx,
                 players,
@@ -404,6 +454,17 @@ impl Node {
         &self.config
     }
 
+    /// Whether the node is connected.
+    ///
+    /// When the [`Lavalink`] client fails over players, a node whose
+    /// connection was lost keeps reconnecting and becomes available again
+    /// once it recovers.
+    ///
+    /// [`Lavalink`]: crate::client::Lavalink
+    pub fn is_available(&self) -> bool {
+        self.available.load(Ordering::Acquire) && !self.lavalink_tx.is_closed()
+    }
+
     /// Retrieve an immutable reference to the player manager used by the node.
     pub const fn players(&self) -> &PlayerManager {
         &self.players
@@ -463,6 +524,7 @@ impl Node {
 }
 
 struct Connection {
+    available: Arc<AtomicBool>,
     config: NodeConfig,
     stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
     node_from: UnboundedReceiver<OutgoingEvent>,
@@ -473,6 +535,7 @@ struct Connection {
 
 impl Connection {
     async fn connect(
+        available: Arc<AtomicBool>,
         config: NodeConfig,
         players: PlayerManager,
         stats: BiLock<Stats>,
@@ -491,6 +554,7 @@ impl Connection {
 
         Ok((
             Self {
+                available,
                 config,
                 stream,
                 node_from: from_node,
@@ -511,7 +575,7 @@ impl Connection {
                         self.incoming(incoming).await?;
                     } else {
                         tracing::debug!("connection to {} closed, reconnecting", self.config.address);
-                        self.stream = reconnect(&self.config).await?;
+                        self.stream = self.reconnect().await?;
                     }
                 }
                 outgoing = self.node_from.recv() => {
@@ -539,6 +603,42 @@ impl Connection {
         Ok(())
     }
 
+    /// Reconnect to the node.
+    ///
+    /// If players fail over to other nodes, the node is marked as unavailable
+    /// and its players failed over once connecting fails after several
+    /// backoff attempts, and connecting is retried until the node recovers.
+    async fn reconnect(&mut self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, NodeError> {
+        loop {
+            match reconnect(&self.config).await {
+                Ok(stream) => {
+                    if !self.available.swap(true, Ordering::AcqRel) {
+                        tracing::debug!("node {} recovered", self.config.address);
+
+                        // Events queued while unavailable were for players
+                        // that have been failed over since.
+                        while self.node_from.try_recv().is_ok() {}
+                    }
+
+                    return Ok(stream);
+                }
+                Err(source)
+                    if matches!(source.kind, NodeErrorType::Connecting)
+                        && self.players.failover.get().is_some() =>
+                {
+                    if self.available.swap(false, Ordering::AcqRel) {
+                        tracing::debug!(
+                            "node {} unavailable, failing over its players",
+                            self.config.address,
+                        );
+                        self.players.disconnected(self.config.address);
+                    }
+                }
+                Err(source) => return Err(source),
+            }
+        }
+    }
+
     async fn incoming(&mut self, incoming: Message) -> Result<bool, NodeError> {
         tracing::debug!(
             "received message from {}: {incoming:?}",
@@ -557,18 +657,25 @@ impl Connection {
             return Ok(true);
         };
 
-        let Ok(event) = serde_json::from_str(text) else {
+        let Ok(mut event) = serde_json::from_str(text) else {
             tracing::warn!("unknown message from lavalink node: {text}");
 
             return Ok(true);
         };
 
-        match &event {
+        match &mut event {
             IncomingEvent::PlayerUpdate(update) => self.player_update(update)?,
+            IncomingEvent::Plugin(event) => self.config.plugins.decode(event),
             IncomingEvent::Stats(stats) => self.stats(stats).await?,
             _ => {}
         }
 
+        if let Some(player_event) = PlayerEvent::from_incoming(&event) {
+            if let Some(player) = self.players.get(&player_event.guild_id()) {
+                player.dispatch(&player_event);
+            }
+        }
+
         // It's fine if the rx end dropped, often users don't need to care about
         // these events.
         if !self.node_to.is_closed() {
@@ -603,10 +710,8 @@ impl Connection {
 
 impl Drop for Connection {
     fn drop(&mut self) {
-        // Cleanup local players associated with the node
-        self.players
-            .players
-            .retain(|_, v| v.node().config().address != self.config.address);
+        // Fail over or clean up local players associated with the node.
+        self.players.disconnected(self.config.address);
     }
 }
 
@@ -623,10 +728,10 @@ fn connect_request(state: &NodeConfig) -> Result<ClientBuilder, NodeError> {
             state.user_id.get().into(),
         );
 
-    if state.resume.is_some() {
+    if let Some(resume) = state.resume.as_ref() {
         builder = builder.add_header(
             HeaderName::from_static("resume-key"),
-            state.address.to_string().parse().unwrap(),
+            resume.session_key(state.address).parse().unwrap(),
         );
     }
 
@@ -649,7 +754,7 @@ async fn reconnect(
 
                 let payload = serde_json::json!({
                     "op": "configureResuming",
-                    "key": config.address,
+                    "key": resume.session_key(config.address),
                     "timeout": resume.timeout,
                 });
                 let msg = Message::text(serde_json::to_string(&payload).unwrap());
@@ -722,6 +827,7 @@ async fn backoff(
 #[cfg(test)]
 mod tests {
     use super::{Node, NodeConfig, NodeError, NodeErrorType, Resume};
+    use crate::model::PluginDecoders;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{
         error::Error,
@@ -730,7 +836,7 @@ mod tests {
     };
     use twilight_model::id::Id;
 
-    assert_fields!(NodeConfig: address, authorization, resume, user_id);
+    assert_fields!(NodeConfig: address, authorization, plugins, resume, user_id);
     assert_impl_all!(NodeConfig: Clone, Debug, Send, Sync);
     assert_fields!(NodeErrorType::SerializingMessage: message);
     assert_fields!(NodeErrorType::Unauthorized: address, authorization);
@@ -745,10 +851,21 @@ mod tests {
         let config = NodeConfig {
             address: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 1312)),
             authorization: "some auth".to_owned(),
+            plugins: PluginDecoders::new(),
             resume: None,
             user_id: Id::new(123),
         };
 
         assert!(format!("{config:?}").contains("authorization: <redacted>"));
     }
+
+    #[test]
+    fn resume_key() {
+        let address = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 1312));
+
+        assert!(Resume::default().key().is_none());
+        assert_eq!("127.0.0.1:1312", Resume::default().session_key(address));
+        assert_eq!(Some("bot"), Resume::with_key(60, "bot").key());
+        assert_eq!("bot", Resume::with_key(60, "bot").session_key(address));
+    }
 }
@@ -5,22 +5,36 @@
 //! use those players to do things like [send events] or [read the position] of
 //! the active audio.
 //!
+//!
+//! Events of a player's tracks can be received with [`Player::events`]
+//! instead of filtering the events of its node.
+//!
 //! [players]: Player
 //! [send events]: Player::send
-//! [read the position]: Player::position
+//! [read the position]: Player::current_position
 
 use crate::{
-    model::{Destroy, OutgoingEvent},
+    model::{
+        Destroy, Filters, IncomingEvent, OutgoingEvent, Pause, Play, Seek, TrackEnd,
+        TrackException, TrackStart, TrackStuck, VoiceUpdate, Volume, WebsocketClosed,
+    },
     node::{Node, NodeSenderError},
 };
 use dashmap::DashMap;
+use futures_util::stream::Stream;
 use std::{
     fmt::Debug,
+    mem,
+    net::SocketAddr,
+    pin::Pin,
     sync::{
         atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex, OnceLock, PoisonError,
     },
+    task::{Context, Poll},
+    time::Instant,
 };
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker},
     Id,
@@ -32,6 +46,9 @@ use twilight_model::id::{
 /// nodes, and can be used to read player information and send events to nodes.
 #[derive(Clone, Debug, Default)]
 pub struct PlayerManager {
+    /// Sender of the addresses of disconnected nodes, whose players are failed
+    /// over to other nodes, if failover is enabled.
+    pub(crate) failover: Arc<OnceLock<UnboundedSender<SocketAddr>>>,
     pub(crate) players: Arc<DashMap<Id<GuildMarker>, Arc<Player>>>,
 }
 
@@ -75,6 +92,118 @@ impl PlayerManager {
 
         Ok(())
     }
+
+    /// Handle the connection to a node having ended.
+    ///
+    /// The players of the node are failed over if failover is enabled,
+    /// otherwise they are removed.
+    pub(crate) fn disconnected(&self, address: SocketAddr) {
+        if let Some(failover) = self.failover.get() {
+            if failover.send(address).is_ok() {
+                return;
+            }
+        }
+
+        self.players
+            .retain(|_, player| player.node().config().address != address);
+    }
+}
+
+/// An event about the tracks or the voice connection of a player.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PlayerEvent {
+    /// A track ended.
+    TrackEnd(TrackEnd),
+    /// A track threw an exception.
+    TrackException(TrackException),
+    /// A track started.
+    TrackStart(TrackStart),
+    /// A track got stuck.
+    TrackStuck(TrackStuck),
+    /// The voice websocket connection was closed.
+    WebsocketClosed(WebsocketClosed),
+}
+
+impl PlayerEvent {
+    /// Guild ID of the player that the event is for.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        match self {
+            Self::TrackEnd(event) => event.guild_id,
+            Self::TrackException(event) => event.guild_id,
+            Self::TrackStart(event) => event.guild_id,
+            Self::TrackStuck(event) => event.guild_id,
+            Self::WebsocketClosed(event) => event.guild_id,
+        }
+    }
+
+    /// Create a player event from an incoming event, if it is for a player.
+    pub(crate) fn from_incoming(event: &IncomingEvent) -> Option<Self> {
+        Some(match event {
+            IncomingEvent::TrackEnd(event) => Self::TrackEnd(event.clone()),
+            IncomingEvent::TrackException(event) => Self::TrackException(event.clone()),
+            IncomingEvent::TrackStart(event) => Self::TrackStart(event.clone()),
+            IncomingEvent::TrackStuck(event) => Self::TrackStuck(event.clone()),
+            IncomingEvent::WeboscketClosed(event) => Self::WebsocketClosed(event.clone()),
+            IncomingEvent::PlayerUpdate(_) | IncomingEvent::Plugin(_) | IncomingEvent::Stats(_) => {
+                return None
+            }
+        })
+    }
+}
+
+/// Stream of events of a player.
+///
+/// Created via [`Player::events`]. The stream ends when the player is removed
+/// and dropped, such as when it's [destroyed].
+///
+/// [destroyed]: PlayerManager::destroy
+#[derive(Debug)]
+pub struct PlayerEvents {
+    inner: UnboundedReceiver<PlayerEvent>,
+}
+
+impl PlayerEvents {
+    /// Closes the receiving half of a channel without dropping it.
+    pub fn close(&mut self) {
+        self.inner.close();
+    }
+}
+
+impl Stream for PlayerEvents {
+    type Item = PlayerEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_recv(cx)
+    }
+}
+
+/// Last known position of a player's track.
+#[derive(Clone, Copy, Debug)]
+struct Position {
+    /// Position in milliseconds.
+    position: i64,
+    /// When the position was last known.
+    updated_at: Instant,
+}
+
+impl Position {
+    /// Create a position known at an instant.
+    const fn new(position: i64, updated_at: Instant) -> Self {
+        Self {
+            position,
+            updated_at,
+        }
+    }
+
+    /// Position at an instant, assuming that the track kept playing since the
+    /// position was last known.
+    fn extrapolate(self, now: Instant) -> i64 {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_millis();
+
+        self.position
+            .saturating_add(i64::try_from(elapsed).unwrap_or(i64::MAX))
+    }
 }
 
 /// A player for a guild connected to a node.
@@ -84,27 +213,102 @@ impl PlayerManager {
 #[derive(Debug)]
 pub struct Player {
     channel_id: AtomicU64,
+    event_senders: Mutex<Vec<UnboundedSender<PlayerEvent>>>,
+    /// Filters last sent, restored when failing over to another node.
+    filters: Mutex<Option<Box<Filters>>>,
     guild_id: Id<GuildMarker>,
     node: Arc<Node>,
     paused: AtomicBool,
-    position: AtomicI64,
+    /// Position last reported by the node or set by a sent event.
+    position: Mutex<Position>,
     time: AtomicI64,
+    track: Mutex<Option<String>>,
     volume: AtomicI64,
 }
 
 impl Player {
-    pub(crate) const fn new(guild_id: Id<GuildMarker>, node: Arc<Node>) -> Self {
+    pub(crate) fn new(guild_id: Id<GuildMarker>, node: Arc<Node>) -> Self {
         Self {
             channel_id: AtomicU64::new(0),
+            event_senders: Mutex::new(Vec::new()),
+            filters: Mutex::new(None),
             guild_id,
             node,
             paused: AtomicBool::new(false),
-            position: AtomicI64::new(0),
+            position: Mutex::new(Position::new(0, Instant::now())),
             time: AtomicI64::new(0),
+            track: Mutex::new(None),
             volume: AtomicI64::new(100),
         }
     }
 
+    /// Subscribe to the events of the player.
+    ///
+    /// Only events received after subscribing are included. Any number of
+    /// subscriptions may exist at once, each receiving every event.
+    ///
+    /// # Examples
+    ///
+    /// Wait for the current track of a player to end:
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use twilight_lavalink::{player::PlayerEvent, Lavalink};
+    /// # use twilight_model::id::Id;
+    /// # #[tokio::main] async fn main() {
+    /// # let (guild_id, user_id) = (Id::new(1), Id::new(2));
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    ///
+    /// if let Some(player) = lavalink.players().get(&guild_id) {
+    ///     let mut events = player.events();
+    ///
+    ///     while let Some(event) = events.next().await {
+    ///         if let PlayerEvent::TrackEnd(track_end) = event {
+    ///             println!("track ended: {}", track_end.reason);
+    ///
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn events(&self) -> PlayerEvents {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        self.event_senders
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(tx);
+
+        PlayerEvents { inner: rx }
+    }
+
+    /// Send an event to the subscriptions of the player, removing those that
+    /// were dropped.
+    pub(crate) fn dispatch(&self, event: &PlayerEvent) {
+        match event {
+            PlayerEvent::TrackEnd(event) => {
+                let mut track = self.track.lock().unwrap_or_else(PoisonError::into_inner);
+
+                // A replaced track ends after the new track was played.
+                if track.as_ref() == Some(&event.track) {
+                    *track = None;
+                }
+            }
+            PlayerEvent::TrackStart(event) => {
+                *self.track.lock().unwrap_or_else(PoisonError::into_inner) =
+                    Some(event.track.clone());
+            }
+            _ => {}
+        }
+
+        self.event_senders
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
     /// Send an event to the player's node.
     ///
     /// Returns a `futures_channel` `TrySendError` if the node has been removed.
@@ -149,7 +353,29 @@ impl Player {
         tracing::debug!("sending event on guild player {}: {event:?}", self.guild_id);
 
         match &event {
-            OutgoingEvent::Pause(event) => self.paused.store(event.pause, Ordering::Release),
+            OutgoingEvent::Filters(event) => {
+                *self.filters.lock().unwrap_or_else(PoisonError::into_inner) = Some(event.clone());
+            }
+            OutgoingEvent::Pause(event) => {
+                // Keep the position reached before pausing, and extrapolate
+                // from when the player is resumed.
+                self.set_position(self.current_position());
+                self.paused.store(event.pause, Ordering::Release);
+            }
+            OutgoingEvent::Play(event) => {
+                *self.track.lock().unwrap_or_else(PoisonError::into_inner) =
+                    Some(event.track.clone());
+                self.set_position(
+                    event
+                        .start_time
+                        .map_or(0, |start_time| start_time.try_into().unwrap_or(i64::MAX)),
+                );
+            }
+            OutgoingEvent::Seek(event) => self.set_position(event.position),
+            OutgoingEvent::Stop(_) => {
+                *self.track.lock().unwrap_or_else(PoisonError::into_inner) = None;
+                self.set_position(0);
+            }
             OutgoingEvent::Volume(event) => {
                 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
                 self.volume.store(event.volume, Ordering::Release);
@@ -160,6 +386,84 @@ impl Player {
         self.node.send(event)
     }
 
+    /// Create a player linked to another node with the state of this player,
+    /// taking over its subscriptions.
+    ///
+    /// The player's state is restored on the node via [`restore`].
+    ///
+    /// [`restore`]: Self::restore
+    pub(crate) fn migrate(&self, node: Arc<Node>) -> Self {
+        tracing::debug!(
+            "migrating guild player {} to node {}",
+            self.guild_id,
+            node.config().address,
+        );
+
+        let event_senders = mem::take(
+            &mut *self
+                .event_senders
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+
+        Self {
+            channel_id: AtomicU64::new(self.channel_id.load(Ordering::Acquire)),
+            event_senders: Mutex::new(event_senders),
+            filters: Mutex::new(
+                self.filters
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone(),
+            ),
+            guild_id: self.guild_id,
+            node,
+            paused: AtomicBool::new(self.paused()),
+            position: Mutex::new(*self.position.lock().unwrap_or_else(PoisonError::into_inner)),
+            time: AtomicI64::new(self.time.load(Ordering::Relaxed)),
+            track: Mutex::new(self.track()),
+            volume: AtomicI64::new(self.volume()),
+        }
+    }
+
+    /// Restore the player's state on its node after migrating.
+    ///
+    /// The voice connection is re-established with the voice update, if any,
+    /// and the current track is resumed at its last known position with the
+    /// player's filters, pause state, and volume.
+    pub(crate) fn restore(&self, voice_update: Option<VoiceUpdate>) -> Result<(), NodeSenderError> {
+        if let Some(voice_update) = voice_update {
+            self.send(voice_update)?;
+        }
+
+        let filters = self
+            .filters
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        if let Some(filters) = filters {
+            self.send(OutgoingEvent::Filters(filters))?;
+        }
+
+        if let Some(track) = self.track() {
+            let position = u64::try_from(self.current_position()).ok();
+            let paused = self.paused();
+            self.send(Play::new(self.guild_id, track, position, None, false))?;
+
+            if paused {
+                self.send(Pause::new(self.guild_id, true))?;
+            }
+        }
+
+        let volume = self.volume();
+
+        if volume != 100 {
+            self.send(Volume::new(self.guild_id, volume))?;
+        }
+
+        Ok(())
+    }
+
     /// Return an immutable reference to the node linked to the player.
     pub const fn node(&self) -> &Arc<Node> {
         &self.node
@@ -192,14 +496,76 @@ impl Player {
         self.paused.load(Ordering::Acquire)
     }
 
-    /// Return the player's position.
+    /// Return the player's position in milliseconds, as last reported by
+    /// the node or set by a sent event.
+    ///
+    /// Refer to [`current_position`] for the position extrapolated to now.
+    ///
+    /// [`current_position`]: Self::current_position
     pub fn position(&self) -> i64 {
-        self.position.load(Ordering::Relaxed)
+        self.position
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .position
+    }
+
+    /// Return the position of the player's track in milliseconds now.
+    ///
+    /// The last known [`position`] is extrapolated by the time elapsed since
+    /// it was known, unless the player is paused or isn't playing a track.
+    /// Filters changing the speed of tracks aren't taken into account.
+    ///
+    /// [`position`]: Self::position
+    pub fn current_position(&self) -> i64 {
+        self.position_at(Instant::now())
     }
 
-    /// Set the player's position.
+    /// Return the position of the player's track in milliseconds at an
+    /// instant.
+    fn position_at(&self, now: Instant) -> i64 {
+        let position = *self.position.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if self.paused() || self.track().is_none() {
+            return position.position;
+        }
+
+        position.extrapolate(now)
+    }
+
+    /// Set the player's position, known now.
     pub(crate) fn set_position(&self, position: i64) {
-        self.position.store(position, Ordering::Release);
+        *self.position.lock().unwrap_or_else(PoisonError::into_inner) =
+            Position::new(position, Instant::now());
+    }
+
+    /// Seek the player's track by an offset in milliseconds from its
+    /// [current position].
+    ///
+    /// Negative offsets seek backwards, up to the start of the track.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NodeSenderErrorType::Sending`] error type if node is no
+    /// longer connected.
+    ///
+    /// [`NodeSenderErrorType::Sending`]: crate::node::NodeSenderErrorType::Sending
+    /// [current position]: Self::current_position
+    pub fn seek_relative(&self, offset: i64) -> Result<(), NodeSenderError> {
+        let position = self.current_position().saturating_add(offset).max(0);
+
+        self.send(Seek::new(self.guild_id, position))
+    }
+
+    /// Restart the player's track from its start.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NodeSenderErrorType::Sending`] error type if node is no
+    /// longer connected.
+    ///
+    /// [`NodeSenderErrorType::Sending`]: crate::node::NodeSenderErrorType::Sending
+    pub fn restart(&self) -> Result<(), NodeSenderError> {
+        self.send(Seek::new(self.guild_id, 0))
     }
 
     /// Return the player's time.
@@ -212,6 +578,14 @@ impl Player {
         self.time.store(time, Ordering::Release);
     }
 
+    /// Return the base64 track that the player is playing, if any.
+    pub fn track(&self) -> Option<String> {
+        self.track
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
     /// Return the player's volume.
     pub fn volume(&self) -> i64 {
         self.volume.load(Ordering::Relaxed)
@@ -220,10 +594,33 @@ impl Player {
 
 #[cfg(test)]
 mod tests {
-    use super::{Player, PlayerManager};
+    use super::{Player, PlayerEvent, PlayerEvents, PlayerManager, Position};
+    use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
 
     assert_impl_all!(PlayerManager: Debug, Default, Send, Sync);
     assert_impl_all!(Player: Debug, Send, Sync);
+    assert_impl_all!(PlayerEvent: Clone, Debug, PartialEq, Send, Sync);
+    assert_impl_all!(PlayerEvents: Debug, Send, Stream<Item = PlayerEvent>, Sync);
+
+    #[test]
+    fn position_extrapolate() {
+        let now = Instant::now();
+        let position = Position::new(1000, now);
+
+        assert_eq!(1000, position.extrapolate(now));
+        assert_eq!(
+            3500,
+            position.extrapolate(now + Duration::from_millis(2500))
+        );
+        // Instants before the position was known don't rewind it.
+        assert_eq!(
+            1000,
+            Position::new(1000, now + Duration::from_secs(1)).extrapolate(now)
+        );
+    }
 }
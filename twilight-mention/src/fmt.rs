@@ -95,6 +95,16 @@ impl Display for MentionFormat<Id<EmojiMarker>> {
     }
 }
 
+/// Mention a guild navigation. This will format as `<id:NAME>`.
+impl Display for MentionFormat<GuildNavigation> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("<id:")?;
+        f.write_str(self.0.name())?;
+
+        f.write_str(">")
+    }
+}
+
 /// Mention a role. This will format as `<@&ID>`.
 impl Display for MentionFormat<Id<RoleMarker>> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -206,6 +216,74 @@ impl CommandMention {
     pub const fn into_mention(self) -> MentionFormat<CommandMention> {
         MentionFormat(self)
     }
+
+    /// ID of the top-level command.
+    pub const fn id(&self) -> Id<CommandMarker> {
+        match self {
+            Self::Command { id, .. }
+            | Self::SubCommand { id, .. }
+            | Self::SubCommandGroup { id, .. } => *id,
+        }
+    }
+
+    /// Name of the top-level command.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Command { name, .. }
+            | Self::SubCommand { name, .. }
+            | Self::SubCommandGroup { name, .. } => name,
+        }
+    }
+
+    /// Names of the command, its subcommand group, and its subcommand, in
+    /// order of appearance in the mention.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::{fmt::CommandMention, ParseMention};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mention = CommandMention::parse("</role add:123>")?;
+    ///
+    /// assert_eq!(vec!["role", "add"], mention.path());
+    /// assert_eq!(123, mention.id().get());
+    /// # Ok(()) }
+    /// ```
+    pub fn path(&self) -> Vec<&str> {
+        match self {
+            Self::Command { name, .. } => vec![name],
+            Self::SubCommand {
+                name, sub_command, ..
+            } => vec![name, sub_command],
+            Self::SubCommandGroup {
+                name,
+                sub_command,
+                sub_command_group,
+                ..
+            } => vec![name, sub_command_group, sub_command],
+        }
+    }
+
+    /// Name of the subcommand, if any.
+    pub fn sub_command(&self) -> Option<&str> {
+        match self {
+            Self::Command { .. } => None,
+            Self::SubCommand { sub_command, .. } | Self::SubCommandGroup { sub_command, .. } => {
+                Some(sub_command)
+            }
+        }
+    }
+
+    /// Name of the subcommand group, if any.
+    pub fn sub_command_group(&self) -> Option<&str> {
+        match self {
+            Self::SubCommandGroup {
+                sub_command_group, ..
+            } => Some(sub_command_group),
+            _ => None,
+        }
+    }
 }
 
 /// Mention the current user. This will format as `<@ID>`.
@@ -229,6 +307,13 @@ impl Mention<Id<EmojiMarker>> for Emoji {
     }
 }
 
+/// Mention a guild navigation. This will format as `<id:NAME>`.
+impl Mention<Self> for GuildNavigation {
+    fn mention(&self) -> MentionFormat<Self> {
+        MentionFormat(*self)
+    }
+}
+
 /// Mention a member's user. This will format as `<@ID>`.
 impl Mention<Id<UserMarker>> for Member {
     fn mention(&self) -> MentionFormat<Id<UserMarker>> {
@@ -280,33 +365,93 @@ impl Mention<Id<UserMarker>> for User {
 ///
 /// [Discord Docs/Message Formatting]: https://discord.com/developers/docs/reference#message-formatting
 /// [Discord Docs Changelog/Slash Command Mentions]: https://discord.com/developers/docs/change-log#slash-command-mentions
-#[allow(missing_docs)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CommandMention {
+    /// Mention of a top-level command.
     Command {
+        /// ID of the command.
         id: Id<CommandMarker>,
+        /// Name of the command.
         name: String,
     },
-
+    /// Mention of a subcommand of a top-level command.
     SubCommand {
+        /// ID of the top-level command.
         id: Id<CommandMarker>,
+        /// Name of the top-level command.
         name: String,
+        /// Name of the subcommand.
         sub_command: String,
     },
-
+    /// Mention of a subcommand within a subcommand group.
     SubCommandGroup {
+        /// ID of the top-level command.
         id: Id<CommandMarker>,
+        /// Name of the top-level command.
         name: String,
+        /// Name of the subcommand.
         sub_command: String,
+        /// Name of the subcommand group.
         sub_command_group: String,
     },
 }
 
+/// Page of a guild that can be navigated to with a mention.
+///
+/// See [Discord Docs/Message Formatting].
+///
+/// [Discord Docs/Message Formatting]: https://discord.com/developers/docs/reference#message-formatting
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildNavigation {
+    /// Browse Channels tab.
+    Browse,
+    /// Channels & Roles tab, where members pick their onboarding answers.
+    Customize,
+    /// Server Guide tab.
+    Guide,
+    /// Linked Roles tab.
+    LinkedRoles,
+}
+
+impl GuildNavigation {
+    /// Name of the page within a mention.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::fmt::GuildNavigation;
+    ///
+    /// assert_eq!("linked-roles", GuildNavigation::LinkedRoles.name());
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Browse => "browse",
+            Self::Customize => "customize",
+            Self::Guide => "guide",
+            Self::LinkedRoles => "linked-roles",
+        }
+    }
+
+    /// Page by its name within a mention.
+    ///
+    /// Returns [`None`] if the name isn't of a known page.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "browse" => Self::Browse,
+            "customize" => Self::Customize,
+            "guide" => Self::Guide,
+            "linked-roles" => Self::LinkedRoles,
+            _ => return None,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::timestamp::{Timestamp, TimestampStyle};
 
-    use super::{CommandMention, Mention, MentionFormat};
+    use super::{CommandMention, GuildNavigation, Mention, MentionFormat};
     use static_assertions::assert_impl_all;
     use std::fmt::{Debug, Display};
     use twilight_model::id::marker::CommandMarker;
@@ -324,6 +469,7 @@ mod tests {
     assert_impl_all!(MentionFormat<Id<ChannelMarker>>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<CommandMention>: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<Id<EmojiMarker>>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<GuildNavigation>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<Id<RoleMarker>>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<Id<UserMarker>>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(Id<ChannelMarker>: Mention<Id<ChannelMarker>>);
@@ -336,6 +482,8 @@ mod tests {
     assert_impl_all!(&'static Id<EmojiMarker>: Mention<Id<EmojiMarker>>);
     assert_impl_all!(Emoji: Mention<Id<EmojiMarker>>);
     assert_impl_all!(&'static Emoji: Mention<Id<EmojiMarker>>);
+    assert_impl_all!(GuildNavigation: Mention<GuildNavigation>);
+    assert_impl_all!(&'static GuildNavigation: Mention<GuildNavigation>);
     assert_impl_all!(Member: Mention<Id<UserMarker>>);
     assert_impl_all!(&'static Member: Mention<Id<UserMarker>>);
     assert_impl_all!(Id<RoleMarker>: Mention<Id<RoleMarker>>);
@@ -394,6 +542,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn command_mention_parts() {
+        let command = CommandMention::Command {
+            id: Id::new(123),
+            name: "name".to_owned(),
+        };
+        assert_eq!(123, command.id().get());
+        assert_eq!("name", command.name());
+        assert_eq!(vec!["name"], command.path());
+        assert!(command.sub_command().is_none());
+        assert!(command.sub_command_group().is_none());
+
+        let sub_command_group = CommandMention::SubCommandGroup {
+            id: Id::new(456),
+            name: "name".to_owned(),
+            sub_command: "subcommand".to_owned(),
+            sub_command_group: "subcommand_group".to_owned(),
+        };
+        assert_eq!(456, sub_command_group.id().get());
+        assert_eq!(
+            vec!["name", "subcommand_group", "subcommand"],
+            sub_command_group.path()
+        );
+        assert_eq!(Some("subcommand"), sub_command_group.sub_command());
+        assert_eq!(
+            Some("subcommand_group"),
+            sub_command_group.sub_command_group()
+        );
+    }
+
     #[test]
     fn mention_format_emoji_id() {
         assert_eq!(
@@ -402,6 +580,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mention_format_guild_navigation() {
+        assert_eq!("<id:browse>", GuildNavigation::Browse.mention().to_string());
+        assert_eq!(
+            "<id:customize>",
+            GuildNavigation::Customize.mention().to_string()
+        );
+        assert_eq!("<id:guide>", GuildNavigation::Guide.mention().to_string());
+        assert_eq!(
+            "<id:linked-roles>",
+            GuildNavigation::LinkedRoles.mention().to_string()
+        );
+    }
+
     #[test]
     fn mention_format_role_id() {
         assert_eq!("<@&123>", Id::<RoleMarker>::new(123).mention().to_string());
@@ -13,6 +13,7 @@
 
 pub mod fmt;
 pub mod parse;
+pub mod sanitize;
 pub mod timestamp;
 
 #[doc(no_inline)]
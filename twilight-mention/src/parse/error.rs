@@ -56,6 +56,12 @@ impl Display for ParseMentionError<'_> {
                 f.write_str("found extraneous part ")?;
                 Debug::fmt(found, f)
             }
+            ParseMentionErrorType::GuildNavigationInvalid { found } => {
+                f.write_str("guild navigation '")?;
+                f.write_str(found)?;
+
+                f.write_str("' is invalid")
+            }
             ParseMentionErrorType::LeadingArrow { found } => {
                 f.write_str("expected to find a leading arrow ('<') but instead found ")?;
 
@@ -143,6 +149,11 @@ pub enum ParseMentionErrorType<'a> {
         /// The extra part that was found.
         found: &'a str,
     },
+    /// Name of the page of a guild navigation is invalid.
+    GuildNavigationInvalid {
+        /// Name of the page.
+        found: &'a str,
+    },
     /// Leading arrow (`<`) is not present.
     LeadingArrow {
         /// Character that was instead found where the leading arrow should be.
@@ -188,6 +199,7 @@ mod tests {
 
     assert_fields!(ParseMentionErrorType::IdNotU64: found);
     assert_fields!(ParseMentionErrorType::ExtraneousPart: found);
+    assert_fields!(ParseMentionErrorType::GuildNavigationInvalid: found);
     assert_fields!(ParseMentionErrorType::LeadingArrow: found);
     assert_fields!(ParseMentionErrorType::Sigil: expected, found);
     assert_fields!(ParseMentionErrorType::TimestampStyleInvalid: found);
@@ -218,6 +230,16 @@ mod tests {
             .to_string()
         );
 
+        expected = "guild navigation 'home' is invalid";
+        assert_eq!(
+            expected,
+            ParseMentionError {
+                kind: ParseMentionErrorType::GuildNavigationInvalid { found: "home" },
+                source: None,
+            }
+            .to_string(),
+        );
+
         expected = "expected to find a leading arrow ('<') but instead found 'a'";
         assert_eq!(
             expected,
@@ -1,7 +1,7 @@
 use crate::timestamp::{Timestamp, TimestampStyle};
 
 use super::{MentionIter, MentionType, ParseMentionError, ParseMentionErrorType};
-use crate::fmt::CommandMention;
+use crate::fmt::{CommandMention, GuildNavigation};
 use std::{num::NonZeroU64, str::Chars};
 use twilight_model::id::marker::CommandMarker;
 use twilight_model::id::{
@@ -223,11 +223,55 @@ impl ParseMention for Id<EmojiMarker> {
     }
 }
 
+impl ParseMention for GuildNavigation {
+    const SIGILS: &'static [&'static str] = &["id:"];
+
+    /// Parse a guild navigation from a string slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseMentionErrorType::GuildNavigationInvalid`] if the name
+    /// of the page is invalid.
+    fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
+    where
+        Self: Sized,
+    {
+        let Some(remaining) = buf.strip_prefix('<') else {
+            return Err(ParseMentionError {
+                kind: ParseMentionErrorType::LeadingArrow {
+                    found: buf.chars().next(),
+                },
+                source: None,
+            });
+        };
+
+        let Some(remaining) = remaining.strip_prefix(Self::SIGILS[0]) else {
+            return Err(ParseMentionError {
+                kind: ParseMentionErrorType::Sigil {
+                    expected: Self::SIGILS,
+                    found: remaining.chars().next(),
+                },
+                source: None,
+            });
+        };
+
+        let name = remaining
+            .find('>')
+            .map(|end_position| &remaining[..end_position])
+            .ok_or_else(|| ParseMentionError::trailing_arrow(None))?;
+
+        GuildNavigation::from_name(name).ok_or(ParseMentionError {
+            kind: ParseMentionErrorType::GuildNavigationInvalid { found: name },
+            source: None,
+        })
+    }
+}
+
 impl ParseMention for MentionType {
     /// Sigils for any type of mention.
     ///
     /// Contains all of the sigils of every other type of mention.
-    const SIGILS: &'static [&'static str] = &["#", ":", "@&", "@", "t:"];
+    const SIGILS: &'static [&'static str] = &["#", ":", "@&", "@", "t:", "id:"];
 
     /// Parse a mention from a string slice.
     ///
@@ -241,6 +285,14 @@ impl ParseMention for MentionType {
     where
         Self: Sized,
     {
+        // Guild navigations are the only mentions without an ID.
+        if buf
+            .strip_prefix('<')
+            .is_some_and(|remaining| remaining.starts_with(GuildNavigation::SIGILS[0]))
+        {
+            return GuildNavigation::parse(buf).map(MentionType::GuildNavigation);
+        }
+
         let (id, maybe_modifier, found) = parse_mention(buf, Self::SIGILS)?;
 
         for sigil in Id::<ChannelMarker>::SIGILS {
@@ -506,7 +558,7 @@ fn separator_sigil_present(chars: &mut Chars<'_>) -> bool {
 /// <https://rust-lang.github.io/api-guidelines/future-proofing.html>
 mod private {
     use super::super::MentionType;
-    use crate::fmt::CommandMention;
+    use crate::fmt::{CommandMention, GuildNavigation};
     use crate::timestamp::Timestamp;
     use twilight_model::id::{
         marker::{ChannelMarker, EmojiMarker, RoleMarker, UserMarker},
@@ -518,6 +570,7 @@ mod private {
     impl Sealed for Id<ChannelMarker> {}
     impl Sealed for CommandMention {}
     impl Sealed for Id<EmojiMarker> {}
+    impl Sealed for GuildNavigation {}
     impl Sealed for MentionType {}
     impl Sealed for Id<RoleMarker> {}
     impl Sealed for Timestamp {}
@@ -531,7 +584,7 @@ mod tests {
         private::Sealed,
         ParseMention,
     };
-    use crate::fmt::CommandMention;
+    use crate::fmt::{CommandMention, GuildNavigation};
     use crate::{
         parse::ParseMentionError,
         timestamp::{Timestamp, TimestampStyle},
@@ -545,6 +598,7 @@ mod tests {
     assert_impl_all!(Id<ChannelMarker>: ParseMention, Sealed);
     assert_impl_all!(CommandMention: ParseMention, Sealed);
     assert_impl_all!(Id<EmojiMarker>: ParseMention, Sealed);
+    assert_impl_all!(GuildNavigation: ParseMention, Sealed);
     assert_impl_all!(MentionType: ParseMention, Sealed);
     assert_impl_all!(Id<RoleMarker>: ParseMention, Sealed);
     assert_impl_all!(Id<UserMarker>: ParseMention, Sealed);
@@ -554,7 +608,8 @@ mod tests {
         assert_eq!(&["#"], Id::<ChannelMarker>::SIGILS);
         assert_eq!(&["/"], CommandMention::SIGILS);
         assert_eq!(&[":"], Id::<EmojiMarker>::SIGILS);
-        assert_eq!(&["#", ":", "@&", "@", "t:"], MentionType::SIGILS);
+        assert_eq!(&["id:"], GuildNavigation::SIGILS);
+        assert_eq!(&["#", ":", "@&", "@", "t:", "id:"], MentionType::SIGILS);
         assert_eq!(&["@&"], Id::<RoleMarker>::SIGILS);
         assert_eq!(&["@"], Id::<UserMarker>::SIGILS);
     }
@@ -656,6 +711,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn iter_command_mention() {
+        let mut iter = CommandMention::iter("use </a b:123> or </c:456> <@789>");
+
+        assert_eq!(
+            Some((
+                CommandMention::SubCommand {
+                    id: Id::new(123),
+                    name: "a".to_owned(),
+                    sub_command: "b".to_owned(),
+                },
+                4,
+                13
+            )),
+            iter.next()
+        );
+        assert!(matches!(iter.next(), Some((mention, _, _)) if mention.id().get() == 456));
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     fn parse_emoji_id() {
         assert_eq!(
@@ -671,6 +746,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_guild_navigation() {
+        assert_eq!(
+            GuildNavigation::Browse,
+            GuildNavigation::parse("<id:browse>").unwrap()
+        );
+        assert_eq!(
+            GuildNavigation::Customize,
+            GuildNavigation::parse("<id:customize>").unwrap()
+        );
+        assert_eq!(
+            GuildNavigation::Guide,
+            GuildNavigation::parse("<id:guide>").unwrap()
+        );
+        assert_eq!(
+            GuildNavigation::LinkedRoles,
+            GuildNavigation::parse("<id:linked-roles>").unwrap()
+        );
+        assert_eq!(
+            &ParseMentionErrorType::GuildNavigationInvalid { found: "home" },
+            GuildNavigation::parse("<id:home>").unwrap_err().kind(),
+        );
+        assert_eq!(
+            &ParseMentionErrorType::Sigil {
+                expected: &["id:"],
+                found: Some('#'),
+            },
+            GuildNavigation::parse("<#123>").unwrap_err().kind(),
+        );
+        assert_eq!(
+            &ParseMentionErrorType::TrailingArrow { found: None },
+            GuildNavigation::parse("<id:guide").unwrap_err().kind(),
+        );
+    }
+
     #[test]
     fn parse_mention_type() {
         assert_eq!(
@@ -681,6 +791,10 @@ mod tests {
             MentionType::Emoji(Id::new(123)),
             MentionType::parse("<:name:123>").unwrap()
         );
+        assert_eq!(
+            MentionType::GuildNavigation(GuildNavigation::Customize),
+            MentionType::parse("<id:customize>").unwrap()
+        );
         assert_eq!(
             MentionType::Role(Id::new(123)),
             MentionType::parse("<@&123>").unwrap()
@@ -691,7 +805,7 @@ mod tests {
         );
         assert_eq!(
             &ParseMentionErrorType::Sigil {
-                expected: &["#", ":", "@&", "@", "t:"],
+                expected: &["#", ":", "@&", "@", "t:", "id:"],
                 found: Some(';'),
             },
             MentionType::parse("<;123>").unwrap_err().kind(),
@@ -1,5 +1,5 @@
 use super::ParseMention;
-use std::{marker::PhantomData, str::CharIndices};
+use std::{marker::PhantomData, ops::Range, str::CharIndices};
 
 /// Iterator of mentions within a buffer.
 ///
@@ -25,6 +25,10 @@ use std::{marker::PhantomData, str::CharIndices};
 /// assert!(matches!(iter.next(), Some((user, _, _)) if user.get() == 456));
 /// assert!(matches!(iter.next(), Some((user, _, _)) if user.get() == 789));
 /// ```
+///
+/// Use [`spans`] to iterate over the byte ranges of the mentions instead.
+///
+/// [`spans`]: Self::spans
 #[derive(Clone, Debug)]
 pub struct MentionIter<'a, T> {
     buf: &'a str,
@@ -49,6 +53,12 @@ impl<'a, T> MentionIter<'a, T> {
     pub const fn as_str(&self) -> &'a str {
         self.buf
     }
+
+    /// Convert into an iterator of mentions and the byte ranges of their
+    /// sources in the buffer.
+    pub const fn spans(self) -> MentionSpans<'a, T> {
+        MentionSpans { iter: self }
+    }
 }
 
 impl<'a, T: ParseMention> Iterator for MentionIter<'a, T> {
@@ -96,13 +106,72 @@ impl<'a, T: ParseMention> Iterator for MentionIter<'a, T> {
     }
 }
 
+/// Iterator of mentions within a buffer along with the byte ranges of their
+/// sources, created by [`MentionIter::spans`].
+///
+/// The ranges are exclusive and can be used to index the buffer or to replace
+/// mentions in place, without searching the buffer again.
+///
+/// # Examples
+///
+/// Replace the mentioned users with their names:
+///
+/// ```
+/// use twilight_mention::ParseMention;
+/// use twilight_model::id::{marker::UserMarker, Id};
+///
+/// let buf = "hi <@123> and <@456>!";
+/// let mut content = buf.to_owned();
+///
+/// // Replace from the end so that the earlier ranges stay valid.
+/// let spans = Id::<UserMarker>::iter(buf).spans().collect::<Vec<_>>();
+///
+/// for (user, range) in spans.into_iter().rev() {
+///     let name = if user.get() == 123 { "@alice" } else { "@bob" };
+///     content.replace_range(range, name);
+/// }
+///
+/// assert_eq!("hi @alice and @bob!", content);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MentionSpans<'a, T> {
+    iter: MentionIter<'a, T>,
+}
+
+impl<'a, T> MentionSpans<'a, T> {
+    /// Return an immutable reference to the underlying buffer of the iterator.
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.iter.buf
+    }
+}
+
+impl<T: ParseMention> Iterator for MentionSpans<'_, T> {
+    /// Found mention followed by the byte range of its source in the string
+    /// returned by [`as_str`].
+    ///
+    /// [`as_str`]: Self::as_str
+    type Item = (T, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The ending index is that of the trailing arrow, which is a single
+        // byte.
+        self.iter
+            .next()
+            .map(|(mention, start, end)| (mention, start..end + 1))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::timestamp::{Timestamp, TimestampStyle};
+    use crate::{
+        fmt::GuildNavigation,
+        timestamp::{Timestamp, TimestampStyle},
+    };
 
     use super::{
         super::{MentionType, ParseMention},
-        MentionIter,
+        MentionIter, MentionSpans,
     };
     use static_assertions::{assert_impl_all, assert_obj_safe};
     use std::fmt::Debug;
@@ -116,6 +185,7 @@ mod tests {
     assert_impl_all!(MentionIter<'_, MentionType>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, Id<RoleMarker>>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, Id<UserMarker>>: Clone, Debug, Iterator, Send, Sync);
+    assert_impl_all!(MentionSpans<'_, MentionType>: Clone, Debug, Iterator, Send, Sync);
     assert_obj_safe!(
         MentionIter<'_, Id<ChannelMarker>>,
         MentionIter<'_, Id<EmojiMarker>>,
@@ -143,6 +213,36 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn iter_spans() {
+        let buf = "one <@123>two<#456> <t:1:R>é<id:browse>";
+        let mut iter = MentionType::iter(buf).spans();
+        assert_eq!(buf, iter.as_str());
+
+        let (mention, range) = iter.next().unwrap();
+        assert_eq!(MentionType::User(Id::new(123)), mention);
+        assert_eq!("<@123>", &buf[range]);
+
+        let (mention, range) = iter.next().unwrap();
+        assert_eq!(MentionType::Channel(Id::new(456)), mention);
+        assert_eq!(13..19, range);
+
+        let (mention, range) = iter.next().unwrap();
+        assert_eq!(
+            MentionType::Timestamp(Timestamp::new(1, Some(TimestampStyle::RelativeTime))),
+            mention
+        );
+        assert_eq!("<t:1:R>", &buf[range]);
+
+        let (mention, range) = iter.next().unwrap();
+        assert_eq!(
+            MentionType::GuildNavigation(GuildNavigation::Browse),
+            mention
+        );
+        assert_eq!("<id:browse>", &buf[range]);
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     fn iter_emoji_ids() {
         let mut iter = Id::<EmojiMarker>::iter("some <:name:123> emojis <:emoji:456>");
@@ -153,9 +253,13 @@ mod tests {
 
     #[test]
     fn iter_mention_type() {
-        let mut iter = MentionType::iter("<#12><:name:34><@&56><@78>");
+        let mut iter = MentionType::iter("<#12><:name:34><id:guide><@&56><@78>");
         assert_eq!(MentionType::Channel(Id::new(12)), iter.next().unwrap().0);
         assert_eq!(MentionType::Emoji(Id::new(34)), iter.next().unwrap().0);
+        assert_eq!(
+            MentionType::GuildNavigation(GuildNavigation::Guide),
+            iter.next().unwrap().0
+        );
         assert_eq!(MentionType::Role(Id::new(56)), iter.next().unwrap().0);
         assert_eq!(MentionType::User(Id::new(78)), iter.next().unwrap().0);
         assert!(iter.next().is_none());
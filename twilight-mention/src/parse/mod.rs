@@ -60,11 +60,11 @@ mod error;
 mod r#impl;
 mod iter;
 
-use crate::{timestamp::Timestamp, Mention};
+use crate::{fmt::GuildNavigation, timestamp::Timestamp, Mention};
 
 pub use self::{
     error::{ParseMentionError, ParseMentionErrorType},
-    iter::MentionIter,
+    iter::{MentionIter, MentionSpans},
     r#impl::ParseMention,
 };
 
@@ -140,6 +140,8 @@ pub enum MentionType {
     Channel(Id<ChannelMarker>),
     /// Emoji mention.
     Emoji(Id<EmojiMarker>),
+    /// Guild navigation mention.
+    GuildNavigation(GuildNavigation),
     /// Role mention.
     Role(Id<RoleMarker>),
     /// Timestamp mention.
@@ -153,6 +155,7 @@ impl Display for MentionType {
         match self {
             Self::Channel(id) => Display::fmt(id, f),
             Self::Emoji(id) => Display::fmt(id, f),
+            Self::GuildNavigation(navigation) => Display::fmt(&navigation.mention(), f),
             Self::Role(id) => Display::fmt(id, f),
             Self::Timestamp(timestamp) => Display::fmt(&timestamp.mention(), f),
             Self::User(id) => Display::fmt(id, f),
@@ -0,0 +1,204 @@
+//! Neutralize mentions in content that its allowed mentions don't permit.
+//!
+//! Allowed mentions control who is pinged by a message, but mentions that
+//! aren't allowed are still displayed as mentions. Bots echoing or bridging
+//! content can instead rewrite those mentions, so that the content reads the
+//! same as the original without looking like it pings anyone.
+//!
+//! # Examples
+//!
+//! Neutralize the user mentions that aren't allowed mentions:
+//!
+//! ```
+//! use twilight_mention::sanitize;
+//! use twilight_model::{channel::message::AllowedMentions, id::Id};
+//!
+//! let allowed_mentions = AllowedMentions {
+//!     users: vec![Id::new(1)],
+//!     ..AllowedMentions::default()
+//! };
+//!
+//! assert_eq!(
+//!     "<@1> <@\u{200B}2> @\u{200B}here",
+//!     sanitize::sanitize("<@1> <@2> @here", &allowed_mentions),
+//! );
+//! ```
+//!
+//! Replace them with the names of the users instead:
+//!
+//! ```
+//! use twilight_mention::{parse::MentionType, sanitize};
+//! use twilight_model::channel::message::AllowedMentions;
+//!
+//! let content = sanitize::sanitize_with(
+//!     "hi <@1>",
+//!     &AllowedMentions::default(),
+//!     |mention| match mention {
+//!         MentionType::User(user_id) if user_id.get() == 1 => Some("@alice".to_owned()),
+//!         _ => None,
+//!     },
+//! );
+//!
+//! assert_eq!("hi @alice", content);
+//! ```
+
+use crate::parse::{MentionType, ParseMention};
+use twilight_model::channel::message::{AllowedMentions, MentionType as AllowedMentionType};
+
+/// Zero width space, which breaks up mentions without being visible.
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Neutralize the mentions in content that allowed mentions don't permit.
+///
+/// A zero width space is inserted into user and role mentions that aren't
+/// allowed, as well as into `@everyone` and `@here` unless
+/// [`AllowedMentionType::Everyone`] is parsed.
+///
+/// [`AllowedMentionType::Everyone`]: twilight_model::channel::message::MentionType::Everyone
+pub fn sanitize(content: &str, allowed_mentions: &AllowedMentions) -> String {
+    sanitize_with(content, allowed_mentions, |_| None)
+}
+
+/// Neutralize the mentions in content that allowed mentions don't permit,
+/// replacing them with the text returned by a resolver.
+///
+/// The resolver is called with each [`MentionType::User`] and
+/// [`MentionType::Role`] mention that isn't allowed and returns the text to
+/// replace it with, such as the name of the user or role. Mentions for which
+/// the resolver returns `None` are neutralized as with [`sanitize`].
+///
+/// `@everyone` and `@here` are neutralized in the returned text as well, as
+/// names are often chosen by users.
+pub fn sanitize_with(
+    content: &str,
+    allowed_mentions: &AllowedMentions,
+    mut resolve: impl FnMut(MentionType) -> Option<String>,
+) -> String {
+    let everyone = allowed_mentions
+        .parse
+        .contains(&AllowedMentionType::Everyone);
+    let mut sanitized = String::with_capacity(content.len());
+    let mut last = 0;
+
+    for (mention, range) in MentionType::iter(content).spans() {
+        let allowed = match mention {
+            MentionType::Role(role_id) => {
+                allowed_mentions.parse.contains(&AllowedMentionType::Roles)
+                    || allowed_mentions.roles.contains(&role_id)
+            }
+            MentionType::User(user_id) => {
+                allowed_mentions.parse.contains(&AllowedMentionType::Users)
+                    || allowed_mentions.users.contains(&user_id)
+            }
+            _ => true,
+        };
+
+        if allowed {
+            continue;
+        }
+
+        push_text(&mut sanitized, &content[last..range.start], everyone);
+
+        if let Some(text) = resolve(mention) {
+            push_text(&mut sanitized, &text, everyone);
+        } else {
+            let (sigil, rest) = content[range.clone()].split_at(2);
+            sanitized.push_str(sigil);
+            sanitized.push(ZERO_WIDTH_SPACE);
+            sanitized.push_str(rest);
+        }
+
+        last = range.end;
+    }
+
+    push_text(&mut sanitized, &content[last..], everyone);
+
+    sanitized
+}
+
+/// Push text, neutralizing `@everyone` and `@here` unless they're allowed.
+fn push_text(sanitized: &mut String, text: &str, everyone: bool) {
+    if everyone {
+        sanitized.push_str(text);
+
+        return;
+    }
+
+    let mut rest = text;
+
+    while let Some(index) = rest.find('@') {
+        let (before, after) = rest.split_at(index + 1);
+        sanitized.push_str(before);
+
+        if after.starts_with("everyone") || after.starts_with("here") {
+            sanitized.push(ZERO_WIDTH_SPACE);
+        }
+
+        rest = after;
+    }
+
+    sanitized.push_str(rest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sanitize, sanitize_with};
+    use crate::parse::MentionType;
+    use twilight_model::{
+        channel::message::{AllowedMentions, MentionType as AllowedMentionType},
+        id::Id,
+    };
+
+    const CONTENT: &str = "<@1> <@2> <@&3> <@&4> <#5> @everyone @here";
+
+    #[test]
+    fn disallowed() {
+        assert_eq!(
+            "<@\u{200B}1> <@\u{200B}2> <@\u{200B}&3> <@\u{200B}&4> <#5> @\u{200B}everyone @\u{200B}here",
+            sanitize(CONTENT, &AllowedMentions::default()),
+        );
+    }
+
+    #[test]
+    fn allowed() {
+        let allowed_mentions = AllowedMentions {
+            parse: vec![AllowedMentionType::Everyone, AllowedMentionType::Users],
+            roles: vec![Id::new(3)],
+            ..AllowedMentions::default()
+        };
+
+        assert_eq!(
+            "<@1> <@2> <@&3> <@\u{200B}&4> <#5> @everyone @here",
+            sanitize(CONTENT, &allowed_mentions),
+        );
+
+        let allowed_mentions = AllowedMentions {
+            parse: vec![AllowedMentionType::Roles],
+            users: vec![Id::new(2)],
+            ..AllowedMentions::default()
+        };
+
+        assert_eq!(
+            "<@\u{200B}1> <@2> <@&3> <@&4> <#5> @\u{200B}everyone @\u{200B}here",
+            sanitize(CONTENT, &allowed_mentions),
+        );
+    }
+
+    #[test]
+    fn resolver() {
+        let content = sanitize_with(
+            CONTENT,
+            &AllowedMentions::default(),
+            |mention| match mention {
+                MentionType::User(user_id) if user_id.get() == 1 => Some("@everyone".to_owned()),
+                MentionType::Role(role_id) if role_id.get() == 3 => Some("@mods".to_owned()),
+                _ => None,
+            },
+        );
+
+        assert_eq!(
+            "@\u{200B}everyone <@\u{200B}2> @mods <@\u{200B}&4> <#5> @\u{200B}everyone @\u{200B}here",
+            content,
+        );
+    }
+}
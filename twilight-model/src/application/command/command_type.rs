@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 // Keep in sync with `twilight-validate::command`!
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum CommandType {
@@ -17,6 +17,18 @@ use std::{cmp::Eq, collections::HashMap};
 /// [`Command`]: super::Command
 /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(
+        bound(serialize = "__S: rkyv::ser::ScratchSpace + rkyv::ser::Serializer"),
+        check_bytes
+    ),
+    archive_attr(check_bytes(
+        bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: std::error::Error"
+    ))
+)]
 pub struct CommandOption {
     /// Whether the command supports autocomplete.
     ///
@@ -137,6 +149,7 @@ pub struct CommandOption {
     /// [`SubCommand`]: CommandOptionType::SubCommand
     /// [`SubCommandGroup`]: CommandOptionType::SubCommandGroup
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", omit_bounds, archive_attr(omit_bounds))]
     pub options: Option<Vec<CommandOption>>,
     /// Whether the option is required.
     ///
@@ -153,6 +166,12 @@ pub struct CommandOption {
 
 /// A predetermined choice users can select.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct CommandOptionChoice {
     /// Name of the choice. Must be 100 characters or less.
     pub name: String,
@@ -176,6 +195,12 @@ pub struct CommandOptionChoice {
 /// Note that the right variant must be selected based on the
 /// [`CommandOption`]'s [`CommandOptionType`].
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum CommandOptionChoiceValue {
     /// String choice. Must be 100 characters or less.
@@ -191,6 +216,12 @@ pub enum CommandOptionChoiceValue {
 /// Note that the right variant must be selected based on the
 /// [`CommandOption`]'s [`CommandOptionType`].
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum CommandOptionValue {
     /// Integer type.
@@ -201,6 +232,12 @@ pub enum CommandOptionValue {
 
 /// Type of a [`CommandOption`].
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum CommandOptionType {
@@ -4,6 +4,12 @@ use crate::guild::Emoji;
 
 /// List of application emojis
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct EmojiList {
     /// List of application emojis
     pub items: Vec<Emoji>,
@@ -6,10 +6,14 @@ mod option;
 
 pub use self::option::{CommandDataOption, CommandOptionValue};
 
+use self::option::{find_option, leaf_options};
 use crate::{
     application::{command::CommandType, interaction::InteractionDataResolved},
     id::{
-        marker::{CommandMarker, GenericMarker, GuildMarker},
+        marker::{
+            AttachmentMarker, ChannelMarker, CommandMarker, GenericMarker, GuildMarker, RoleMarker,
+            UserMarker,
+        },
         Id,
     },
 };
@@ -24,6 +28,12 @@ use serde::{Deserialize, Serialize};
 /// [`ApplicationCommandAutocomplete`]: crate::application::interaction::InteractionType::ApplicationCommandAutocomplete
 /// [Discord Docs/Application Command Data Structure]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-data-structure
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct CommandData {
     /// ID of the guild the command is registered to.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -45,3 +55,182 @@ pub struct CommandData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_id: Option<Id<GenericMarker>>,
 }
+
+impl CommandData {
+    /// Value of an option by name.
+    ///
+    /// Options of the invoked subcommand are searched if the command has
+    /// subcommands or subcommand groups, as only one of them is invoked at a
+    /// time. Refer to [`subcommand_path`] for which one was invoked.
+    ///
+    /// # Examples
+    ///
+    /// Get the options of a `/ban user:<user> days:<integer>` command:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     application::{
+    ///         command::CommandType,
+    ///         interaction::application_command::{
+    ///             CommandData, CommandDataOption, CommandOptionValue,
+    ///         },
+    ///     },
+    ///     id::Id,
+    /// };
+    ///
+    /// let data = CommandData {
+    ///     guild_id: None,
+    ///     id: Id::new(1),
+    ///     name: "ban".to_owned(),
+    ///     kind: CommandType::ChatInput,
+    ///     options: vec![
+    ///         CommandDataOption {
+    ///             name: "user".to_owned(),
+    ///             value: CommandOptionValue::User(Id::new(2)),
+    ///         },
+    ///         CommandDataOption {
+    ///             name: "days".to_owned(),
+    ///             value: CommandOptionValue::Integer(7),
+    ///         },
+    ///     ],
+    ///     resolved: None,
+    ///     target_id: None,
+    /// };
+    ///
+    /// assert_eq!(Some(Id::new(2)), data.get_user("user"));
+    /// assert_eq!(Some(7), data.get_integer("days"));
+    /// assert_eq!(None, data.get_string("days"));
+    /// assert_eq!(None, data.get_string("reason"));
+    /// ```
+    ///
+    /// [`subcommand_path`]: Self::subcommand_path
+    pub fn get(&self, name: &str) -> Option<&CommandOptionValue> {
+        find_option(&self.options, name)
+    }
+
+    /// Value of an attachment option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_attachment(&self, name: &str) -> Option<Id<AttachmentMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_attachment)
+    }
+
+    /// Value of a boolean option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_boolean(&self, name: &str) -> Option<bool> {
+        self.get(name).and_then(CommandOptionValue::as_boolean)
+    }
+
+    /// Value of a channel option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_channel(&self, name: &str) -> Option<Id<ChannelMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_channel)
+    }
+
+    /// Value of an integer option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_integer(&self, name: &str) -> Option<i64> {
+        self.get(name).and_then(CommandOptionValue::as_integer)
+    }
+
+    /// Value of a mentionable option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_mentionable(&self, name: &str) -> Option<Id<GenericMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_mentionable)
+    }
+
+    /// Value of a number option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_number(&self, name: &str) -> Option<f64> {
+        self.get(name).and_then(CommandOptionValue::as_number)
+    }
+
+    /// Value of a role option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_role(&self, name: &str) -> Option<Id<RoleMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_role)
+    }
+
+    /// Value of a string option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(CommandOptionValue::as_str)
+    }
+
+    /// Value of a user option by name.
+    ///
+    /// The user itself may be retrieved from the [`resolved`] data.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    /// [`resolved`]: Self::resolved
+    pub fn get_user(&self, name: &str) -> Option<Id<UserMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_user)
+    }
+
+    /// Options of the invoked subcommand, or of the command itself if it has
+    /// no subcommands.
+    pub fn leaf_options(&self) -> &[CommandDataOption] {
+        leaf_options(&self.options)
+    }
+
+    /// Names of the invoked subcommand group and subcommand, in order.
+    ///
+    /// Empty if the command has no subcommands.
+    ///
+    /// # Examples
+    ///
+    /// Dispatch a `/config role set` command:
+    ///
+    /// ```no_run
+    /// # use twilight_model::application::interaction::application_command::CommandData;
+    /// # fn handle(data: &CommandData) {
+    /// match (data.name.as_str(), data.subcommand_path().as_slice()) {
+    ///     ("config", ["role", "set"]) => {
+    ///         let role_id = data.get_role("role");
+    ///     }
+    ///     ("config", ["role", "reset"]) => {}
+    ///     _ => {}
+    /// }
+    /// # }
+    /// ```
+    pub fn subcommand_path(&self) -> Vec<&str> {
+        let mut path = Vec::new();
+        let mut options = self.options.as_slice();
+
+        while let [option] = options {
+            let Some(nested) = option.value.options() else {
+                break;
+            };
+
+            path.push(option.name.as_str());
+            options = nested;
+        }
+
+        path
+    }
+}
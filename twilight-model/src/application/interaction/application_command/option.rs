@@ -18,6 +18,12 @@ use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 ///
 /// [Discord Docs/Application Command Object]: https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-interaction-data-option-structure
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct CommandDataOption {
     /// Name of the option.
     pub name: String,
@@ -25,6 +31,98 @@ pub struct CommandDataOption {
     pub value: CommandOptionValue,
 }
 
+impl CommandDataOption {
+    /// Value of an option of the subcommand or subcommand group by name.
+    ///
+    /// Descends into the invoked subcommand if this is a subcommand group.
+    /// Returns [`None`] if this isn't a subcommand or subcommand group or it
+    /// has no option with the name.
+    pub fn get(&self, name: &str) -> Option<&CommandOptionValue> {
+        find_option(self.value.options().unwrap_or_default(), name)
+    }
+
+    /// Value of an attachment option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_attachment(&self, name: &str) -> Option<Id<AttachmentMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_attachment)
+    }
+
+    /// Value of a boolean option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_boolean(&self, name: &str) -> Option<bool> {
+        self.get(name).and_then(CommandOptionValue::as_boolean)
+    }
+
+    /// Value of a channel option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_channel(&self, name: &str) -> Option<Id<ChannelMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_channel)
+    }
+
+    /// Value of an integer option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_integer(&self, name: &str) -> Option<i64> {
+        self.get(name).and_then(CommandOptionValue::as_integer)
+    }
+
+    /// Value of a mentionable option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_mentionable(&self, name: &str) -> Option<Id<GenericMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_mentionable)
+    }
+
+    /// Value of a number option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_number(&self, name: &str) -> Option<f64> {
+        self.get(name).and_then(CommandOptionValue::as_number)
+    }
+
+    /// Value of a role option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_role(&self, name: &str) -> Option<Id<RoleMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_role)
+    }
+
+    /// Value of a string option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(CommandOptionValue::as_str)
+    }
+
+    /// Value of a user option by name.
+    ///
+    /// Refer to [`get`] for how the option is looked up.
+    ///
+    /// [`get`]: Self::get
+    pub fn get_user(&self, name: &str) -> Option<Id<UserMarker>> {
+        self.get(name).and_then(CommandOptionValue::as_user)
+    }
+}
+
 impl Serialize for CommandDataOption {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let subcommand_is_empty = matches!(
@@ -323,6 +421,18 @@ impl<'de> Deserialize<'de> for CommandDataOption {
 
 /// Combined value and value type for a [`CommandDataOption`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(
+        bound(serialize = "__S: rkyv::ser::ScratchSpace + rkyv::ser::Serializer"),
+        check_bytes
+    ),
+    archive_attr(check_bytes(
+        bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: std::error::Error"
+    ))
+)]
 pub enum CommandOptionValue {
     /// Attachment option.
     Attachment(Id<AttachmentMarker>),
@@ -353,9 +463,15 @@ pub enum CommandOptionValue {
     /// String option.
     String(String),
     /// Subcommand option.
-    SubCommand(Vec<CommandDataOption>),
+    SubCommand(
+        #[cfg_attr(feature = "rkyv", omit_bounds, archive_attr(omit_bounds))]
+        Vec<CommandDataOption>,
+    ),
     /// Subcommand group option.
-    SubCommandGroup(Vec<CommandDataOption>),
+    SubCommandGroup(
+        #[cfg_attr(feature = "rkyv", omit_bounds, archive_attr(omit_bounds))]
+        Vec<CommandDataOption>,
+    ),
     /// User option.
     User(Id<UserMarker>),
 }
@@ -377,6 +493,117 @@ impl CommandOptionValue {
             CommandOptionValue::User(_) => CommandOptionType::User,
         }
     }
+
+    /// ID of the attachment if this is an attachment option.
+    pub const fn as_attachment(&self) -> Option<Id<AttachmentMarker>> {
+        match self {
+            Self::Attachment(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Value if this is a boolean option.
+    pub const fn as_boolean(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// ID of the channel if this is a channel option.
+    pub const fn as_channel(&self) -> Option<Id<ChannelMarker>> {
+        match self {
+            Self::Channel(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Value if this is an integer option.
+    pub const fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// ID of the user or role if this is a mentionable option.
+    pub const fn as_mentionable(&self) -> Option<Id<GenericMarker>> {
+        match self {
+            Self::Mentionable(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Value if this is a number option.
+    pub const fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// ID of the role if this is a role option.
+    pub const fn as_role(&self) -> Option<Id<RoleMarker>> {
+        match self {
+            Self::Role(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Value if this is a string option.
+    ///
+    /// Focused options of autocomplete interactions are not included, even if
+    /// they are string options.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// ID of the user if this is a user option.
+    pub const fn as_user(&self) -> Option<Id<UserMarker>> {
+        match self {
+            Self::User(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Options if this is a subcommand or subcommand group option.
+    pub fn options(&self) -> Option<&[CommandDataOption]> {
+        match self {
+            Self::SubCommand(options) | Self::SubCommandGroup(options) => Some(options),
+            _ => None,
+        }
+    }
+}
+
+/// Options of the invoked subcommand, descending into subcommand groups and
+/// subcommands.
+///
+/// Returns the options themselves if none of them is a subcommand or
+/// subcommand group.
+pub(super) fn leaf_options(mut options: &[CommandDataOption]) -> &[CommandDataOption] {
+    while let [CommandDataOption {
+        value: CommandOptionValue::SubCommand(nested) | CommandOptionValue::SubCommandGroup(nested),
+        ..
+    }] = options
+    {
+        options = nested;
+    }
+
+    options
+}
+
+/// Value of an option of the invoked subcommand by name.
+pub(super) fn find_option<'a>(
+    options: &'a [CommandDataOption],
+    name: &str,
+) -> Option<&'a CommandOptionValue> {
+    leaf_options(options)
+        .iter()
+        .find(|option| option.name == name)
+        .map(|option| &option.value)
 }
 
 #[cfg(test)]
@@ -707,4 +934,55 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn typed_getters() {
+        let option = |name: &str, value| CommandDataOption {
+            name: name.to_owned(),
+            value,
+        };
+        let data = CommandData {
+            guild_id: None,
+            id: Id::new(1),
+            name: "config".to_owned(),
+            kind: CommandType::ChatInput,
+            options: vec![option(
+                "role",
+                CommandOptionValue::SubCommandGroup(vec![option(
+                    "set",
+                    CommandOptionValue::SubCommand(vec![
+                        option("role", CommandOptionValue::Role(Id::new(2))),
+                        option("reason", CommandOptionValue::String("why".to_owned())),
+                        option("hoist", CommandOptionValue::Boolean(true)),
+                    ]),
+                )]),
+            )],
+            resolved: None,
+            target_id: None,
+        };
+
+        assert_eq!(vec!["role", "set"], data.subcommand_path());
+        assert_eq!(3, data.leaf_options().len());
+        assert_eq!(Some(Id::new(2)), data.get_role("role"));
+        assert_eq!(Some("why"), data.get_string("reason"));
+        assert_eq!(Some(true), data.get_boolean("hoist"));
+        assert_eq!(None, data.get_integer("hoist"));
+        assert_eq!(None, data.get_user("user"));
+
+        let group = &data.options[0];
+        assert_eq!(Some("why"), group.get_string("reason"));
+        assert_eq!(None, data.options[0].value.as_str());
+        assert_eq!(
+            None,
+            option("days", CommandOptionValue::Integer(7)).get("days")
+        );
+
+        let data = CommandData {
+            options: vec![option("days", CommandOptionValue::Integer(7))],
+            ..data
+        };
+        assert!(data.subcommand_path().is_empty());
+        assert_eq!(Some(7), data.get_integer("days"));
+        assert_eq!(None, data.get_number("days"));
+    }
 }
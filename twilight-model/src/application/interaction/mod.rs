@@ -44,6 +44,12 @@ use super::monetization::Entitlement;
 ///
 /// [Discord Docs/Interaction Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-structure
 #[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Interaction {
     /// App's permissions in the channel the interaction was sent from.
     ///
@@ -411,6 +417,12 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
 /// Additional [`Interaction`] data, such as the invoking user.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[serde(untagged)]
 pub enum InteractionData {
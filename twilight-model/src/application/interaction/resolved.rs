@@ -1,6 +1,6 @@
 use crate::{
     channel::{thread::ThreadMetadata, Attachment, ChannelType, Message},
-    guild::{MemberFlags, Permissions, Role},
+    guild::{Member, MemberFlags, PartialMember, Permissions, Role},
     id::{
         marker::{AttachmentMarker, ChannelMarker, MessageMarker, RoleMarker, UserMarker},
         Id,
@@ -18,6 +18,12 @@ use std::collections::hash_map::HashMap;
 /// [`ApplicationCommand`]: crate::application::interaction::InteractionType::ApplicationCommand
 /// [Discord Docs/Resolved Data Structure]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-resolved-data-structure
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct InteractionDataResolved {
     /// Map of resolved attachments.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
@@ -45,6 +51,12 @@ pub struct InteractionDataResolved {
 ///
 /// [`Interaction`]: crate::application::interaction::Interaction
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct InteractionChannel {
     /// ID of the channel.
     pub id: Id<ChannelMarker>,
@@ -69,6 +81,12 @@ pub struct InteractionChannel {
 ///
 /// [`Interaction`]: crate::application::interaction::Interaction
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct InteractionMember {
     /// Member's guild avatar.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -95,6 +113,62 @@ pub struct InteractionMember {
     pub roles: Vec<Id<RoleMarker>>,
 }
 
+impl InteractionMember {
+    /// Convert into a member of a user, such as the resolved user of the same
+    /// ID.
+    ///
+    /// Interaction members don't include whether the member is deafened or
+    /// muted, so [`deaf`] and [`mute`] default to `false`, and the
+    /// [`permissions`] are discarded.
+    ///
+    /// [`deaf`]: Member::deaf
+    /// [`mute`]: Member::mute
+    /// [`permissions`]: Self::permissions
+    #[must_use = "converting into a member has no effect if left unused"]
+    pub fn into_member(self, user: User) -> Member {
+        Member {
+            avatar: self.avatar,
+            communication_disabled_until: self.communication_disabled_until,
+            deaf: false,
+            flags: self.flags,
+            joined_at: self.joined_at,
+            mute: false,
+            nick: self.nick,
+            pending: self.pending,
+            premium_since: self.premium_since,
+            roles: self.roles,
+            user,
+        }
+    }
+}
+
+impl From<InteractionMember> for PartialMember {
+    /// Convert an interaction member into a partial member without a user.
+    ///
+    /// Interaction members don't include whether the member is deafened or
+    /// muted, so [`deaf`] and [`mute`] default to `false`, and whether the
+    /// member is [`pending`] is discarded.
+    ///
+    /// [`deaf`]: PartialMember::deaf
+    /// [`mute`]: PartialMember::mute
+    /// [`pending`]: InteractionMember::pending
+    fn from(member: InteractionMember) -> Self {
+        Self {
+            avatar: member.avatar,
+            communication_disabled_until: member.communication_disabled_until,
+            deaf: false,
+            flags: member.flags,
+            joined_at: member.joined_at,
+            mute: false,
+            nick: member.nick,
+            permissions: Some(member.permissions),
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{InteractionChannel, InteractionDataResolved, InteractionMember};
@@ -584,4 +658,50 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn interaction_member_conversions() {
+        let member = InteractionMember {
+            avatar: None,
+            communication_disabled_until: None,
+            flags: MemberFlags::empty(),
+            joined_at: None,
+            nick: Some("nick".to_owned()),
+            pending: true,
+            permissions: Permissions::SEND_MESSAGES,
+            premium_since: None,
+            roles: vec![Id::new(1)],
+        };
+        let user = User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            global_name: None,
+            id: Id::new(2),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+
+        let partial_member = PartialMember::from(member.clone());
+        assert_eq!(Some(Permissions::SEND_MESSAGES), partial_member.permissions);
+        assert_eq!(member.roles, partial_member.roles);
+        assert!(partial_member.user.is_none());
+
+        let full_member = member.into_member(user.clone());
+        assert!(full_member.pending);
+        assert!(!full_member.deaf && !full_member.mute);
+        assert_eq!(Some("nick"), full_member.nick.as_deref());
+        assert_eq!(user, full_member.user);
+    }
 }
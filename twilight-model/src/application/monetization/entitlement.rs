@@ -12,6 +12,12 @@ use super::entitlement_type::EntitlementType;
 
 /// Entitlements in Discord represent that a user or guild has access to a premium offering in your application.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Entitlement {
     /// ID of the parent application.
     pub application_id: Id<ApplicationMarker>,
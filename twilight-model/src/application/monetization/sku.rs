@@ -9,6 +9,12 @@ use super::{SkuFlags, SkuType};
 
 /// SKUs (stock-keeping units) in Discord represent premium offerings that can be made available to your application's users or guilds.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Sku {
     /// ID of the parent application.
     application_id: Id<ApplicationMarker>,
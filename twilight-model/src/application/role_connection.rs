@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Role connection of a user to an application.
+///
+/// Requires authentication with a bearer token with the
+/// [`ROLE_CONNECTIONS_WRITE`] scope to retrieve or update.
+///
+/// Refer to [Discord Docs/Application Role Connection Object][1] for more
+/// information.
+///
+/// [`ROLE_CONNECTIONS_WRITE`]: crate::oauth::scope::ROLE_CONNECTIONS_WRITE
+/// [1]: https://discord.com/developers/docs/resources/user#application-role-connection-object
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+pub struct ApplicationRoleConnection {
+    /// Mapping of application role connection metadata keys to their values
+    /// for the user.
+    pub metadata: HashMap<String, String>,
+    /// Vanity name of the platform the application is linked to.
+    pub platform_name: Option<String>,
+    /// Username of the user on the platform.
+    pub platform_username: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationRoleConnection;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Token};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashMap, fmt::Debug};
+
+    assert_fields!(
+        ApplicationRoleConnection: metadata,
+        platform_name,
+        platform_username
+    );
+    assert_impl_all!(
+        ApplicationRoleConnection: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn serde() {
+        let value = ApplicationRoleConnection {
+            metadata: HashMap::from([("level".to_owned(), "10".to_owned())]),
+            platform_name: Some("Equestria".to_owned()),
+            platform_username: None,
+        };
+
+        assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnection",
+                    len: 3,
+                },
+                Token::Str("metadata"),
+                Token::Map { len: Some(1) },
+                Token::Str("level"),
+                Token::Str("10"),
+                Token::MapEnd,
+                Token::Str("platform_name"),
+                Token::Some,
+                Token::Str("Equestria"),
+                Token::Str("platform_username"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
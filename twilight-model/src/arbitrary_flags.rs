@@ -0,0 +1,46 @@
+//! Implementations of [`arbitrary::Arbitrary`] for flags.
+
+use crate::{
+    application::monetization::SkuFlags,
+    channel::{message::MessageFlags, AttachmentFlags, ChannelFlags},
+    gateway::{presence::ActivityFlags, Intents},
+    guild::{MemberFlags, Permissions, RoleFlags, SystemChannelFlags},
+    oauth::ApplicationFlags,
+    user::UserFlags,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Implement [`Arbitrary`] for flags, creating them from arbitrary bits.
+///
+/// Unknown bits are truncated, as deserialization does, so that arbitrary
+/// flags match the ones Discord may send.
+macro_rules! arbitrary_flags {
+    ($($flags:ident: $bits:ty),+ $(,)?) => {
+        $(
+            impl<'a> Arbitrary<'a> for $flags {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    Ok(Self::from_bits_truncate(u.arbitrary()?))
+                }
+
+                fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                    <$bits as Arbitrary>::size_hint(depth)
+                }
+            }
+        )+
+    };
+}
+
+arbitrary_flags! {
+    ActivityFlags: u64,
+    ApplicationFlags: u64,
+    AttachmentFlags: u64,
+    ChannelFlags: u64,
+    Intents: u64,
+    MemberFlags: u64,
+    MessageFlags: u64,
+    Permissions: u64,
+    RoleFlags: u64,
+    SkuFlags: u64,
+    SystemChannelFlags: u64,
+    UserFlags: u64,
+}
@@ -0,0 +1,286 @@
+//! Implementations of [`rkyv`] traits for types that can't derive them.
+//!
+//! IDs are archived as their [`NonZeroU64`] value, timestamps as their Unix
+//! timestamp in microseconds, and flags as their bits, so that archived models
+//! are cheap to read without conversions. Payloads of unknown events are
+//! archived as JSON.
+
+use crate::{
+    application::monetization::SkuFlags,
+    channel::{message::MessageFlags, AttachmentFlags, ChannelFlags},
+    gateway::{presence::ActivityFlags, Intents},
+    guild::{MemberFlags, Permissions, RoleFlags, SystemChannelFlags},
+    id::{AnonymizableId, Id},
+    oauth::ApplicationFlags,
+    user::UserFlags,
+    util::Timestamp,
+};
+use rkyv::{
+    ser::Serializer,
+    string::{ArchivedString, StringResolver},
+    with::{ArchiveWith, DeserializeWith, SerializeWith},
+    Archive, Archived, Deserialize, Fallible, Serialize,
+};
+use serde_value::Value;
+use std::num::NonZeroU64;
+
+impl<T> Archive for Id<T> {
+    type Archived = Archived<NonZeroU64>;
+    type Resolver = ();
+
+    #[allow(unsafe_code)]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        // SAFETY: the caller upholds the contract of resolving the value.
+        unsafe { self.into_nonzero().resolve(pos, resolver, out) };
+    }
+}
+
+impl<S: Fallible + ?Sized, T> Serialize<S> for Id<T> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.into_nonzero().serialize(serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized, T> Deserialize<Id<T>, D> for Archived<NonZeroU64> {
+    fn deserialize(&self, deserializer: &mut D) -> Result<Id<T>, D::Error> {
+        Deserialize::<NonZeroU64, D>::deserialize(self, deserializer).map(Id::from)
+    }
+}
+
+/// Anonymized IDs are archived as 0.
+impl<T> Archive for AnonymizableId<T> {
+    type Archived = Archived<u64>;
+    type Resolver = ();
+
+    #[allow(unsafe_code)]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        let value = match self {
+            Self::Anonymized => 0,
+            Self::Id(id) => id.get(),
+        };
+
+        // SAFETY: the caller upholds the contract of resolving the value.
+        unsafe { value.resolve(pos, resolver, out) };
+    }
+}
+
+impl<S: Fallible + ?Sized, T> Serialize<S> for AnonymizableId<T> {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, T> Deserialize<AnonymizableId<T>, D> for Archived<u64> {
+    fn deserialize(&self, deserializer: &mut D) -> Result<AnonymizableId<T>, D::Error> {
+        let value = Deserialize::<u64, D>::deserialize(self, deserializer)?;
+
+        Ok(Id::new_checked(value).map_or(AnonymizableId::Anonymized, AnonymizableId::Id))
+    }
+}
+
+impl Archive for Timestamp {
+    type Archived = Archived<i64>;
+    type Resolver = ();
+
+    #[allow(unsafe_code)]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        // SAFETY: the caller upholds the contract of resolving the value.
+        unsafe { self.as_micros().resolve(pos, resolver, out) };
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Timestamp {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+/// Timestamps outside of the supported range, which can only occur in
+/// corrupted archives, are clamped into it.
+impl<D: Fallible + ?Sized> Deserialize<Timestamp, D> for Archived<i64> {
+    fn deserialize(&self, deserializer: &mut D) -> Result<Timestamp, D::Error> {
+        /// Unix timestamp in microseconds of -9999-01-01T00:00:00Z.
+        const MIN_MICROS: i64 = -377_705_116_800_000_000;
+        /// Unix timestamp in microseconds of 9999-12-31T23:59:59.999999Z.
+        const MAX_MICROS: i64 = 253_402_300_799_999_999;
+
+        let micros = Deserialize::<i64, D>::deserialize(self, deserializer)?;
+
+        Ok(Timestamp::from_micros(micros.clamp(MIN_MICROS, MAX_MICROS))
+            .expect("clamped timestamp is in range"))
+    }
+}
+
+/// Archive the payload of an unknown event as a JSON string.
+///
+/// Payloads are received as JSON, so they can always be serialized into it
+/// again. Payloads that fail to be deserialized are deserialized as
+/// [`Value::Unit`].
+pub(crate) struct JsonPayload;
+
+impl JsonPayload {
+    /// Serialize a payload as JSON.
+    fn json(value: &Value) -> String {
+        serde_json::to_string(value).unwrap_or_else(|_| "null".to_owned())
+    }
+}
+
+impl ArchiveWith<Value> for JsonPayload {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    #[allow(unsafe_code)]
+    unsafe fn resolve_with(
+        field: &Value,
+        pos: usize,
+        resolver: Self::Resolver,
+        out: *mut Self::Archived,
+    ) {
+        // SAFETY: the caller upholds the contract of resolving the value, and
+        // the JSON is equal to the one that was serialized.
+        unsafe { ArchivedString::resolve_from_str(&Self::json(field), pos, resolver, out) };
+    }
+}
+
+impl<S: Fallible + Serializer + ?Sized> SerializeWith<Value, S> for JsonPayload {
+    fn serialize_with(field: &Value, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(&Self::json(field), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<ArchivedString, Value, D> for JsonPayload {
+    fn deserialize_with(field: &ArchivedString, _: &mut D) -> Result<Value, D::Error> {
+        Ok(serde_json::from_str(field.as_str()).unwrap_or(Value::Unit))
+    }
+}
+
+/// Implement the [`rkyv`] traits for flags, archiving them as their bits and
+/// retaining unknown bits.
+macro_rules! archive_flags {
+    ($($flags:ident: $bits:ty),+ $(,)?) => {
+        $(
+            impl Archive for $flags {
+                type Archived = Archived<$bits>;
+                type Resolver = ();
+
+                #[allow(unsafe_code)]
+                unsafe fn resolve(
+                    &self,
+                    pos: usize,
+                    resolver: Self::Resolver,
+                    out: *mut Self::Archived,
+                ) {
+                    // SAFETY: the caller upholds the contract of resolving the
+                    // value.
+                    unsafe { self.bits().resolve(pos, resolver, out) };
+                }
+            }
+
+            impl<S: Fallible + ?Sized> Serialize<S> for $flags {
+                fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                    Ok(())
+                }
+            }
+
+            impl<D: Fallible + ?Sized> Deserialize<$flags, D> for Archived<$bits> {
+                fn deserialize(&self, deserializer: &mut D) -> Result<$flags, D::Error> {
+                    Deserialize::<$bits, D>::deserialize(self, deserializer)
+                        .map($flags::from_bits_retain)
+                }
+            }
+        )+
+    };
+}
+
+archive_flags! {
+    ActivityFlags: u64,
+    ApplicationFlags: u64,
+    AttachmentFlags: u64,
+    ChannelFlags: u64,
+    Intents: u64,
+    MemberFlags: u64,
+    MessageFlags: u64,
+    Permissions: u64,
+    RoleFlags: u64,
+    SkuFlags: u64,
+    SystemChannelFlags: u64,
+    UserFlags: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::interaction::application_command::{CommandDataOption, CommandOptionValue},
+        gateway::{event::Event, payload::incoming::RoleDelete, CloseFrame},
+        guild::Permissions,
+        id::Id,
+        util::Timestamp,
+    };
+    use rkyv::{
+        de::deserializers::SharedDeserializeMap, validation::validators::DefaultValidator, Archive,
+        CheckBytes, Deserialize, Serialize,
+    };
+    use serde_value::Value;
+    use std::{collections::BTreeMap, fmt::Debug};
+
+    fn roundtrip<T>(value: &T)
+    where
+        T: Archive + Debug + PartialEq + Serialize<rkyv::ser::serializers::AllocSerializer<256>>,
+        T::Archived:
+            for<'a> CheckBytes<DefaultValidator<'a>> + Deserialize<T, SharedDeserializeMap>,
+    {
+        let bytes = rkyv::to_bytes::<_, 256>(value).unwrap();
+        let deserialized = rkyv::from_bytes::<T>(&bytes).unwrap();
+
+        assert_eq!(value, &deserialized);
+    }
+
+    #[test]
+    fn integers() {
+        let bytes = rkyv::to_bytes::<_, 16>(&Permissions::ADMINISTRATOR).unwrap();
+        let archived = rkyv::check_archived_root::<Permissions>(&bytes).unwrap();
+        assert_eq!(*archived, Permissions::ADMINISTRATOR.bits());
+
+        roundtrip(&Id::<crate::id::marker::GuildMarker>::new(1));
+        roundtrip(&Timestamp::from_secs(1_580_608_922).unwrap());
+        roundtrip(&Permissions::from_bits_retain(u64::MAX));
+    }
+
+    #[test]
+    fn event() {
+        roundtrip(&Event::RoleDelete(RoleDelete {
+            guild_id: Id::new(1),
+            role_id: Id::new(2),
+        }));
+        roundtrip(&Event::GatewayClose(Some(CloseFrame::RESUME)));
+    }
+
+    #[test]
+    fn unknown_event_payload() {
+        let mut map = BTreeMap::new();
+        map.insert(
+            Value::String("key".to_owned()),
+            Value::String("value".to_owned()),
+        );
+        map.insert(Value::String("number".to_owned()), Value::U64(1));
+
+        roundtrip(&Event::Unknown {
+            kind: "NEW_EVENT_TYPE".to_owned(),
+            payload: Value::Map(map),
+        });
+    }
+
+    #[test]
+    fn nested_option() {
+        roundtrip(&CommandDataOption {
+            name: "group".to_owned(),
+            value: CommandOptionValue::SubCommandGroup(Vec::from([CommandDataOption {
+                name: "subcommand".to_owned(),
+                value: CommandOptionValue::SubCommand(Vec::from([CommandDataOption {
+                    name: "user".to_owned(),
+                    value: CommandOptionValue::User(Id::new(3)),
+                }])),
+            }])),
+        });
+    }
+}
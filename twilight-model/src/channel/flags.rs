@@ -7,10 +7,18 @@ use serde::{
 bitflags! {
     #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
     pub struct ChannelFlags: u64 {
+        /// Channel has been removed from the guild's home feed.
+        const GUILD_FEED_REMOVED = 1;
         /// Channel is pinned in a forum.
         const PINNED = 1 << 1;
+        /// Channel has been removed from the guild's active channels.
+        const ACTIVE_CHANNELS_REMOVED = 1 << 2;
         /// New threads in a forum channel require a tag.
         const REQUIRE_TAG = 1 << 4;
+        /// Channel is a resource channel of the guild's server guide.
+        const IS_GUILD_RESOURCE_CHANNEL = 1 << 7;
+        /// Embedded media download options are hidden for media channels.
+        const HIDE_MEDIA_DOWNLOAD_OPTIONS = 1 << 15;
     }
 }
 
@@ -70,8 +78,12 @@ mod tests {
         Sync,
         UpperHex
     );
+    const_assert_eq!(ChannelFlags::GUILD_FEED_REMOVED.bits(), 1);
     const_assert_eq!(ChannelFlags::PINNED.bits(), 1 << 1);
+    const_assert_eq!(ChannelFlags::ACTIVE_CHANNELS_REMOVED.bits(), 1 << 2);
     const_assert_eq!(ChannelFlags::REQUIRE_TAG.bits(), 1 << 4);
+    const_assert_eq!(ChannelFlags::IS_GUILD_RESOURCE_CHANNEL.bits(), 1 << 7);
+    const_assert_eq!(ChannelFlags::HIDE_MEDIA_DOWNLOAD_OPTIONS.bits(), 1 << 15);
 
     #[test]
     fn serde() {
@@ -79,6 +91,10 @@ mod tests {
             &ChannelFlags::PINNED,
             &[Token::U64(ChannelFlags::PINNED.bits())],
         );
+        serde_test::assert_tokens(
+            &(ChannelFlags::HIDE_MEDIA_DOWNLOAD_OPTIONS | ChannelFlags::GUILD_FEED_REMOVED),
+            &[Token::U64((1 << 15) | 1)],
+        );
         // Deserialization truncates unknown bits.
         serde_test::assert_de_tokens(&ChannelFlags::empty(), &[Token::U64(1 << 63)]);
     }
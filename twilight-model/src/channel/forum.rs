@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// Exactly one of `emoji_id` and `emoji_name` must be set.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct DefaultReaction {
     /// ID of custom guild emoji.
     ///
@@ -24,6 +30,12 @@ pub struct DefaultReaction {
 /// [channel]: super::Channel
 /// [forum]: super::ChannelType::GuildForum
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum ForumLayout {
@@ -75,6 +87,12 @@ impl From<ForumLayout> for u8 {
 /// [channel]: super::Channel
 /// [forum]: super::ChannelType::GuildForum
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum ForumSortOrder {
@@ -123,6 +141,12 @@ impl From<ForumSortOrder> for u8 {
 /// [`Channel`]: super::Channel
 /// [`GuildForum`]: super::ChannelType::GuildForum
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct ForumTag {
     /// ID of custom guild emoji.
     ///
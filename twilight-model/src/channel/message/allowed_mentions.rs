@@ -20,6 +20,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Message Formatting]: https://discord.com/developers/docs/reference#message-formatting
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct AllowedMentions {
     /// List of allowed mention types.
     ///
@@ -45,6 +51,12 @@ pub struct AllowedMentions {
 
 /// Allowed mention type.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum MentionType {
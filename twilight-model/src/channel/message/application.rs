@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Application`]: crate::oauth::Application
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct MessageApplication {
     /// Default rich presence invite cover image.
     #[serde(skip_serializing_if = "Option::is_none")]
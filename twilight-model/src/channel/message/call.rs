@@ -7,6 +7,12 @@ use serde::{Deserialize, Serialize};
 
 /// Information about the call in a private channel.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct MessageCall {
     /// The timestamp of when the call ended.
     pub ended_timestamp: Option<Timestamp>,
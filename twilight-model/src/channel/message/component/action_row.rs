@@ -2,8 +2,21 @@ use super::Component;
 
 /// Non-interactive [`Component`] container of other (non action row) components.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(
+        bound(serialize = "__S: rkyv::ser::ScratchSpace + rkyv::ser::Serializer"),
+        check_bytes
+    ),
+    archive_attr(check_bytes(
+        bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: std::error::Error"
+    ))
+)]
 pub struct ActionRow {
     /// List of components in the action row.
+    #[cfg_attr(feature = "rkyv", omit_bounds, archive_attr(omit_bounds))]
     pub components: Vec<Component>,
 }
 
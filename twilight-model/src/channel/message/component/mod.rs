@@ -116,6 +116,12 @@ use std::fmt::{Formatter, Result as FmtResult};
 /// });
 /// ```
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub enum Component {
     /// Top level, non-interactive container of other (non action row) components.
     ActionRow(ActionRow),
@@ -7,6 +7,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Component`]: super::Component
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct SelectMenu {
     /// An optional list of channel types.
     ///
@@ -36,6 +42,12 @@ pub struct SelectMenu {
 
 /// A [`SelectMenu`]'s type.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 pub enum SelectMenuType {
     /// Select menus with a text-based `options` list.
@@ -55,6 +67,12 @@ pub enum SelectMenuType {
 
 /// Dropdown options that are part of [`SelectMenu`].
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct SelectMenuOption {
     /// Whether the option will be selected by default.
     #[serde(default)]
@@ -74,6 +92,12 @@ pub struct SelectMenuOption {
 
 /// A default value for an auto-populated select menu.
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(tag = "type", content = "id", rename_all = "snake_case")]
 pub enum SelectDefaultValue {
     /// Default user.
@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 
 /// Associated interaction metadata.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct MessageInteraction {
     /// ID of the interaction.
     pub id: Id<InteractionMarker>,
@@ -57,6 +57,18 @@ use serde::{Deserialize, Serialize};
 
 /// Text message sent in a [`Channel`].
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(
+        bound(serialize = "__S: rkyv::ser::ScratchSpace + rkyv::ser::Serializer"),
+        check_bytes
+    ),
+    archive_attr(check_bytes(
+        bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: std::error::Error"
+    ))
+)]
 pub struct Message {
     /// Present with Rich Presence-related chat embeds.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,6 +198,7 @@ pub struct Message {
     ///
     /// [`reference`]: Self::reference
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", omit_bounds, archive_attr(omit_bounds))]
     pub referenced_message: Option<Box<Message>>,
     /// Information about the role subscription purchase or renewal that
     /// prompted this message.
@@ -6,6 +6,12 @@ use serde::{Deserialize, Serialize};
 
 /// Reaction below a message.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Reaction {
     /// HEX colors used for super reaction.
     pub burst_colors: Vec<HexColor>,
@@ -23,6 +29,12 @@ pub struct Reaction {
 
 /// Type of emoji in a [`Reaction`].
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum EmojiReactionType {
     /// Custom [`Emoji`].
@@ -57,6 +69,12 @@ pub enum EmojiReactionType {
 
 /// Breakdown of normal and super reaction counts for the associated emoji.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct ReactionCountDetails {
     /// Count of super reactions.
     pub burst: u64,
@@ -5,6 +5,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Message`]: super::Message
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct RoleSubscriptionData {
     /// Whether this notification is for a renewal rather than a new purchase.
     pub is_renewal: bool,
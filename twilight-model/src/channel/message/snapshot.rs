@@ -10,6 +10,12 @@ use serde::{Deserialize, Serialize};
 
 /// The snap-shot of a message.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct MessageSnapshot {
     /// Subset of fields in the message object.
     message: MessageSnapshotFields,
@@ -20,6 +26,12 @@ pub struct MessageSnapshot {
 
 /// A subset of the fields for a message that has been snap-shotted.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct MessageSnapshotFields {
     /// List of attachments from the message snapshot.
     pub attachments: Vec<Attachment>,
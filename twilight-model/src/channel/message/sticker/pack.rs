@@ -9,6 +9,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Standard`]: super::StickerType::Standard
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct StickerPack {
     /// ID of the sticker pack's banner image.
     pub banner_asset_id: Option<Id<StickerBannerAssetMarker>>,
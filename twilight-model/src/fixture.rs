@@ -0,0 +1,330 @@
+//! Constructors of large models with defaults, for use in unit tests.
+//!
+//! Models received from Discord have many fields, most of which are
+//! irrelevant to any one test. The constructors in this module fill in
+//! defaults for all of them, only taking the IDs that relate models together,
+//! so that tests can set only the fields they care about.
+//!
+//! Defaults are the values of a minimal model: optional fields are [`None`],
+//! lists are empty, flags are empty, and booleans are `false`. Timestamps are
+//! set to [`TIMESTAMP_SECS`].
+//!
+//! # Examples
+//!
+//! Create a message sent in a guild by a user:
+//!
+//! ```
+//! use twilight_model::{fixture, id::Id};
+//!
+//! let user_id = Id::new(3);
+//!
+//! let mut message = fixture::message(Id::new(1), Id::new(2), fixture::user(user_id));
+//! message.content = "!ping".to_owned();
+//! message.guild_id = Some(Id::new(4));
+//! message.member = Some(fixture::partial_member());
+//!
+//! assert_eq!(user_id, message.author.id);
+//! ```
+
+use crate::{
+    application::interaction::{Interaction, InteractionType},
+    channel::{
+        message::{Message, MessageType},
+        Channel, ChannelType,
+    },
+    guild::{
+        AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, Member,
+        MemberFlags, MfaLevel, NSFWLevel, PartialMember, PremiumTier, SystemChannelFlags,
+        VerificationLevel,
+    },
+    id::{
+        marker::{
+            ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, MessageMarker,
+            UserMarker,
+        },
+        Id,
+    },
+    user::User,
+    util::Timestamp,
+};
+
+/// Unix timestamp in seconds of the timestamps of models.
+pub const TIMESTAMP_SECS: i64 = 1_632_072_645;
+
+/// Timestamp of models.
+fn timestamp() -> Timestamp {
+    Timestamp::from_secs(TIMESTAMP_SECS).expect("valid timestamp")
+}
+
+/// Create a channel of a type.
+///
+/// The channel isn't in a guild and has no name; set [`Channel::guild_id`]
+/// and [`Channel::name`] for a guild channel.
+pub const fn channel(id: Id<ChannelMarker>, kind: ChannelType) -> Channel {
+    Channel {
+        application_id: None,
+        applied_tags: None,
+        available_tags: None,
+        bitrate: None,
+        default_auto_archive_duration: None,
+        default_forum_layout: None,
+        default_reaction_emoji: None,
+        default_sort_order: None,
+        default_thread_rate_limit_per_user: None,
+        flags: None,
+        guild_id: None,
+        icon: None,
+        id,
+        invitable: None,
+        kind,
+        last_message_id: None,
+        last_pin_timestamp: None,
+        managed: None,
+        member: None,
+        member_count: None,
+        message_count: None,
+        name: None,
+        newly_created: None,
+        nsfw: None,
+        owner_id: None,
+        parent_id: None,
+        permission_overwrites: None,
+        position: None,
+        rate_limit_per_user: None,
+        recipients: None,
+        rtc_region: None,
+        thread_metadata: None,
+        topic: None,
+        user_limit: None,
+        video_quality_mode: None,
+    }
+}
+
+/// Create an available guild owned by a user.
+///
+/// The guild has no channels, members, roles, or other lists of entities.
+pub fn guild(id: Id<GuildMarker>, owner_id: Id<UserMarker>) -> Guild {
+    Guild {
+        afk_channel_id: None,
+        afk_timeout: AfkTimeout::FIVE_MINUTES,
+        application_id: None,
+        approximate_member_count: None,
+        approximate_presence_count: None,
+        banner: None,
+        channels: Vec::new(),
+        default_message_notifications: DefaultMessageNotificationLevel::All,
+        description: None,
+        discovery_splash: None,
+        emojis: Vec::new(),
+        explicit_content_filter: ExplicitContentFilter::None,
+        features: Vec::new(),
+        guild_scheduled_events: Vec::new(),
+        icon: None,
+        id,
+        joined_at: Some(timestamp()),
+        large: false,
+        max_members: None,
+        max_presences: None,
+        max_stage_video_channel_users: None,
+        max_video_channel_users: None,
+        member_count: None,
+        members: Vec::new(),
+        mfa_level: MfaLevel::None,
+        name: "guild".to_owned(),
+        nsfw_level: NSFWLevel::Default,
+        owner_id,
+        owner: None,
+        permissions: None,
+        preferred_locale: "en-US".to_owned(),
+        premium_progress_bar_enabled: false,
+        premium_subscription_count: None,
+        premium_tier: PremiumTier::None,
+        presences: Vec::new(),
+        public_updates_channel_id: None,
+        roles: Vec::new(),
+        rules_channel_id: None,
+        safety_alerts_channel_id: None,
+        splash: None,
+        stage_instances: Vec::new(),
+        stickers: Vec::new(),
+        system_channel_flags: SystemChannelFlags::empty(),
+        system_channel_id: None,
+        threads: Vec::new(),
+        unavailable: false,
+        vanity_url_code: None,
+        verification_level: VerificationLevel::None,
+        voice_states: Vec::new(),
+        widget_channel_id: None,
+        widget_enabled: None,
+    }
+}
+
+/// Create an interaction of a type.
+///
+/// The interaction has no data and wasn't invoked in a guild or by a user;
+/// set [`Interaction::guild_id`] and [`Interaction::member`], or
+/// [`Interaction::user`], for the context it was invoked in.
+#[allow(deprecated)]
+pub fn interaction(
+    id: Id<InteractionMarker>,
+    application_id: Id<ApplicationMarker>,
+    kind: InteractionType,
+) -> Interaction {
+    Interaction {
+        app_permissions: None,
+        application_id,
+        channel: None,
+        channel_id: None,
+        data: None,
+        entitlements: Vec::new(),
+        guild_id: None,
+        guild_locale: None,
+        id,
+        kind,
+        locale: None,
+        member: None,
+        message: None,
+        token: "token".to_owned(),
+        user: None,
+    }
+}
+
+/// Create a member of a guild from their user.
+pub fn member(user: User) -> Member {
+    Member {
+        avatar: None,
+        communication_disabled_until: None,
+        deaf: false,
+        flags: MemberFlags::empty(),
+        joined_at: Some(timestamp()),
+        mute: false,
+        nick: None,
+        pending: false,
+        premium_since: None,
+        roles: Vec::new(),
+        user,
+    }
+}
+
+/// Create a regular message sent by a user.
+///
+/// The message isn't in a guild and has no content; set
+/// [`Message::guild_id`] and [`Message::member`] for a message in a guild.
+pub fn message(id: Id<MessageMarker>, channel_id: Id<ChannelMarker>, author: User) -> Message {
+    Message {
+        activity: None,
+        application: None,
+        application_id: None,
+        attachments: Vec::new(),
+        author,
+        call: None,
+        channel_id,
+        components: Vec::new(),
+        content: String::new(),
+        edited_timestamp: None,
+        embeds: Vec::new(),
+        flags: None,
+        guild_id: None,
+        id,
+        interaction: None,
+        kind: MessageType::Regular,
+        member: None,
+        mention_channels: Vec::new(),
+        mention_everyone: false,
+        mention_roles: Vec::new(),
+        mentions: Vec::new(),
+        message_snapshots: Vec::new(),
+        pinned: false,
+        poll: None,
+        reactions: Vec::new(),
+        reference: None,
+        referenced_message: None,
+        role_subscription_data: None,
+        sticker_items: Vec::new(),
+        timestamp: timestamp(),
+        thread: None,
+        tts: false,
+        webhook_id: None,
+    }
+}
+
+/// Create a partial member of a guild, as included in messages and
+/// interactions.
+///
+/// The user of the member isn't included, as is the case for messages.
+pub fn partial_member() -> PartialMember {
+    PartialMember {
+        avatar: None,
+        communication_disabled_until: None,
+        deaf: false,
+        flags: MemberFlags::empty(),
+        joined_at: Some(timestamp()),
+        mute: false,
+        nick: None,
+        permissions: None,
+        premium_since: None,
+        roles: Vec::new(),
+        user: None,
+    }
+}
+
+/// Create a user who isn't a bot.
+pub fn user(id: Id<UserMarker>) -> User {
+    User {
+        accent_color: None,
+        avatar: None,
+        avatar_decoration: None,
+        avatar_decoration_data: None,
+        banner: None,
+        bot: false,
+        discriminator: 0,
+        email: None,
+        flags: None,
+        global_name: None,
+        id,
+        locale: None,
+        mfa_enabled: None,
+        name: "user".to_owned(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::interaction::{Interaction, InteractionType},
+        channel::{message::Message, Channel, ChannelType},
+        guild::{Guild, Member},
+        id::Id,
+        user::User,
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::fmt::Debug;
+
+    /// Assert that a model survives being serialized and deserialized, which
+    /// means that it is one that Discord could send.
+    fn assert_roundtrip<T: Debug + DeserializeOwned + PartialEq + Serialize>(value: &T) {
+        let json = serde_json::to_string(value).unwrap();
+
+        assert_eq!(value, &serde_json::from_str::<T>(&json).unwrap());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let user = super::user(Id::new(1));
+
+        assert_roundtrip::<Channel>(&super::channel(Id::new(2), ChannelType::GuildText));
+        assert_roundtrip::<Guild>(&super::guild(Id::new(3), user.id));
+        assert_roundtrip::<Interaction>(&super::interaction(
+            Id::new(4),
+            Id::new(5),
+            InteractionType::Ping,
+        ));
+        assert_roundtrip::<Member>(&super::member(user.clone()));
+        assert_roundtrip::<Message>(&super::message(Id::new(6), Id::new(2), user.clone()));
+        assert_roundtrip::<User>(&user);
+    }
+}
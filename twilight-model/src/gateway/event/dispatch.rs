@@ -1,8 +1,9 @@
 use super::{super::payload::incoming::*, Event, EventConversionError, EventType};
 use serde::{
-    de::{Deserialize, DeserializeSeed, Deserializer, Error as DeError, IgnoredAny},
-    Serialize,
+    de::{Deserialize, DeserializeSeed, Deserializer, IgnoredAny},
+    Serialize, Serializer,
 };
+use serde_value::Value;
 
 /// A dispatch event, containing information about a created guild, a member
 /// added, etc.
@@ -12,6 +13,12 @@ use serde::{
 // **NOTE**: When adding a variant, be sure to add it to the DeserializeSeed
 // implementation.
 #[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum DispatchEvent {
     AutoModerationActionExecution(AutoModerationActionExecution),
@@ -77,6 +84,18 @@ pub enum DispatchEvent {
     ThreadUpdate(Box<ThreadUpdate>),
     TypingStart(Box<TypingStart>),
     UnavailableGuild(UnavailableGuild),
+    /// Dispatch event type not yet modeled.
+    ///
+    /// Serializes as its payload.
+    #[serde(serialize_with = "serialize_unknown")]
+    Unknown {
+        /// Name of the event type.
+        kind: String,
+        /// Data of the event.
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = super::arbitrary_payload))]
+        #[cfg_attr(feature = "rkyv", with(crate::archive::JsonPayload))]
+        payload: Value,
+    },
     UserUpdate(UserUpdate),
     VoiceServerUpdate(VoiceServerUpdate),
     VoiceStateUpdate(Box<VoiceStateUpdate>),
@@ -150,6 +169,7 @@ impl DispatchEvent {
             Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
+            Self::Unknown { .. } => EventType::Unknown,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -158,6 +178,16 @@ impl DispatchEvent {
     }
 }
 
+/// Serialize an unknown dispatch event as its payload.
+#[allow(clippy::ptr_arg)]
+fn serialize_unknown<S: Serializer>(
+    _: &String,
+    payload: &Value,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    payload.serialize(serializer)
+}
+
 impl TryFrom<Event> for DispatchEvent {
     type Error = EventConversionError;
 
@@ -222,6 +252,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::ThreadUpdate(v) => Self::ThreadUpdate(v),
             Event::TypingStart(v) => Self::TypingStart(v),
             Event::UnavailableGuild(v) => Self::UnavailableGuild(v),
+            Event::Unknown { kind, payload } => Self::Unknown { kind, payload },
             Event::UserUpdate(v) => Self::UserUpdate(v),
             Event::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             Event::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
@@ -435,7 +466,10 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "WEBHOOKS_UPDATE" => {
                 DispatchEvent::WebhooksUpdate(WebhooksUpdate::deserialize(deserializer)?)
             }
-            other => return Err(DeError::unknown_variant(other, &[])),
+            other => DispatchEvent::Unknown {
+                kind: other.to_owned(),
+                payload: Value::deserialize(deserializer)?,
+            },
         })
     }
 }
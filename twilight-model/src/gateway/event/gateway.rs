@@ -19,6 +19,12 @@ use std::{
 /// An event from the gateway, which can either be a dispatch event with
 /// stateful updates or a heartbeat, hello, etc. that a shard needs to operate.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub enum GatewayEvent {
     Dispatch(u64, DispatchEvent),
     Heartbeat(u64),
@@ -376,7 +382,11 @@ impl Serialize for GatewayEvent {
         let mut s = serializer.serialize_struct("GatewayEvent", 4)?;
 
         if let Self::Dispatch(sequence, event) = self {
-            s.serialize_field("t", &event.kind())?;
+            if let DispatchEvent::Unknown { kind, .. } = event {
+                s.serialize_field("t", kind)?;
+            } else {
+                s.serialize_field("t", &event.kind())?;
+            }
             s.serialize_field("s", &sequence)?;
             s.serialize_field("op", &opcode(self))?;
             s.serialize_field("d", &event)?;
@@ -420,6 +430,8 @@ mod tests {
     use serde::de::DeserializeSeed;
     use serde_json::de::Deserializer;
     use serde_test::Token;
+    use serde_value::Value;
+    use std::collections::BTreeMap;
 
     #[test]
     fn deserialize_dispatch_role_delete() {
@@ -615,6 +627,38 @@ mod tests {
         assert!(matches!(event, GatewayEvent::Dispatch(_, _)));
     }
 
+    #[test]
+    fn deserialize_dispatch_unknown() {
+        let input = r#"{
+            "d": {
+                "id": "1"
+            },
+            "op": 0,
+            "s": 7,
+            "t": "NEW_EVENT"
+        }"#;
+
+        let deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        let GatewayEvent::Dispatch(7, DispatchEvent::Unknown { kind, payload }) = &event else {
+            panic!("expected unknown dispatch event: {event:?}");
+        };
+        assert_eq!("NEW_EVENT", kind);
+        assert_eq!(
+            &Value::Map(BTreeMap::from([(
+                Value::String("id".to_owned()),
+                Value::String("1".to_owned())
+            )])),
+            payload
+        );
+        assert_eq!(
+            r#"{"t":"NEW_EVENT","s":7,"op":0,"d":{"id":"1"}}"#,
+            serde_json::to_string(&event).unwrap()
+        );
+    }
+
     #[test]
     fn deserialize_heartbeat() {
         let input = r#"{
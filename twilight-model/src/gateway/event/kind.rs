@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 /// The type of an event.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EventType {
     AutoModerationActionExecution,
@@ -91,6 +97,9 @@ pub enum EventType {
     VoiceServerUpdate,
     VoiceStateUpdate,
     WebhooksUpdate,
+    /// Dispatch event type not yet modeled.
+    #[serde(other)]
+    Unknown,
 }
 
 impl EventType {
@@ -168,7 +177,8 @@ impl EventType {
             | Self::GatewayHeartbeatAck
             | Self::GatewayHello
             | Self::GatewayInvalidateSession
-            | Self::GatewayReconnect => None,
+            | Self::GatewayReconnect
+            | Self::Unknown => None,
         }
     }
 }
@@ -378,4 +388,15 @@ mod tests {
         assert_variant(EventType::WebhooksUpdate, "WEBHOOKS_UPDATE");
         assert_variant(EventType::EntitlementCreate, "ENTITLEMENT_CREATE");
     }
+
+    #[test]
+    fn unknown() {
+        serde_test::assert_de_tokens(
+            &EventType::Unknown,
+            &[Token::UnitVariant {
+                name: "EventType",
+                variant: "NEW_EVENT",
+            }],
+        );
+    }
 }
@@ -13,9 +13,21 @@ pub use self::{
 
 use super::{payload::incoming::*, CloseFrame};
 use crate::id::{marker::GuildMarker, Id};
+use serde_value::Value;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
+/// Create an arbitrary payload of an unknown event, a map of strings.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_payload(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Value> {
+    let map = u
+        .arbitrary_iter::<(String, String)>()?
+        .map(|entry| entry.map(|(key, value)| (Value::String(key), Value::String(value))))
+        .collect::<arbitrary::Result<_>>()?;
+
+    Ok(Value::Map(map))
+}
+
 /// Any type of event that a shard emits.
 ///
 /// Flattened type containing all the variants of [`DispatchEvent`] and
@@ -23,6 +35,12 @@ use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 ///
 /// [gateway close event]: Self::GatewayClose
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub enum Event {
     /// Message was blocked by AutoMod according to a rule.
     AutoModerationActionExecution(AutoModerationActionExecution),
@@ -171,6 +189,18 @@ pub enum Event {
     TypingStart(Box<TypingStart>),
     /// A guild is now unavailable.
     UnavailableGuild(UnavailableGuild),
+    /// A dispatch event type not yet modeled was received.
+    ///
+    /// Only received if the type was requested, such as via the gateway's
+    /// `EventTypeFlags::UNKNOWN`.
+    Unknown {
+        /// Name of the event type, such as `"NEW_EVENT_TYPE"`.
+        kind: String,
+        /// Data of the event.
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_payload))]
+        #[cfg_attr(feature = "rkyv", with(crate::archive::JsonPayload))]
+        payload: Value,
+    },
     /// The current user was updated.
     UserUpdate(UserUpdate),
     /// A voice server update was sent.
@@ -257,6 +287,7 @@ impl Event {
             | Event::GatewayReconnect
             | Event::Ready(_)
             | Event::Resumed
+            | Event::Unknown { .. }
             | Event::UserUpdate(_) => None,
         }
     }
@@ -332,6 +363,7 @@ impl Event {
             Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
+            Self::Unknown { .. } => EventType::Unknown,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -410,6 +442,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::ThreadUpdate(v) => Self::ThreadUpdate(v),
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
+            DispatchEvent::Unknown { kind, payload } => Self::Unknown { kind, payload },
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
             DispatchEvent::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             DispatchEvent::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
@@ -552,4 +585,22 @@ mod tests {
     const_assert!(mem::size_of::<WebhooksUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<MessagePollVoteAdd>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<MessagePollVoteRemove>() <= EVENT_THRESHOLD);
+
+    /// Test that arbitrary events, including deeply nested ones, can be
+    /// generated from any input.
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() -> arbitrary::Result<()> {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (0..4096_u32)
+            .map(|i| i.wrapping_mul(2_654_435_761).to_le_bytes()[2])
+            .collect::<Vec<_>>();
+
+        for start in (0..data.len()).step_by(256) {
+            Event::arbitrary(&mut Unstructured::new(&data[start..]))?;
+        }
+
+        Ok(())
+    }
 }
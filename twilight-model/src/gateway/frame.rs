@@ -17,13 +17,31 @@ use std::borrow::Cow;
 /// [causing a session resume]: CloseFrame::RESUME
 /// [full session disconnect]: CloseFrame::NORMAL
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct CloseFrame<'a> {
     /// Reason for the close.
     pub code: u16,
     /// Textual representation of the reason the connection is being closed.
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub reason: Cow<'a, str>,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CloseFrame<'_> {
+    /// Create an arbitrary close frame with an owned reason, so that frames
+    /// of any lifetime can be created.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            code: u.arbitrary()?,
+            reason: Cow::Owned(u.arbitrary()?),
+        })
+    }
+}
+
 impl<'a> CloseFrame<'a> {
     /// Normal close code indicating the shard will not be reconnecting soon.
     ///
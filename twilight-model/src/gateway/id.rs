@@ -92,6 +92,11 @@ pub enum ShardIdParseErrorType {
 ///
 /// [Discord Docs/Sharding]: https://discord.com/developers/docs/topics/gateway#sharding
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(try_from = "[u32; 2]", into = "[u32; 2]")]
 pub struct ShardId {
     /// Number of the shard, 0-indexed.
@@ -164,6 +169,17 @@ impl ShardId {
 /// Display the shard ID.
 ///
 /// Formats as `[{number}, {total}]`.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ShardId {
+    /// Create an arbitrary shard ID whose number is less than its total.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let total = u.int_in_range(1..=u32::MAX)?;
+        let number = u.int_in_range(0..=total - 1)?;
+
+        Self::new_checked(number, total).ok_or(arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Display for ShardId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_list()
@@ -13,6 +13,12 @@ use std::ops::{Deref, DerefMut};
 /// [`Event`]:crate::gateway::event::Event
 /// [`VIEW_AUDIT_LOG`]: crate::guild::Permissions::VIEW_AUDIT_LOG
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct GuildAuditLogEntryCreate(pub AuditLogEntry);
 
 impl Deref for GuildAuditLogEntryCreate {
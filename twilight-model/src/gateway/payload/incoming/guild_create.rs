@@ -7,6 +7,12 @@ use serde::{Deserialize, Serialize};
 // Developer note: Do not change order as we want unavailable to fail
 // first.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum GuildCreate {
     Unavailable(UnavailableGuild),
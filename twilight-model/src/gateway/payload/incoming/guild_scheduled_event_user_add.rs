@@ -6,6 +6,12 @@ use serde::{Deserialize, Serialize};
 
 /// Sent when a user has subscribed to a guild scheduled event.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct GuildScheduledEventUserAdd {
     /// Guild ID of the scheduled event.
     pub guild_id: Id<GuildMarker>,
@@ -7,6 +7,12 @@ use serde::{Deserialize, Serialize};
 /// Sent when a user votes on a poll. If the poll allows multiple selection,
 /// one event will be sent per answer.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct MessagePollVoteAdd {
     /// ID of the answer.
     pub answer_id: u8,
@@ -14,6 +14,12 @@ use std::ops::{Deref, DerefMut};
 /// [`Intents::GUILD_PRESENCES`]: crate::gateway::Intents
 /// [Discord Docs/Presence Update]: https://discord.com/developers/docs/topics/gateway#presence-update
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct PresenceUpdate(pub Presence);
 
 impl Deref for PresenceUpdate {
@@ -12,6 +12,12 @@ use serde::{
 use std::fmt::{Formatter, Result as FmtResult};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct ThreadMembersUpdate {
     /// List of thread members.
     ///
@@ -161,8 +167,12 @@ mod tests {
                 animated: None,
             }),
             party: None,
+            platform: None,
             secrets: None,
+            session_id: None,
             state: None,
+            supported_platforms: Vec::new(),
+            sync_id: None,
             timestamps: None,
             url: None,
         };
@@ -71,6 +71,12 @@ pub enum UserIdsErrorType {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct RequestGuildMembers {
     pub d: RequestGuildMembersInfo,
     pub op: OpCode,
@@ -87,6 +93,12 @@ impl RequestGuildMembers {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct RequestGuildMembersBuilder {
     guild_id: Id<GuildMarker>,
     nonce: Option<String>,
@@ -270,6 +282,12 @@ impl RequestGuildMembersBuilder {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct RequestGuildMembersInfo {
     /// Guild ID.
     pub guild_id: Id<GuildMarker>,
@@ -288,6 +306,12 @@ pub struct RequestGuildMembersInfo {
 
 /// One or a list of IDs in a request.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum RequestGuildMemberId<T> {
     /// Single ID specified.
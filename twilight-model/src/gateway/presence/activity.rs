@@ -1,13 +1,19 @@
 use crate::{
     gateway::presence::{
         ActivityAssets, ActivityButton, ActivityEmoji, ActivityFlags, ActivityParty,
-        ActivitySecrets, ActivityTimestamps, ActivityType,
+        ActivityPlatform, ActivitySecrets, ActivityTimestamps, ActivityType,
     },
     id::{marker::ApplicationMarker, Id},
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Activity {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
@@ -33,10 +39,23 @@ pub struct Activity {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub party: Option<ActivityParty>,
+    /// Platform the activity is played on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<ActivityPlatform>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub secrets: Option<ActivitySecrets>,
+    /// ID of the session of an embedded activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
+    /// Platforms the activity supports being played on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supported_platforms: Vec<ActivityPlatform>,
+    /// ID of the track or other media of the activity, such as for Spotify
+    /// listening activities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamps: Option<ActivityTimestamps>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,4 +65,73 @@ pub struct Activity {
 #[cfg(test)]
 mod tests {
     // Custom activities is tested by the custom presence test.
+
+    use super::Activity;
+    use crate::{
+        gateway::presence::{ActivityFlags, ActivityPlatform, ActivityType},
+        id::Id,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn embedded() {
+        let value = Activity {
+            application_id: Some(Id::new(1)),
+            assets: None,
+            buttons: Vec::new(),
+            created_at: Some(1_700_000_000_000),
+            details: None,
+            emoji: None,
+            flags: Some(ActivityFlags::EMBEDDED | ActivityFlags::PARTY_PRIVACY_VOICE_CHANNEL),
+            id: None,
+            instance: None,
+            kind: ActivityType::Playing,
+            name: "Watch Together".to_owned(),
+            party: None,
+            platform: Some(ActivityPlatform::Embedded),
+            secrets: None,
+            session_id: Some("session".to_owned()),
+            state: None,
+            supported_platforms: vec![ActivityPlatform::Embedded, ActivityPlatform::Ios],
+            sync_id: None,
+            timestamps: None,
+            url: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Activity",
+                    len: 8,
+                },
+                Token::Str("application_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("created_at"),
+                Token::Some,
+                Token::U64(1_700_000_000_000),
+                Token::Str("flags"),
+                Token::Some,
+                Token::U64(384),
+                Token::Str("type"),
+                Token::U8(0),
+                Token::Str("name"),
+                Token::Str("Watch Together"),
+                Token::Str("platform"),
+                Token::Some,
+                Token::Str("embedded"),
+                Token::Str("session_id"),
+                Token::Some,
+                Token::Str("session"),
+                Token::Str("supported_platforms"),
+                Token::Seq { len: Some(2) },
+                Token::Str("embedded"),
+                Token::Str("ios"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
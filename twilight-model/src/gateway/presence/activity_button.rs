@@ -50,6 +50,12 @@ use std::fmt::{Formatter, Result as FmtResult};
 /// # Ok(()) }
 /// ```
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub enum ActivityButton {
     /// Activity button is a link.
     Link(ActivityButtonLink),
@@ -183,6 +189,12 @@ impl Serialize for ActivityButton {
 
 /// Button used in an activity with a URL.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct ActivityButtonLink {
     /// Text shown on the button.
     pub label: String,
@@ -209,6 +221,12 @@ pub struct ActivityButtonLink {
 /// # Ok(()) }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(transparent)]
 pub struct ActivityButtonText {
     /// Text shown on the button.
@@ -7,14 +7,25 @@ use serde::{
 bitflags! {
     #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
     pub struct ActivityFlags: u64 {
+        /// Activity is an instanced game session.
         const INSTANCE = 1;
+        /// Activity can be joined.
         const JOIN = 1 << 1;
+        /// Activity can be spectated.
         const SPECTATE = 1 << 2;
+        /// Activity can be requested to be joined.
         const JOIN_REQUEST = 1 << 3;
+        /// Activity can be synced, such as listening along on Spotify.
         const SYNC = 1 << 4;
+        /// Activity can be played.
         const PLAY = 1 << 5;
+        /// Party of the activity can only be joined by friends.
         const PARTY_PRIVACY_FRIENDS = 1 << 6;
+        /// Party of the activity can only be joined by members of the voice
+        /// channel.
         const PARTY_PRIVACY_VOICE_CHANNEL = 1 << 7;
+        /// Activity is embedded within a Discord client, such as in a voice
+        /// channel.
         const EMBEDDED = 1 << 8;
     }
 }
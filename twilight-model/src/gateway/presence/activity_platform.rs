@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Platform an activity is played on or supports.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+#[non_exhaustive]
+#[serde(from = "String", into = "Cow<'static, str>")]
+pub enum ActivityPlatform {
+    /// Android devices.
+    Android,
+    /// Desktop clients.
+    Desktop,
+    /// Embedded within a Discord client, such as an activity in a voice
+    /// channel.
+    Embedded,
+    /// iOS devices.
+    Ios,
+    /// PS4 consoles.
+    PlayStation4,
+    /// PS5 consoles.
+    PlayStation5,
+    /// Samsung devices.
+    Samsung,
+    /// Xbox consoles.
+    Xbox,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl From<ActivityPlatform> for Cow<'static, str> {
+    fn from(value: ActivityPlatform) -> Self {
+        match value {
+            ActivityPlatform::Android => "android".into(),
+            ActivityPlatform::Desktop => "desktop".into(),
+            ActivityPlatform::Embedded => "embedded".into(),
+            ActivityPlatform::Ios => "ios".into(),
+            ActivityPlatform::PlayStation4 => "ps4".into(),
+            ActivityPlatform::PlayStation5 => "ps5".into(),
+            ActivityPlatform::Samsung => "samsung".into(),
+            ActivityPlatform::Xbox => "xbox".into(),
+            ActivityPlatform::Unknown(unknown) => unknown.into(),
+        }
+    }
+}
+
+impl From<String> for ActivityPlatform {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "android" => Self::Android,
+            "desktop" => Self::Desktop,
+            "embedded" => Self::Embedded,
+            "ios" => Self::Ios,
+            "ps4" => Self::PlayStation4,
+            "ps5" => Self::PlayStation5,
+            "samsung" => Self::Samsung,
+            "xbox" => Self::Xbox,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ActivityPlatform;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&ActivityPlatform::Android, &[Token::Str("android")]);
+        serde_test::assert_tokens(&ActivityPlatform::Desktop, &[Token::Str("desktop")]);
+        serde_test::assert_tokens(&ActivityPlatform::Embedded, &[Token::Str("embedded")]);
+        serde_test::assert_tokens(&ActivityPlatform::Ios, &[Token::Str("ios")]);
+        serde_test::assert_tokens(&ActivityPlatform::PlayStation4, &[Token::Str("ps4")]);
+        serde_test::assert_tokens(&ActivityPlatform::PlayStation5, &[Token::Str("ps5")]);
+        serde_test::assert_tokens(&ActivityPlatform::Samsung, &[Token::Str("samsung")]);
+        serde_test::assert_tokens(&ActivityPlatform::Xbox, &[Token::Str("xbox")]);
+        serde_test::assert_tokens(
+            &ActivityPlatform::Unknown("switch".to_owned()),
+            &[Token::Str("switch")],
+        );
+    }
+}
@@ -1,6 +1,12 @@
 use super::{Activity, ActivityType};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct MinimalActivity {
     pub kind: ActivityType,
     pub name: String,
@@ -22,8 +28,12 @@ impl From<MinimalActivity> for Activity {
             kind: minimal_activity.kind,
             name: minimal_activity.name,
             party: None,
+            platform: None,
             secrets: None,
+            session_id: None,
             state: None,
+            supported_platforms: Vec::new(),
+            sync_id: None,
             timestamps: None,
             url: minimal_activity.url,
         }
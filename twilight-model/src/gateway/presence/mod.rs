@@ -5,6 +5,7 @@ mod activity_assets;
 mod activity_emoji;
 mod activity_flags;
 mod activity_party;
+mod activity_platform;
 mod activity_secrets;
 mod activity_timestamps;
 mod activity_type;
@@ -15,9 +16,9 @@ mod status;
 pub use self::{
     activity::Activity, activity_assets::ActivityAssets, activity_button::ActivityButton,
     activity_emoji::ActivityEmoji, activity_flags::ActivityFlags, activity_party::ActivityParty,
-    activity_secrets::ActivitySecrets, activity_timestamps::ActivityTimestamps,
-    activity_type::ActivityType, client_status::ClientStatus, minimal_activity::MinimalActivity,
-    status::Status,
+    activity_platform::ActivityPlatform, activity_secrets::ActivitySecrets,
+    activity_timestamps::ActivityTimestamps, activity_type::ActivityType,
+    client_status::ClientStatus, minimal_activity::MinimalActivity, status::Status,
 };
 
 use crate::{
@@ -36,6 +37,12 @@ use serde::{
 use std::fmt::{Formatter, Result as FmtResult};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Presence {
     #[serde(default)]
     pub activities: Vec<Activity>,
@@ -46,6 +53,12 @@ pub struct Presence {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum UserOrId {
     User(User),
@@ -110,6 +123,12 @@ impl<'de> Visitor<'de> for PresenceVisitor {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct PresenceDeserializer(Id<GuildMarker>);
 
 impl PresenceDeserializer {
@@ -129,6 +148,12 @@ impl<'de> DeserializeSeed<'de> for PresenceDeserializer {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct PresenceListDeserializer(Id<GuildMarker>);
 
 impl PresenceListDeserializer {
@@ -198,8 +223,12 @@ mod tests {
                 animated: None,
             }),
             party: None,
+            platform: None,
             secrets: None,
+            session_id: None,
             state: None,
+            supported_platforms: Vec::new(),
+            sync_id: None,
             timestamps: None,
             url: None,
         };
@@ -15,6 +15,12 @@ use std::time::Duration;
 ///
 /// [`Guild::afk_timeout`]: super::Guild::afk_timeout
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 pub struct AfkTimeout(u16);
 
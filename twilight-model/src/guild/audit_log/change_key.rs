@@ -8,6 +8,12 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 /// [`AuditLogChange`]: super::AuditLogChange
 /// [1]: https://discord.com/developers/docs/resources/audit-log#audit-log-change-object-audit-log-change-key
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum AuditLogChangeKey {
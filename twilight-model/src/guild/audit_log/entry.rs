@@ -9,6 +9,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`AuditLog`]: super::AuditLog
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct AuditLogEntry {
     /// Type of event to cause the entry.
     pub action_type: AuditLogEventType,
@@ -4,6 +4,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`AuditLogEntry`]: super::AuditLogEntry
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(from = "u16", into = "u16")]
 pub enum AuditLogEventType {
     /// [Guild] was updated.
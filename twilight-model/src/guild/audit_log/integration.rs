@@ -11,6 +11,12 @@ use serde::{Deserialize, Serialize};
 /// [audit log]: super::AuditLog
 /// [guild integration]: super::super::GuildIntegration
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct AuditLogGuildIntegration {
     /// Account of the integration.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 /// Indicates in what event context a rule should be checked.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(from = "u8", into = "u8")]
 pub enum AutoModerationEventType {
     /// When a member sends or edits a message in a guild.
@@ -32,6 +32,12 @@ use serde::{Deserialize, Serialize};
 
 /// Configured auto moderation rule.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct AutoModerationRule {
     /// Actions which will execute when the rule is triggered.
     pub actions: Vec<AutoModerationAction>,
@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 /// Internally pre-defined wordsets which will be searched for in content.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(from = "u8", into = "u8")]
 pub enum AutoModerationKeywordPresetType {
     /// Words that may be considered forms of swearing or cursing.
@@ -7,6 +7,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`AutoModerationRule::trigger_type`]: super::AutoModerationRule::trigger_type
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct AutoModerationTriggerMetadata {
     /// Substrings that will be exempt from triggering the preset type.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 /// Characterizes the type of content which can trigger the rule.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[serde(from = "u8", into = "u8")]
 pub enum AutoModerationTriggerType {
     /// Check if content contains words from a user defined list of keywords.
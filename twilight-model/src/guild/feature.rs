@@ -9,6 +9,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Guild Features]: https://discord.com/developers/docs/resources/guild#guild-object-guild-features
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[serde(from = "String", into = "Cow<'static, str>")]
 pub enum GuildFeature {
@@ -16,6 +22,8 @@ pub enum GuildFeature {
     AnimatedBanner,
     /// Has access to set an animated guild icon.
     AnimatedIcon,
+    /// Is using the old permissions configuration behavior.
+    ApplicationCommandPermissionsV2,
     /// Has set up auto moderation rules.
     AutoModeration,
     /// Has access to set a guild banner image.
@@ -33,12 +41,16 @@ pub enum GuildFeature {
     Discoverable,
     /// Is able to be featured in the directory.
     Featurable,
+    /// Guests can be invited to voice channels without becoming members.
+    GuestsEnabled,
     /// Invites have been paused, this prevents new users from joining.
     InvitesDisabled,
     /// Has access to set an invite splash background.
     InviteSplash,
     /// Has enabled membership screening.
     MemberVerificationGateEnabled,
+    /// Has increased custom soundboard sound slots.
+    MoreSoundboard,
     /// Has increased custom sticker slots.
     MoreStickers,
     /// Has access to create news channels.
@@ -57,6 +69,8 @@ pub enum GuildFeature {
     RoleSubscriptionsAvailableForPurchase,
     /// Guild has enabled role subscriptions.
     RoleSubscriptionsEnabled,
+    /// Has created soundboard sounds.
+    Soundboard,
     /// Has enabled ticketed events.
     TicketedEventsEnabled,
     /// Has access to set a vanity URL.
@@ -76,6 +90,9 @@ impl From<GuildFeature> for Cow<'static, str> {
         match value {
             GuildFeature::AnimatedBanner => "ANIMATED_BANNER".into(),
             GuildFeature::AnimatedIcon => "ANIMATED_ICON".into(),
+            GuildFeature::ApplicationCommandPermissionsV2 => {
+                "APPLICATION_COMMAND_PERMISSIONS_V2".into()
+            }
             GuildFeature::AutoModeration => "AUTO_MODERATION".into(),
             GuildFeature::Banner => "BANNER".into(),
             GuildFeature::Community => "COMMUNITY".into(),
@@ -84,11 +101,13 @@ impl From<GuildFeature> for Cow<'static, str> {
             GuildFeature::DeveloperSupportServer => "DEVELOPER_SUPPORT_SERVER".into(),
             GuildFeature::Discoverable => "DISCOVERABLE".into(),
             GuildFeature::Featurable => "FEATURABLE".into(),
+            GuildFeature::GuestsEnabled => "GUESTS_ENABLED".into(),
             GuildFeature::InvitesDisabled => "INVITES_DISABLED".into(),
             GuildFeature::InviteSplash => "INVITE_SPLASH".into(),
             GuildFeature::MemberVerificationGateEnabled => {
                 "MEMBER_VERIFICATION_GATE_ENABLED".into()
             }
+            GuildFeature::MoreSoundboard => "MORE_SOUNDBOARD".into(),
             GuildFeature::MoreStickers => "MORE_STICKERS".into(),
             GuildFeature::News => "NEWS".into(),
             GuildFeature::Partnered => "PARTNERED".into(),
@@ -100,6 +119,7 @@ impl From<GuildFeature> for Cow<'static, str> {
                 "ROLE_SUBSCRIPTIONS_AVAILABLE_FOR_PURCHASE".into()
             }
             GuildFeature::RoleSubscriptionsEnabled => "ROLE_SUBSCRIPTIONS_ENABLED".into(),
+            GuildFeature::Soundboard => "SOUNDBOARD".into(),
             GuildFeature::TicketedEventsEnabled => "TICKETED_EVENTS_ENABLED".into(),
             GuildFeature::VanityUrl => "VANITY_URL".into(),
             GuildFeature::Verified => "VERIFIED".into(),
@@ -115,6 +135,7 @@ impl From<String> for GuildFeature {
         match value.as_str() {
             "ANIMATED_BANNER" => Self::AnimatedBanner,
             "ANIMATED_ICON" => Self::AnimatedIcon,
+            "APPLICATION_COMMAND_PERMISSIONS_V2" => Self::ApplicationCommandPermissionsV2,
             "AUTO_MODERATION" => Self::AutoModeration,
             "BANNER" => Self::Banner,
             "COMMUNITY" => Self::Community,
@@ -123,9 +144,11 @@ impl From<String> for GuildFeature {
             "DEVELOPER_SUPPORT_SERVER" => Self::DeveloperSupportServer,
             "DISCOVERABLE" => Self::Discoverable,
             "FEATURABLE" => Self::Featurable,
+            "GUESTS_ENABLED" => Self::GuestsEnabled,
             "INVITES_DISABLED" => Self::InvitesDisabled,
             "INVITE_SPLASH" => Self::InviteSplash,
             "MEMBER_VERIFICATION_GATE_ENABLED" => Self::MemberVerificationGateEnabled,
+            "MORE_SOUNDBOARD" => Self::MoreSoundboard,
             "MORE_STICKERS" => Self::MoreStickers,
             "NEWS" => Self::News,
             "PARTNERED" => Self::Partnered,
@@ -137,6 +160,7 @@ impl From<String> for GuildFeature {
                 GuildFeature::RoleSubscriptionsAvailableForPurchase
             }
             "ROLE_SUBSCRIPTIONS_ENABLED" => GuildFeature::RoleSubscriptionsEnabled,
+            "SOUNDBOARD" => Self::Soundboard,
             "TICKETED_EVENTS_ENABLED" => Self::TicketedEventsEnabled,
             "VANITY_URL" => Self::VanityUrl,
             "VERIFIED" => Self::Verified,
@@ -159,6 +183,10 @@ mod tests {
             &[Token::Str("ANIMATED_BANNER")],
         );
         serde_test::assert_tokens(&GuildFeature::AnimatedIcon, &[Token::Str("ANIMATED_ICON")]);
+        serde_test::assert_tokens(
+            &GuildFeature::ApplicationCommandPermissionsV2,
+            &[Token::Str("APPLICATION_COMMAND_PERMISSIONS_V2")],
+        );
         serde_test::assert_tokens(
             &GuildFeature::AutoModeration,
             &[Token::Str("AUTO_MODERATION")],
@@ -179,6 +207,10 @@ mod tests {
         );
         serde_test::assert_tokens(&GuildFeature::Discoverable, &[Token::Str("DISCOVERABLE")]);
         serde_test::assert_tokens(&GuildFeature::Featurable, &[Token::Str("FEATURABLE")]);
+        serde_test::assert_tokens(
+            &GuildFeature::GuestsEnabled,
+            &[Token::Str("GUESTS_ENABLED")],
+        );
         serde_test::assert_tokens(
             &GuildFeature::InvitesDisabled,
             &[Token::Str("INVITES_DISABLED")],
@@ -188,6 +220,10 @@ mod tests {
             &GuildFeature::MemberVerificationGateEnabled,
             &[Token::Str("MEMBER_VERIFICATION_GATE_ENABLED")],
         );
+        serde_test::assert_tokens(
+            &GuildFeature::MoreSoundboard,
+            &[Token::Str("MORE_SOUNDBOARD")],
+        );
         serde_test::assert_tokens(&GuildFeature::MoreStickers, &[Token::Str("MORE_STICKERS")]);
         serde_test::assert_tokens(&GuildFeature::News, &[Token::Str("NEWS")]);
         serde_test::assert_tokens(&GuildFeature::Partnered, &[Token::Str("PARTNERED")]);
@@ -199,6 +235,10 @@ mod tests {
             &GuildFeature::PrivateThreads,
             &[Token::Str("PRIVATE_THREADS")],
         );
+        serde_test::assert_tokens(
+            &GuildFeature::RaidAlertsDisabled,
+            &[Token::Str("RAID_ALERTS_DISABLED")],
+        );
         serde_test::assert_tokens(&GuildFeature::RoleIcons, &[Token::Str("ROLE_ICONS")]);
         serde_test::assert_tokens(
             &GuildFeature::RoleSubscriptionsAvailableForPurchase,
@@ -208,6 +248,7 @@ mod tests {
             &GuildFeature::RoleSubscriptionsEnabled,
             &[Token::Str("ROLE_SUBSCRIPTIONS_ENABLED")],
         );
+        serde_test::assert_tokens(&GuildFeature::Soundboard, &[Token::Str("SOUNDBOARD")]);
         serde_test::assert_tokens(
             &GuildFeature::TicketedEventsEnabled,
             &[Token::Str("TICKETED_EVENTS_ENABLED")],
@@ -20,6 +20,12 @@ pub use self::{
 
 /// The onboarding data for a guild.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Onboarding {
     /// Channel IDs that new members get opted into automatically
     pub default_channel_ids: Vec<Id<ChannelMarker>>,
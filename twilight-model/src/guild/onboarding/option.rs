@@ -13,6 +13,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Emoji`]: crate::guild::Emoji
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct OnboardingPromptEmoji {
     pub name: Option<String>,
     pub id: Option<Id<EmojiMarker>>,
@@ -32,6 +38,12 @@ impl From<Emoji> for OnboardingPromptEmoji {
 
 /// A prompt option for a guild onboarding screen.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct OnboardingPromptOption {
     /// Channels opted into when this option is selected.
     pub channel_ids: Vec<Id<ChannelMarker>>,
@@ -5,6 +5,12 @@ use super::{OnboardingPromptOption, OnboardingPromptType};
 
 /// A prompt in the onboarding flow.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct OnboardingPrompt {
     /// ID of the prompt.
     pub id: Id<OnboardingPromptMarker>,
@@ -1,5 +1,5 @@
 use crate::{
-    guild::Permissions,
+    guild::{Member, Permissions},
     id::{marker::RoleMarker, Id},
     user::User,
     util::{ImageHash, Timestamp},
@@ -9,6 +9,12 @@ use serde::{Deserialize, Serialize};
 use super::MemberFlags;
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct PartialMember {
     /// Member's guild avatar.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,12 +38,66 @@ pub struct PartialMember {
     pub user: Option<User>,
 }
 
+impl PartialMember {
+    /// Convert into a member of a user.
+    ///
+    /// The user is used in place of [`user`], which isn't sent in messages.
+    /// Partial members don't include whether the member is [`pending`], so it
+    /// defaults to `false`, and the [`permissions`] are discarded.
+    ///
+    /// [`pending`]: Member::pending
+    /// [`permissions`]: Self::permissions
+    /// [`user`]: Self::user
+    #[must_use = "converting into a member has no effect if left unused"]
+    pub fn into_member(self, user: User) -> Member {
+        Member {
+            avatar: self.avatar,
+            communication_disabled_until: self.communication_disabled_until,
+            deaf: self.deaf,
+            flags: self.flags,
+            joined_at: self.joined_at,
+            mute: self.mute,
+            nick: self.nick,
+            pending: false,
+            premium_since: self.premium_since,
+            roles: self.roles,
+            user,
+        }
+    }
+}
+
+impl From<Member> for PartialMember {
+    /// Convert a member into a partial member with its user.
+    ///
+    /// Partial members don't include whether the member is
+    /// [`pending`], so it's discarded, and the [`permissions`] are [`None`].
+    ///
+    /// [`pending`]: Member::pending
+    /// [`permissions`]: Self::permissions
+    fn from(member: Member) -> Self {
+        Self {
+            avatar: member.avatar,
+            communication_disabled_until: member.communication_disabled_until,
+            deaf: member.deaf,
+            flags: member.flags,
+            joined_at: member.joined_at,
+            mute: member.mute,
+            nick: member.nick,
+            permissions: None,
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user: Some(member.user),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PartialMember;
     use crate::{
-        guild::MemberFlags,
+        guild::{Member, MemberFlags},
         id::Id,
+        user::User,
         util::datetime::{Timestamp, TimestampParseError},
     };
     use serde_test::Token;
@@ -96,4 +156,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn member_conversions() {
+        let user = User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            global_name: None,
+            id: Id::new(2),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+        let member = Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: true,
+            flags: MemberFlags::DID_REJOIN,
+            joined_at: None,
+            mute: false,
+            nick: Some("nick".to_owned()),
+            pending: false,
+            premium_since: None,
+            roles: vec![Id::new(1)],
+            user: user.clone(),
+        };
+
+        let partial_member = PartialMember::from(member.clone());
+        assert_eq!(Some(&user), partial_member.user.as_ref());
+        assert!(partial_member.deaf);
+        assert!(partial_member.permissions.is_none());
+
+        assert_eq!(member, partial_member.into_member(user));
+    }
 }
@@ -86,6 +86,148 @@ bitflags! {
     }
 }
 
+/// Permissions and their names in the Discord API, in the order of their bits.
+const NAMES: [(Permissions, &str); 47] = [
+    (Permissions::CREATE_INVITE, "CREATE_INSTANT_INVITE"),
+    (Permissions::KICK_MEMBERS, "KICK_MEMBERS"),
+    (Permissions::BAN_MEMBERS, "BAN_MEMBERS"),
+    (Permissions::ADMINISTRATOR, "ADMINISTRATOR"),
+    (Permissions::MANAGE_CHANNELS, "MANAGE_CHANNELS"),
+    (Permissions::MANAGE_GUILD, "MANAGE_GUILD"),
+    (Permissions::ADD_REACTIONS, "ADD_REACTIONS"),
+    (Permissions::VIEW_AUDIT_LOG, "VIEW_AUDIT_LOG"),
+    (Permissions::PRIORITY_SPEAKER, "PRIORITY_SPEAKER"),
+    (Permissions::STREAM, "STREAM"),
+    (Permissions::VIEW_CHANNEL, "VIEW_CHANNEL"),
+    (Permissions::SEND_MESSAGES, "SEND_MESSAGES"),
+    (Permissions::SEND_TTS_MESSAGES, "SEND_TTS_MESSAGES"),
+    (Permissions::MANAGE_MESSAGES, "MANAGE_MESSAGES"),
+    (Permissions::EMBED_LINKS, "EMBED_LINKS"),
+    (Permissions::ATTACH_FILES, "ATTACH_FILES"),
+    (Permissions::READ_MESSAGE_HISTORY, "READ_MESSAGE_HISTORY"),
+    (Permissions::MENTION_EVERYONE, "MENTION_EVERYONE"),
+    (Permissions::USE_EXTERNAL_EMOJIS, "USE_EXTERNAL_EMOJIS"),
+    (Permissions::VIEW_GUILD_INSIGHTS, "VIEW_GUILD_INSIGHTS"),
+    (Permissions::CONNECT, "CONNECT"),
+    (Permissions::SPEAK, "SPEAK"),
+    (Permissions::MUTE_MEMBERS, "MUTE_MEMBERS"),
+    (Permissions::DEAFEN_MEMBERS, "DEAFEN_MEMBERS"),
+    (Permissions::MOVE_MEMBERS, "MOVE_MEMBERS"),
+    (Permissions::USE_VAD, "USE_VAD"),
+    (Permissions::CHANGE_NICKNAME, "CHANGE_NICKNAME"),
+    (Permissions::MANAGE_NICKNAMES, "MANAGE_NICKNAMES"),
+    (Permissions::MANAGE_ROLES, "MANAGE_ROLES"),
+    (Permissions::MANAGE_WEBHOOKS, "MANAGE_WEBHOOKS"),
+    (
+        Permissions::MANAGE_GUILD_EXPRESSIONS,
+        "MANAGE_GUILD_EXPRESSIONS",
+    ),
+    (Permissions::USE_SLASH_COMMANDS, "USE_APPLICATION_COMMANDS"),
+    (Permissions::REQUEST_TO_SPEAK, "REQUEST_TO_SPEAK"),
+    (Permissions::MANAGE_EVENTS, "MANAGE_EVENTS"),
+    (Permissions::MANAGE_THREADS, "MANAGE_THREADS"),
+    (Permissions::CREATE_PUBLIC_THREADS, "CREATE_PUBLIC_THREADS"),
+    (
+        Permissions::CREATE_PRIVATE_THREADS,
+        "CREATE_PRIVATE_THREADS",
+    ),
+    (Permissions::USE_EXTERNAL_STICKERS, "USE_EXTERNAL_STICKERS"),
+    (
+        Permissions::SEND_MESSAGES_IN_THREADS,
+        "SEND_MESSAGES_IN_THREADS",
+    ),
+    (
+        Permissions::USE_EMBEDDED_ACTIVITIES,
+        "USE_EMBEDDED_ACTIVITIES",
+    ),
+    (Permissions::MODERATE_MEMBERS, "MODERATE_MEMBERS"),
+    (
+        Permissions::VIEW_CREATOR_MONETIZATION_ANALYTICS,
+        "VIEW_CREATOR_MONETIZATION_ANALYTICS",
+    ),
+    (Permissions::USE_SOUNDBOARD, "USE_SOUNDBOARD"),
+    (Permissions::USE_EXTERNAL_SOUNDS, "USE_EXTERNAL_SOUNDS"),
+    (Permissions::SEND_VOICE_MESSAGES, "SEND_VOICE_MESSAGES"),
+    (Permissions::SEND_POLLS, "SEND_POLLS"),
+    (Permissions::USE_EXTERNAL_APPS, "USE_EXTERNAL_APPS"),
+];
+
+impl Permissions {
+    /// Parse a permission from its name in the Discord API, such as
+    /// `MANAGE_GUILD`.
+    ///
+    /// Names are those of the [Discord Docs/Permissions], which differ from the
+    /// names of the constants for [`CREATE_INVITE`] (`CREATE_INSTANT_INVITE`)
+    /// and [`USE_SLASH_COMMANDS`] (`USE_APPLICATION_COMMANDS`).
+    ///
+    /// Returns [`None`] if the name isn't of a known permission.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::guild::Permissions;
+    ///
+    /// assert_eq!(
+    ///     Some(Permissions::CREATE_INVITE),
+    ///     Permissions::from_discord_name("CREATE_INSTANT_INVITE"),
+    /// );
+    /// assert_eq!(None, Permissions::from_discord_name("manage_guild"));
+    /// ```
+    ///
+    /// [`CREATE_INVITE`]: Self::CREATE_INVITE
+    /// [`USE_SLASH_COMMANDS`]: Self::USE_SLASH_COMMANDS
+    /// [Discord Docs/Permissions]: https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags
+    pub fn from_discord_name(name: &str) -> Option<Self> {
+        NAMES
+            .iter()
+            .find(|(_, permission_name)| *permission_name == name)
+            .map(|(permission, _)| *permission)
+    }
+
+    /// Name of a single permission in the Discord API.
+    ///
+    /// Returns [`None`] if the permissions are empty, contain multiple
+    /// permissions, or are of an unknown permission.
+    ///
+    /// Refer to [`from_discord_name`] for the names.
+    ///
+    /// [`from_discord_name`]: Self::from_discord_name
+    pub fn discord_name(self) -> Option<&'static str> {
+        NAMES
+            .iter()
+            .find(|(permission, _)| *permission == self)
+            .map(|(_, name)| *name)
+    }
+
+    /// Iterate over the set permissions and their names in the Discord API, in
+    /// the order of their bits.
+    ///
+    /// Unknown permissions are skipped. Refer to [`from_discord_name`] for the
+    /// names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::guild::Permissions;
+    ///
+    /// let permissions = Permissions::USE_SLASH_COMMANDS | Permissions::KICK_MEMBERS;
+    /// let names = permissions
+    ///     .iter_discord_names()
+    ///     .map(|(name, _)| name)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(vec!["KICK_MEMBERS", "USE_APPLICATION_COMMANDS"], names);
+    /// ```
+    ///
+    /// [`from_discord_name`]: Self::from_discord_name
+    pub fn iter_discord_names(self) -> impl Iterator<Item = (&'static str, Self)> {
+        NAMES
+            .into_iter()
+            .filter(move |(permission, _)| self.contains(*permission))
+            .map(|(permission, name)| (name, permission))
+    }
+}
+
 struct PermissionsVisitor;
 
 impl<'de> Visitor<'de> for PermissionsVisitor {
@@ -213,6 +355,44 @@ mod tests {
     const_assert_eq!(Permissions::SEND_POLLS.bits(), 1 << 49);
     const_assert_eq!(Permissions::USE_EXTERNAL_APPS.bits(), 1 << 50);
 
+    #[test]
+    fn discord_names() {
+        let all = super::NAMES
+            .iter()
+            .fold(Permissions::empty(), |all, (permission, _)| {
+                all | *permission
+            });
+        assert_eq!(Permissions::all(), all);
+
+        for (permission, name) in super::NAMES {
+            assert_eq!(1, permission.bits().count_ones());
+            assert_eq!(Some(permission), Permissions::from_discord_name(name));
+            assert_eq!(Some(name), permission.discord_name());
+        }
+
+        assert_eq!(
+            Some("MANAGE_GUILD_EXPRESSIONS"),
+            Permissions::MANAGE_GUILD_EXPRESSIONS.discord_name()
+        );
+        assert_eq!(
+            None,
+            (Permissions::CONNECT | Permissions::SPEAK).discord_name()
+        );
+        assert_eq!(None, Permissions::empty().discord_name());
+        assert_eq!(None, Permissions::from_discord_name("USE_SLASH_COMMANDS"));
+
+        let permissions = Permissions::from_bits_retain(
+            (Permissions::SEND_POLLS | Permissions::CREATE_INVITE).bits() | 1 << 63,
+        );
+        assert_eq!(
+            vec![
+                ("CREATE_INSTANT_INVITE", Permissions::CREATE_INVITE),
+                ("SEND_POLLS", Permissions::SEND_POLLS),
+            ],
+            permissions.iter_discord_names().collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn serde() {
         serde_test::assert_tokens(&Permissions::CREATE_INVITE, &[Token::Str("1")]);
@@ -7,6 +7,12 @@ use serde::{Deserialize, Serialize};
 
 /// Container for user and member data returned by Discord.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct GuildScheduledEventUser {
     /// ID of the scheduled event.
     pub guild_scheduled_event_id: Id<ScheduledEventMarker>,
@@ -108,6 +108,11 @@ mod tests {
                 SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATION_REPLIES.bits(),
             )],
         );
+        serde_test::assert_tokens(
+            &(SystemChannelFlags::SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATIONS
+                | SystemChannelFlags::SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATION_REPLIES),
+            &[Token::U64((1 << 4) | (1 << 5))],
+        );
         // Deserialization truncates unknown bits.
         serde_test::assert_de_tokens(&SystemChannelFlags::empty(), &[Token::U64(1 << 63)]);
     }
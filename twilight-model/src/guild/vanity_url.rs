@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 /// Information about a guild's vanity URL setting.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct VanityUrl {
     /// Code of the vanity URL.
     ///
@@ -25,11 +25,28 @@ use serde::{Deserialize, Serialize};
 /// attachment.description("Raw data about Twilight Sparkle".to_owned());
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Attachment {
     /// Description of the attachment, useful for screen readers and users
     /// requiring alt text.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Duration of the audio file in milliseconds, if the attachment is a
+    /// voice message.
+    ///
+    /// Sent to Discord as `duration_secs`, in fractional seconds.
+    #[serde(
+        default,
+        rename = "duration_secs",
+        skip_serializing_if = "Option::is_none",
+        with = "duration_secs"
+    )]
+    pub duration_millis: Option<u64>,
     /// Content of the file.
     #[serde(skip)]
     pub file: Vec<u8>,
@@ -44,6 +61,10 @@ pub struct Attachment {
     /// to be in any particular format; for example, IDs of 0, 100, the current
     /// timestamp, and so on are all valid.
     pub id: u64,
+    /// Base64 encoded sampled waveform of the audio file, if the attachment is
+    /// a voice message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waveform: Option<String>,
 }
 
 impl Attachment {
@@ -65,9 +86,11 @@ impl Attachment {
     pub const fn from_bytes(filename: String, file: Vec<u8>, id: u64) -> Self {
         Self {
             description: None,
+            duration_millis: None,
             file,
             filename,
             id,
+            waveform: None,
         }
     }
 
@@ -79,3 +102,70 @@ impl Attachment {
         self.description = Some(description);
     }
 }
+
+/// (De)serialize a duration in milliseconds as fractional seconds.
+mod duration_secs {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        let Some(secs) = Option::<f64>::deserialize(deserializer)? else {
+            return Ok(None);
+        };
+
+        if !secs.is_finite() || secs < 0.0 {
+            return Err(D::Error::custom(
+                "duration must be a non-negative number of seconds",
+            ));
+        }
+
+        Ok(Some((secs * 1000.0).round() as u64))
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::ref_option,
+        clippy::trivially_copy_pass_by_ref
+    )]
+    pub fn serialize<S: Serializer>(
+        millis: &Option<u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match millis {
+            Some(millis) => serializer.serialize_some(&(*millis as f64 / 1000.0)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Attachment;
+    use serde_test::Token;
+
+    #[test]
+    fn duration_secs() {
+        let mut attachment = Attachment::from_bytes("voice.ogg".to_owned(), Vec::new(), 1);
+        attachment.duration_millis = Some(1500);
+
+        serde_test::assert_tokens(
+            &attachment,
+            &[
+                Token::Struct {
+                    name: "Attachment",
+                    len: 3,
+                },
+                Token::Str("duration_secs"),
+                Token::Some,
+                Token::F64(1.5),
+                Token::Str("filename"),
+                Token::Str("voice.ogg"),
+                Token::Str("id"),
+                Token::U64(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
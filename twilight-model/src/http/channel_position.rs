@@ -11,6 +11,12 @@ use crate::id::{marker::ChannelMarker, Id};
 /// The fields with `Option<Option<T>>` will be `null` if they have
 /// the form `Some(None)`, `None` will be skipped.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct Position {
     /// Channel id
     pub id: Id<ChannelMarker>,
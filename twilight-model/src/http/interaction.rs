@@ -15,6 +15,12 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 ///
 /// [Discord Docs/Interaction Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-structure
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct InteractionResponse {
     /// Type of the response.
     #[serde(rename = "type")]
@@ -37,6 +43,12 @@ pub struct InteractionResponse {
 
 /// Data included in an interaction response.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct InteractionResponseData {
     /// Allowed mentions of the response.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,6 +90,12 @@ pub struct InteractionResponseData {
 
 /// Type of interaction response.
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum InteractionResponseType {
@@ -196,9 +214,11 @@ mod tests {
             data: Some(InteractionResponseData {
                 attachments: Some(Vec::from([Attachment {
                     description: None,
+                    duration_millis: None,
                     file: "file data".into(),
                     filename: "filename.jpg".into(),
                     id: 1,
+                    waveform: None,
                 }])),
                 ..InteractionResponseData::default()
             }),
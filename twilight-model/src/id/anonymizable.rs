@@ -9,6 +9,17 @@ pub enum AnonymizableId<T> {
     Id(Id<T>),
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for AnonymizableId<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(if u.arbitrary()? {
+            Self::Id(Id::arbitrary(u)?)
+        } else {
+            Self::Anonymized
+        })
+    }
+}
+
 impl<T> Clone for AnonymizableId<T> {
     fn clone(&self) -> Self {
         *self
@@ -15,6 +15,8 @@
 //! - [`std::str::FromStr`]
 //! - [`std::convert::TryFrom`]\<i64>
 //! - [`std::convert::TryFrom`]\<u64>
+//! - [`std::convert::TryFrom`]\<&str> for channel, emoji, role, and user IDs,
+//!   which also accepts mentions
 //! - [`Id::new`]
 //! - [`Id::new_checked`]
 //! - [`Id::new_unchecked`]
@@ -47,6 +49,8 @@ mod anonymizable;
 
 pub use anonymizable::AnonymizableId;
 
+use self::marker::{ChannelMarker, EmojiMarker, RoleMarker, UserMarker};
+
 use serde::{
     de::{Deserialize, Deserializer, Error as DeError, Unexpected, Visitor},
     ser::{Serialize, Serializer},
@@ -218,6 +222,22 @@ impl<T> Clone for Id<T> {
 
 impl<T> Copy for Id<T> {}
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for Id<T> {
+    /// Create an arbitrary ID, which is 1 if the arbitrary value is 0.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let value = u64::arbitrary(u)?;
+
+        Ok(Self::from_nonzero(
+            NonZeroU64::new(value).unwrap_or(NonZeroU64::MIN),
+        ))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u64 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 impl<T> Debug for Id<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_str("Id")?;
@@ -409,6 +429,103 @@ impl<T> TryFrom<u64> for Id<T> {
     }
 }
 
+impl TryFrom<&str> for Id<ChannelMarker> {
+    type Error = ParseIntError;
+
+    /// Parse a channel ID from either its string or its mention, such as
+    /// `<#123>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIntError`] if the value isn't a valid ID or isn't a
+    /// channel mention.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str(strip_mention(value, &["#"]))
+    }
+}
+
+impl TryFrom<&str> for Id<EmojiMarker> {
+    type Error = ParseIntError;
+
+    /// Parse an emoji ID from either its string or its mention, such as
+    /// `<:name:123>` or `<a:name:123>` for animated emojis.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIntError`] if the value isn't a valid ID or isn't an
+    /// emoji mention.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let inner = strip_mention(value, &[":", "a:"]);
+
+        // The name of the emoji precedes the ID.
+        let id = if inner.len() == value.len() {
+            inner
+        } else {
+            inner.rsplit_once(':').map_or(inner, |(_, id)| id)
+        };
+
+        Self::from_str(id)
+    }
+}
+
+impl TryFrom<&str> for Id<RoleMarker> {
+    type Error = ParseIntError;
+
+    /// Parse a role ID from either its string or its mention, such as
+    /// `<@&123>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIntError`] if the value isn't a valid ID or isn't a
+    /// role mention.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str(strip_mention(value, &["@&"]))
+    }
+}
+
+impl TryFrom<&str> for Id<UserMarker> {
+    type Error = ParseIntError;
+
+    /// Parse a user ID from either its string or its mention, such as `<@123>`
+    /// or the legacy `<@!123>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::id::{marker::UserMarker, Id};
+    ///
+    /// assert_eq!(Ok(Id::new(123)), Id::<UserMarker>::try_from("<@123>"));
+    /// assert_eq!(Ok(Id::new(123)), Id::<UserMarker>::try_from("123"));
+    /// assert!(Id::<UserMarker>::try_from("<@&123>").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIntError`] if the value isn't a valid ID or isn't a
+    /// user mention.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str(strip_mention(value, &["@!", "@"]))
+    }
+}
+
+/// Strip the arrows and the first matching sigil of a mention.
+///
+/// Returns the value unchanged if it isn't a mention with one of the sigils,
+/// so that it's parsed as a plain ID.
+fn strip_mention<'a>(value: &'a str, sigils: &[&str]) -> &'a str {
+    let Some(inner) = value
+        .strip_prefix('<')
+        .and_then(|value| value.strip_suffix('>'))
+    else {
+        return value;
+    };
+
+    sigils
+        .iter()
+        .find_map(|sigil| inner.strip_prefix(sigil))
+        .unwrap_or(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -481,6 +598,23 @@ mod tests {
         assert!(Id::<GenericMarker>::from_str("0").is_err());
         assert!(Id::<GenericMarker>::from_str("123a").is_err());
 
+        // `TryFrom<&str>`
+        assert_eq!(123_u64, Id::<ChannelMarker>::try_from("123")?);
+        assert_eq!(123_u64, Id::<ChannelMarker>::try_from("<#123>")?);
+        assert!(Id::<ChannelMarker>::try_from("<@123>").is_err());
+        assert!(Id::<ChannelMarker>::try_from("<#0>").is_err());
+        assert_eq!(123_u64, Id::<EmojiMarker>::try_from("<:name:123>")?);
+        assert_eq!(123_u64, Id::<EmojiMarker>::try_from("<a:name:123>")?);
+        assert_eq!(123_u64, Id::<EmojiMarker>::try_from("123")?);
+        assert!(Id::<EmojiMarker>::try_from("<:name:>").is_err());
+        assert_eq!(123_u64, Id::<RoleMarker>::try_from("<@&123>")?);
+        assert!(Id::<RoleMarker>::try_from("<@123>").is_err());
+        assert_eq!(123_u64, Id::<UserMarker>::try_from("<@123>")?);
+        assert_eq!(123_u64, Id::<UserMarker>::try_from("<@!123>")?);
+        assert!(Id::<UserMarker>::try_from("<@&123>").is_err());
+        assert!(Id::<UserMarker>::try_from("<@123").is_err());
+        assert!(Id::<UserMarker>::try_from("<#123>").is_err());
+
         // `TryFrom`
         assert!(Id::<GenericMarker>::try_from(-123_i64).is_err());
         assert!(Id::<GenericMarker>::try_from(0_i64).is_err());
@@ -828,4 +962,17 @@ mod tests {
             ],
         );
     }
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() -> arbitrary::Result<()> {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let id = Id::<GenericMarker>::arbitrary(&mut Unstructured::new(&[0; 8]))?;
+        assert_eq!(1, id.get());
+
+        let id = Id::<GenericMarker>::arbitrary(&mut Unstructured::new(&7_u64.to_le_bytes()))?;
+        assert_eq!(7, id.get());
+
+        Ok(())
+    }
 }
@@ -1,3 +1,4 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
 #![warn(clippy::missing_const_for_fn, clippy::pedantic, unsafe_code)]
 #![allow(
@@ -5,9 +6,17 @@
     clippy::must_use_candidate,
     clippy::semicolon_if_nothing_returned
 )]
+// Code generated by the rkyv derives binds underscored fields and mirrors field
+// names onto the archived types.
+#![cfg_attr(
+    feature = "rkyv",
+    allow(clippy::struct_field_names, clippy::used_underscore_binding)
+)]
 
 pub mod application;
 pub mod channel;
+#[cfg(feature = "test-util")]
+pub mod fixture;
 pub mod gateway;
 pub mod guild;
 pub mod http;
@@ -18,6 +27,10 @@ pub mod user;
 pub mod util;
 pub mod voice;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_flags;
+#[cfg(feature = "rkyv")]
+mod archive;
 mod visitor;
 
 #[cfg(test)]
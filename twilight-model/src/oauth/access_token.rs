@@ -0,0 +1,157 @@
+use crate::channel::Webhook;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+/// Access token granted via an OAuth2 flow.
+///
+/// Returned when exchanging an authorization code, refreshing a token, or
+/// using the client credentials grant.
+///
+/// The access and refresh tokens are redacted in the [`Debug`] implementation.
+///
+/// Refer to [Discord Docs/Access Token Response][1] for more information.
+///
+/// [1]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant-access-token-response
+#[derive(Clone, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+pub struct AccessToken {
+    /// Token to authenticate requests with as a Bearer token.
+    pub access_token: String,
+    /// Number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// Token to retrieve a new access token with once it expired.
+    ///
+    /// Not present for tokens granted via the client credentials grant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// List of [scopes] the access token is authorized for.
+    ///
+    /// [scopes]: crate::oauth::scope
+    #[serde(
+        deserialize_with = "deserialize_scopes",
+        rename = "scope",
+        serialize_with = "serialize_scopes"
+    )]
+    pub scopes: Vec<String>,
+    /// Type of the token, always `Bearer`.
+    pub token_type: String,
+    /// Webhook created if authorized with the [`WEBHOOK_INCOMING`] scope.
+    ///
+    /// [`WEBHOOK_INCOMING`]: crate::oauth::scope::WEBHOOK_INCOMING
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<Webhook>,
+}
+
+impl Debug for AccessToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AccessToken")
+            .field("access_token", &"<redacted>")
+            .field("expires_in", &self.expires_in)
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("scopes", &self.scopes)
+            .field("token_type", &self.token_type)
+            .field("webhook", &self.webhook)
+            .finish()
+    }
+}
+
+/// Deserialize the space separated list of scopes.
+fn deserialize_scopes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let scopes = String::deserialize(deserializer)?;
+
+    Ok(scopes.split_whitespace().map(ToOwned::to_owned).collect())
+}
+
+/// Serialize the list of scopes as a space separated string.
+#[allow(clippy::ptr_arg)]
+fn serialize_scopes<S: Serializer>(scopes: &Vec<String>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&scopes.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessToken;
+    use crate::oauth::scope;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Token};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+
+    assert_fields!(
+        AccessToken: access_token,
+        expires_in,
+        refresh_token,
+        scopes,
+        token_type,
+        webhook
+    );
+    assert_impl_all!(
+        AccessToken: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn debug_redacts_tokens() {
+        let value = AccessToken {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scopes: Vec::from([scope::IDENTIFY.to_owned()]),
+            token_type: "Bearer".to_owned(),
+            webhook: None,
+        };
+        let debug = format!("{value:?}");
+
+        assert!(!debug.contains("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"));
+        assert!(!debug.contains("D43f5y0ahjqew82jZ4NViEr2YafMKhue"));
+        assert!(debug.contains(r#"refresh_token: Some("<redacted>")"#));
+    }
+
+    #[test]
+    fn serde() {
+        let value = AccessToken {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scopes: Vec::from([scope::IDENTIFY.to_owned(), scope::GUILDS.to_owned()]),
+            token_type: "Bearer".to_owned(),
+            webhook: None,
+        };
+
+        assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+                Token::Str("scope"),
+                Token::Str("identify guilds"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
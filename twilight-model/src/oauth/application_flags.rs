@@ -7,6 +7,14 @@ use serde::{
 bitflags! {
     #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
     pub struct ApplicationFlags: u64 {
+        /// Embedded app has been released to the public.
+        const EMBEDDED_RELEASED = 1 << 1;
+        /// Embedded app can offer in-app purchases.
+        const EMBEDDED_IAP = 1 << 3;
+        /// Indicates if an app uses the [Auto Moderation API].
+        ///
+        /// [Auto Moderation API]: https://discord.com/developers/docs/resources/auto-moderation
+        const APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE = 1 << 6;
         /// Intent required for bots in 100 guilds or more to receive
         /// [`PresenceUpdate`] events.
         ///
@@ -41,6 +49,8 @@ bitflags! {
         ///
         /// [message content]: https://support-dev.discord.com/hc/en-us/articles/4404772028055
         const GATEWAY_MESSAGE_CONTENT_LIMITED = 1 << 19;
+        /// Embedded app is made by Discord.
+        const EMBEDDED_FIRST_PARTY = 1 << 20;
 
         /// Indicates whether an app has registered global [application commands].
         ///
@@ -105,6 +115,12 @@ mod tests {
         Sync,
         UpperHex
     );
+    const_assert_eq!(ApplicationFlags::EMBEDDED_RELEASED.bits(), 1 << 1);
+    const_assert_eq!(ApplicationFlags::EMBEDDED_IAP.bits(), 1 << 3);
+    const_assert_eq!(
+        ApplicationFlags::APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE.bits(),
+        1 << 6
+    );
     const_assert_eq!(ApplicationFlags::GATEWAY_PRESENCE.bits(), 1 << 12);
     const_assert_eq!(ApplicationFlags::GATEWAY_PRESENCE_LIMITED.bits(), 1 << 13);
     const_assert_eq!(ApplicationFlags::GATEWAY_GUILD_MEMBERS.bits(), 1 << 14);
@@ -122,6 +138,7 @@ mod tests {
         ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED.bits(),
         1 << 19
     );
+    const_assert_eq!(ApplicationFlags::EMBEDDED_FIRST_PARTY.bits(), 1 << 20);
     const_assert_eq!(ApplicationFlags::APPLICATION_COMMAND_BADGE.bits(), 1 << 23);
 
     #[test]
@@ -130,6 +147,11 @@ mod tests {
             &ApplicationFlags::GATEWAY_MESSAGE_CONTENT,
             &[Token::U64(ApplicationFlags::GATEWAY_MESSAGE_CONTENT.bits())],
         );
+        serde_test::assert_tokens(
+            &(ApplicationFlags::APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE
+                | ApplicationFlags::EMBEDDED_IAP),
+            &[Token::U64((1 << 6) | (1 << 3))],
+        );
         // Deserialization truncates unknown bits.
         serde_test::assert_de_tokens(&ApplicationFlags::empty(), &[Token::U64(1 << 63)]);
     }
@@ -1,6 +1,7 @@
 pub mod scope;
 pub mod team;
 
+mod access_token;
 mod application;
 mod application_flags;
 mod current_authorization_information;
@@ -8,7 +9,7 @@ mod install_params;
 mod partial_application;
 
 pub use self::{
-    application::Application, application_flags::ApplicationFlags,
+    access_token::AccessToken, application::Application, application_flags::ApplicationFlags,
     current_authorization_information::CurrentAuthorizationInformation,
     install_params::InstallParams, partial_application::PartialApplication,
 };
@@ -7,6 +7,12 @@ use crate::{
 
 /// The data for the user's avatar decoration.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct AvatarDecorationData {
     /// The avatar decoration hash.
     pub asset: ImageHash,
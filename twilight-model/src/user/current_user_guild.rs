@@ -12,6 +12,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Get Current User Guilds]: https://discord.com/developers/docs/resources/user#get-current-user-guilds-example-partial-guild
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct CurrentUserGuild {
     /// Unique ID.
     pub id: Id<GuildMarker>,
@@ -33,6 +39,16 @@ pub struct CurrentUserGuild {
     pub permissions: Permissions,
     /// List of enabled guild features.
     pub features: Vec<String>,
+    /// Approximate number of members in the guild.
+    ///
+    /// Only present when requested via the `with_counts` query parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate_member_count: Option<u64>,
+    /// Approximate number of online members in the guild.
+    ///
+    /// Only present when requested via the `with_counts` query parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate_presence_count: Option<u64>,
 }
 
 #[cfg(test)]
@@ -51,6 +67,8 @@ mod tests {
             owner: true,
             permissions: Permissions::from_bits_truncate(36_953_089),
             features: vec!["a feature".to_owned()],
+            approximate_member_count: None,
+            approximate_presence_count: None,
         };
 
         serde_test::assert_tokens(
@@ -80,4 +98,49 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn current_user_guild_with_counts() {
+        let value = CurrentUserGuild {
+            id: Id::new(1),
+            name: "abcd".to_owned(),
+            icon: None,
+            owner: false,
+            permissions: Permissions::empty(),
+            features: Vec::new(),
+            approximate_member_count: Some(10),
+            approximate_presence_count: Some(5),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CurrentUserGuild",
+                    len: 8,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("abcd"),
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("owner"),
+                Token::Bool(false),
+                Token::Str("permissions"),
+                Token::Str("0"),
+                Token::Str("features"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("approximate_member_count"),
+                Token::Some,
+                Token::U64(10),
+                Token::Str("approximate_presence_count"),
+                Token::Some,
+                Token::U64(5),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -124,6 +124,10 @@ mod tests {
             &UserFlags::PARTNER,
             &[Token::U64(UserFlags::PARTNER.bits())],
         );
+        serde_test::assert_tokens(
+            &(UserFlags::ACTIVE_DEVELOPER | UserFlags::BOT_HTTP_INTERACTIONS),
+            &[Token::U64((1 << 22) | (1 << 19))],
+        );
         // Deserialization truncates unknown bits.
         serde_test::assert_de_tokens(&UserFlags::empty(), &[Token::U64(1 << 63)]);
     }
@@ -75,6 +75,12 @@ pub(crate) mod discriminator {
 /// assert_eq!("0016", display.to_string());
 /// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 #[must_use = "display implementations should be formatted"]
 pub struct DiscriminatorDisplay(u16);
 
@@ -119,6 +125,12 @@ impl Display for DiscriminatorDisplay {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct User {
     /// Accent color of the user's banner.
     ///
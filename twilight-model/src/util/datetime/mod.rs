@@ -31,6 +31,21 @@
 //! );
 //! # Ok(()) }
 //! ```
+//!
+//! Calculate when a member's timeout ends, a week after they joined:
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::time::Duration;
+//! use twilight_model::util::Timestamp;
+//!
+//! let joined_at = Timestamp::from_secs(1_580_608_922)?;
+//! let until = joined_at + Duration::from_secs(7 * 24 * 60 * 60);
+//!
+//! assert!(joined_at < until);
+//! assert_eq!(1_581_213_722, until.as_secs());
+//! # Ok(()) }
+//! ```
 
 #![warn(clippy::missing_docs_in_private_items)]
 
@@ -48,9 +63,14 @@ use serde::{
 };
 use std::{
     fmt::{Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, Sub, SubAssign},
     str::FromStr,
+    time::Duration,
+};
+use time::{
+    format_description::well_known::Rfc3339, Duration as TimeDuration, OffsetDateTime,
+    PrimitiveDateTime, UtcOffset,
 };
-use time::{format_description::well_known::Rfc3339, OffsetDateTime, PrimitiveDateTime};
 
 /// Number of microseconds in a second.
 const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
@@ -72,13 +92,32 @@ const NANOSECONDS_PER_MICROSECOND: i64 = 1_000;
 /// [retrieving an ISO 8601 formatter]: Self::iso_8601
 /// [microseconds precision]: Self::as_micros
 /// [seconds precision]: Self::as_secs
+///
+/// # Arithmetic
+///
+/// A [`Duration`] may be added to or subtracted from a timestamp, which panics
+/// if the result is out of range, like for [`SystemTime`]. Use
+/// [`checked_add`] and [`checked_sub`] to handle the overflow instead. The
+/// duration between two timestamps is calculated with [`duration_since`].
+///
+/// Timestamps are ordered chronologically.
+///
+/// [`SystemTime`]: std::time::SystemTime
+/// [`checked_add`]: Self::checked_add
+/// [`checked_sub`]: Self::checked_sub
+/// [`duration_since`]: Self::duration_since
 // We use a [`PrimitiveDateTime`] here since it does not store an offset, and
 // the API only operates in UTC. Additionally, it is four bytes smaller than an
 // [`OffsetDateTime`].
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Timestamp(PrimitiveDateTime);
 
 impl Timestamp {
+    /// Create a timestamp of the current time.
+    pub fn now() -> Self {
+        Self::from(OffsetDateTime::now_utc())
+    }
+
     /// Create a timestamp from a Unix timestamp with microseconds precision.
     ///
     /// # Errors
@@ -196,6 +235,136 @@ impl Timestamp {
     pub const fn iso_8601(self) -> TimestampIso8601Display {
         TimestampIso8601Display::new(self)
     }
+
+    /// Add a duration to the timestamp.
+    ///
+    /// Returns [`None`] if the result is out of range.
+    #[must_use = "adding a duration returns a new timestamp"]
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let duration = TimeDuration::try_from(duration).ok()?;
+
+        self.0.checked_add(duration).map(Self)
+    }
+
+    /// Subtract a duration from the timestamp.
+    ///
+    /// Returns [`None`] if the result is out of range.
+    #[must_use = "subtracting a duration returns a new timestamp"]
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let duration = TimeDuration::try_from(duration).ok()?;
+
+        self.0.checked_sub(duration).map(Self)
+    }
+
+    /// Duration elapsed from an earlier timestamp to this one.
+    ///
+    /// Returns [`None`] if the other timestamp is later than this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::util::Timestamp;
+    ///
+    /// let created_at = Timestamp::from_secs(1_580_608_922)?;
+    /// let edited_at = Timestamp::from_micros(1_580_608_982_500_000)?;
+    ///
+    /// assert_eq!(
+    ///     Some(Duration::from_millis(60_500)),
+    ///     edited_at.duration_since(created_at),
+    /// );
+    /// assert!(created_at.duration_since(edited_at).is_none());
+    /// # Ok(()) }
+    /// ```
+    pub fn duration_since(self, earlier: Self) -> Option<Duration> {
+        Duration::try_from(self.0 - earlier.0).ok()
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result is out of range. Use [`Timestamp::checked_add`]
+    /// to handle the overflow.
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+impl AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result is out of range. Use [`Timestamp::checked_sub`]
+    /// to handle the overflow.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+impl SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl From<OffsetDateTime> for Timestamp {
+    /// Convert a datetime into a timestamp in UTC.
+    fn from(datetime: OffsetDateTime) -> Self {
+        let utc = datetime.to_offset(UtcOffset::UTC);
+
+        Self(PrimitiveDateTime::new(utc.date(), utc.time()))
+    }
+}
+
+impl From<Timestamp> for OffsetDateTime {
+    /// Convert a timestamp into a datetime in UTC.
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0.assume_utc()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = TimestampParseError;
+
+    /// Convert a chrono datetime into a timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Parsing`] error type if the
+    /// datetime is out of range.
+    fn try_from(datetime: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        let nanoseconds = i128::from(datetime.timestamp()) * 1_000_000_000
+            + i128::from(datetime.timestamp_subsec_nanos());
+
+        OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)
+            .map(Self::from)
+            .map_err(TimestampParseError::from_component_range)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    /// Convert a timestamp into a chrono datetime.
+    fn from(timestamp: Timestamp) -> Self {
+        let utc = timestamp.0.assume_utc();
+
+        // Timestamps can't be out of the range of chrono.
+        chrono::DateTime::from_timestamp(utc.unix_timestamp(), utc.nanosecond()).unwrap_or_default()
+    }
 }
 
 impl FromStr for Timestamp {
@@ -230,6 +399,24 @@ impl FromStr for Timestamp {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timestamp {
+    /// Create an arbitrary timestamp between the Unix epoch and the end of the
+    /// year 9999, which can be formatted as an ISO 8601 datetime.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        /// Unix timestamp in microseconds of 9999-12-31T23:59:59.999999Z.
+        const MAX_MICROS: i64 = 253_402_300_799_999_999;
+
+        let micros = u.int_in_range(0..=MAX_MICROS)?;
+
+        Self::from_micros(micros).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <i64 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 impl<'de> Deserialize<'de> for Timestamp {
     /// Parse a timestamp from an ISO 8601 datetime string emitted by Discord.
     ///
@@ -314,24 +501,115 @@ mod tests {
     use super::{Timestamp, TimestampParseError};
     use serde::{Deserialize, Serialize};
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, hash::Hash, str::FromStr};
-    use time::{OffsetDateTime, PrimitiveDateTime};
+    use std::{
+        fmt::Debug,
+        hash::Hash,
+        ops::{Add, AddAssign, Sub, SubAssign},
+        str::FromStr,
+        time::Duration,
+    };
+    use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
 
     assert_impl_all!(
-        Timestamp: Clone,
+        Timestamp: Add<Duration>,
+        AddAssign<Duration>,
+        Clone,
         Copy,
         Debug,
         Deserialize<'static>,
         Eq,
+        From<OffsetDateTime>,
         FromStr,
         Hash,
+        Ord,
         PartialEq,
+        PartialOrd,
         Send,
         Serialize,
+        Sub<Duration>,
+        SubAssign<Duration>,
         Sync,
         TryFrom<&'static str>,
     );
 
+    #[test]
+    fn arithmetic() -> Result<(), TimestampParseError> {
+        let timestamp = Timestamp::from_secs(1_580_608_922)?;
+        let later = timestamp + Duration::from_micros(1_500);
+
+        assert_eq!(1_580_608_922_001_500, later.as_micros());
+        assert_eq!(timestamp, later - Duration::from_micros(1_500));
+        assert!(timestamp < later);
+        assert_eq!(
+            Some(Duration::from_micros(1_500)),
+            later.duration_since(timestamp)
+        );
+        assert_eq!(None, timestamp.duration_since(later));
+        assert_eq!(Some(Duration::ZERO), timestamp.duration_since(timestamp));
+
+        let mut assigned = timestamp;
+        assigned += Duration::from_secs(60);
+        assert_eq!(1_580_608_982, assigned.as_secs());
+        assigned -= Duration::from_secs(120);
+        assert_eq!(1_580_608_862, assigned.as_secs());
+
+        assert!(timestamp.checked_add(Duration::MAX).is_none());
+        assert!(timestamp
+            .checked_sub(Duration::from_secs(u64::from(u32::MAX) * 1_000))
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_date_time() -> Result<(), TimestampParseError> {
+        let timestamp = Timestamp::from_micros(1_580_608_922_020_000)?;
+        let datetime = OffsetDateTime::from(timestamp);
+
+        assert_eq!(1_580_608_922, datetime.unix_timestamp());
+        assert_eq!(UtcOffset::UTC, datetime.offset());
+        assert_eq!(timestamp, Timestamp::from(datetime));
+
+        // Datetimes in other offsets are converted into UTC.
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        assert_eq!(timestamp, Timestamp::from(datetime.to_offset(offset)));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() -> Result<(), TimestampParseError> {
+        let timestamp = Timestamp::from_micros(1_580_608_922_020_000)?;
+        let datetime = chrono::DateTime::<chrono::Utc>::from(timestamp);
+
+        assert_eq!(1_580_608_922_020_000, datetime.timestamp_micros());
+        assert_eq!(timestamp, Timestamp::try_from(datetime)?);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() -> Result<(), Box<dyn std::error::Error>> {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        for data in [
+            [0; 8],
+            [0xff; 8],
+            [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0],
+        ] {
+            let timestamp = Timestamp::arbitrary(&mut Unstructured::new(&data))?;
+
+            assert_eq!(
+                timestamp,
+                Timestamp::parse(&timestamp.iso_8601().to_string())?
+            );
+        }
+
+        Ok(())
+    }
+
     /// Test a variety of supported ISO 8601 datetime formats.
     #[test]
     fn parse_iso8601() -> Result<(), TimestampParseError> {
@@ -120,6 +120,12 @@ pub enum ImageHashParseErrorType {
 /// Clyde AI has a unique hash that doesn't match the patterns of other hashes,
 /// uniquely processed as [`ImageHash::CLYDE`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
 pub struct ImageHash {
     /// Whether the image is animated.
     ///
@@ -0,0 +1,65 @@
+//! Builder to configure and construct a [`Standby`].
+
+use crate::{
+    config::{Config, OverflowPolicy},
+    Standby,
+};
+
+/// Builder to configure and construct a [`Standby`].
+#[derive(Debug, Default)]
+#[must_use = "has no effect if not built"]
+pub struct StandbyBuilder(Config);
+
+impl StandbyBuilder {
+    /// Create a builder to configure and construct a [`Standby`].
+    pub const fn new() -> Self {
+        Self(Config::new())
+    }
+
+    /// Consume the builder, returning a configured Standby.
+    pub fn build(self) -> Standby {
+        Standby::with_config(self.0)
+    }
+
+    /// Set the maximum number of bystanders across all keys, applying the
+    /// [overflow policy] once reached.
+    ///
+    /// Defaults to being unbounded.
+    ///
+    /// [overflow policy]: Self::overflow_policy
+    pub const fn bystander_limit(mut self, bystander_limit: usize) -> Self {
+        self.0.bystander_limit = Some(bystander_limit);
+
+        self
+    }
+
+    /// Set the maximum number of bystanders per key, such as the ID of the
+    /// channel for messages, applying the [overflow policy] once reached.
+    ///
+    /// Defaults to being unbounded.
+    ///
+    /// [overflow policy]: Self::overflow_policy
+    pub const fn key_bystander_limit(mut self, key_bystander_limit: usize) -> Self {
+        self.0.key_bystander_limit = Some(key_bystander_limit);
+
+        self
+    }
+
+    /// Set the behavior when registering a bystander would exceed a limit.
+    ///
+    /// Defaults to [`OverflowPolicy::RejectNew`].
+    pub const fn overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.0.overflow_policy = overflow_policy;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StandbyBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(StandbyBuilder: Debug, Default, Send, Sync);
+}
@@ -0,0 +1,110 @@
+//! Configuration for a [`Standby`].
+//!
+//! [`Standby`]: crate::Standby
+
+/// Behavior when registering a bystander would exceed a configured limit.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Evict the oldest bystander, of the key or across all keys depending on
+    /// the limit reached.
+    ///
+    /// The future of the evicted bystander resolves to a [`Canceled`] error
+    /// and its stream ends.
+    ///
+    /// [`Canceled`]: crate::future::Canceled
+    EvictOldest,
+    /// Reject the new bystander.
+    ///
+    /// The returned future immediately resolves to a [`Canceled`] error and
+    /// the returned stream immediately ends.
+    ///
+    /// [`Canceled`]: crate::future::Canceled
+    #[default]
+    RejectNew,
+}
+
+/// Configuration for a [`Standby`].
+///
+/// [`Standby`]: crate::Standby
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Config {
+    /// Maximum number of bystanders across all keys.
+    pub(crate) bystander_limit: Option<usize>,
+    /// Maximum number of bystanders per key.
+    pub(crate) key_bystander_limit: Option<usize>,
+    /// Behavior when a limit is reached.
+    pub(crate) overflow_policy: OverflowPolicy,
+}
+
+impl Config {
+    /// Create a new default configuration.
+    ///
+    /// Refer to individual getters for their defaults.
+    pub const fn new() -> Self {
+        Self {
+            bystander_limit: None,
+            key_bystander_limit: None,
+            overflow_policy: OverflowPolicy::RejectNew,
+        }
+    }
+
+    /// Maximum number of bystanders across all keys.
+    ///
+    /// Defaults to being unbounded.
+    pub const fn bystander_limit(&self) -> Option<usize> {
+        self.bystander_limit
+    }
+
+    /// Maximum number of bystanders per key, such as the ID of the channel
+    /// for messages.
+    ///
+    /// Bystanders for any event are not associated with a key and are only
+    /// bounded by the [`bystander_limit`].
+    ///
+    /// Defaults to being unbounded.
+    ///
+    /// [`bystander_limit`]: Self::bystander_limit
+    pub const fn key_bystander_limit(&self) -> Option<usize> {
+        self.key_bystander_limit
+    }
+
+    /// Behavior when registering a bystander would exceed a limit.
+    ///
+    /// Bystanders whose future or stream was dropped are removed before the
+    /// policy applies.
+    ///
+    /// Defaults to [`OverflowPolicy::RejectNew`].
+    pub const fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, OverflowPolicy};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(Config: Clone, Debug, Default, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(
+        OverflowPolicy: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn defaults() {
+        let config = Config::new();
+        assert_eq!(Config::default(), config);
+        assert!(config.bystander_limit().is_none());
+        assert!(config.key_bystander_limit().is_none());
+        assert_eq!(OverflowPolicy::RejectNew, config.overflow_policy());
+    }
+}
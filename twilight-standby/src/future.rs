@@ -2,23 +2,29 @@
 //!
 //! [`Standby`]: super::Standby
 
+use crate::{Abandoned, Registration};
 use futures_core::Stream;
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
-use tokio::sync::{
-    mpsc::UnboundedReceiver as MpscReceiver,
-    oneshot::{error::RecvError, Receiver},
+use tokio::{
+    sync::{
+        mpsc::UnboundedReceiver as MpscReceiver,
+        oneshot::{error::RecvError, Receiver},
+    },
+    time::{self, Instant, Sleep},
 };
 use twilight_model::{
     application::interaction::Interaction,
     gateway::{
         event::Event,
-        payload::incoming::{MessageCreate, ReactionAdd},
+        payload::incoming::{MessageCreate, MessagePollVoteAdd, ReactionAdd, ReactionRemove},
     },
 };
 
@@ -45,16 +51,234 @@ impl Error for Canceled {
     }
 }
 
+/// Future or stream timed out before receiving an event.
+#[derive(Debug)]
+pub struct Elapsed {
+    /// Duration after which the timeout elapsed.
+    duration: Duration,
+}
+
+impl Elapsed {
+    /// Duration after which the timeout elapsed.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("no matching event was received within ")?;
+
+        Debug::fmt(&self.duration, f)
+    }
+}
+
+impl Error for Elapsed {}
+
+/// Call of a future or stream, whose bystander is deregistered once it is
+/// abandoned.
+#[derive(Clone, Debug)]
+pub(crate) struct Call {
+    /// Calls abandoned by their futures and streams.
+    pub(crate) abandoned: Arc<Abandoned>,
+    /// Registration of the call's bystander.
+    pub(crate) registration: Registration,
+}
+
+impl Call {
+    /// Queue the call's bystander to be deregistered.
+    fn abandon(&self) {
+        self.abandoned.push(self.registration.clone());
+    }
+}
+
+/// The future returned from the `timeout` method of futures such as
+/// [`WaitForMessageFuture::timeout`].
+///
+/// Resolves to an [`Elapsed`] error if no matching event is received within
+/// the duration. The call is deregistered when the timeout elapses or the
+/// future is dropped before resolving.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Timeout<F> {
+    /// Call of the future.
+    call: Call,
+    /// Duration of the timeout.
+    duration: Duration,
+    /// Future being timed out, taken once it has resolved or timed out.
+    future: Option<F>,
+    /// Sleep until the timeout elapses.
+    sleep: Pin<Box<Sleep>>,
+}
+
+impl<F> Timeout<F> {
+    /// Time out a future of a call.
+    fn new(call: Call, future: F, duration: Duration) -> Self {
+        Self {
+            call,
+            duration,
+            future: Some(future),
+            sleep: Box::pin(time::sleep(duration)),
+        }
+    }
+
+    /// Drop the future and abandon its call, if the future has not resolved
+    /// yet.
+    fn cancel(&mut self) {
+        if let Some(future) = self.future.take() {
+            // Drop the receiver first to have the bystander be closed once
+            // deregistered.
+            drop(future);
+            self.call.abandon();
+        }
+    }
+}
+
+impl<F: Future + Unpin> Future for Timeout<F> {
+    type Output = Result<F::Output, Elapsed>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let future = this
+            .future
+            .as_mut()
+            .expect("timeout polled after completion");
+
+        if let Poll::Ready(output) = Pin::new(future).poll(cx) {
+            this.future = None;
+
+            return Poll::Ready(Ok(output));
+        }
+
+        if this.sleep.as_mut().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        this.cancel();
+        this.call.abandoned.record_timeout();
+
+        Poll::Ready(Err(Elapsed {
+            duration: this.duration,
+        }))
+    }
+}
+
+impl<F> Drop for Timeout<F> {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// The stream returned from the `timeout` method of streams such as
+/// [`WaitForMessageStream::timeout`].
+///
+/// Yields an [`Elapsed`] error and ends if no matching event is received
+/// within the duration since the stream was created or the last event was
+/// received. The call is deregistered when the timeout elapses or the stream
+/// is dropped before ending.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct TimeoutStream<S> {
+    /// Call of the stream.
+    call: Call,
+    /// Duration of the timeout.
+    duration: Duration,
+    /// Sleep until the timeout elapses, reset whenever an event is received.
+    sleep: Pin<Box<Sleep>>,
+    /// Stream being timed out, taken once it has ended or timed out.
+    stream: Option<S>,
+}
+
+impl<S> TimeoutStream<S> {
+    /// Time out a stream of a call.
+    fn new(call: Call, stream: S, duration: Duration) -> Self {
+        Self {
+            call,
+            duration,
+            sleep: Box::pin(time::sleep(duration)),
+            stream: Some(stream),
+        }
+    }
+
+    /// Drop the stream and abandon its call, if the stream has not ended
+    /// yet.
+    fn cancel(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            // Drop the receiver first to have the bystander be closed once
+            // deregistered.
+            drop(stream);
+            self.call.abandon();
+        }
+    }
+}
+
+impl<S: Stream + Unpin> Stream for TimeoutStream<S> {
+    type Item = Result<S::Item, Elapsed>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        let Some(stream) = this.stream.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        match Pin::new(stream).poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                let deadline = Instant::now() + this.duration;
+                this.sleep.as_mut().reset(deadline);
+
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => {
+                this.stream = None;
+
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        if this.sleep.as_mut().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        this.cancel();
+        this.call.abandoned.record_timeout();
+
+        Poll::Ready(Some(Err(Elapsed {
+            duration: this.duration,
+        })))
+    }
+}
+
+impl<S> Drop for TimeoutStream<S> {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
 /// The future returned from [`Standby::wait_for_event`].
 ///
 /// [`Standby::wait_for_event`]: crate::Standby::wait_for_event
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForEventFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<Event>,
 }
 
+impl WaitForEventFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Future for WaitForEventFuture {
     type Output = Result<Event, Canceled>;
 
@@ -69,10 +293,24 @@ impl Future for WaitForEventFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForEventStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<Event>,
 }
 
+impl WaitForEventStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Stream for WaitForEventStream {
     type Item = Event;
 
@@ -87,10 +325,23 @@ impl Stream for WaitForEventStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForGuildEventFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<Event>,
 }
 
+impl WaitForGuildEventFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Future for WaitForGuildEventFuture {
     type Output = Result<Event, Canceled>;
 
@@ -105,10 +356,24 @@ impl Future for WaitForGuildEventFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForGuildEventStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<Event>,
 }
 
+impl WaitForGuildEventStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Stream for WaitForGuildEventStream {
     type Item = Event;
 
@@ -117,16 +382,92 @@ impl Stream for WaitForGuildEventStream {
     }
 }
 
+/// The future returned from [`Standby::wait_for_map`].
+///
+/// [`Standby::wait_for_map`]: crate::Standby::wait_for_map
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForMapFuture<T> {
+    /// Call of the future.
+    pub(crate) call: Call,
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<T>,
+}
+
+impl<T> WaitForMapFuture<T> {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
+impl<T> Future for WaitForMapFuture<T> {
+    type Output = Result<T, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx).poll(cx).map_err(Canceled)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_map_stream`].
+///
+/// [`Standby::wait_for_map_stream`]: crate::Standby::wait_for_map_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForMapStream<T> {
+    /// Call of the stream.
+    pub(crate) call: Call,
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<T>,
+}
+
+impl<T> WaitForMapStream<T> {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
+impl<T> Stream for WaitForMapStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
 /// The future returned from [`Standby::wait_for_message`].
 ///
 /// [`Standby::wait_for_message`]: crate::Standby::wait_for_message
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForMessageFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<MessageCreate>,
 }
 
+impl WaitForMessageFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Future for WaitForMessageFuture {
     type Output = Result<MessageCreate, Canceled>;
 
@@ -141,10 +482,24 @@ impl Future for WaitForMessageFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForMessageStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<MessageCreate>,
 }
 
+impl WaitForMessageStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Stream for WaitForMessageStream {
     type Item = MessageCreate;
 
@@ -153,16 +508,92 @@ impl Stream for WaitForMessageStream {
     }
 }
 
+/// The future returned from [`Standby::wait_for_poll_vote`].
+///
+/// [`Standby::wait_for_poll_vote`]: crate::Standby::wait_for_poll_vote
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForPollVoteFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<MessagePollVoteAdd>,
+}
+
+impl WaitForPollVoteFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
+impl Future for WaitForPollVoteFuture {
+    type Output = Result<MessagePollVoteAdd, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx).poll(cx).map_err(Canceled)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_poll_vote_stream`].
+///
+/// [`Standby::wait_for_poll_vote_stream`]: crate::Standby::wait_for_poll_vote_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForPollVoteStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<MessagePollVoteAdd>,
+}
+
+impl WaitForPollVoteStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
+impl Stream for WaitForPollVoteStream {
+    type Item = MessagePollVoteAdd;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
 /// The future returned from [`Standby::wait_for_reaction`].
 ///
 /// [`Standby::wait_for_reaction`]: crate::Standby::wait_for_reaction
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForReactionFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<ReactionAdd>,
 }
 
+impl WaitForReactionFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Future for WaitForReactionFuture {
     type Output = Result<ReactionAdd, Canceled>;
 
@@ -177,10 +608,24 @@ impl Future for WaitForReactionFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForReactionStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<ReactionAdd>,
 }
 
+impl WaitForReactionStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Stream for WaitForReactionStream {
     type Item = ReactionAdd;
 
@@ -195,10 +640,23 @@ impl Stream for WaitForReactionStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForComponentFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<Interaction>,
 }
 
+impl WaitForComponentFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Future for WaitForComponentFuture {
     type Output = Result<Interaction, Canceled>;
 
@@ -213,10 +671,24 @@ impl Future for WaitForComponentFuture {
 #[derive(Debug)]
 #[must_use]
 pub struct WaitForComponentStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<Interaction>,
 }
 
+impl WaitForComponentStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
 impl Stream for WaitForComponentStream {
     type Item = Interaction;
 
@@ -225,22 +697,163 @@ impl Stream for WaitForComponentStream {
     }
 }
 
+/// The future returned from [`Standby::wait_for_modal`].
+///
+/// [`Standby::wait_for_modal`]: crate::Standby::wait_for_modal
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForModalFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<Interaction>,
+}
+
+impl WaitForModalFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
+impl Future for WaitForModalFuture {
+    type Output = Result<Interaction, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx).poll(cx).map_err(Canceled)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_modal_stream`].
+///
+/// [`Standby::wait_for_modal_stream`]: crate::Standby::wait_for_modal_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForModalStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<Interaction>,
+}
+
+impl WaitForModalStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
+impl Stream for WaitForModalStream {
+    type Item = Interaction;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_reaction_remove`].
+///
+/// [`Standby::wait_for_reaction_remove`]: crate::Standby::wait_for_reaction_remove
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForReactionRemoveFuture {
+    /// Call of the future.
+    pub(crate) call: Call,
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<ReactionRemove>,
+}
+
+impl WaitForReactionRemoveFuture {
+    /// Time out the future, resolving to an [`Elapsed`] error if no matching
+    /// event is received within a duration.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// future is dropped before resolving.
+    pub fn timeout(self, duration: Duration) -> Timeout<Self> {
+        Timeout::new(self.call.clone(), self, duration)
+    }
+}
+
+impl Future for WaitForReactionRemoveFuture {
+    type Output = Result<ReactionRemove, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx).poll(cx).map_err(Canceled)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_reaction_remove_stream`].
+///
+/// [`Standby::wait_for_reaction_remove_stream`]: crate::Standby::wait_for_reaction_remove_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForReactionRemoveStream {
+    /// Call of the stream.
+    pub(crate) call: Call,
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<ReactionRemove>,
+}
+
+impl WaitForReactionRemoveStream {
+    /// Time out the stream, yielding an [`Elapsed`] error and ending if no
+    /// matching event is received within a duration since the stream was
+    /// created or last received an event.
+    ///
+    /// The call is deregistered once the timeout elapses or the returned
+    /// stream is dropped before ending.
+    pub fn timeout(self, duration: Duration) -> TimeoutStream<Self> {
+        TimeoutStream::new(self.call.clone(), self, duration)
+    }
+}
+
+impl Stream for WaitForReactionRemoveStream {
+    type Item = ReactionRemove;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
-        WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture, WaitForReactionStream,
+        Elapsed, Timeout, TimeoutStream, WaitForEventFuture, WaitForEventStream,
+        WaitForGuildEventFuture, WaitForGuildEventStream, WaitForMapFuture, WaitForMapStream,
+        WaitForMessageFuture, WaitForMessageStream, WaitForModalFuture, WaitForModalStream,
+        WaitForPollVoteFuture, WaitForPollVoteStream, WaitForReactionFuture,
+        WaitForReactionRemoveFuture, WaitForReactionRemoveStream, WaitForReactionStream,
     };
     use futures_core::Stream;
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, future::Future};
+    use std::{error::Error, fmt::Debug, future::Future};
+
+    assert_impl_all!(Elapsed: Debug, Error, Send, Sync);
+    assert_impl_all!(Timeout<WaitForMessageFuture>: Debug, Future, Send, Sync);
+    assert_impl_all!(TimeoutStream<WaitForMessageStream>: Debug, Stream, Send, Sync);
 
     assert_impl_all!(WaitForEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForGuildEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForMessageFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForMapFuture<u64>: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForModalFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForPollVoteFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForReactionFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForReactionRemoveFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForEventStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForGuildEventStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForMessageStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForMapStream<u64>: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForModalStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForPollVoteStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForReactionStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForReactionRemoveStream: Debug, Stream, Send, Sync);
 }
@@ -14,26 +14,42 @@
 
 pub mod future;
 
+mod builder;
+mod config;
+mod stats;
+
+pub use self::{
+    builder::StandbyBuilder,
+    config::{Config, OverflowPolicy},
+    stats::StandbyStats,
+};
+
 use self::future::{
-    WaitForComponentFuture, WaitForComponentStream, WaitForEventFuture, WaitForEventStream,
-    WaitForGuildEventFuture, WaitForGuildEventStream, WaitForMessageFuture, WaitForMessageStream,
-    WaitForReactionFuture, WaitForReactionStream,
+    Call, WaitForComponentFuture, WaitForComponentStream, WaitForEventFuture, WaitForEventStream,
+    WaitForGuildEventFuture, WaitForGuildEventStream, WaitForMapFuture, WaitForMapStream,
+    WaitForMessageFuture, WaitForMessageStream, WaitForModalFuture, WaitForModalStream,
+    WaitForPollVoteFuture, WaitForPollVoteStream, WaitForReactionFuture,
+    WaitForReactionRemoveFuture, WaitForReactionRemoveStream, WaitForReactionStream,
 };
 use dashmap::DashMap;
 use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     hash::Hash,
-    sync::atomic::{AtomicU64, Ordering},
+    mem,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
 };
 use tokio::sync::{
     mpsc::{self, UnboundedReceiver, UnboundedSender as MpscSender},
     oneshot::{self, Receiver, Sender as OneshotSender},
 };
 use twilight_model::{
-    application::interaction::{Interaction, InteractionType},
+    application::interaction::{Interaction, InteractionData, InteractionType},
     gateway::{
         event::Event,
-        payload::incoming::{MessageCreate, ReactionAdd},
+        payload::incoming::{MessageCreate, MessagePollVoteAdd, ReactionAdd, ReactionRemove},
     },
     id::{
         marker::{ChannelMarker, GuildMarker, MessageMarker},
@@ -64,6 +80,61 @@ impl<E> Sender<E> {
     }
 }
 
+/// Key of a bystander in one of the maps of a [`Standby`], used to deregister
+/// it.
+#[derive(Clone, Debug)]
+pub(crate) enum Registration {
+    /// Bystander in [`Standby::components`].
+    Component(Id<MessageMarker>),
+    /// Bystander in [`Standby::events`].
+    Event(u64),
+    /// Bystander in [`Standby::guilds`].
+    Guild(Id<GuildMarker>),
+    /// Bystander in [`Standby::maps`].
+    Map(u64),
+    /// Bystander in [`Standby::messages`].
+    Message(Id<ChannelMarker>),
+    /// Bystander in [`Standby::modals`].
+    Modal(String),
+    /// Bystander in [`Standby::poll_votes`].
+    PollVote(Id<MessageMarker>),
+    /// Bystander in [`Standby::reactions`].
+    Reaction(Id<MessageMarker>),
+    /// Bystander in [`Standby::reaction_removals`].
+    ReactionRemoval(Id<MessageMarker>),
+}
+
+/// Calls whose future or stream was timed out or dropped before completing,
+/// shared with the futures and streams returned by [`Standby`].
+#[derive(Debug, Default)]
+pub(crate) struct Abandoned {
+    /// Registrations of the calls' bystanders, deregistered the next time
+    /// the [`Standby`] processes an event or registers a call.
+    registrations: Mutex<Vec<Registration>>,
+    /// Number of calls that timed out.
+    timed_out: AtomicU64,
+}
+
+impl Abandoned {
+    /// Queue a call's bystander to be deregistered.
+    pub(crate) fn push(&self, registration: Registration) {
+        self.registrations
+            .lock()
+            .expect("abandoned calls poisoned")
+            .push(registration);
+    }
+
+    /// Count a call that timed out.
+    pub(crate) fn record_timeout(&self) {
+        self.timed_out.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Take the queued registrations.
+    fn take(&self) -> Vec<Registration> {
+        mem::take(&mut *self.registrations.lock().expect("abandoned calls poisoned"))
+    }
+}
+
 /// Registration for a caller to wait for an event based on a predicate
 /// function.
 struct Bystander<T> {
@@ -72,6 +143,18 @@ struct Bystander<T> {
     /// [`Sender::Future`]s consume themselves once upon sending so the sender
     /// needs to be able to be taken out separately.
     sender: Option<Sender<T>>,
+    /// ID in [`Standby::event_counter`] at registration, ordering bystanders
+    /// by age.
+    sequence: u64,
+}
+
+impl<T> Bystander<T> {
+    /// Whether the bystander may still be sent an event.
+    fn is_open(&self) -> bool {
+        self.sender
+            .as_ref()
+            .is_some_and(|sender| !sender.is_closed())
+    }
 }
 
 impl<T: Debug> Debug for Bystander<T> {
@@ -79,10 +162,81 @@ impl<T: Debug> Debug for Bystander<T> {
         f.debug_struct("Bystander")
             .field("func", &"<dyn Fn(&T) -> bool>")
             .field("sender", &self.sender)
+            .field("sequence", &self.sequence)
+            .finish()
+    }
+}
+
+/// Registration for a caller to wait for any event from which a predicate
+/// function extracts a value, erasing the type of the value.
+trait MapBystander: Debug + Send + Sync {
+    /// Whether the bystander may still be sent a value.
+    fn is_open(&self) -> bool;
+
+    /// Process an event, sending the extracted value if the predicate
+    /// extracts one.
+    fn process(&mut self, event: &Event) -> ProcessStatus;
+}
+
+/// [`MapBystander`] of a particular value type and predicate function.
+struct Mapper<T, F> {
+    /// Predicate extracting a value from an event.
+    func: F,
+    /// [`Sender::Future`]s consume themselves once upon sending so the sender
+    /// needs to be able to be taken out separately.
+    sender: Option<Sender<T>>,
+}
+
+impl<T, F> Debug for Mapper<T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Mapper")
+            .field("func", &"<dyn Fn(&Event) -> Option<T>>")
+            .field("sender", &self.sender.as_ref().map(|_| "<Sender<T>>"))
             .finish()
     }
 }
 
+impl<T: Send, F: Fn(&Event) -> Option<T> + Send + Sync> MapBystander for Mapper<T, F> {
+    fn is_open(&self) -> bool {
+        self.sender
+            .as_ref()
+            .is_some_and(|sender| !sender.is_closed())
+    }
+
+    fn process(&mut self, event: &Event) -> ProcessStatus {
+        let Some(sender) = self.sender.take() else {
+            return ProcessStatus::AlreadyComplete;
+        };
+
+        if sender.is_closed() {
+            return ProcessStatus::Dropped;
+        }
+
+        let Some(value) = (self.func)(event) else {
+            self.sender.replace(sender);
+
+            return ProcessStatus::Skip;
+        };
+
+        match sender {
+            Sender::Future(tx) => {
+                drop(tx.send(value));
+
+                ProcessStatus::SentFuture
+            }
+            Sender::Stream(tx) => {
+                if tx.send(value).is_ok() {
+                    self.sender.replace(Sender::Stream(tx));
+
+                    ProcessStatus::SentStream
+                } else {
+                    ProcessStatus::Dropped
+                }
+            }
+        }
+    }
+}
+
 /// The `Standby` struct, used by the main event loop to process events and by
 /// tasks to wait for an event.
 ///
@@ -97,8 +251,9 @@ impl<T: Debug> Debug for Bystander<T> {
 ///
 /// ## Timeouts
 ///
-/// Futures can be timed out by passing the future returned by Standby to
-/// functions such as [`tokio::time::timeout`]:
+/// Futures and streams returned by Standby can be timed out via their
+/// `timeout` method, such as [`WaitForEventFuture::timeout`], which
+/// deregisters the call once timed out:
 ///
 /// ```rust,no_run
 /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -108,13 +263,36 @@ impl<T: Debug> Debug for Bystander<T> {
 ///
 /// let standby = Standby::new();
 /// let future = standby.wait_for_event(|event: &Event| event.kind() == EventType::Ready);
-/// let event = tokio::time::timeout(Duration::from_secs(1), future).await?;
+/// let event = future.timeout(Duration::from_secs(1)).await??;
 /// # Ok(()) }
 /// ```
 ///
+/// Futures can also be timed out by passing them to functions such as
+/// [`tokio::time::timeout`], although the call then stays registered until a
+/// later event is processed for it.
+///
+/// ## Limits
+///
+/// The number of bystanders can be bounded in total and per key, such as per
+/// channel for messages, to protect long-running processes from unbounded
+/// growth. Once a limit is reached new calls are either rejected or evict the
+/// oldest call according to the [`OverflowPolicy`]:
+///
+/// ```
+/// use twilight_standby::{OverflowPolicy, Standby};
+///
+/// let standby = Standby::builder()
+///     .bystander_limit(10_000)
+///     .key_bystander_limit(100)
+///     .overflow_policy(OverflowPolicy::EvictOldest)
+///     .build();
+/// ```
+///
 /// [`tokio::time::timeout`]: https://docs.rs/tokio/latest/tokio/time/fn.timeout.html
 #[derive(Debug, Default)]
 pub struct Standby {
+    /// Calls abandoned by their futures and streams.
+    abandoned: Arc<Abandoned>,
     /// List of component bystanders where the ID of the message is known
     /// beforehand.
     components: DashMap<Id<MessageMarker>, Vec<Bystander<Interaction>>>,
@@ -124,18 +302,49 @@ pub struct Standby {
     ///
     /// [`event_counter`]: Self::event_counter
     events: DashMap<u64, Bystander<Event>>,
-    /// Event counter to be used as the key of [`events`].
+    /// Configuration of limits on bystanders.
+    config: Config,
+    /// Number of bystanders dropped as their receiver was dropped.
+    dropped: AtomicU64,
+    /// Event counter to be used as the key of [`events`] and the sequence of
+    /// bystanders.
     ///
     /// [`events`]: Self::events
     event_counter: AtomicU64,
+    /// Number of bystanders evicted due to reaching a limit.
+    evicted: AtomicU64,
     /// List of bystanders where the ID of the guild is known beforehand.
     guilds: DashMap<Id<GuildMarker>, Vec<Bystander<Event>>>,
+    /// Bystanders for any event from which a value is extracted.
+    ///
+    /// The key is generated via [`event_counter`].
+    ///
+    /// [`event_counter`]: Self::event_counter
+    maps: DashMap<u64, Box<dyn MapBystander>>,
+    /// Number of events sent to bystanders.
+    matched: AtomicU64,
     /// List of message bystanders where the ID of the channel is known
     /// beforehand.
     messages: DashMap<Id<ChannelMarker>, Vec<Bystander<MessageCreate>>>,
+    /// List of modal bystanders keyed by the prefix of the modal's custom ID.
+    modals: DashMap<String, Vec<Bystander<Interaction>>>,
+    /// List of poll vote bystanders where the ID of the message is known
+    /// beforehand.
+    poll_votes: DashMap<Id<MessageMarker>, Vec<Bystander<MessagePollVoteAdd>>>,
+    /// List of reaction removal bystanders where the ID of the message is
+    /// known beforehand.
+    reaction_removals: DashMap<Id<MessageMarker>, Vec<Bystander<ReactionRemove>>>,
     /// List of reaction bystanders where the ID of the message is known
     /// beforehand.
     reactions: DashMap<Id<MessageMarker>, Vec<Bystander<ReactionAdd>>>,
+    /// Number of registered bystanders, including ones whose receiver was
+    /// dropped but which haven't been removed yet.
+    registered: AtomicUsize,
+    /// Number of bystanders rejected due to reaching a limit.
+    rejected: AtomicU64,
+    /// Lock held while making room for and inserting a bystander, so that
+    /// concurrent insertions can't exceed the limits.
+    room: Mutex<()>,
 }
 
 impl Standby {
@@ -153,6 +362,79 @@ impl Standby {
         Self::default()
     }
 
+    /// Create a builder to configure limits on the number of bystanders.
+    pub const fn builder() -> StandbyBuilder {
+        StandbyBuilder::new()
+    }
+
+    /// Create a new instance of `Standby` with a configuration.
+    fn with_config(config: Config) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Immutable reference to the configuration of the Standby.
+    pub const fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Number of bystanders evicted due to reaching a limit.
+    ///
+    /// Refer to [`OverflowPolicy::EvictOldest`] for more information.
+    pub fn evicted(&self) -> u64 {
+        self.evicted.load(Ordering::Relaxed)
+    }
+
+    /// Number of bystanders rejected due to reaching a limit.
+    ///
+    /// Refer to [`OverflowPolicy::RejectNew`] for more information.
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+
+    /// Statistics about the registered bystanders and how their calls
+    /// completed.
+    ///
+    /// # Examples
+    ///
+    /// Log the number of registered bystanders and timed out calls:
+    ///
+    /// ```
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// // later on...
+    /// let stats = standby.stats();
+    /// println!(
+    ///     "{} bystanders registered, {} calls timed out",
+    ///     stats.bystanders(),
+    ///     stats.timed_out(),
+    /// );
+    /// ```
+    pub fn stats(&self) -> StandbyStats {
+        self.deregister_abandoned();
+
+        StandbyStats {
+            components: Self::map_len(&self.components),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            events: self.events.len(),
+            evicted: self.evicted(),
+            guilds: Self::map_len(&self.guilds),
+            maps: self.maps.len(),
+            matched: self.matched.load(Ordering::Relaxed),
+            messages: Self::map_len(&self.messages),
+            modals: Self::map_len(&self.modals),
+            poll_votes: Self::map_len(&self.poll_votes),
+            reaction_removals: Self::map_len(&self.reaction_removals),
+            reactions: Self::map_len(&self.reactions),
+            rejected: self.rejected(),
+            timed_out: self.abandoned.timed_out.load(Ordering::Relaxed),
+        }
+    }
+
     /// Process an event, calling any bystanders that might be waiting on it.
     ///
     /// Returns statistics about matched [`Standby`] calls and how they were
@@ -164,51 +446,97 @@ impl Standby {
     ///
     /// This function must be called when events are received in order for
     /// futures returned by methods to fulfill.
+    ///
+    /// Processing a [`GuildDelete`] event for a guild the current user was
+    /// removed from deregisters the guild's calls made via methods such as
+    /// [`wait_for_stream`], counted by [`ProcessResults::removed`].
+    ///
+    /// [`GuildDelete`]: twilight_model::gateway::payload::incoming::GuildDelete
+    /// [`wait_for_stream`]: Self::wait_for_stream
     pub fn process(&self, event: &Event) -> ProcessResults {
         tracing::trace!(event_type = ?event.kind(), ?event, "processing event");
 
+        self.deregister_abandoned();
+
         let mut completions = ProcessResults::new();
 
         match event {
             Event::InteractionCreate(e) => {
                 if e.kind == InteractionType::MessageComponent {
                     if let Some(message) = &e.message {
-                        completions.add_with(&Self::process_specific_event(
+                        completions.add_with(&self.process_specific_event(
                             &self.components,
                             message.id,
                             e,
                         ));
                     }
                 }
+
+                if let Some(InteractionData::ModalSubmit(data)) = &e.data {
+                    completions.add_with(&self.process_modal(&data.custom_id, e));
+                }
             }
             Event::MessageCreate(e) => {
-                completions.add_with(&Self::process_specific_event(
+                completions.add_with(&self.process_specific_event(
                     &self.messages,
                     e.0.channel_id,
                     e,
                 ));
             }
+            Event::MessagePollVoteAdd(e) => {
+                completions.add_with(&self.process_specific_event(
+                    &self.poll_votes,
+                    e.message_id,
+                    e,
+                ));
+            }
             Event::ReactionAdd(e) => {
-                completions.add_with(&Self::process_specific_event(
+                completions.add_with(&self.process_specific_event(
                     &self.reactions,
                     e.0.message_id,
                     e,
                 ));
             }
+            Event::ReactionRemove(e) => {
+                completions.add_with(&self.process_specific_event(
+                    &self.reaction_removals,
+                    e.0.message_id,
+                    e,
+                ));
+            }
             _ => {}
         }
 
         if let Some(guild_id) = event.guild_id() {
-            completions.add_with(&Self::process_specific_event(&self.guilds, guild_id, event));
+            completions.add_with(&self.process_specific_event(&self.guilds, guild_id, event));
         }
 
-        completions.add_with(&Self::process_event(&self.events, event));
+        // Bystanders of a guild the current user was removed from would never
+        // be matched again. They are still sent the event beforehand.
+        if let Event::GuildDelete(guild) = event {
+            if !guild.unavailable {
+                completions.add_with(&self.remove_guild(guild.id));
+            }
+        }
+
+        completions.add_with(&self.process_event(event));
+        completions.add_with(&self.process_maps(event));
+
+        self.dropped
+            .fetch_add(completions.dropped() as u64, Ordering::Relaxed);
+        self.matched
+            .fetch_add(completions.matched() as u64, Ordering::Relaxed);
 
         completions
     }
 
     /// Wait for an event in a certain guild.
     ///
+    /// The call is deregistered once a [`GuildDelete`] event is processed for
+    /// the guild because the current user was removed from it, such as by
+    /// being kicked. The event is sent to the call beforehand if it matches.
+    /// Guilds becoming unavailable during an outage don't deregister calls.
+    ///
     /// To wait for multiple guild events matching the given predicate use
     /// [`wait_for_stream`].
     ///
@@ -238,10 +566,12 @@ impl Standby {
     /// # Errors
     ///
     /// The returned future resolves to a [`Canceled`] error if the associated
-    /// [`Standby`] instance is dropped.
+    /// [`Standby`] instance is dropped or the call is deregistered due to the
+    /// current user being removed from the guild.
     ///
     /// [`BanAdd`]: twilight_model::gateway::payload::incoming::BanAdd
     /// [`Canceled`]: future::Canceled
+    /// [`GuildDelete`]: twilight_model::gateway::payload::incoming::GuildDelete
     /// [`wait_for_stream`]: Self::wait_for_stream
     pub fn wait_for<F: Fn(&Event) -> bool + Send + Sync + 'static>(
         &self,
@@ -251,12 +581,16 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
 
         WaitForGuildEventFuture {
-            rx: Self::insert_future(&self.guilds, guild_id, check),
+            call: self.call(Registration::Guild(guild_id)),
+            rx: self.insert_future(&self.guilds, guild_id, check),
         }
     }
 
     /// Wait for a stream of events in a certain guild.
     ///
+    /// As with [`wait_for`], the call is deregistered and the stream ends once
+    /// the current user is removed from the guild.
+    ///
     /// To wait for only one guild event matching the given predicate use
     /// [`wait_for`].
     ///
@@ -292,7 +626,7 @@ impl Standby {
     /// # Errors
     ///
     /// The returned stream ends when the associated [`Standby`] instance is
-    /// dropped.
+    /// dropped or the current user is removed from the guild.
     ///
     /// [`BanAdd`]: twilight_model::gateway::payload::incoming::BanAdd
     /// [`wait_for`]: Self::wait_for
@@ -304,7 +638,8 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
 
         WaitForGuildEventStream {
-            rx: Self::insert_stream(&self.guilds, guild_id, check),
+            call: self.call(Registration::Guild(guild_id)),
+            rx: self.insert_stream(&self.guilds, guild_id, check),
         }
     }
 
@@ -353,16 +688,12 @@ impl Standby {
         tracing::trace!("waiting for event");
 
         let (tx, rx) = oneshot::channel();
+        let event_id = self.insert_event(check, Sender::Future(tx));
 
-        self.events.insert(
-            self.next_event_id(),
-            Bystander {
-                func: check.into(),
-                sender: Some(Sender::Future(tx)),
-            },
-        );
-
-        WaitForEventFuture { rx }
+        WaitForEventFuture {
+            call: self.call(Registration::Event(event_id)),
+            rx,
+        }
     }
 
     /// Wait for a stream of events not in a certain guild. This must be
@@ -412,16 +743,122 @@ impl Standby {
         tracing::trace!("waiting for event");
 
         let (tx, rx) = mpsc::unbounded_channel();
+        let event_id = self.insert_event(check, Sender::Stream(tx));
 
-        self.events.insert(
-            self.next_event_id(),
-            Bystander {
-                func: check.into(),
-                sender: Some(Sender::Stream(tx)),
-            },
-        );
+        WaitForEventStream {
+            call: self.call(Registration::Event(event_id)),
+            rx,
+        }
+    }
+
+    /// Wait for any event from which a value can be extracted, resolving to
+    /// the extracted value.
+    ///
+    /// The predicate both filters and extracts: the first event for which it
+    /// returns a value completes the call. This avoids matching the returned
+    /// [`Event`] again, as is required with [`wait_for_event`].
+    ///
+    /// To wait for multiple values use [`wait_for_map_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for the ID of the next guild to become available:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::gateway::event::Event;
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let guild_id = standby
+    ///     .wait_for_map(|event: &Event| match event {
+    ///         Event::GuildCreate(guild) => Some(guild.id()),
+    ///         _ => None,
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_event`]: Self::wait_for_event
+    /// [`wait_for_map_stream`]: Self::wait_for_map_stream
+    pub fn wait_for_map<F: Fn(&Event) -> Option<T> + Send + Sync + 'static, T: Send + 'static>(
+        &self,
+        check: impl Into<Box<F>>,
+    ) -> WaitForMapFuture<T> {
+        tracing::trace!("waiting for mapped event");
+
+        let (tx, rx) = oneshot::channel();
+        let map_id = self.insert_map(check, Sender::Future(tx));
 
-        WaitForEventStream { rx }
+        WaitForMapFuture {
+            call: self.call(Registration::Map(map_id)),
+            rx,
+        }
+    }
+
+    /// Wait for a stream of values extracted from any event.
+    ///
+    /// The stream yields a value for every event from which the predicate
+    /// extracts one.
+    ///
+    /// To wait for only one value use [`wait_for_map`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for the content of messages starting with "!":
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::gateway::event::Event;
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut commands = standby.wait_for_map_stream(|event: &Event| match event {
+    ///     Event::MessageCreate(message) if message.content.starts_with('!') => {
+    ///         Some(message.content.clone())
+    ///     }
+    ///     _ => None,
+    /// });
+    ///
+    /// while let Some(command) = commands.next().await {
+    ///     println!("got command {command}");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_map`]: Self::wait_for_map
+    pub fn wait_for_map_stream<
+        F: Fn(&Event) -> Option<T> + Send + Sync + 'static,
+        T: Send + 'static,
+    >(
+        &self,
+        check: impl Into<Box<F>>,
+    ) -> WaitForMapStream<T> {
+        tracing::trace!("waiting for mapped event");
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let map_id = self.insert_map(check, Sender::Stream(tx));
+
+        WaitForMapStream {
+            call: self.call(Registration::Map(map_id)),
+            rx,
+        }
     }
 
     /// Wait for a message in a certain channel.
@@ -467,7 +904,8 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
 
         WaitForMessageFuture {
-            rx: Self::insert_future(&self.messages, channel_id, check),
+            call: self.call(Registration::Message(channel_id)),
+            rx: self.insert_future(&self.messages, channel_id, check),
         }
     }
 
@@ -517,7 +955,8 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
 
         WaitForMessageStream {
-            rx: Self::insert_stream(&self.messages, channel_id, check),
+            call: self.call(Registration::Message(channel_id)),
+            rx: self.insert_stream(&self.messages, channel_id, check),
         }
     }
 
@@ -564,7 +1003,8 @@ impl Standby {
         tracing::trace!(%message_id, "waiting for reaction on message");
 
         WaitForReactionFuture {
-            rx: Self::insert_future(&self.reactions, message_id, check),
+            call: self.call(Registration::Reaction(message_id)),
+            rx: self.insert_future(&self.reactions, message_id, check),
         }
     }
 
@@ -616,7 +1056,210 @@ impl Standby {
         tracing::trace!(%message_id, "waiting for reaction on message");
 
         WaitForReactionStream {
-            rx: Self::insert_stream(&self.reactions, message_id, check),
+            call: self.call(Registration::Reaction(message_id)),
+            rx: self.insert_stream(&self.reactions, message_id, check),
+        }
+    }
+
+    /// Wait for a reaction to be removed from a certain message.
+    ///
+    /// To wait for multiple reaction removals matching the given predicate use
+    /// [`wait_for_reaction_remove_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for user 456 to remove a reaction from message 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{gateway::payload::incoming::ReactionRemove, id::Id};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let message_id = Id::new(123);
+    /// let user_id = Id::new(456);
+    ///
+    /// let reaction = standby
+    ///     .wait_for_reaction_remove(message_id, move |event: &ReactionRemove| {
+    ///         event.user_id == user_id
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_reaction_remove_stream`]: Self::wait_for_reaction_remove_stream
+    pub fn wait_for_reaction_remove<F: Fn(&ReactionRemove) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionRemoveFuture {
+        tracing::trace!(%message_id, "waiting for reaction removal on message");
+
+        WaitForReactionRemoveFuture {
+            call: self.call(Registration::ReactionRemoval(message_id)),
+            rx: self.insert_future(&self.reaction_removals, message_id, check),
+        }
+    }
+
+    /// Wait for a stream of reactions to be removed from a certain message.
+    ///
+    /// To wait for only one reaction removal matching the given predicate use
+    /// [`wait_for_reaction_remove`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for multiple removals of the unicode reaction "🤠" from message
+    /// 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::{
+    ///     channel::message::EmojiReactionType,
+    ///     gateway::payload::incoming::ReactionRemove,
+    ///     id::Id,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let message_id = Id::new(123);
+    ///
+    /// let mut removals =
+    ///     standby.wait_for_reaction_remove_stream(message_id, |event: &ReactionRemove| {
+    ///         matches!(&event.emoji, EmojiReactionType::Unicode { name } if name == "🤠")
+    ///     });
+    ///
+    /// while let Some(reaction) = removals.next().await {
+    ///     println!("reaction removed by {}", reaction.user_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_reaction_remove`]: Self::wait_for_reaction_remove
+    pub fn wait_for_reaction_remove_stream<
+        F: Fn(&ReactionRemove) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionRemoveStream {
+        tracing::trace!(%message_id, "waiting for reaction removal on message");
+
+        WaitForReactionRemoveStream {
+            call: self.call(Registration::ReactionRemoval(message_id)),
+            rx: self.insert_stream(&self.reaction_removals, message_id, check),
+        }
+    }
+
+    /// Wait for a vote on the poll of a certain message.
+    ///
+    /// To wait for multiple poll votes matching the given predicate use
+    /// [`wait_for_poll_vote_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for user 456 to vote on the poll of message 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{gateway::payload::incoming::MessagePollVoteAdd, id::Id};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let message_id = Id::new(123);
+    /// let user_id = Id::new(456);
+    ///
+    /// let vote = standby
+    ///     .wait_for_poll_vote(message_id, move |event: &MessagePollVoteAdd| {
+    ///         event.user_id == user_id
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_poll_vote_stream`]: Self::wait_for_poll_vote_stream
+    pub fn wait_for_poll_vote<F: Fn(&MessagePollVoteAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForPollVoteFuture {
+        tracing::trace!(%message_id, "waiting for poll vote on message");
+
+        WaitForPollVoteFuture {
+            call: self.call(Registration::PollVote(message_id)),
+            rx: self.insert_future(&self.poll_votes, message_id, check),
+        }
+    }
+
+    /// Wait for a stream of votes on the poll of a certain message.
+    ///
+    /// To wait for only one poll vote matching the given predicate use
+    /// [`wait_for_poll_vote`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for multiple votes for answer 1 on the poll of message 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::{gateway::payload::incoming::MessagePollVoteAdd, id::Id};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let message_id = Id::new(123);
+    ///
+    /// let mut votes = standby.wait_for_poll_vote_stream(message_id, |event: &MessagePollVoteAdd| {
+    ///     event.answer_id == 1
+    /// });
+    ///
+    /// while let Some(vote) = votes.next().await {
+    ///     println!("got a vote by {}", vote.user_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_poll_vote`]: Self::wait_for_poll_vote
+    pub fn wait_for_poll_vote_stream<F: Fn(&MessagePollVoteAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForPollVoteStream {
+        tracing::trace!(%message_id, "waiting for poll vote on message");
+
+        WaitForPollVoteStream {
+            call: self.call(Registration::PollVote(message_id)),
+            rx: self.insert_stream(&self.poll_votes, message_id, check),
         }
     }
 
@@ -656,7 +1299,8 @@ impl Standby {
         tracing::trace!(%message_id, "waiting for component on message");
 
         WaitForComponentFuture {
-            rx: Self::insert_future(&self.components, message_id, check),
+            call: self.call(Registration::Component(message_id)),
+            rx: self.insert_future(&self.components, message_id, check),
         }
     }
 
@@ -692,76 +1336,555 @@ impl Standby {
     ///     }
     /// });
     ///
-    /// while let Some(component) = components.next().await {
-    ///     println!("got a component by {}", component.author_id().unwrap());
-    /// }
-    /// # Ok(()) }
-    /// ```
+    /// while let Some(component) = components.next().await {
+    ///     println!("got a component by {}", component.author_id().unwrap());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`wait_for_component`]: Self::wait_for_component
+    pub fn wait_for_component_stream<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentStream {
+        tracing::trace!(%message_id, "waiting for component on message");
+
+        WaitForComponentStream {
+            call: self.call(Registration::Component(message_id)),
+            rx: self.insert_stream(&self.components, message_id, check),
+        }
+    }
+
+    /// Wait for a modal submission whose custom ID starts with a prefix.
+    ///
+    /// To wait for multiple modal submissions matching the given predicate use
+    /// [`wait_for_modal_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for a submission of a modal with a custom ID starting with
+    /// "report:" by user 456 in guild 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{application::interaction::Interaction, id::Id};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let modal = standby
+    ///     .wait_for_modal("report:", |event: &Interaction| {
+    ///         event.guild_id == Some(Id::new(123)) && event.author_id() == Some(Id::new(456))
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_modal_stream`]: Self::wait_for_modal_stream
+    pub fn wait_for_modal<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        custom_id_prefix: impl Into<String>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForModalFuture {
+        let custom_id_prefix = custom_id_prefix.into();
+        tracing::trace!(%custom_id_prefix, "waiting for modal submission");
+
+        WaitForModalFuture {
+            call: self.call(Registration::Modal(custom_id_prefix.clone())),
+            rx: self.insert_future(&self.modals, custom_id_prefix, check),
+        }
+    }
+
+    /// Wait for a stream of modal submissions whose custom ID starts with a
+    /// prefix.
+    ///
+    /// To wait for only one modal submission matching the given predicate use
+    /// [`wait_for_modal`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for multiple submissions of modals with a custom ID starting with
+    /// "feedback:" on message 789:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::{
+    ///     application::interaction::{Interaction, InteractionData},
+    ///     id::Id,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut modals = standby.wait_for_modal_stream("feedback:", |event: &Interaction| {
+    ///     event
+    ///         .message
+    ///         .as_ref()
+    ///         .is_some_and(|message| message.id == Id::new(789))
+    /// });
+    ///
+    /// while let Some(modal) = modals.next().await {
+    ///     if let Some(InteractionData::ModalSubmit(data)) = &modal.data {
+    ///         println!("got modal {}", data.custom_id);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_modal`]: Self::wait_for_modal
+    pub fn wait_for_modal_stream<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        custom_id_prefix: impl Into<String>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForModalStream {
+        let custom_id_prefix = custom_id_prefix.into();
+        tracing::trace!(%custom_id_prefix, "waiting for modal submission");
+
+        WaitForModalStream {
+            call: self.call(Registration::Modal(custom_id_prefix.clone())),
+            rx: self.insert_stream(&self.modals, custom_id_prefix, check),
+        }
+    }
+
+    /// Call of a bystander, deregistered once abandoned by its future or
+    /// stream.
+    fn call(&self, registration: Registration) -> Call {
+        Call {
+            abandoned: Arc::clone(&self.abandoned),
+            registration,
+        }
+    }
+
+    /// Deregister the bystanders of abandoned calls.
+    fn deregister_abandoned(&self) {
+        for registration in self.abandoned.take() {
+            self.deregister(&registration);
+        }
+    }
+
+    /// Deregister an abandoned call's bystander, removing the bystanders
+    /// whose receiver was dropped.
+    fn deregister(&self, registration: &Registration) {
+        tracing::trace!(?registration, "deregistering bystander");
+
+        let removed = match registration {
+            Registration::Component(message_id) => {
+                Self::remove_closed(&self.components, message_id)
+            }
+            Registration::Event(event_id) => usize::from(self.events.remove(event_id).is_some()),
+            Registration::Guild(guild_id) => Self::remove_closed(&self.guilds, guild_id),
+            Registration::Map(map_id) => usize::from(self.maps.remove(map_id).is_some()),
+            Registration::Message(channel_id) => Self::remove_closed(&self.messages, channel_id),
+            Registration::Modal(custom_id_prefix) => {
+                Self::remove_closed(&self.modals, custom_id_prefix)
+            }
+            Registration::PollVote(message_id) => Self::remove_closed(&self.poll_votes, message_id),
+            Registration::Reaction(message_id) => Self::remove_closed(&self.reactions, message_id),
+            Registration::ReactionRemoval(message_id) => {
+                Self::remove_closed(&self.reaction_removals, message_id)
+            }
+        };
+
+        self.release(removed);
+    }
+
+    /// Deregister all bystanders of a guild the current user was removed
+    /// from, canceling their futures and ending their streams.
+    fn remove_guild(&self, guild_id: Id<GuildMarker>) -> ProcessResults {
+        let mut results = ProcessResults::new();
+
+        if let Some((_, bystanders)) = self.guilds.remove(&guild_id) {
+            self.release(bystanders.len());
+            results.removed = bystanders.iter().filter(|b| b.is_open()).count();
+
+            tracing::trace!(
+                %guild_id,
+                removed = results.removed,
+                "removing bystanders of deleted guild",
+            );
+        }
+
+        results
+    }
+
+    /// Next event ID in [`Standby::event_counter`].
+    fn next_event_id(&self) -> u64 {
+        self.event_counter.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Insert a new bystander for any event, returning its ID.
+    ///
+    /// The bystander is dropped if there is no room for it.
+    fn insert_event<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        check: impl Into<Box<F>>,
+        sender: Sender<Event>,
+    ) -> u64 {
+        self.deregister_abandoned();
+        let _room = self.room.lock().expect("room lock poisoned");
+        let event_id = self.next_event_id();
+
+        if self.make_room() {
+            self.registered.fetch_add(1, Ordering::Relaxed);
+            self.events.insert(
+                event_id,
+                Bystander {
+                    func: check.into(),
+                    sender: Some(sender),
+                    sequence: event_id,
+                },
+            );
+        }
+
+        event_id
+    }
+
+    /// Insert a new bystander extracting a value from any event, returning
+    /// its ID.
+    ///
+    /// The bystander is dropped if there is no room for it.
+    fn insert_map<F: Fn(&Event) -> Option<T> + Send + Sync + 'static, T: Send + 'static>(
+        &self,
+        check: impl Into<Box<F>>,
+        sender: Sender<T>,
+    ) -> u64 {
+        self.deregister_abandoned();
+        let _room = self.room.lock().expect("room lock poisoned");
+        let map_id = self.next_event_id();
+
+        if self.make_room() {
+            self.registered.fetch_add(1, Ordering::Relaxed);
+            self.maps.insert(
+                map_id,
+                Box::new(Mapper {
+                    func: check.into(),
+                    sender: Some(sender),
+                }),
+            );
+        }
+
+        map_id
+    }
+
+    /// Append a new future bystander into a map according to the ID.
+    fn insert_future<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
+        &self,
+        map: &BystanderMap<K, V>,
+        id: K,
+        check: impl Into<Box<F>>,
+    ) -> Receiver<V> {
+        let (tx, rx) = oneshot::channel();
+        self.insert(map, id, check, Sender::Future(tx));
+
+        rx
+    }
+
+    /// Append a new stream bystander into a map according to the ID.
+    fn insert_stream<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
+        &self,
+        map: &BystanderMap<K, V>,
+        id: K,
+        check: impl Into<Box<F>>,
+    ) -> UnboundedReceiver<V> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.insert(map, id, check, Sender::Stream(tx));
+
+        rx
+    }
+
+    /// Append a new bystander into a map according to the ID.
+    ///
+    /// The bystander is dropped if there is no room for it.
+    fn insert<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
+        &self,
+        map: &BystanderMap<K, V>,
+        id: K,
+        check: impl Into<Box<F>>,
+        sender: Sender<V>,
+    ) {
+        self.deregister_abandoned();
+        let _room = self.room.lock().expect("room lock poisoned");
+
+        if !self.make_room_in(map, &id) || !self.make_room() {
+            return;
+        }
+
+        self.registered.fetch_add(1, Ordering::Relaxed);
+        let sequence = self.next_event_id();
+        let mut entry = map.entry(id).or_default();
+        entry.push(Bystander {
+            func: check.into(),
+            sender: Some(sender),
+            sequence,
+        });
+    }
+
+    /// Make room for a new bystander of an ID if over the
+    /// [per key limit][`Config::key_bystander_limit`], returning whether
+    /// there is room.
+    fn make_room_in<K: Eq + Hash, V>(&self, map: &BystanderMap<K, V>, id: &K) -> bool {
+        let Some(limit) = self.config.key_bystander_limit() else {
+            return true;
+        };
+
+        let fits = map.get_mut(id).map_or(limit > 0, |mut bystanders| {
+            if bystanders.len() >= limit {
+                let len = bystanders.len();
+                bystanders.retain(Bystander::is_open);
+                self.release(len - bystanders.len());
+            }
+
+            if bystanders.len() < limit {
+                return true;
+            }
+
+            if self.config.overflow_policy() == OverflowPolicy::EvictOldest
+                && !bystanders.is_empty()
+            {
+                tracing::debug!("evicting oldest bystander of key over limit");
+                bystanders.remove(0);
+                self.release(1);
+                self.evicted.fetch_add(1, Ordering::Relaxed);
+
+                return true;
+            }
+
+            false
+        });
+
+        if !fits {
+            tracing::debug!("rejecting bystander of key over limit");
+            map.remove_if(id, |_, bystanders| bystanders.is_empty());
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fits
+    }
+
+    /// Make room for a new bystander if over the
+    /// [global limit][`Config::bystander_limit`], returning whether there is
+    /// room.
+    ///
+    /// Must be called with the [`room`] lock held, the count of registered
+    /// bystanders otherwise being able to change before the bystander is
+    /// inserted.
     ///
-    /// [`wait_for_component`]: Self::wait_for_component
-    pub fn wait_for_component_stream<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
-        &self,
-        message_id: Id<MessageMarker>,
-        check: impl Into<Box<F>>,
-    ) -> WaitForComponentStream {
-        tracing::trace!(%message_id, "waiting for component on message");
+    /// [`room`]: Self::room
+    fn make_room(&self) -> bool {
+        let Some(limit) = self.config.bystander_limit() else {
+            return true;
+        };
 
-        WaitForComponentStream {
-            rx: Self::insert_stream(&self.components, message_id, check),
+        if self.registered.load(Ordering::Relaxed) < limit {
+            return true;
+        }
+
+        // Only sweep the maps once at the limit, bystanders otherwise being
+        // removed as events are processed.
+        self.remove_all_closed();
+
+        if self.registered.load(Ordering::Relaxed) < limit {
+            return true;
+        }
+
+        if self.config.overflow_policy() == OverflowPolicy::EvictOldest && self.evict_oldest() {
+            tracing::debug!("evicted oldest bystander over limit");
+            self.evicted.fetch_add(1, Ordering::Relaxed);
+
+            return true;
         }
+
+        tracing::debug!("rejecting bystander over limit");
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+
+        false
     }
 
-    /// Next event ID in [`Standby::event_counter`].
-    fn next_event_id(&self) -> u64 {
-        self.event_counter.fetch_add(1, Ordering::SeqCst)
+    /// Count bystanders as no longer registered.
+    fn release(&self, count: usize) {
+        if count > 0 {
+            self.registered.fetch_sub(count, Ordering::Relaxed);
+        }
     }
 
-    /// Append a new future bystander into a map according to the ID.
-    fn insert_future<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
-        map: &BystanderMap<K, V>,
-        id: K,
-        check: impl Into<Box<F>>,
-    ) -> Receiver<V> {
-        let (tx, rx) = oneshot::channel();
+    /// Number of bystanders in a map.
+    fn map_len<K: Eq + Hash, V>(map: &BystanderMap<K, V>) -> usize {
+        map.iter().map(|bystanders| bystanders.len()).sum()
+    }
 
-        let mut entry = map.entry(id).or_default();
-        entry.push(Bystander {
-            func: check.into(),
-            sender: Some(Sender::Future(tx)),
+    /// Remove the bystanders whose receiver was dropped from all maps.
+    fn remove_all_closed(&self) {
+        let mut removed = 0;
+
+        self.events.retain(|_, bystander| {
+            let open = bystander.is_open();
+            removed += usize::from(!open);
+
+            open
         });
+        self.maps.retain(|_, bystander| {
+            let open = bystander.is_open();
+            removed += usize::from(!open);
 
-        rx
+            open
+        });
+
+        removed += Self::retain_open(&self.components)
+            + Self::retain_open(&self.guilds)
+            + Self::retain_open(&self.messages)
+            + Self::retain_open(&self.modals)
+            + Self::retain_open(&self.poll_votes)
+            + Self::retain_open(&self.reactions)
+            + Self::retain_open(&self.reaction_removals);
+
+        self.release(removed);
     }
 
-    /// Append a new stream bystander into a map according to the ID.
-    fn insert_stream<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
-        map: &BystanderMap<K, V>,
-        id: K,
-        check: impl Into<Box<F>>,
-    ) -> UnboundedReceiver<V> {
-        let (tx, rx) = mpsc::unbounded_channel();
+    /// Remove the bystanders whose receiver was dropped from a map, removing
+    /// IDs once no bystanders remain, returning the number removed.
+    fn retain_open<K: Eq + Hash, V>(map: &BystanderMap<K, V>) -> usize {
+        let mut removed = 0;
 
-        let mut entry = map.entry(id).or_default();
-        entry.push(Bystander {
-            func: check.into(),
-            sender: Some(Sender::Stream(tx)),
+        map.retain(|_, bystanders| {
+            let len = bystanders.len();
+            bystanders.retain(Bystander::is_open);
+            removed += len - bystanders.len();
+
+            !bystanders.is_empty()
         });
 
-        rx
+        removed
+    }
+
+    /// Remove the oldest bystander across all maps, returning whether there
+    /// was one.
+    fn evict_oldest(&self) -> bool {
+        let oldest = [
+            Self::oldest(&self.components)
+                .map(|(sequence, id)| (sequence, Registration::Component(id))),
+            self.events
+                .iter()
+                .map(|bystander| *bystander.key())
+                .min()
+                .map(|id| (id, Registration::Event(id))),
+            Self::oldest(&self.guilds).map(|(sequence, id)| (sequence, Registration::Guild(id))),
+            self.maps
+                .iter()
+                .map(|bystander| *bystander.key())
+                .min()
+                .map(|id| (id, Registration::Map(id))),
+            Self::oldest(&self.messages)
+                .map(|(sequence, id)| (sequence, Registration::Message(id))),
+            Self::oldest(&self.modals).map(|(sequence, id)| (sequence, Registration::Modal(id))),
+            Self::oldest(&self.poll_votes)
+                .map(|(sequence, id)| (sequence, Registration::PollVote(id))),
+            Self::oldest(&self.reactions)
+                .map(|(sequence, id)| (sequence, Registration::Reaction(id))),
+            Self::oldest(&self.reaction_removals)
+                .map(|(sequence, id)| (sequence, Registration::ReactionRemoval(id))),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by_key(|(sequence, _)| *sequence);
+
+        let Some((_, registration)) = oldest else {
+            return false;
+        };
+
+        // The bystander may have been removed by an event being processed in
+        // the meantime.
+        let removed = match registration {
+            Registration::Component(message_id) => {
+                Self::remove_oldest(&self.components, &message_id)
+            }
+            Registration::Event(event_id) => self.events.remove(&event_id).is_some(),
+            Registration::Guild(guild_id) => Self::remove_oldest(&self.guilds, &guild_id),
+            Registration::Map(map_id) => self.maps.remove(&map_id).is_some(),
+            Registration::Message(channel_id) => Self::remove_oldest(&self.messages, &channel_id),
+            Registration::Modal(custom_id_prefix) => {
+                Self::remove_oldest(&self.modals, &custom_id_prefix)
+            }
+            Registration::PollVote(message_id) => {
+                Self::remove_oldest(&self.poll_votes, &message_id)
+            }
+            Registration::Reaction(message_id) => Self::remove_oldest(&self.reactions, &message_id),
+            Registration::ReactionRemoval(message_id) => {
+                Self::remove_oldest(&self.reaction_removals, &message_id)
+            }
+        };
+
+        self.release(usize::from(removed));
+
+        removed
+    }
+
+    /// Sequence and ID of the oldest bystander in a map.
+    fn oldest<K: Clone + Eq + Hash, V>(map: &BystanderMap<K, V>) -> Option<(u64, K)> {
+        map.iter()
+            .filter_map(|bystanders| {
+                bystanders
+                    .first()
+                    .map(|bystander| (bystander.sequence, bystanders.key().clone()))
+            })
+            .min_by_key(|(sequence, _)| *sequence)
+    }
+
+    /// Remove the oldest bystander of an ID, removing the ID once no
+    /// bystanders remain, returning whether there was one.
+    fn remove_oldest<K: Eq + Hash, V>(map: &BystanderMap<K, V>, id: &K) -> bool {
+        let removed = map.get_mut(id).is_some_and(|mut bystanders| {
+            if bystanders.is_empty() {
+                return false;
+            }
+
+            bystanders.remove(0);
+
+            true
+        });
+
+        map.remove_if(id, |_, bystanders| bystanders.is_empty());
+
+        removed
+    }
+
+    /// Remove the bystanders of an ID whose receiver was dropped, removing the
+    /// ID once no bystanders remain, returning the number removed.
+    fn remove_closed<K: Eq + Hash, V>(map: &BystanderMap<K, V>, id: &K) -> usize {
+        let removed = map.get_mut(id).map_or(0, |mut bystanders| {
+            let len = bystanders.len();
+            bystanders.retain(Bystander::is_open);
+
+            len - bystanders.len()
+        });
+
+        map.remove_if(id, |_, bystanders| bystanders.is_empty());
+
+        removed
     }
 
     /// Process a general event that is not of any particular type or in any
     /// particular guild.
-    #[tracing::instrument(level = "trace")]
-    fn process_event<K: Debug + Display + Eq + Hash + PartialEq + 'static, V: Clone + Debug>(
-        map: &DashMap<K, Bystander<V>>,
-        event: &V,
-    ) -> ProcessResults {
+    #[tracing::instrument(level = "trace", skip(self))]
+    fn process_event(&self, event: &Event) -> ProcessResults {
         tracing::trace!(?event, "processing event");
 
         let mut results = ProcessResults::new();
+        let mut removed = 0;
 
-        map.retain(|id, bystander| {
+        self.events.retain(|id, bystander| {
             let result = Self::bystander_process(bystander, event);
             results.handle(result);
 
@@ -769,19 +1892,68 @@ impl Standby {
 
             // We want to retain bystanders that are *incomplete* and remove
             // bystanders that are *complete*.
-            !result.is_complete()
+            let complete = result.is_complete();
+            removed += usize::from(complete);
+
+            !complete
+        });
+
+        self.release(removed);
+
+        results
+    }
+
+    /// Process an event for the bystanders extracting a value from it.
+    fn process_maps(&self, event: &Event) -> ProcessResults {
+        let mut results = ProcessResults::new();
+        let mut removed = 0;
+
+        self.maps.retain(|id, bystander| {
+            let result = bystander.process(event);
+            results.handle(result);
+
+            tracing::trace!(bystander_id = %id, ?result, "map bystander processed");
+
+            let complete = result.is_complete();
+            removed += usize::from(complete);
+
+            !complete
         });
 
+        self.release(removed);
+
+        results
+    }
+
+    /// Process a modal submission, calling the bystanders of every prefix of
+    /// its custom ID.
+    fn process_modal(&self, custom_id: &str, interaction: &Interaction) -> ProcessResults {
+        // Collect the prefixes first to not hold a reference into the map
+        // while processing its bystanders.
+        let prefixes = self
+            .modals
+            .iter()
+            .filter(|bystanders| custom_id.starts_with(bystanders.key().as_str()))
+            .map(|bystanders| bystanders.key().clone())
+            .collect::<Vec<_>>();
+
+        let mut results = ProcessResults::new();
+
+        for prefix in prefixes {
+            results.add_with(&self.process_specific_event(&self.modals, prefix, interaction));
+        }
+
         results
     }
 
     /// Process a general event that is either of a particular type or in a
     /// particular guild.
-    #[tracing::instrument(level = "trace")]
+    #[tracing::instrument(level = "trace", skip(self))]
     fn process_specific_event<
         K: Debug + Display + Eq + Hash + PartialEq + 'static,
         V: Clone + Debug,
     >(
+        &self,
         map: &DashMap<K, Vec<Bystander<V>>>,
         guild_id: K,
         event: &V,
@@ -789,7 +1961,9 @@ impl Standby {
         // Iterate over a guild's bystanders and mark it for removal if there
         // are no bystanders remaining.
         let (remove_guild, results) = if let Some(mut bystanders) = map.get_mut(&guild_id) {
+            let len = bystanders.len();
             let results = Self::bystander_iter(&mut bystanders, event);
+            self.release(len - bystanders.len());
 
             (bystanders.is_empty(), results)
         } else {
@@ -801,7 +1975,8 @@ impl Standby {
         if remove_guild {
             tracing::trace!(%guild_id, "removing guild from map");
 
-            map.remove(&guild_id);
+            // A bystander may have been inserted in the meantime.
+            map.remove_if(&guild_id, |_, bystanders| bystanders.is_empty());
         }
 
         results
@@ -939,6 +2114,9 @@ pub struct ProcessResults {
     dropped: usize,
     /// Number of future bystanders that were open and were sent an event.
     fulfilled: usize,
+    /// Number of open guild bystanders that were deregistered as the current
+    /// user was removed from the guild.
+    removed: usize,
     /// Number of stream bystanders that were open and were sent an event.
     sent: usize,
 }
@@ -949,6 +2127,7 @@ impl ProcessResults {
         Self {
             dropped: 0,
             fulfilled: 0,
+            removed: 0,
             sent: 0,
         }
     }
@@ -987,6 +2166,14 @@ impl ProcessResults {
         self.fulfilled() + self.sent()
     }
 
+    /// Number of [`Standby`] guild calls that were deregistered because the
+    /// current user was removed from their guild.
+    ///
+    /// Refer to [`Standby::wait_for`] for more information.
+    pub const fn removed(&self) -> usize {
+        self.removed
+    }
+
     /// Number of [`Standby`] streaming calls that were matched and had an event
     /// sent to them.
     ///
@@ -1005,6 +2192,7 @@ impl ProcessResults {
     fn add_with(&mut self, other: &Self) {
         self.dropped = self.dropped.saturating_add(other.dropped);
         self.fulfilled = self.fulfilled.saturating_add(other.fulfilled);
+        self.removed = self.removed.saturating_add(other.removed);
         self.sent = self.sent.saturating_add(other.sent);
     }
 
@@ -1055,22 +2243,26 @@ impl ProcessStatus {
 mod tests {
     #![allow(clippy::non_ascii_literal)]
 
-    use crate::Standby;
+    use crate::{OverflowPolicy, Standby};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, sync::atomic::Ordering, thread, time::Duration};
+    use tokio::time;
     use tokio_stream::StreamExt;
     use twilight_gateway::{Event, EventType};
     use twilight_model::{
         application::interaction::{
-            message_component::MessageComponentInteractionData, Interaction, InteractionData,
-            InteractionType,
+            message_component::MessageComponentInteractionData, modal::ModalInteractionData,
+            Interaction, InteractionData, InteractionType,
         },
         channel::{
             message::{component::ComponentType, EmojiReactionType, Message, MessageType},
             Channel, ChannelType,
         },
         gateway::{
-            payload::incoming::{InteractionCreate, MessageCreate, ReactionAdd, Ready, RoleDelete},
+            payload::incoming::{
+                GuildDelete, InteractionCreate, MessageCreate, MessagePollVoteAdd, ReactionAdd,
+                ReactionRemove, Ready, RoleDelete,
+            },
             GatewayReaction, ShardId,
         },
         guild::Permissions,
@@ -1238,6 +2430,17 @@ mod tests {
         }
     }
 
+    fn modal(custom_id: &str) -> Interaction {
+        let mut interaction = button();
+        interaction.data = Some(InteractionData::ModalSubmit(ModalInteractionData {
+            components: Vec::new(),
+            custom_id: custom_id.to_owned(),
+        }));
+        interaction.kind = InteractionType::ModalSubmit;
+
+        interaction
+    }
+
     /// Test that if a receiver drops their end, the result properly counts the
     /// statistic.
     #[tokio::test]
@@ -1372,6 +2575,45 @@ mod tests {
         assert!(standby.guilds.is_empty());
     }
 
+    /// Test that the calls of a guild are deregistered once the current user
+    /// is removed from it, but not when it becomes unavailable.
+    #[tokio::test]
+    async fn test_guild_delete_removes_bystanders() {
+        let standby = Standby::new();
+        let guild_id = Id::new(1);
+
+        let mut stream = standby.wait_for_stream(guild_id, |event: &Event| {
+            event.kind() == EventType::GuildDelete
+        });
+        let future = standby.wait_for(guild_id, |event: &Event| {
+            event.kind() == EventType::MemberUpdate
+        });
+        let _other = standby.wait_for_stream(Id::new(2), |_: &Event| true);
+
+        let unavailable = Event::GuildDelete(GuildDelete {
+            id: guild_id,
+            unavailable: true,
+        });
+        let results = standby.process(&unavailable);
+        assert_eq!(1, results.sent());
+        assert_eq!(0, results.removed());
+        assert_eq!(3, standby.stats().guilds());
+
+        let removed = Event::GuildDelete(GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+        let results = standby.process(&removed);
+        assert_eq!(1, results.sent());
+        assert_eq!(2, results.removed());
+        assert_eq!(1, standby.stats().guilds());
+
+        assert_eq!(Some(unavailable), stream.next().await);
+        assert_eq!(Some(removed), stream.next().await);
+        assert!(stream.next().await.is_none());
+        assert!(future.await.is_err());
+    }
+
     /// Test basic functionality of the [`Standby::wait_for_event`] method.
     #[tokio::test]
     async fn test_wait_for_event() {
@@ -1541,6 +2783,154 @@ mod tests {
         assert!(standby.components.is_empty());
     }
 
+    /// Assert that only modal submissions with a custom ID starting with the
+    /// prefix are matched.
+    #[tokio::test]
+    async fn test_wait_for_modal() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_modal("report:", |modal: &Interaction| {
+            modal.author_id() == Some(Id::new(2))
+        });
+
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            button(),
+        ))));
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            modal("feedback:1"),
+        ))));
+        assert_eq!(1, standby.modals.len());
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            modal("report:1"),
+        ))));
+
+        let modal = wait.await.unwrap();
+        assert!(matches!(
+            modal.data,
+            Some(InteractionData::ModalSubmit(data)) if data.custom_id == "report:1"
+        ));
+        assert!(standby.modals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_modal_stream() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_modal_stream("report:", |_: &Interaction| true);
+        let wait = standby.wait_for_modal("report:2", |_: &Interaction| true);
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            modal("report:1"),
+        ))));
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            modal("report:2"),
+        ))));
+
+        assert!(stream.next().await.is_some());
+        assert!(stream.next().await.is_some());
+        assert!(wait.await.is_ok());
+        drop(stream);
+        assert_eq!(1, standby.modals.len());
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            modal("report:3"),
+        ))));
+        assert!(standby.modals.is_empty());
+    }
+
+    /// Assert that poll votes are matched by the ID of their message.
+    #[tokio::test]
+    async fn test_wait_for_poll_vote() {
+        let vote = |message_id| {
+            Event::MessagePollVoteAdd(MessagePollVoteAdd {
+                answer_id: 1,
+                channel_id: Id::new(2),
+                guild_id: Some(Id::new(3)),
+                message_id: Id::new(message_id),
+                user_id: Id::new(5),
+            })
+        };
+
+        let standby = Standby::new();
+        let wait =
+            standby.wait_for_poll_vote(Id::new(4), |vote: &MessagePollVoteAdd| vote.answer_id == 1);
+        let mut stream =
+            standby.wait_for_poll_vote_stream(Id::new(4), |_: &MessagePollVoteAdd| true);
+
+        standby.process(&vote(6));
+        assert_eq!(1, standby.poll_votes.len());
+        standby.process(&vote(4));
+        standby.process(&vote(4));
+
+        assert_eq!(Id::new(5), wait.await.unwrap().user_id);
+        assert!(stream.next().await.is_some());
+        assert!(stream.next().await.is_some());
+        drop(stream);
+        standby.process(&vote(4));
+        assert!(standby.poll_votes.is_empty());
+    }
+
+    /// Assert that reaction removals are matched separately from reaction
+    /// additions.
+    #[tokio::test]
+    async fn test_wait_for_reaction_remove() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_reaction_remove(Id::new(4), |reaction: &ReactionRemove| {
+            reaction.user_id == Id::new(3)
+        });
+
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+        assert_eq!(1, standby.reaction_removals.len());
+        standby.process(&Event::ReactionRemove(Box::new(ReactionRemove(reaction()))));
+
+        assert_eq!(Id::new(3), wait.await.unwrap().user_id);
+        assert!(standby.reaction_removals.is_empty());
+
+        let mut stream =
+            standby.wait_for_reaction_remove_stream(Id::new(4), |_: &ReactionRemove| true);
+        standby.process(&Event::ReactionRemove(Box::new(ReactionRemove(reaction()))));
+        assert!(stream.next().await.is_some());
+    }
+
+    /// Assert that mapped calls resolve to the extracted value and only
+    /// complete once a value is extracted.
+    #[tokio::test]
+    async fn test_wait_for_map() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_map(|event: &Event| match event {
+            Event::MessageCreate(message) => Some(message.content.clone()),
+            _ => None,
+        });
+        let mut stream = standby.wait_for_map_stream(|event: &Event| match event {
+            Event::ReactionAdd(reaction) => Some(reaction.user_id),
+            _ => None,
+        });
+
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+        assert_eq!(2, standby.maps.len());
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+
+        assert_eq!("test", wait.await.unwrap());
+        assert_eq!(Some(Id::new(3)), stream.next().await);
+        assert_eq!(Some(Id::new(3)), stream.next().await);
+        assert_eq!(1, standby.maps.len());
+        drop(stream);
+        standby.process(&Event::GatewayHeartbeatAck);
+        assert!(standby.maps.is_empty());
+    }
+
+    /// Test that timed out map streams deregister their bystander.
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_map_stream_timeout() {
+        let standby = Standby::new();
+        let mut stream = standby
+            .wait_for_map_stream(|event: &Event| event.guild_id())
+            .timeout(Duration::from_secs(5));
+        assert_eq!(1, standby.maps.len());
+
+        assert!(matches!(stream.next().await, Some(Err(_))));
+        assert!(stream.next().await.is_none());
+        assert_eq!(0, standby.stats().maps());
+        assert_eq!(1, standby.stats().timed_out());
+    }
+
     #[tokio::test]
     async fn test_handles_wrong_events() {
         let standby = Standby::new();
@@ -1586,4 +2976,190 @@ mod tests {
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
         assert!(matches!(wait.await, Ok(Event::ReactionAdd(_))));
     }
+
+    /// Test that futures with a timeout resolve to an [`Elapsed`] error and
+    /// deregister their bystander once timed out.
+    ///
+    /// [`Elapsed`]: crate::future::Elapsed
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_message_timeout() {
+        let standby = Standby::new();
+        let wait = standby
+            .wait_for_message(Id::new(1), |_: &MessageCreate| true)
+            .timeout(Duration::from_secs(5));
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert!(matches!(wait.await, Ok(Ok(message)) if message.id.get() == 3));
+
+        let _other = standby.wait_for_message(Id::new(1), |_: &MessageCreate| false);
+        let wait = standby
+            .wait_for_message(Id::new(1), |_: &MessageCreate| false)
+            .timeout(Duration::from_secs(5));
+        assert_eq!(2, standby.messages.get(&Id::new(1)).unwrap().len());
+
+        let elapsed = wait.await.unwrap_err();
+        assert_eq!(Duration::from_secs(5), elapsed.duration());
+        assert_eq!(1, standby.stats().messages());
+    }
+
+    /// Test that dropping a future with a timeout deregisters its bystander
+    /// once the next event is processed.
+    #[tokio::test]
+    async fn test_wait_for_event_timeout_dropped() {
+        let standby = Standby::new();
+        let wait = standby
+            .wait_for_event(|event: &Event| event.kind() == EventType::Resumed)
+            .timeout(Duration::from_secs(5));
+        assert_eq!(1, standby.events.len());
+
+        drop(wait);
+        assert_eq!(1, standby.events.len());
+        standby.process(&Event::GatewayReconnect);
+        assert!(standby.events.is_empty());
+    }
+
+    /// Test that streams with a timeout yield an [`Elapsed`] error and end
+    /// once no event has been received for the duration.
+    ///
+    /// [`Elapsed`]: crate::future::Elapsed
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_component_stream_timeout() {
+        let standby = Standby::new();
+        let mut stream = standby
+            .wait_for_component_stream(Id::new(3), |_: &Interaction| true)
+            .timeout(Duration::from_secs(5));
+
+        time::sleep(Duration::from_secs(4)).await;
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            button(),
+        ))));
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+
+        time::sleep(Duration::from_secs(4)).await;
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            button(),
+        ))));
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+
+        assert!(matches!(stream.next().await, Some(Err(_))));
+        assert!(stream.next().await.is_none());
+        assert_eq!(0, standby.stats().components());
+    }
+
+    /// Test that bystanders over the limit of a key are rejected while
+    /// bystanders of other keys are not.
+    #[tokio::test]
+    async fn test_key_bystander_limit_rejects() {
+        let standby = Standby::builder().key_bystander_limit(1).build();
+        let first = standby.wait_for_message(Id::new(1), |_: &MessageCreate| false);
+        let second = standby.wait_for_message(Id::new(1), |_: &MessageCreate| false);
+        let _other = standby.wait_for_message(Id::new(2), |_: &MessageCreate| false);
+
+        assert!(second.await.is_err());
+        assert_eq!(1, standby.rejected());
+        assert_eq!(1, standby.messages.get(&Id::new(1)).unwrap().len());
+        assert_eq!(1, standby.messages.get(&Id::new(2)).unwrap().len());
+
+        // Dropped bystanders make room for new ones.
+        drop(first);
+        let _third = standby.wait_for_message(Id::new(1), |_: &MessageCreate| false);
+        assert_eq!(1, standby.rejected());
+    }
+
+    /// Test that the oldest bystander across all maps is evicted once the
+    /// global limit is reached.
+    #[tokio::test]
+    async fn test_bystander_limit_evicts_oldest() {
+        let standby = Standby::builder()
+            .bystander_limit(2)
+            .overflow_policy(OverflowPolicy::EvictOldest)
+            .build();
+        let oldest = standby.wait_for_event(|_: &Event| false);
+        let mut stream = standby.wait_for_reaction_stream(Id::new(4), |_: &ReactionAdd| false);
+        let _newest = standby.wait_for_message(Id::new(1), |_: &MessageCreate| false);
+
+        assert!(oldest.await.is_err());
+        assert!(standby.events.is_empty());
+        assert_eq!(1, standby.evicted());
+
+        let _newer = standby.wait_for(Id::new(1), |_: &Event| false);
+        assert!(stream.next().await.is_none());
+        assert!(standby.reactions.is_empty());
+        assert_eq!(2, standby.evicted());
+        assert_eq!(0, standby.rejected());
+    }
+
+    /// Test that fulfilled bystanders make room for new ones under the global
+    /// limit.
+    #[tokio::test]
+    async fn test_bystander_limit_fulfilled() {
+        let standby = Standby::builder().bystander_limit(1).build();
+        let wait = standby.wait_for_message(Id::new(1), |_: &MessageCreate| true);
+        assert_eq!(1, standby.registered.load(Ordering::Relaxed));
+
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert!(wait.await.is_ok());
+        assert_eq!(0, standby.registered.load(Ordering::Relaxed));
+
+        let _wait = standby.wait_for_event(|_: &Event| false);
+        assert_eq!(1, standby.registered.load(Ordering::Relaxed));
+        assert_eq!(0, standby.rejected());
+    }
+
+    /// Test that concurrent insertions don't exceed the global limit.
+    #[test]
+    fn test_bystander_limit_concurrent() {
+        let standby = Standby::builder().bystander_limit(10).build();
+
+        let waits = thread::scope(|scope| {
+            let handles = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..10)
+                            .map(|id| {
+                                standby.wait_for_message(Id::new(id + 1), |_: &MessageCreate| false)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(80, waits.len());
+        assert_eq!(10, standby.registered.load(Ordering::Relaxed));
+        assert_eq!(10, Standby::map_len(&standby.messages));
+        assert_eq!(70, standby.rejected());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stats() {
+        let standby = Standby::new();
+        let _message = standby.wait_for_message(Id::new(1), |_: &MessageCreate| true);
+        let _event = standby.wait_for_event(|_: &Event| false);
+        let dropped = standby.wait_for_reaction(Id::new(4), |_: &ReactionAdd| true);
+        let timeout = standby
+            .wait_for_reaction(Id::new(5), |_: &ReactionAdd| true)
+            .timeout(Duration::from_secs(5));
+
+        let stats = standby.stats();
+        assert_eq!(4, stats.bystanders());
+        assert_eq!(1, stats.events());
+        assert_eq!(1, stats.messages());
+        assert_eq!(2, stats.reactions());
+
+        drop(dropped);
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert!(timeout.await.is_err());
+
+        let stats = standby.stats();
+        assert_eq!(1, stats.bystanders());
+        assert_eq!(1, stats.dropped());
+        assert_eq!(1, stats.matched());
+        assert_eq!(1, stats.timed_out());
+    }
 }
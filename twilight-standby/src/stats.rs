@@ -0,0 +1,194 @@
+//! Statistics about the bystanders of a [`Standby`].
+//!
+//! [`Standby`]: crate::Standby
+
+/// Snapshot of the bystanders registered with a [`Standby`] and of how their
+/// calls completed.
+///
+/// Counts of registered bystanders include bystanders whose receiver was
+/// dropped but that haven't been removed yet, which happens when an event
+/// is next processed for them or when making room for new bystanders. A
+/// steadily growing number of bystanders may indicate calls that are never
+/// completed.
+///
+/// Retrieved via [`Standby::stats`].
+///
+/// [`Standby`]: crate::Standby
+/// [`Standby::stats`]: crate::Standby::stats
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct StandbyStats {
+    /// Number of component bystanders.
+    pub(crate) components: usize,
+    /// Number of bystanders dropped as their receiver was dropped.
+    pub(crate) dropped: u64,
+    /// Number of bystanders for any event.
+    pub(crate) events: usize,
+    /// Number of bystanders evicted due to reaching a limit.
+    pub(crate) evicted: u64,
+    /// Number of guild event bystanders.
+    pub(crate) guilds: usize,
+    /// Number of bystanders for any event from which a value is extracted.
+    pub(crate) maps: usize,
+    /// Number of events sent to bystanders.
+    pub(crate) matched: u64,
+    /// Number of message bystanders.
+    pub(crate) messages: usize,
+    /// Number of modal bystanders.
+    pub(crate) modals: usize,
+    /// Number of poll vote bystanders.
+    pub(crate) poll_votes: usize,
+    /// Number of reaction removal bystanders.
+    pub(crate) reaction_removals: usize,
+    /// Number of reaction bystanders.
+    pub(crate) reactions: usize,
+    /// Number of bystanders rejected due to reaching a limit.
+    pub(crate) rejected: u64,
+    /// Number of calls that timed out.
+    pub(crate) timed_out: u64,
+}
+
+impl StandbyStats {
+    /// Total number of registered bystanders.
+    pub const fn bystanders(&self) -> usize {
+        self.components
+            + self.events
+            + self.guilds
+            + self.maps
+            + self.messages
+            + self.modals
+            + self.poll_votes
+            + self.reaction_removals
+            + self.reactions
+    }
+
+    /// Number of registered bystanders waiting for components, such as via
+    /// [`Standby::wait_for_component`].
+    ///
+    /// [`Standby::wait_for_component`]: crate::Standby::wait_for_component
+    pub const fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Number of bystanders that were matched by an event after their
+    /// receiver was dropped.
+    ///
+    /// Refer to [`ProcessResults::dropped`] for more information.
+    ///
+    /// [`ProcessResults::dropped`]: crate::ProcessResults::dropped
+    pub const fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Number of registered bystanders waiting for any event, such as via
+    /// [`Standby::wait_for_event`].
+    ///
+    /// [`Standby::wait_for_event`]: crate::Standby::wait_for_event
+    pub const fn events(&self) -> usize {
+        self.events
+    }
+
+    /// Number of bystanders evicted due to reaching a limit.
+    ///
+    /// Refer to [`Standby::evicted`] for more information.
+    ///
+    /// [`Standby::evicted`]: crate::Standby::evicted
+    pub const fn evicted(&self) -> u64 {
+        self.evicted
+    }
+
+    /// Number of registered bystanders waiting for an event in a guild, such
+    /// as via [`Standby::wait_for`].
+    ///
+    /// [`Standby::wait_for`]: crate::Standby::wait_for
+    pub const fn guilds(&self) -> usize {
+        self.guilds
+    }
+
+    /// Number of registered bystanders extracting a value from any event,
+    /// such as via [`Standby::wait_for_map`].
+    ///
+    /// [`Standby::wait_for_map`]: crate::Standby::wait_for_map
+    pub const fn maps(&self) -> usize {
+        self.maps
+    }
+
+    /// Number of events sent to bystanders, both fulfilling futures and sent
+    /// to streams.
+    ///
+    /// Refer to [`ProcessResults::matched`] for more information.
+    ///
+    /// [`ProcessResults::matched`]: crate::ProcessResults::matched
+    pub const fn matched(&self) -> u64 {
+        self.matched
+    }
+
+    /// Number of registered bystanders waiting for messages, such as via
+    /// [`Standby::wait_for_message`].
+    ///
+    /// [`Standby::wait_for_message`]: crate::Standby::wait_for_message
+    pub const fn messages(&self) -> usize {
+        self.messages
+    }
+
+    /// Number of registered bystanders waiting for modal submissions, such as
+    /// via [`Standby::wait_for_modal`].
+    ///
+    /// [`Standby::wait_for_modal`]: crate::Standby::wait_for_modal
+    pub const fn modals(&self) -> usize {
+        self.modals
+    }
+
+    /// Number of registered bystanders waiting for poll votes, such as via
+    /// [`Standby::wait_for_poll_vote`].
+    ///
+    /// [`Standby::wait_for_poll_vote`]: crate::Standby::wait_for_poll_vote
+    pub const fn poll_votes(&self) -> usize {
+        self.poll_votes
+    }
+
+    /// Number of registered bystanders waiting for reaction removals, such as
+    /// via [`Standby::wait_for_reaction_remove`].
+    ///
+    /// [`Standby::wait_for_reaction_remove`]: crate::Standby::wait_for_reaction_remove
+    pub const fn reaction_removals(&self) -> usize {
+        self.reaction_removals
+    }
+
+    /// Number of registered bystanders waiting for reactions, such as via
+    /// [`Standby::wait_for_reaction`].
+    ///
+    /// [`Standby::wait_for_reaction`]: crate::Standby::wait_for_reaction
+    pub const fn reactions(&self) -> usize {
+        self.reactions
+    }
+
+    /// Number of bystanders rejected due to reaching a limit.
+    ///
+    /// Refer to [`Standby::rejected`] for more information.
+    ///
+    /// [`Standby::rejected`]: crate::Standby::rejected
+    pub const fn rejected(&self) -> u64 {
+        self.rejected
+    }
+
+    /// Number of calls that timed out before receiving an event, such as via
+    /// [`WaitForMessageFuture::timeout`].
+    ///
+    /// Calls timed out by other means, such as [`tokio::time::timeout`],
+    /// aren't counted.
+    ///
+    /// [`WaitForMessageFuture::timeout`]: crate::future::WaitForMessageFuture::timeout
+    /// [`tokio::time::timeout`]: https://docs.rs/tokio/latest/tokio/time/fn.timeout.html
+    pub const fn timed_out(&self) -> u64 {
+        self.timed_out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StandbyStats;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(StandbyStats: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+}
@@ -0,0 +1,206 @@
+//! Create an activity for a bot's presence with a builder.
+
+use twilight_model::gateway::presence::{Activity, ActivityType, MinimalActivity};
+use twilight_validate::activity::{activity as validate_activity, ActivityValidationError};
+
+/// Name of custom status activities, which is displayed in place of their
+/// state by some clients.
+const CUSTOM_STATUS_NAME: &str = "Custom Status";
+
+/// Create an activity for a bot's presence with a builder.
+///
+/// Bots may only set the name, state, type, and URL of an activity, so unlike
+/// a [`MinimalActivity`] the builder also supports the state, displayed as the
+/// text of custom statuses and below the name of other activities.
+///
+/// The built activity can be passed to [`UpdatePresence`] or set as an
+/// activity of the presence a shard identifies with.
+///
+/// # Examples
+///
+/// Build a streaming activity and a custom status:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::gateway::{
+///     payload::outgoing::UpdatePresence,
+///     presence::{ActivityType, Status},
+/// };
+/// use twilight_util::builder::activity::ActivityBuilder;
+///
+/// let streaming = ActivityBuilder::streaming("Speedruns", "https://twitch.tv/twilight")
+///     .state("Any%")
+///     .validate()?
+///     .build();
+/// assert_eq!(ActivityType::Streaming, streaming.kind);
+///
+/// let custom = ActivityBuilder::custom("Fixing bugs").validate()?.build();
+/// assert_eq!(Some("Fixing bugs"), custom.state.as_deref());
+///
+/// let presence = UpdatePresence::new(vec![streaming], false, None, Status::Online)?;
+/// # Ok(()) }
+/// ```
+///
+/// [`UpdatePresence`]: twilight_model::gateway::payload::outgoing::UpdatePresence
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into an activity"]
+pub struct ActivityBuilder(Activity);
+
+impl ActivityBuilder {
+    /// Create a new builder of an activity of a type.
+    ///
+    /// Refer to the other constructors for builders of specific types.
+    pub fn new(kind: ActivityType, name: impl Into<String>) -> Self {
+        Self(Activity::from(MinimalActivity {
+            kind,
+            name: name.into(),
+            url: None,
+        }))
+    }
+
+    /// Create a new builder of a competing activity.
+    pub fn competing(name: impl Into<String>) -> Self {
+        Self::new(ActivityType::Competing, name)
+    }
+
+    /// Create a new builder of a custom status with its text.
+    pub fn custom(state: impl Into<String>) -> Self {
+        Self::new(ActivityType::Custom, CUSTOM_STATUS_NAME).state(state)
+    }
+
+    /// Create a new builder of a listening activity.
+    pub fn listening(name: impl Into<String>) -> Self {
+        Self::new(ActivityType::Listening, name)
+    }
+
+    /// Create a new builder of a playing activity.
+    pub fn playing(name: impl Into<String>) -> Self {
+        Self::new(ActivityType::Playing, name)
+    }
+
+    /// Create a new builder of a streaming activity.
+    ///
+    /// The URL must link to a Twitch or YouTube stream for clients to display
+    /// the activity as streaming.
+    pub fn streaming(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self::new(ActivityType::Streaming, name).url(url)
+    }
+
+    /// Create a new builder of a watching activity.
+    pub fn watching(name: impl Into<String>) -> Self {
+        Self::new(ActivityType::Watching, name)
+    }
+
+    /// Build into an activity.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a presence"]
+    pub fn build(self) -> Activity {
+        self.0
+    }
+
+    /// Ensure the activity can be set by a bot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`KindUnsupported`] if the type is unknown.
+    ///
+    /// Returns an error of type [`UrlUnsupported`] if a URL is set for an
+    /// activity that isn't streaming.
+    ///
+    /// [`KindUnsupported`]: twilight_validate::activity::ActivityValidationErrorType::KindUnsupported
+    /// [`UrlUnsupported`]: twilight_validate::activity::ActivityValidationErrorType::UrlUnsupported
+    pub fn validate(self) -> Result<Self, ActivityValidationError> {
+        validate_activity(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Set the state.
+    ///
+    /// This is the text of custom statuses, which is created with a state.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.0.state = Some(state.into());
+
+        self
+    }
+
+    /// Set the URL of the stream.
+    ///
+    /// Only streaming activities may have a URL, which they're created with.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = Some(url.into());
+
+        self
+    }
+}
+
+impl TryFrom<ActivityBuilder> for Activity {
+    type Error = ActivityValidationError;
+
+    /// Convert an activity builder into an activity, validating its contents.
+    ///
+    /// This is equivalent to calling [`ActivityBuilder::validate`], then
+    /// [`ActivityBuilder::build`].
+    fn try_from(builder: ActivityBuilder) -> Result<Self, Self::Error> {
+        Ok(builder.validate()?.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_validate::activity::ActivityValidationErrorType;
+
+    assert_impl_all!(ActivityBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Activity: TryFrom<ActivityBuilder>);
+
+    #[test]
+    fn streaming() {
+        let activity = ActivityBuilder::streaming("name", "https://twitch.tv/twilight")
+            .state("state")
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(ActivityType::Streaming, activity.kind);
+        assert_eq!("name", activity.name);
+        assert_eq!(Some("state"), activity.state.as_deref());
+        assert_eq!(Some("https://twitch.tv/twilight"), activity.url.as_deref());
+    }
+
+    #[test]
+    fn custom() {
+        let activity = Activity::try_from(ActivityBuilder::custom("text")).unwrap();
+
+        assert_eq!(ActivityType::Custom, activity.kind);
+        assert_eq!(CUSTOM_STATUS_NAME, activity.name);
+        assert_eq!(Some("text"), activity.state.as_deref());
+        assert!(activity.url.is_none());
+    }
+
+    #[test]
+    fn validate() {
+        let error = ActivityBuilder::playing("name")
+            .url("https://twitch.tv/twilight")
+            .validate()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ActivityValidationErrorType::UrlUnsupported {
+                kind: ActivityType::Playing
+            }
+        ));
+
+        let error = ActivityBuilder::new(ActivityType::Unknown(10), "name")
+            .validate()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ActivityValidationErrorType::KindUnsupported { .. }
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+//! Create an [`Attachment`] with a builder.
+
+use crate::image_data;
+use std::time::Duration;
+use twilight_model::http::attachment::Attachment;
+use twilight_validate::message::{attachment as validate_attachment, MessageValidationError};
+
+/// Prefix of the filename of attachments marked as spoilers.
+const SPOILER_PREFIX: &str = "SPOILER_";
+
+/// Create an [`Attachment`] with a builder.
+///
+/// The filename is sanitized so that it is accepted by Discord: only the last
+/// component of a path is kept and characters other than ASCII alphanumeric
+/// characters, dots, dashes, and underscores are replaced with underscores.
+///
+/// # Examples
+///
+/// Build an attachment marked as a spoiler, with alt text:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_util::builder::attachment::AttachmentBuilder;
+///
+/// let attachment = AttachmentBuilder::new("photos/my cat.png", Vec::new(), 1)
+///     .description("A cat sleeping in a box")
+///     .spoiler()
+///     .validate()?
+///     .build();
+///
+/// assert_eq!("SPOILER_my_cat.png", attachment.filename);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into an attachment"]
+pub struct AttachmentBuilder(Attachment);
+
+impl AttachmentBuilder {
+    /// Create a new builder of an attachment, sanitizing its filename.
+    ///
+    /// The ID must be unique among the attachments of a message.
+    pub fn new(filename: impl AsRef<str>, file: Vec<u8>, id: u64) -> Self {
+        Self(Attachment::from_bytes(
+            sanitize_filename(filename.as_ref()),
+            file,
+            id,
+        ))
+    }
+
+    /// Build into an attachment.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a message"]
+    pub fn build(self) -> Attachment {
+        self.0
+    }
+
+    /// Ensure the attachment is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::message::attachment`] for possible errors.
+    pub fn validate(self) -> Result<Self, MessageValidationError> {
+        validate_attachment(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Set the description, which is displayed as alt text.
+    ///
+    /// Refer to [`ATTACHMENT_DESCIPTION_LENGTH_MAX`] for the maximum number of
+    /// characters that can be in a description.
+    ///
+    /// [`ATTACHMENT_DESCIPTION_LENGTH_MAX`]: twilight_validate::message::ATTACHMENT_DESCIPTION_LENGTH_MAX
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+
+        self
+    }
+
+    /// Mark the attachment as a spoiler, prefixing its filename.
+    pub fn spoiler(mut self) -> Self {
+        if !self.0.filename.starts_with(SPOILER_PREFIX) {
+            self.0.filename.insert_str(0, SPOILER_PREFIX);
+        }
+
+        self
+    }
+
+    /// Set the metadata of a voice message.
+    ///
+    /// The waveform consists of up to 256 samples of the volume, each a byte.
+    ///
+    /// Voice messages must be sent with the [`IS_VOICE_MESSAGE`] flag and as
+    /// the only attachment of the message.
+    ///
+    /// [`IS_VOICE_MESSAGE`]: twilight_model::channel::message::MessageFlags::IS_VOICE_MESSAGE
+    pub fn voice_message(mut self, duration: Duration, waveform: &[u8]) -> Self {
+        self.0.duration_millis = Some(duration.as_millis().try_into().unwrap_or(u64::MAX));
+        self.0.waveform = Some(image_data::base64(waveform));
+
+        self
+    }
+}
+
+impl TryFrom<AttachmentBuilder> for Attachment {
+    type Error = MessageValidationError;
+
+    /// Convert an attachment builder into an attachment, validating its
+    /// contents.
+    ///
+    /// This is equivalent to calling [`AttachmentBuilder::validate`], then
+    /// [`AttachmentBuilder::build`].
+    fn try_from(builder: AttachmentBuilder) -> Result<Self, Self::Error> {
+        Ok(builder.validate()?.build())
+    }
+}
+
+/// Keep the last component of a path and replace the characters that aren't
+/// accepted in filenames.
+fn sanitize_filename(filename: &str) -> String {
+    let filename = filename.rsplit(['/', '\\']).next().unwrap_or_default();
+
+    if filename.is_empty() {
+        return "file".to_owned();
+    }
+
+    filename
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() || matches!(character, '.' | '-' | '_') {
+                character
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(AttachmentBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Attachment: TryFrom<AttachmentBuilder>);
+
+    #[test]
+    fn sanitize() {
+        assert_eq!("cat.png", sanitize_filename("cat.png"));
+        assert_eq!("cat.png", sanitize_filename("C:\\Users\\me\\cat.png"));
+        assert_eq!("my_cat__1_.png", sanitize_filename("my cat (1).png"));
+        assert_eq!("_.txt", sanitize_filename("é.txt"));
+        assert_eq!("file", sanitize_filename("directory/"));
+    }
+
+    #[test]
+    fn builder() {
+        let attachment = AttachmentBuilder::new("voice message.ogg", vec![1, 2], 3)
+            .spoiler()
+            .spoiler()
+            .voice_message(Duration::from_millis(1500), b"foo")
+            .validate()
+            .unwrap()
+            .build();
+
+        let expected = Attachment {
+            description: None,
+            duration_millis: Some(1500),
+            file: vec![1, 2],
+            filename: "SPOILER_voice_message.ogg".to_owned(),
+            id: 3,
+            waveform: Some("Zm9v".to_owned()),
+        };
+
+        assert_eq!(expected, attachment);
+    }
+}
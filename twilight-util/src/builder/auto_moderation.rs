@@ -0,0 +1,809 @@
+//! Create auto moderation rules with a builder.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_http::{response::ResponseFuture, Client};
+use twilight_model::{
+    guild::auto_moderation::{
+        AutoModerationActionType, AutoModerationEventType, AutoModerationKeywordPresetType,
+        AutoModerationRule, AutoModerationTriggerType,
+    },
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{
+    auto_moderation_action_metadata_duration_seconds as validate_duration_seconds,
+    auto_moderation_actions as validate_actions,
+    auto_moderation_block_action_custom_message_limit as validate_custom_message,
+    auto_moderation_exempt_channels as validate_exempt_channels,
+    auto_moderation_exempt_roles as validate_exempt_roles,
+    auto_moderation_metadata_keyword_allow_list as validate_keyword_allow_list,
+    auto_moderation_metadata_keyword_filter as validate_keyword_filter,
+    auto_moderation_metadata_mention_total_limit as validate_mention_total_limit,
+    auto_moderation_metadata_preset_allow_list as validate_preset_allow_list,
+    auto_moderation_metadata_regex_patterns as validate_regex_patterns, ValidationError,
+};
+
+/// Building an auto moderation rule failed.
+#[derive(Debug)]
+pub struct AutoModerationRuleError {
+    kind: AutoModerationRuleErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl AutoModerationRuleError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AutoModerationRuleErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        AutoModerationRuleErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+
+    /// Create an error of type [`Validation`].
+    ///
+    /// [`Validation`]: AutoModerationRuleErrorType::Validation
+    fn validation(source: ValidationError) -> Self {
+        Self {
+            kind: AutoModerationRuleErrorType::Validation,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for AutoModerationRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            AutoModerationRuleErrorType::ActionUnsupported { kind, trigger_type } => {
+                f.write_str("action type ")?;
+                Display::fmt(&u8::from(*kind), f)?;
+                f.write_str(" is not supported by trigger type ")?;
+
+                Display::fmt(&u8::from(*trigger_type), f)
+            }
+            AutoModerationRuleErrorType::ActionsEmpty => f.write_str("rule has no actions"),
+            AutoModerationRuleErrorType::EventTypeUnsupported {
+                event_type,
+                trigger_type,
+            } => {
+                f.write_str("event type ")?;
+                Display::fmt(&u8::from(*event_type), f)?;
+                f.write_str(" is not supported by trigger type ")?;
+
+                Display::fmt(&u8::from(*trigger_type), f)
+            }
+            AutoModerationRuleErrorType::Validation => f.write_str("rule contains invalid fields"),
+        }
+    }
+}
+
+impl Error for AutoModerationRuleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`AutoModerationRuleError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AutoModerationRuleErrorType {
+    /// Action isn't supported by the rule's trigger, such as a
+    /// [`Timeout`] action of a [`Spam`] rule.
+    ///
+    /// [`Spam`]: AutoModerationTriggerType::Spam
+    /// [`Timeout`]: AutoModerationActionType::Timeout
+    ActionUnsupported {
+        /// Type of the unsupported action.
+        kind: AutoModerationActionType,
+        /// Type of the rule's trigger.
+        trigger_type: AutoModerationTriggerType,
+    },
+    /// Rule has no actions.
+    ActionsEmpty,
+    /// Event type isn't supported by the rule's trigger, such as a
+    /// [`MemberProfile`] rule checked when messages are sent.
+    ///
+    /// [`MemberProfile`]: AutoModerationTriggerType::MemberProfile
+    EventTypeUnsupported {
+        /// Event type of the rule.
+        event_type: AutoModerationEventType,
+        /// Type of the rule's trigger.
+        trigger_type: AutoModerationTriggerType,
+    },
+    /// Trigger metadata, an action's metadata, or the exempted channels or
+    /// roles are invalid.
+    ///
+    /// The source is a [`ValidationError`].
+    Validation,
+}
+
+/// Trigger of an auto moderation rule, along with the metadata it requires.
+///
+/// Refer to [Discord Docs/Trigger Metadata] for which metadata is relevant to
+/// each trigger type.
+///
+/// [Discord Docs/Trigger Metadata]: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AutoModerationTrigger {
+    /// Check if content contains words from a user defined list of keywords.
+    Keyword {
+        /// Substrings exempt from triggering the rule.
+        allow_list: Vec<String>,
+        /// Substrings which will be searched for in content.
+        keyword_filter: Vec<String>,
+        /// Regular expression patterns which will be matched against content.
+        regex_patterns: Vec<String>,
+    },
+    /// Check if content contains words from internal pre-defined wordsets.
+    KeywordPreset {
+        /// Substrings exempt from triggering the rule.
+        allow_list: Vec<String>,
+        /// Internally pre-defined wordsets which will be searched for in
+        /// content.
+        presets: Vec<AutoModerationKeywordPresetType>,
+    },
+    /// Check if member profiles contain words from a user defined list of
+    /// keywords.
+    MemberProfile {
+        /// Substrings exempt from triggering the rule.
+        allow_list: Vec<String>,
+        /// Substrings which will be searched for in member profiles.
+        keyword_filter: Vec<String>,
+        /// Regular expression patterns which will be matched against member
+        /// profiles.
+        regex_patterns: Vec<String>,
+    },
+    /// Check if content contains more unique mentions than allowed.
+    MentionSpam {
+        /// Whether to automatically detect mention raids.
+        mention_raid_protection_enabled: bool,
+        /// Total number of unique role and user mentions allowed per
+        /// message.
+        mention_total_limit: u8,
+    },
+    /// Check if content represents generic spam.
+    Spam,
+}
+
+impl AutoModerationTrigger {
+    /// Type of the trigger.
+    pub const fn kind(&self) -> AutoModerationTriggerType {
+        match self {
+            Self::Keyword { .. } => AutoModerationTriggerType::Keyword,
+            Self::KeywordPreset { .. } => AutoModerationTriggerType::KeywordPreset,
+            Self::MemberProfile { .. } => AutoModerationTriggerType::MemberProfile,
+            Self::MentionSpam { .. } => AutoModerationTriggerType::MentionSpam,
+            Self::Spam => AutoModerationTriggerType::Spam,
+        }
+    }
+
+    /// Whether rules with the trigger may execute an action type.
+    ///
+    /// Timeouts are only supported by [`Keyword`] and [`MentionSpam`] rules,
+    /// and [`MemberProfile`] rules block member interactions instead of
+    /// messages.
+    ///
+    /// [`Keyword`]: Self::Keyword
+    /// [`MemberProfile`]: Self::MemberProfile
+    /// [`MentionSpam`]: Self::MentionSpam
+    pub const fn supports_action(&self, kind: AutoModerationActionType) -> bool {
+        match kind {
+            AutoModerationActionType::BlockMemberInteraction => {
+                matches!(self, Self::MemberProfile { .. })
+            }
+            AutoModerationActionType::BlockMessage => !matches!(self, Self::MemberProfile { .. }),
+            AutoModerationActionType::SendAlertMessage => true,
+            AutoModerationActionType::Timeout => {
+                matches!(self, Self::Keyword { .. } | Self::MentionSpam { .. })
+            }
+            AutoModerationActionType::Unknown(_) => false,
+        }
+    }
+
+    /// Event type the trigger checks.
+    ///
+    /// [`MemberProfile`] rules check members when they join or update their
+    /// profile, all other rules check messages when they're sent.
+    ///
+    /// [`MemberProfile`]: Self::MemberProfile
+    pub const fn event_type(&self) -> AutoModerationEventType {
+        match self {
+            Self::MemberProfile { .. } => AutoModerationEventType::MemberUpdate,
+            _ => AutoModerationEventType::MessageSend,
+        }
+    }
+
+    /// Ensure the metadata of the trigger is valid.
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::Keyword {
+                allow_list,
+                keyword_filter,
+                regex_patterns,
+            }
+            | Self::MemberProfile {
+                allow_list,
+                keyword_filter,
+                regex_patterns,
+            } => {
+                validate_keyword_allow_list(allow_list)?;
+                validate_keyword_filter(keyword_filter)?;
+                validate_regex_patterns(regex_patterns)
+            }
+            Self::KeywordPreset { allow_list, .. } => validate_preset_allow_list(allow_list),
+            Self::MentionSpam {
+                mention_total_limit,
+                ..
+            } => validate_mention_total_limit(*mention_total_limit),
+            Self::Spam => Ok(()),
+        }
+    }
+}
+
+/// Action of an auto moderation rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Action {
+    BlockMemberInteraction,
+    BlockMessage { custom_message: Option<String> },
+    SendAlertMessage { channel_id: Id<ChannelMarker> },
+    Timeout { duration_seconds: u32 },
+}
+
+impl Action {
+    /// Type of the action.
+    const fn kind(&self) -> AutoModerationActionType {
+        match self {
+            Self::BlockMemberInteraction => AutoModerationActionType::BlockMemberInteraction,
+            Self::BlockMessage { .. } => AutoModerationActionType::BlockMessage,
+            Self::SendAlertMessage { .. } => AutoModerationActionType::SendAlertMessage,
+            Self::Timeout { .. } => AutoModerationActionType::Timeout,
+        }
+    }
+
+    /// Ensure the metadata of the action is valid.
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::BlockMessage {
+                custom_message: Some(custom_message),
+            } => validate_custom_message(custom_message),
+            Self::Timeout { duration_seconds } => validate_duration_seconds(*duration_seconds),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Create an auto moderation rule with a builder.
+///
+/// The [trigger] determines the metadata that must be provided, along with
+/// the event type and the actions the rule supports. Rules are enabled by
+/// default.
+///
+/// # Examples
+///
+/// Create a rule blocking messages containing "darn" and alerting moderators:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::Client;
+/// use twilight_model::{guild::auto_moderation::AutoModerationEventType, id::Id};
+/// use twilight_util::builder::auto_moderation::{
+///     AutoModerationRuleBuilder, AutoModerationTrigger,
+/// };
+///
+/// let client = Client::new("my token".to_owned());
+///
+/// let trigger = AutoModerationTrigger::Keyword {
+///     allow_list: vec!["darn it".to_owned()],
+///     keyword_filter: vec!["darn".to_owned()],
+///     regex_patterns: Vec::new(),
+/// };
+///
+/// let rule = AutoModerationRuleBuilder::new("no darns", AutoModerationEventType::MessageSend, trigger)
+///     .action_block_message()
+///     .action_send_alert_message(Id::new(1))
+///     .build(&client, Id::new(2))?
+///     .await?
+///     .model()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [trigger]: AutoModerationTrigger
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a request creating the rule"]
+pub struct AutoModerationRuleBuilder {
+    /// Actions which will execute when the rule is triggered.
+    actions: Vec<Action>,
+    /// Whether the rule is enabled.
+    enabled: bool,
+    /// Event context in which the rule should be checked.
+    event_type: AutoModerationEventType,
+    /// Channels that should not be affected by the rule.
+    exempt_channels: Vec<Id<ChannelMarker>>,
+    /// Roles that should not be affected by the rule.
+    exempt_roles: Vec<Id<RoleMarker>>,
+    /// Name of the rule.
+    name: String,
+    /// Trigger of the rule.
+    trigger: AutoModerationTrigger,
+}
+
+impl AutoModerationRuleBuilder {
+    /// Create a new auto moderation rule builder.
+    ///
+    /// Refer to [`AutoModerationTrigger::event_type`] for the event type the
+    /// trigger supports.
+    pub fn new(
+        name: impl Into<String>,
+        event_type: AutoModerationEventType,
+        trigger: AutoModerationTrigger,
+    ) -> Self {
+        Self {
+            actions: Vec::new(),
+            enabled: true,
+            event_type,
+            exempt_channels: Vec::new(),
+            exempt_roles: Vec::new(),
+            name: name.into(),
+            trigger,
+        }
+    }
+
+    /// Validate the rule and build it into a request creating it in a guild.
+    ///
+    /// The returned future creates the rule via
+    /// [`Client::create_auto_moderation_rule`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ActionsEmpty`] if the rule has no actions.
+    ///
+    /// Returns an error of type [`ActionUnsupported`] if an action isn't
+    /// supported by the trigger, refer to
+    /// [`AutoModerationTrigger::supports_action`].
+    ///
+    /// Returns an error of type [`EventTypeUnsupported`] if the event type
+    /// isn't supported by the trigger, refer to
+    /// [`AutoModerationTrigger::event_type`].
+    ///
+    /// Returns an error of type [`Validation`] if the trigger's metadata, the
+    /// number of actions, an action's metadata, or the exempted channels or
+    /// roles are invalid. Refer to the auto moderation validation functions
+    /// of [`twilight_validate::request`] for the possible errors.
+    ///
+    /// [`ActionUnsupported`]: AutoModerationRuleErrorType::ActionUnsupported
+    /// [`ActionsEmpty`]: AutoModerationRuleErrorType::ActionsEmpty
+    /// [`EventTypeUnsupported`]: AutoModerationRuleErrorType::EventTypeUnsupported
+    /// [`Validation`]: AutoModerationRuleErrorType::Validation
+    pub fn build(
+        self,
+        client: &Client,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<ResponseFuture<AutoModerationRule>, AutoModerationRuleError> {
+        self.validate()?;
+
+        let mut request = client
+            .create_auto_moderation_rule(guild_id, &self.name, self.event_type)
+            .enabled(self.enabled);
+
+        for action in &self.actions {
+            request = match action {
+                Action::BlockMemberInteraction => request.action_block_member_interaction(),
+                Action::BlockMessage {
+                    custom_message: Some(custom_message),
+                } => request.action_block_message_with_explanation(custom_message),
+                Action::BlockMessage {
+                    custom_message: None,
+                } => request.action_block_message(),
+                Action::SendAlertMessage { channel_id } => {
+                    request.action_send_alert_message(*channel_id)
+                }
+                Action::Timeout { duration_seconds } => request.action_timeout(*duration_seconds),
+            };
+        }
+
+        if !self.exempt_channels.is_empty() {
+            request = request.exempt_channels(&self.exempt_channels);
+        }
+
+        if !self.exempt_roles.is_empty() {
+            request = request.exempt_roles(&self.exempt_roles);
+        }
+
+        Ok(match &self.trigger {
+            AutoModerationTrigger::Keyword {
+                allow_list,
+                keyword_filter,
+                regex_patterns,
+            } => request.with_keyword(
+                &strs(keyword_filter),
+                &strs(regex_patterns),
+                &strs(allow_list),
+            ),
+            AutoModerationTrigger::KeywordPreset {
+                allow_list,
+                presets,
+            } => request.with_keyword_preset(presets, &strs(allow_list)),
+            AutoModerationTrigger::MemberProfile {
+                allow_list,
+                keyword_filter,
+                regex_patterns,
+            } => request.with_member_profile(
+                &strs(keyword_filter),
+                &strs(regex_patterns),
+                &strs(allow_list),
+            ),
+            AutoModerationTrigger::MentionSpam {
+                mention_raid_protection_enabled,
+                mention_total_limit,
+            } => request
+                .mention_raid_protection_enabled(*mention_raid_protection_enabled)
+                .with_mention_spam(*mention_total_limit),
+            AutoModerationTrigger::Spam => request.with_spam(),
+        })
+    }
+
+    /// Append an action of type [`BlockMemberInteraction`], only supported by
+    /// [`MemberProfile`] rules.
+    ///
+    /// [`BlockMemberInteraction`]: AutoModerationActionType::BlockMemberInteraction
+    /// [`MemberProfile`]: AutoModerationTrigger::MemberProfile
+    pub fn action_block_member_interaction(self) -> Self {
+        self.action(Action::BlockMemberInteraction)
+    }
+
+    /// Append an action of type [`BlockMessage`].
+    ///
+    /// [`BlockMessage`]: AutoModerationActionType::BlockMessage
+    pub fn action_block_message(self) -> Self {
+        self.action(Action::BlockMessage {
+            custom_message: None,
+        })
+    }
+
+    /// Append an action of type [`BlockMessage`] with an explanation shown to
+    /// members whenever their message is blocked.
+    ///
+    /// Refer to [`AUTO_MODERATION_ACTION_BLOCK_CUSTOM_MESSAGE_LENGTH_MAX`] for
+    /// the maximum number of characters that can be in the explanation.
+    ///
+    /// [`AUTO_MODERATION_ACTION_BLOCK_CUSTOM_MESSAGE_LENGTH_MAX`]: twilight_validate::request::AUTO_MODERATION_ACTION_BLOCK_CUSTOM_MESSAGE_LENGTH_MAX
+    /// [`BlockMessage`]: AutoModerationActionType::BlockMessage
+    pub fn action_block_message_with_explanation(self, custom_message: impl Into<String>) -> Self {
+        self.action(Action::BlockMessage {
+            custom_message: Some(custom_message.into()),
+        })
+    }
+
+    /// Append an action of type [`SendAlertMessage`], logging content to a
+    /// channel.
+    ///
+    /// [`SendAlertMessage`]: AutoModerationActionType::SendAlertMessage
+    pub fn action_send_alert_message(self, channel_id: Id<ChannelMarker>) -> Self {
+        self.action(Action::SendAlertMessage { channel_id })
+    }
+
+    /// Append an action of type [`Timeout`], timing out the member for a
+    /// duration in seconds.
+    ///
+    /// Timeouts can only be set up for [`Keyword`] and [`MentionSpam`] rules.
+    ///
+    /// Refer to [`AUTO_MODERATION_ACTION_METADATA_DURATION_SECONDS_MAX`] for
+    /// the maximum duration.
+    ///
+    /// [`AUTO_MODERATION_ACTION_METADATA_DURATION_SECONDS_MAX`]: twilight_validate::request::AUTO_MODERATION_ACTION_METADATA_DURATION_SECONDS_MAX
+    /// [`Keyword`]: AutoModerationTrigger::Keyword
+    /// [`MentionSpam`]: AutoModerationTrigger::MentionSpam
+    /// [`Timeout`]: AutoModerationActionType::Timeout
+    pub fn action_timeout(self, duration_seconds: u32) -> Self {
+        self.action(Action::Timeout { duration_seconds })
+    }
+
+    /// Set whether the rule is enabled.
+    ///
+    /// Defaults to `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+
+        self
+    }
+
+    /// Exempt a channel from the rule.
+    ///
+    /// Refer to [`AUTO_MODERATION_EXEMPT_CHANNELS_MAX`] for the maximum
+    /// number of exempt channels.
+    ///
+    /// [`AUTO_MODERATION_EXEMPT_CHANNELS_MAX`]: twilight_validate::request::AUTO_MODERATION_EXEMPT_CHANNELS_MAX
+    pub fn exempt_channel(mut self, channel_id: Id<ChannelMarker>) -> Self {
+        self.exempt_channels.push(channel_id);
+
+        self
+    }
+
+    /// Exempt a role from the rule.
+    ///
+    /// Refer to [`AUTO_MODERATION_EXEMPT_ROLES_MAX`] for the maximum number of
+    /// exempt roles.
+    ///
+    /// [`AUTO_MODERATION_EXEMPT_ROLES_MAX`]: twilight_validate::request::AUTO_MODERATION_EXEMPT_ROLES_MAX
+    pub fn exempt_role(mut self, role_id: Id<RoleMarker>) -> Self {
+        self.exempt_roles.push(role_id);
+
+        self
+    }
+
+    /// Append an action.
+    fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+
+        self
+    }
+
+    /// Ensure the rule is valid.
+    fn validate(&self) -> Result<(), AutoModerationRuleError> {
+        let trigger_type = self.trigger.kind();
+
+        if self.event_type != self.trigger.event_type() {
+            return Err(AutoModerationRuleError {
+                kind: AutoModerationRuleErrorType::EventTypeUnsupported {
+                    event_type: self.event_type,
+                    trigger_type,
+                },
+                source: None,
+            });
+        }
+
+        if self.actions.is_empty() {
+            return Err(AutoModerationRuleError {
+                kind: AutoModerationRuleErrorType::ActionsEmpty,
+                source: None,
+            });
+        }
+
+        if let Some(action) = self
+            .actions
+            .iter()
+            .find(|action| !self.trigger.supports_action(action.kind()))
+        {
+            return Err(AutoModerationRuleError {
+                kind: AutoModerationRuleErrorType::ActionUnsupported {
+                    kind: action.kind(),
+                    trigger_type,
+                },
+                source: None,
+            });
+        }
+
+        self.trigger
+            .validate()
+            .and_then(|()| validate_actions(self.actions.len()))
+            .and_then(|()| self.actions.iter().try_for_each(Action::validate))
+            .and_then(|()| validate_exempt_channels(&self.exempt_channels))
+            .and_then(|()| validate_exempt_roles(&self.exempt_roles))
+            .map_err(AutoModerationRuleError::validation)
+    }
+}
+
+/// Borrow a list of strings as string slices.
+fn strs(strings: &[String]) -> Vec<&str> {
+    strings.iter().map(String::as_str).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_validate::request::ValidationErrorType;
+
+    assert_impl_all!(AutoModerationRuleBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(AutoModerationRuleError: Debug, Error, Send, Sync);
+
+    fn validation_error(error: AutoModerationRuleError) -> ValidationErrorType {
+        let (kind, source) = error.into_parts();
+        assert!(matches!(kind, AutoModerationRuleErrorType::Validation));
+
+        source
+            .unwrap()
+            .downcast::<ValidationError>()
+            .unwrap()
+            .into_parts()
+            .0
+    }
+
+    #[test]
+    fn keyword() {
+        let trigger = AutoModerationTrigger::Keyword {
+            allow_list: vec!["darn it".to_owned()],
+            keyword_filter: vec!["darn".to_owned()],
+            regex_patterns: Vec::new(),
+        };
+        let builder = AutoModerationRuleBuilder::new(
+            "no darns",
+            AutoModerationEventType::MessageSend,
+            trigger,
+        )
+        .action_block_message_with_explanation("no")
+        .action_timeout(60)
+        .exempt_role(Id::new(1));
+
+        assert!(builder.validate().is_ok());
+        assert_eq!(2, builder.actions.len());
+        assert_eq!(vec![Id::new(1)], builder.exempt_roles);
+    }
+
+    #[test]
+    fn member_profile() {
+        let trigger = AutoModerationTrigger::MemberProfile {
+            allow_list: Vec::new(),
+            keyword_filter: vec!["darn".to_owned()],
+            regex_patterns: Vec::new(),
+        };
+        assert_eq!(AutoModerationEventType::MemberUpdate, trigger.event_type());
+
+        let builder = AutoModerationRuleBuilder::new(
+            "no darns",
+            AutoModerationEventType::MemberUpdate,
+            trigger.clone(),
+        )
+        .action_block_member_interaction()
+        .action_send_alert_message(Id::new(1));
+        assert!(builder.validate().is_ok());
+
+        let error = AutoModerationRuleBuilder::new(
+            "no darns",
+            AutoModerationEventType::MessageSend,
+            trigger,
+        )
+        .action_block_member_interaction()
+        .validate()
+        .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            AutoModerationRuleErrorType::EventTypeUnsupported {
+                event_type: AutoModerationEventType::MessageSend,
+                trigger_type: AutoModerationTriggerType::MemberProfile,
+            }
+        ));
+    }
+
+    #[test]
+    fn actions() {
+        let error = AutoModerationRuleBuilder::new(
+            "spam",
+            AutoModerationEventType::MessageSend,
+            AutoModerationTrigger::Spam,
+        )
+        .validate()
+        .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            AutoModerationRuleErrorType::ActionsEmpty
+        ));
+
+        let error = AutoModerationRuleBuilder::new(
+            "spam",
+            AutoModerationEventType::MessageSend,
+            AutoModerationTrigger::Spam,
+        )
+        .action_block_message()
+        .action_timeout(60)
+        .validate()
+        .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            AutoModerationRuleErrorType::ActionUnsupported {
+                kind: AutoModerationActionType::Timeout,
+                trigger_type: AutoModerationTriggerType::Spam,
+            }
+        ));
+
+        let error = AutoModerationRuleBuilder::new(
+            "spam",
+            AutoModerationEventType::MessageSend,
+            AutoModerationTrigger::Spam,
+        )
+        .action_block_member_interaction()
+        .validate()
+        .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            AutoModerationRuleErrorType::ActionUnsupported {
+                kind: AutoModerationActionType::BlockMemberInteraction,
+                trigger_type: AutoModerationTriggerType::Spam,
+            }
+        ));
+    }
+
+    #[test]
+    fn validate() {
+        let mention_spam = AutoModerationTrigger::MentionSpam {
+            mention_raid_protection_enabled: true,
+            mention_total_limit: 100,
+        };
+        let error = AutoModerationRuleBuilder::new(
+            "mentions",
+            AutoModerationEventType::MessageSend,
+            mention_spam,
+        )
+        .action_block_message()
+        .validate()
+        .unwrap_err();
+        assert!(matches!(
+            validation_error(error),
+            ValidationErrorType::AutoModerationMetadataMentionTotalLimit { .. }
+        ));
+
+        let mention_spam = AutoModerationTrigger::MentionSpam {
+            mention_raid_protection_enabled: true,
+            mention_total_limit: 10,
+        };
+        let error = AutoModerationRuleBuilder::new(
+            "timeout",
+            AutoModerationEventType::MessageSend,
+            mention_spam,
+        )
+        .action_timeout(u32::MAX)
+        .validate()
+        .unwrap_err();
+        assert!(matches!(
+            validation_error(error),
+            ValidationErrorType::AutoModerationActionMetadataDurationSeconds { .. }
+        ));
+
+        let error = AutoModerationRuleBuilder::new(
+            "actions",
+            AutoModerationEventType::MessageSend,
+            AutoModerationTrigger::Spam,
+        )
+        .action_block_message()
+        .action_block_message()
+        .action_block_message()
+        .action_block_message()
+        .validate()
+        .unwrap_err();
+        assert!(matches!(
+            validation_error(error),
+            ValidationErrorType::AutoModerationActions { len: 4 }
+        ));
+    }
+
+    #[tokio::test]
+    async fn build() {
+        let client = Client::new(String::new());
+        let builder = AutoModerationRuleBuilder::new(
+            "mentions",
+            AutoModerationEventType::MessageSend,
+            AutoModerationTrigger::MentionSpam {
+                mention_raid_protection_enabled: true,
+                mention_total_limit: 10,
+            },
+        )
+        .action_block_message()
+        .exempt_channel(Id::new(1));
+
+        assert!(builder.build(&client, Id::new(2)).is_ok());
+    }
+}
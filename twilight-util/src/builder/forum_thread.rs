@@ -0,0 +1,336 @@
+//! Create the fields of a post in a forum or media channel with a builder.
+
+use twilight_model::{
+    channel::{
+        message::{AllowedMentions, Component, Embed, MessageFlags},
+        thread::AutoArchiveDuration,
+    },
+    http::attachment::Attachment,
+    id::{
+        marker::{StickerMarker, TagMarker},
+        Id,
+    },
+};
+
+/// Fields of a post in a forum or media channel, built by a
+/// [`ForumThreadBuilder`].
+///
+/// The fields can be passed to the request creating a forum thread, whose
+/// methods have the same names as the fields. The fields of the
+/// [`message`] can be passed to the request's message.
+///
+/// [`message`]: Self::message
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct ForumThreadFields {
+    /// Tags of the channel applied to the post.
+    pub applied_tags: Option<Vec<Id<TagMarker>>>,
+    /// Duration without messages after which the post is archived.
+    pub auto_archive_duration: Option<AutoArchiveDuration>,
+    /// First message of the post.
+    pub message: ForumThreadMessageFields,
+    /// Name of the post.
+    pub name: String,
+    /// Number of seconds a user must wait between sending messages.
+    pub rate_limit_per_user: Option<u16>,
+}
+
+/// Fields of the first message of a post in a forum or media channel.
+///
+/// At least one of the attachments, content, embeds, or stickers is present.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct ForumThreadMessageFields {
+    /// Allowed mentions of the message.
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Attachments of the message.
+    pub attachments: Option<Vec<Attachment>>,
+    /// Components of the message.
+    pub components: Option<Vec<Component>>,
+    /// Content of the message.
+    pub content: Option<String>,
+    /// Embeds of the message.
+    pub embeds: Option<Vec<Embed>>,
+    /// Flags of the message.
+    pub flags: Option<MessageFlags>,
+    /// Stickers of the message.
+    pub sticker_ids: Option<Vec<Id<StickerMarker>>>,
+}
+
+/// Create the fields of a post in a forum or media channel with a builder.
+///
+/// Posts must be created with a first message that isn't empty, so the
+/// builder is created with either the message's content, an attachment, an
+/// embed, or a sticker.
+///
+/// The fields are validated when passed to the request creating the post.
+///
+/// # Examples
+///
+/// Build a post with a tag applied that is archived after a day without
+/// messages:
+///
+/// ```
+/// use twilight_model::{channel::thread::AutoArchiveDuration, id::Id};
+/// use twilight_util::builder::forum_thread::ForumThreadBuilder;
+///
+/// let post = ForumThreadBuilder::with_content("Feedback", "What should we add next?")
+///     .applied_tags(vec![Id::new(1)])
+///     .auto_archive_duration(AutoArchiveDuration::Day)
+///     .rate_limit_per_user(60)
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[must_use = "must be built into forum thread fields"]
+pub struct ForumThreadBuilder(ForumThreadFields);
+
+impl ForumThreadBuilder {
+    /// Create a new builder of a post whose first message has an attachment.
+    pub fn with_attachment(name: impl Into<String>, attachment: Attachment) -> Self {
+        Self::new(
+            name,
+            ForumThreadMessageFields {
+                attachments: Some(vec![attachment]),
+                ..ForumThreadMessageFields::empty()
+            },
+        )
+    }
+
+    /// Create a new builder of a post whose first message has content.
+    pub fn with_content(name: impl Into<String>, content: impl Into<String>) -> Self {
+        Self::new(
+            name,
+            ForumThreadMessageFields {
+                content: Some(content.into()),
+                ..ForumThreadMessageFields::empty()
+            },
+        )
+    }
+
+    /// Create a new builder of a post whose first message has an embed.
+    pub fn with_embed(name: impl Into<String>, embed: Embed) -> Self {
+        Self::new(
+            name,
+            ForumThreadMessageFields {
+                embeds: Some(vec![embed]),
+                ..ForumThreadMessageFields::empty()
+            },
+        )
+    }
+
+    /// Create a new builder of a post whose first message has a sticker.
+    pub fn with_sticker(name: impl Into<String>, sticker_id: Id<StickerMarker>) -> Self {
+        Self::new(
+            name,
+            ForumThreadMessageFields {
+                sticker_ids: Some(vec![sticker_id]),
+                ..ForumThreadMessageFields::empty()
+            },
+        )
+    }
+
+    /// Build into the fields of a post.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used when creating a forum thread"]
+    pub fn build(self) -> ForumThreadFields {
+        self.0
+    }
+
+    /// Set the allowed mentions of the first message.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.0.message.allowed_mentions = Some(allowed_mentions);
+
+        self
+    }
+
+    /// Set the tags of the channel applied to the post.
+    ///
+    /// Refer to [`CHANNEL_APPLIED_TAGS_MAX`] for the maximum number of tags
+    /// that can be applied.
+    ///
+    /// [`CHANNEL_APPLIED_TAGS_MAX`]: twilight_validate::channel::CHANNEL_APPLIED_TAGS_MAX
+    pub fn applied_tags(mut self, applied_tags: Vec<Id<TagMarker>>) -> Self {
+        self.0.applied_tags = Some(applied_tags);
+
+        self
+    }
+
+    /// Add an attachment to the first message.
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.0
+            .message
+            .attachments
+            .get_or_insert_with(Vec::new)
+            .push(attachment);
+
+        self
+    }
+
+    /// Set the duration without messages after which the post is archived.
+    pub const fn auto_archive_duration(
+        mut self,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> Self {
+        self.0.auto_archive_duration = Some(auto_archive_duration);
+
+        self
+    }
+
+    /// Set the components of the first message.
+    pub fn components(mut self, components: Vec<Component>) -> Self {
+        self.0.message.components = Some(components);
+
+        self
+    }
+
+    /// Set the content of the first message.
+    ///
+    /// Posts created with an attachment, embed or sticker may additionally
+    /// have content.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.0.message.content = Some(content.into());
+
+        self
+    }
+
+    /// Add an embed to the first message.
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.0
+            .message
+            .embeds
+            .get_or_insert_with(Vec::new)
+            .push(embed);
+
+        self
+    }
+
+    /// Set the flags of the first message.
+    ///
+    /// Only [`SUPPRESS_EMBEDS`] and [`SUPPRESS_NOTIFICATIONS`] may be set.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: MessageFlags::SUPPRESS_EMBEDS
+    /// [`SUPPRESS_NOTIFICATIONS`]: MessageFlags::SUPPRESS_NOTIFICATIONS
+    pub const fn flags(mut self, flags: MessageFlags) -> Self {
+        self.0.message.flags = Some(flags);
+
+        self
+    }
+
+    /// Set the number of seconds a user must wait between sending messages.
+    ///
+    /// Refer to [`CHANNEL_RATE_LIMIT_PER_USER_MAX`] for the maximum number of
+    /// seconds.
+    ///
+    /// [`CHANNEL_RATE_LIMIT_PER_USER_MAX`]: twilight_validate::channel::CHANNEL_RATE_LIMIT_PER_USER_MAX
+    pub const fn rate_limit_per_user(mut self, rate_limit_per_user: u16) -> Self {
+        self.0.rate_limit_per_user = Some(rate_limit_per_user);
+
+        self
+    }
+
+    /// Add a sticker to the first message.
+    pub fn sticker(mut self, sticker_id: Id<StickerMarker>) -> Self {
+        self.0
+            .message
+            .sticker_ids
+            .get_or_insert_with(Vec::new)
+            .push(sticker_id);
+
+        self
+    }
+
+    /// Create a new builder of a post with a first message.
+    fn new(name: impl Into<String>, message: ForumThreadMessageFields) -> Self {
+        Self(ForumThreadFields {
+            applied_tags: None,
+            auto_archive_duration: None,
+            message,
+            name: name.into(),
+            rate_limit_per_user: None,
+        })
+    }
+}
+
+impl ForumThreadMessageFields {
+    /// Fields of a message without any content.
+    const fn empty() -> Self {
+        Self {
+            allowed_mentions: None,
+            attachments: None,
+            components: None,
+            content: None,
+            embeds: None,
+            flags: None,
+            sticker_ids: None,
+        }
+    }
+}
+
+impl From<ForumThreadBuilder> for ForumThreadFields {
+    /// Convert a forum thread builder into the fields of a post.
+    ///
+    /// This is equivalent to calling [`ForumThreadBuilder::build`].
+    fn from(builder: ForumThreadBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::embed::EmbedBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(ForumThreadBuilder: Clone, Debug, PartialEq, Send, Sync);
+    assert_impl_all!(ForumThreadFields: From<ForumThreadBuilder>);
+    assert_impl_all!(ForumThreadMessageFields: Clone, Debug, PartialEq, Send, Sync);
+
+    #[test]
+    fn with_content() {
+        let fields = ForumThreadBuilder::with_content("name", "content")
+            .applied_tags(vec![Id::new(1), Id::new(2)])
+            .auto_archive_duration(AutoArchiveDuration::Week)
+            .flags(MessageFlags::SUPPRESS_NOTIFICATIONS)
+            .rate_limit_per_user(60)
+            .build();
+
+        assert_eq!("name", fields.name);
+        assert_eq!(Some(vec![Id::new(1), Id::new(2)]), fields.applied_tags);
+        assert_eq!(
+            Some(AutoArchiveDuration::Week),
+            fields.auto_archive_duration
+        );
+        assert_eq!(Some(60), fields.rate_limit_per_user);
+        assert_eq!(Some("content"), fields.message.content.as_deref());
+        assert_eq!(
+            Some(MessageFlags::SUPPRESS_NOTIFICATIONS),
+            fields.message.flags
+        );
+        assert!(fields.message.embeds.is_none());
+    }
+
+    #[test]
+    fn with_embed() {
+        let embed = EmbedBuilder::new().title("title").build();
+        let fields = ForumThreadBuilder::with_embed("name", embed.clone())
+            .embed(embed.clone())
+            .sticker(Id::new(1))
+            .build();
+
+        assert_eq!(Some(vec![embed.clone(), embed]), fields.message.embeds);
+        assert_eq!(Some(vec![Id::new(1)]), fields.message.sticker_ids);
+        assert!(fields.message.content.is_none());
+    }
+
+    #[test]
+    fn with_attachment() {
+        let attachment = Attachment::from_bytes("image.png".to_owned(), vec![1, 2, 3], 1);
+        let fields = ForumThreadBuilder::with_attachment("name", attachment.clone())
+            .content("content")
+            .build();
+
+        assert_eq!(Some(vec![attachment]), fields.message.attachments);
+        assert_eq!(Some("content"), fields.message.content.as_deref());
+    }
+}
@@ -72,6 +72,18 @@ impl InteractionResponseDataBuilder {
         self
     }
 
+    /// Add an attachment to the message.
+    ///
+    /// Defaults to [`None`].
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.0
+            .attachments
+            .get_or_insert_with(Vec::new)
+            .push(attachment);
+
+        self
+    }
+
     /// Set the attachments of the message.
     ///
     /// Defaults to [`None`].
@@ -81,6 +93,18 @@ impl InteractionResponseDataBuilder {
         self
     }
 
+    /// Add an autocomplete choice to the response.
+    ///
+    /// Only valid when the type of the interaction is
+    /// [`ApplicationCommandAutocompleteResult`].
+    ///
+    /// [`ApplicationCommandAutocompleteResult`]: twilight_model::http::interaction::InteractionResponseType::ApplicationCommandAutocompleteResult
+    pub fn choice(mut self, choice: CommandOptionChoice) -> Self {
+        self.0.choices.get_or_insert_with(Vec::new).push(choice);
+
+        self
+    }
+
     /// Set the autocomplete choices of the response.
     ///
     /// Only valid when the type of the interaction is
@@ -93,6 +117,18 @@ impl InteractionResponseDataBuilder {
         self
     }
 
+    /// Add a message [`Component`] to the callback.
+    ///
+    /// Defaults to [`None`].
+    pub fn component(mut self, component: Component) -> Self {
+        self.0
+            .components
+            .get_or_insert_with(Vec::new)
+            .push(component);
+
+        self
+    }
+
     /// Set the message [`Component`]s of the callback.
     ///
     /// Defaults to [`None`].
@@ -120,9 +156,18 @@ impl InteractionResponseDataBuilder {
         self
     }
 
+    /// Add an [`Embed`] to the callback.
+    ///
+    /// Defaults to [`None`].
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.0.embeds.get_or_insert_with(Vec::new).push(embed);
+
+        self
+    }
+
     /// Set the [`Embed`]s of the callback.
     ///
-    /// Defaults to an empty list.
+    /// Defaults to [`None`].
     pub fn embeds(mut self, embeds: impl IntoIterator<Item = Embed>) -> Self {
         self.0.embeds = Some(embeds.into_iter().collect());
 
@@ -168,12 +213,19 @@ impl Default for InteractionResponseDataBuilder {
     }
 }
 
+impl From<InteractionResponseData> for InteractionResponseDataBuilder {
+    fn from(value: InteractionResponseData) -> Self {
+        Self(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
     use twilight_model::{
+        application::command::CommandOptionChoiceValue,
         channel::message::{
             component::{Button, ButtonStyle},
             MentionType,
@@ -246,4 +298,28 @@ mod tests {
 
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn append() {
+        let choice = CommandOptionChoice {
+            name: "name".to_owned(),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::Integer(1),
+        };
+
+        let value = InteractionResponseDataBuilder::new()
+            .choice(choice.clone())
+            .choice(choice.clone())
+            .build();
+
+        assert_eq!(Some(vec![choice.clone(), choice.clone()]), value.choices);
+
+        let value = InteractionResponseDataBuilder::from(value)
+            .choices([choice.clone()])
+            .choice(choice.clone())
+            .build();
+
+        assert_eq!(Some(vec![choice.clone(), choice]), value.choices);
+        assert!(value.embeds.is_none());
+    }
 }
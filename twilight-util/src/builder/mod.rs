@@ -1,7 +1,14 @@
 //! Builders for large structs.
 
+pub mod activity;
+pub mod attachment;
+#[cfg(feature = "auto-moderation")]
+pub mod auto_moderation;
 pub mod command;
 pub mod embed;
+pub mod forum_thread;
+pub mod scheduled_event;
+pub mod select_menu;
 
 mod interaction_response_data;
 
@@ -0,0 +1,317 @@
+//! Create the fields of a guild scheduled event with a builder.
+
+use crate::image_data;
+use twilight_model::{
+    guild::scheduled_event::{EntityType, PrivacyLevel},
+    id::{marker::ChannelMarker, Id},
+    util::Timestamp,
+};
+use twilight_validate::request::{
+    scheduled_event_description as validate_scheduled_event_description,
+    scheduled_event_end_time as validate_scheduled_event_end_time,
+    scheduled_event_entity_fields as validate_scheduled_event_entity_fields,
+    scheduled_event_location as validate_scheduled_event_location,
+    scheduled_event_name as validate_scheduled_event_name, ValidationError,
+};
+
+/// Fields of a guild scheduled event, built by a [`ScheduledEventBuilder`].
+///
+/// The fields can be passed to the requests creating or updating a scheduled
+/// event, whose methods have the same names as the fields.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ScheduledEventFields {
+    /// Channel the event takes place in, if it takes place in a stage
+    /// instance or voice channel.
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// Description of the event.
+    pub description: Option<String>,
+    /// Type of the event.
+    pub entity_type: EntityType,
+    /// Cover image of the event, as a Data URI.
+    pub image: Option<String>,
+    /// Location of the event, if it takes place outside of Discord.
+    pub location: Option<String>,
+    /// Name of the event.
+    pub name: String,
+    /// Privacy level of the event.
+    pub privacy_level: PrivacyLevel,
+    /// When the event is scheduled to end.
+    ///
+    /// This is always present for [`External`] events.
+    ///
+    /// [`External`]: EntityType::External
+    pub scheduled_end_time: Option<Timestamp>,
+    /// When the event is scheduled to start.
+    pub scheduled_start_time: Timestamp,
+}
+
+/// Create the fields of a guild scheduled event with a builder.
+///
+/// The builder is created for a type of event, requiring the fields that the
+/// type depends on: stage instance and voice events take place in a channel,
+/// whereas external events take place at a location and must have an end
+/// time.
+///
+/// # Examples
+///
+/// Build an external event with a cover image:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::util::Timestamp;
+/// use twilight_util::builder::scheduled_event::ScheduledEventBuilder;
+///
+/// let start = Timestamp::from_secs(1_700_000_000)?;
+/// let end = Timestamp::from_secs(1_700_003_600)?;
+/// let cover = [0x89, b'P', b'N', b'G'];
+///
+/// let event = ScheduledEventBuilder::external("Meetup", "Central Park", start, end)
+///     .description("Meet other members in person")
+///     .image("image/png", &cover)
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into scheduled event fields"]
+pub struct ScheduledEventBuilder(ScheduledEventFields);
+
+impl ScheduledEventBuilder {
+    /// Create a new builder of an event taking place outside of Discord.
+    pub fn external(
+        name: impl Into<String>,
+        location: impl Into<String>,
+        scheduled_start_time: Timestamp,
+        scheduled_end_time: Timestamp,
+    ) -> Self {
+        Self(ScheduledEventFields {
+            channel_id: None,
+            description: None,
+            entity_type: EntityType::External,
+            image: None,
+            location: Some(location.into()),
+            name: name.into(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            scheduled_end_time: Some(scheduled_end_time),
+            scheduled_start_time,
+        })
+    }
+
+    /// Create a new builder of an event taking place in a stage instance.
+    pub fn stage_instance(
+        name: impl Into<String>,
+        channel_id: Id<ChannelMarker>,
+        scheduled_start_time: Timestamp,
+    ) -> Self {
+        Self::channel(
+            EntityType::StageInstance,
+            name,
+            channel_id,
+            scheduled_start_time,
+        )
+    }
+
+    /// Create a new builder of an event taking place in a voice channel.
+    pub fn voice(
+        name: impl Into<String>,
+        channel_id: Id<ChannelMarker>,
+        scheduled_start_time: Timestamp,
+    ) -> Self {
+        Self::channel(EntityType::Voice, name, channel_id, scheduled_start_time)
+    }
+
+    /// Build into the fields of a scheduled event.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used when creating or updating a scheduled event"]
+    pub fn build(self) -> ScheduledEventFields {
+        self.0
+    }
+
+    /// Ensure the scheduled event is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ScheduledEventName`] if the name is invalid.
+    ///
+    /// Returns an error of type [`ScheduledEventDescription`] if the
+    /// description is invalid.
+    ///
+    /// Returns an error of type [`ScheduledEventLocation`] if the location is
+    /// invalid.
+    ///
+    /// Returns an error of type [`ScheduledEventEndTime`] if the event doesn't
+    /// end after it starts.
+    ///
+    /// Returns an error of type [`ScheduledEventEntityFields`] if a field
+    /// required by the type of the event is missing.
+    ///
+    /// [`ScheduledEventDescription`]: twilight_validate::request::ValidationErrorType::ScheduledEventDescription
+    /// [`ScheduledEventEndTime`]: twilight_validate::request::ValidationErrorType::ScheduledEventEndTime
+    /// [`ScheduledEventEntityFields`]: twilight_validate::request::ValidationErrorType::ScheduledEventEntityFields
+    /// [`ScheduledEventLocation`]: twilight_validate::request::ValidationErrorType::ScheduledEventLocation
+    /// [`ScheduledEventName`]: twilight_validate::request::ValidationErrorType::ScheduledEventName
+    pub fn validate(self) -> Result<Self, ValidationError> {
+        validate_scheduled_event_name(&self.0.name)?;
+
+        if let Some(description) = &self.0.description {
+            validate_scheduled_event_description(description)?;
+        }
+
+        if let Some(location) = &self.0.location {
+            validate_scheduled_event_location(location)?;
+        }
+
+        if let Some(scheduled_end_time) = self.0.scheduled_end_time {
+            validate_scheduled_event_end_time(self.0.scheduled_start_time, scheduled_end_time)?;
+        }
+
+        validate_scheduled_event_entity_fields(
+            self.0.entity_type,
+            self.0.channel_id.is_some(),
+            self.0.location.is_some(),
+            self.0.scheduled_end_time.is_some(),
+        )?;
+
+        Ok(self)
+    }
+
+    /// Set the description.
+    ///
+    /// Refer to [`SCHEDULED_EVENT_DESCRIPTION_MAX`] for the maximum number of
+    /// characters that can be in a description.
+    ///
+    /// [`SCHEDULED_EVENT_DESCRIPTION_MAX`]: twilight_validate::request::SCHEDULED_EVENT_DESCRIPTION_MAX
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+
+        self
+    }
+
+    /// Set the cover image, encoding it as a Data URI.
+    ///
+    /// The MIME type of the image, such as `image/png`, must be provided. To
+    /// detect the type and validate the size of an image instead, encode it
+    /// with [`image_data::encode`] and set it as the image of the built fields.
+    pub fn image(mut self, mime_type: &str, data: &[u8]) -> Self {
+        self.0.image = Some(image_data::encode_as(mime_type, data));
+
+        self
+    }
+
+    /// Set when the event is scheduled to end.
+    ///
+    /// External events are created with an end time, which this replaces.
+    pub const fn scheduled_end_time(mut self, scheduled_end_time: Timestamp) -> Self {
+        self.0.scheduled_end_time = Some(scheduled_end_time);
+
+        self
+    }
+
+    /// Create a new builder of an event taking place in a channel.
+    fn channel(
+        entity_type: EntityType,
+        name: impl Into<String>,
+        channel_id: Id<ChannelMarker>,
+        scheduled_start_time: Timestamp,
+    ) -> Self {
+        Self(ScheduledEventFields {
+            channel_id: Some(channel_id),
+            description: None,
+            entity_type,
+            image: None,
+            location: None,
+            name: name.into(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            scheduled_end_time: None,
+            scheduled_start_time,
+        })
+    }
+}
+
+impl TryFrom<ScheduledEventBuilder> for ScheduledEventFields {
+    type Error = ValidationError;
+
+    /// Convert a scheduled event builder into the fields of a scheduled event,
+    /// validating its contents.
+    ///
+    /// This is equivalent to calling [`ScheduledEventBuilder::validate`],
+    /// then [`ScheduledEventBuilder::build`].
+    fn try_from(builder: ScheduledEventBuilder) -> Result<Self, Self::Error> {
+        Ok(builder.validate()?.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_validate::request::ValidationErrorType;
+
+    assert_impl_all!(ScheduledEventBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ScheduledEventFields: TryFrom<ScheduledEventBuilder>);
+
+    #[test]
+    fn external() {
+        let start = Timestamp::from_secs(1_700_000_000).unwrap();
+        let end = Timestamp::from_secs(1_700_003_600).unwrap();
+        let fields = ScheduledEventBuilder::external("name", "location", start, end)
+            .image("image/png", b"foo")
+            .build();
+
+        assert_eq!(EntityType::External, fields.entity_type);
+        assert!(fields.channel_id.is_none());
+        assert_eq!(Some("location"), fields.location.as_deref());
+        assert_eq!(Some(end), fields.scheduled_end_time);
+        assert_eq!(Some("data:image/png;base64,Zm9v"), fields.image.as_deref());
+    }
+
+    #[test]
+    fn voice() {
+        let start = Timestamp::from_secs(1_700_000_000).unwrap();
+        let fields = ScheduledEventBuilder::voice("name", Id::new(1), start)
+            .description("description")
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(EntityType::Voice, fields.entity_type);
+        assert_eq!(Some(Id::new(1)), fields.channel_id);
+        assert!(fields.location.is_none());
+        assert!(fields.scheduled_end_time.is_none());
+    }
+
+    #[test]
+    fn validate() {
+        let start = Timestamp::from_secs(1_700_000_000).unwrap();
+        let error = ScheduledEventBuilder::stage_instance("", Id::new(1), start)
+            .validate()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ValidationErrorType::ScheduledEventName { len: 0 }
+        ));
+
+        let error = ScheduledEventBuilder::voice("name", Id::new(1), start)
+            .scheduled_end_time(start)
+            .validate()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ValidationErrorType::ScheduledEventEndTime
+        ));
+
+        let end = Timestamp::from_secs(1_700_003_600).unwrap();
+        let error = ScheduledEventBuilder::external("name", "", start, end)
+            .validate()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ValidationErrorType::ScheduledEventLocation { len: 0 }
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+//! Create a [`SelectMenu`] with a builder.
+
+use twilight_model::channel::{
+    message::{
+        component::{SelectDefaultValue, SelectMenu, SelectMenuOption, SelectMenuType},
+        Component, EmojiReactionType,
+    },
+    ChannelType,
+};
+use twilight_validate::component::{select_menu as validate_select_menu, ComponentValidationError};
+
+/// Create a [`SelectMenu`] with a builder.
+///
+/// Select menus of every [`SelectMenuType`] can be built: [`text`] select
+/// menus take a list of [options], whereas [`user`], [`role`],
+/// [`mentionable`], and [`channel`] select menus are auto-populated and may
+/// instead take [default values].
+///
+/// # Examples
+///
+/// Build a text select menu:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_util::builder::select_menu::{SelectMenuBuilder, SelectMenuOptionBuilder};
+///
+/// let select_menu = SelectMenuBuilder::text("pony")
+///     .option(SelectMenuOptionBuilder::new("Twilight Sparkle", "twilight").default())
+///     .option(SelectMenuOptionBuilder::new("Rarity", "rarity"))
+///     .placeholder("Choose the best pony")
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+///
+/// Build a channel select menu for up to 3 text channels:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::{
+///     channel::{message::component::SelectDefaultValue, ChannelType},
+///     id::Id,
+/// };
+/// use twilight_util::builder::select_menu::SelectMenuBuilder;
+///
+/// let select_menu = SelectMenuBuilder::channel("channels")
+///     .channel_types(vec![ChannelType::GuildText])
+///     .default_value(SelectDefaultValue::Channel(Id::new(1)))
+///     .max_values(3)
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+///
+/// [`channel`]: Self::channel
+/// [`mentionable`]: Self::mentionable
+/// [`role`]: Self::role
+/// [`text`]: Self::text
+/// [`user`]: Self::user
+/// [default values]: Self::default_value
+/// [options]: Self::option
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu"]
+pub struct SelectMenuBuilder(SelectMenu);
+
+impl SelectMenuBuilder {
+    /// Create a new select menu builder of a type.
+    ///
+    /// Refer to [`COMPONENT_CUSTOM_ID_LENGTH`] for the maximum number of
+    /// characters that can be in a custom ID.
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    pub fn new(custom_id: impl Into<String>, kind: SelectMenuType) -> Self {
+        Self(SelectMenu {
+            channel_types: None,
+            custom_id: custom_id.into(),
+            default_values: None,
+            disabled: false,
+            kind,
+            max_values: None,
+            min_values: None,
+            options: None,
+            placeholder: None,
+        })
+    }
+
+    /// Create a new builder of a [text select menu](SelectMenuType::Text).
+    ///
+    /// Text select menus require at least one [option].
+    ///
+    /// [option]: Self::option
+    pub fn text(custom_id: impl Into<String>) -> Self {
+        Self::new(custom_id, SelectMenuType::Text)
+    }
+
+    /// Create a new builder of a [user select menu](SelectMenuType::User).
+    pub fn user(custom_id: impl Into<String>) -> Self {
+        Self::new(custom_id, SelectMenuType::User)
+    }
+
+    /// Create a new builder of a [role select menu](SelectMenuType::Role).
+    pub fn role(custom_id: impl Into<String>) -> Self {
+        Self::new(custom_id, SelectMenuType::Role)
+    }
+
+    /// Create a new builder of a
+    /// [mentionable select menu](SelectMenuType::Mentionable).
+    pub fn mentionable(custom_id: impl Into<String>) -> Self {
+        Self::new(custom_id, SelectMenuType::Mentionable)
+    }
+
+    /// Create a new builder of a
+    /// [channel select menu](SelectMenuType::Channel).
+    pub fn channel(custom_id: impl Into<String>) -> Self {
+        Self::new(custom_id, SelectMenuType::Channel)
+    }
+
+    /// Build into a select menu.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> SelectMenu {
+        self.0
+    }
+
+    /// Ensure the select menu is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the documentation of
+    /// [`twilight_validate::component::select_menu`] for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        validate_select_menu(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Set the channel types that can be selected.
+    ///
+    /// This is only applicable to
+    /// [channel select menus](SelectMenuType::Channel).
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.0.channel_types = Some(channel_types);
+
+        self
+    }
+
+    /// Add a default value.
+    ///
+    /// Default values are only supported by auto-populated select menus. The
+    /// number of default values must be within the [minimum] and [maximum]
+    /// number of values.
+    ///
+    /// [maximum]: Self::max_values
+    /// [minimum]: Self::min_values
+    pub fn default_value(mut self, default_value: SelectDefaultValue) -> Self {
+        self.0
+            .default_values
+            .get_or_insert_with(Vec::new)
+            .push(default_value);
+
+        self
+    }
+
+    /// Disable the select menu.
+    ///
+    /// Select menus are enabled by default.
+    pub const fn disabled(mut self) -> Self {
+        self.0.disabled = true;
+
+        self
+    }
+
+    /// Set the maximum number of values that may be chosen.
+    ///
+    /// Refer to [`SELECT_MAXIMUM_VALUES_LIMIT`] for the largest maximum and
+    /// [`SELECT_MAXIMUM_VALUES_REQUIREMENT`] for the smallest maximum.
+    ///
+    /// [`SELECT_MAXIMUM_VALUES_LIMIT`]: twilight_validate::component::SELECT_MAXIMUM_VALUES_LIMIT
+    /// [`SELECT_MAXIMUM_VALUES_REQUIREMENT`]: twilight_validate::component::SELECT_MAXIMUM_VALUES_REQUIREMENT
+    pub const fn max_values(mut self, max_values: u8) -> Self {
+        self.0.max_values = Some(max_values);
+
+        self
+    }
+
+    /// Set the minimum number of values that must be chosen.
+    ///
+    /// Refer to [`SELECT_MINIMUM_VALUES_LIMIT`] for the largest minimum.
+    ///
+    /// [`SELECT_MINIMUM_VALUES_LIMIT`]: twilight_validate::component::SELECT_MINIMUM_VALUES_LIMIT
+    pub const fn min_values(mut self, min_values: u8) -> Self {
+        self.0.min_values = Some(min_values);
+
+        self
+    }
+
+    /// Add an option.
+    ///
+    /// This is only applicable to [text select menus](SelectMenuType::Text).
+    ///
+    /// Refer to [`SELECT_OPTION_COUNT`] for the maximum number of options.
+    ///
+    /// [`SELECT_OPTION_COUNT`]: twilight_validate::component::SELECT_OPTION_COUNT
+    pub fn option(mut self, option: impl Into<SelectMenuOption>) -> Self {
+        self.0
+            .options
+            .get_or_insert_with(Vec::new)
+            .push(option.into());
+
+        self
+    }
+
+    /// Set the placeholder shown if no value is selected.
+    ///
+    /// Refer to [`SELECT_PLACEHOLDER_LENGTH`] for the maximum number of
+    /// characters that can be in a placeholder.
+    ///
+    /// [`SELECT_PLACEHOLDER_LENGTH`]: twilight_validate::component::SELECT_PLACEHOLDER_LENGTH
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.0.placeholder = Some(placeholder.into());
+
+        self
+    }
+}
+
+impl From<SelectMenu> for SelectMenuBuilder {
+    fn from(value: SelectMenu) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<SelectMenuBuilder> for SelectMenu {
+    type Error = ComponentValidationError;
+
+    /// Convert a select menu builder into a select menu, validating its
+    /// contents.
+    ///
+    /// This is equivalent to calling [`SelectMenuBuilder::validate`], then
+    /// [`SelectMenuBuilder::build`].
+    fn try_from(builder: SelectMenuBuilder) -> Result<Self, Self::Error> {
+        Ok(builder.validate()?.build())
+    }
+}
+
+impl TryFrom<SelectMenuBuilder> for Component {
+    type Error = ComponentValidationError;
+
+    /// Convert a select menu builder into a component, validating its
+    /// contents.
+    fn try_from(builder: SelectMenuBuilder) -> Result<Self, Self::Error> {
+        Ok(Self::SelectMenu(builder.try_into()?))
+    }
+}
+
+/// Create a [`SelectMenuOption`] with a builder.
+///
+/// This can be passed into [`SelectMenuBuilder::option`].
+///
+/// Refer to [`SELECT_OPTION_LABEL_LENGTH`], [`SELECT_OPTION_VALUE_LENGTH`],
+/// and [`SELECT_OPTION_DESCRIPTION_LENGTH`] for the maximum number of
+/// characters that can be in a label, value, and description.
+///
+/// [`SELECT_OPTION_DESCRIPTION_LENGTH`]: twilight_validate::component::SELECT_OPTION_DESCRIPTION_LENGTH
+/// [`SELECT_OPTION_LABEL_LENGTH`]: twilight_validate::component::SELECT_OPTION_LABEL_LENGTH
+/// [`SELECT_OPTION_VALUE_LENGTH`]: twilight_validate::component::SELECT_OPTION_VALUE_LENGTH
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu option"]
+pub struct SelectMenuOptionBuilder(SelectMenuOption);
+
+impl SelectMenuOptionBuilder {
+    /// Create a new select menu option builder.
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self(SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: label.into(),
+            value: value.into(),
+        })
+    }
+
+    /// Build into a select menu option.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a select menu builder"]
+    pub fn build(self) -> SelectMenuOption {
+        self.0
+    }
+
+    /// Select the option by default.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn default(mut self) -> Self {
+        self.0.default = true;
+
+        self
+    }
+
+    /// Set the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+
+        self
+    }
+
+    /// Set the emoji shown left of the label and description.
+    pub fn emoji(mut self, emoji: EmojiReactionType) -> Self {
+        self.0.emoji = Some(emoji);
+
+        self
+    }
+}
+
+impl From<SelectMenuOptionBuilder> for SelectMenuOption {
+    /// Convert a select menu option builder into a select menu option.
+    ///
+    /// This is equivalent to calling [`SelectMenuOptionBuilder::build`].
+    fn from(builder: SelectMenuOptionBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::Id;
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(SelectMenuBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenuOptionBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenu: TryFrom<SelectMenuBuilder>);
+    assert_impl_all!(SelectMenuOption: From<SelectMenuOptionBuilder>);
+
+    #[test]
+    fn text() {
+        let select_menu = SelectMenuBuilder::text("custom")
+            .option(
+                SelectMenuOptionBuilder::new("label", "value")
+                    .default()
+                    .description("description"),
+            )
+            .min_values(1)
+            .placeholder("placeholder")
+            .validate()
+            .unwrap()
+            .build();
+
+        let expected = SelectMenu {
+            channel_types: None,
+            custom_id: "custom".to_owned(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::Text,
+            max_values: None,
+            min_values: Some(1),
+            options: Some(vec![SelectMenuOption {
+                default: true,
+                description: Some("description".to_owned()),
+                emoji: None,
+                label: "label".to_owned(),
+                value: "value".to_owned(),
+            }]),
+            placeholder: Some("placeholder".to_owned()),
+        };
+
+        assert_eq!(select_menu, expected);
+    }
+
+    #[test]
+    fn channel() {
+        let select_menu = SelectMenuBuilder::channel("custom")
+            .channel_types(vec![ChannelType::GuildText])
+            .default_value(SelectDefaultValue::Channel(Id::new(1)))
+            .default_value(SelectDefaultValue::Channel(Id::new(2)))
+            .disabled()
+            .max_values(2)
+            .build();
+
+        assert_eq!(
+            Some(vec![ChannelType::GuildText]),
+            select_menu.channel_types
+        );
+        assert_eq!(2, select_menu.default_values.unwrap().len());
+        assert!(select_menu.disabled);
+        assert_eq!(SelectMenuType::Channel, select_menu.kind);
+    }
+
+    #[test]
+    fn validate() {
+        assert!(matches!(
+            SelectMenuBuilder::text("custom")
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectOptionsMissing
+        ));
+        assert!(matches!(
+            SelectMenuBuilder::text("custom")
+                .option(SelectMenuOptionBuilder::new("label", "value"))
+                .default_value(SelectDefaultValue::User(Id::new(1)))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectUnsupportedDefaultValues { .. }
+        ));
+        assert!(matches!(
+            SelectMenuBuilder::role("custom")
+                .default_value(SelectDefaultValue::Role(Id::new(1)))
+                .default_value(SelectDefaultValue::Role(Id::new(2)))
+                .max_values(1)
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectTooManyDefaultValues { .. }
+        ));
+        assert!(Component::try_from(SelectMenuBuilder::user("custom").max_values(0)).is_err());
+    }
+}
@@ -0,0 +1,551 @@
+//! Builders for URLs of assets hosted on Discord's CDN.
+//!
+//! Each asset has a constructor function returning a [`CdnUrl`], which can be
+//! customized with an [image format] and an [image size] before being
+//! formatted into a URL via its [`Display`] implementation.
+//!
+//! Animated image hashes default to the [`ImageFormat::Gif`] format while all
+//! other assets default to [`ImageFormat::Png`].
+//!
+//! # Examples
+//!
+//! Create the URL of a user's avatar as a 256 pixel WebP image:
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_model::{id::Id, util::ImageHash};
+//! use twilight_util::cdn::{self, ImageFormat, ImageSize};
+//!
+//! let hash = ImageHash::parse(b"b0e09d6697b11e9c79a89e5e3756ddee")?;
+//! let url = cdn::user_avatar(Id::new(1), hash)
+//!     .format(ImageFormat::WebP)
+//!     .size(ImageSize::Px256);
+//!
+//! assert_eq!(
+//!     "https://cdn.discordapp.com/avatars/1/b0e09d6697b11e9c79a89e5e3756ddee.webp?size=256",
+//!     url.to_string(),
+//! );
+//! # Ok(()) }
+//! ```
+//!
+//! [image format]: CdnUrl::format
+//! [image size]: CdnUrl::size
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use twilight_model::{
+    channel::message::sticker::StickerFormatType,
+    id::{
+        marker::{
+            EmojiMarker, GuildMarker, RoleMarker, ScheduledEventMarker, StickerMarker, UserMarker,
+        },
+        Id,
+    },
+    util::ImageHash,
+};
+
+/// Base URL of Discord's CDN.
+pub const BASE: &str = "https://cdn.discordapp.com";
+
+/// Base URL of Discord's media proxy, used for GIF stickers.
+const MEDIA_BASE: &str = "https://media.discordapp.net";
+
+/// Format of an image asset.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// Animated GIF image.
+    ///
+    /// Only available for animated assets; other assets fall back to
+    /// [`ImageFormat::Png`].
+    Gif,
+    /// JPEG image.
+    Jpeg,
+    /// Lottie animation.
+    ///
+    /// Only available for Lottie stickers.
+    Lottie,
+    /// PNG image.
+    Png,
+    /// WebP image.
+    WebP,
+}
+
+impl ImageFormat {
+    /// Best format for an image hash: [`ImageFormat::Gif`] if the hash is
+    /// animated, otherwise the preferred static format.
+    ///
+    /// Preferring [`ImageFormat::Gif`] or [`ImageFormat::Lottie`] as the static
+    /// format results in [`ImageFormat::Png`], as neither is available for
+    /// static images. Whether animation is available is determined by
+    /// [`ImageHash::is_animated`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::util::ImageHash;
+    /// use twilight_util::cdn::ImageFormat;
+    ///
+    /// let animated = ImageHash::parse(b"a_b0e09d6697b11e9c79a89e5e3756ddee")?;
+    /// let still = ImageHash::parse(b"b0e09d6697b11e9c79a89e5e3756ddee")?;
+    ///
+    /// assert_eq!("gif", ImageFormat::for_hash(animated, ImageFormat::WebP).extension());
+    /// assert_eq!("webp", ImageFormat::for_hash(still, ImageFormat::WebP).extension());
+    /// # Ok(()) }
+    /// ```
+    pub const fn for_hash(hash: ImageHash, static_format: Self) -> Self {
+        if hash.is_animated() {
+            return Self::Gif;
+        }
+
+        match static_format {
+            Self::Gif | Self::Lottie => Self::Png,
+            format => format,
+        }
+    }
+
+    /// File extension of the format.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Jpeg => "jpg",
+            Self::Lottie => "json",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+}
+
+/// Size of an image asset in pixels.
+///
+/// Discord only accepts powers of two between 16 and 4096.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ImageSize {
+    /// 16 pixels.
+    Px16,
+    /// 32 pixels.
+    Px32,
+    /// 64 pixels.
+    Px64,
+    /// 128 pixels.
+    Px128,
+    /// 256 pixels.
+    Px256,
+    /// 512 pixels.
+    Px512,
+    /// 1024 pixels.
+    Px1024,
+    /// 2048 pixels.
+    Px2048,
+    /// 4096 pixels.
+    Px4096,
+}
+
+impl ImageSize {
+    /// Size in pixels.
+    pub const fn pixels(self) -> u16 {
+        match self {
+            Self::Px16 => 16,
+            Self::Px32 => 32,
+            Self::Px64 => 64,
+            Self::Px128 => 128,
+            Self::Px256 => 256,
+            Self::Px512 => 512,
+            Self::Px1024 => 1024,
+            Self::Px2048 => 2048,
+            Self::Px4096 => 4096,
+        }
+    }
+
+    /// Image size with the provided number of pixels, if it is valid.
+    pub const fn from_pixels(pixels: u16) -> Option<Self> {
+        Some(match pixels {
+            16 => Self::Px16,
+            32 => Self::Px32,
+            64 => Self::Px64,
+            128 => Self::Px128,
+            256 => Self::Px256,
+            512 => Self::Px512,
+            1024 => Self::Px1024,
+            2048 => Self::Px2048,
+            4096 => Self::Px4096,
+            _ => return None,
+        })
+    }
+}
+
+/// Path of a [`CdnUrl`] relative to the CDN's base URL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Asset {
+    /// Asset identified by an image hash.
+    Hash {
+        /// Path segments preceding the hash.
+        prefix: String,
+        /// Image hash of the asset.
+        hash: ImageHash,
+    },
+    /// Asset identified by its ID or index.
+    Id {
+        /// Path segments preceding the ID.
+        prefix: &'static str,
+        /// ID or index of the asset.
+        id: u64,
+    },
+}
+
+/// URL of an asset on Discord's CDN.
+///
+/// Created via the constructor functions of the [module].
+///
+/// [module]: self
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "has no effect if not formatted into a URL"]
+pub struct CdnUrl {
+    /// Whether the asset is animated.
+    animated: bool,
+    /// Path of the asset.
+    asset: Asset,
+    /// Selected format of the asset.
+    format: Option<ImageFormat>,
+    /// Whether the format may not be changed.
+    fixed_format: bool,
+    /// Whether the asset is served from the media proxy.
+    media: bool,
+    /// Selected size of the asset.
+    size: Option<ImageSize>,
+}
+
+impl CdnUrl {
+    /// Create a URL of an asset identified by an image hash.
+    const fn hash(prefix: String, hash: ImageHash) -> Self {
+        Self {
+            animated: hash.is_animated(),
+            asset: Asset::Hash { prefix, hash },
+            format: None,
+            fixed_format: false,
+            media: false,
+            size: None,
+        }
+    }
+
+    /// Create a URL of an asset identified by its ID or index.
+    const fn id(prefix: &'static str, id: u64, animated: bool) -> Self {
+        Self {
+            animated,
+            asset: Asset::Id { prefix, id },
+            format: None,
+            fixed_format: false,
+            media: false,
+            size: None,
+        }
+    }
+
+    /// Set the format of the image.
+    ///
+    /// Selecting [`ImageFormat::Gif`] for a static asset results in a
+    /// [`ImageFormat::Png`] URL. Default avatars, Lottie stickers, and GIF
+    /// stickers only have a single format and ignore this setting.
+    pub const fn format(mut self, format: ImageFormat) -> Self {
+        if !self.fixed_format {
+            self.format = Some(format);
+        }
+
+        self
+    }
+
+    /// Set the size of the image.
+    pub const fn size(mut self, size: ImageSize) -> Self {
+        self.size = Some(size);
+
+        self
+    }
+
+    /// Whether the asset is animated.
+    pub const fn is_animated(&self) -> bool {
+        self.animated
+    }
+
+    /// Format of the image the URL will point to.
+    pub const fn resolved_format(&self) -> ImageFormat {
+        match (self.format, self.animated) {
+            (Some(format), _) if self.fixed_format => format,
+            (Some(ImageFormat::Gif) | None, true) => ImageFormat::Gif,
+            (Some(ImageFormat::Gif | ImageFormat::Lottie) | None, _) => ImageFormat::Png,
+            (Some(format), _) => format,
+        }
+    }
+
+    /// Restrict the URL to a single format.
+    const fn fixed(mut self, format: ImageFormat) -> Self {
+        self.fixed_format = true;
+        self.format = Some(format);
+
+        self
+    }
+}
+
+impl Display for CdnUrl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(if self.media { MEDIA_BASE } else { BASE })?;
+        f.write_str("/")?;
+
+        match &self.asset {
+            Asset::Hash { prefix, hash } => {
+                f.write_str(prefix)?;
+                f.write_str("/")?;
+                Display::fmt(hash, f)?;
+            }
+            Asset::Id { prefix, id } => {
+                f.write_str(prefix)?;
+                f.write_str("/")?;
+                Display::fmt(id, f)?;
+            }
+        }
+
+        let format = self.resolved_format();
+        f.write_str(".")?;
+        f.write_str(format.extension())?;
+
+        if let Some(size) = self.size.filter(|_| format != ImageFormat::Lottie) {
+            f.write_str("?size=")?;
+            Display::fmt(&size.pixels(), f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// URL of an avatar decoration.
+pub fn avatar_decoration(asset: ImageHash) -> CdnUrl {
+    CdnUrl::hash("avatar-decoration-presets".to_owned(), asset).fixed(ImageFormat::Png)
+}
+
+/// URL of a user's default avatar.
+///
+/// Users that migrated to the new username system have a discriminator of
+/// `0`.
+pub const fn default_avatar(user_id: Id<UserMarker>, discriminator: u16) -> CdnUrl {
+    let index = if discriminator == 0 {
+        (user_id.get() >> 22) % 6
+    } else {
+        discriminator as u64 % 5
+    };
+
+    CdnUrl::id("embed/avatars", index, false).fixed(ImageFormat::Png)
+}
+
+/// URL of a custom emoji.
+pub const fn emoji(emoji_id: Id<EmojiMarker>, animated: bool) -> CdnUrl {
+    CdnUrl::id("emojis", emoji_id.get(), animated)
+}
+
+/// URL of a guild's banner.
+pub fn guild_banner(guild_id: Id<GuildMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("banners/{guild_id}"), hash)
+}
+
+/// URL of a guild's discovery splash.
+pub fn guild_discovery_splash(guild_id: Id<GuildMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("discovery-splashes/{guild_id}"), hash)
+}
+
+/// URL of a guild's icon.
+pub fn guild_icon(guild_id: Id<GuildMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("icons/{guild_id}"), hash)
+}
+
+/// URL of a guild's invite splash.
+pub fn guild_splash(guild_id: Id<GuildMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("splashes/{guild_id}"), hash)
+}
+
+/// URL of a member's guild specific avatar.
+pub fn member_avatar(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    hash: ImageHash,
+) -> CdnUrl {
+    CdnUrl::hash(format!("guilds/{guild_id}/users/{user_id}/avatars"), hash)
+}
+
+/// URL of a member's guild specific banner.
+pub fn member_banner(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    hash: ImageHash,
+) -> CdnUrl {
+    CdnUrl::hash(format!("guilds/{guild_id}/users/{user_id}/banners"), hash)
+}
+
+/// URL of a role's icon.
+pub fn role_icon(role_id: Id<RoleMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("role-icons/{role_id}"), hash)
+}
+
+/// URL of a guild scheduled event's cover image.
+pub fn scheduled_event_cover(event_id: Id<ScheduledEventMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("guild-events/{event_id}"), hash)
+}
+
+/// URL of a sticker.
+///
+/// Lottie stickers are only available as [`ImageFormat::Lottie`] and GIF
+/// stickers only as [`ImageFormat::Gif`] via the media proxy.
+pub const fn sticker(sticker_id: Id<StickerMarker>, format_type: StickerFormatType) -> CdnUrl {
+    let url = CdnUrl::id("stickers", sticker_id.get(), false);
+
+    match format_type {
+        StickerFormatType::Gif => {
+            let mut url = url.fixed(ImageFormat::Gif);
+            url.animated = true;
+            url.media = true;
+
+            url
+        }
+        StickerFormatType::Lottie => url.fixed(ImageFormat::Lottie),
+        _ => url.fixed(ImageFormat::Png),
+    }
+}
+
+/// URL of a user's avatar.
+pub fn user_avatar(user_id: Id<UserMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("avatars/{user_id}"), hash)
+}
+
+/// URL of a user's banner.
+pub fn user_banner(user_id: Id<UserMarker>, hash: ImageHash) -> CdnUrl {
+    CdnUrl::hash(format!("banners/{user_id}"), hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnUrl, ImageFormat, ImageSize};
+    use static_assertions::assert_impl_all;
+    use std::fmt::{Debug, Display};
+    use twilight_model::{channel::message::sticker::StickerFormatType, id::Id, util::ImageHash};
+
+    assert_impl_all!(CdnUrl: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ImageFormat: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ImageSize: Clone, Copy, Debug, Eq, Ord, PartialEq, Send, Sync);
+
+    const ANIMATED: &[u8] = b"a_b0e09d6697b11e9c79a89e5e3756ddee";
+    const STATIC: &[u8] = b"b0e09d6697b11e9c79a89e5e3756ddee";
+
+    #[test]
+    fn animated_hash() {
+        let hash = ImageHash::parse(ANIMATED).unwrap();
+
+        assert_eq!(
+            "https://cdn.discordapp.com/icons/1/a_b0e09d6697b11e9c79a89e5e3756ddee.gif",
+            super::guild_icon(Id::new(1), hash).to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/icons/1/a_b0e09d6697b11e9c79a89e5e3756ddee.webp?size=64",
+            super::guild_icon(Id::new(1), hash)
+                .format(ImageFormat::WebP)
+                .size(ImageSize::Px64)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn format_for_hash() {
+        let animated = ImageHash::parse(ANIMATED).unwrap();
+        let still = ImageHash::parse(STATIC).unwrap();
+
+        assert_eq!(
+            ImageFormat::Gif,
+            ImageFormat::for_hash(animated, ImageFormat::Png)
+        );
+        assert_eq!(
+            ImageFormat::WebP,
+            ImageFormat::for_hash(still, ImageFormat::WebP)
+        );
+        assert_eq!(
+            ImageFormat::Png,
+            ImageFormat::for_hash(still, ImageFormat::Gif)
+        );
+        assert_eq!(
+            ImageFormat::Png,
+            ImageFormat::for_hash(still, ImageFormat::Lottie)
+        );
+    }
+
+    #[test]
+    fn static_hash() {
+        let hash = ImageHash::parse(STATIC).unwrap();
+
+        assert_eq!(
+            "https://cdn.discordapp.com/guilds/1/users/2/avatars/b0e09d6697b11e9c79a89e5e3756ddee.png",
+            super::member_avatar(Id::new(1), Id::new(2), hash).to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/role-icons/3/b0e09d6697b11e9c79a89e5e3756ddee.png",
+            super::role_icon(Id::new(3), hash)
+                .format(ImageFormat::Gif)
+                .to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/avatar-decoration-presets/b0e09d6697b11e9c79a89e5e3756ddee.png",
+            super::avatar_decoration(hash)
+                .format(ImageFormat::Jpeg)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn default_avatar() {
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/1.png",
+            super::default_avatar(Id::new(1), 1).to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/2.png",
+            super::default_avatar(Id::new(2 << 22), 0).to_string()
+        );
+    }
+
+    #[test]
+    fn emoji() {
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/4.gif?size=32",
+            super::emoji(Id::new(4), true)
+                .size(ImageSize::Px32)
+                .to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/4.jpg",
+            super::emoji(Id::new(4), false)
+                .format(ImageFormat::Jpeg)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn sticker() {
+        assert_eq!(
+            "https://cdn.discordapp.com/stickers/5.json",
+            super::sticker(Id::new(5), StickerFormatType::Lottie)
+                .size(ImageSize::Px512)
+                .to_string()
+        );
+        assert_eq!(
+            "https://media.discordapp.net/stickers/5.gif",
+            super::sticker(Id::new(5), StickerFormatType::Gif)
+                .format(ImageFormat::Png)
+                .to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/stickers/5.png?size=128",
+            super::sticker(Id::new(5), StickerFormatType::Apng)
+                .size(ImageSize::Px128)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn image_size() {
+        assert_eq!(Some(ImageSize::Px1024), ImageSize::from_pixels(1024));
+        assert!(ImageSize::from_pixels(100).is_none());
+    }
+}
@@ -0,0 +1,228 @@
+//! Encoding of images into the Data URIs accepted by Discord when uploading
+//! avatars, icons, banners, and other images.
+//!
+//! The type of an image is detected from its contents, and its size is
+//! validated before it is encoded.
+//!
+//! # Examples
+//!
+//! Encode a PNG image, such as one read from a file:
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_util::image_data::{self, EMOJI_SIZE_MAX};
+//!
+//! let image = b"\x89PNG\r\n\x1a\n";
+//! let data = image_data::encode(image, EMOJI_SIZE_MAX)?;
+//!
+//! assert_eq!("data:image/png;base64,iVBORw0KGgo=", data);
+//! # Ok(()) }
+//! ```
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Maximum size of an emoji image in bytes.
+pub const EMOJI_SIZE_MAX: usize = 256 * 1024;
+
+/// Encoding an image into a Data URI failed.
+#[derive(Debug)]
+pub struct ImageDataError {
+    kind: ImageDataErrorType,
+}
+
+impl ImageDataError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ImageDataErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ImageDataErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ImageDataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ImageDataErrorType::SizeInvalid { len, max } => {
+                f.write_str("image is ")?;
+                Display::fmt(&len, f)?;
+                f.write_str(" bytes, but must be at most ")?;
+                Display::fmt(&max, f)?;
+
+                f.write_str(" bytes")
+            }
+            ImageDataErrorType::TypeUnknown => {
+                f.write_str("image isn't a gif, jpeg, png, or webp image")
+            }
+        }
+    }
+}
+
+impl Error for ImageDataError {}
+
+/// Type of [`ImageDataError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageDataErrorType {
+    /// Image is larger than the maximum size.
+    SizeInvalid {
+        /// Size of the image in bytes.
+        len: usize,
+        /// Maximum size of the image in bytes.
+        max: usize,
+    },
+    /// Type of the image couldn't be detected or isn't supported by Discord.
+    TypeUnknown,
+}
+
+/// Type of an image supported by Discord.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageType {
+    /// GIF image.
+    Gif,
+    /// JPEG image.
+    Jpeg,
+    /// PNG image.
+    Png,
+    /// WebP image.
+    WebP,
+}
+
+impl ImageType {
+    /// Detect the type of an image from the signature at the start of its
+    /// contents.
+    ///
+    /// Returns [`None`] if the type isn't recognized.
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+            Some(Self::WebP)
+        } else {
+            None
+        }
+    }
+
+    /// MIME type of the image type.
+    pub const fn mime_type(self) -> &'static str {
+        match self {
+            Self::Gif => "image/gif",
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::WebP => "image/webp",
+        }
+    }
+}
+
+/// Encode an image into a Data URI, detecting its type.
+///
+/// The maximum size depends on the type of upload, such as
+/// [`EMOJI_SIZE_MAX`] for emojis.
+///
+/// # Errors
+///
+/// Returns an error of type [`SizeInvalid`] if the image is larger than the
+/// maximum size.
+///
+/// Returns an error of type [`TypeUnknown`] if the type of the image couldn't
+/// be detected.
+///
+/// [`SizeInvalid`]: ImageDataErrorType::SizeInvalid
+/// [`TypeUnknown`]: ImageDataErrorType::TypeUnknown
+pub fn encode(data: &[u8], size_max: usize) -> Result<String, ImageDataError> {
+    if data.len() > size_max {
+        return Err(ImageDataError {
+            kind: ImageDataErrorType::SizeInvalid {
+                len: data.len(),
+                max: size_max,
+            },
+        });
+    }
+
+    let kind = ImageType::detect(data).ok_or(ImageDataError {
+        kind: ImageDataErrorType::TypeUnknown,
+    })?;
+
+    Ok(encode_as(kind.mime_type(), data))
+}
+
+/// Encode data of a MIME type into a Data URI without any validation.
+pub(crate) fn encode_as(mime_type: &str, data: &[u8]) -> String {
+    format!("data:{mime_type};base64,{}", base64(data))
+}
+
+/// Encode bytes as padded base64.
+pub(crate) fn base64(data: &[u8]) -> String {
+    STANDARD.encode(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ImageDataError, ImageDataErrorType, ImageType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(ImageDataErrorType: Debug, Send, Sync);
+    assert_impl_all!(ImageDataError: Debug, Error, Send, Sync);
+    assert_impl_all!(ImageType: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!("", super::base64(b""));
+        assert_eq!("Zg==", super::base64(b"f"));
+        assert_eq!("Zm8=", super::base64(b"fo"));
+        assert_eq!("Zm9v", super::base64(b"foo"));
+        assert_eq!("Zm9vYmE=", super::base64(b"fooba"));
+    }
+
+    #[test]
+    fn detect() {
+        assert_eq!(Some(ImageType::Gif), ImageType::detect(b"GIF89a..."));
+        assert_eq!(
+            Some(ImageType::Jpeg),
+            ImageType::detect(&[0xFF, 0xD8, 0xFF, 0xE0])
+        );
+        assert_eq!(
+            Some(ImageType::WebP),
+            ImageType::detect(b"RIFF\0\0\0\0WEBPVP8 ")
+        );
+        assert_eq!(None, ImageType::detect(b"RIFF\0\0\0\0WAVE"));
+        assert_eq!(None, ImageType::detect(b""));
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(
+            "data:image/gif;base64,R0lGODlh",
+            super::encode(b"GIF89a", 6).unwrap()
+        );
+        assert!(matches!(
+            super::encode(b"GIF89a", 5).unwrap_err().kind(),
+            ImageDataErrorType::SizeInvalid { len: 6, max: 5 }
+        ));
+        assert!(matches!(
+            super::encode(b"text", 100).unwrap_err().kind(),
+            ImageDataErrorType::TypeUnknown
+        ));
+    }
+}
@@ -0,0 +1,516 @@
+//! Routing of interactions to handlers by command and custom ID.
+//!
+//! An [`InteractionRouter`] maps the paths of commands to handlers, such as
+//! `ping` or `config set`, as well as prefixes of the custom IDs of components
+//! and modals. Handlers of commands receive the options of the invoked
+//! subcommand as [`CommandOptions`], which extracts them by name and type.
+//!
+//! The router only routes interactions; responding to them is left to the
+//! handlers.
+//!
+//! # Examples
+//!
+//! Route a command with a subcommand and the buttons of a message:
+//!
+//! ```
+//! use twilight_model::{
+//!     application::interaction::Interaction,
+//!     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+//! };
+//! use twilight_util::interaction_router::{CommandOptions, InteractionRouter};
+//!
+//! fn message(content: String) -> InteractionResponse {
+//!     InteractionResponse {
+//!         kind: InteractionResponseType::ChannelMessageWithSource,
+//!         data: Some(InteractionResponseData {
+//!             content: Some(content),
+//!             ..Default::default()
+//!         }),
+//!     }
+//! }
+//!
+//! async fn set_volume(_: Interaction, options: CommandOptions) -> InteractionResponse {
+//!     let volume = options.integer("volume").unwrap_or(100);
+//!
+//!     message(format!("Volume set to {volume}%"))
+//! }
+//!
+//! async fn vote(_: Interaction) -> InteractionResponse {
+//!     message("Thanks for voting!".to_owned())
+//! }
+//!
+//! async fn handle(router: &InteractionRouter<InteractionResponse>, interaction: Interaction) {
+//!     match router.route(interaction) {
+//!         Ok(future) => {
+//!             let response = future.await;
+//!             // Respond to the interaction.
+//!         }
+//!         Err(interaction) => {
+//!             // The interaction didn't match any route.
+//!         }
+//!     }
+//! }
+//!
+//! let router = InteractionRouter::new()
+//!     .command("config volume", set_volume)
+//!     .component("vote:", vote);
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+};
+use twilight_model::{
+    application::{
+        command::CommandOptionType,
+        interaction::{
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+            Interaction, InteractionData, InteractionDataResolved, InteractionType,
+        },
+    },
+    id::{
+        marker::{AttachmentMarker, ChannelMarker, GenericMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Future returned by a routed handler.
+pub type RouteFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Handler of commands, receiving the options of the invoked subcommand.
+type CommandHandler<T> = Box<dyn Fn(Interaction, CommandOptions) -> RouteFuture<T> + Send + Sync>;
+
+/// Handler of components and modals.
+type CustomIdHandler<T> = Box<dyn Fn(Interaction) -> RouteFuture<T> + Send + Sync>;
+
+/// Options of an invoked command or subcommand.
+///
+/// Options of subcommands and subcommand groups are flattened, so that the
+/// options are those of the invoked subcommand. Options are looked up via the
+/// getters of [`CommandData`], such as [`CommandData::get_integer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandOptions {
+    /// Data of the invoked command.
+    data: CommandData,
+}
+
+impl CommandOptions {
+    /// Extract the options of the invoked subcommand from command data.
+    pub fn new(data: &CommandData) -> Self {
+        Self { data: data.clone() }
+    }
+
+    /// Value of an attachment option.
+    pub fn attachment(&self, name: &str) -> Option<Id<AttachmentMarker>> {
+        self.data.get_attachment(name)
+    }
+
+    /// Value of a boolean option.
+    pub fn boolean(&self, name: &str) -> Option<bool> {
+        self.data.get_boolean(name)
+    }
+
+    /// Value of a channel option.
+    pub fn channel(&self, name: &str) -> Option<Id<ChannelMarker>> {
+        self.data.get_channel(name)
+    }
+
+    /// Name, partial value, and type of the option focused during
+    /// autocomplete.
+    pub fn focused(&self) -> Option<(&str, &str, CommandOptionType)> {
+        self.options()
+            .iter()
+            .find_map(|option| match &option.value {
+                CommandOptionValue::Focused(value, kind) => {
+                    Some((option.name.as_str(), value.as_str(), *kind))
+                }
+                _ => None,
+            })
+    }
+
+    /// Value of an option of any type.
+    pub fn get(&self, name: &str) -> Option<&CommandOptionValue> {
+        self.data.get(name)
+    }
+
+    /// Value of an integer option.
+    pub fn integer(&self, name: &str) -> Option<i64> {
+        self.data.get_integer(name)
+    }
+
+    /// Value of a mentionable option.
+    pub fn mentionable(&self, name: &str) -> Option<Id<GenericMarker>> {
+        self.data.get_mentionable(name)
+    }
+
+    /// Value of a number option.
+    pub fn number(&self, name: &str) -> Option<f64> {
+        self.data.get_number(name)
+    }
+
+    /// Options of the invoked subcommand.
+    pub fn options(&self) -> &[CommandDataOption] {
+        self.data.leaf_options()
+    }
+
+    /// Entities resolved from the options, such as the users of user
+    /// options.
+    pub const fn resolved(&self) -> Option<&InteractionDataResolved> {
+        self.data.resolved.as_ref()
+    }
+
+    /// Value of a role option.
+    pub fn role(&self, name: &str) -> Option<Id<RoleMarker>> {
+        self.data.get_role(name)
+    }
+
+    /// Value of a string option.
+    pub fn string(&self, name: &str) -> Option<&str> {
+        self.data.get_string(name)
+    }
+
+    /// Names of the invoked subcommand group and subcommand, in order.
+    pub fn subcommand_path(&self) -> Vec<&str> {
+        self.data.subcommand_path()
+    }
+
+    /// Value of a user option.
+    pub fn user(&self, name: &str) -> Option<Id<UserMarker>> {
+        self.data.get_user(name)
+    }
+}
+
+/// Router of interactions to handlers.
+///
+/// Commands are matched by their path, which is their name followed by the
+/// names of the invoked subcommand group and subcommand separated by spaces.
+/// Components and modals are matched by the longest registered prefix of
+/// their custom ID.
+///
+/// Handlers producing a `T` are registered with the router's methods, which
+/// replace a handler previously registered for the same path or prefix.
+pub struct InteractionRouter<T> {
+    /// Handlers of autocomplete interactions by command path.
+    autocomplete: HashMap<String, CommandHandler<T>>,
+    /// Handlers of commands by path.
+    commands: HashMap<String, CommandHandler<T>>,
+    /// Handlers of components by custom ID prefix.
+    components: HashMap<String, CustomIdHandler<T>>,
+    /// Handlers of modals by custom ID prefix.
+    modals: HashMap<String, CustomIdHandler<T>>,
+}
+
+impl<T: 'static> InteractionRouter<T> {
+    /// Create a router without any routes.
+    pub fn new() -> Self {
+        Self {
+            autocomplete: HashMap::new(),
+            commands: HashMap::new(),
+            components: HashMap::new(),
+            modals: HashMap::new(),
+        }
+    }
+
+    /// Route autocomplete interactions of a command path to a handler.
+    #[must_use = "has no effect if not used"]
+    pub fn autocomplete<H, F>(mut self, path: impl Into<String>, handler: H) -> Self
+    where
+        H: Fn(Interaction, CommandOptions) -> F + Send + Sync + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        self.autocomplete.insert(
+            path.into(),
+            Box::new(move |interaction, options| Box::pin(handler(interaction, options))),
+        );
+
+        self
+    }
+
+    /// Route commands with a path to a handler.
+    #[must_use = "has no effect if not used"]
+    pub fn command<H, F>(mut self, path: impl Into<String>, handler: H) -> Self
+    where
+        H: Fn(Interaction, CommandOptions) -> F + Send + Sync + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        self.commands.insert(
+            path.into(),
+            Box::new(move |interaction, options| Box::pin(handler(interaction, options))),
+        );
+
+        self
+    }
+
+    /// Route components whose custom ID starts with a prefix to a handler.
+    #[must_use = "has no effect if not used"]
+    pub fn component<H, F>(mut self, prefix: impl Into<String>, handler: H) -> Self
+    where
+        H: Fn(Interaction) -> F + Send + Sync + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        self.components.insert(
+            prefix.into(),
+            Box::new(move |interaction| Box::pin(handler(interaction))),
+        );
+
+        self
+    }
+
+    /// Route modals whose custom ID starts with a prefix to a handler.
+    #[must_use = "has no effect if not used"]
+    pub fn modal<H, F>(mut self, prefix: impl Into<String>, handler: H) -> Self
+    where
+        H: Fn(Interaction) -> F + Send + Sync + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        self.modals.insert(
+            prefix.into(),
+            Box::new(move |interaction| Box::pin(handler(interaction))),
+        );
+
+        self
+    }
+
+    /// Route an interaction to its handler.
+    ///
+    /// # Errors
+    ///
+    /// Returns the interaction if no route matches it.
+    #[allow(clippy::result_large_err)]
+    pub fn route(&self, interaction: Interaction) -> Result<RouteFuture<T>, Interaction> {
+        let handler = match (interaction.kind, &interaction.data) {
+            (
+                kind @ (InteractionType::ApplicationCommand
+                | InteractionType::ApplicationCommandAutocomplete),
+                Some(InteractionData::ApplicationCommand(data)),
+            ) => {
+                let routes = if kind == InteractionType::ApplicationCommand {
+                    &self.commands
+                } else {
+                    &self.autocomplete
+                };
+
+                let Some(handler) = routes.get(&command_path(data)) else {
+                    return Err(interaction);
+                };
+                let options = CommandOptions::new(data);
+
+                return Ok(handler(interaction, options));
+            }
+            (InteractionType::MessageComponent, Some(InteractionData::MessageComponent(data))) => {
+                longest_prefix(&self.components, &data.custom_id)
+            }
+            (InteractionType::ModalSubmit, Some(InteractionData::ModalSubmit(data))) => {
+                longest_prefix(&self.modals, &data.custom_id)
+            }
+            _ => None,
+        };
+
+        match handler {
+            Some(handler) => Ok(handler(interaction)),
+            None => Err(interaction),
+        }
+    }
+}
+
+impl<T: 'static> Default for InteractionRouter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for InteractionRouter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractionRouter")
+            .field("autocomplete", &self.autocomplete.keys())
+            .field("commands", &self.commands.keys())
+            .field("components", &self.components.keys())
+            .field("modals", &self.modals.keys())
+            .finish()
+    }
+}
+
+/// Path of an invoked command, such as `config set`.
+fn command_path(data: &CommandData) -> String {
+    let mut path = data.name.clone();
+    for name in data.subcommand_path() {
+        path.push(' ');
+        path.push_str(name);
+    }
+
+    path
+}
+
+/// Handler whose prefix is the longest prefix of a custom ID.
+fn longest_prefix<'a, H>(handlers: &'a HashMap<String, H>, custom_id: &str) -> Option<&'a H> {
+    handlers
+        .iter()
+        .filter(|(prefix, _)| custom_id.starts_with(prefix.as_str()))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, handler)| handler)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandOptions, InteractionRouter};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        application::{
+            command::{CommandOptionType, CommandType},
+            interaction::{
+                application_command::{CommandData, CommandDataOption, CommandOptionValue},
+                message_component::MessageComponentInteractionData,
+                Interaction, InteractionData, InteractionType,
+            },
+        },
+        channel::message::component::ComponentType,
+        fixture,
+        id::Id,
+    };
+
+    assert_impl_all!(CommandOptions: Clone, Debug, PartialEq, Send, Sync);
+    assert_impl_all!(InteractionRouter<()>: Debug, Default, Send, Sync);
+
+    fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+        CommandDataOption {
+            name: name.to_owned(),
+            value,
+        }
+    }
+
+    fn command(kind: InteractionType, options: Vec<CommandDataOption>) -> Interaction {
+        let mut interaction = fixture::interaction(Id::new(1), Id::new(2), kind);
+        interaction.data = Some(InteractionData::ApplicationCommand(Box::new(CommandData {
+            guild_id: None,
+            id: Id::new(3),
+            name: "config".to_owned(),
+            kind: CommandType::ChatInput,
+            options,
+            resolved: None,
+            target_id: None,
+        })));
+
+        interaction
+    }
+
+    fn component(custom_id: &str) -> Interaction {
+        let mut interaction =
+            fixture::interaction(Id::new(1), Id::new(2), InteractionType::MessageComponent);
+        interaction.data = Some(InteractionData::MessageComponent(Box::new(
+            MessageComponentInteractionData {
+                custom_id: custom_id.to_owned(),
+                component_type: ComponentType::Button,
+                resolved: None,
+                values: Vec::new(),
+            },
+        )));
+
+        interaction
+    }
+
+    fn router() -> InteractionRouter<String> {
+        InteractionRouter::new()
+            .command("config", |_, _| async { "config".to_owned() })
+            .command(
+                "config volume set",
+                |_, options: CommandOptions| async move {
+                    format!("volume {}", options.integer("volume").unwrap())
+                },
+            )
+            .autocomplete(
+                "config volume set",
+                |_, options: CommandOptions| async move {
+                    let (name, value, _) = options.focused().unwrap();
+
+                    format!("{name} {value}")
+                },
+            )
+            .component("vote:", |_| async { "vote".to_owned() })
+            .component("vote:admin:", |_| async { "admin vote".to_owned() })
+    }
+
+    #[tokio::test]
+    async fn commands() {
+        let router = router();
+        let subcommand = |kind, value| {
+            command(
+                kind,
+                vec![option(
+                    "volume",
+                    CommandOptionValue::SubCommandGroup(vec![option(
+                        "set",
+                        CommandOptionValue::SubCommand(vec![option("volume", value)]),
+                    )]),
+                )],
+            )
+        };
+
+        let interaction = command(InteractionType::ApplicationCommand, Vec::new());
+        assert_eq!("config", router.route(interaction).unwrap().await);
+
+        let interaction = subcommand(
+            InteractionType::ApplicationCommand,
+            CommandOptionValue::Integer(50),
+        );
+        assert_eq!("volume 50", router.route(interaction).unwrap().await);
+
+        let interaction = subcommand(
+            InteractionType::ApplicationCommandAutocomplete,
+            CommandOptionValue::Focused("5".to_owned(), CommandOptionType::Integer),
+        );
+        assert_eq!("volume 5", router.route(interaction).unwrap().await);
+
+        let interaction = command(
+            InteractionType::ApplicationCommand,
+            vec![option("reset", CommandOptionValue::SubCommand(Vec::new()))],
+        );
+        assert!(router.route(interaction).is_err());
+    }
+
+    #[tokio::test]
+    async fn components() {
+        let router = router();
+
+        assert_eq!("vote", router.route(component("vote:yes")).unwrap().await);
+        assert_eq!(
+            "admin vote",
+            router.route(component("vote:admin:yes")).unwrap().await
+        );
+
+        let Err(interaction) = router.route(component("other")) else {
+            panic!("expected no route to match");
+        };
+        assert!(matches!(
+            interaction.data,
+            Some(InteractionData::MessageComponent(data)) if data.custom_id == "other"
+        ));
+    }
+
+    #[test]
+    fn options() {
+        let mut interaction = command(
+            InteractionType::ApplicationCommand,
+            vec![
+                option("flag", CommandOptionValue::Boolean(true)),
+                option("name", CommandOptionValue::String("name".to_owned())),
+                option("user", CommandOptionValue::User(Id::new(4))),
+            ],
+        );
+        let Some(InteractionData::ApplicationCommand(data)) = interaction.data.take() else {
+            unreachable!();
+        };
+        let options = CommandOptions::new(&data);
+
+        assert_eq!(Some(true), options.boolean("flag"));
+        assert_eq!(Some("name"), options.string("name"));
+        assert_eq!(Some(Id::new(4)), options.user("user"));
+        assert!(options.integer("name").is_none());
+        assert!(options.string("missing").is_none());
+        assert!(options.focused().is_none());
+        assert_eq!(3, options.options().len());
+        assert!(options.subcommand_path().is_empty());
+    }
+}
@@ -0,0 +1,328 @@
+//! [`hyper`] service answering interactions received via HTTP.
+//!
+//! [`InteractionService`] verifies the signature of incoming requests,
+//! answers pings, and passes all other interactions to a handler whose
+//! [`InteractionResponse`] is returned to Discord. This allows building bots
+//! without a gateway connection, such as on serverless platforms.
+//!
+//! # Examples
+//!
+//! Serve interactions on port 3030, answering every command with a message:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use hyper::server::conn::http1;
+//! use hyper_util::rt::TokioIo;
+//! use tokio::net::TcpListener;
+//! use twilight_model::{
+//!     application::interaction::Interaction,
+//!     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+//! };
+//! use twilight_util::{interaction_service::InteractionService, signature::Verifier};
+//!
+//! async fn handle(_: Interaction) -> InteractionResponse {
+//!     InteractionResponse {
+//!         kind: InteractionResponseType::ChannelMessageWithSource,
+//!         data: Some(InteractionResponseData {
+//!             content: Some("Vroom vroom".to_owned()),
+//!             ..Default::default()
+//!         }),
+//!     }
+//! }
+//!
+//! let service = InteractionService::new(Verifier::new("PUBLIC_KEY")?, handle);
+//! let listener = TcpListener::bind("127.0.0.1:3030").await?;
+//!
+//! loop {
+//!     let (stream, _) = listener.accept().await?;
+//!     let service = service.clone();
+//!
+//!     tokio::spawn(async move {
+//!         let _ = http1::Builder::new()
+//!             .serve_connection(TokioIo::new(stream), service)
+//!             .await;
+//!     });
+//! }
+//! # }
+//! ```
+
+use crate::signature::{SignatureErrorType, Verifier, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::{
+    body::{Body, Bytes},
+    header::{HeaderValue, CONTENT_TYPE},
+    service::Service,
+    Method, Request, Response, StatusCode,
+};
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+};
+use twilight_model::{
+    application::interaction::{Interaction, InteractionType},
+    http::interaction::{InteractionResponse, InteractionResponseType},
+};
+
+/// Maximum size of a request body in bytes.
+///
+/// Interactions are far smaller, so larger bodies are rejected without being
+/// received in full.
+pub const MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+
+/// Future returned by [`InteractionService`].
+pub type InteractionServiceFuture =
+    Pin<Box<dyn Future<Output = Result<Response<Full<Bytes>>, Infallible>> + Send>>;
+
+/// Service answering interactions received via HTTP.
+///
+/// Requests are answered with the following status codes:
+///
+/// - `405 Method Not Allowed` if the method isn't `POST`;
+/// - `401 Unauthorized` if the signature is missing or invalid;
+/// - `400 Bad Request` if the body can't be received or isn't an interaction;
+/// - `413 Payload Too Large` if the body exceeds [`MAX_BODY_SIZE`];
+/// - `500 Internal Server Error` if the response couldn't be serialized;
+/// - `200 OK` with the JSON serialized [`InteractionResponse`] otherwise.
+///
+/// Pings are answered with a pong without calling the handler.
+pub struct InteractionService<H> {
+    /// Handler producing the responses to interactions.
+    handler: Arc<H>,
+    /// Verifier of request signatures.
+    verifier: Arc<Verifier>,
+}
+
+impl<H, F> InteractionService<H>
+where
+    H: Fn(Interaction) -> F + Send + Sync + 'static,
+    F: Future<Output = InteractionResponse> + Send + 'static,
+{
+    /// Create a new service passing verified interactions to a handler.
+    pub fn new(verifier: Verifier, handler: H) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            verifier: Arc::new(verifier),
+        }
+    }
+}
+
+impl<H> Clone for InteractionService<H> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: Arc::clone(&self.handler),
+            verifier: Arc::clone(&self.verifier),
+        }
+    }
+}
+
+impl<H> Debug for InteractionService<H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractionService")
+            .field("verifier", &self.verifier)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, H, F> Service<Request<B>> for InteractionService<H>
+where
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+    H: Fn(Interaction) -> F + Send + Sync + 'static,
+    F: Future<Output = InteractionResponse> + Send + 'static,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = InteractionServiceFuture;
+
+    fn call(&self, request: Request<B>) -> Self::Future {
+        let handler = Arc::clone(&self.handler);
+        let verifier = Arc::clone(&self.verifier);
+
+        Box::pin(async move { Ok(handle(&verifier, &*handler, request).await) })
+    }
+}
+
+/// Answer a request.
+async fn handle<B, H, F>(
+    verifier: &Verifier,
+    handler: &H,
+    request: Request<B>,
+) -> Response<Full<Bytes>>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+    H: Fn(Interaction) -> F,
+    F: Future<Output = InteractionResponse>,
+{
+    if request.method() != Method::POST {
+        return status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    let (Some(signature), Some(timestamp)) = (
+        header(&request, SIGNATURE_HEADER),
+        header(&request, TIMESTAMP_HEADER),
+    ) else {
+        return status(StatusCode::UNAUTHORIZED);
+    };
+
+    let body = match Limited::new(request.into_body(), MAX_BODY_SIZE)
+        .collect()
+        .await
+    {
+        Ok(body) => body,
+        Err(source) if source.is::<LengthLimitError>() => {
+            return status(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+        Err(_) => return status(StatusCode::BAD_REQUEST),
+    };
+
+    let interaction = match verifier.verify_interaction(&signature, &timestamp, &body.to_bytes()) {
+        Ok(interaction) => interaction,
+        Err(source) if matches!(source.kind(), SignatureErrorType::Deserializing) => {
+            return status(StatusCode::BAD_REQUEST);
+        }
+        Err(_) => return status(StatusCode::UNAUTHORIZED),
+    };
+
+    let response = if interaction.kind == InteractionType::Ping {
+        InteractionResponse {
+            kind: InteractionResponseType::Pong,
+            data: None,
+        }
+    } else {
+        handler(interaction).await
+    };
+
+    let Ok(json) = serde_json::to_vec(&response) else {
+        return status(StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    let mut response = Response::new(Full::from(json));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response
+}
+
+/// Value of a header of a request.
+fn header<B>(request: &Request<B>, name: &str) -> Option<String> {
+    request
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+/// Create an empty response with a status code.
+fn status(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = status;
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InteractionService, MAX_BODY_SIZE};
+    use crate::signature::{Verifier, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+    use ed25519_dalek::{Signer, SigningKey};
+    use http_body_util::{BodyExt, Full};
+    use hyper::{body::Bytes, service::Service, Method, Request, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::{Debug, Write},
+        future::{ready, Ready},
+    };
+    use twilight_model::{
+        application::interaction::Interaction,
+        http::interaction::{InteractionResponse, InteractionResponseType},
+    };
+
+    type Handler = fn(Interaction) -> Ready<InteractionResponse>;
+
+    assert_impl_all!(InteractionService<Handler>: Clone, Debug, Send, Sync);
+
+    const TIMESTAMP: &str = "1700000000";
+
+    fn handler(_: Interaction) -> Ready<InteractionResponse> {
+        ready(InteractionResponse {
+            kind: InteractionResponseType::DeferredChannelMessageWithSource,
+            data: None,
+        })
+    }
+
+    fn service(key: &SigningKey) -> InteractionService<Handler> {
+        InteractionService::new(Verifier::from_key(key.verifying_key()), handler)
+    }
+
+    fn request(key: &SigningKey, body: &[u8]) -> Request<Full<Bytes>> {
+        let signature = key.sign(&[TIMESTAMP.as_bytes(), body].concat());
+        let signature = signature
+            .to_bytes()
+            .iter()
+            .fold(String::new(), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+
+                hex
+            });
+
+        Request::builder()
+            .method(Method::POST)
+            .header(SIGNATURE_HEADER, signature)
+            .header(TIMESTAMP_HEADER, TIMESTAMP)
+            .body(Full::from(body.to_vec()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn responses() -> Result<(), Box<dyn Error>> {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let service = service(&key);
+
+        let ping = br#"{"application_id":"2","id":"1","token":"token","type":1,"version":1}"#;
+        let response = service.call(request(&key, ping)).await?;
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            br#"{"type":1}"#.as_slice(),
+            response.into_body().collect().await?.to_bytes()
+        );
+
+        let command = br#"{"application_id":"2","data":{"id":"3","name":"vroom","type":1},"id":"1","token":"token","type":2,"version":1}"#;
+        let response = service.call(request(&key, command)).await?;
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            br#"{"type":5}"#.as_slice(),
+            response.into_body().collect().await?.to_bytes()
+        );
+
+        let mut unsigned = request(&key, ping);
+        unsigned.headers_mut().remove(SIGNATURE_HEADER);
+        let response = service.call(unsigned).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+        let other_key = SigningKey::from_bytes(&[8; 32]);
+        let response = service.call(request(&other_key, ping)).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+        let response = service.call(request(&key, b"{}")).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+
+        let mut get = request(&key, ping);
+        *get.method_mut() = Method::GET;
+        let response = service.call(get).await?;
+        assert_eq!(StatusCode::METHOD_NOT_ALLOWED, response.status());
+
+        let large = vec![b' '; MAX_BODY_SIZE + 1];
+        let response = service.call(request(&key, &large)).await?;
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+        Ok(())
+    }
+}
@@ -15,11 +15,35 @@
 #[cfg(feature = "builder")]
 pub mod builder;
 
+#[cfg(feature = "cdn")]
+pub mod cdn;
+
+#[cfg(feature = "image-data")]
+pub mod image_data;
+
+#[cfg(feature = "interaction-service")]
+pub mod interaction_service;
+
+#[cfg(feature = "interaction-router")]
+pub mod interaction_router;
+
 #[cfg(feature = "link")]
 pub mod link;
 
+#[cfg(feature = "markdown")]
+pub mod markdown;
+
+#[cfg(feature = "paginator")]
+pub mod paginator;
+
 #[cfg(feature = "permission-calculator")]
 pub mod permission_calculator;
 
+#[cfg(feature = "signature")]
+pub mod signature;
+
 #[cfg(feature = "snowflake")]
 pub mod snowflake;
+
+#[cfg(feature = "split")]
+pub mod split;
@@ -1,3 +1,4 @@
 //! Utilities for parsing and formatting links to various resources.
 
+pub mod parse;
 pub mod webhook;
@@ -0,0 +1,637 @@
+//! Utilities for parsing links to Discord resources.
+//!
+//! This is the inverse of formatting links, such as the URLs of [CDN assets],
+//! recognizing message, channel, invite, webhook, and CDN asset links and
+//! returning the IDs they contain.
+//!
+//! [CDN assets]: crate::cdn
+
+use super::webhook::{self, WebhookParseErrorType};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::NonZeroU64,
+};
+use twilight_model::{
+    id::{
+        marker::{
+            AttachmentMarker, ChannelMarker, EmojiMarker, GenericMarker, GuildMarker,
+            MessageMarker, RoleMarker, StickerMarker, UserMarker, WebhookMarker,
+        },
+        Id,
+    },
+    util::ImageHash,
+};
+
+/// Hosts of the Discord client, which message and channel links point to.
+const CLIENT_HOSTS: &[&str] = &[
+    "discord.com",
+    "canary.discord.com",
+    "ptb.discord.com",
+    "discordapp.com",
+    "canary.discordapp.com",
+    "ptb.discordapp.com",
+    "www.discord.com",
+];
+
+/// Hosts of Discord's CDN and media proxy.
+const CDN_HOSTS: &[&str] = &["cdn.discordapp.com", "media.discordapp.net"];
+
+/// Host of Discord's invite shortener.
+const INVITE_HOST: &str = "discord.gg";
+
+/// Error when [parsing] a link.
+///
+/// [parsing]: link
+#[derive(Debug)]
+pub struct LinkParseError {
+    kind: LinkParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl LinkParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &LinkParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (LinkParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Shorthand to create an error of a type without a source.
+    const fn new(kind: LinkParseErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for LinkParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            LinkParseErrorType::HashInvalid => f.write_str("url path segment isn't a valid hash"),
+            LinkParseErrorType::IdInvalid => f.write_str("url path segment isn't a valid ID"),
+            LinkParseErrorType::SegmentMissing => {
+                f.write_str("url is missing a required path segment")
+            }
+            LinkParseErrorType::Unrecognized => {
+                f.write_str("url doesn't link to a recognized resource")
+            }
+        }
+    }
+}
+
+impl Error for LinkParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`LinkParseError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LinkParseErrorType {
+    /// Image hash segment in the URL path is not a valid hash.
+    HashInvalid,
+    /// ID segment in the URL path is not an integer.
+    IdInvalid,
+    /// Required segment of the URL path is missing.
+    SegmentMissing,
+    /// URL is not a link to a recognized resource.
+    Unrecognized,
+}
+
+/// Asset hosted on Discord's CDN.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CdnAsset<'a> {
+    /// Attachment of a message.
+    Attachment {
+        /// ID of the attachment.
+        attachment_id: Id<AttachmentMarker>,
+        /// ID of the channel the message was sent in.
+        channel_id: Id<ChannelMarker>,
+        /// Name of the attached file.
+        filename: &'a str,
+    },
+    /// Avatar of a user.
+    Avatar {
+        /// Hash of the avatar.
+        hash: ImageHash,
+        /// ID of the user.
+        user_id: Id<UserMarker>,
+    },
+    /// Banner of a guild or user.
+    ///
+    /// The ID may be of either, as they share the same path.
+    Banner {
+        /// Hash of the banner.
+        hash: ImageHash,
+        /// ID of the guild or user.
+        id: Id<GenericMarker>,
+    },
+    /// Custom emoji.
+    Emoji {
+        /// ID of the emoji.
+        emoji_id: Id<EmojiMarker>,
+    },
+    /// Icon of a guild.
+    GuildIcon {
+        /// ID of the guild.
+        guild_id: Id<GuildMarker>,
+        /// Hash of the icon.
+        hash: ImageHash,
+    },
+    /// Avatar of a member specific to a guild.
+    MemberAvatar {
+        /// ID of the guild.
+        guild_id: Id<GuildMarker>,
+        /// Hash of the avatar.
+        hash: ImageHash,
+        /// ID of the user.
+        user_id: Id<UserMarker>,
+    },
+    /// Icon of a role.
+    RoleIcon {
+        /// Hash of the icon.
+        hash: ImageHash,
+        /// ID of the role.
+        role_id: Id<RoleMarker>,
+    },
+    /// Sticker.
+    Sticker {
+        /// ID of the sticker.
+        sticker_id: Id<StickerMarker>,
+    },
+}
+
+/// Resource linked to by a URL.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Link<'a> {
+    /// Asset hosted on Discord's CDN.
+    Cdn(CdnAsset<'a>),
+    /// Channel in a guild or a private channel.
+    Channel {
+        /// ID of the channel.
+        channel_id: Id<ChannelMarker>,
+        /// ID of the guild, if the channel isn't a private channel.
+        guild_id: Option<Id<GuildMarker>>,
+    },
+    /// Invite to a guild or group DM.
+    Invite {
+        /// Code of the invite.
+        code: &'a str,
+    },
+    /// Message in a guild or private channel.
+    Message {
+        /// ID of the channel.
+        channel_id: Id<ChannelMarker>,
+        /// ID of the guild, if the channel isn't a private channel.
+        guild_id: Option<Id<GuildMarker>>,
+        /// ID of the message.
+        message_id: Id<MessageMarker>,
+    },
+    /// Webhook, with its token if it's included.
+    Webhook {
+        /// Token of the webhook.
+        token: Option<&'a str>,
+        /// ID of the webhook.
+        webhook_id: Id<WebhookMarker>,
+    },
+}
+
+/// Parse the resource a URL links to.
+///
+/// The scheme of the URL is optional and its query and fragment are ignored.
+/// Hosts are matched case-insensitively.
+///
+/// # Examples
+///
+/// Parse a message link:
+///
+/// ```
+/// use twilight_model::id::Id;
+/// use twilight_util::link::parse::{self, Link};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = "https://discord.com/channels/1/2/3";
+///
+/// assert_eq!(
+///     Link::Message {
+///         channel_id: Id::new(2),
+///         guild_id: Some(Id::new(1)),
+///         message_id: Id::new(3),
+///     },
+///     parse::link(url)?,
+/// );
+/// # Ok(()) }
+/// ```
+///
+/// Parse an invite link:
+///
+/// ```
+/// use twilight_util::link::parse::{self, Link};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(
+///     Link::Invite { code: "twilight" },
+///     parse::link("https://discord.gg/twilight")?,
+/// );
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`LinkParseErrorType::HashInvalid`] error type if the image hash
+/// segment of a CDN asset URL is not a valid hash.
+///
+/// Returns [`LinkParseErrorType::IdInvalid`] error type if an ID segment of the
+/// URL is not a valid integer.
+///
+/// Returns [`LinkParseErrorType::SegmentMissing`] error type if a segment
+/// required by the type of link is missing.
+///
+/// Returns [`LinkParseErrorType::Unrecognized`] error type if the URL isn't a
+/// link to a recognized resource.
+pub fn link(url: &str) -> Result<Link<'_>, LinkParseError> {
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = url.split_once('/').unwrap_or((url, ""));
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+
+    let is_host = |known: &&str| known.eq_ignore_ascii_case(host);
+
+    if is_host(&INVITE_HOST) {
+        return invite(segments.next());
+    }
+
+    if CDN_HOSTS.iter().any(is_host) {
+        return cdn(segments).map(Link::Cdn);
+    }
+
+    if !CLIENT_HOSTS.iter().any(is_host) {
+        return Err(LinkParseError::new(LinkParseErrorType::Unrecognized));
+    }
+
+    match segments.next() {
+        Some("api") => webhook(path),
+        Some("channels") => channel(segments),
+        Some("invite") => invite(segments.next()),
+        _ => Err(LinkParseError::new(LinkParseErrorType::Unrecognized)),
+    }
+}
+
+/// Parse the asset from the path segments of a CDN URL.
+fn cdn<'a>(mut segments: impl Iterator<Item = &'a str>) -> Result<CdnAsset<'a>, LinkParseError> {
+    let asset = match segments.next() {
+        Some("attachments") => CdnAsset::Attachment {
+            channel_id: id(segments.next())?,
+            attachment_id: id(segments.next())?,
+            filename: required(segments.next())?,
+        },
+        Some("avatars") => CdnAsset::Avatar {
+            user_id: id(segments.next())?,
+            hash: hash(segments.next())?,
+        },
+        Some("banners") => CdnAsset::Banner {
+            id: id(segments.next())?,
+            hash: hash(segments.next())?,
+        },
+        Some("emojis") => CdnAsset::Emoji {
+            emoji_id: id(segments.next().map(strip_extension))?,
+        },
+        Some("guilds") => {
+            let guild_id = id(segments.next())?;
+
+            if segments.next() != Some("users") {
+                return Err(LinkParseError::new(LinkParseErrorType::Unrecognized));
+            }
+
+            let user_id = id(segments.next())?;
+
+            if segments.next() != Some("avatars") {
+                return Err(LinkParseError::new(LinkParseErrorType::Unrecognized));
+            }
+
+            CdnAsset::MemberAvatar {
+                guild_id,
+                hash: hash(segments.next())?,
+                user_id,
+            }
+        }
+        Some("icons") => CdnAsset::GuildIcon {
+            guild_id: id(segments.next())?,
+            hash: hash(segments.next())?,
+        },
+        Some("role-icons") => CdnAsset::RoleIcon {
+            role_id: id(segments.next())?,
+            hash: hash(segments.next())?,
+        },
+        Some("stickers") => CdnAsset::Sticker {
+            sticker_id: id(segments.next().map(strip_extension))?,
+        },
+        _ => return Err(LinkParseError::new(LinkParseErrorType::Unrecognized)),
+    };
+
+    Ok(asset)
+}
+
+/// Parse a channel or message link from the segments of its path following
+/// `channels`.
+fn channel<'a>(mut segments: impl Iterator<Item = &'a str>) -> Result<Link<'a>, LinkParseError> {
+    let guild_id = match required(segments.next())? {
+        "@me" => None,
+        segment => Some(id(Some(segment))?),
+    };
+    let channel_id = id(segments.next())?;
+
+    let Some(message_segment) = segments.next() else {
+        return Ok(Link::Channel {
+            channel_id,
+            guild_id,
+        });
+    };
+
+    Ok(Link::Message {
+        channel_id,
+        guild_id,
+        message_id: id(Some(message_segment))?,
+    })
+}
+
+/// Parse an invite link from its code segment.
+fn invite(segment: Option<&str>) -> Result<Link<'_>, LinkParseError> {
+    Ok(Link::Invite {
+        code: required(segment)?,
+    })
+}
+
+/// Parse a webhook link from its path via the [webhook URL parser].
+///
+/// The API version segment is optional.
+///
+/// [webhook URL parser]: webhook::parse
+fn webhook(path: &str) -> Result<Link<'_>, LinkParseError> {
+    let (_api, path) = split_segment(path);
+    let (mut segment, mut path) = split_segment(path);
+
+    if segment.starts_with('v') {
+        (segment, path) = split_segment(path);
+    }
+
+    if segment != "webhooks" {
+        return Err(LinkParseError::new(LinkParseErrorType::Unrecognized));
+    }
+
+    let (webhook_id, token) = webhook::parse_path(path).map_err(|source| {
+        let (kind, source) = source.into_parts();
+        let kind = match kind {
+            WebhookParseErrorType::IdInvalid => LinkParseErrorType::IdInvalid,
+            WebhookParseErrorType::SegmentMissing => LinkParseErrorType::SegmentMissing,
+        };
+
+        LinkParseError { kind, source }
+    })?;
+
+    Ok(Link::Webhook { token, webhook_id })
+}
+
+/// Parse an image hash from a file name segment, ignoring its extension.
+fn hash(segment: Option<&str>) -> Result<ImageHash, LinkParseError> {
+    let segment = strip_extension(required(segment)?);
+
+    ImageHash::parse(segment.as_bytes()).map_err(|source| LinkParseError {
+        kind: LinkParseErrorType::HashInvalid,
+        source: Some(Box::new(source)),
+    })
+}
+
+/// Parse an ID from a segment.
+fn id<T>(segment: Option<&str>) -> Result<Id<T>, LinkParseError> {
+    required(segment)?
+        .parse::<NonZeroU64>()
+        .map(Id::from)
+        .map_err(|source| LinkParseError {
+            kind: LinkParseErrorType::IdInvalid,
+            source: Some(Box::new(source)),
+        })
+}
+
+/// Ensure a required segment is present.
+fn required(segment: Option<&str>) -> Result<&str, LinkParseError> {
+    segment.ok_or(LinkParseError::new(LinkParseErrorType::SegmentMissing))
+}
+
+/// Split the first segment off a path.
+fn split_segment(path: &str) -> (&str, &str) {
+    path.split_once('/').unwrap_or((path, ""))
+}
+
+/// Remove the file extension of a segment, if it has one.
+fn strip_extension(segment: &str) -> &str {
+    segment.rsplit_once('.').map_or(segment, |(name, _)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnAsset, Link, LinkParseError, LinkParseErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{id::Id, util::ImageHash};
+
+    assert_impl_all!(CdnAsset<'_>: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Link<'_>: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(LinkParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(LinkParseError: Debug, Error, Send, Sync);
+
+    const HASH: &str = "b0e09d6697b11e9c79a89e5e3756ddee";
+
+    #[test]
+    fn channel_and_message() {
+        assert_eq!(
+            Link::Channel {
+                channel_id: Id::new(2),
+                guild_id: Some(Id::new(1)),
+            },
+            super::link("https://discord.com/channels/1/2").unwrap(),
+        );
+        assert_eq!(
+            Link::Message {
+                channel_id: Id::new(2),
+                guild_id: None,
+                message_id: Id::new(3),
+            },
+            super::link("https://canary.discord.com/channels/@me/2/3?query#fragment").unwrap(),
+        );
+        assert!(matches!(
+            super::link("discord.com/channels/1").unwrap_err().kind(),
+            LinkParseErrorType::SegmentMissing,
+        ));
+        assert!(matches!(
+            super::link("discord.com/channels/1/two")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::IdInvalid,
+        ));
+    }
+
+    #[test]
+    fn invite() {
+        assert_eq!(
+            Link::Invite { code: "twilight" },
+            super::link("https://discord.gg/twilight").unwrap(),
+        );
+        assert_eq!(
+            Link::Invite { code: "twilight" },
+            super::link("http://discord.com/invite/twilight/").unwrap(),
+        );
+        assert!(matches!(
+            super::link("https://discord.gg/").unwrap_err().kind(),
+            LinkParseErrorType::SegmentMissing,
+        ));
+    }
+
+    #[test]
+    fn webhook() {
+        assert_eq!(
+            Link::Webhook {
+                token: Some("token"),
+                webhook_id: Id::new(1),
+            },
+            super::link("https://discord.com/api/webhooks/1/token").unwrap(),
+        );
+        assert_eq!(
+            Link::Webhook {
+                token: None,
+                webhook_id: Id::new(1),
+            },
+            super::link("https://discordapp.com/api/v10/webhooks/1/").unwrap(),
+        );
+        assert!(matches!(
+            super::link("https://discord.com/api/webhooks/one")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::IdInvalid,
+        ));
+        assert!(matches!(
+            super::link("https://discord.com/api/webhooks")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::SegmentMissing,
+        ));
+        assert!(matches!(
+            super::link("https://discord.com/api/v10/users/1")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::Unrecognized,
+        ));
+    }
+
+    #[test]
+    fn cdn() {
+        let hash = ImageHash::parse(HASH.as_bytes()).unwrap();
+
+        assert_eq!(
+            Link::Cdn(CdnAsset::Attachment {
+                attachment_id: Id::new(2),
+                channel_id: Id::new(1),
+                filename: "image.png",
+            }),
+            super::link("https://cdn.discordapp.com/attachments/1/2/image.png?ex=1").unwrap(),
+        );
+        assert_eq!(
+            Link::Cdn(CdnAsset::Avatar {
+                hash,
+                user_id: Id::new(1),
+            }),
+            super::link(&format!(
+                "https://cdn.discordapp.com/avatars/1/{HASH}.webp?size=256"
+            ))
+            .unwrap(),
+        );
+        assert_eq!(
+            Link::Cdn(CdnAsset::MemberAvatar {
+                guild_id: Id::new(1),
+                hash,
+                user_id: Id::new(2),
+            }),
+            super::link(&format!(
+                "https://cdn.discordapp.com/guilds/1/users/2/avatars/{HASH}.png"
+            ))
+            .unwrap(),
+        );
+        assert_eq!(
+            Link::Cdn(CdnAsset::Emoji {
+                emoji_id: Id::new(4),
+            }),
+            super::link("https://cdn.discordapp.com/emojis/4.gif?size=32").unwrap(),
+        );
+        assert_eq!(
+            Link::Cdn(CdnAsset::Sticker {
+                sticker_id: Id::new(5),
+            }),
+            super::link("https://media.discordapp.net/stickers/5.gif").unwrap(),
+        );
+        assert!(matches!(
+            super::link("https://cdn.discordapp.com/icons/1/nothash.png")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::HashInvalid,
+        ));
+    }
+
+    #[test]
+    fn host_case_insensitive() {
+        assert_eq!(
+            Link::Channel {
+                channel_id: Id::new(2),
+                guild_id: Some(Id::new(1)),
+            },
+            super::link("https://Discord.com/channels/1/2").unwrap(),
+        );
+        assert_eq!(
+            Link::Invite { code: "twilight" },
+            super::link("DISCORD.GG/twilight").unwrap(),
+        );
+        assert!(matches!(
+            super::link("https://CDN.discordapp.com/emojis/4.png").unwrap(),
+            Link::Cdn(CdnAsset::Emoji { .. }),
+        ));
+    }
+
+    #[test]
+    fn unrecognized() {
+        assert!(matches!(
+            super::link("https://example.com/channels/1/2")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::Unrecognized,
+        ));
+        assert!(matches!(
+            super::link("https://discord.com/developers")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::Unrecognized,
+        ));
+        assert!(matches!(
+            super::link("https://cdn.discordapp.com/app-icons/1/hash.png")
+                .unwrap_err()
+                .kind(),
+            LinkParseErrorType::Unrecognized,
+        ));
+    }
+}
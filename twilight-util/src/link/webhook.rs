@@ -117,15 +117,21 @@ pub enum WebhookParseErrorType {
 /// required segments is missing. This can be the "api" or "webhooks" standard
 /// segment of the URL or the segment containing the webhook ID.
 pub fn parse(url: &str) -> Result<(Id<WebhookMarker>, Option<&str>), WebhookParseError> {
-    let mut segments = {
-        let mut start = url.split("discord.com/api/webhooks/");
-        let path = start.nth(1).ok_or(WebhookParseError {
-            kind: WebhookParseErrorType::SegmentMissing,
-            source: None,
-        })?;
+    let mut start = url.split("discord.com/api/webhooks/");
+    let path = start.nth(1).ok_or(WebhookParseError {
+        kind: WebhookParseErrorType::SegmentMissing,
+        source: None,
+    })?;
+
+    parse_path(path)
+}
 
-        path.split('/')
-    };
+/// Parse the webhook ID and token from the path of a webhook URL following
+/// its "webhooks" segment.
+pub(super) fn parse_path(
+    path: &str,
+) -> Result<(Id<WebhookMarker>, Option<&str>), WebhookParseError> {
+    let mut segments = path.split('/');
 
     let id_segment = segments.next().ok_or(WebhookParseError {
         kind: WebhookParseErrorType::SegmentMissing,
@@ -0,0 +1,224 @@
+//! Utilities for escaping Discord markdown and neutralizing mentions in
+//! user-supplied text.
+//!
+//! Echoing text from users back into a message may otherwise format it in
+//! unintended ways or ping large numbers of users.
+//!
+//! Neutralizing mentions is a safeguard for the message content only; setting
+//! the allowed mentions of a message is the reliable way to control who is
+//! pinged.
+
+/// Zero width space, which breaks up character sequences without being
+/// visible.
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Characters that format text anywhere in a line.
+const INLINE: &[char] = &['\\', '*', '_', '~', '`', '|', '[', ']', '<'];
+
+/// Characters that format text only at the start of a line, such as quotes,
+/// headers, and lists.
+const LINE_START: &[char] = &['>', '#', '-', '+'];
+
+/// Escape markdown in text, so that it is displayed as written.
+///
+/// This escapes text styles, spoilers, code, masked links, custom emojis, and
+/// mentions anywhere in the text, and quotes, headers, and lists at the start
+/// of lines.
+///
+/// Escaping also applies to URLs in the text, which may therefore no longer be
+/// clickable.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::markdown;
+///
+/// assert_eq!(
+///     r"\*\*bold\*\* and \|\|spoiler\|\|",
+///     markdown::escape("**bold** and ||spoiler||"),
+/// );
+/// assert_eq!(r"\> not a quote", markdown::escape("> not a quote"));
+/// ```
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut line_start = true;
+
+    for character in text.chars() {
+        if INLINE.contains(&character) || (line_start && LINE_START.contains(&character)) {
+            escaped.push('\\');
+        }
+
+        escaped.push(character);
+
+        if character == '\n' {
+            line_start = true;
+        } else if !character.is_whitespace() {
+            line_start = false;
+        }
+    }
+
+    escaped
+}
+
+/// Escape text to be placed within a code block.
+///
+/// Markdown is not formatted within code blocks, but a sequence of backticks
+/// would end the code block early. This separates consecutive backticks with
+/// zero width spaces.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::markdown;
+///
+/// let code = markdown::escape_code_block("```rust");
+///
+/// assert_eq!("`\u{200B}`\u{200B}`rust", code);
+/// assert!(!code.contains("``"));
+/// ```
+pub fn escape_code_block(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut previous = None;
+
+    for character in text.chars() {
+        if character == '`' && previous == Some('`') {
+            escaped.push(ZERO_WIDTH_SPACE);
+        }
+
+        escaped.push(character);
+        previous = Some(character);
+    }
+
+    escaped
+}
+
+/// Neutralize mass mentions in text, so that they don't ping when the text is
+/// sent.
+///
+/// A zero width space is inserted into `@everyone`, `@here`, and role
+/// mentions, which still display similar to the original text.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::markdown;
+///
+/// let text = markdown::neutralize_mass_mentions("hi @everyone and <@&1>");
+///
+/// assert_eq!("hi @\u{200B}everyone and <@\u{200B}&1>", text);
+/// ```
+pub fn neutralize_mass_mentions(text: &str) -> String {
+    let mut neutralized = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find('@') {
+        let (before, after) = rest.split_at(index + 1);
+        neutralized.push_str(before);
+
+        if after.starts_with("everyone")
+            || after.starts_with("here")
+            || (after.starts_with('&') && before.ends_with("<@"))
+        {
+            neutralized.push(ZERO_WIDTH_SPACE);
+        }
+
+        rest = after;
+    }
+
+    neutralized.push_str(rest);
+
+    neutralized
+}
+
+/// Remove mass mentions from text.
+///
+/// `@everyone` and `@here` are removed along with role mentions, such as
+/// `<@&1>`.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::markdown;
+///
+/// assert_eq!("hi  and ", markdown::strip_mass_mentions("hi @everyone and <@&1>"));
+/// ```
+pub fn strip_mass_mentions(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find('@') {
+        let (before, after) = rest.split_at(index);
+
+        if let Some(after) = after
+            .strip_prefix("@everyone")
+            .or_else(|| after.strip_prefix("@here"))
+        {
+            stripped.push_str(before);
+            rest = after;
+        } else if let Some(role) = before
+            .ends_with('<')
+            .then(|| after.strip_prefix("@&"))
+            .flatten()
+            .and_then(|role| role.split_once('>'))
+            .filter(|(id, _)| !id.is_empty() && id.bytes().all(|byte| byte.is_ascii_digit()))
+        {
+            stripped.push_str(&before[..before.len() - 1]);
+            rest = role.1;
+        } else {
+            stripped.push_str(before);
+            stripped.push('@');
+            rest = &after[1..];
+        }
+    }
+
+    stripped.push_str(rest);
+
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn escape() {
+        assert_eq!(
+            r"\_\_underline\_\_ \~\~strike\~\~ \`code\`",
+            super::escape("__underline__ ~~strike~~ `code`"),
+        );
+        assert_eq!(
+            r"\[masked\](https://example.com)",
+            super::escape("[masked](https://example.com)"),
+        );
+        assert_eq!(r"\<:emoji:1>", super::escape("<:emoji:1>"));
+        assert_eq!(
+            "\\# header\n  \\- item\nnot - item",
+            super::escape("# header\n  - item\nnot - item"),
+        );
+        assert_eq!(r"\\\*", super::escape(r"\*"));
+    }
+
+    #[test]
+    fn escape_code_block() {
+        assert_eq!("a`b", super::escape_code_block("a`b"));
+        assert_eq!(
+            "`\u{200B}`\u{200B}`\u{200B}`",
+            super::escape_code_block("````")
+        );
+    }
+
+    #[test]
+    fn neutralize_mass_mentions() {
+        assert_eq!(
+            "@\u{200B}here @user <@1> <@\u{200B}&2> email@example.com",
+            super::neutralize_mass_mentions("@here @user <@1> <@&2> email@example.com"),
+        );
+    }
+
+    #[test]
+    fn strip_mass_mentions() {
+        assert_eq!(
+            " @user <@1>  <@&> @",
+            super::strip_mass_mentions("@here @user <@1> <@&2> <@&> @"),
+        );
+        assert_eq!("", super::strip_mass_mentions("@everyone@here"));
+    }
+}
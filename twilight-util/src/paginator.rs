@@ -0,0 +1,356 @@
+//! Paginate a list of pages in a message with previous and next buttons.
+//!
+//! A [`Paginator`] renders a page along with buttons leading to the adjacent
+//! pages, and applies clicks on those buttons to render the page they lead to.
+//! The target page is stored in the custom IDs of the buttons, so no state
+//! needs to be kept between interactions besides the paginator itself.
+
+use twilight_model::{
+    application::interaction::message_component::MessageComponentInteractionData,
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component, Embed,
+    },
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
+
+/// Label of the button leading to the next page by default.
+const NEXT_LABEL: &str = "Next";
+
+/// Label of the button leading to the previous page by default.
+const PREVIOUS_LABEL: &str = "Previous";
+
+/// Page of a [`Paginator`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Page {
+    /// Content of the page.
+    pub content: Option<String>,
+    /// Embeds of the page.
+    pub embeds: Vec<Embed>,
+}
+
+impl Page {
+    /// Create a page with content.
+    pub fn content(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+            embeds: Vec::new(),
+        }
+    }
+
+    /// Create a page with embeds.
+    pub const fn embeds(embeds: Vec<Embed>) -> Self {
+        Self {
+            content: None,
+            embeds,
+        }
+    }
+}
+
+impl From<Embed> for Page {
+    fn from(embed: Embed) -> Self {
+        Self::embeds(vec![embed])
+    }
+}
+
+/// Paginate a list of pages with previous and next buttons.
+///
+/// The custom IDs of the buttons start with the paginator's ID followed by a
+/// colon, which can be used to route component interactions to the paginator.
+/// Custom IDs are limited to 100 characters, which leaves room for IDs of up
+/// to 70 characters.
+///
+/// # Examples
+///
+/// Respond with the first page and update the message when a button is
+/// clicked:
+///
+/// ```
+/// use twilight_model::{
+///     application::interaction::message_component::MessageComponentInteractionData,
+///     channel::message::component::ComponentType,
+///     http::interaction::{InteractionResponse, InteractionResponseType},
+/// };
+/// use twilight_util::paginator::{Page, Paginator};
+///
+/// let pages = vec![Page::content("first"), Page::content("second")];
+/// let paginator = Paginator::new("help", pages).expect("pages aren't empty");
+///
+/// let response = InteractionResponse {
+///     kind: InteractionResponseType::ChannelMessageWithSource,
+///     data: paginator.render(0),
+/// };
+///
+/// // Later, when the next button is clicked:
+/// let data = MessageComponentInteractionData {
+///     custom_id: "help:next:1".to_owned(),
+///     component_type: ComponentType::Button,
+///     resolved: None,
+///     values: Vec::new(),
+/// };
+/// let update = paginator.apply(&data).expect("button of the paginator");
+///
+/// assert_eq!(InteractionResponseType::UpdateMessage, update.kind);
+/// assert_eq!(Some("second"), update.data.unwrap().content.as_deref());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Paginator {
+    /// Prefix of the custom IDs of the buttons.
+    id: String,
+    /// Label of the button leading to the next page.
+    next_label: String,
+    /// Pages to paginate, of which there is at least one.
+    pages: Vec<Page>,
+    /// Label of the button leading to the previous page.
+    previous_label: String,
+}
+
+impl Paginator {
+    /// Create a paginator of a list of pages.
+    ///
+    /// Returns `None` if there are no pages.
+    pub fn new(id: impl Into<String>, pages: Vec<Page>) -> Option<Self> {
+        if pages.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            id: id.into(),
+            next_label: NEXT_LABEL.to_owned(),
+            pages,
+            previous_label: PREVIOUS_LABEL.to_owned(),
+        })
+    }
+
+    /// Set the label of the button leading to the next page.
+    ///
+    /// Defaults to "Next".
+    #[must_use = "has no effect if not used"]
+    pub fn next_label(mut self, label: impl Into<String>) -> Self {
+        self.next_label = label.into();
+
+        self
+    }
+
+    /// Set the label of the button leading to the previous page.
+    ///
+    /// Defaults to "Previous".
+    #[must_use = "has no effect if not used"]
+    pub fn previous_label(mut self, label: impl Into<String>) -> Self {
+        self.previous_label = label.into();
+
+        self
+    }
+
+    /// ID of the paginator.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Pages of the paginator.
+    pub fn pages(&self) -> &[Page] {
+        &self.pages
+    }
+
+    /// Apply a component interaction, producing a response updating the
+    /// message to the page the clicked button leads to.
+    ///
+    /// Returns `None` if the component isn't a button of the paginator.
+    pub fn apply(&self, data: &MessageComponentInteractionData) -> Option<InteractionResponse> {
+        let index = self.page_index(&data.custom_id)?;
+
+        Some(InteractionResponse {
+            kind: InteractionResponseType::UpdateMessage,
+            data: self.render(index),
+        })
+    }
+
+    /// Components of a page, consisting of a row with the previous and next
+    /// buttons.
+    ///
+    /// The previous button is disabled on the first page, and the next button
+    /// is disabled on the last page.
+    ///
+    /// Returns `None` if there is no page at the index.
+    pub fn components(&self, index: usize) -> Option<Vec<Component>> {
+        if index >= self.pages.len() {
+            return None;
+        }
+
+        let last = self.pages.len() - 1;
+        let previous = self.button(
+            "previous",
+            &self.previous_label,
+            index.saturating_sub(1),
+            index == 0,
+        );
+        let next = self.button(
+            "next",
+            &self.next_label,
+            (index + 1).min(last),
+            index == last,
+        );
+
+        Some(vec![Component::ActionRow(ActionRow {
+            components: vec![previous, next],
+        })])
+    }
+
+    /// Index of the page a button of the paginator leads to.
+    ///
+    /// Returns `None` if the custom ID isn't of a button of the paginator.
+    pub fn page_index(&self, custom_id: &str) -> Option<usize> {
+        let (direction, index) = custom_id
+            .strip_prefix(self.id.as_str())?
+            .strip_prefix(':')?
+            .split_once(':')?;
+
+        if direction != "previous" && direction != "next" {
+            return None;
+        }
+
+        index.parse().ok().filter(|index| *index < self.pages.len())
+    }
+
+    /// Render a page along with its components.
+    ///
+    /// The content and embeds are always set, so that updating a message to
+    /// the page removes those of the previous page.
+    ///
+    /// Returns `None` if there is no page at the index.
+    pub fn render(&self, index: usize) -> Option<InteractionResponseData> {
+        let page = self.pages.get(index)?;
+
+        Some(InteractionResponseData {
+            components: self.components(index),
+            content: Some(page.content.clone().unwrap_or_default()),
+            embeds: Some(page.embeds.clone()),
+            ..InteractionResponseData::default()
+        })
+    }
+
+    /// Create a button leading to a page.
+    fn button(&self, direction: &str, label: &str, index: usize, disabled: bool) -> Component {
+        Component::Button(Button {
+            custom_id: Some(format!("{}:{direction}:{index}", self.id)),
+            disabled,
+            emoji: None,
+            label: Some(label.to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+            sku_id: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Page, Paginator};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        application::interaction::message_component::MessageComponentInteractionData,
+        channel::message::{
+            component::{Button, ComponentType},
+            Component,
+        },
+        http::interaction::InteractionResponseType,
+    };
+
+    assert_impl_all!(Page: Clone, Debug, Default, PartialEq, Send, Sync);
+    assert_impl_all!(Paginator: Clone, Debug, PartialEq, Send, Sync);
+
+    fn paginator() -> Paginator {
+        Paginator::new(
+            "list",
+            vec![Page::content("1"), Page::content("2"), Page::content("3")],
+        )
+        .unwrap()
+    }
+
+    fn buttons(components: &[Component]) -> Vec<Button> {
+        let Component::ActionRow(row) = &components[0] else {
+            panic!("expected an action row");
+        };
+
+        row.components
+            .iter()
+            .map(|component| match component {
+                Component::Button(button) => button.clone(),
+                _ => panic!("expected a button"),
+            })
+            .collect()
+    }
+
+    fn click(custom_id: &str) -> MessageComponentInteractionData {
+        MessageComponentInteractionData {
+            custom_id: custom_id.to_owned(),
+            component_type: ComponentType::Button,
+            resolved: None,
+            values: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Paginator::new("list", Vec::new()).is_none());
+    }
+
+    #[test]
+    fn components() {
+        let paginator = paginator().previous_label("<").next_label(">");
+
+        let first = buttons(&paginator.components(0).unwrap());
+        assert_eq!(Some("list:previous:0"), first[0].custom_id.as_deref());
+        assert_eq!(Some("<"), first[0].label.as_deref());
+        assert!(first[0].disabled);
+        assert_eq!(Some("list:next:1"), first[1].custom_id.as_deref());
+        assert_eq!(Some(">"), first[1].label.as_deref());
+        assert!(!first[1].disabled);
+
+        let last = buttons(&paginator.components(2).unwrap());
+        assert_eq!(Some("list:previous:1"), last[0].custom_id.as_deref());
+        assert!(!last[0].disabled);
+        assert_eq!(Some("list:next:2"), last[1].custom_id.as_deref());
+        assert!(last[1].disabled);
+
+        assert!(paginator.components(3).is_none());
+    }
+
+    #[test]
+    fn single_page() {
+        let paginator = Paginator::new("list", vec![Page::content("1")]).unwrap();
+        let buttons = buttons(&paginator.components(0).unwrap());
+
+        assert!(buttons.iter().all(|button| button.disabled));
+        assert_ne!(buttons[0].custom_id, buttons[1].custom_id);
+    }
+
+    #[test]
+    fn apply() {
+        let paginator = paginator();
+
+        let response = paginator.apply(&click("list:next:1")).unwrap();
+        assert_eq!(InteractionResponseType::UpdateMessage, response.kind);
+        let data = response.data.unwrap();
+        assert_eq!(Some("2"), data.content.as_deref());
+        assert_eq!(Some(Vec::new()), data.embeds);
+
+        let response = paginator.apply(&click("list:previous:0")).unwrap();
+        assert_eq!(Some("1"), response.data.unwrap().content.as_deref());
+
+        assert!(paginator.apply(&click("list:next:3")).is_none());
+        assert!(paginator.apply(&click("list:other:1")).is_none());
+        assert!(paginator.apply(&click("lists:next:1")).is_none());
+        assert!(paginator.apply(&click("other")).is_none());
+    }
+
+    #[test]
+    fn render_clears_content() {
+        let paginator = Paginator::new("list", vec![Page::default()]).unwrap();
+        let data = paginator.render(0).unwrap();
+
+        assert_eq!(Some(""), data.content.as_deref());
+        assert!(paginator.render(1).is_none());
+    }
+}
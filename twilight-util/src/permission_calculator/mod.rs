@@ -99,13 +99,14 @@ use self::preset::{
     PERMISSIONS_VOICE_OMIT,
 };
 use twilight_model::{
+    application::command::permissions::{CommandPermission, CommandPermissionType},
     channel::{
         permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
         ChannelType,
     },
     guild::Permissions,
     id::{
-        marker::{GuildMarker, RoleMarker, UserMarker},
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
         Id,
     },
 };
@@ -128,8 +129,9 @@ pub struct PermissionCalculator<'a> {
     member_roles: &'a [(Id<RoleMarker>, Permissions)],
     /// ID of the owner.
     owner_id: Option<Id<UserMarker>>,
-    /// ID of the user whose permissions are being calculated.
-    user_id: Id<UserMarker>,
+    /// ID of the user whose permissions are being calculated, if they're
+    /// calculated for a member rather than a set of roles.
+    user_id: Option<Id<UserMarker>>,
 }
 
 impl<'a> PermissionCalculator<'a> {
@@ -152,7 +154,66 @@ impl<'a> PermissionCalculator<'a> {
             guild_id,
             owner_id: None,
             member_roles,
-            user_id,
+            user_id: Some(user_id),
+        }
+    }
+
+    /// Create a calculator to calculate the permissions of a set of roles,
+    /// without a member.
+    ///
+    /// This is useful to preview what a configuration of roles would allow,
+    /// such as in dashboards. As there is no member, permission overwrites of
+    /// members and command permissions of users are ignored.
+    ///
+    /// `everyone_role` is the permissions of the `@everyone` role on a
+    /// guild-level; the permissions may be empty. The `@everyone` role's ID is
+    /// the same as that of the `guild_id`.
+    ///
+    /// The provided roles *should not* contain the `@everyone` role.
+    ///
+    /// # Examples
+    ///
+    /// Calculate the permissions of a role in a channel where the role is
+    /// denied sending messages:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     channel::{
+    ///         permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    ///         ChannelType,
+    ///     },
+    ///     guild::Permissions,
+    ///     id::Id,
+    /// };
+    /// use twilight_util::permission_calculator::PermissionCalculator;
+    ///
+    /// let roles = &[(Id::new(2), Permissions::SEND_MESSAGES)];
+    /// let overwrites = &[PermissionOverwrite {
+    ///     allow: Permissions::empty(),
+    ///     deny: Permissions::SEND_MESSAGES,
+    ///     id: Id::new(2),
+    ///     kind: PermissionOverwriteType::Role,
+    /// }];
+    ///
+    /// let calculator = PermissionCalculator::from_roles(Id::new(1), Permissions::VIEW_CHANNEL, roles);
+    ///
+    /// assert_eq!(
+    ///     Permissions::VIEW_CHANNEL,
+    ///     calculator.in_channel(ChannelType::GuildText, overwrites),
+    /// );
+    /// ```
+    #[must_use = "calculators should be used to calculate permissions"]
+    pub const fn from_roles(
+        guild_id: Id<GuildMarker>,
+        everyone_role: Permissions,
+        roles: &'a [(Id<RoleMarker>, Permissions)],
+    ) -> Self {
+        Self {
+            everyone_role,
+            guild_id,
+            owner_id: None,
+            member_roles: roles,
+            user_id: None,
         }
     }
 
@@ -178,7 +239,10 @@ impl<'a> PermissionCalculator<'a> {
     pub const fn root(&self) -> Permissions {
         // If the user is the owner, then we can just return all of the
         // permissions.
-        if matches!(self.owner_id, Some(id) if id.get() == self.user_id.get()) {
+        if matches!(
+            (self.owner_id, self.user_id),
+            (Some(owner_id), Some(user_id)) if owner_id.get() == user_id.get()
+        ) {
             return Permissions::all();
         }
 
@@ -398,6 +462,154 @@ impl<'a> PermissionCalculator<'a> {
 
         permissions
     }
+
+    /// Calculate whether the member can use an application command in a
+    /// channel.
+    ///
+    /// `command_permissions` are the permissions of the command, whereas
+    /// `application_permissions` are the permissions of the application, which
+    /// have the ID of the application in place of a command ID and are used
+    /// when the command has no permission for a channel or the member. Either
+    /// may be empty if there are none.
+    ///
+    /// When no permission applies to the member or their roles, the member
+    /// needs the command's `default_member_permissions`, if there are any.
+    /// Empty default member permissions disable the command for everyone but
+    /// administrators.
+    ///
+    /// The guild owner and administrators can always use commands.
+    ///
+    /// **Note** that this doesn't check whether the member has the
+    /// [Use Slash Commands] permission in the channel; use [`in_channel`] for
+    /// that.
+    ///
+    /// # Precedence
+    ///
+    /// The channel must not be denied, in order of precedence by:
+    ///
+    /// 1. the command's permission for the channel;
+    /// 2. the command's permission for all channels, whose ID is one less than
+    ///    the guild's ID;
+    /// 3. the application's permission for the channel; and
+    /// 4. the application's permission for all channels.
+    ///
+    /// Then the member must be permitted, with the command's permissions taking
+    /// precedence over the application's, by:
+    ///
+    /// 1. the permission for the member;
+    /// 2. the permissions for the member's roles, which permit the member if
+    ///    any of them is allowed; and
+    /// 3. the permission for the `@everyone` role, whose ID is the guild's ID.
+    ///
+    /// # Examples
+    ///
+    /// Check that a member can use a command only allowed for one of their
+    /// roles:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     application::command::permissions::{CommandPermission, CommandPermissionType},
+    ///     guild::Permissions,
+    ///     id::Id,
+    /// };
+    /// use twilight_util::permission_calculator::PermissionCalculator;
+    ///
+    /// let guild_id = Id::new(1);
+    /// let member_roles = &[(Id::new(3), Permissions::empty())];
+    /// let command_permissions = &[
+    ///     CommandPermission {
+    ///         id: CommandPermissionType::Role(guild_id.cast()),
+    ///         permission: false,
+    ///     },
+    ///     CommandPermission {
+    ///         id: CommandPermissionType::Role(Id::new(3)),
+    ///         permission: true,
+    ///     },
+    /// ];
+    ///
+    /// let calculator =
+    ///     PermissionCalculator::new(guild_id, Id::new(2), Permissions::empty(), member_roles);
+    ///
+    /// assert!(calculator.can_use_command(Id::new(4), command_permissions, &[], None));
+    /// ```
+    ///
+    /// [`in_channel`]: Self::in_channel
+    /// [Use Slash Commands]: twilight_model::guild::Permissions::USE_SLASH_COMMANDS
+    #[must_use = "calculating permissions is only useful if they're used"]
+    pub fn can_use_command(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        command_permissions: &[CommandPermission],
+        application_permissions: &[CommandPermission],
+        default_member_permissions: Option<Permissions>,
+    ) -> bool {
+        let root = self.root();
+
+        if root.contains(Permissions::ADMINISTRATOR) {
+            return true;
+        }
+
+        // The ID representing all channels is one less than the guild's ID.
+        let all_channels = self.guild_id.get() - 1;
+        let channel_permitted = [
+            (command_permissions, channel_id.get()),
+            (command_permissions, all_channels),
+            (application_permissions, channel_id.get()),
+            (application_permissions, all_channels),
+        ]
+        .into_iter()
+        .find_map(|(permissions, id)| {
+            permissions
+                .iter()
+                .find_map(|permission| match permission.id {
+                    CommandPermissionType::Channel(channel_id) if channel_id.get() == id => {
+                        Some(permission.permission)
+                    }
+                    _ => None,
+                })
+        });
+
+        if channel_permitted == Some(false) {
+            return false;
+        }
+
+        if let Some(permitted) = self
+            .member_command_permitted(command_permissions)
+            .or_else(|| self.member_command_permitted(application_permissions))
+        {
+            return permitted;
+        }
+
+        default_member_permissions.map_or(true, |required| {
+            !required.is_empty() && root.contains(required)
+        })
+    }
+
+    /// Calculate whether command permissions permit the member, by the
+    /// permission for the member, then their roles, then the `@everyone` role.
+    ///
+    /// Returns [`None`] if none of the permissions apply to the member.
+    fn member_command_permitted(&self, permissions: &[CommandPermission]) -> Option<bool> {
+        let mut roles_permitted = None;
+        let mut everyone_permitted = None;
+
+        for permission in permissions {
+            match permission.id {
+                CommandPermissionType::User(user_id) if Some(user_id) == self.user_id => {
+                    return Some(permission.permission);
+                }
+                CommandPermissionType::Role(role_id) if role_id == self.guild_id.cast() => {
+                    everyone_permitted = Some(permission.permission);
+                }
+                CommandPermissionType::Role(role_id) if has_role(self.member_roles, role_id) => {
+                    roles_permitted = Some(roles_permitted == Some(true) || permission.permission);
+                }
+                _ => {}
+            }
+        }
+
+        roles_permitted.or(everyone_permitted)
+    }
 }
 
 const fn has_role(roles: &[(Id<RoleMarker>, Permissions)], role_id: Id<RoleMarker>) -> bool {
@@ -422,7 +634,7 @@ const fn process_permission_overwrites(
     channel_overwrites: &[PermissionOverwrite],
     member_roles: &[(Id<RoleMarker>, Permissions)],
     configured_guild_id: Id<GuildMarker>,
-    configured_user_id: Id<UserMarker>,
+    configured_user_id: Option<Id<UserMarker>>,
 ) -> Permissions {
     // Hierarchy documentation:
     // <https://discord.com/developers/docs/topics/permissions>
@@ -461,7 +673,7 @@ const fn process_permission_overwrites(
                 roles_deny = bitops::insert(roles_deny, overwrite.deny);
             }
             PermissionOverwriteType::Member => {
-                if overwrite.id.get() == configured_user_id.get() {
+                if matches!(configured_user_id, Some(id) if id.get() == overwrite.id.get()) {
                     member_allow = bitops::insert(member_allow, overwrite.allow);
                     member_deny = bitops::insert(member_deny, overwrite.deny);
                 }
@@ -519,6 +731,7 @@ mod tests {
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
     use twilight_model::{
+        application::command::permissions::{CommandPermission, CommandPermissionType},
         channel::{
             permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
             ChannelType,
@@ -701,4 +914,125 @@ mod tests {
             assert!(!calculated.intersects(PERMISSIONS_ROOT_ONLY));
         }
     }
+
+    fn channel(id: u64, permission: bool) -> CommandPermission {
+        CommandPermission {
+            id: CommandPermissionType::Channel(Id::new(id)),
+            permission,
+        }
+    }
+
+    fn role(id: u64, permission: bool) -> CommandPermission {
+        CommandPermission {
+            id: CommandPermissionType::Role(Id::new(id)),
+            permission,
+        }
+    }
+
+    fn user(id: u64, permission: bool) -> CommandPermission {
+        CommandPermission {
+            id: CommandPermissionType::User(Id::new(id)),
+            permission,
+        }
+    }
+
+    /// Test that channel permissions of the command take precedence over those
+    /// of the application, and specific channels over all channels.
+    #[test]
+    fn command_channel_precedence() {
+        let calc = PermissionCalculator::new(Id::new(10), Id::new(2), Permissions::empty(), &[]);
+
+        assert!(calc.can_use_command(Id::new(3), &[], &[], None));
+        assert!(!calc.can_use_command(Id::new(3), &[channel(9, false)], &[], None));
+        assert!(calc.can_use_command(
+            Id::new(3),
+            &[channel(9, false), channel(3, true)],
+            &[],
+            None
+        ));
+        assert!(!calc.can_use_command(Id::new(3), &[channel(9, false)], &[channel(3, true)], None));
+        assert!(calc.can_use_command(Id::new(3), &[channel(3, true)], &[channel(9, false)], None));
+        assert!(!calc.can_use_command(Id::new(3), &[], &[channel(9, false)], None));
+    }
+
+    /// Test that member permissions take precedence over role permissions,
+    /// which take precedence over the permission of the `@everyone` role.
+    #[test]
+    fn command_member_precedence() {
+        let member_roles = &[
+            (Id::new(3), Permissions::empty()),
+            (Id::new(4), Permissions::empty()),
+        ];
+        let calc =
+            PermissionCalculator::new(Id::new(1), Id::new(2), Permissions::empty(), member_roles);
+
+        assert!(calc.can_use_command(Id::new(5), &[role(1, true)], &[], None));
+        assert!(!calc.can_use_command(Id::new(5), &[role(1, true), role(3, false)], &[], None));
+        assert!(calc.can_use_command(Id::new(5), &[role(4, true), role(3, false)], &[], None));
+        assert!(!calc.can_use_command(
+            Id::new(5),
+            &[role(1, true), role(4, true), user(2, false)],
+            &[],
+            None
+        ));
+        assert!(calc.can_use_command(Id::new(5), &[role(1, true)], &[user(2, false)], None));
+        assert!(!calc.can_use_command(Id::new(5), &[], &[user(2, false)], None));
+    }
+
+    /// Test that the default member permissions are required when no command
+    /// permission applies to the member, unless they're an administrator.
+    #[test]
+    fn command_default_member_permissions() {
+        let member_roles = &[(Id::new(3), Permissions::BAN_MEMBERS)];
+        let calc =
+            PermissionCalculator::new(Id::new(1), Id::new(2), Permissions::empty(), member_roles);
+
+        assert!(calc.can_use_command(
+            Id::new(5),
+            &[user(4, false)],
+            &[],
+            Some(Permissions::BAN_MEMBERS)
+        ));
+        assert!(!calc.can_use_command(Id::new(5), &[], &[], Some(Permissions::KICK_MEMBERS)));
+        assert!(!calc.can_use_command(Id::new(5), &[], &[], Some(Permissions::empty())));
+
+        let admin_roles = &[(Id::new(3), Permissions::ADMINISTRATOR)];
+        let admin =
+            PermissionCalculator::new(Id::new(1), Id::new(2), Permissions::empty(), admin_roles);
+
+        assert!(admin.can_use_command(
+            Id::new(5),
+            &[role(1, false)],
+            &[],
+            Some(Permissions::empty())
+        ));
+    }
+
+    /// Test that a calculator for a set of roles ignores member overwrites.
+    #[test]
+    fn from_roles() {
+        let roles = &[(Id::new(3), Permissions::SEND_MESSAGES)];
+        let overwrites = &[
+            PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::VIEW_CHANNEL,
+                id: Id::new(2),
+                kind: PermissionOverwriteType::Member,
+            },
+            PermissionOverwrite {
+                allow: Permissions::ADD_REACTIONS,
+                deny: Permissions::empty(),
+                id: Id::new(3),
+                kind: PermissionOverwriteType::Role,
+            },
+        ];
+
+        let calc = PermissionCalculator::from_roles(Id::new(1), Permissions::VIEW_CHANNEL, roles);
+
+        assert_eq!(
+            Permissions::ADD_REACTIONS | Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            calc.clone().in_channel(ChannelType::GuildText, overwrites),
+        );
+        assert!(!calc.owner_id(Id::new(2)).root().is_all());
+    }
 }
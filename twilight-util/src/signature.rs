@@ -0,0 +1,330 @@
+//! Verification of the signatures of interactions received via HTTP.
+//!
+//! Discord signs every interaction sent to an application's interactions
+//! endpoint URL with the application's Ed25519 key. Requests failing
+//! verification must be answered with a `401 Unauthorized` status code;
+//! Discord regularly sends requests with invalid signatures to check that
+//! verification is performed.
+//!
+//! Refer to [Discord Docs/Security and Authorization] for more information.
+//!
+//! # Examples
+//!
+//! Verify a request and deserialize its interaction:
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_util::signature::Verifier;
+//!
+//! # let (signature, timestamp, body) = ("", "", b"");
+//! let verifier = Verifier::new("PUBLIC_KEY")?;
+//!
+//! // Values of the `X-Signature-Ed25519` and `X-Signature-Timestamp` headers
+//! // and the raw body of the request.
+//! let interaction = verifier.verify_interaction(signature, timestamp, body)?;
+//!
+//! println!("received interaction {}", interaction.id);
+//! # Ok(()) }
+//! ```
+//!
+//! [Discord Docs/Security and Authorization]: https://discord.com/developers/docs/interactions/overview#setting-up-an-endpoint-validating-security-request-headers
+
+use ed25519_dalek::{Signature, VerifyingKey, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+use twilight_model::application::interaction::Interaction;
+
+/// Name of the header containing the hex encoded signature.
+pub const SIGNATURE_HEADER: &str = "x-signature-ed25519";
+
+/// Name of the header containing the signed timestamp.
+pub const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Verifying a request failed.
+#[derive(Debug)]
+pub struct SignatureError {
+    kind: SignatureErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SignatureError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SignatureErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (SignatureErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for SignatureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            SignatureErrorType::Deserializing => {
+                f.write_str("verified body isn't a valid interaction")
+            }
+            SignatureErrorType::PublicKeyInvalid => {
+                f.write_str("public key isn't a valid hex encoded ed25519 key")
+            }
+            SignatureErrorType::SignatureInvalid => {
+                f.write_str("signature isn't a valid hex encoded ed25519 signature")
+            }
+            SignatureErrorType::Unverified => {
+                f.write_str("signature doesn't match the timestamp and body")
+            }
+        }
+    }
+}
+
+impl Error for SignatureError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`SignatureError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SignatureErrorType {
+    /// Body was verified but isn't a valid interaction.
+    ///
+    /// The request should be answered with a `400 Bad Request` status code.
+    Deserializing,
+    /// Public key isn't a valid hex encoded Ed25519 public key.
+    PublicKeyInvalid,
+    /// Signature isn't a valid hex encoded Ed25519 signature.
+    ///
+    /// The request should be answered with a `401 Unauthorized` status code.
+    SignatureInvalid,
+    /// Signature doesn't match the timestamp and body.
+    ///
+    /// The request should be answered with a `401 Unauthorized` status code.
+    Unverified,
+}
+
+/// Verifier of requests signed with an application's key.
+#[derive(Clone)]
+pub struct Verifier {
+    key: VerifyingKey,
+}
+
+impl Verifier {
+    /// Create a verifier from the hex encoded public key of the application,
+    /// as shown in the developer portal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`PublicKeyInvalid`] if the key isn't a valid
+    /// hex encoded Ed25519 public key.
+    ///
+    /// [`PublicKeyInvalid`]: SignatureErrorType::PublicKeyInvalid
+    pub fn new(public_key: &str) -> Result<Self, SignatureError> {
+        let bytes = decode_hex::<PUBLIC_KEY_LENGTH>(public_key).ok_or(SignatureError {
+            kind: SignatureErrorType::PublicKeyInvalid,
+            source: None,
+        })?;
+
+        let key = VerifyingKey::from_bytes(&bytes).map_err(|source| SignatureError {
+            kind: SignatureErrorType::PublicKeyInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+        Ok(Self { key })
+    }
+
+    /// Create a verifier from a public key.
+    pub const fn from_key(key: VerifyingKey) -> Self {
+        Self { key }
+    }
+
+    /// Verify that the body was signed with the application's key.
+    ///
+    /// `signature` and `timestamp` are the values of the [`SIGNATURE_HEADER`]
+    /// and [`TIMESTAMP_HEADER`] headers; `body` is the raw body of the
+    /// request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`SignatureInvalid`] if the signature isn't a
+    /// valid hex encoded Ed25519 signature.
+    ///
+    /// Returns an error of type [`Unverified`] if the signature doesn't match.
+    ///
+    /// [`SignatureInvalid`]: SignatureErrorType::SignatureInvalid
+    /// [`Unverified`]: SignatureErrorType::Unverified
+    pub fn verify(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        body: &[u8],
+    ) -> Result<(), SignatureError> {
+        let bytes = decode_hex::<SIGNATURE_LENGTH>(signature).ok_or(SignatureError {
+            kind: SignatureErrorType::SignatureInvalid,
+            source: None,
+        })?;
+        let signature = Signature::from_bytes(&bytes);
+
+        let mut message = Vec::with_capacity(timestamp.len() + body.len());
+        message.extend_from_slice(timestamp.as_bytes());
+        message.extend_from_slice(body);
+
+        self.key
+            .verify_strict(&message, &signature)
+            .map_err(|source| SignatureError {
+                kind: SignatureErrorType::Unverified,
+                source: Some(Box::new(source)),
+            })
+    }
+
+    /// Verify that the body was signed with the application's key and
+    /// deserialize it into an [`Interaction`].
+    ///
+    /// Refer to [`verify`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Deserializing`] if the body isn't a valid
+    /// interaction.
+    ///
+    /// Returns the errors of [`verify`] if the body couldn't be verified.
+    ///
+    /// [`Deserializing`]: SignatureErrorType::Deserializing
+    /// [`verify`]: Self::verify
+    pub fn verify_interaction(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        body: &[u8],
+    ) -> Result<Interaction, SignatureError> {
+        self.verify(signature, timestamp, body)?;
+
+        serde_json::from_slice(body).map_err(|source| SignatureError {
+            kind: SignatureErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        })
+    }
+}
+
+impl Debug for Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Verifier")
+            .field("key", &self.key.as_bytes())
+            .finish()
+    }
+}
+
+/// Decode a hex string of exactly `N` bytes.
+fn decode_hex<const N: usize>(value: &str) -> Option<[u8; N]> {
+    /// Value of a single hex digit.
+    const fn digit(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let value = value.as_bytes();
+
+    if value.len() != N * 2 {
+        return None;
+    }
+
+    let mut bytes = [0; N];
+
+    for (byte, pair) in bytes.iter_mut().zip(value.chunks_exact(2)) {
+        *byte = digit(pair[0])? << 4 | digit(pair[1])?;
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_hex, SignatureError, SignatureErrorType, Verifier};
+    use ed25519_dalek::{Signer, SigningKey};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, fmt::Write};
+    use twilight_model::application::interaction::InteractionType;
+
+    assert_impl_all!(SignatureError: Error, Send, Sync);
+    assert_impl_all!(Verifier: Clone, Debug, Send, Sync);
+
+    const BODY: &[u8] = br#"{"application_id":"2","id":"1","token":"token","type":1,"version":1}"#;
+    const TIMESTAMP: &str = "1700000000";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+
+            hex
+        })
+    }
+
+    fn sign(key: &SigningKey, timestamp: &str, body: &[u8]) -> String {
+        hex(&key.sign(&[timestamp.as_bytes(), body].concat()).to_bytes())
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(Some([0x01, 0xab, 0xFF]), decode_hex::<3>("01abFF"));
+        assert_eq!(None, decode_hex::<3>("01ab"));
+        assert_eq!(None, decode_hex::<2>("0g00"));
+    }
+
+    #[test]
+    fn verify() -> Result<(), Box<dyn Error>> {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let verifier = Verifier::new(&hex(key.verifying_key().as_bytes()))?;
+        let signature = sign(&key, TIMESTAMP, BODY);
+
+        let interaction = verifier.verify_interaction(&signature, TIMESTAMP, BODY)?;
+        assert_eq!(InteractionType::Ping, interaction.kind);
+
+        assert!(matches!(
+            verifier
+                .verify(&signature, "1700000001", BODY)
+                .unwrap_err()
+                .kind(),
+            SignatureErrorType::Unverified
+        ));
+        assert!(matches!(
+            verifier.verify("zz", TIMESTAMP, BODY).unwrap_err().kind(),
+            SignatureErrorType::SignatureInvalid
+        ));
+
+        let signature = sign(&key, TIMESTAMP, b"{}");
+        assert!(matches!(
+            verifier
+                .verify_interaction(&signature, TIMESTAMP, b"{}")
+                .unwrap_err()
+                .kind(),
+            SignatureErrorType::Deserializing
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn public_key_invalid() {
+        assert!(matches!(
+            Verifier::new("not a key").unwrap_err().kind(),
+            SignatureErrorType::PublicKeyInvalid
+        ));
+    }
+}
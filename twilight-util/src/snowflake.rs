@@ -1,5 +1,9 @@
 //! Provides the Snowflake trait for defining extractable information from a Discord Snowflake.
+//!
+//! Additionally provides functions for creating Snowflakes bounding a point or
+//! window in time, such as for filtering messages by age or paginating by time.
 
+use std::ops::RangeInclusive;
 use twilight_model::id::{
     marker::{
         ApplicationMarker, AttachmentMarker, AuditLogEntryMarker, ChannelMarker, CommandMarker,
@@ -11,6 +15,117 @@ use twilight_model::id::{
     Id,
 };
 
+/// Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Number of bits below the timestamp of a Snowflake.
+const TIMESTAMP_SHIFT: u32 = 22;
+
+/// Largest number of milliseconds since the Discord epoch that fits in a Snowflake.
+const TIMESTAMP_MAX: u64 = u64::MAX >> TIMESTAMP_SHIFT;
+
+/// Number of milliseconds since the Discord epoch of a Unix timestamp in
+/// milliseconds, if it can be represented by a Snowflake.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+const fn elapsed(timestamp: i64) -> Option<u64> {
+    if timestamp < DISCORD_EPOCH as i64 {
+        return None;
+    }
+
+    let elapsed = timestamp as u64 - DISCORD_EPOCH;
+
+    if elapsed > TIMESTAMP_MAX {
+        return None;
+    }
+
+    Some(elapsed)
+}
+
+/// Create the smallest Snowflake that could have been generated at a Unix
+/// timestamp in milliseconds.
+///
+/// Since IDs may not be zero, the Snowflake of the exact Discord epoch is 1.
+///
+/// Returns [`None`] if the timestamp is before the Discord epoch or too far in
+/// the future to be represented by a Snowflake.
+///
+/// # Examples
+///
+/// Get the ID to paginate messages sent after a point in time:
+///
+/// ```
+/// use twilight_model::id::{marker::MessageMarker, Id};
+/// use twilight_util::snowflake::{self, Snowflake};
+///
+/// let after = snowflake::min_id_at::<MessageMarker>(1_445_219_918_546).unwrap();
+///
+/// assert_eq!(Id::new(105_484_726_235_561_984), after);
+/// assert_eq!(1_445_219_918_546, after.timestamp());
+/// ```
+pub const fn min_id_at<T>(timestamp: i64) -> Option<Id<T>> {
+    let Some(elapsed) = elapsed(timestamp) else {
+        return None;
+    };
+
+    let id = elapsed << TIMESTAMP_SHIFT;
+
+    Id::new_checked(if id == 0 { 1 } else { id })
+}
+
+/// Create the largest Snowflake that could have been generated at a Unix
+/// timestamp in milliseconds.
+///
+/// Returns [`None`] if the timestamp is before the Discord epoch or too far in
+/// the future to be represented by a Snowflake.
+pub const fn max_id_at<T>(timestamp: i64) -> Option<Id<T>> {
+    let Some(elapsed) = elapsed(timestamp) else {
+        return None;
+    };
+
+    Id::new_checked((elapsed << TIMESTAMP_SHIFT) | ((1 << TIMESTAMP_SHIFT) - 1))
+}
+
+/// Create the range of Snowflakes that could have been generated between two
+/// Unix timestamps in milliseconds, inclusive.
+///
+/// Returns [`None`] if either timestamp is before the Discord epoch or too far
+/// in the future to be represented by a Snowflake.
+///
+/// # Examples
+///
+/// Filter messages to those that are young enough to be bulk deleted, which is
+/// the case for messages sent within the last 14 days:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+/// use twilight_model::id::{marker::MessageMarker, Id};
+/// use twilight_util::snowflake;
+///
+/// const FOURTEEN_DAYS: Duration = Duration::from_secs(60 * 60 * 24 * 14);
+///
+/// let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+/// let start = (now - FOURTEEN_DAYS).as_millis().try_into()?;
+/// let end = now.as_millis().try_into()?;
+/// let range = snowflake::id_range::<MessageMarker>(start, end).unwrap();
+///
+/// let messages = [Id::new(105_484_726_235_607_040)];
+/// let deletable = messages.into_iter().filter(|id| range.contains(id));
+///
+/// assert_eq!(0, deletable.count());
+/// # Ok(()) }
+/// ```
+pub const fn id_range<T>(start: i64, end: i64) -> Option<RangeInclusive<Id<T>>> {
+    let Some(start) = min_id_at(start) else {
+        return None;
+    };
+    let Some(end) = max_id_at(end) else {
+        return None;
+    };
+
+    Some(RangeInclusive::new(start, end))
+}
+
 /// Snowflake is a trait for defining extractable information from a Snowflake. A Snowflake is a
 /// u64 generated by Discord to uniquely identify a resource.
 pub trait Snowflake {
@@ -59,10 +174,7 @@ pub trait Snowflake {
     /// ```
     #[allow(clippy::cast_possible_wrap)]
     fn timestamp(&self) -> i64 {
-        // Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
-        const DISCORD_EPOCH: u64 = 1_420_070_400_000;
-
-        ((self.id() >> 22) + DISCORD_EPOCH) as i64
+        ((self.id() >> TIMESTAMP_SHIFT) + DISCORD_EPOCH) as i64
     }
 
     /// The id of the internal worker that generated the Snowflake.
@@ -274,6 +386,42 @@ mod tests {
         assert_eq!(expected, id.timestamp());
     }
 
+    #[test]
+    fn min_and_max_id_at() {
+        let id = Id::<GenericMarker>::new(105_484_726_235_607_040);
+        let min = min_id_at::<GenericMarker>(id.timestamp()).unwrap();
+        let max = max_id_at::<GenericMarker>(id.timestamp()).unwrap();
+
+        assert!(min <= id && id <= max);
+        assert_eq!(id.timestamp(), min.timestamp());
+        assert_eq!(id.timestamp(), max.timestamp());
+        assert_eq!(
+            id.timestamp() + 1,
+            Id::<GenericMarker>::new(max.get() + 1).timestamp()
+        );
+    }
+
+    #[test]
+    fn id_at_bounds() {
+        let epoch = 1_420_070_400_000;
+
+        assert_eq!(Some(Id::<GenericMarker>::new(1)), min_id_at(epoch));
+        assert!(min_id_at::<GenericMarker>(epoch - 1).is_none());
+        assert!(max_id_at::<GenericMarker>(epoch - 1).is_none());
+        assert!(max_id_at::<GenericMarker>(i64::MAX).is_none());
+    }
+
+    #[test]
+    fn id_range_contains() {
+        let id = Id::<GenericMarker>::new(105_484_726_235_607_040);
+        let range = id_range(id.timestamp() - 1000, id.timestamp()).unwrap();
+
+        assert!(range.contains(&id));
+        assert!(!id_range(id.timestamp() + 1, id.timestamp() + 1000)
+            .unwrap()
+            .contains(&id));
+    }
+
     #[test]
     fn worker_id() {
         let expected: u8 = 8;
@@ -0,0 +1,368 @@
+//! Split long text into message contents or embeds within Discord's length
+//! limits.
+//!
+//! Text is preferably split at line breaks, then at whitespace, and only
+//! within words if a word doesn't fit on its own. The line break or whitespace
+//! a chunk is split at is removed.
+
+use twilight_model::channel::message::{embed::EmbedField, Embed};
+use twilight_validate::{
+    embed::{
+        DESCRIPTION_LENGTH, EMBED_TOTAL_LENGTH, FIELD_COUNT, FIELD_VALUE_LENGTH, TITLE_LENGTH,
+    },
+    message::{EMBED_COUNT_LIMIT, MESSAGE_CONTENT_LENGTH_MAX},
+};
+
+/// Name of fields continuing the text, a zero width space since field names
+/// may not be empty.
+const FIELD_NAME: &str = "\u{200B}";
+
+/// Split text into the contents of messages.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::split;
+///
+/// let log = "line\n".repeat(1000);
+/// let contents = split::content(&log);
+///
+/// assert_eq!(3, contents.len());
+/// assert!(contents.iter().all(|content| content.chars().count() <= 2000));
+/// ```
+pub fn content(text: &str) -> Vec<String> {
+    let mut contents = Vec::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let (chunk, tail) = take(rest, MESSAGE_CONTENT_LENGTH_MAX, usize::MAX, true);
+        contents.push(chunk.to_owned());
+        rest = tail;
+    }
+
+    contents
+}
+
+/// Split text into the embeds of messages.
+///
+/// Each embed holds as much text in its description as possible, continuing
+/// in fields if [enabled], and each message holds as many embeds as possible.
+/// The number of embeds of a message and the combined length of their text
+/// stays within the limits of a message.
+///
+/// Lengths are counted in bytes, which is more conservative than Discord
+/// counting characters, for embeds to also pass [`twilight_validate`].
+///
+/// # Examples
+///
+/// Send a long log dump in as few messages as possible:
+///
+/// ```
+/// use twilight_util::split::EmbedSplitter;
+///
+/// let log = "2024-01-01 something happened\n".repeat(1000);
+/// let messages = EmbedSplitter::new()
+///     .color(0xff_00_00)
+///     .fields(true)
+///     .title("Log")
+///     .split(&log);
+///
+/// assert_eq!(6, messages.len());
+/// assert_eq!(Some("Log"), messages[0][0].title.as_deref());
+///
+/// for embeds in &messages {
+///     assert!(twilight_validate::message::embeds(embeds).is_ok());
+/// }
+/// ```
+///
+/// [enabled]: Self::fields
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "has no effect unless text is split"]
+pub struct EmbedSplitter {
+    /// Color of the embeds.
+    color: Option<u32>,
+    /// Whether to continue text in fields once the description is full.
+    fields: bool,
+    /// Title of the first embed.
+    title: Option<String>,
+}
+
+impl EmbedSplitter {
+    /// Create a new splitter of text into embeds without a color or title.
+    pub const fn new() -> Self {
+        Self {
+            color: None,
+            fields: false,
+            title: None,
+        }
+    }
+
+    /// Set the color of the embeds.
+    pub const fn color(mut self, color: u32) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    /// Set whether to continue text in fields once the description of an
+    /// embed is full.
+    ///
+    /// Fitting more text into an embed results in fewer embeds, although text
+    /// in fields is displayed in a smaller font by some clients.
+    ///
+    /// Defaults to `false`.
+    pub const fn fields(mut self, fields: bool) -> Self {
+        self.fields = fields;
+
+        self
+    }
+
+    /// Set the title of the first embed.
+    ///
+    /// Titles longer than [`TITLE_LENGTH`] characters are truncated.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        let mut title = title.into();
+
+        if let Some((index, _)) = title.char_indices().nth(TITLE_LENGTH) {
+            title.truncate(index);
+        }
+
+        self.title = Some(title);
+
+        self
+    }
+
+    /// Split text into the embeds of messages.
+    ///
+    /// Returns no messages if the text is empty.
+    pub fn split(&self, text: &str) -> Vec<Vec<Embed>> {
+        let mut messages = Vec::new();
+        let mut embeds = Vec::new();
+        let mut remaining = EMBED_TOTAL_LENGTH;
+        let mut rest = text;
+        let mut title = self.title.clone();
+
+        while !rest.is_empty() {
+            let title_len = title.as_ref().map_or(0, String::len);
+            // Words are only split in embeds of a message without other embeds,
+            // so that they are rather moved to a new message.
+            let split_words = embeds.is_empty();
+            let (description, tail) =
+                take(rest, DESCRIPTION_LENGTH, remaining - title_len, split_words);
+
+            // Start a new message if this one is full.
+            if description.is_empty() || embeds.len() == EMBED_COUNT_LIMIT {
+                messages.push(std::mem::take(&mut embeds));
+                remaining = EMBED_TOTAL_LENGTH;
+
+                continue;
+            }
+
+            remaining -= title_len + description.len();
+            rest = tail;
+
+            let mut embed = Embed {
+                author: None,
+                color: self.color,
+                description: Some(description.to_owned()),
+                fields: Vec::new(),
+                footer: None,
+                image: None,
+                kind: "rich".to_owned(),
+                provider: None,
+                thumbnail: None,
+                timestamp: None,
+                title: title.take(),
+                url: None,
+                video: None,
+            };
+
+            while self.fields && !rest.is_empty() && embed.fields.len() < FIELD_COUNT {
+                let budget = remaining.saturating_sub(FIELD_NAME.len());
+                let (value, tail) = take(rest, FIELD_VALUE_LENGTH, budget, false);
+
+                if value.is_empty() {
+                    break;
+                }
+
+                remaining -= FIELD_NAME.len() + value.len();
+                rest = tail;
+
+                embed.fields.push(EmbedField {
+                    inline: false,
+                    name: FIELD_NAME.to_owned(),
+                    value: value.to_owned(),
+                });
+            }
+
+            embeds.push(embed);
+        }
+
+        if !embeds.is_empty() {
+            messages.push(embeds);
+        }
+
+        messages
+    }
+}
+
+/// Split off the longest prefix of at most `max_chars` characters and
+/// `max_bytes` bytes, preferably at a line break, or else at whitespace.
+///
+/// Words longer than `max_chars` are always split, whereas other words are
+/// only split if `split_words` is set.
+///
+/// Returns the prefix and the remaining text, without the line break or
+/// whitespace split at. The prefix is empty if nothing fits.
+fn take(text: &str, max_chars: usize, max_bytes: usize, split_words: bool) -> (&str, &str) {
+    let Some((count, (end, next))) =
+        text.char_indices()
+            .enumerate()
+            .find(|(count, (index, character))| {
+                *count == max_chars || index + character.len_utf8() > max_bytes
+            })
+    else {
+        return (text, "");
+    };
+
+    // Include the first character not fitting, which may be split at itself.
+    let window = &text[..end + next.len_utf8()];
+    let separator = window
+        .rfind('\n')
+        .or_else(|| window.rfind(char::is_whitespace))
+        .filter(|index| *index > 0);
+
+    match separator {
+        Some(index) => {
+            let separator_len = text[index..].chars().next().map_or(0, char::len_utf8);
+
+            (&text[..index], &text[index + separator_len..])
+        }
+        None if split_words || count == max_chars => text.split_at(end),
+        None => ("", text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content, take, EmbedSplitter, FIELD_NAME};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_validate::{
+        embed::{embed as validate_embed, TITLE_LENGTH},
+        message::embeds as validate_embeds,
+    };
+
+    assert_impl_all!(EmbedSplitter: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn take_prefers_line_breaks() {
+        assert_eq!(
+            ("one two", "three"),
+            take("one two\nthree", 10, usize::MAX, true)
+        );
+        assert_eq!(
+            ("one two", "three"),
+            take("one two three", 10, usize::MAX, true)
+        );
+        assert_eq!(("one", "two"), take("one\ntwo", 3, usize::MAX, true));
+        assert_eq!(("abcde", "fgh"), take("abcdefgh", 5, usize::MAX, true));
+        assert_eq!(("short", ""), take("short", 10, usize::MAX, true));
+        assert_eq!(("\u{e9}", "\u{e9}"), take("\u{e9}\u{e9}", 10, 3, true));
+        assert_eq!(("", "\u{1F600}"), take("\u{1F600}", 10, 3, true));
+        assert_eq!(("one", "two three"), take("one two three", 10, 6, false));
+        assert_eq!(("", "three"), take("three", 10, 3, false));
+        assert_eq!(("thr", "ee"), take("three", 3, 10, false));
+    }
+
+    #[test]
+    fn split_content() {
+        assert!(content("").is_empty());
+        assert_eq!(vec!["text"], content("text"));
+
+        let word = "a".repeat(4500);
+        let contents = content(&word);
+        assert_eq!(
+            vec![2000, 2000, 500],
+            contents.iter().map(String::len).collect::<Vec<_>>()
+        );
+        assert_eq!(word, contents.concat());
+
+        let lines = vec!["line"; 1000].join("\n");
+        let contents = content(&lines);
+        assert!(contents.iter().all(|content| content.len() <= 2000));
+        assert_eq!(lines, contents.join("\n"));
+    }
+
+    #[test]
+    fn split_embeds() {
+        let text = vec!["words of a log line"; 2000].join("\n");
+        let messages = EmbedSplitter::new().title("title").split(&text);
+
+        for embeds in &messages {
+            assert!(validate_embeds(embeds).is_ok());
+
+            for embed in embeds {
+                assert!(validate_embed(embed).is_ok());
+                assert!(embed.fields.is_empty());
+            }
+        }
+
+        // Descriptions may also be split at spaces once a message is nearly
+        // full.
+        let words = messages
+            .iter()
+            .flatten()
+            .filter_map(|embed| embed.description.as_deref())
+            .flat_map(str::split_whitespace)
+            .collect::<Vec<_>>();
+        assert_eq!(text.split_whitespace().collect::<Vec<_>>(), words);
+        assert_eq!(Some("title"), messages[0][0].title.as_deref());
+        assert!(messages
+            .iter()
+            .flatten()
+            .skip(1)
+            .all(|embed| embed.title.is_none()));
+    }
+
+    #[test]
+    fn split_embeds_fields() {
+        let text = "a".repeat(30_000) + &"\u{e9}".repeat(10_000);
+        let messages = EmbedSplitter::new().color(1).fields(true).split(&text);
+
+        let mut chunks = Vec::new();
+        for embeds in &messages {
+            assert!(validate_embeds(embeds).is_ok());
+
+            for embed in embeds {
+                assert!(validate_embed(embed).is_ok());
+                assert_eq!(Some(1), embed.color);
+                chunks.extend(embed.description.as_deref());
+                chunks.extend(embed.fields.iter().map(|field| {
+                    assert_eq!(FIELD_NAME, field.name);
+
+                    field.value.as_str()
+                }));
+            }
+        }
+
+        assert!(messages
+            .iter()
+            .flatten()
+            .any(|embed| !embed.fields.is_empty()));
+        assert_eq!(text, chunks.concat());
+    }
+
+    #[test]
+    fn title_truncated() {
+        let splitter = EmbedSplitter::new().title("t".repeat(TITLE_LENGTH + 10));
+        let messages = splitter.split("text");
+
+        assert_eq!(1, messages.len());
+        assert_eq!(
+            Some(TITLE_LENGTH),
+            messages[0][0].title.as_ref().map(String::len)
+        );
+        assert!(EmbedSplitter::new().split("").is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+//! Error types and functions for validating [`Activity`] fields set by bots.
+
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+use twilight_model::gateway::presence::{Activity, ActivityType};
+
+/// Activity is not valid for a bot to set.
+#[derive(Debug)]
+pub struct ActivityValidationError {
+    /// Type of error that occurred.
+    kind: ActivityValidationErrorType,
+}
+
+impl ActivityValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ActivityValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ActivityValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ActivityValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ActivityValidationErrorType::FieldUnsupported { field } => {
+                f.write_str("activity field `")?;
+                f.write_str(field)?;
+
+                f.write_str("` can not be set by bots")
+            }
+            ActivityValidationErrorType::KindUnsupported { kind } => {
+                f.write_str("activity type ")?;
+                Debug::fmt(kind, f)?;
+
+                f.write_str(" can not be set by bots")
+            }
+            ActivityValidationErrorType::UrlUnsupported { kind } => {
+                f.write_str("activity has a url, but its type ")?;
+                Debug::fmt(kind, f)?;
+
+                f.write_str(" is not streaming")
+            }
+        }
+    }
+}
+
+impl Error for ActivityValidationError {}
+
+/// Type of [`ActivityValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ActivityValidationErrorType {
+    /// Field other than the name, state, type, and URL is set.
+    FieldUnsupported {
+        /// Name of the field.
+        field: &'static str,
+    },
+    /// Type of the activity is unknown.
+    KindUnsupported {
+        /// Type of the activity.
+        kind: ActivityType,
+    },
+    /// URL is set, but the type of the activity isn't
+    /// [`ActivityType::Streaming`].
+    UrlUnsupported {
+        /// Type of the activity.
+        kind: ActivityType,
+    },
+}
+
+/// Ensure that an activity can be set by a bot.
+///
+/// Bots may only set the name, state, type, and URL of activities, of which
+/// the URL may only be set for [`ActivityType::Streaming`] activities. This is
+/// based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`FieldUnsupported`] if another field is set.
+///
+/// Returns an error of type [`KindUnsupported`] if the type is
+/// [`ActivityType::Unknown`].
+///
+/// Returns an error of type [`UrlUnsupported`] if the URL is set for an
+/// activity that isn't streaming.
+///
+/// [`FieldUnsupported`]: ActivityValidationErrorType::FieldUnsupported
+/// [`KindUnsupported`]: ActivityValidationErrorType::KindUnsupported
+/// [`UrlUnsupported`]: ActivityValidationErrorType::UrlUnsupported
+/// [this documentation entry]: https://discord.com/developers/docs/topics/gateway-events#activity-object
+pub fn activity(activity: &Activity) -> Result<(), ActivityValidationError> {
+    if let ActivityType::Unknown(_) = activity.kind {
+        return Err(ActivityValidationError {
+            kind: ActivityValidationErrorType::KindUnsupported {
+                kind: activity.kind,
+            },
+        });
+    }
+
+    if activity.url.is_some() && activity.kind != ActivityType::Streaming {
+        return Err(ActivityValidationError {
+            kind: ActivityValidationErrorType::UrlUnsupported {
+                kind: activity.kind,
+            },
+        });
+    }
+
+    let fields = [
+        ("application_id", activity.application_id.is_some()),
+        ("assets", activity.assets.is_some()),
+        ("buttons", !activity.buttons.is_empty()),
+        ("created_at", activity.created_at.is_some()),
+        ("details", activity.details.is_some()),
+        ("emoji", activity.emoji.is_some()),
+        ("flags", activity.flags.is_some()),
+        ("id", activity.id.is_some()),
+        ("instance", activity.instance.is_some()),
+        ("party", activity.party.is_some()),
+        ("platform", activity.platform.is_some()),
+        ("secrets", activity.secrets.is_some()),
+        ("session_id", activity.session_id.is_some()),
+        (
+            "supported_platforms",
+            !activity.supported_platforms.is_empty(),
+        ),
+        ("sync_id", activity.sync_id.is_some()),
+        ("timestamps", activity.timestamps.is_some()),
+    ];
+
+    if let Some((field, _)) = fields.into_iter().find(|(_, set)| *set) {
+        return Err(ActivityValidationError {
+            kind: ActivityValidationErrorType::FieldUnsupported { field },
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use twilight_model::gateway::presence::MinimalActivity;
+
+    assert_impl_all!(ActivityValidationErrorType: Debug, Send, Sync);
+    assert_impl_all!(ActivityValidationError: Debug, Send, Sync);
+
+    fn minimal(kind: ActivityType, url: Option<&str>) -> Activity {
+        MinimalActivity {
+            kind,
+            name: "name".to_owned(),
+            url: url.map(ToOwned::to_owned),
+        }
+        .into()
+    }
+
+    #[test]
+    fn kind() {
+        assert!(activity(&minimal(ActivityType::Playing, None)).is_ok());
+        assert!(activity(&minimal(ActivityType::Custom, None)).is_ok());
+
+        assert!(matches!(
+            activity(&minimal(ActivityType::Unknown(7), None)),
+            Err(ActivityValidationError {
+                kind: ActivityValidationErrorType::KindUnsupported {
+                    kind: ActivityType::Unknown(7),
+                },
+            })
+        ));
+    }
+
+    #[test]
+    fn url() {
+        let url = Some("https://twitch.tv/twilight");
+        assert!(activity(&minimal(ActivityType::Streaming, url)).is_ok());
+
+        assert!(matches!(
+            activity(&minimal(ActivityType::Watching, url)),
+            Err(ActivityValidationError {
+                kind: ActivityValidationErrorType::UrlUnsupported {
+                    kind: ActivityType::Watching,
+                },
+            })
+        ));
+    }
+
+    #[test]
+    fn fields() {
+        let mut value = minimal(ActivityType::Listening, None);
+        value.state = Some("state".to_owned());
+        assert!(activity(&value).is_ok());
+
+        value.details = Some("details".to_owned());
+        assert!(matches!(
+            activity(&value),
+            Err(ActivityValidationError {
+                kind: ActivityValidationErrorType::FieldUnsupported { field: "details" },
+            })
+        ));
+    }
+}
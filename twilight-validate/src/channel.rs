@@ -4,7 +4,13 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::ChannelType;
+use twilight_model::{
+    channel::{forum::ForumTag, ChannelType},
+    id::{marker::TagMarker, Id},
+};
+
+/// Maximum number of tags that can be applied to a thread.
+pub const CHANNEL_APPLIED_TAGS_MAX: usize = 5;
 
 /// Minimum bitrate of a voice channel.
 pub const CHANNEL_BITRATE_MIN: u32 = 8000;
@@ -15,6 +21,12 @@ pub const CHANNEL_BULK_DELETE_MESSAGES_MAX: usize = 100;
 /// Minimum number of bulk messages that can be deleted.
 pub const CHANNEL_BULK_DELETE_MESSAGES_MIN: usize = 2;
 
+/// Maximum length of a forum tag's name.
+pub const CHANNEL_FORUM_TAG_NAME_LENGTH_MAX: usize = 20;
+
+/// Maximum number of tags available in a forum channel.
+pub const CHANNEL_FORUM_TAGS_MAX: usize = 20;
+
 /// Maximum length of a forum channel's topic.
 pub const CHANNEL_FORUM_TOPIC_LENGTH_MAX: usize = 4096;
 
@@ -75,6 +87,11 @@ impl ChannelValidationError {
 impl Display for ChannelValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ChannelValidationErrorType::AppliedTagsInvalid => {
+                f.write_str("number of applied tags is greater than ")?;
+
+                Display::fmt(&CHANNEL_APPLIED_TAGS_MAX, f)
+            }
             ChannelValidationErrorType::BitrateInvalid => {
                 f.write_str("bitrate is less than ")?;
                 Display::fmt(&CHANNEL_BITRATE_MIN, f)
@@ -86,6 +103,19 @@ impl Display for ChannelValidationError {
 
                 Display::fmt(&CHANNEL_BULK_DELETE_MESSAGES_MAX, f)
             }
+            ChannelValidationErrorType::ForumTagEmojiInvalid => {
+                f.write_str("the forum tag has both a custom and a unicode emoji")
+            }
+            ChannelValidationErrorType::ForumTagNameInvalid => {
+                f.write_str("the length of the forum tag's name is greater than ")?;
+
+                Display::fmt(&CHANNEL_FORUM_TAG_NAME_LENGTH_MAX, f)
+            }
+            ChannelValidationErrorType::ForumTagsInvalid => {
+                f.write_str("number of available forum tags is greater than ")?;
+
+                Display::fmt(&CHANNEL_FORUM_TAGS_MAX, f)
+            }
             ChannelValidationErrorType::ForumTopicInvalid => {
                 f.write_str("the forum topic is invalid")
             }
@@ -123,10 +153,19 @@ impl Error for ChannelValidationError {}
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ChannelValidationErrorType {
+    /// More than 5 tags are applied to a thread.
+    AppliedTagsInvalid,
     /// The bitrate is less than 8000.
     BitrateInvalid,
     /// Number of messages being deleted in bulk is invalid.
     BulkDeleteMessagesInvalid,
+    /// A forum tag has both the ID of a custom emoji and the name of a
+    /// unicode emoji.
+    ForumTagEmojiInvalid,
+    /// The length of a forum tag's name is more than 20 characters.
+    ForumTagNameInvalid,
+    /// More than 20 tags are available in a forum channel.
+    ForumTagsInvalid,
     /// The length of the topic is more than 4096 UTF-16 characters.
     ForumTopicInvalid,
     /// The length of the name is either fewer than 1 UTF-16 characters or
@@ -150,6 +189,26 @@ pub enum ChannelValidationErrorType {
     UserLimitInvalid,
 }
 
+/// Ensure the number of tags applied to a thread is correct.
+///
+/// The number must be at most [`CHANNEL_APPLIED_TAGS_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`AppliedTagsInvalid`] if too many tags are
+/// applied.
+///
+/// [`AppliedTagsInvalid`]: ChannelValidationErrorType::AppliedTagsInvalid
+pub const fn applied_tags(tags: &[Id<TagMarker>]) -> Result<(), ChannelValidationError> {
+    if tags.len() <= CHANNEL_APPLIED_TAGS_MAX {
+        Ok(())
+    } else {
+        Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::AppliedTagsInvalid,
+        })
+    }
+}
+
 /// Ensure a channel's bitrate is collect.
 ///
 /// Must be at least 8000.
@@ -208,6 +267,60 @@ pub const fn is_thread(kind: ChannelType) -> Result<(), ChannelValidationError>
     }
 }
 
+/// Ensure a forum tag is correct.
+///
+/// The length of the name must be at most
+/// [`CHANNEL_FORUM_TAG_NAME_LENGTH_MAX`], and the tag may have either a custom
+/// emoji or a unicode emoji, but not both.
+///
+/// # Errors
+///
+/// Returns an error of type [`ForumTagEmojiInvalid`] if both an emoji ID and an
+/// emoji name are set.
+///
+/// Returns an error of type [`ForumTagNameInvalid`] if the name is too long.
+///
+/// [`ForumTagEmojiInvalid`]: ChannelValidationErrorType::ForumTagEmojiInvalid
+/// [`ForumTagNameInvalid`]: ChannelValidationErrorType::ForumTagNameInvalid
+pub fn forum_tag(tag: &ForumTag) -> Result<(), ChannelValidationError> {
+    if tag.emoji_id.is_some() && tag.emoji_name.is_some() {
+        return Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::ForumTagEmojiInvalid,
+        });
+    }
+
+    if tag.name.chars().count() > CHANNEL_FORUM_TAG_NAME_LENGTH_MAX {
+        return Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::ForumTagNameInvalid,
+        });
+    }
+
+    Ok(())
+}
+
+/// Ensure the tags available in a forum channel are correct.
+///
+/// There must be at most [`CHANNEL_FORUM_TAGS_MAX`] tags, and each tag must be
+/// valid.
+///
+/// # Errors
+///
+/// Returns an error of type [`ForumTagsInvalid`] if there are too many tags.
+///
+/// Otherwise, refer to the errors section of [`forum_tag`] for a list of errors
+/// that may occur.
+///
+/// [`ForumTagsInvalid`]: ChannelValidationErrorType::ForumTagsInvalid
+pub fn forum_tags(tags: &[ForumTag]) -> Result<(), ChannelValidationError> {
+    if tags.len() > CHANNEL_FORUM_TAGS_MAX {
+        return Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::ForumTagsInvalid,
+        });
+    }
+
+    tags.iter().try_for_each(forum_tag)
+}
+
 /// Ensure a forum channel's topic's length is correct.
 ///
 /// # Errors
@@ -342,6 +455,26 @@ pub const fn user_limit(value: u16) -> Result<(), ChannelValidationError> {
 mod tests {
     use super::*;
 
+    fn tag(name: &str) -> ForumTag {
+        ForumTag {
+            emoji_id: None,
+            emoji_name: None,
+            id: Id::new(1),
+            moderated: false,
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn applied_tags_count() {
+        assert!(applied_tags(&[]).is_ok());
+        assert!(applied_tags(&[Id::new(1); 5]).is_ok());
+        assert!(matches!(
+            applied_tags(&[Id::new(1); 6]).unwrap_err().kind(),
+            ChannelValidationErrorType::AppliedTagsInvalid
+        ));
+    }
+
     #[test]
     fn bulk_delete_messages() {
         assert!(matches!(
@@ -384,6 +517,41 @@ mod tests {
         assert!(name("a".repeat(101)).is_err());
     }
 
+    #[test]
+    fn forum_tag_fields() {
+        assert!(forum_tag(&tag("")).is_ok());
+        assert!(forum_tag(&tag(&"a".repeat(20))).is_ok());
+        assert!(matches!(
+            forum_tag(&tag(&"a".repeat(21))).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumTagNameInvalid
+        ));
+
+        let mut emoji = tag("emoji");
+        emoji.emoji_name = Some("🦀".to_owned());
+        assert!(forum_tag(&emoji).is_ok());
+
+        emoji.emoji_id = Some(Id::new(2));
+        assert!(matches!(
+            forum_tag(&emoji).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumTagEmojiInvalid
+        ));
+    }
+
+    #[test]
+    fn forum_tags_count() {
+        assert!(forum_tags(&vec![tag("a"); 20]).is_ok());
+        assert!(matches!(
+            forum_tags(&vec![tag("a"); 21]).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumTagsInvalid
+        ));
+        assert!(matches!(
+            forum_tags(&[tag("a"), tag(&"a".repeat(21))])
+                .unwrap_err()
+                .kind(),
+            ChannelValidationErrorType::ForumTagNameInvalid
+        ));
+    }
+
     #[test]
     fn rate_limit_per_user_value() {
         assert!(rate_limit_per_user(0).is_ok());
@@ -1,6 +1,7 @@
 //! Constants, error types, and functions for validating [`Component`]s.
 
 use std::{
+    collections::HashSet,
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
 };
@@ -195,6 +196,16 @@ impl Display for ComponentValidationError {
 
                 Display::fmt(&ACTION_ROW_COMPONENT_COUNT, f)
             }
+            ComponentValidationErrorType::ActionRowExclusiveComponent { kind, row, index } => {
+                f.write_str("a '")?;
+                Display::fmt(kind, f)?;
+                f.write_str("' component at index ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" of action row ")?;
+                Display::fmt(row, f)?;
+
+                f.write_str(" must be the only component in its action row")
+            }
             ComponentValidationErrorType::ButtonConflict => {
                 f.write_str("button has both a custom id and url, which is never valid")
             }
@@ -231,6 +242,20 @@ impl Display for ComponentValidationError {
 
                 Display::fmt(&COMPONENT_BUTTON_LABEL_LENGTH, f)
             }
+            ComponentValidationErrorType::DuplicateCustomId {
+                custom_id,
+                row,
+                index,
+            } => {
+                f.write_str("the custom id '")?;
+                Display::fmt(custom_id, f)?;
+                f.write_str("' of the component at index ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" of action row ")?;
+                Display::fmt(row, f)?;
+
+                f.write_str(" is already used by another component")
+            }
             ComponentValidationErrorType::InvalidChildComponent { kind } => {
                 f.write_str("a '")?;
                 Display::fmt(&kind, f)?;
@@ -371,6 +396,17 @@ pub enum ComponentValidationErrorType {
         /// Number of components within the action row.
         count: usize,
     },
+    /// Component that must be the only component of an [`ActionRow`], such as
+    /// a [`SelectMenu`] or [`TextInput`], shares its action row with other
+    /// components.
+    ActionRowExclusiveComponent {
+        /// Type of the component.
+        kind: ComponentType,
+        /// Index of the action row.
+        row: usize,
+        /// Index of the component within the action row.
+        index: usize,
+    },
     /// Button has both a custom ID and URL set.
     ButtonConflict,
     /// Button does not have the required field based on its style.
@@ -398,6 +434,15 @@ pub enum ComponentValidationErrorType {
         /// Number of codepoints that were provided.
         chars: usize,
     },
+    /// Custom ID of a component is already used by a previous component.
+    DuplicateCustomId {
+        /// Custom ID that is used more than once.
+        custom_id: String,
+        /// Index of the action row of the component.
+        row: usize,
+        /// Index of the component within the action row.
+        index: usize,
+    },
     /// Provided component cannot be a child component.
     InvalidChildComponent {
         /// Type of provided component.
@@ -503,6 +548,45 @@ pub enum ComponentValidationErrorType {
     },
 }
 
+/// Ensure that the components of a message or modal are correct.
+///
+/// In addition to validating each component, this ensures that the components
+/// are correct in combination with each other.
+///
+/// # Errors
+///
+/// Returns an error of type [`ComponentCount`] if there are too many
+/// components.
+///
+/// Returns an error of type [`ActionRowExclusiveComponent`] if a
+/// [`SelectMenu`] or [`TextInput`] shares its action row with other
+/// components.
+///
+/// Returns an error of type [`DuplicateCustomId`] if a custom ID is used by
+/// more than one component.
+///
+/// Refer to [`component`] for potential errors when validating each
+/// component.
+///
+/// [`ActionRowExclusiveComponent`]: ComponentValidationErrorType::ActionRowExclusiveComponent
+/// [`ComponentCount`]: ComponentValidationErrorType::ComponentCount
+/// [`DuplicateCustomId`]: ComponentValidationErrorType::DuplicateCustomId
+pub fn components(components: &[Component]) -> Result<(), ComponentValidationError> {
+    let count = components.len();
+
+    if count > COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ComponentCount { count },
+        });
+    }
+
+    for component in components {
+        self::component(component)?;
+    }
+
+    self::component_layout(components)
+}
+
 /// Ensure that a top-level request component is correct.
 ///
 /// Intended to ensure that a fully formed top-level component for requests
@@ -784,6 +868,66 @@ pub fn text_input(text_input: &TextInput) -> Result<(), ComponentValidationError
     Ok(())
 }
 
+/// Validate that components are correct in combination with each other.
+///
+/// # Errors
+///
+/// Returns an error of type [`ActionRowExclusiveComponent`] if a
+/// [`SelectMenu`] or [`TextInput`] shares its action row with other
+/// components.
+///
+/// Returns an error of type [`DuplicateCustomId`] if a custom ID is used by
+/// more than one component.
+///
+/// [`ActionRowExclusiveComponent`]: ComponentValidationErrorType::ActionRowExclusiveComponent
+/// [`DuplicateCustomId`]: ComponentValidationErrorType::DuplicateCustomId
+pub(crate) fn component_layout(components: &[Component]) -> Result<(), ComponentValidationError> {
+    let mut custom_ids = HashSet::new();
+
+    for (row, component) in components.iter().enumerate() {
+        let Component::ActionRow(action_row) = component else {
+            continue;
+        };
+
+        let exclusive = action_row.components.len() > 1;
+
+        for (index, component) in action_row.components.iter().enumerate() {
+            let custom_id = match component {
+                Component::Button(button) => button.custom_id.as_deref(),
+                Component::SelectMenu(SelectMenu { custom_id, .. })
+                | Component::TextInput(TextInput { custom_id, .. }) => {
+                    if exclusive {
+                        return Err(ComponentValidationError {
+                            kind: ComponentValidationErrorType::ActionRowExclusiveComponent {
+                                kind: component.kind(),
+                                row,
+                                index,
+                            },
+                        });
+                    }
+
+                    Some(custom_id.as_str())
+                }
+                _ => None,
+            };
+
+            if let Some(custom_id) = custom_id {
+                if !custom_ids.insert(custom_id) {
+                    return Err(ComponentValidationError {
+                        kind: ComponentValidationErrorType::DuplicateCustomId {
+                            custom_id: custom_id.to_owned(),
+                            row,
+                            index,
+                        },
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate that an [`ActionRow`] does not contain too many components.
 ///
 /// [`ActionRow`]s may only have so many components within it, defined by
@@ -1193,9 +1337,11 @@ mod tests {
     use twilight_model::channel::message::EmojiReactionType;
 
     assert_fields!(ComponentValidationErrorType::ActionRowComponentCount: count);
+    assert_fields!(ComponentValidationErrorType::ActionRowExclusiveComponent: kind, row, index);
     assert_fields!(ComponentValidationErrorType::ComponentCount: count);
     assert_fields!(ComponentValidationErrorType::ComponentCustomIdLength: chars);
     assert_fields!(ComponentValidationErrorType::ComponentLabelLength: chars);
+    assert_fields!(ComponentValidationErrorType::DuplicateCustomId: custom_id, row, index);
     assert_fields!(ComponentValidationErrorType::InvalidChildComponent: kind);
     assert_fields!(ComponentValidationErrorType::InvalidRootComponent: kind);
     assert_fields!(ComponentValidationErrorType::SelectMaximumValuesCount: count);
@@ -1488,4 +1634,70 @@ mod tests {
 
         assert!(component_text_input_min(4001).is_err());
     }
+
+    #[test]
+    fn components_layout() {
+        let button = |custom_id: &str| {
+            Component::Button(Button {
+                custom_id: Some(custom_id.to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("label".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+                sku_id: None,
+            })
+        };
+        let select_menu = Component::SelectMenu(SelectMenu {
+            channel_types: None,
+            custom_id: "select".to_owned(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::User,
+            max_values: None,
+            min_values: None,
+            options: None,
+            placeholder: None,
+        });
+        let row = |components: Vec<Component>| Component::ActionRow(ActionRow { components });
+
+        assert!(components(&[
+            row(vec![button("a"), button("b")]),
+            row(vec![select_menu.clone()]),
+        ])
+        .is_ok());
+
+        assert!(matches!(
+            components(&[
+                row(vec![button("a"), button("b")]),
+                row(vec![button("c"), button("a")]),
+            ]),
+            Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::DuplicateCustomId {
+                    custom_id,
+                    row: 1,
+                    index: 1,
+                },
+            })
+            if custom_id == "a"
+        ));
+
+        assert!(matches!(
+            components(&[row(vec![button("a")]), row(vec![button("b"), select_menu])]),
+            Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::ActionRowExclusiveComponent {
+                    kind: ComponentType::UserSelectMenu,
+                    row: 1,
+                    index: 1,
+                },
+            })
+        ));
+
+        assert!(matches!(
+            components(&vec![row(vec![button("a")]); 6]),
+            Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::ComponentCount { count: 6 },
+            })
+        ));
+    }
 }
@@ -12,10 +12,12 @@
     clippy::unnecessary_wraps
 )]
 
+pub mod activity;
 pub mod channel;
 pub mod command;
 pub mod component;
 pub mod embed;
 pub mod message;
+pub mod onboarding;
 pub mod request;
 pub mod sticker;
@@ -13,6 +13,7 @@ use std::{
 };
 use twilight_model::{
     channel::message::{Component, Embed},
+    guild::PremiumTier,
     http::attachment::Attachment,
     id::{marker::StickerMarker, Id},
 };
@@ -20,6 +21,22 @@ use twilight_model::{
 /// Maximum length of an attachment's description.
 pub const ATTACHMENT_DESCIPTION_LENGTH_MAX: usize = 1024;
 
+/// Maximum combined size in bytes of the attachments of a message, unless the
+/// guild's premium tier raises it.
+pub const ATTACHMENTS_SIZE_MAX: u64 = 10 * 1024 * 1024;
+
+/// Maximum combined size in bytes of the attachments of a message in a guild
+/// with the [`Tier2`] premium tier.
+///
+/// [`Tier2`]: PremiumTier::Tier2
+pub const ATTACHMENTS_SIZE_MAX_TIER_2: u64 = 50 * 1024 * 1024;
+
+/// Maximum combined size in bytes of the attachments of a message in a guild
+/// with the [`Tier3`] premium tier.
+///
+/// [`Tier3`]: PremiumTier::Tier3
+pub const ATTACHMENTS_SIZE_MAX_TIER_3: u64 = 100 * 1024 * 1024;
+
 /// Maximum number of embeds that a message may have.
 pub const EMBED_COUNT_LIMIT: usize = 10;
 
@@ -100,6 +117,14 @@ impl Display for MessageValidationError {
 
                 f.write_str("`is invalid")
             }
+            MessageValidationErrorType::AttachmentsSizeInvalid { max, size } => {
+                f.write_str("the attachments are ")?;
+                Display::fmt(size, f)?;
+                f.write_str(" bytes in total, but the max is ")?;
+                Display::fmt(max, f)?;
+
+                f.write_str(" bytes")
+            }
             MessageValidationErrorType::ComponentCount { count } => {
                 Display::fmt(count, f)?;
                 f.write_str(" components were provided, but only ")?;
@@ -153,6 +178,13 @@ pub enum MessageValidationErrorType {
         /// Provided number of codepoints.
         chars: usize,
     },
+    /// Combined size of the attachments is larger than the upload limit.
+    AttachmentsSizeInvalid {
+        /// Upload limit in bytes.
+        max: u64,
+        /// Combined size of the attachments in bytes.
+        size: u64,
+    },
     /// Too many message components were provided.
     ComponentCount {
         /// Number of components that were provided.
@@ -256,6 +288,59 @@ pub fn attachment_filename(filename: impl AsRef<str>) -> Result<(), MessageValid
     }
 }
 
+/// Ensure the combined size of attachments is within the upload limit of a
+/// guild's premium tier.
+///
+/// Pass [`None`] for messages outside of guilds, which have the default
+/// limit. Refer to [`attachments_size_max`] for the limit of each premium tier.
+///
+/// **Note** that the limit may be higher for users with Nitro, which isn't
+/// taken into account.
+///
+/// # Errors
+///
+/// Returns an error of type [`AttachmentsSizeInvalid`] if the attachments are
+/// too large.
+///
+/// [`AttachmentsSizeInvalid`]: MessageValidationErrorType::AttachmentsSizeInvalid
+pub fn attachments_size(
+    attachments: &[Attachment],
+    premium_tier: Option<PremiumTier>,
+) -> Result<(), MessageValidationError> {
+    let max = attachments_size_max(premium_tier);
+    let size = attachments
+        .iter()
+        .map(|attachment| attachment.file.len() as u64)
+        .sum();
+
+    if size <= max {
+        Ok(())
+    } else {
+        Err(MessageValidationError {
+            kind: MessageValidationErrorType::AttachmentsSizeInvalid { max, size },
+            source: None,
+        })
+    }
+}
+
+/// Maximum combined size in bytes of the attachments of a message in a guild
+/// with a premium tier.
+///
+/// Guilds with the [`Tier2`] and [`Tier3`] premium tiers have raised limits of
+/// [`ATTACHMENTS_SIZE_MAX_TIER_2`] and [`ATTACHMENTS_SIZE_MAX_TIER_3`]
+/// respectively, whereas other guilds and [`None`] have the default
+/// [`ATTACHMENTS_SIZE_MAX`].
+///
+/// [`Tier2`]: PremiumTier::Tier2
+/// [`Tier3`]: PremiumTier::Tier3
+pub const fn attachments_size_max(premium_tier: Option<PremiumTier>) -> u64 {
+    match premium_tier {
+        Some(PremiumTier::Tier2) => ATTACHMENTS_SIZE_MAX_TIER_2,
+        Some(PremiumTier::Tier3) => ATTACHMENTS_SIZE_MAX_TIER_3,
+        _ => ATTACHMENTS_SIZE_MAX,
+    }
+}
+
 /// Ensure a list of components is correct.
 ///
 /// # Errors
@@ -263,6 +348,12 @@ pub fn attachment_filename(filename: impl AsRef<str>) -> Result<(), MessageValid
 /// Returns a [`ComponentValidationErrorType::ComponentCount`] if there are
 /// too many components in the provided list.
 ///
+/// Returns a [`ComponentValidationErrorType::ActionRowExclusiveComponent`] if
+/// a select menu or text input shares its action row with other components.
+///
+/// Returns a [`ComponentValidationErrorType::DuplicateCustomId`] if a custom
+/// ID is used by more than one component.
+///
 /// Refer to the errors section of [`component`] for a list of errors that may
 /// be returned as a result of validating each provided component.
 ///
@@ -287,7 +378,19 @@ pub fn components(components: &[Component]) -> Result<(), MessageValidationError
             })?;
         }
 
-        Ok(())
+        crate::component::component_layout(components).map_err(|source| {
+            let (kind, source) = source.into_parts();
+            let idx = match kind {
+                ComponentValidationErrorType::ActionRowExclusiveComponent { row, .. }
+                | ComponentValidationErrorType::DuplicateCustomId { row, .. } => row,
+                _ => 0,
+            };
+
+            MessageValidationError {
+                kind: MessageValidationErrorType::ComponentInvalid { idx, kind },
+                source,
+            }
+        })
     }
 }
 
@@ -384,6 +487,7 @@ pub fn sticker_ids(sticker_ids: &[Id<StickerMarker>]) -> Result<(), MessageValid
 #[cfg(test)]
 mod tests {
     use super::*;
+    use twilight_model::channel::message::component::{ActionRow, Button, ButtonStyle};
 
     #[test]
     fn attachment_description_limit() {
@@ -408,6 +512,37 @@ mod tests {
         assert!(attachment_filename("????????").is_err());
     }
 
+    #[test]
+    fn attachments_size_limit() {
+        let attachment = |size| Attachment::from_bytes("file.bin".to_owned(), vec![0; size], 1);
+        #[allow(clippy::cast_possible_truncation)]
+        let mib = |size: u64| (size * 1024 * 1024) as usize;
+
+        let attachments = [attachment(mib(6)), attachment(mib(4))];
+        assert!(attachments_size(&attachments, None).is_ok());
+
+        let attachments = [attachment(mib(6)), attachment(mib(4) + 1)];
+        assert!(matches!(
+            attachments_size(&attachments, Some(PremiumTier::Tier1))
+                .unwrap_err()
+                .kind(),
+            MessageValidationErrorType::AttachmentsSizeInvalid {
+                max: ATTACHMENTS_SIZE_MAX,
+                size: 10_485_761
+            }
+        ));
+        assert!(attachments_size(&attachments, Some(PremiumTier::Tier2)).is_ok());
+
+        assert_eq!(
+            ATTACHMENTS_SIZE_MAX_TIER_3,
+            attachments_size_max(Some(PremiumTier::Tier3))
+        );
+        assert_eq!(
+            ATTACHMENTS_SIZE_MAX,
+            attachments_size_max(Some(PremiumTier::Other(4)))
+        );
+    }
+
     #[test]
     fn content_length() {
         assert!(content("").is_ok());
@@ -415,4 +550,33 @@ mod tests {
 
         assert!(content("a".repeat(2001)).is_err());
     }
+
+    #[test]
+    fn components_duplicate_custom_id() {
+        let button = Component::Button(Button {
+            custom_id: Some("a".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("label".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        });
+        let row = Component::ActionRow(ActionRow {
+            components: vec![button],
+        });
+
+        assert!(components(std::slice::from_ref(&row)).is_ok());
+        assert!(matches!(
+            components(&[row.clone(), row]).unwrap_err().kind(),
+            MessageValidationErrorType::ComponentInvalid {
+                idx: 1,
+                kind: ComponentValidationErrorType::DuplicateCustomId {
+                    row: 1,
+                    index: 0,
+                    ..
+                },
+            }
+        ));
+    }
 }
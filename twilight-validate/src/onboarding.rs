@@ -0,0 +1,372 @@
+//! Constants, error types, and functions for validating [`Onboarding`] fields.
+//!
+//! [`Onboarding`]: twilight_model::guild::onboarding::Onboarding
+
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::onboarding::OnboardingMode,
+    id::{marker::ChannelMarker, Id},
+};
+
+/// Minimum number of channels a member is in after enabled onboarding.
+pub const ONBOARDING_CHANNELS_MIN: usize = 7;
+
+/// Maximum length of a prompt option's description.
+pub const ONBOARDING_OPTION_DESCRIPTION_LENGTH_MAX: usize = 100;
+
+/// Maximum length of a prompt option's title.
+pub const ONBOARDING_OPTION_TITLE_LENGTH_MAX: usize = 50;
+
+/// Minimum length of a prompt option's title.
+pub const ONBOARDING_OPTION_TITLE_LENGTH_MIN: usize = 1;
+
+/// Maximum number of options of a prompt.
+pub const ONBOARDING_PROMPT_OPTIONS_MAX: usize = 50;
+
+/// Minimum number of options of a prompt.
+pub const ONBOARDING_PROMPT_OPTIONS_MIN: usize = 1;
+
+/// Maximum length of a prompt's title.
+pub const ONBOARDING_PROMPT_TITLE_LENGTH_MAX: usize = 100;
+
+/// Minimum length of a prompt's title.
+pub const ONBOARDING_PROMPT_TITLE_LENGTH_MIN: usize = 1;
+
+/// Maximum number of prompts of an onboarding flow.
+pub const ONBOARDING_PROMPTS_MAX: usize = 15;
+
+/// Error created if validation of an onboarding field fails.
+#[derive(Debug)]
+pub struct OnboardingValidationError {
+    /// Type of error that occurred.
+    kind: OnboardingValidationErrorType,
+}
+
+impl OnboardingValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &OnboardingValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        OnboardingValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for OnboardingValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            OnboardingValidationErrorType::ChannelsInvalid { count, mode } => {
+                f.write_str("onboarding in mode ")?;
+                Display::fmt(mode.name(), f)?;
+                f.write_str(" counts ")?;
+                Display::fmt(&count, f)?;
+                f.write_str(" channels, but must count at least ")?;
+
+                Display::fmt(&ONBOARDING_CHANNELS_MIN, f)
+            }
+            OnboardingValidationErrorType::OptionDescriptionInvalid => {
+                f.write_str("prompt option's description is longer than ")?;
+
+                Display::fmt(&ONBOARDING_OPTION_DESCRIPTION_LENGTH_MAX, f)
+            }
+            OnboardingValidationErrorType::OptionTitleInvalid => {
+                f.write_str("prompt option's title is invalid")
+            }
+            OnboardingValidationErrorType::PromptOptionsInvalid => {
+                f.write_str("number of prompt options is less than ")?;
+                Display::fmt(&ONBOARDING_PROMPT_OPTIONS_MIN, f)?;
+                f.write_str(" or greater than ")?;
+
+                Display::fmt(&ONBOARDING_PROMPT_OPTIONS_MAX, f)
+            }
+            OnboardingValidationErrorType::PromptTitleInvalid => {
+                f.write_str("prompt's title is invalid")
+            }
+            OnboardingValidationErrorType::PromptsInvalid => {
+                f.write_str("number of prompts is greater than ")?;
+
+                Display::fmt(&ONBOARDING_PROMPTS_MAX, f)
+            }
+        }
+    }
+}
+
+impl Error for OnboardingValidationError {}
+
+/// Type of [`OnboardingValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum OnboardingValidationErrorType {
+    /// Fewer than 7 channels count towards the constraints of the onboarding
+    /// mode.
+    ChannelsInvalid {
+        /// Number of channels that count towards the constraints.
+        count: usize,
+        /// Mode of the onboarding flow.
+        mode: OnboardingMode,
+    },
+    /// Prompt option's description is more than 100 characters.
+    OptionDescriptionInvalid,
+    /// Prompt option's title is fewer than 1 or more than 50 characters.
+    OptionTitleInvalid,
+    /// Number of options of a prompt is fewer than 1 or more than 50.
+    PromptOptionsInvalid,
+    /// Prompt's title is fewer than 1 or more than 100 characters.
+    PromptTitleInvalid,
+    /// Number of prompts is more than 15.
+    PromptsInvalid,
+}
+
+/// Ensure that enough channels count towards the constraints of the
+/// onboarding mode.
+///
+/// Members must be in at least [`ONBOARDING_CHANNELS_MIN`] channels after
+/// onboarding. In [`OnboardingDefault`] mode only the default channels count,
+/// whereas in [`OnboardingAdvanced`] mode the channels of prompt options count
+/// too. This only applies to enabled onboarding flows. This is based on
+/// [this documentation entry].
+///
+/// **Note** that Discord additionally requires some of the channels to allow
+/// `@everyone` to send messages, which isn't validated.
+///
+/// # Errors
+///
+/// Returns an error of type [`ChannelsInvalid`] if too few channels count.
+///
+/// [`ChannelsInvalid`]: OnboardingValidationErrorType::ChannelsInvalid
+/// [`OnboardingAdvanced`]: OnboardingMode::OnboardingAdvanced
+/// [`OnboardingDefault`]: OnboardingMode::OnboardingDefault
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-mode
+pub fn channels<'a>(
+    mode: OnboardingMode,
+    default_channel_ids: &'a [Id<ChannelMarker>],
+    option_channel_ids: impl IntoIterator<Item = &'a Id<ChannelMarker>>,
+) -> Result<(), OnboardingValidationError> {
+    let mut channel_ids = default_channel_ids.iter().collect::<HashSet<_>>();
+
+    match mode {
+        OnboardingMode::OnboardingAdvanced => channel_ids.extend(option_channel_ids),
+        OnboardingMode::OnboardingDefault => {}
+        _ => return Ok(()),
+    }
+
+    let count = channel_ids.len();
+
+    if count >= ONBOARDING_CHANNELS_MIN {
+        Ok(())
+    } else {
+        Err(OnboardingValidationError {
+            kind: OnboardingValidationErrorType::ChannelsInvalid { count, mode },
+        })
+    }
+}
+
+/// Ensure that a prompt option's description is correct.
+///
+/// The length must be at most [`ONBOARDING_OPTION_DESCRIPTION_LENGTH_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`OptionDescriptionInvalid`] if the length is
+/// invalid.
+///
+/// [`OptionDescriptionInvalid`]: OnboardingValidationErrorType::OptionDescriptionInvalid
+pub fn option_description(value: impl AsRef<str>) -> Result<(), OnboardingValidationError> {
+    if value.as_ref().chars().count() <= ONBOARDING_OPTION_DESCRIPTION_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(OnboardingValidationError {
+            kind: OnboardingValidationErrorType::OptionDescriptionInvalid,
+        })
+    }
+}
+
+/// Ensure that a prompt option's title is correct.
+///
+/// The length must be at least [`ONBOARDING_OPTION_TITLE_LENGTH_MIN`] and at
+/// most [`ONBOARDING_OPTION_TITLE_LENGTH_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`OptionTitleInvalid`] if the length is invalid.
+///
+/// [`OptionTitleInvalid`]: OnboardingValidationErrorType::OptionTitleInvalid
+pub fn option_title(value: impl AsRef<str>) -> Result<(), OnboardingValidationError> {
+    let len = value.as_ref().chars().count();
+
+    if (ONBOARDING_OPTION_TITLE_LENGTH_MIN..=ONBOARDING_OPTION_TITLE_LENGTH_MAX).contains(&len) {
+        Ok(())
+    } else {
+        Err(OnboardingValidationError {
+            kind: OnboardingValidationErrorType::OptionTitleInvalid,
+        })
+    }
+}
+
+/// Ensure that the number of options of a prompt is correct.
+///
+/// The number must be at least [`ONBOARDING_PROMPT_OPTIONS_MIN`] and at most
+/// [`ONBOARDING_PROMPT_OPTIONS_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`PromptOptionsInvalid`] if the number is invalid.
+///
+/// [`PromptOptionsInvalid`]: OnboardingValidationErrorType::PromptOptionsInvalid
+pub const fn prompt_options(count: usize) -> Result<(), OnboardingValidationError> {
+    if count >= ONBOARDING_PROMPT_OPTIONS_MIN && count <= ONBOARDING_PROMPT_OPTIONS_MAX {
+        Ok(())
+    } else {
+        Err(OnboardingValidationError {
+            kind: OnboardingValidationErrorType::PromptOptionsInvalid,
+        })
+    }
+}
+
+/// Ensure that a prompt's title is correct.
+///
+/// The length must be at least [`ONBOARDING_PROMPT_TITLE_LENGTH_MIN`] and at
+/// most [`ONBOARDING_PROMPT_TITLE_LENGTH_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`PromptTitleInvalid`] if the length is invalid.
+///
+/// [`PromptTitleInvalid`]: OnboardingValidationErrorType::PromptTitleInvalid
+pub fn prompt_title(value: impl AsRef<str>) -> Result<(), OnboardingValidationError> {
+    let len = value.as_ref().chars().count();
+
+    if (ONBOARDING_PROMPT_TITLE_LENGTH_MIN..=ONBOARDING_PROMPT_TITLE_LENGTH_MAX).contains(&len) {
+        Ok(())
+    } else {
+        Err(OnboardingValidationError {
+            kind: OnboardingValidationErrorType::PromptTitleInvalid,
+        })
+    }
+}
+
+/// Ensure that the number of prompts is correct.
+///
+/// The number must be at most [`ONBOARDING_PROMPTS_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`PromptsInvalid`] if the number is invalid.
+///
+/// [`PromptsInvalid`]: OnboardingValidationErrorType::PromptsInvalid
+pub const fn prompts(count: usize) -> Result<(), OnboardingValidationError> {
+    if count <= ONBOARDING_PROMPTS_MAX {
+        Ok(())
+    } else {
+        Err(OnboardingValidationError {
+            kind: OnboardingValidationErrorType::PromptsInvalid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channels_by_mode() {
+        let default_channel_ids = (1..=5).map(Id::new).collect::<Vec<_>>();
+        let option_channel_ids = (4..=7).map(Id::new).collect::<Vec<_>>();
+
+        assert!(channels(
+            OnboardingMode::OnboardingAdvanced,
+            &default_channel_ids,
+            &option_channel_ids
+        )
+        .is_ok());
+        assert!(matches!(
+            channels(
+                OnboardingMode::OnboardingDefault,
+                &default_channel_ids,
+                &option_channel_ids
+            )
+            .unwrap_err()
+            .kind(),
+            OnboardingValidationErrorType::ChannelsInvalid {
+                count: 5,
+                mode: OnboardingMode::OnboardingDefault
+            }
+        ));
+        assert!(matches!(
+            channels(
+                OnboardingMode::OnboardingAdvanced,
+                &default_channel_ids,
+                &option_channel_ids[..2]
+            )
+            .unwrap_err()
+            .kind(),
+            OnboardingValidationErrorType::ChannelsInvalid { count: 5, .. }
+        ));
+    }
+
+    #[test]
+    fn option_description_length() {
+        assert!(option_description("").is_ok());
+        assert!(option_description("a".repeat(100)).is_ok());
+
+        assert!(option_description("a".repeat(101)).is_err());
+    }
+
+    #[test]
+    fn option_title_length() {
+        assert!(option_title("a").is_ok());
+        assert!(option_title("a".repeat(50)).is_ok());
+
+        assert!(option_title("").is_err());
+        assert!(option_title("a".repeat(51)).is_err());
+    }
+
+    #[test]
+    fn prompt_options_count() {
+        assert!(prompt_options(1).is_ok());
+        assert!(prompt_options(50).is_ok());
+
+        assert!(prompt_options(0).is_err());
+        assert!(prompt_options(51).is_err());
+    }
+
+    #[test]
+    fn prompt_title_length() {
+        assert!(prompt_title("a").is_ok());
+        assert!(prompt_title("a".repeat(100)).is_ok());
+
+        assert!(prompt_title("").is_err());
+        assert!(prompt_title("a".repeat(101)).is_err());
+    }
+
+    #[test]
+    fn prompts_count() {
+        assert!(prompts(0).is_ok());
+        assert!(prompts(15).is_ok());
+
+        assert!(matches!(
+            prompts(16).unwrap_err().kind(),
+            OnboardingValidationErrorType::PromptsInvalid
+        ));
+    }
+}
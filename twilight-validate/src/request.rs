@@ -7,6 +7,10 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     time::{SystemTime, UNIX_EPOCH},
 };
+use twilight_model::guild::{
+    auto_moderation::{AutoModerationAction, AutoModerationTriggerMetadata},
+    scheduled_event::EntityType,
+};
 use twilight_model::id::marker::{ChannelMarker, RoleMarker};
 use twilight_model::id::Id;
 use twilight_model::util::Timestamp;
@@ -14,6 +18,9 @@ use twilight_model::util::Timestamp;
 /// The maximum audit log reason length in UTF-16 codepoints.
 pub const AUDIT_REASON_MAX: usize = 512;
 
+/// Maximum amount of actions of an auto moderation rule.
+pub const AUTO_MODERATION_ACTIONS_MAX: usize = 3;
+
 /// Maximum length of an auto moderation block action's custom message.
 pub const AUTO_MODERATION_ACTION_BLOCK_CUSTOM_MESSAGE_LENGTH_MAX: usize = 150;
 
@@ -134,6 +141,12 @@ pub const SCHEDULED_EVENT_GET_USERS_MAX: u16 = 100;
 /// Minimum amount of scheduled event users to get.
 pub const SCHEDULED_EVENT_GET_USERS_MIN: u16 = 1;
 
+/// Maximum length of a scheduled event's location.
+pub const SCHEDULED_EVENT_LOCATION_MAX: usize = 100;
+
+/// Minimum length of a scheduled event's location.
+pub const SCHEDULED_EVENT_LOCATION_MIN: usize = 1;
+
 /// Maximum length of a scheduled event's name.
 pub const SCHEDULED_EVENT_NAME_MAX: usize = 100;
 
@@ -320,6 +333,13 @@ impl Display for ValidationError {
 
                 Display::fmt(&AUTO_MODERATION_EXEMPT_CHANNELS_MAX, f)
             }
+            ValidationErrorType::AutoModerationActions { len } => {
+                f.write_str("provided auto moderation actions length is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at most ")?;
+
+                Display::fmt(&AUTO_MODERATION_ACTIONS_MAX, f)
+            }
             ValidationErrorType::CreateGuildBanDeleteMessageSeconds {
                 seconds: delete_message_seconds,
             } => {
@@ -443,6 +463,18 @@ impl Display for ValidationError {
 
                 Display::fmt(&SCHEDULED_EVENT_DESCRIPTION_MAX, f)
             }
+            ValidationErrorType::ScheduledEventEndTime => {
+                f.write_str("provided scheduled event end time is not after its start time")
+            }
+            ValidationErrorType::ScheduledEventEntityFields { entity_type } => {
+                if *entity_type == EntityType::External {
+                    f.write_str(
+                        "provided external scheduled event is missing its location or end time",
+                    )
+                } else {
+                    f.write_str("provided scheduled event is missing its channel")
+                }
+            }
             ValidationErrorType::ScheduledEventGetUsers { limit } => {
                 f.write_str("provided scheduled event get users limit is ")?;
                 Display::fmt(limit, f)?;
@@ -452,6 +484,15 @@ impl Display for ValidationError {
 
                 Display::fmt(&SCHEDULED_EVENT_GET_USERS_MAX, f)
             }
+            ValidationErrorType::ScheduledEventLocation { len } => {
+                f.write_str("provided scheduled event location is length is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&SCHEDULED_EVENT_LOCATION_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&SCHEDULED_EVENT_LOCATION_MAX, f)
+            }
             ValidationErrorType::ScheduledEventName { len } => {
                 f.write_str("provided scheduled event name is length is ")?;
                 Display::fmt(len, f)?;
@@ -606,6 +647,11 @@ pub enum ValidationErrorType {
         /// Invalid length.
         len: usize,
     },
+    /// Provided amount of actions was invalid.
+    AutoModerationActions {
+        /// Invalid length.
+        len: usize,
+    },
     /// Provided create guild ban delete message seconds was invalid.
     CreateGuildBanDeleteMessageSeconds {
         /// Invalid seconds.
@@ -681,11 +727,23 @@ pub enum ValidationErrorType {
         /// Invalid length.
         len: usize,
     },
+    /// Scheduled event end time is not after its start time.
+    ScheduledEventEndTime,
+    /// Scheduled event is missing fields required by its entity type.
+    ScheduledEventEntityFields {
+        /// Entity type of the scheduled event.
+        entity_type: EntityType,
+    },
     /// Scheduled event get users limit is invalid.
     ScheduledEventGetUsers {
         /// Invalid limit.
         limit: u16,
     },
+    /// Scheduled event location is invalid.
+    ScheduledEventLocation {
+        /// Invalid length.
+        len: usize,
+    },
     /// Scheduled event name is invalid.
     ScheduledEventName {
         /// Invalid length.
@@ -1127,6 +1185,88 @@ pub const fn auto_moderation_exempt_channels(
     }
 }
 
+/// Ensure that the amount of actions of an auto moderation rule is correct.
+///
+/// The amount must be at most [`AUTO_MODERATION_ACTIONS_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`AutoModerationActions`] if the amount is
+/// invalid.
+///
+/// [`AutoModerationActions`]: ValidationErrorType::AutoModerationActions
+pub const fn auto_moderation_actions(len: usize) -> Result<(), ValidationError> {
+    if len <= AUTO_MODERATION_ACTIONS_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::AutoModerationActions { len },
+        })
+    }
+}
+
+/// Ensure that the metadata of an auto moderation action is correct.
+///
+/// # Errors
+///
+/// Refer to the errors sections of
+/// [`auto_moderation_block_action_custom_message_limit`] and
+/// [`auto_moderation_action_metadata_duration_seconds`] for possible errors.
+pub fn auto_moderation_action(action: &AutoModerationAction) -> Result<(), ValidationError> {
+    let Some(metadata) = &action.metadata else {
+        return Ok(());
+    };
+
+    if let Some(custom_message) = &metadata.custom_message {
+        auto_moderation_block_action_custom_message_limit(custom_message)?;
+    }
+
+    if let Some(duration_seconds) = metadata.duration_seconds {
+        auto_moderation_action_metadata_duration_seconds(duration_seconds)?;
+    }
+
+    Ok(())
+}
+
+/// Ensure that the trigger metadata of an auto moderation rule is correct.
+///
+/// The `allow_list` is validated against the limits of keyword preset rules
+/// if `presets` are set, and against the limits of keyword rules otherwise.
+///
+/// # Errors
+///
+/// Refer to the errors sections of
+/// [`auto_moderation_metadata_keyword_allow_list`],
+/// [`auto_moderation_metadata_keyword_filter`],
+/// [`auto_moderation_metadata_mention_total_limit`],
+/// [`auto_moderation_metadata_preset_allow_list`], and
+/// [`auto_moderation_metadata_regex_patterns`] for possible errors.
+pub fn auto_moderation_trigger_metadata(
+    metadata: &AutoModerationTriggerMetadata,
+) -> Result<(), ValidationError> {
+    if let Some(allow_list) = &metadata.allow_list {
+        if metadata.presets.is_some() {
+            auto_moderation_metadata_preset_allow_list(allow_list)?;
+        } else {
+            auto_moderation_metadata_keyword_allow_list(allow_list)?;
+        }
+    }
+
+    if let Some(keyword_filter) = &metadata.keyword_filter {
+        auto_moderation_metadata_keyword_filter(keyword_filter)?;
+    }
+
+    if let Some(mention_total_limit) = metadata.mention_total_limit {
+        auto_moderation_metadata_mention_total_limit(mention_total_limit)?;
+    }
+
+    if let Some(regex_patterns) = &metadata.regex_patterns {
+        auto_moderation_metadata_regex_patterns(regex_patterns)?;
+    }
+
+    Ok(())
+}
+
 /// Ensure that the delete message seconds amount for the Create Guild Ban request
 /// is correct.
 ///
@@ -1505,6 +1645,89 @@ pub fn scheduled_event_name(name: impl AsRef<str>) -> Result<(), ValidationError
     }
 }
 
+/// Ensure that a scheduled event's end time is after its start time.
+///
+/// # Errors
+///
+/// Returns an error of type [`ScheduledEventEndTime`] if the end time is not
+/// after the start time.
+///
+/// [`ScheduledEventEndTime`]: ValidationErrorType::ScheduledEventEndTime
+pub const fn scheduled_event_end_time(
+    scheduled_start_time: Timestamp,
+    scheduled_end_time: Timestamp,
+) -> Result<(), ValidationError> {
+    if scheduled_end_time.as_micros() > scheduled_start_time.as_micros() {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventEndTime,
+        })
+    }
+}
+
+/// Ensure that a scheduled event has the fields required by its entity type.
+///
+/// [`External`] events require a location and an end time, whereas
+/// [`StageInstance`] and [`Voice`] events require a channel. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ScheduledEventEntityFields`] if a required field
+/// is missing.
+///
+/// [`External`]: EntityType::External
+/// [`ScheduledEventEntityFields`]: ValidationErrorType::ScheduledEventEntityFields
+/// [`StageInstance`]: EntityType::StageInstance
+/// [`Voice`]: EntityType::Voice
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-field-requirements-by-entity-type
+pub const fn scheduled_event_entity_fields(
+    entity_type: EntityType,
+    has_channel: bool,
+    has_location: bool,
+    has_scheduled_end_time: bool,
+) -> Result<(), ValidationError> {
+    let valid = match entity_type {
+        EntityType::External => has_location && has_scheduled_end_time,
+        EntityType::StageInstance | EntityType::Voice => has_channel,
+        _ => true,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventEntityFields { entity_type },
+        })
+    }
+}
+
+/// Ensure that a scheduled event's location is correct.
+///
+/// The length must be at least [`SCHEDULED_EVENT_LOCATION_MIN`] and at most
+/// [`SCHEDULED_EVENT_LOCATION_MAX`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ScheduledEventLocation`] if the length is
+/// invalid.
+///
+/// [`ScheduledEventLocation`]: ValidationErrorType::ScheduledEventLocation
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata
+pub fn scheduled_event_location(location: impl AsRef<str>) -> Result<(), ValidationError> {
+    let len = location.as_ref().chars().count();
+
+    if (SCHEDULED_EVENT_LOCATION_MIN..=SCHEDULED_EVENT_LOCATION_MAX).contains(&len) {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventLocation { len },
+        })
+    }
+}
+
 /// Ensure that the limit for the Search Guild Members endpoint is correct.
 ///
 /// The limit must be at least [`SEARCH_GUILD_MEMBERS_LIMIT_MIN`] and at most
@@ -1674,6 +1897,9 @@ pub fn webhook_username(value: impl AsRef<str>) -> Result<(), ValidationError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use twilight_model::guild::auto_moderation::{
+        AutoModerationActionMetadata, AutoModerationActionType, AutoModerationKeywordPresetType,
+    };
 
     #[test]
     fn username_variants() {
@@ -1828,6 +2054,82 @@ mod tests {
         assert!(auto_moderation_action_metadata_duration_seconds(2_419_201).is_err());
     }
 
+    #[test]
+    fn auto_moderation_actions_max() {
+        assert!(auto_moderation_actions(0).is_ok());
+        assert!(auto_moderation_actions(3).is_ok());
+
+        assert!(matches!(
+            auto_moderation_actions(4).unwrap_err().kind(),
+            ValidationErrorType::AutoModerationActions { len: 4 }
+        ));
+    }
+
+    #[test]
+    fn auto_moderation_action_metadata() {
+        let mut action = AutoModerationAction {
+            kind: AutoModerationActionType::Timeout,
+            metadata: None,
+        };
+
+        assert!(auto_moderation_action(&action).is_ok());
+
+        action.metadata = Some(AutoModerationActionMetadata {
+            channel_id: None,
+            custom_message: None,
+            duration_seconds: Some(2_419_201),
+        });
+
+        assert!(matches!(
+            auto_moderation_action(&action).unwrap_err().kind(),
+            ValidationErrorType::AutoModerationActionMetadataDurationSeconds { .. }
+        ));
+
+        action.kind = AutoModerationActionType::BlockMessage;
+        action.metadata = Some(AutoModerationActionMetadata {
+            channel_id: None,
+            custom_message: Some("a".repeat(151)),
+            duration_seconds: None,
+        });
+
+        assert!(matches!(
+            auto_moderation_action(&action).unwrap_err().kind(),
+            ValidationErrorType::AutoModerationBlockActionCustomMessageLimit { len: 151 }
+        ));
+    }
+
+    #[test]
+    fn auto_moderation_trigger_metadata_lists() {
+        let mut metadata = AutoModerationTriggerMetadata {
+            allow_list: Some(vec!["a".to_owned(); 101]),
+            keyword_filter: None,
+            presets: None,
+            mention_raid_protection_enabled: None,
+            mention_total_limit: None,
+            regex_patterns: None,
+        };
+
+        assert!(matches!(
+            auto_moderation_trigger_metadata(&metadata)
+                .unwrap_err()
+                .kind(),
+            ValidationErrorType::AutoModerationMetadataAllowList { len: 101 }
+        ));
+
+        metadata.presets = Some(vec![AutoModerationKeywordPresetType::Profanity]);
+
+        assert!(auto_moderation_trigger_metadata(&metadata).is_ok());
+
+        metadata.regex_patterns = Some(vec!["a".to_owned(); 11]);
+
+        assert!(matches!(
+            auto_moderation_trigger_metadata(&metadata)
+                .unwrap_err()
+                .kind(),
+            ValidationErrorType::AutoModerationMetadataRegexPatterns { len: 11 }
+        ));
+    }
+
     #[test]
     fn create_guild_ban_delete_message_seconds_max() {
         assert!(create_guild_ban_delete_message_seconds(0).is_ok());
@@ -1962,6 +2264,39 @@ mod tests {
         assert!(scheduled_event_description("a".repeat(1001)).is_err());
     }
 
+    #[test]
+    fn scheduled_event_end_time_order() {
+        let start = Timestamp::from_secs(1_700_000_000).unwrap();
+        let end = Timestamp::from_secs(1_700_003_600).unwrap();
+
+        assert!(scheduled_event_end_time(start, end).is_ok());
+
+        assert!(scheduled_event_end_time(start, start).is_err());
+        assert!(matches!(
+            scheduled_event_end_time(end, start).unwrap_err().kind(),
+            ValidationErrorType::ScheduledEventEndTime
+        ));
+    }
+
+    #[test]
+    fn scheduled_event_entity_fields_required() {
+        assert!(scheduled_event_entity_fields(EntityType::External, false, true, true).is_ok());
+        assert!(scheduled_event_entity_fields(EntityType::Voice, true, false, false).is_ok());
+        assert!(
+            scheduled_event_entity_fields(EntityType::StageInstance, true, false, true).is_ok()
+        );
+
+        assert!(matches!(
+            scheduled_event_entity_fields(EntityType::External, true, true, false)
+                .unwrap_err()
+                .kind(),
+            ValidationErrorType::ScheduledEventEntityFields {
+                entity_type: EntityType::External
+            }
+        ));
+        assert!(scheduled_event_entity_fields(EntityType::Voice, false, true, true).is_err());
+    }
+
     #[test]
     fn scheduled_event_get_users_length() {
         assert!(scheduled_event_get_users(0).is_err());
@@ -1970,6 +2305,15 @@ mod tests {
         assert!(scheduled_event_get_users(1).is_ok());
     }
 
+    #[test]
+    fn scheduled_event_location_length() {
+        assert!(scheduled_event_location("a").is_ok());
+        assert!(scheduled_event_location("a".repeat(100)).is_ok());
+
+        assert!(scheduled_event_location("").is_err());
+        assert!(scheduled_event_location("a".repeat(101)).is_err());
+    }
+
     #[test]
     fn scheduled_event_name_length() {
         assert!(scheduled_event_name("a").is_ok());
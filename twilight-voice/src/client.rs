@@ -0,0 +1,169 @@
+//! Tracking of voice states and servers to connect with.
+
+use crate::connection::ConnectionInfo;
+use dashmap::DashMap;
+use twilight_model::{
+    gateway::{event::Event, payload::incoming::VoiceServerUpdate},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Combines the current user's voice states and the guilds' voice servers into
+/// the [`ConnectionInfo`] required to [connect].
+///
+/// **Note**: You must call the [`process`] method with every Voice State
+/// Update and Voice Server Update event you receive from Discord.
+///
+/// [connect]: crate::Connection::connect
+/// [`process`]: Self::process
+#[derive(Debug)]
+pub struct Voice {
+    /// Voice servers by their guild.
+    servers: DashMap<Id<GuildMarker>, VoiceServerUpdate>,
+    /// Voice state session IDs of the current user by their guild.
+    sessions: DashMap<Id<GuildMarker>, String>,
+    /// ID of the current user.
+    user_id: Id<UserMarker>,
+}
+
+impl Voice {
+    /// Create a new voice client for the current user.
+    pub fn new(user_id: Id<UserMarker>) -> Self {
+        Self {
+            servers: DashMap::new(),
+            sessions: DashMap::new(),
+            user_id,
+        }
+    }
+
+    /// ID of the current user.
+    pub const fn user_id(&self) -> Id<UserMarker> {
+        self.user_id
+    }
+
+    /// Process an event, returning the info to (re)connect to a guild's voice
+    /// server if it changed.
+    ///
+    /// Any event can be provided, but only Voice Server Update and the current
+    /// user's Voice State Update events are used. Leaving a voice channel
+    /// forgets about the guild.
+    pub fn process(&self, event: &Event) -> Option<ConnectionInfo> {
+        let guild_id = match event {
+            Event::VoiceServerUpdate(update) => {
+                if update.endpoint.is_none() {
+                    tracing::debug!(guild_id = %update.guild_id, "voice server deallocated");
+                    self.servers.remove(&update.guild_id);
+
+                    return None;
+                }
+
+                self.servers.insert(update.guild_id, update.clone());
+
+                update.guild_id
+            }
+            Event::VoiceStateUpdate(update) if update.user_id == self.user_id => {
+                let guild_id = update.guild_id?;
+
+                if update.channel_id.is_none() {
+                    self.servers.remove(&guild_id);
+                    self.sessions.remove(&guild_id);
+
+                    return None;
+                }
+
+                let previous = self.sessions.insert(guild_id, update.session_id.clone());
+                if previous.as_ref() == Some(&update.session_id) {
+                    return None;
+                }
+
+                guild_id
+            }
+            _ => return None,
+        };
+
+        let server = self.servers.get(&guild_id)?;
+        let session_id = self.sessions.get(&guild_id)?;
+        tracing::debug!(%guild_id, "got voice state and server");
+
+        Some(ConnectionInfo {
+            endpoint: server.endpoint.clone()?,
+            guild_id,
+            session_id: session_id.clone(),
+            token: server.token.clone(),
+            user_id: self.user_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Voice;
+    use crate::ConnectionInfo;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::incoming::{VoiceServerUpdate, VoiceStateUpdate},
+        },
+        id::Id,
+        voice::VoiceState,
+    };
+
+    assert_impl_all!(Voice: Debug, Send, Sync);
+
+    fn state(user_id: u64, session_id: &str, connected: bool) -> Event {
+        Event::VoiceStateUpdate(Box::new(VoiceStateUpdate(VoiceState {
+            channel_id: connected.then(|| Id::new(2)),
+            deaf: false,
+            guild_id: Some(Id::new(1)),
+            member: None,
+            mute: false,
+            self_deaf: false,
+            self_mute: false,
+            self_stream: false,
+            self_video: false,
+            session_id: session_id.to_owned(),
+            suppress: false,
+            user_id: Id::new(user_id),
+            request_to_speak_timestamp: None,
+        })))
+    }
+
+    fn server(endpoint: Option<&str>) -> Event {
+        Event::VoiceServerUpdate(VoiceServerUpdate {
+            endpoint: endpoint.map(ToOwned::to_owned),
+            guild_id: Id::new(1),
+            token: "token".to_owned(),
+        })
+    }
+
+    #[test]
+    fn process() {
+        let voice = Voice::new(Id::new(3));
+        let info = ConnectionInfo {
+            endpoint: "endpoint".to_owned(),
+            guild_id: Id::new(1),
+            session_id: "session".to_owned(),
+            token: "token".to_owned(),
+            user_id: Id::new(3),
+        };
+
+        assert_eq!(None, voice.process(&state(3, "session", true)));
+        assert_eq!(None, voice.process(&state(4, "other", true)));
+        assert_eq!(
+            Some(&info),
+            voice.process(&server(Some("endpoint"))).as_ref()
+        );
+
+        // Unchanged session, such as when muting.
+        assert_eq!(None, voice.process(&state(3, "session", true)));
+        assert_eq!(Some(info), voice.process(&server(Some("endpoint"))));
+
+        assert_eq!(None, voice.process(&server(None)));
+        assert_eq!(None, voice.process(&state(3, "session", false)));
+        assert_eq!(None, voice.process(&server(Some("endpoint"))));
+    }
+}
@@ -0,0 +1,859 @@
+//! Voice connection to a voice server.
+
+use crate::{
+    crypto::{Cipher, EncryptionMode},
+    discovery,
+    model::{
+        self, Event, Heartbeat, Identify, OpCode, Ready, SelectProtocol, SelectProtocolData,
+        SpeakingUpdate,
+    },
+    rtp::{self, Header},
+};
+use dashmap::DashMap;
+use futures_util::{SinkExt, StreamExt};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    net::{IpAddr, Ipv4Addr},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    net::{TcpStream, UdpSocket},
+    sync::mpsc,
+    task::JoinHandle,
+    time::{self, Duration, MissedTickBehavior},
+};
+use tokio_websockets::{ClientBuilder, CloseCode, MaybeTlsStream, Message, WebSocketStream};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Voice gateway API version used by this crate.
+pub const API_VERSION: u8 = 8;
+
+/// Duration of audio of each Opus frame.
+pub const FRAME_DURATION: Duration = Duration::from_millis(20);
+
+/// Opus frame of silence.
+///
+/// Five of these should be sent after the last audio frame to avoid
+/// interpolation with the next audio.
+pub const SILENCE_FRAME: &[u8] = &[0xf8, 0xff, 0xfe];
+
+/// Maximum duration of the handshake before timing out.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum length of received UDP packets.
+const MAX_PACKET_LEN: usize = 1460;
+
+/// Number of samples per channel in each 20 ms frame at 48 kHz.
+const SAMPLES_PER_FRAME: u32 = 960;
+
+/// [`tokio_websockets`] library Websocket connection.
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Connecting to or communicating with a voice server failed.
+#[derive(Debug)]
+pub struct ConnectionError {
+    /// Type of error.
+    kind: ConnectionErrorType,
+    /// Source error if available.
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ConnectionError {
+    /// Immutable reference to the type of error that occurred.
+    pub const fn kind(&self) -> &ConnectionErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ConnectionErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Shortcut to create a new error from a websocket error.
+    fn websocket(kind: ConnectionErrorType, source: tokio_websockets::Error) -> Self {
+        Self {
+            kind,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    /// Shortcut to create a new error from a UDP socket error.
+    fn io(source: std::io::Error) -> Self {
+        Self {
+            kind: ConnectionErrorType::Io,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for ConnectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ConnectionErrorType::Closed => f.write_str("voice connection was closed"),
+            ConnectionErrorType::Connecting => f.write_str("failed to connect to voice server"),
+            ConnectionErrorType::Cryptography => {
+                f.write_str("failed to encrypt or decrypt a voice packet")
+            }
+            ConnectionErrorType::Deserializing { event } => {
+                f.write_str("voice gateway event could not be deserialized: event=")?;
+
+                f.write_str(event)
+            }
+            ConnectionErrorType::Discovery => f.write_str("ip discovery response was invalid"),
+            ConnectionErrorType::HeartbeatInterval { heartbeat_interval } => {
+                f.write_str("voice server sent invalid heartbeat interval ")?;
+
+                Display::fmt(heartbeat_interval, f)
+            }
+            ConnectionErrorType::Io => f.write_str("voice udp socket failed"),
+            ConnectionErrorType::Sending => f.write_str("failed to send voice gateway command"),
+            ConnectionErrorType::Timeout => f.write_str("voice handshake timed out"),
+            ConnectionErrorType::UnsupportedEncryption => {
+                f.write_str("voice server supports no known encryption mode")
+            }
+        }
+    }
+}
+
+impl Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ConnectionError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConnectionErrorType {
+    /// Voice websocket connection was closed.
+    ///
+    /// Voice channels requiring the unsupported DAVE end-to-end encryption
+    /// protocol close the connection with close code 4017.
+    Closed,
+    /// Connecting to the voice websocket failed.
+    Connecting,
+    /// Encrypting or decrypting a voice packet failed.
+    Cryptography,
+    /// Voice gateway event could not be deserialized.
+    Deserializing {
+        /// Voice gateway event that failed to deserialize.
+        event: String,
+    },
+    /// IP discovery response was invalid.
+    Discovery,
+    /// Voice server sent a heartbeat interval that's not a positive number of
+    /// milliseconds.
+    HeartbeatInterval {
+        /// Heartbeat interval in milliseconds.
+        heartbeat_interval: f64,
+    },
+    /// Sending or receiving over the UDP socket failed.
+    Io,
+    /// Sending a voice gateway command failed.
+    Sending,
+    /// Handshake did not complete in time.
+    Timeout,
+    /// Voice server supports no [`EncryptionMode`].
+    UnsupportedEncryption,
+}
+
+/// Information required to connect to a voice server.
+///
+/// Created by [`Voice::process`] from the current user's voice state and the
+/// guild's voice server.
+///
+/// [`Voice::process`]: crate::Voice::process
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConnectionInfo {
+    /// Endpoint of the voice server.
+    ///
+    /// Endpoints without a `ws://` or `wss://` scheme are connected to over
+    /// `wss://`.
+    pub endpoint: String,
+    /// ID of the guild of the voice channel.
+    pub guild_id: Id<GuildMarker>,
+    /// Session ID of the current user's voice state.
+    pub session_id: String,
+    /// Token of the voice server.
+    pub token: String,
+    /// ID of the current user.
+    pub user_id: Id<UserMarker>,
+}
+
+/// Opus frame received from a user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    /// Opus encoded audio.
+    pub opus: Vec<u8>,
+    /// Sequence number of the packet.
+    pub sequence: u16,
+    /// Synchronization source of the audio.
+    pub ssrc: u32,
+    /// Sampling instant of the audio at 48 kHz.
+    pub timestamp: u32,
+    /// ID of the user sending the audio, if known.
+    pub user_id: Option<Id<UserMarker>>,
+}
+
+/// Voice connection sending and receiving Opus frames.
+///
+/// The voice websocket connection is maintained by a background task, which
+/// closes it once the connection is dropped. Connections don't reconnect: a
+/// new connection should be made once [`is_closed`] returns `true`.
+///
+/// **Note:** The DAVE end-to-end encryption protocol isn't supported. Voice
+/// channels requiring it close the connection with close code 4017, which
+/// surfaces as a [`ConnectionErrorType::Closed`] error type or
+/// [`is_closed`] returning `true`.
+///
+/// [`is_closed`]: Self::is_closed
+pub struct Connection {
+    /// Cipher of the session's encryption mode.
+    cipher: Cipher,
+    /// ID of the guild of the voice channel.
+    guild_id: Id<GuildMarker>,
+    /// Encryption mode of the session.
+    mode: EncryptionMode,
+    /// Nonce of the next sent packet.
+    nonce: u32,
+    /// Sequence number of the next sent packet.
+    sequence: u16,
+    /// UDP socket connected to the voice server.
+    socket: UdpSocket,
+    /// SSRC of the current user.
+    ssrc: u32,
+    /// Task maintaining the websocket connection.
+    task: JoinHandle<()>,
+    /// Timestamp of the next sent packet.
+    timestamp: u32,
+    /// Sender of commands to the websocket task.
+    tx: mpsc::UnboundedSender<String>,
+    /// Users by their SSRC.
+    users: Arc<DashMap<u32, Id<UserMarker>>>,
+}
+
+impl Connection {
+    /// Connect to a voice server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Timeout`] error type if the handshake
+    /// did not complete within 10 seconds.
+    ///
+    /// Returns a [`ConnectionErrorType::UnsupportedEncryption`] error type if
+    /// the voice server supports no [`EncryptionMode`].
+    ///
+    /// Returns a [`ConnectionErrorType::HeartbeatInterval`] error type if the
+    /// voice server sent an invalid heartbeat interval.
+    ///
+    /// Returns other error types if connecting or communicating with the voice
+    /// server failed.
+    pub async fn connect(info: &ConnectionInfo) -> Result<Self, ConnectionError> {
+        time::timeout(HANDSHAKE_TIMEOUT, Self::handshake(info))
+            .await
+            .map_err(|source| ConnectionError {
+                kind: ConnectionErrorType::Timeout,
+                source: Some(Box::new(source)),
+            })?
+    }
+
+    /// Perform the voice websocket and UDP handshake.
+    async fn handshake(info: &ConnectionInfo) -> Result<Self, ConnectionError> {
+        let uri = if info.endpoint.starts_with("ws://") {
+            format!("{}/?v={API_VERSION}", info.endpoint)
+        } else {
+            let endpoint = info.endpoint.trim_start_matches("wss://");
+
+            format!("wss://{endpoint}/?v={API_VERSION}")
+        };
+        tracing::debug!(%uri, guild_id = %info.guild_id, "connecting to voice server");
+
+        let (mut stream, _) = ClientBuilder::new()
+            .uri(&uri)
+            .map_err(|source| ConnectionError {
+                kind: ConnectionErrorType::Connecting,
+                source: Some(Box::new(source)),
+            })?
+            .connect()
+            .await
+            .map_err(|source| {
+                ConnectionError::websocket(ConnectionErrorType::Connecting, source)
+            })?;
+
+        let identify = Identify {
+            max_dave_protocol_version: 0,
+            server_id: info.guild_id,
+            session_id: &info.session_id,
+            token: &info.token,
+            user_id: info.user_id,
+        };
+        send(&mut stream, model::payload(OpCode::Identify, &identify)).await?;
+
+        let mut heartbeat_interval = None;
+        let mut seq = None;
+        let ready = loop {
+            match next_event(&mut stream, &mut seq).await? {
+                Event::Hello(hello) => heartbeat_interval = Some(hello.heartbeat_interval),
+                Event::Ready(ready) => break ready,
+                _ => {}
+            }
+        };
+
+        let mode = EncryptionMode::select(&ready.modes).ok_or(ConnectionError {
+            kind: ConnectionErrorType::UnsupportedEncryption,
+            source: None,
+        })?;
+
+        let (socket, address, port) = bind(&ready).await?;
+        tracing::debug!(%address, port, ?mode, "discovered external address");
+
+        let select_protocol = SelectProtocol {
+            data: SelectProtocolData {
+                address: &address.to_string(),
+                mode: mode.name(),
+                port,
+            },
+            protocol: "udp",
+        };
+        send(
+            &mut stream,
+            model::payload(OpCode::SelectProtocol, &select_protocol),
+        )
+        .await?;
+
+        let description = loop {
+            match next_event(&mut stream, &mut seq).await? {
+                Event::Hello(hello) => heartbeat_interval = Some(hello.heartbeat_interval),
+                Event::SessionDescription(description) => break description,
+                _ => {}
+            }
+        };
+        let mode = EncryptionMode::from_name(&description.mode).unwrap_or(mode);
+        let cipher = Cipher::new(mode, &description.secret_key).ok_or(ConnectionError {
+            kind: ConnectionErrorType::Cryptography,
+            source: None,
+        })?;
+
+        let heartbeat_interval = match heartbeat_interval {
+            Some(heartbeat_interval) => heartbeat_interval,
+            None => loop {
+                if let Event::Hello(hello) = next_event(&mut stream, &mut seq).await? {
+                    break hello.heartbeat_interval;
+                }
+            },
+        };
+        let heartbeat_interval = heartbeat_duration(heartbeat_interval)?;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let users = Arc::new(DashMap::new());
+        let task = tokio::spawn(run(stream, rx, heartbeat_interval, seq, Arc::clone(&users)));
+        tracing::debug!(guild_id = %info.guild_id, ssrc = ready.ssrc, "connected to voice server");
+
+        Ok(Self {
+            cipher,
+            guild_id: info.guild_id,
+            mode,
+            nonce: 0,
+            sequence: 0,
+            socket,
+            ssrc: ready.ssrc,
+            task,
+            timestamp: 0,
+            tx,
+            users,
+        })
+    }
+
+    /// ID of the guild of the voice channel.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+
+    /// Encryption mode of the session.
+    pub const fn mode(&self) -> EncryptionMode {
+        self.mode
+    }
+
+    /// SSRC of the current user.
+    pub const fn ssrc(&self) -> u32 {
+        self.ssrc
+    }
+
+    /// Whether the voice websocket connection was closed.
+    pub fn is_closed(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// ID of the user sending audio with the SSRC, if known.
+    pub fn user(&self, ssrc: u32) -> Option<Id<UserMarker>> {
+        self.users.get(&ssrc).map(|user_id| *user_id)
+    }
+
+    /// Indicate whether the current user is speaking.
+    ///
+    /// Must be enabled before sending frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Closed`] error type if the voice
+    /// websocket connection was closed.
+    pub fn speaking(&self, speaking: bool) -> Result<(), ConnectionError> {
+        let update = SpeakingUpdate {
+            delay: 0,
+            speaking: speaking.into(),
+            ssrc: self.ssrc,
+        };
+
+        self.tx
+            .send(model::payload(OpCode::Speaking, &update))
+            .map_err(|_| ConnectionError {
+                kind: ConnectionErrorType::Closed,
+                source: None,
+            })
+    }
+
+    /// Send an Opus frame of 20 ms of stereo audio at 48 kHz.
+    ///
+    /// Frames should be sent every [`FRAME_DURATION`], which is left to the
+    /// caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Cryptography`] error type if the frame
+    /// failed to encrypt.
+    ///
+    /// Returns a [`ConnectionErrorType::Io`] error type if sending the packet
+    /// failed.
+    pub async fn send(&mut self, opus: &[u8]) -> Result<(), ConnectionError> {
+        let header = rtp::header(self.sequence, self.timestamp, self.ssrc);
+        let packet = self
+            .cipher
+            .encrypt(&header, opus, self.nonce)
+            .map_err(|source| ConnectionError {
+                kind: ConnectionErrorType::Cryptography,
+                source: Some(Box::new(source)),
+            })?;
+
+        self.socket
+            .send(&packet)
+            .await
+            .map_err(ConnectionError::io)?;
+
+        self.nonce = self.nonce.wrapping_add(1);
+        self.sequence = self.sequence.wrapping_add(1);
+        self.timestamp = self.timestamp.wrapping_add(SAMPLES_PER_FRAME);
+
+        Ok(())
+    }
+
+    /// Receive the next Opus frame from another user.
+    ///
+    /// Packets other than Opus audio, such as RTCP packets, are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Cryptography`] error type if the packet
+    /// failed to decrypt.
+    ///
+    /// Returns a [`ConnectionErrorType::Io`] error type if receiving a packet
+    /// failed.
+    pub async fn recv(&self) -> Result<Frame, ConnectionError> {
+        let mut buf = [0; MAX_PACKET_LEN];
+
+        loop {
+            let len = self
+                .socket
+                .recv(&mut buf)
+                .await
+                .map_err(ConnectionError::io)?;
+            let packet = &buf[..len];
+
+            let Some(header) = Header::parse(packet).filter(|header| header.opus) else {
+                continue;
+            };
+
+            let opus = self
+                .cipher
+                .decrypt(&header, packet)
+                .map_err(|source| ConnectionError {
+                    kind: ConnectionErrorType::Cryptography,
+                    source: Some(Box::new(source)),
+                })?;
+
+            return Ok(Frame {
+                opus,
+                sequence: header.sequence,
+                ssrc: header.ssrc,
+                timestamp: header.timestamp,
+                user_id: self.user(header.ssrc),
+            });
+        }
+    }
+}
+
+impl Debug for Connection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Connection")
+            .field("guild_id", &self.guild_id)
+            .field("mode", &self.mode)
+            .field("sequence", &self.sequence)
+            .field("ssrc", &self.ssrc)
+            .field("timestamp", &self.timestamp)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Send a JSON payload over the websocket.
+async fn send(stream: &mut WebSocket, json: String) -> Result<(), ConnectionError> {
+    stream
+        .send(Message::text(json))
+        .await
+        .map_err(|source| ConnectionError::websocket(ConnectionErrorType::Sending, source))
+}
+
+/// Receive the next event, updating the sequence number.
+async fn next_event(
+    stream: &mut WebSocket,
+    seq: &mut Option<u64>,
+) -> Result<Event, ConnectionError> {
+    loop {
+        let message = match stream.next().await {
+            Some(Ok(message)) => message,
+            Some(Err(source)) => {
+                return Err(ConnectionError::websocket(
+                    ConnectionErrorType::Closed,
+                    source,
+                ))
+            }
+            None => {
+                return Err(ConnectionError {
+                    kind: ConnectionErrorType::Closed,
+                    source: None,
+                })
+            }
+        };
+
+        if message.is_close() {
+            tracing::debug!(frame = ?message.as_close(), "voice server closed connection");
+
+            return Err(ConnectionError {
+                kind: ConnectionErrorType::Closed,
+                source: None,
+            });
+        }
+
+        let Some(json) = message.as_text() else {
+            continue;
+        };
+
+        let (event, sequence) = model::parse(json).map_err(|source| ConnectionError {
+            kind: ConnectionErrorType::Deserializing {
+                event: json.to_owned(),
+            },
+            source: Some(Box::new(source)),
+        })?;
+        if sequence.is_some() {
+            *seq = sequence;
+        }
+
+        return Ok(event);
+    }
+}
+
+/// Convert a heartbeat interval in milliseconds into a duration.
+///
+/// Intervals that are negative, zero, not a number or too large to represent
+/// are invalid.
+fn heartbeat_duration(heartbeat_interval: f64) -> Result<Duration, ConnectionError> {
+    Duration::try_from_secs_f64(heartbeat_interval / 1000.0)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or(ConnectionError {
+            kind: ConnectionErrorType::HeartbeatInterval { heartbeat_interval },
+            source: None,
+        })
+}
+
+/// Bind a UDP socket connected to the voice server, discovering its external
+/// address and port.
+async fn bind(ready: &Ready) -> Result<(UdpSocket, IpAddr, u16), ConnectionError> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .await
+        .map_err(ConnectionError::io)?;
+    socket
+        .connect((ready.ip.as_str(), ready.port))
+        .await
+        .map_err(ConnectionError::io)?;
+    let (address, port) = discover(&socket, ready.ssrc).await?;
+
+    Ok((socket, address, port))
+}
+
+/// Discover the external address and port of the socket.
+///
+/// Requests are resent every second, since UDP packets may be lost.
+async fn discover(socket: &UdpSocket, ssrc: u32) -> Result<(IpAddr, u16), ConnectionError> {
+    let request = discovery::request(ssrc);
+    let mut buf = [0; discovery::PACKET_LEN];
+
+    loop {
+        socket.send(&request).await.map_err(ConnectionError::io)?;
+
+        match time::timeout(Duration::from_secs(1), socket.recv(&mut buf)).await {
+            Ok(Ok(len)) => {
+                return discovery::parse_response(&buf[..len]).ok_or(ConnectionError {
+                    kind: ConnectionErrorType::Discovery,
+                    source: None,
+                })
+            }
+            Ok(Err(source)) => return Err(ConnectionError::io(source)),
+            Err(_) => tracing::debug!("retrying ip discovery"),
+        }
+    }
+}
+
+/// Maintain the websocket connection until either side closes it.
+async fn run(
+    mut stream: WebSocket,
+    mut rx: mpsc::UnboundedReceiver<String>,
+    heartbeat_interval: Duration,
+    mut seq: Option<u64>,
+    users: Arc<DashMap<u32, Id<UserMarker>>>,
+) {
+    let mut interval = time::interval(heartbeat_interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                #[allow(clippy::cast_possible_truncation)]
+                let t = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64;
+                let heartbeat = model::payload(OpCode::Heartbeat, &Heartbeat { seq_ack: seq, t });
+
+                if let Err(source) = send(&mut stream, heartbeat).await {
+                    tracing::warn!(?source, "failed to send voice heartbeat");
+
+                    break;
+                }
+            }
+            json = rx.recv() => {
+                let Some(json) = json else {
+                    _ = stream.send(Message::close(Some(CloseCode::NORMAL_CLOSURE), "")).await;
+
+                    break;
+                };
+
+                if let Err(source) = send(&mut stream, json).await {
+                    tracing::warn!(?source, "failed to send voice command");
+
+                    break;
+                }
+            }
+            event = next_event(&mut stream, &mut seq) => match event {
+                Ok(Event::Speaking(speaking)) => {
+                    users.insert(speaking.ssrc, speaking.user_id);
+                }
+                Ok(Event::ClientDisconnect(disconnect)) => {
+                    users.retain(|_, user_id| *user_id != disconnect.user_id);
+                }
+                Ok(_) => {}
+                Err(source) if matches!(source.kind(), ConnectionErrorType::Deserializing { .. }) => {
+                    tracing::warn!(?source, "failed to deserialize voice event");
+                }
+                Err(source) => {
+                    tracing::debug!(?source, "voice connection closed");
+
+                    break;
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        heartbeat_duration, Connection, ConnectionError, ConnectionErrorType, ConnectionInfo, Frame,
+    };
+    use crate::{
+        crypto::{Cipher, EncryptionMode},
+        discovery,
+        rtp::Header,
+    };
+    use futures_util::{SinkExt, StreamExt};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use tokio::{
+        net::{TcpListener, TcpStream, UdpSocket},
+        task::JoinHandle,
+    };
+    use tokio_websockets::{Message, ServerBuilder, WebSocketStream};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(Connection: Debug, Send, Sync);
+    assert_impl_all!(ConnectionError: Error, Send, Sync);
+    assert_impl_all!(ConnectionErrorType: Debug, Send, Sync);
+    assert_impl_all!(ConnectionInfo: Clone, Debug, Eq, Send, Sync);
+    assert_impl_all!(Frame: Clone, Debug, Eq, Send, Sync);
+
+    /// Secret key of fake voice sessions.
+    const SECRET_KEY: [u8; 32] = [7; 32];
+
+    /// Fake voice server performing the handshake.
+    struct Server {
+        /// Information to connect to the server.
+        info: ConnectionInfo,
+        /// Task performing the websocket handshake, resolving to the
+        /// websocket connection.
+        task: JoinHandle<WebSocketStream<TcpStream>>,
+        /// UDP socket of the server.
+        udp: UdpSocket,
+    }
+
+    /// Start a fake voice server sending the heartbeat interval.
+    async fn server(heartbeat_interval: &'static str) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let udp = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let info = ConnectionInfo {
+            endpoint: format!("ws://{}", listener.local_addr().unwrap()),
+            guild_id: Id::new(1),
+            session_id: "session".to_owned(),
+            token: "token".to_owned(),
+            user_id: Id::new(2),
+        };
+        let udp_port = udp.local_addr().unwrap().port();
+
+        let task = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = ServerBuilder::new().accept(stream).await.unwrap();
+
+            let identify = stream.next().await.unwrap().unwrap();
+            assert!(identify.as_text().unwrap().contains(r#""op":0"#));
+            send_json(
+                &mut stream,
+                format!(r#"{{"op":8,"d":{{"heartbeat_interval":{heartbeat_interval}}}}}"#),
+            )
+            .await;
+            send_json(&mut stream, format!(
+                r#"{{"op":2,"seq":1,"d":{{"ssrc":3,"ip":"127.0.0.1","port":{udp_port},"modes":["{}"]}}}}"#,
+                EncryptionMode::Aes256Gcm.name(),
+            ))
+            .await;
+
+            let select_protocol = stream.next().await.unwrap().unwrap();
+            assert!(select_protocol.as_text().unwrap().contains(r#""op":1"#));
+            send_json(
+                &mut stream,
+                format!(
+                    r#"{{"op":4,"seq":2,"d":{{"mode":"{}","secret_key":{SECRET_KEY:?}}}}}"#,
+                    EncryptionMode::Aes256Gcm.name(),
+                ),
+            )
+            .await;
+            send_json(
+                &mut stream,
+                r#"{"op":5,"seq":3,"d":{"ssrc":4,"user_id":"5","speaking":1}}"#.to_owned(),
+            )
+            .await;
+
+            stream
+        });
+
+        Server { info, task, udp }
+    }
+
+    /// Send a JSON payload over the websocket.
+    async fn send_json(stream: &mut WebSocketStream<TcpStream>, json: String) {
+        stream.send(Message::text(json)).await.unwrap();
+    }
+
+    /// Answer the IP discovery request of a client.
+    async fn answer_discovery(udp: &UdpSocket) {
+        let mut buf = [0; discovery::PACKET_LEN];
+        let (_, address) = udp.recv_from(&mut buf).await.unwrap();
+
+        let mut response = [0; discovery::PACKET_LEN];
+        response[0..2].copy_from_slice(&[0, 2]);
+        response[8..17].copy_from_slice(b"127.0.0.1");
+        response[72..74].copy_from_slice(&address.port().to_be_bytes());
+        udp.send_to(&response, address).await.unwrap();
+    }
+
+    #[test]
+    fn heartbeat_interval() {
+        assert_eq!(
+            Duration::from_millis(41_250),
+            heartbeat_duration(41_250.0).unwrap()
+        );
+
+        for heartbeat_interval in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                heartbeat_duration(heartbeat_interval).unwrap_err().kind(),
+                ConnectionErrorType::HeartbeatInterval { .. }
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn connect() {
+        let Server { info, task, udp } = server("41250.0").await;
+
+        let (connection, ()) = tokio::join!(Connection::connect(&info), answer_discovery(&udp));
+        let mut connection = connection.unwrap();
+        let _stream = task.await.unwrap();
+        assert_eq!(Id::new(1), connection.guild_id());
+        assert_eq!(EncryptionMode::Aes256Gcm, connection.mode());
+        assert_eq!(3, connection.ssrc());
+
+        connection.send(&[1, 2, 3]).await.unwrap();
+        let mut buf = [0; 64];
+        let len = udp.recv(&mut buf).await.unwrap();
+        let packet = &buf[..len];
+        let header = Header::parse(packet).unwrap();
+        assert!(header.opus);
+        assert_eq!(3, header.ssrc);
+
+        let cipher = Cipher::new(EncryptionMode::Aes256Gcm, &SECRET_KEY).unwrap();
+        assert_eq!(vec![1, 2, 3], cipher.decrypt(&header, packet).unwrap());
+
+        // The speaking event is processed by the background task.
+        for _ in 0..100 {
+            if connection.user(4).is_some() {
+                break;
+            }
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(Some(Id::new(5)), connection.user(4));
+        assert!(!connection.is_closed());
+    }
+
+    #[tokio::test]
+    async fn connect_invalid_heartbeat_interval() {
+        let Server { info, task, udp } = server("0").await;
+
+        let (connection, ()) = tokio::join!(Connection::connect(&info), answer_discovery(&udp));
+        let error = connection.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ConnectionErrorType::HeartbeatInterval { heartbeat_interval } if *heartbeat_interval == 0.0
+        ));
+        drop(task.await.unwrap());
+    }
+}
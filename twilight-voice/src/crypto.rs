@@ -0,0 +1,219 @@
+//! Encryption of voice packets.
+//!
+//! Only the `rtpsize` AEAD modes are supported, which leave the RTP header
+//! (including the extension header) unencrypted as additional data and append
+//! a 32 bit incrementing nonce to each packet.
+//!
+//! See <https://discord.com/developers/docs/topics/voice-connections#transport-encryption-modes>
+
+use crate::rtp::Header;
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm,
+};
+use chacha20poly1305::XChaCha20Poly1305;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Length of the nonce appended to packets.
+const NONCE_LEN: usize = 4;
+
+/// Length of the authentication tag.
+const TAG_LEN: usize = 16;
+
+/// Encryption mode of voice packets.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EncryptionMode {
+    /// AEAD AES256-GCM with a 32 bit nonce, preferred if available.
+    Aes256Gcm,
+    /// AEAD XChaCha20-Poly1305 with a 32 bit nonce, which Discord always
+    /// supports.
+    XChaCha20Poly1305,
+}
+
+impl EncryptionMode {
+    /// Modes in order of preference.
+    const PREFERENCE: [Self; 2] = [Self::Aes256Gcm, Self::XChaCha20Poly1305];
+
+    /// Parse a mode from its name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::PREFERENCE
+            .into_iter()
+            .find(|mode| mode.name() == name)
+    }
+
+    /// Name of the mode as used by Discord.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Aes256Gcm => "aead_aes256_gcm_rtpsize",
+            Self::XChaCha20Poly1305 => "aead_xchacha20_poly1305_rtpsize",
+        }
+    }
+
+    /// Select the most preferred supported mode of the modes.
+    pub(crate) fn select(modes: &[impl AsRef<str>]) -> Option<Self> {
+        Self::PREFERENCE
+            .into_iter()
+            .find(|mode| modes.iter().any(|name| name.as_ref() == mode.name()))
+    }
+}
+
+/// Encrypting or decrypting a packet failed.
+#[derive(Debug)]
+pub struct CryptoError;
+
+impl Display for CryptoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("packet failed encryption or authentication")
+    }
+}
+
+impl Error for CryptoError {}
+
+/// Cipher of an [`EncryptionMode`] with a secret key.
+pub enum Cipher {
+    /// [`EncryptionMode::Aes256Gcm`] cipher.
+    Aes256Gcm(Box<Aes256Gcm>),
+    /// [`EncryptionMode::XChaCha20Poly1305`] cipher.
+    XChaCha20Poly1305(Box<XChaCha20Poly1305>),
+}
+
+impl Cipher {
+    /// Create a new cipher of the mode.
+    ///
+    /// Returns `None` if the key has an invalid length.
+    pub fn new(mode: EncryptionMode, key: &[u8]) -> Option<Self> {
+        Some(match mode {
+            EncryptionMode::Aes256Gcm => {
+                Self::Aes256Gcm(Box::new(Aes256Gcm::new_from_slice(key).ok()?))
+            }
+            EncryptionMode::XChaCha20Poly1305 => {
+                Self::XChaCha20Poly1305(Box::new(XChaCha20Poly1305::new_from_slice(key).ok()?))
+            }
+        })
+    }
+
+    /// Encrypt the payload, returning the packet of the header, encrypted
+    /// payload and nonce.
+    pub fn encrypt(
+        &self,
+        header: &[u8],
+        payload: &[u8],
+        nonce: u32,
+    ) -> Result<Vec<u8>, CryptoError> {
+        let payload = Payload {
+            aad: header,
+            msg: payload,
+        };
+        let encrypted = match self {
+            Self::Aes256Gcm(cipher) => cipher.encrypt(&nonce_bytes::<12>(nonce).into(), payload),
+            Self::XChaCha20Poly1305(cipher) => {
+                cipher.encrypt(&nonce_bytes::<24>(nonce).into(), payload)
+            }
+        }
+        .map_err(|_| CryptoError)?;
+
+        let mut packet = Vec::with_capacity(header.len() + encrypted.len() + NONCE_LEN);
+        packet.extend_from_slice(header);
+        packet.extend_from_slice(&encrypted);
+        packet.extend_from_slice(&nonce.to_be_bytes());
+
+        Ok(packet)
+    }
+
+    /// Decrypt the payload of the packet, stripping the extension data.
+    pub fn decrypt(&self, header: &Header, packet: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        if packet.len() < header.len + TAG_LEN + NONCE_LEN {
+            return Err(CryptoError);
+        }
+
+        let (packet, nonce) = packet.split_at(packet.len() - NONCE_LEN);
+        let nonce = u32::from_be_bytes(nonce.try_into().expect("nonce is 4 bytes"));
+        let (aad, msg) = packet.split_at(header.len);
+        let payload = Payload { aad, msg };
+
+        let mut decrypted = match self {
+            Self::Aes256Gcm(cipher) => cipher.decrypt(&nonce_bytes::<12>(nonce).into(), payload),
+            Self::XChaCha20Poly1305(cipher) => {
+                cipher.decrypt(&nonce_bytes::<24>(nonce).into(), payload)
+            }
+        }
+        .map_err(|_| CryptoError)?;
+
+        if header.extension_len > decrypted.len() {
+            return Err(CryptoError);
+        }
+        decrypted.drain(..header.extension_len);
+
+        Ok(decrypted)
+    }
+}
+
+/// Expand the 32 bit nonce into a nonce of `N` bytes.
+fn nonce_bytes<const N: usize>(nonce: u32) -> [u8; N] {
+    let mut bytes = [0; N];
+    bytes[..NONCE_LEN].copy_from_slice(&nonce.to_be_bytes());
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cipher, EncryptionMode};
+    use crate::rtp::{self, Header};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(EncryptionMode: Clone, Copy, Debug, Eq, Send, Sync);
+    assert_impl_all!(Cipher: Send, Sync);
+
+    #[test]
+    fn select() {
+        assert_eq!(
+            Some(EncryptionMode::Aes256Gcm),
+            EncryptionMode::select(&["aead_xchacha20_poly1305_rtpsize", "aead_aes256_gcm_rtpsize"])
+        );
+        assert_eq!(
+            Some(EncryptionMode::XChaCha20Poly1305),
+            EncryptionMode::select(&["xsalsa20_poly1305", "aead_xchacha20_poly1305_rtpsize"])
+        );
+        assert_eq!(None, EncryptionMode::select(&["xsalsa20_poly1305"]));
+    }
+
+    #[test]
+    fn round_trip() {
+        for mode in EncryptionMode::PREFERENCE {
+            assert_eq!(Some(mode), EncryptionMode::from_name(mode.name()));
+
+            let cipher = Cipher::new(mode, &[7; 32]).unwrap();
+            let header = rtp::header(1, 960, 42);
+            let packet = cipher.encrypt(&header, b"opus", 5).unwrap();
+            assert_eq!([0, 0, 0, 5], packet[packet.len() - 4..]);
+
+            let parsed = Header::parse(&packet).unwrap();
+            assert_eq!(b"opus".to_vec(), cipher.decrypt(&parsed, &packet).unwrap());
+
+            let mut tampered = packet.clone();
+            tampered[3] ^= 1;
+            assert!(cipher.decrypt(&parsed, &tampered).is_err());
+        }
+
+        assert!(Cipher::new(EncryptionMode::Aes256Gcm, &[0; 16]).is_none());
+    }
+
+    #[test]
+    fn strips_extension() {
+        let cipher = Cipher::new(EncryptionMode::XChaCha20Poly1305, &[7; 32]).unwrap();
+        let mut header = rtp::header(1, 960, 42).to_vec();
+        header[0] |= 0x10;
+        header.extend_from_slice(&[0xbe, 0xde, 0x00, 0x01]);
+
+        let packet = cipher.encrypt(&header, b"extnopus", 1).unwrap();
+        let parsed = Header::parse(&packet).unwrap();
+
+        assert_eq!(b"opus".to_vec(), cipher.decrypt(&parsed, &packet).unwrap());
+    }
+}
@@ -0,0 +1,68 @@
+//! UDP IP discovery of the external address.
+//!
+//! See <https://discord.com/developers/docs/topics/voice-connections#ip-discovery>
+
+use std::net::IpAddr;
+
+/// Length of discovery packets.
+pub const PACKET_LEN: usize = 74;
+
+/// Type of discovery requests.
+const REQUEST: u16 = 1;
+
+/// Type of discovery responses.
+const RESPONSE: u16 = 2;
+
+/// Create a discovery request for the SSRC.
+pub fn request(ssrc: u32) -> [u8; PACKET_LEN] {
+    let mut packet = [0; PACKET_LEN];
+    packet[0..2].copy_from_slice(&REQUEST.to_be_bytes());
+    #[allow(clippy::cast_possible_truncation)]
+    packet[2..4].copy_from_slice(&((PACKET_LEN - 4) as u16).to_be_bytes());
+    packet[4..8].copy_from_slice(&ssrc.to_be_bytes());
+
+    packet
+}
+
+/// Parse the external address and port from a discovery response.
+pub fn parse_response(packet: &[u8]) -> Option<(IpAddr, u16)> {
+    if packet.len() != PACKET_LEN || packet[0..2] != RESPONSE.to_be_bytes() {
+        return None;
+    }
+
+    let address = &packet[8..72];
+    let end = address.iter().position(|&byte| byte == 0)?;
+    let address = std::str::from_utf8(&address[..end]).ok()?.parse().ok()?;
+    let port = u16::from_be_bytes([packet[72], packet[73]]);
+
+    Some((address, port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_response, request, PACKET_LEN};
+    use std::net::{IpAddr, Ipv4Addr};
+
+    #[test]
+    fn request_packet() {
+        let packet = request(0x0102_0304);
+
+        assert_eq!([0, 1, 0, 70, 1, 2, 3, 4], packet[..8]);
+        assert!(packet[8..].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn response_packet() {
+        let mut packet = [0; PACKET_LEN];
+        packet[0..2].copy_from_slice(&[0, 2]);
+        packet[8..17].copy_from_slice(b"127.0.0.1");
+        packet[72..74].copy_from_slice(&50_000_u16.to_be_bytes());
+
+        assert_eq!(
+            Some((IpAddr::V4(Ipv4Addr::LOCALHOST), 50_000)),
+            parse_response(&packet)
+        );
+        assert_eq!(None, parse_response(&request(1)));
+        assert_eq!(None, parse_response(&packet[..PACKET_LEN - 1]));
+    }
+}
@@ -0,0 +1,24 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc = include_str!("../README.md")]
+#![warn(
+    clippy::missing_const_for_fn,
+    clippy::missing_docs_in_private_items,
+    clippy::pedantic,
+    missing_docs,
+    unsafe_code
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+pub mod client;
+pub mod connection;
+
+mod crypto;
+mod discovery;
+mod model;
+mod rtp;
+
+pub use self::{
+    client::Voice,
+    connection::{Connection, ConnectionInfo, Frame},
+    crypto::EncryptionMode,
+};
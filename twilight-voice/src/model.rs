@@ -0,0 +1,292 @@
+//! Payloads of the voice gateway.
+//!
+//! See <https://discord.com/developers/docs/topics/voice-connections>
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Voice gateway opcodes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum OpCode {
+    /// Begin a voice session.
+    Identify = 0,
+    /// Select the voice protocol and encryption mode.
+    SelectProtocol = 1,
+    /// Voice session is ready for discovery.
+    Ready = 2,
+    /// Keep the websocket connection alive.
+    Heartbeat = 3,
+    /// Encryption key of the voice session.
+    SessionDescription = 4,
+    /// Indicate which users are speaking.
+    Speaking = 5,
+    /// Acknowledgement of a heartbeat.
+    HeartbeatAck = 6,
+    /// Interval to heartbeat at.
+    Hello = 8,
+    /// A user disconnected from the voice channel.
+    ClientDisconnect = 13,
+}
+
+impl OpCode {
+    /// Parse an opcode from its number.
+    pub const fn new(op: u8) -> Option<Self> {
+        Some(match op {
+            0 => Self::Identify,
+            1 => Self::SelectProtocol,
+            2 => Self::Ready,
+            3 => Self::Heartbeat,
+            4 => Self::SessionDescription,
+            5 => Self::Speaking,
+            6 => Self::HeartbeatAck,
+            8 => Self::Hello,
+            13 => Self::ClientDisconnect,
+            _ => return None,
+        })
+    }
+}
+
+/// Begin a voice session.
+#[derive(Debug, Serialize)]
+pub struct Identify<'a> {
+    /// Highest supported DAVE end-to-end encryption protocol version.
+    pub max_dave_protocol_version: u8,
+    /// ID of the guild of the voice channel.
+    pub server_id: Id<GuildMarker>,
+    /// Session ID of the voice state.
+    pub session_id: &'a str,
+    /// Token of the voice server.
+    pub token: &'a str,
+    /// ID of the current user.
+    pub user_id: Id<UserMarker>,
+}
+
+/// Select the voice protocol and encryption mode.
+#[derive(Debug, Serialize)]
+pub struct SelectProtocol<'a> {
+    /// Protocol data.
+    pub data: SelectProtocolData<'a>,
+    /// Voice protocol, always `udp`.
+    pub protocol: &'a str,
+}
+
+/// [`SelectProtocol`] data.
+#[derive(Debug, Serialize)]
+pub struct SelectProtocolData<'a> {
+    /// External IP address as discovered.
+    pub address: &'a str,
+    /// Encryption mode.
+    pub mode: &'a str,
+    /// External port as discovered.
+    pub port: u16,
+}
+
+/// Keep the websocket connection alive.
+#[derive(Debug, Serialize)]
+pub struct Heartbeat {
+    /// Sequence number of the last received payload.
+    pub seq_ack: Option<u64>,
+    /// Nonce echoed by the heartbeat acknowledgement.
+    pub t: u64,
+}
+
+/// Indicate that the current user is sending audio.
+#[derive(Debug, Serialize)]
+pub struct SpeakingUpdate {
+    /// Delay of the audio, always `0` for bots.
+    pub delay: u8,
+    /// Bitfield of speaking modes, `1` for microphone audio.
+    pub speaking: u8,
+    /// SSRC of the current user.
+    pub ssrc: u32,
+}
+
+/// Serialize a payload with an opcode.
+pub fn payload(op: OpCode, d: &impl Serialize) -> String {
+    /// Envelope of outgoing payloads.
+    #[derive(Serialize)]
+    struct Outgoing<'a, T> {
+        /// Payload data.
+        d: &'a T,
+        /// Payload opcode.
+        op: u8,
+    }
+
+    serde_json::to_string(&Outgoing { d, op: op as u8 }).expect("serialization cannot fail")
+}
+
+/// A user disconnected from the voice channel.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct ClientDisconnect {
+    /// ID of the user.
+    pub user_id: Id<UserMarker>,
+}
+
+/// Interval to heartbeat at.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Hello {
+    /// Heartbeat interval in milliseconds.
+    pub heartbeat_interval: f64,
+}
+
+/// Voice session is ready for discovery.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Ready {
+    /// IP address of the voice server.
+    pub ip: String,
+    /// Supported encryption modes.
+    pub modes: Vec<String>,
+    /// UDP port of the voice server.
+    pub port: u16,
+    /// SSRC of the current user.
+    pub ssrc: u32,
+}
+
+/// Encryption key of the voice session.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct SessionDescription {
+    /// Selected encryption mode.
+    pub mode: String,
+    /// Secret encryption key.
+    pub secret_key: Vec<u8>,
+}
+
+/// A user started or stopped speaking.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Speaking {
+    /// SSRC of the user's audio.
+    pub ssrc: u32,
+    /// ID of the user.
+    pub user_id: Id<UserMarker>,
+}
+
+/// Event received over the voice gateway.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    /// A user disconnected from the voice channel.
+    ClientDisconnect(ClientDisconnect),
+    /// Acknowledgement of a heartbeat.
+    HeartbeatAck,
+    /// Interval to heartbeat at.
+    Hello(Hello),
+    /// Voice session is ready for discovery.
+    Ready(Ready),
+    /// Encryption key of the voice session.
+    SessionDescription(SessionDescription),
+    /// A user started or stopped speaking.
+    Speaking(Speaking),
+    /// Event with an unhandled opcode.
+    Unknown(u8),
+}
+
+/// Parse a JSON payload into an event and its sequence number, if any.
+pub fn parse(json: &str) -> Result<(Event, Option<u64>), serde_json::Error> {
+    /// Envelope of incoming payloads.
+    #[derive(Deserialize)]
+    struct Incoming {
+        /// Payload data.
+        #[serde(default)]
+        d: Value,
+        /// Payload opcode.
+        op: u8,
+        /// Sequence number of the payload.
+        #[serde(default)]
+        seq: Option<u64>,
+    }
+
+    let Incoming { d, op, seq } = serde_json::from_str(json)?;
+    let event = match OpCode::new(op) {
+        Some(OpCode::ClientDisconnect) => Event::ClientDisconnect(serde_json::from_value(d)?),
+        Some(OpCode::HeartbeatAck) => Event::HeartbeatAck,
+        Some(OpCode::Hello) => Event::Hello(serde_json::from_value(d)?),
+        Some(OpCode::Ready) => Event::Ready(serde_json::from_value(d)?),
+        Some(OpCode::SessionDescription) => Event::SessionDescription(serde_json::from_value(d)?),
+        Some(OpCode::Speaking) => Event::Speaking(serde_json::from_value(d)?),
+        _ => Event::Unknown(op),
+    };
+
+    Ok((event, seq))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse, payload, Event, Heartbeat, Hello, OpCode, Ready, SessionDescription, Speaking,
+    };
+    use twilight_model::id::Id;
+
+    #[test]
+    fn parse_events() {
+        assert_eq!(
+            (
+                Event::Hello(Hello {
+                    heartbeat_interval: 41_250.0
+                }),
+                None
+            ),
+            parse(r#"{"op":8,"d":{"v":8,"heartbeat_interval":41250.0}}"#).unwrap()
+        );
+        assert_eq!(
+            (
+                Event::Ready(Ready {
+                    ip: "127.0.0.1".to_owned(),
+                    modes: vec!["aead_aes256_gcm_rtpsize".to_owned()],
+                    port: 1234,
+                    ssrc: 1,
+                }),
+                Some(1)
+            ),
+            parse(
+                r#"{"op":2,"seq":1,"d":{"ssrc":1,"ip":"127.0.0.1","port":1234,"modes":["aead_aes256_gcm_rtpsize"],"heartbeat_interval":1}}"#
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            (
+                Event::SessionDescription(SessionDescription {
+                    mode: "aead_aes256_gcm_rtpsize".to_owned(),
+                    secret_key: vec![1, 2, 3],
+                }),
+                Some(2)
+            ),
+            parse(
+                r#"{"op":4,"seq":2,"d":{"mode":"aead_aes256_gcm_rtpsize","secret_key":[1,2,3]}}"#
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            (
+                Event::Speaking(Speaking {
+                    ssrc: 2,
+                    user_id: Id::new(3),
+                }),
+                Some(3)
+            ),
+            parse(r#"{"op":5,"seq":3,"d":{"user_id":"3","ssrc":2,"speaking":1}}"#).unwrap()
+        );
+        assert_eq!(
+            (Event::HeartbeatAck, None),
+            parse(r#"{"op":6,"d":{"t":1}}"#).unwrap()
+        );
+        assert_eq!((Event::Unknown(18), None), parse(r#"{"op":18}"#).unwrap());
+    }
+
+    #[test]
+    fn serialize_payload() {
+        assert_eq!(
+            r#"{"d":{"seq_ack":5,"t":1},"op":3}"#,
+            payload(
+                OpCode::Heartbeat,
+                &Heartbeat {
+                    seq_ack: Some(5),
+                    t: 1
+                }
+            )
+        );
+    }
+}
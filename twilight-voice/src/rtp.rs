@@ -0,0 +1,118 @@
+//! Construction and parsing of RTP packets.
+//!
+//! See <https://datatracker.ietf.org/doc/html/rfc3550#section-5.1>
+
+/// Length of a header without CSRCs or extensions.
+pub const HEADER_LEN: usize = 12;
+
+/// Payload type of Opus audio.
+const OPUS_PAYLOAD_TYPE: u8 = 0x78;
+
+/// Supported RTP version.
+const VERSION: u8 = 2;
+
+/// Parsed header of an RTP packet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Header {
+    /// Length of the extension data, following the header.
+    pub extension_len: usize,
+    /// Length of the header, including CSRCs and the extension header.
+    pub len: usize,
+    /// Whether the payload is Opus audio.
+    pub opus: bool,
+    /// Sequence number of the packet.
+    pub sequence: u16,
+    /// Synchronization source of the packet.
+    pub ssrc: u32,
+    /// Sampling instant of the first sample.
+    pub timestamp: u32,
+}
+
+impl Header {
+    /// Parse the header of a packet.
+    ///
+    /// Returns `None` if the packet isn't a valid RTP packet.
+    pub fn parse(packet: &[u8]) -> Option<Self> {
+        if packet.len() < HEADER_LEN || packet[0] >> 6 != VERSION {
+            return None;
+        }
+
+        let csrc_count = usize::from(packet[0] & 0x0f);
+        let mut len = HEADER_LEN + 4 * csrc_count;
+        let extension_len = if packet[0] & 0x10 == 0 {
+            0
+        } else {
+            let words = packet.get(len + 2..len + 4)?;
+            len += 4;
+
+            4 * usize::from(u16::from_be_bytes([words[0], words[1]]))
+        };
+
+        if packet.len() < len {
+            return None;
+        }
+
+        Some(Self {
+            extension_len,
+            len,
+            opus: packet[1] & 0x7f == OPUS_PAYLOAD_TYPE,
+            sequence: u16::from_be_bytes([packet[2], packet[3]]),
+            ssrc: u32::from_be_bytes([packet[8], packet[9], packet[10], packet[11]]),
+            timestamp: u32::from_be_bytes([packet[4], packet[5], packet[6], packet[7]]),
+        })
+    }
+}
+
+/// Create the header of an Opus audio packet.
+pub fn header(sequence: u16, timestamp: u32, ssrc: u32) -> [u8; HEADER_LEN] {
+    let mut header = [0; HEADER_LEN];
+    header[0] = VERSION << 6;
+    header[1] = OPUS_PAYLOAD_TYPE;
+    header[2..4].copy_from_slice(&sequence.to_be_bytes());
+    header[4..8].copy_from_slice(&timestamp.to_be_bytes());
+    header[8..12].copy_from_slice(&ssrc.to_be_bytes());
+
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{header, Header, HEADER_LEN};
+
+    #[test]
+    fn round_trip() {
+        let header = header(1, 960, 42);
+
+        assert_eq!(
+            Some(Header {
+                extension_len: 0,
+                len: HEADER_LEN,
+                opus: true,
+                sequence: 1,
+                ssrc: 42,
+                timestamp: 960,
+            }),
+            Header::parse(&header)
+        );
+    }
+
+    #[test]
+    fn extension() {
+        let mut packet = header(1, 960, 42).to_vec();
+        packet[0] |= 0x10;
+        packet.extend_from_slice(&[0xbe, 0xde, 0x00, 0x01]);
+
+        let header = Header::parse(&packet).unwrap();
+        assert_eq!(HEADER_LEN + 4, header.len);
+        assert_eq!(4, header.extension_len);
+
+        packet.truncate(HEADER_LEN + 2);
+        assert_eq!(None, Header::parse(&packet));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(None, Header::parse(&[0x80; HEADER_LEN - 1]));
+        assert_eq!(None, Header::parse(&[0; HEADER_LEN]));
+    }
+}